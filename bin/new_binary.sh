@@ -133,7 +133,8 @@ sed -f temp.sed src/op/binary/powf.rs > src/op/binary/$name.rs
 #
 cat << EOF
 src/op/binary/$name.rs: Fix ${name}_forward_der and ${name}_reverse_der
-                        Check constraints in this set_op_fns function.
+                        Check constraints and linearity in this set_op_fns function.
+src/op/taylor.rs:       Add the Taylor coefficient recurrence for $name.
 examples/f_binary.rs: Add an example for $name function values.
 tests/$name.rs: Add a test for $name derivatives.
 EOF
@@ -148,7 +148,8 @@ sed -f temp.sed src/op/unary/exp_m1.rs > src/op/unary/$name.rs
 #
 cat << EOF
 src/op/unary/$name.rs: Fix ${name}_forward_der and ${name}_reverse_der
-                       Check constraints in this set_op_fns function.
+                       Check constraints and linearity in this set_op_fns function.
+src/op/taylor.rs:      Add the Taylor coefficient recurrence for $name.
 src/float/az_float.rs: Check implementation of fn $name(&self) -> Self
 examples/f_unary.rs: Add an example for $name function values.
 test/f_unary.rs: Add a test for $name derivatives.
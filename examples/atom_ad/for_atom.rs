@@ -149,7 +149,7 @@ pub fn register_for_sumsq_atom()-> IndexT {
     //
     // for_sumsq_callback
     let for_sumsq_callback = AtomCallback {
-        name                 : &"for_sumsq",
+        name                 : "for_sumsq",
         rev_depend           :  Some( for_sumsq_rev_depend ),
         //
         forward_fun_value    :  Some(for_sumsq_forward_fun_value),
@@ -54,7 +54,7 @@ pub fn sumsq_forward_fun_ad(
 {   //
     // atom_id
     let atom_id = ATOM_ID_VEC.with_borrow( |atom_id_vec|
-        atom_id_vec[3 * (call_info as usize) + 0]
+        atom_id_vec[3 * (call_info as usize)]
     );
     //
     // n_domain
@@ -1,6 +1,7 @@
 // SPDX-License-Identifier: EPL-2.0 OR GPL-2.0-or-later
 // SPDX-FileCopyrightText: Bradley M. Bell <bradbell@seanet.com>
 // SPDX-FileContributor: 2025-26 Bradley M. Bell
+#![allow(clippy::let_and_return, clippy::needless_range_loop, clippy::op_ref)]
 /*
 This atomic example uses all the possible AtomCallback function.
 The sumsq_forward_der_ad and reverse_forward_der_ad callbacks each
@@ -36,7 +37,8 @@ type V = AzFloat<f64>;
 //
 // ATOM_ID_VEC
 thread_local! {
-    pub static ATOM_ID_VEC : RefCell< Vec<IndexT> > = RefCell::new(Vec::new());
+    pub static ATOM_ID_VEC : RefCell< Vec<IndexT> > =
+        const { RefCell::new(Vec::new()) };
 }
 //
 // tests
@@ -95,7 +97,7 @@ fn register_sumsq_atom()-> IndexT {
     //
     // sumsq_callback
     let sumsq_callback = AtomCallback {
-        name                 : &"sumsq",
+        name                 : "sumsq",
         rev_depend           :  Some( sumsq_rev_depend ),
         //
         forward_fun_value    :  Some(sumsq_forward_fun_value),
@@ -149,7 +149,7 @@ pub fn register_rev_sumsq_atom()-> IndexT {
     //
     // rev_sumsq_callback
     let rev_sumsq_callback = AtomCallback {
-        name                 : &"rev_sumsq",
+        name                 : "rev_sumsq",
         rev_depend           :  Some( rev_sumsq_rev_depend ),
         //
         forward_fun_value    :  Some(rev_sumsq_forward_fun_value),
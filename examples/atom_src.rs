@@ -1,6 +1,7 @@
 // SPDX-License-Identifier: EPL-2.0 OR GPL-2.0-or-later
 // SPDX-FileCopyrightText: Bradley M. Bell <bradbell@seanet.com>
 // SPDX-FileContributor: 2025-26 Bradley M. Bell
+#![allow(clippy::let_and_return, clippy::needless_range_loop)]
 /*
 Example converting an ADfn, that has an atomic function, call to source code.
 
@@ -52,7 +53,7 @@ fn register_sumsq_atom()-> IndexT {
     //
     // sumsq_callback
     let sumsq_callback = AtomCallback {
-        name                 : &"sumsq",
+        name                 : "sumsq",
         rev_depend           :  Some( sumsq_rev_depend ),
         //
         forward_fun_value    :  Some( sumsq_forward_fun_value ),
@@ -156,14 +157,14 @@ fn main() {
     let lib         = get_lib(src_dir, lib_file, replace_lib);
     //
     // sumsq_fn
-    let sumsq_fn : RustSrcLink<V> = get_rust_src_fn(&lib, &fn_name);
+    let sumsq_fn : RustSrcLink<V> = get_rust_src_fn(&lib, fn_name);
     //
     // p_ref, x_ref
     let p_ref     : Vec<&V> = Vec::new();
     let x         : Vec<V>  = vec![ V::from(3.0); nx ];
     let mut x_ref : Vec<&V> = Vec::new();
     for x_j in x.iter() {
-        x_ref.push( &x_j )
+        x_ref.push( x_j )
     }
     //
     // check result
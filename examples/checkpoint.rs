@@ -1,6 +1,7 @@
 // SPDX-License-Identifier: EPL-2.0 OR GPL-2.0-or-later
 // SPDX-FileCopyrightText: Bradley M. Bell <bradbell@seanet.com>
 // SPDX-FileContributor: 2025-26 Bradley M. Bell
+#![allow(clippy::let_and_return, clippy::needless_range_loop)]
 //
 // ---------------------------------------------------------------------------
 // Example of doing checkpointing using atomic functions.
@@ -86,4 +86,18 @@ fn main() {
         let check = NumVec::new( vec![ S::from(1.0), S::from(16.0) ] );
         assert_eq!(res, check);
     }
+    //
+    // min, max
+    {   type S  = AzFloat<f64>;
+        let lhs = NumVec::new( vec![ S::from(1.0), S::from(4.0) ] );
+        let rhs = NumVec::new( vec![ S::from(3.0), S::from(2.0) ] );
+        //
+        let res   = lhs.clone().min( rhs.clone() );
+        let check = NumVec::new( vec![ S::from(1.0), S::from(2.0) ] );
+        assert_eq!(res, check);
+        //
+        let res   = lhs.max(rhs);
+        let check = NumVec::new( vec![ S::from(3.0), S::from(4.0) ] );
+        assert_eq!(res, check);
+    }
 }
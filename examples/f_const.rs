@@ -2,6 +2,7 @@
 // SPDX-FileCopyrightText: Bradley M. Bell <bradbell@seanet.com>
 // SPDX-FileContributor: 2026 Bradley M. Bell
 //
+#[allow(clippy::eq_op)]
 fn main() {
     use rustad::{
         AzFloat,
@@ -12,6 +13,7 @@ fn main() {
     // pi
     {   type V      = AzFloat<f64>;
         let pi      = V::pi();
+        #[allow(clippy::approx_constant, clippy::excessive_precision)]
         let check   = V::from(3.14159265358979323846264338327950288);
         assert_eq!(pi, check);
     }
@@ -0,0 +1,75 @@
+// SPDX-License-Identifier: EPL-2.0 OR GPL-2.0-or-later
+// SPDX-FileCopyrightText: Bradley M. Bell <bradbell@seanet.com>
+// SPDX-FileContributor: 2026 Bradley M. Bell
+//
+fn main() {
+    use rustad::{
+        AD,
+        AzFloat,
+        NumVec,
+        FReduce,
+        start_recording,
+        stop_recording,
+    };
+    //
+    // reduce_sum, reduce_mean, reduce_max, reduce_len
+    {   type S    = AzFloat<f64>;
+        let x     = NumVec::new(
+            vec![ S::from(3.0), S::from(5.0), S::from(1.0) ]
+        );
+        assert_eq!( (&x).reduce_sum(),  NumVec::from( S::from(9.0) ) );
+        assert_eq!( (&x).reduce_mean(), NumVec::from( S::from(3.0) ) );
+        assert_eq!( (&x).reduce_max(),  NumVec::from( S::from(5.0) ) );
+        assert_eq!( (&x).reduce_len(),  NumVec::from( S::from(3.0) ) );
+    }
+    //
+    // reduce_dot
+    {   type S    = AzFloat<f32>;
+        let x     = NumVec::new( vec![ S::from(1.0), S::from(2.0) ] );
+        let y     = NumVec::new( vec![ S::from(3.0), S::from(4.0) ] );
+        let res   = (&x).reduce_dot(&y);
+        assert_eq!( res.len(), 1 );
+        assert_eq!( res.get(0), S::from(11.0) );
+    }
+    //
+    // scalar
+    {   type V    = AzFloat<f64>;
+        let x     = V::from(2.0);
+        assert_eq!( (&x).reduce_sum(), x );
+        assert_eq!( (&x).reduce_len(), V::from(1.0) );
+    }
+    //
+    // batch loss
+    // loss(p) = mean_i ( y_i - p * x_i )^2
+    {   type S    = AzFloat<f64>;
+        type V    = NumVec<S>;
+        let opt_vec : Vec<[&str; 2]> = Vec::new();
+        let x     = NumVec::new( [1.0, 2.0, 3.0].map( S::from ).to_vec() );
+        let y     = NumVec::new( [2.0, 4.0, 7.0].map( S::from ).to_vec() );
+        let p     = V::from( S::from(2.0) );
+        //
+        let (_, ap)  = start_recording( None, vec![ p.clone() ] );
+        let ax       = AD::from( x.clone() );
+        let ay       = AD::from( y.clone() );
+        let ares     = &ay - &( &ap[0] * &ax );
+        let aloss    = (&ares).reduce_dot(&ares);
+        let aloss    = &aloss / &(&ares).reduce_len();
+        let f        = stop_recording( vec![ aloss ] );
+        //
+        // loss = (0^2 + 0^2 + 1^2) / 3
+        let (loss, v) = f.forward_var_value(None, vec![ p.clone() ], &opt_vec);
+        assert_eq!( loss[0], V::from( S::from(1.0) / S::from(3.0) ) );
+        //
+        // d/dp loss = - 2 * mean_i ( y_i - p * x_i ) * x_i = - 2 * 3 / 3
+        let dp        = vec![ V::from( S::from(1.0) ) ];
+        let dloss     = f.forward_der_value(None, &v, dp, &opt_vec);
+        assert_eq!( dloss[0], V::from( S::from(-2.0) ) );
+        //
+        // The partial w.r.t. the scalar p is element-wise because p is
+        // multiplied by the vector x; i.e., its sum is d/dp loss.
+        let dloss     = vec![ V::from( S::from(1.0) ) ];
+        let dp        = f.reverse_der_value(None, &v, dloss, &opt_vec);
+        assert_eq!( dp[0].len(), 3 );
+        assert_eq!( (&dp[0]).reduce_sum(), V::from( S::from(-2.0) ) );
+    }
+}
@@ -18,6 +18,34 @@ fn main() {
         let sum          = minus_3 + abs_minus_3;
         assert_eq!( sum, FConst::zero() );
     }
+    // asin
+    {   type V = AzFloat<f64>;
+        let half        = V::from(0.5);
+        let asin_half   = FUnary::asin( &half );
+        let pi_6        = V::pi() / V::from(6);
+        let opt_vec : Vec<[&str; 2]> = Vec::new();
+        assert!( nearly_eq::<V>(&asin_half, &pi_6, &opt_vec) );
+    }
+    // atan
+    {   type V = AzFloat<f32>;
+        let atan_1      = V::from(1.0).atan();
+        let pi_4        = V::pi() / V::from(4);
+        let opt_vec : Vec<[&str; 2]> = Vec::new();
+        assert!( nearly_eq::<V>(&atan_1, &pi_4, &opt_vec) );
+    }
+    // erf
+    {   type V = AzFloat<f64>;
+        let x           = V::from(0.5);
+        let sum         = x.erf() + x.erfc();
+        assert_eq!( sum, FConst::one() );
+    }
+    // log2, exp2
+    {   type V = AzFloat<f64>;
+        let eight       = V::from(8.0);
+        let log2_8      = eight.log2();
+        assert_eq!( log2_8, V::from(3.0) );
+        assert_eq!( log2_8.exp2(), eight );
+    }
     // exp
     {   type V = AzFloat<f32>;
         let one          = FConst::one();
@@ -73,6 +101,14 @@ fn main() {
         let two        = V::from(2.0);
         assert_eq!(exp_ln_2, two);
     }
+    // floor, ceil, round, trunc
+    {   type V = AzFloat<f64>;
+        let x = V::from( -2.5 );
+        assert_eq!( x.floor(), V::from( -3.0 ) );
+        assert_eq!( x.ceil(),  V::from( -2.0 ) );
+        assert_eq!( x.round(), V::from( -3.0 ) );
+        assert_eq!( x.trunc(), V::from( -2.0 ) );
+    }
     // signum
     {   type V = AzFloat<f32>;
         let minus_1      = V::from( -1.0 );
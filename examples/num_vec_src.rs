@@ -1,6 +1,7 @@
 // SPDX-License-Identifier: EPL-2.0 OR GPL-2.0-or-later
 // SPDX-FileCopyrightText: Bradley M. Bell <bradbell@seanet.com>
 // SPDX-FileContributor: 2025-26 Bradley M. Bell
+#![allow(clippy::needless_range_loop)]
 //
 // Example converting a NumVec function to source code.
 //
@@ -53,10 +54,10 @@ fn main () {
     let lib         = get_lib(src_dir, lib_file, replace_lib);
     //
     // num_vec_sumsq_fn
-    let num_vec_sumsq_fn : RustSrcLink<V> = get_rust_src_fn(&lib, &gn_name);
+    let num_vec_sumsq_fn : RustSrcLink<V> = get_rust_src_fn(&lib, gn_name);
     //
     // v
-    let sv  : Vec<S>  = (0 .. nx).map( |j| S::from(j) ).collect();
+    let sv  : Vec<S>  = (0 .. nx).map( S::from ).collect();
     let v   : V       = NumVec::new( sv );
     //
     // p_ref, x_ref
@@ -64,7 +65,7 @@ fn main () {
     let x     : Vec<V>  = vec![ v.clone() ; nx ];
     let mut x_ref : Vec<&V> = Vec::new();
     for x_j in x.iter() {
-        x_ref.push( &x_j )
+        x_ref.push( x_j )
     }
     //
     // check result
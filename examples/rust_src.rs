@@ -1,6 +1,7 @@
 // SPDX-License-Identifier: EPL-2.0 OR GPL-2.0-or-later
 // SPDX-FileCopyrightText: Bradley M. Bell <bradbell@seanet.com>
 // SPDX-FileContributor: 2025-26 Bradley M. Bell
+#![allow(clippy::needless_range_loop)]
 //
 // Example converting a derivative calculation to rust source code
 //
@@ -65,14 +66,14 @@ fn main () {
     let lib         = get_lib(src_dir, lib_file, replace_lib);
     //
     // sumsq_fn_reverse_der_fn
-    let sumsq_reverse_der_fn : RustSrcLink<V> = get_rust_src_fn(&lib, &gn_name);
+    let sumsq_reverse_der_fn : RustSrcLink<V> = get_rust_src_fn(&lib, gn_name);
     //
     // p_ref, x_ref
     let p_ref     : Vec<&V> = Vec::new();
     let x         : Vec<V>  = vec![ V::from(3.0); nx ];
     let mut x_ref : Vec<&V> = Vec::new();
     for x_j in x.iter() {
-        x_ref.push( &x_j )
+        x_ref.push( x_j )
     }
     //
     // check result
@@ -68,9 +68,8 @@ fn main() {
     assert_eq!( y[0], p[0] * p[0] );
     assert_eq!( y[1], x[0] * x[0] );
     let option       = pop_this_thread_message();
-    match option  {
-        Some(_msg) => panic!("zero_one: expected no message"),
-        None       => (),
+    if let Some(_msg) = option {
+        panic!("zero_one: expected no message");
     }
     //
     // y
@@ -100,8 +99,7 @@ fn main() {
     }
     //
     let option       = pop_this_thread_message();
-    match option  {
-        Some(_msg) => panic!("zero_one: expected no message"),
-        None       => (),
+    if let Some(_msg) = option {
+        panic!("zero_one: expected no message");
     }
 }
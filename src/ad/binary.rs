@@ -117,7 +117,7 @@ pub fn doc_ad_binary_op() { }
 // --------------------------------------------------------------------------
 // record_aa
 /// Record one binary where left and right operands are `AD<V>`
-fn record_aa <V> (
+pub(crate) fn record_aa <V> (
     tape      : &mut Tape<V>  ,
     lhs       : &AD<V>        ,
     rhs       : &AD<V>        ,
@@ -170,15 +170,13 @@ where
         }
         match op_id_pp {
             //
-            id::ADD_PP_OP => {
+            id::ADD_PP_OP if lhs.value == V::zero() => {
                 // add with left operand the constant zero
-                if lhs.value == V::zero() {
-                    return (rhs.tape_id, rhs.index, rhs.ad_type);
-                }
+                return (rhs.tape_id, rhs.index, rhs.ad_type);
             },
             id::MUL_PP_OP => {
                 // multiply with left operand the constant zero
-                if lhs.value == V::zero() {
+                if V::ABSOLUTE_ZERO && lhs.value == V::zero() {
                     return (new_tape_id, new_index, new_ad_type);
                 }
                 // multiply with left operand the constant one
@@ -200,15 +198,13 @@ where
     } else if cop_rhs {
         match op_id_pp {
             //
-            id::ADD_PP_OP => {
+            id::ADD_PP_OP if rhs.value == V::zero() => {
                 // add with right operand the constant zero
-                if rhs.value == V::zero() {
-                    return (lhs.tape_id, lhs.index, lhs.ad_type);
-                }
+                return (lhs.tape_id, lhs.index, lhs.ad_type);
             },
             id::MUL_PP_OP => {
                 // multiply with right operand the constant zero
-                if rhs.value == V::zero() {
+                if V::ABSOLUTE_ZERO && rhs.value == V::zero() {
                     return (new_tape_id, new_index, new_ad_type);
                 }
                 // multiply with right operand the constant one
@@ -216,11 +212,9 @@ where
                     return (lhs.tape_id, lhs.index, lhs.ad_type);
                 }
             },
-            id::DIV_PP_OP => {
+            id::DIV_PP_OP if rhs.value == V::one() => {
                 // divide with right operand the constant one
-                if rhs.value == V::one() {
-                    return (lhs.tape_id, lhs.index, lhs.ad_type);
-                }
+                return (lhs.tape_id, lhs.index, lhs.ad_type);
             },
             _ => { }
         }
@@ -303,7 +297,7 @@ where
 ///
 /// We use _ac when left is an AD object and right is known to be constant.
 /// We do not use _av to avoid confusion between values and variables.
-fn record_ac<V> (
+pub(crate) fn record_ac<V> (
     tape     : &mut Tape<V> ,
     lhs      : &AD<V>       ,
     rhs      : &V           ,
@@ -340,15 +334,13 @@ where
     }
     match op_id_pp {
         //
-        id::ADD_PP_OP => {
+        id::ADD_PP_OP if *rhs == V::zero() => {
             // add with right operand the constant zero
-            if *rhs == V::zero() {
-                return (lhs.tape_id, lhs.index, lhs.ad_type);
-            }
+            return (lhs.tape_id, lhs.index, lhs.ad_type);
         },
         id::MUL_PP_OP => {
             // multiply with right operand the constant zero
-            if *rhs == V::zero() {
+            if V::ABSOLUTE_ZERO && *rhs == V::zero() {
                 return (new_tape_id, new_index, new_ad_type);
             }
             // multiply with right operand the constant one
@@ -356,11 +348,9 @@ where
                 return (lhs.tape_id, lhs.index, lhs.ad_type);
             }
         },
-        id::DIV_PP_OP => {
+        id::DIV_PP_OP if *rhs == V::one() => {
             // divide with right operand the constant one
-            if *rhs == V::one() {
-                return (lhs.tape_id, lhs.index, lhs.ad_type);
-            }
+            return (lhs.tape_id, lhs.index, lhs.ad_type);
         },
         _ => { }
     }
@@ -453,15 +443,13 @@ where
     }
     match op_id_pp {
         //
-        id::ADD_PP_OP => {
+        id::ADD_PP_OP if *lhs == V::zero() => {
             // add with left operand the constant zero
-            if *lhs == V::zero() {
-                return (rhs.tape_id, rhs.index, rhs.ad_type);
-            }
+            return (rhs.tape_id, rhs.index, rhs.ad_type);
         },
         id::MUL_PP_OP => {
             // multiply with left operand the constant zero
-            if *lhs == V::zero() {
+            if V::ABSOLUTE_ZERO && *lhs == V::zero() {
                 return (new_tape_id, new_index, new_ad_type);
             }
             // multiply with left operand the constant one
@@ -469,11 +457,9 @@ where
                 return (rhs.tape_id, rhs.index, rhs.ad_type);
             }
         },
-        id::DIV_PP_OP => {
+        id::DIV_PP_OP if V::ABSOLUTE_ZERO && *lhs == V::zero() => {
             // divide with left operand the constant zero
-            if *lhs == V::zero() {
-                return (new_tape_id, new_index, new_ad_type);
-            }
+            return (new_tape_id, new_index, new_ad_type);
         },
         _ => { }
     }
@@ -803,6 +789,41 @@ ad_compound_op!(Sub);
 ad_compound_op!(Mul);
 ad_compound_op!(Div);
 // ---------------------------------------------------------------------------
+// record_value_op_ad
+/// Compute and record one binary operator where lhs is a *V* object
+/// and rhs is an `AD<V>` object.
+///
+/// * V : see [doc_generic_v]
+/// * op : computes the value of the operator.
+/// * op_id_pp : is the corresponding parameter, parameter operator id.
+///
+/// This function is used by [impl_value_op_ad](crate::impl_value_op_ad) .
+#[doc(hidden)]
+pub fn record_value_op_ad<V>(
+    lhs      : &V                  ,
+    rhs      : &AD<V>              ,
+    op       : fn(&V, &V) -> V     ,
+    op_id_pp : u8                  ,
+) -> AD<V>
+where
+    V : Clone + FConst + PartialEq + ThisThreadTape ,
+{   //
+    // new_value
+    let new_value = op(lhs, &rhs.value);
+    //
+    // local_key
+    let local_key : &LocalKey< RefCell< Tape<V> > > = ThisThreadTape::get();
+    //
+    // new_tape_id, new_index, new_ad_type
+    let (new_tape_id, new_index, new_ad_type) =
+    local_key.with_borrow_mut( |tape|
+        record_ca::<V>( tape, lhs, rhs, op_id_pp )
+    );
+    //
+    // result
+    AD::new(new_tape_id, new_index, new_ad_type, new_value)
+}
+// ---------------------------------------------------------------------------
 // impl_value_op_ad!
 //
 // If you try to make this implementation generic w.r.t V, you get the message
@@ -812,71 +833,54 @@ ad_compound_op!(Div);
 ///
 /// * V : see [doc_generic_v]
 /// * Name : is the operator name; i.e., Add, Sub, Mul, or Div.
-/// * Op : is the operator token; i.e., +, -, *, or /.
+/// * name : is the operator function name; i.e., add, sub, mul, or div.
+/// * OpId : is the corresponding parameter, parameter operator id.
 ///
 /// If *V* is the only argument to this macro, it will invoke itself
-/// with *Op* equal to +, -, *, / and the corresponding *Name* .
+/// for each of the four binary operators.
 ///
 /// see [doc_ad_binary_op]
 ///
-/// This macro can be invoked from anywhere given the following use statements:
-/// ```text
-///     use std::thread::LocalKey;
-///     use std::cell::RefCell;
-///     use crate::ad::AD;
-/// ```
+/// This macro is used by [setup_value_type](crate::setup_value_type) .
+#[doc(hidden)]
+#[macro_export]
 macro_rules! impl_value_op_ad{
     ($V:ty)                      => {
-        crate::ad::binary::impl_value_op_ad!($V, Add);
-        crate::ad::binary::impl_value_op_ad!($V, Sub);
-        crate::ad::binary::impl_value_op_ad!($V, Mul);
-        crate::ad::binary::impl_value_op_ad!($V, Div);
+        $crate::impl_value_op_ad!($V, Add, add, ADD_PP_OP);
+        $crate::impl_value_op_ad!($V, Sub, sub, SUB_PP_OP);
+        $crate::impl_value_op_ad!($V, Mul, mul, MUL_PP_OP);
+        $crate::impl_value_op_ad!($V, Div, div, DIV_PP_OP);
     };
-    ($V:ty, $Name:ident) => { paste::paste! {
+    ($V:ty, $Name:ident, $name:ident, $OpId:ident) => {
         #[doc =
         "see [doc_ad_binary_op](crate::ad::binary::doc_ad_binary_op)"
         ]
-        impl std::ops::$Name< &AD<$V> > for & $V
+        impl std::ops::$Name< &$crate::AD<$V> > for & $V
         where
             for <'a> &'a $V : std::ops::$Name<&'a $V, Output=$V>,
-        {   type Output = AD<$V>;
+        {   type Output = $crate::AD<$V>;
             //
             #[ doc = concat!(
                 "compute `&", stringify!($V), "` ",
                 stringify!($Name), " `&AD<", stringify!($V), ">` "
             ) ]
-            fn [< $Name:lower >] (self , rhs : &AD<$V>) -> AD<$V> {
-                //
-                // new_value
-                let new_value = self.[< $Name:lower >] ( &rhs.value);
-                //
-                // op_id_pp
-                let op_id_pp  = crate::op::id::[< $Name:upper _PP_OP >];
-                //
-                // local_key
-                let local_key : &LocalKey<
-                    RefCell< crate::tape::Tape<$V> >
-                > = crate::tape::sealed::ThisThreadTape::get();
-                //
-                // new_tape_id, new_index
-                let (new_tape_id, new_index, new_ad_type) =
-                local_key.with_borrow_mut( |tape|
-                    crate::ad::binary::record_ca::<$V>
-                            ( tape, &self, &rhs, op_id_pp )
-                );
-                //
-                // result
-                AD::new(new_tape_id, new_index, new_ad_type, new_value)
+            fn $name (self , rhs : &$crate::AD<$V>) -> $crate::AD<$V> {
+                $crate::setup::private::record_value_op_ad::<$V>(
+                    self,
+                    rhs,
+                    |lhs, rhs| std::ops::$Name::$name(lhs, rhs),
+                    $crate::setup::private::$OpId,
+                )
             }
         }
-        impl std::ops::$Name< AD<$V> > for $V
+        impl std::ops::$Name< $crate::AD<$V> > for $V
         where
-            for <'a> &'a $V : std::ops::$Name<&'a AD<$V>, Output=AD<$V> >,
-        {   type Output = AD<$V>;
-            fn [< $Name:lower >] (self , rhs : AD<$V>) -> AD<$V> {
-                (&self).[< $Name:lower >] ( &rhs )
+            for <'a> &'a $V :
+                std::ops::$Name<&'a $crate::AD<$V>, Output=$crate::AD<$V> >,
+        {   type Output = $crate::AD<$V>;
+            fn $name (self , rhs : $crate::AD<$V>) -> $crate::AD<$V> {
+                std::ops::$Name::$name(&self, &rhs)
             }
         }
-    } }
+    }
 }
-pub(crate) use impl_value_op_ad;
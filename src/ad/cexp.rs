@@ -0,0 +1,275 @@
+// SPDX-License-Identifier: EPL-2.0 OR GPL-2.0-or-later
+// SPDX-FileCopyrightText: Bradley M. Bell <bradbell@seanet.com>
+// SPDX-FileContributor: 2026 Bradley M. Bell
+// ---------------------------------------------------------------------------
+//! This pub module defines the `AD<V>` select function
+//! (conditional expressions).
+//!
+//! Link to [parent module](super)
+//!
+// ---------------------------------------------------------------------------
+// use
+use std::thread::LocalKey;
+use std::cell::RefCell;
+use crate::{
+    AD,
+    IndexT,
+};
+use crate::ad::ADType;
+use crate::tape::Tape;
+use crate::op::id::CEXP_OP;
+use crate::tape::sealed::ThisThreadTape;
+// ---------------------------------------------------------------------------
+// CompareOp
+/// The comparison used by a conditional expression; see [AD::select] .
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CompareOp {
+    /// cond_lhs <  cond_rhs
+    Lt,
+    /// cond_lhs <= cond_rhs
+    Le,
+    /// cond_lhs == cond_rhs
+    Eq,
+    /// cond_lhs != cond_rhs
+    Ne,
+    /// cond_lhs >= cond_rhs
+    Ge,
+    /// cond_lhs >  cond_rhs
+    Gt,
+}
+// ---------------------------------------------------------------------------
+pub(crate) mod sealed {
+    //! The sub-module sealed is used to seal traits in this package
+    //
+    use std::ops::{
+        Add,
+        Sub,
+        Mul,
+    };
+    use crate::{
+        IndexT,
+        FConst,
+        FValue,
+        FBinary,
+    };
+    use crate::op::cexp::{
+        compare,
+        select,
+    };
+    //
+    // SelectValue
+    /// Value of a conditional expression and which branches were selected.
+    pub trait SelectValue
+    where
+        Self : Sized ,
+    {
+        fn select_value(
+            cmp_code : IndexT ,
+            cond_lhs : &Self  ,
+            cond_rhs : &Self  ,
+            if_true  : &Self  ,
+            if_false : &Self  ,
+        ) -> (Self, [bool; 2]);
+    }
+    impl<V> SelectValue for V
+    where
+        V             : Clone + FConst + FValue ,
+        for<'a> &'a V : FBinary<&'a V, Output = V> ,
+        for<'a> &'a V : Add<&'a V, Output = V> ,
+        for<'a> &'a V : Sub<&'a V, Output = V> ,
+        for<'a> &'a V : Mul<&'a V, Output = V> ,
+    {
+        fn select_value(
+            cmp_code : IndexT ,
+            cond_lhs : &V     ,
+            cond_rhs : &V     ,
+            if_true  : &V     ,
+            if_false : &V     ,
+        ) -> (V, [bool; 2]) {
+            let c        = compare::<V>(cmp_code, cond_lhs, cond_rhs);
+            let value    = select::<V>(&c, if_true, if_false);
+            let selected = [ ! c.is_zero(), ! c.is_one() ];
+            (value, selected)
+        }
+    }
+}
+// ---------------------------------------------------------------------------
+impl<V> AD<V>
+where
+    V : Clone + ThisThreadTape + sealed::SelectValue ,
+{
+    /// Conditional expression
+    ///
+    /// * Syntax :
+    ///   ```text
+    ///     aresult = AD::select(&cond_lhs, &cond_rhs, cmp, &if_true, &if_false)
+    ///   ```
+    ///
+    /// * cond_lhs, cond_rhs :
+    ///   are the left and right operands for the comparison.
+    ///
+    /// * cmp :
+    ///   is the [CompareOp] used to compare cond_lhs with cond_rhs.
+    ///
+    /// * if_true, if_false :
+    ///   are the possible values for the result.
+    ///
+    /// * aresult :
+    ///   is if_true (if_false) when the comparison is true (false).
+    ///   For numeric vectors, the comparison and selection is element wise.
+    ///   The branch that is not selected does not affect the result,
+    ///   even if it is nan.
+    ///
+    /// * Recording :
+    ///   If the comparison only depends on constants, the selected branch
+    ///   is returned and nothing is recorded.
+    ///   Otherwise, one CEXP_OP operator is recorded and the comparison
+    ///   is evaluated each time the function is evaluated.
+    ///   Derivatives of the result are the derivative of the selected branch;
+    ///   i.e., the comparison does not contribute to the derivative.
+    ///   The branches selected during the recording are also recorded;
+    ///   see the cexp option for [ADfn::optimize](crate::ADfn::optimize)
+    ///   and [ADfn::for_sparsity](crate::ADfn::for_sparsity).
+    ///
+    /// # Example
+    /// ```
+    /// use rustad::{
+    ///     AD,
+    ///     CompareOp,
+    ///     FUnary,
+    ///     start_recording,
+    ///     stop_recording,
+    /// };
+    /// //
+    /// // V
+    /// type V = rustad::AzFloat<f64>;
+    /// //
+    /// // f
+    /// // f(x) = if x[0] < x[1] { sin(x[0]) } else { x[1] * x[1] }
+    /// let x         = vec![ V::from(1.0), V::from(2.0) ];
+    /// let (_, ax)   = start_recording(None, x);
+    /// let asin      = (&ax[0]).sin();
+    /// let asq       = &ax[1] * &ax[1];
+    /// let ay        = AD::select(&ax[0], &ax[1], CompareOp::Lt, &asin, &asq);
+    /// let f         = stop_recording( vec![ay] );
+    /// //
+    /// // check
+    /// let opt_vec : Vec<[&str; 2]> = Vec::new();
+    /// let x         = vec![ V::from(3.0), V::from(2.0) ];
+    /// let (y, _)    = f.forward_var_value(None, x, &opt_vec);
+    /// assert_eq!( y[0], V::from(4.0) );
+    /// let x         = vec![ V::from(0.5), V::from(2.0) ];
+    /// let (y, _)    = f.forward_var_value(None, x.clone(), &opt_vec);
+    /// assert_eq!( y[0], x[0].sin() );
+    /// ```
+    pub fn select(
+        cond_lhs : &AD<V>    ,
+        cond_rhs : &AD<V>    ,
+        cmp      : CompareOp ,
+        if_true  : &AD<V>    ,
+        if_false : &AD<V>    ,
+    ) -> AD<V> {
+        //
+        // cmp_code, new_value, selected
+        // selected: was if_true, if_false selected for some element
+        let cmp_code  = cmp as IndexT;
+        let (new_value, selected) = sealed::SelectValue::select_value(
+            cmp_code,
+            &cond_lhs.value,
+            &cond_rhs.value,
+            &if_true.value,
+            &if_false.value,
+        );
+        //
+        // local_key
+        let local_key : &LocalKey< RefCell< Tape<V> > > =
+            ThisThreadTape::get();
+        //
+        // new_tape_id, new_index, new_ad_type
+        let arg = [ cond_lhs, cond_rhs, if_true, if_false ];
+        let (new_tape_id, new_index, new_ad_type) =
+            local_key.with_borrow_mut( |tape|
+                record_cexp(tape, cmp_code, &arg, selected)
+            );
+        AD::new(new_tape_id, new_index, new_ad_type, new_value)
+    }
+}
+// ---------------------------------------------------------------------------
+// record_cexp
+fn record_cexp<V>(
+    tape     : &mut Tape<V>   ,
+    cmp_code : IndexT         ,
+    arg      : &[&AD<V>; 4]   ,
+    selected : [bool; 2]      ,
+) -> (usize, usize, ADType)
+where
+    V : Clone,
+{   //
+    if ! tape.recording {
+        return (0, 0, ADType::ConstantP);
+    }
+    //
+    // arg_type
+    let mut arg_type = [ADType::ConstantP; 4];
+    for i in 0 .. 4 {
+        if arg[i].tape_id == tape.tape_id {
+            debug_assert!( arg[i].ad_type != ADType::ConstantP );
+            arg_type[i] = arg[i].ad_type;
+        }
+    }
+    //
+    // constant condition
+    // only one branch is selected so there is no need to record the choice.
+    if arg_type[0].is_constant() && arg_type[1].is_constant() {
+        for (i_branch, other) in [ (2, 1), (3, 0) ] {
+            if ! selected[other] {
+                if arg_type[i_branch].is_constant() {
+                    return (0, 0, ADType::ConstantP);
+                }
+                let branch = arg[i_branch];
+                return (branch.tape_id, branch.index, branch.ad_type);
+            }
+        }
+    }
+    //
+    // new_ad_type
+    let new_ad_type = *arg_type.iter().max().unwrap();
+    if new_ad_type.is_constant() {
+        return (0, 0, ADType::ConstantP);
+    }
+    //
+    // agraph
+    let agraph = if new_ad_type.is_variable() {
+        &mut tape.var
+    } else {
+        &mut tape.dyp
+    };
+    //
+    // new_index
+    let new_index = agraph.n_dom + agraph.n_dep;
+    //
+    // agraph: n_dep, id_all, arg_start
+    agraph.n_dep += 1;
+    agraph.id_all.push( CEXP_OP );
+    agraph.arg_start.push( agraph.arg_all.len() as IndexT );
+    //
+    // agraph: arg_all, arg_type_all, bool_all
+    agraph.arg_all.push( cmp_code );
+    agraph.arg_all.push( agraph.bool_all.len() as IndexT );
+    agraph.arg_type_all.push( ADType::Empty );
+    agraph.arg_type_all.push( ADType::Empty );
+    agraph.bool_all.push( selected[0] );
+    agraph.bool_all.push( selected[1] );
+    //
+    // agraph: arg_all, arg_type_all, tape.cop
+    for i in 0 .. 4 {
+        if arg_type[i].is_constant() {
+            agraph.arg_all.push( tape.cop.len() as IndexT );
+            tape.cop.push( arg[i].value.clone() );
+        } else {
+            agraph.arg_all.push( arg[i].index as IndexT );
+        }
+        agraph.arg_type_all.push( arg_type[i] );
+    }
+    (tape.tape_id, new_index, new_ad_type)
+}
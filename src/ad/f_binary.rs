@@ -19,6 +19,7 @@ use crate::{
 use crate::ad::ADType;
 use crate::tape::Tape;
 use crate::tape::sealed::ThisThreadTape;
+use crate::op::binary::num_cmp::is_num_cmp_op;
 use crate::op::id::{
     LT_OP,
     LE_OP,
@@ -29,6 +30,13 @@ use crate::op::id::{
     ATAN2_OP,
     HYPOT_OP,
     POWF_OP,
+    MIN_PP_OP,
+    MAX_PP_OP,
+};
+use crate::ad::binary::{
+    record_aa,
+    record_ac,
+    record_ca,
 };
 //
 // ---------------------------------------------------------------------------
@@ -144,7 +152,13 @@ pub fn doc_f_binary_ad() { }
 // ---------------------------------------------------------------------------
 //
 // impl_f_binary_aa_borrow
-macro_rules! impl_f_binary_aa_borrow{ ($name:ident, $OpId:ident) =>  {
+// The optional record argument is record_aa for operators that have
+// PP, PV, VP, and VV versions; e.g., min and max.
+macro_rules! impl_f_binary_aa_borrow{
+    ($name:ident, $OpId:ident) => {
+        impl_f_binary_aa_borrow!( $name, $OpId, record_f_binary_aa );
+    };
+    ($name:ident, $OpId:ident, $record:ident) =>  {
     //
     #[doc = concat!(
         " `&AD<V>` ", stringify!($name), " `&AD<V>`; see [doc_f_binary_ad]"
@@ -161,7 +175,9 @@ macro_rules! impl_f_binary_aa_borrow{ ($name:ident, $OpId:ident) =>  {
         // new_tape_id, new_index, new_ad_type
         let (new_tape_id, new_index, new_ad_type) =
             local_key.with_borrow_mut( |tape| {
-                record_f_binary_aa::<V> ( tape, self, rhs, $OpId )
+                let result = $record::<V> ( tape, self, rhs, $OpId );
+                record_num_cmp_result::<V>(tape, result.2, $OpId, &new_value);
+                result
             } );
         //
         // result
@@ -184,6 +200,8 @@ where
     impl_f_binary_aa_borrow!( atan2, ATAN2_OP );
     impl_f_binary_aa_borrow!( hypot, HYPOT_OP );
     impl_f_binary_aa_borrow!( powf, POWF_OP );
+    impl_f_binary_aa_borrow!( min, MIN_PP_OP, record_aa );
+    impl_f_binary_aa_borrow!( max, MAX_PP_OP, record_aa );
 }
 //
 /// see [doc_f_binary_ad]
@@ -213,11 +231,19 @@ where
     impl_f_binary_aa_own!( atan2 );
     impl_f_binary_aa_own!( hypot );
     impl_f_binary_aa_own!( powf );
+    impl_f_binary_aa_own!( min );
+    impl_f_binary_aa_own!( max );
 }
 // ---------------------------------------------------------------------------
 //
 // impl_f_binary_ac_borrow
-macro_rules! impl_f_binary_ac_borrow{ ($name:ident, $OpId:ident) => {
+// The optional record argument is record_ac for operators that have
+// PP, PV, VP, and VV versions; e.g., min and max.
+macro_rules! impl_f_binary_ac_borrow{
+    ($name:ident, $OpId:ident) => {
+        impl_f_binary_ac_borrow!( $name, $OpId, record_f_binary_ac );
+    };
+    ($name:ident, $OpId:ident, $record:ident) => {
     //
     #[doc = concat!(
         " `&AD<V>` ", stringify!($name), "( &V )",
@@ -235,7 +261,9 @@ macro_rules! impl_f_binary_ac_borrow{ ($name:ident, $OpId:ident) => {
         // new_tape_id, new_index, new_ad_type
         let (new_tape_id, new_index, new_ad_type) =
             local_key.with_borrow_mut( |tape| {
-                record_f_binary_ac::<V> ( tape, self, rhs, $OpId )
+                let result = $record::<V> ( tape, self, rhs, $OpId );
+                record_num_cmp_result::<V>(tape, result.2, $OpId, &new_value);
+                result
             } );
         //
         // result
@@ -258,6 +286,8 @@ where
     impl_f_binary_ac_borrow!( atan2, ATAN2_OP );
     impl_f_binary_ac_borrow!( hypot, HYPOT_OP );
     impl_f_binary_ac_borrow!( powf, POWF_OP );
+    impl_f_binary_ac_borrow!( min, MIN_PP_OP, record_ac );
+    impl_f_binary_ac_borrow!( max, MAX_PP_OP, record_ac );
 }
 //
 /// see [doc_f_binary_ad]
@@ -287,11 +317,19 @@ where
     impl_f_binary_ac_own!( atan2 );
     impl_f_binary_ac_own!( hypot );
     impl_f_binary_ac_own!( powf );
+    impl_f_binary_ac_own!( min );
+    impl_f_binary_ac_own!( max );
 }
 // ---------------------------------------------------------------------------
 //
 // impl_f_binary_ca_borrow
-macro_rules! impl_f_binary_ca_borrow{ ($name:ident, $OpId:ident) => {
+// The optional record argument is record_ca for operators that have
+// PP, PV, VP, and VV versions; e.g., min and max.
+macro_rules! impl_f_binary_ca_borrow{
+    ($name:ident, $OpId:ident) => {
+        impl_f_binary_ca_borrow!( $name, $OpId, record_f_binary_ca );
+    };
+    ($name:ident, $OpId:ident, $record:ident) => {
     //
     #[doc = concat!(
         "&V" , stringify!($name), " `&AD<V>`; see [doc_f_binary_ad]"
@@ -308,7 +346,9 @@ macro_rules! impl_f_binary_ca_borrow{ ($name:ident, $OpId:ident) => {
         // new_tape_id, new_index, new_ad_type
         let (new_tape_id, new_index, new_ad_type) =
             local_key.with_borrow_mut( |tape| {
-                record_f_binary_ca::<V> ( tape, self, rhs, $OpId )
+                let result = $record::<V> ( tape, self, rhs, $OpId );
+                record_num_cmp_result::<V>(tape, result.2, $OpId, &new_value);
+                result
             } );
         //
         // result
@@ -331,6 +371,8 @@ where
     impl_f_binary_ca_borrow!( atan2, ATAN2_OP );
     impl_f_binary_ca_borrow!( hypot, HYPOT_OP );
     impl_f_binary_ca_borrow!( powf, POWF_OP );
+    impl_f_binary_ca_borrow!( min, MIN_PP_OP, record_ca );
+    impl_f_binary_ca_borrow!( max, MAX_PP_OP, record_ca );
 }
 //
 // impl_f_binary_ca_own
@@ -359,6 +401,41 @@ where
     impl_f_binary_ca_own!( atan2 );
     impl_f_binary_ca_own!( hypot );
     impl_f_binary_ca_own!( powf );
+    impl_f_binary_ca_own!( min );
+    impl_f_binary_ca_own!( max );
+}
+// ---------------------------------------------------------------------------
+// record_num_cmp_result
+/// If op_id is a numerical comparison operator that was just recorded,
+/// add its result during recording as a third argument; see
+/// [num_cmp](crate::op::binary::num_cmp) .
+///
+/// * new_ad_type :
+///   is the type of the result for the operator just recorded
+///   (ConstantP if no operator was recorded).
+///
+/// * new_value :
+///   is the result of the comparison during recording.
+fn record_num_cmp_result<V>(
+    tape        : &mut Tape<V> ,
+    new_ad_type : ADType       ,
+    op_id       : u8           ,
+    new_value   : &V           ,
+)
+where
+    V : Clone ,
+{
+    if ! is_num_cmp_op(op_id) || new_ad_type.is_constant() {
+        return;
+    }
+    let agraph = if new_ad_type.is_variable() {
+        &mut tape.var
+    } else {
+        &mut tape.dyp
+    };
+    agraph.arg_all.push( tape.cop.len() as IndexT );
+    agraph.arg_type_all.push( ADType::ConstantP );
+    tape.cop.push( new_value.clone() );
 }
 // ---------------------------------------------------------------------------
 // record_f_binary_aa
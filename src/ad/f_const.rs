@@ -23,4 +23,5 @@ where
     fn zero()          -> AD<V> { AD::<V>::from( V::zero() ) }
     fn epsilon()       -> AD<V> { AD::<V>::from( V::epsilon() ) }
     fn min_positive()  -> AD<V> { AD::<V>::from( V::min_positive() ) }
+    const ABSOLUTE_ZERO : bool = V::ABSOLUTE_ZERO;
 }
@@ -0,0 +1,122 @@
+// SPDX-License-Identifier: EPL-2.0 OR GPL-2.0-or-later
+// SPDX-FileCopyrightText: Bradley M. Bell <bradbell@seanet.com>
+// SPDX-FileContributor: 2026 Bradley M. Bell
+// ---------------------------------------------------------------------------
+//! This pub(crate) module implements FReduce for AD types
+//!
+//! Link to [parent module](super)
+//!
+// ---------------------------------------------------------------------------
+use std::thread::LocalKey;
+use std::cell::RefCell;
+use std::ops::{
+    Mul,
+    Div,
+};
+use crate::{
+    FConst,
+    FReduce,
+    AD,
+};
+use crate::tape::Tape;
+use crate::tape::sealed::ThisThreadTape;
+use crate::ad::f_unary::record_unary;
+use crate::op::id;
+// ---------------------------------------------------------------------------
+// doc_f_reduce_ad
+/// `AD<V>` reduction functions
+///
+/// * Syntax :
+///   ``ay = ax.Name()``
+///   ``ay = ax.reduce_dot(&az)``
+///
+/// * V : see [doc_generic_v](crate::doc_generic_v)
+///
+/// * Name : is reduce_sum, reduce_mean, reduce_max, or reduce_len;
+///   see [FReduce] .
+///
+/// * ax, az : are `&AD<V>` objects.
+///
+/// * ay : is the `AD<V>` result.
+///   The operators reduce_sum, reduce_max, and reduce_len are recorded.
+///   The reduce_mean function is recorded as reduce_sum divided by
+///   reduce_len and reduce_dot is recorded as reduce_sum of a product.
+///
+/// # Example
+/// ```
+/// use rustad::{
+///     AD,
+///     AzFloat,
+///     NumVec,
+///     FReduce,
+///     start_recording,
+///     stop_recording,
+/// };
+/// type S = AzFloat<f64>;
+/// type V = NumVec<S>;
+/// let opt_vec : Vec<[&str; 2]> = Vec::new();
+/// //
+/// let x       = NumVec::new( vec![ S::from(1), S::from(2), S::from(3) ] );
+/// let (_, ax) = start_recording( None, vec![ x.clone() ] );
+/// let ay      = (&ax[0]).reduce_dot( &ax[0] );
+/// let f       = stop_recording( vec![ ay ] );
+/// //
+/// // y = x[0]^2 + x[1]^2 + x[2]^2
+/// let (y, v)  = f.forward_var_value(None, vec![ x.clone() ], &opt_vec);
+/// assert_eq!( y[0], V::from( S::from(14) ) );
+/// //
+/// // dx = 2 * x
+/// let dy      = vec![ V::from( S::from(1) ) ];
+/// let dx      = f.reverse_der_value(None, &v, dy, &opt_vec);
+/// let check   = NumVec::new( vec![ S::from(2), S::from(4), S::from(6) ] );
+/// assert_eq!( dx[0], check );
+/// ```
+pub fn doc_f_reduce_ad() {}
+// ---------------------------------------------------------------------------
+macro_rules! reduce_self_borrowed{ ($name:ident) => { paste::paste! {
+    #[doc = "see doc_f_reduce_ad" ]
+    fn $name(self) -> AD<V> {
+        //
+        // new_value
+        let new_value = self.value.$name();
+        //
+        // op_id
+        let op_id = id::[< $name:upper _OP >];
+        //
+        // local_key
+        let local_key : &LocalKey<RefCell< Tape<V> >> = ThisThreadTape::get();
+        //
+        // result
+        local_key.with_borrow_mut(
+            |tape| record_unary( tape, self, new_value, op_id)
+        )
+    }
+} } }
+//
+/// Implements the FReduce trait `&AD<V>`
+impl<V> FReduce for &AD<V>
+where
+    V : Clone + FConst + PartialEq + crate::ThisThreadTapePublic ,
+    V : crate::op::reduce::sealed::ReduceValue ,
+    for<'a> &'a V : FReduce<Output=V> +
+        Mul<&'a V, Output=V> + Div<&'a V, Output=V> ,
+{
+    type Output = AD<V>;
+    //
+    // use reduce_self_borrowed
+    reduce_self_borrowed!(reduce_sum);
+    reduce_self_borrowed!(reduce_max);
+    reduce_self_borrowed!(reduce_len);
+    //
+    // reduce_mean
+    /// see doc_f_reduce_ad
+    fn reduce_mean(self) -> AD<V> {
+        &self.reduce_sum() / &self.reduce_len()
+    }
+    //
+    // reduce_dot
+    /// see doc_f_reduce_ad
+    fn reduce_dot(self, rhs : Self) -> AD<V> {
+        (&(self * rhs)).reduce_sum()
+    }
+}
@@ -104,6 +104,14 @@ where
     //
     // use unary_self_borrowed
     unary_self_borrowed!(square);
+    unary_self_borrowed!(erf);
+    unary_self_borrowed!(erfc);
+    unary_self_borrowed!(acos);
+    unary_self_borrowed!(acosh);
+    unary_self_borrowed!(asin);
+    unary_self_borrowed!(asinh);
+    unary_self_borrowed!(atan);
+    unary_self_borrowed!(atanh);
     unary_self_borrowed!(ln_1p);
     unary_self_borrowed!(exp_m1);
     unary_self_borrowed!(ln);
@@ -118,6 +126,14 @@ where
     unary_self_borrowed!(minus);
     unary_self_borrowed!(cos);
     unary_self_borrowed!(sin);
+    unary_self_borrowed!(cbrt);
+    unary_self_borrowed!(exp2);
+    unary_self_borrowed!(log10);
+    unary_self_borrowed!(log2);
+    unary_self_borrowed!(ceil);
+    unary_self_borrowed!(floor);
+    unary_self_borrowed!(round);
+    unary_self_borrowed!(trunc);
     //
     // powi
     /// `AD<V>`.powi(`i32`)
@@ -146,6 +162,14 @@ where
     //
     // use unary_self_owned
     unary_self_owned!(square);
+    unary_self_owned!(erf);
+    unary_self_owned!(erfc);
+    unary_self_owned!(acos);
+    unary_self_owned!(acosh);
+    unary_self_owned!(asin);
+    unary_self_owned!(asinh);
+    unary_self_owned!(atan);
+    unary_self_owned!(atanh);
     unary_self_owned!(ln_1p);
     unary_self_owned!(exp_m1);
     unary_self_owned!(ln);
@@ -160,6 +184,14 @@ where
     unary_self_owned!(minus);
     unary_self_owned!(cos);
     unary_self_owned!(sin);
+    unary_self_owned!(cbrt);
+    unary_self_owned!(exp2);
+    unary_self_owned!(log10);
+    unary_self_owned!(log2);
+    unary_self_owned!(ceil);
+    unary_self_owned!(floor);
+    unary_self_owned!(round);
+    unary_self_owned!(trunc);
     //
     // powi
     /// `AD<V>`.powi(`i32`)
@@ -180,7 +212,7 @@ where
 }
 //
 // record_unary
-fn record_unary<V>(
+pub(crate) fn record_unary<V>(
     tape      : &mut Tape<V> ,
     arg       : &AD<V>       ,
     new_value : V            ,
@@ -12,9 +12,13 @@
 // sub-modules
 //
 pub mod binary;
+pub mod cexp;
 pub mod f_const;
 pub mod f_unary;
 pub mod f_binary;
+pub mod f_reduce;
+pub mod vec_ad;
+pub mod print;
 pub mod zero_one;
 // ---------------------------------------------------------------------------
 //
@@ -71,7 +75,7 @@ impl ADType {
     pub fn is_empty(&self) -> bool
     {   *self == ADType::Empty }
 }
-///
+//
 #[test]
 fn test_ad_type() {
     assert!( ADType::ConstantP < ADType::DynamicP );
@@ -0,0 +1,217 @@
+// SPDX-License-Identifier: EPL-2.0 OR GPL-2.0-or-later
+// SPDX-FileCopyrightText: Bradley M. Bell <bradbell@seanet.com>
+// SPDX-FileContributor: 2026 Bradley M. Bell
+// ---------------------------------------------------------------------------
+//! This pub module defines the print_on_forward `AD<V>` member functions.
+//!
+//! Link to [parent module](super)
+//!
+// ---------------------------------------------------------------------------
+// use
+use std::thread::LocalKey;
+use std::cell::RefCell;
+//
+use crate::{
+    AD,
+    IndexT,
+};
+use crate::ad::ADType;
+use crate::tape::Tape;
+use crate::op::id;
+use crate::tape::sealed::ThisThreadTape;
+// -------------------------------------------------------------------------
+// doc_print_on_forward
+/// The print_on_forward and print_on_forward_if `AD<V>` member functions
+///
+/// * Syntax :
+///   ```text
+///     aval.print_on_forward(label)
+///     aval.print_on_forward_if(label, &acond)
+///   ```
+///
+/// * Prototype :
+///   see [AD::print_on_forward], [AD::print_on_forward_if] .
+///
+/// * aval :
+///   is the value that is printed.
+///
+/// * label :
+///   is the text printed before the value. The line that is printed is
+///   ```text
+///     format!( "{label}{value}" )
+///   ```
+///   where value is the value corresponding to aval.
+///
+/// * acond :
+///   If acond is present, the line is only printed when acond is positive.
+///   For numeric vectors, it is printed if any element of acond is positive.
+///
+/// * Recording :
+///   If aval and acond do not depend on the domain parameters or variables,
+///   nothing is recorded.
+///   Otherwise one print operator is recorded and the line is printed
+///   each time [forward_dyp_value](crate::ADfn::forward_dyp_value)
+///   (if the operator only depends on dynamic parameters)
+///   or [forward_var_value](crate::ADfn::forward_var_value) is called.
+///   The print operators are not printed during recording, during
+///   the other forward and reverse mode calculations,
+///   or when evaluating with AD types.
+///   Optimization keeps the print operators.
+///
+/// * Sink :
+///   The lines are printed on standard output unless a different sink
+///   is set using [set_this_thread_print_sink](crate::set_this_thread_print_sink).
+///   The [rust_src](crate::ADfn::rust_src) version of a print operator
+///   always prints on standard output.
+///
+/// # Example
+/// ```
+/// use rustad::{
+///     AD,
+///     start_recording,
+///     stop_recording,
+///     set_this_thread_print_sink,
+/// };
+/// use std::rc::Rc;
+/// use std::cell::RefCell;
+/// //
+/// // V
+/// type V = rustad::AzFloat<f64>;
+/// //
+/// // f
+/// // f(x) = x[0] * x[0] and print x[0] * x[0] when x[0] is positive
+/// let x       = vec![ V::from(1.0) ];
+/// let (_, ax) = start_recording(None, x);
+/// let asq     = &ax[0] * &ax[0];
+/// asq.print_on_forward_if("asq = ", &ax[0]);
+/// let f       = stop_recording( vec![asq] );
+/// //
+/// // lines
+/// let lines : Rc< RefCell< Vec<String> > > = Rc::new( RefCell::new( Vec::new() ) );
+/// let lines_clone = lines.clone();
+/// set_this_thread_print_sink( Some( Box::new(
+///     move |line : &str| lines_clone.borrow_mut().push( line.to_string() )
+/// ) ) );
+/// //
+/// // forward_var_value
+/// let opt_vec : Vec<[&str; 2]> = Vec::new();
+/// f.forward_var_value(None, vec![ V::from(3.0) ], &opt_vec);
+/// f.forward_var_value(None, vec![ V::from(-2.0) ], &opt_vec);
+/// set_this_thread_print_sink(None);
+/// //
+/// // check
+/// assert_eq!( *lines.borrow(), vec![ "asq = 9".to_string() ] );
+/// ```
+#[cfg(doc)]
+pub fn doc_print_on_forward() { }
+//
+impl<V> AD<V>
+where
+    V : Clone + ThisThreadTape,
+{
+    //
+    /// see [doc_print_on_forward]
+    pub fn print_on_forward(&self, label : &str)
+    {   //
+        // local_key
+        let local_key : &LocalKey<RefCell< Tape<V> >> = ThisThreadTape::get();
+        //
+        // tape
+        local_key.with_borrow_mut( |tape|
+            record_print(tape, label, self, None)
+        );
+    }
+    //
+    /// see [doc_print_on_forward]
+    pub fn print_on_forward_if(&self, label : &str, cond : &AD<V>)
+    {   //
+        // local_key
+        let local_key : &LocalKey<RefCell< Tape<V> >> = ThisThreadTape::get();
+        //
+        // tape
+        local_key.with_borrow_mut( |tape|
+            record_print(tape, label, self, Some(cond) )
+        );
+    }
+}
+//
+// arg_ad_type
+// The type of value or cond for the print operator.
+fn arg_ad_type<V>(tape : &Tape<V>, arg : &AD<V>) -> ADType {
+    if arg.tape_id == tape.tape_id {
+        debug_assert!( arg.ad_type != ADType::ConstantP );
+        arg.ad_type
+    } else {
+        ADType::ConstantP
+    }
+}
+//
+// record_print
+fn record_print<V>(
+    tape       : &mut Tape<V>     ,
+    label      : &str             ,
+    value      : &AD<V>           ,
+    cond       : Option<&AD<V>>   ,
+)
+where
+    V : Clone ,
+{   //
+    if ! tape.recording {
+        return;
+    }
+    //
+    // value_type, cond_type, ad_type
+    let value_type = arg_ad_type(tape, value);
+    let cond_type  = match cond {
+        None       => ADType::Empty,
+        Some(cond) => arg_ad_type(tape, cond),
+    };
+    let ad_type = if cond_type == ADType::Empty {
+        value_type
+    } else {
+        std::cmp::max(value_type, cond_type)
+    };
+    if ! ( ad_type.is_dynamic() || ad_type.is_variable() ) {
+        return;
+    }
+    //
+    // value_index, cond_index
+    let mut arg_index = |arg : &AD<V>, arg_type : ADType| -> IndexT {
+        if arg_type == ADType::ConstantP {
+            tape.cop.push( arg.value.clone() );
+            (tape.cop.len() - 1) as IndexT
+        } else {
+            arg.index as IndexT
+        }
+    };
+    let value_index = arg_index(value, value_type);
+    let cond_index  = match cond {
+        None       => 0,
+        Some(cond) => arg_index(cond, cond_type),
+    };
+    //
+    // agraph
+    let agraph = if ad_type.is_variable() {
+        &mut tape.var
+    } else {
+        &mut tape.dyp
+    };
+    //
+    // agraph: id_all, n_dep, arg_start
+    agraph.id_all.push( id::PRINT_OP );
+    agraph.n_dep += 1; // This value is never used
+    agraph.arg_start.push( agraph.arg_all.len() as IndexT );
+    //
+    // agraph: arg_all, str_all
+    agraph.arg_all.push( agraph.str_all.len() as IndexT );
+    agraph.str_all += label;
+    agraph.arg_all.push( agraph.str_all.len() as IndexT );
+    //
+    // agraph: arg_all, arg_type_all
+    agraph.arg_all.push( value_index );
+    agraph.arg_all.push( cond_index );
+    agraph.arg_type_all.push( ADType::Empty );
+    agraph.arg_type_all.push( ADType::Empty );
+    agraph.arg_type_all.push( value_type );
+    agraph.arg_type_all.push( cond_type );
+}
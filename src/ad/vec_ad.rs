@@ -0,0 +1,387 @@
+// SPDX-License-Identifier: EPL-2.0 OR GPL-2.0-or-later
+// SPDX-FileCopyrightText: Bradley M. Bell <bradbell@seanet.com>
+// SPDX-FileContributor: 2026 Bradley M. Bell
+// ---------------------------------------------------------------------------
+//! This pub module defines the [VecAD] type
+//! (vectors that can be indexed by AD objects).
+//!
+//! Link to [parent module](super)
+//!
+// ---------------------------------------------------------------------------
+// use
+use std::thread::LocalKey;
+use std::cell::RefCell;
+use crate::{
+    AD,
+    IndexT,
+};
+use crate::ad::ADType;
+use crate::tape::Tape;
+use crate::op::id::{
+    LOAD_OP,
+    STORE_OP,
+};
+use crate::tape::sealed::ThisThreadTape;
+// ---------------------------------------------------------------------------
+pub(crate) mod sealed {
+    //! The sub-module sealed is used to seal traits in this package
+    //
+    use std::ops::{
+        Add,
+        Sub,
+        Mul,
+        Div,
+    };
+    use crate::{
+        FConst,
+        FValue,
+        FBinary,
+    };
+    use crate::op::cexp::select;
+    use crate::op::vec_ad::load;
+    //
+    // VecADValue
+    /// Values corresponding to the load and store operators.
+    pub trait VecADValue
+    where
+        Self : Sized ,
+    {
+        /// value of element index in element
+        fn load_value(index : &Self, element : &[Self]) -> Self;
+        /// value of element j after storing value at index
+        fn store_value(index : &Self, j : &Self, value : &Self, old : &Self)
+            -> Self;
+        /// is index equal to j (for all elements)
+        fn index_equal(index : &Self, j : &Self) -> bool;
+    }
+    impl<V> VecADValue for V
+    where
+        V             : Clone + FConst + FValue ,
+        for<'a> &'a V : FBinary<&'a V, Output = V> ,
+        for<'a> &'a V : Add<&'a V, Output = V> ,
+        for<'a> &'a V : Sub<&'a V, Output = V> ,
+        for<'a> &'a V : Mul<&'a V, Output = V> ,
+        for<'a> &'a V : Div<&'a V, Output = V> ,
+    {
+        fn load_value(index : &V, element : &[V]) -> V {
+            load::<V>(index, element)
+        }
+        fn store_value(index : &V, j : &V, value : &V, old : &V) -> V {
+            let c = index.num_eq(j);
+            select::<V>(&c, value, old)
+        }
+        fn index_equal(index : &V, j : &V) -> bool {
+            index.num_eq(j).is_one()
+        }
+    }
+}
+// ---------------------------------------------------------------------------
+// VecAD
+/// A vector of AD objects that can be indexed by an AD object.
+///
+/// * V : see [doc_generic_v](crate::doc_generic_v)
+///
+/// * Recording :
+///   Indexing a `Vec< AD<V> >` by the value of an AD object
+///   records the element that was selected during the recording.
+///   The load and store operations for this type record the index so that
+///   the function acts like the index changes
+///   when it is evaluated at different domain points.
+///
+/// * Sparsity :
+///   A load depends on all of the elements in the vector
+///   (and on the index);
+///   i.e., the vector is treated as a single dependency set.
+///   The index does not contribute to the derivative of a load and
+///   is not included in Hessian sparsity patterns.
+///
+/// # Example
+/// ```
+/// use rustad::{
+///     AD,
+///     VecAD,
+///     start_recording,
+///     stop_recording,
+/// };
+/// //
+/// // V
+/// type V = rustad::AzFloat<f64>;
+/// //
+/// // f
+/// // f(x) = table[ x[0] ] * x[1] where table = [ 4, 5, 6 ]
+/// let table     = vec![ V::from(4.0), V::from(5.0), V::from(6.0) ];
+/// let atable    = table.into_iter().map( AD::from ).collect();
+/// let avec      = VecAD::new(atable);
+/// let x         = vec![ V::from(0.0), V::from(2.0) ];
+/// let (_, ax)   = start_recording(None, x);
+/// let aload     = avec.load( &ax[0] );
+/// let ay        = &aload * &ax[1];
+/// let f         = stop_recording( vec![ay] );
+/// //
+/// // check
+/// let opt_vec : Vec<[&str; 2]> = Vec::new();
+/// let x         = vec![ V::from(2.0), V::from(3.0) ];
+/// let (y, _)    = f.forward_var_value(None, x, &opt_vec);
+/// assert_eq!( y[0], V::from(18.0) );
+/// ```
+#[derive(Clone, Debug)]
+pub struct VecAD<V> {
+    //
+    // element
+    /// the current value of each element of the vector.
+    element : Vec< AD<V> >,
+}
+//
+impl<V> VecAD<V>
+where
+    V : Clone + From<f32> + ThisThreadTape + sealed::VecADValue ,
+{
+    //
+    // new
+    /// Create a VecAD object
+    ///
+    /// * element :
+    ///   is the initial value for the elements of the vector.
+    ///   They can be constants, dynamic parameters or variables.
+    pub fn new(element : Vec< AD<V> >) -> Self {
+        Self{ element }
+    }
+    //
+    // len
+    /// Number of elements in the vector.
+    pub fn len(&self) -> usize {
+        self.element.len()
+    }
+    //
+    // is_empty
+    /// Is the number of elements in the vector zero.
+    pub fn is_empty(&self) -> bool {
+        self.element.is_empty()
+    }
+    //
+    // match_index
+    // The element j that index is equal to (for all elements of the value).
+    fn match_index(&self, index : &AD<V>) -> Option<usize> {
+        ( 0 .. self.element.len() ).find( |j|
+            V::index_equal( &index.value, &V::from(*j as f32) )
+        )
+    }
+    //
+    // load
+    /// Load an element of the vector.
+    ///
+    /// * Syntax :
+    ///   ```text
+    ///     aresult = avec.load(&index)
+    ///   ```
+    ///
+    /// * index :
+    ///   is the index of the element; i.e., its value is 0, ..., n-1
+    ///   where n is the length of the vector.
+    ///   For numeric vectors, the indexing is element wise.
+    ///
+    /// * aresult :
+    ///   is the element of the vector corresponding to index.
+    ///   It is nan if index is not one of 0, ..., n-1 .
+    ///
+    /// * Recording :
+    ///   If the index is a constant, and is equal to j,
+    ///   the j-th element is returned and nothing is recorded.
+    ///   Otherwise, if the index or an element is a dynamic parameter or
+    ///   variable, one LOAD_OP operator is recorded.
+    pub fn load(&self, index : &AD<V>) -> AD<V> {
+        //
+        // element_value, new_value, match_j
+        let element_value : Vec<V> =
+            self.element.iter().map( |e| e.value.clone() ).collect();
+        let new_value = V::load_value(&index.value, &element_value);
+        let match_j   = self.match_index(index);
+        //
+        // local_key
+        let local_key : &LocalKey< RefCell< Tape<V> > > =
+            ThisThreadTape::get();
+        //
+        // new_tape_id, new_index, new_ad_type
+        let (new_tape_id, new_index, new_ad_type) =
+            local_key.with_borrow_mut( |tape|
+                record_load(tape, index, &self.element, match_j)
+            );
+        AD::new(new_tape_id, new_index, new_ad_type, new_value)
+    }
+    //
+    // store
+    /// Store a value in an element of the vector.
+    ///
+    /// * Syntax :
+    ///   ```text
+    ///     avec.store(&index, &value)
+    ///   ```
+    ///
+    /// * index :
+    ///   is the index of the element; i.e., its value is 0, ..., n-1
+    ///   where n is the length of the vector.
+    ///   For numeric vectors, the indexing is element wise.
+    ///   If index is not one of 0, ..., n-1, the vector does not change.
+    ///
+    /// * value :
+    ///   is the new value for the element corresponding to index.
+    ///
+    /// * Recording :
+    ///   If the index is a constant, and is equal to j,
+    ///   value is stored in the j-th element and nothing is recorded.
+    ///   Otherwise, one STORE_OP operator is recorded for each
+    ///   element that depends on a dynamic parameter or variable.
+    pub fn store(&mut self, index : &AD<V>, value : &AD<V>) {
+        //
+        // match_j
+        let match_j = self.match_index(index);
+        //
+        // local_key
+        let local_key : &LocalKey< RefCell< Tape<V> > > =
+            ThisThreadTape::get();
+        //
+        // constant_index
+        let constant_index = local_key.with_borrow( |tape|
+            ! tape.recording || index.tape_id != tape.tape_id
+        );
+        if constant_index && let Some(j) = match_j {
+            self.element[j] = value.clone();
+            return;
+        }
+        //
+        // self.element
+        for j in 0 .. self.element.len() {
+            let j_value   = V::from(j as f32);
+            let old       = &self.element[j];
+            let new_value = V::store_value(
+                &index.value, &j_value, &value.value, &old.value
+            );
+            let (new_tape_id, new_index, new_ad_type) =
+                local_key.with_borrow_mut( |tape|
+                    record_store(tape, index, j_value, value, old)
+                );
+            self.element[j] =
+                AD::new(new_tape_id, new_index, new_ad_type, new_value);
+        }
+    }
+}
+// ---------------------------------------------------------------------------
+// arg_ad_type
+// The type of an argument for the operators in this module.
+fn arg_ad_type<V>(tape : &Tape<V>, arg : &AD<V>) -> ADType {
+    if arg.tape_id == tape.tape_id {
+        debug_assert!( arg.ad_type != ADType::ConstantP );
+        arg.ad_type
+    } else {
+        ADType::ConstantP
+    }
+}
+//
+// push_arg
+// Push one argument onto the end of the operator arguments.
+fn push_arg<V>(tape : &mut Tape<V>, is_var : bool, arg : &AD<V>, ad_type : ADType)
+where
+    V : Clone,
+{   let index = if ad_type.is_constant() {
+        tape.cop.push( arg.value.clone() );
+        tape.cop.len() - 1
+    } else {
+        arg.index
+    };
+    let agraph = if is_var { &mut tape.var } else { &mut tape.dyp };
+    agraph.arg_all.push( index as IndexT );
+    agraph.arg_type_all.push( ad_type );
+}
+//
+// push_op
+// Push an operator, without its arguments, onto the tape and
+// return the index of its result.
+fn push_op<V>(tape : &mut Tape<V>, is_var : bool, op_id : u8) -> usize {
+    let agraph = if is_var { &mut tape.var } else { &mut tape.dyp };
+    let new_index = agraph.n_dom + agraph.n_dep;
+    agraph.n_dep += 1;
+    agraph.id_all.push( op_id );
+    agraph.arg_start.push( agraph.arg_all.len() as IndexT );
+    new_index
+}
+// ---------------------------------------------------------------------------
+// record_load
+fn record_load<V>(
+    tape     : &mut Tape<V>    ,
+    index    : &AD<V>          ,
+    element  : &[ AD<V> ]      ,
+    match_j  : Option<usize>   ,
+) -> (usize, usize, ADType)
+where
+    V : Clone,
+{   //
+    if ! tape.recording {
+        return (0, 0, ADType::ConstantP);
+    }
+    //
+    // constant index that is equal to j
+    let index_type = arg_ad_type(tape, index);
+    if index_type.is_constant() && let Some(j) = match_j {
+        let e = &element[j];
+        if arg_ad_type(tape, e).is_constant() {
+            return (0, 0, ADType::ConstantP);
+        }
+        return (e.tape_id, e.index, e.ad_type);
+    }
+    //
+    // new_ad_type
+    let mut new_ad_type = index_type;
+    for e in element {
+        new_ad_type = std::cmp::max( new_ad_type, arg_ad_type(tape, e) );
+    }
+    if new_ad_type.is_constant() {
+        return (0, 0, ADType::ConstantP);
+    }
+    //
+    // tape
+    let is_var    = new_ad_type.is_variable();
+    let new_index = push_op(tape, is_var, LOAD_OP);
+    push_arg(tape, is_var, index, index_type);
+    for e in element {
+        let ad_type = arg_ad_type(tape, e);
+        push_arg(tape, is_var, e, ad_type);
+    }
+    (tape.tape_id, new_index, new_ad_type)
+}
+//
+// record_store
+fn record_store<V>(
+    tape     : &mut Tape<V>    ,
+    index    : &AD<V>          ,
+    j_value  : V               ,
+    value    : &AD<V>          ,
+    old      : &AD<V>          ,
+) -> (usize, usize, ADType)
+where
+    V : Clone,
+{   //
+    if ! tape.recording {
+        return (0, 0, ADType::ConstantP);
+    }
+    //
+    // new_ad_type
+    let arg_type    = [
+        arg_ad_type(tape, index),
+        arg_ad_type(tape, value),
+        arg_ad_type(tape, old),
+    ];
+    let new_ad_type = *arg_type.iter().max().unwrap();
+    if new_ad_type.is_constant() {
+        return (0, 0, ADType::ConstantP);
+    }
+    //
+    // tape
+    let is_var    = new_ad_type.is_variable();
+    let new_index = push_op(tape, is_var, STORE_OP);
+    let aj        = AD::new(0, 0, ADType::ConstantP, j_value);
+    push_arg(tape, is_var, index, arg_type[0]);
+    push_arg(tape, is_var, &aj, ADType::ConstantP);
+    push_arg(tape, is_var, value, arg_type[1]);
+    push_arg(tape, is_var, old, arg_type[2]);
+    (tape.tape_id, new_index, new_ad_type)
+}
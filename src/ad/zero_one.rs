@@ -15,6 +15,7 @@ use crate::{
     AD,
     IndexT,
     FValue,
+    FBinary,
 };
 use crate::ad::ADType;
 use crate::tape::Tape;
@@ -71,6 +72,13 @@ fn panic_fn(check_one : bool, message : &str) {
 ///   [forward_dyp_value](crate::ADfn::forward_dyp_value) ,
 ///   [forward_var_value](crate::ADfn::forward_var_value).
 ///
+/// * Absolute Zero :
+///   The check uses the value of aval and does not depend on
+///   [ABSOLUTE_ZERO](crate::FConst::ABSOLUTE_ZERO) .
+///   If V does not have an absolute zero, a product with the constant zero
+///   is not a constant, so checking it is recorded and reports a change
+///   when, for example, the other factor is nan.
+///
 /// * opt_vec :
 ///   controls what should happen when bval would have changed.
 ///   The [opt_vec](crate::doc_opt_vec) argument
@@ -109,6 +117,20 @@ fn panic_fn(check_one : bool, message : &str) {
 ///
 /// * example : see examples/zero_one.rs.
 ///
+/// * to_value_checked :
+///   The syntax
+///   ```text
+///     value = aval.to_value_checked(opt_vec)
+///   ```
+///   sets value to a copy of aval.to_value() and records
+///   ```text
+///     aval.num_eq(&value).is_one(opt_vec)
+///   ```
+///   This detects when value would have been different; e.g.,
+///   when aval is the result of floor, ceil, round, or trunc and value
+///   is used to choose what operations are recorded.
+///   In this case fn_name is is_one.
+///
 #[cfg(doc)]
 pub fn doc_zero_one() { }
 //
@@ -129,6 +151,16 @@ where
         self.zero_one(check_one, opt_vec)
     }
     //
+    /// see [doc_zero_one]
+    pub fn to_value_checked(&self, opt_vec : &Vec< [&str; 2] > ) -> V
+    where
+        V                 : Clone ,
+        for<'a> &'a AD<V> : FBinary<&'a V, Output = AD<V> > ,
+    {   let value = self.value.clone();
+        self.num_eq(&value).is_one(opt_vec);
+        value
+    }
+    //
     fn zero_one(&self, check_one : bool, opt_vec : &Vec< [&str; 2] > ) -> bool
     {   //
         let mut ignore   = false;
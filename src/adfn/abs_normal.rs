@@ -0,0 +1,386 @@
+// SPDX-License-Identifier: EPL-2.0 OR GPL-2.0-or-later
+// SPDX-FileCopyrightText: Bradley M. Bell <bradbell@seanet.com>
+// SPDX-FileContributor: 2026 Bradley M. Bell
+// ---------------------------------------------------------------------------
+//
+//! Implement the [ADfn] abs_normal method (abs-normal form).
+//!
+//! Link to [parent module](super)
+// ---------------------------------------------------------------------------
+// use
+//
+use std::ops::{
+    Add,
+    Sub,
+    Mul,
+};
+//
+use crate::ad::ADType;
+use crate::{
+    AD,
+    ADfn,
+    FConst,
+    FUnary,
+    start_recording,
+    stop_recording,
+};
+use crate::op::id;
+use crate::op::info::sealed::GlobalOpFnsVec;
+use crate::tape::sealed::ThisThreadTape;
+use crate::op::info::ConstData;
+//
+#[cfg(doc)]
+use crate::doc_generic_v;
+// ---------------------------------------------------------------------------
+// Switch
+// The kinds of variable operators that become switching variables.
+#[derive(Clone, Copy, PartialEq)]
+enum Switch {
+    Abs,
+    Min,
+    Max,
+}
+//
+// switch_kind
+fn switch_kind(op_id : u8) -> Option<Switch> {
+    match op_id {
+        id::ABS_OP    => Some( Switch::Abs ),
+        //
+        id::MIN_PV_OP => Some( Switch::Min ),
+        id::MIN_VP_OP => Some( Switch::Min ),
+        id::MIN_VV_OP => Some( Switch::Min ),
+        //
+        id::MAX_PV_OP => Some( Switch::Max ),
+        id::MAX_VP_OP => Some( Switch::Max ),
+        id::MAX_VV_OP => Some( Switch::Max ),
+        //
+        _             => None,
+    }
+}
+//
+// arg_value
+// The AD value of one operator argument.
+fn arg_value<V>(
+    cop      : &[V]       ,
+    dyp_all  : &[AD<V>]   ,
+    var_all  : &[AD<V>]   ,
+    index    : usize      ,
+    ad_type  : ADType     ,
+) -> AD<V>
+where
+    V : Clone ,
+{   match ad_type {
+        ADType::ConstantP => AD::from( cop[index].clone() ),
+        ADType::DynamicP  => dyp_all[index].clone(),
+        ADType::Variable  => var_all[index].clone(),
+        ADType::Empty     => panic!( "abs_normal: empty argument type" ),
+    }
+}
+// ---------------------------------------------------------------------------
+// abs_normal
+/// Abs-normal form of a function.
+///
+/// * Syntax :
+///   ```text
+///     (g, a, n_switch) = f.abs_normal(opt_vec)
+///   ```
+///
+/// * V : see [doc_generic_v]
+/// * f : is an [ADfn] object.
+///
+/// * Switching Variables :
+///   Each abs operator in the variable acyclic graph of f is a kink in f.
+///   So is each min and max operator that has a variable argument;
+///   i.e., min(x, y) = ( x + y - |x - y| ) / 2 and
+///   max(x, y) = ( x + y + |x - y| ) / 2 .
+///   The argument of the i-th kink, in the order they were recorded,
+///   is the switching variable z_i. For min and max, z_i = x - y .
+///   The corresponding abs result is u_i = |z_i| .
+///
+/// * n_switch :
+///   is the number of switching variables s.
+///
+/// * g :
+///   is a smooth function with the same domain dynamic parameters as f.
+///   It has n + s domain variables and m + s range components,
+///   where n (m) is the domain (range) dimension for f.
+///   The domain variables for g are ( x, u ) and its range is ( y, z ) :
+///   y is the range for f with the i-th abs result replaced by u_i ,
+///   and z is the vector of switching variables as a function of ( x, u ) .
+///
+/// * a :
+///   is a function with the same domain dynamic parameters and
+///   domain variables as f. It has s range components.
+///   The value of its i-th range component is the abs result u_i .
+///   Hence, for all x ,
+///   ```text
+///     f(x) = y( x, a(x) ) and a(x) = | z( x, a(x) ) |
+///   ```
+///   Note that z_i( x, u ) only depends on u_j for j < i .
+///
+/// * opt_vec :
+///   is an [opt_vec](crate::doc_opt_vec) with the following possible keys:
+///
+///   * trace
+///     The corresponding value must be true of false (default is false).
+///     If it is true, the operator index and kind for each
+///     switching variable is printed on stdout.
+///
+/// * Recording :
+///   There must not be a recording in process on the current thread
+///   when abs_normal is called.
+///   The functions g and a are recorded using zero for the values of
+///   the domain dynamic parameters and variables.
+///
+/// # Example
+/// ```
+/// use rustad::{
+///     AzFloat,
+///     FUnary,
+///     start_recording,
+///     stop_recording,
+/// };
+/// //
+/// // V
+/// type V = AzFloat<f64>;
+/// //
+/// // f
+/// // f(x) = | x[0] - x[1] | + x[1]
+/// let x        = vec![ V::from(1.0), V::from(2.0) ];
+/// let (_, ax)  = start_recording(None, x);
+/// let adiff    = &ax[0] - &ax[1];
+/// let ay       = &adiff.abs() + &ax[1];
+/// let f        = stop_recording( vec![ay] );
+/// //
+/// // g, a, n_switch
+/// let opt_vec : Vec<[&str; 2]> = Vec::new();
+/// let (g, a, n_switch) = f.abs_normal(&opt_vec);
+/// assert_eq!( n_switch, 1 );
+/// //
+/// // u = a(x)
+/// let x       = vec![ V::from(4.0), V::from(1.0) ];
+/// let (u, _)  = a.forward_var_value(None, x.clone(), &opt_vec);
+/// assert_eq!( u[0], V::from(3.0) );
+/// //
+/// // (y, z) = g(x, u)
+/// let xu      = vec![ x[0], x[1], u[0] ];
+/// let (yz, _) = g.forward_var_value(None, xu, &opt_vec);
+/// assert_eq!( yz[0], V::from(4.0) );
+/// assert_eq!( yz[1], V::from(3.0) );
+/// ```
+pub fn doc_abs_normal() { }
+//
+impl<V> ADfn<V>
+where
+    V                 : Clone + std::fmt::Display + From<f32> ,
+    V                 : GlobalOpFnsVec + FConst + ThisThreadTape ,
+    for<'a> &'a AD<V> : Add<&'a AD<V>, Output = AD<V> > ,
+    for<'a> &'a AD<V> : Sub<&'a AD<V>, Output = AD<V> > ,
+    for<'a> &'a AD<V> : Mul<&'a AD<V>, Output = AD<V> > ,
+    for<'a> &'a AD<V> : FUnary<Output = AD<V> > ,
+{
+    /// see [doc_abs_normal]
+    pub fn abs_normal(
+        &self,
+        opt_vec : &Vec<[&str; 2]> ,
+    ) -> ( ADfn<V>, ADfn<V>, usize ) {
+        //
+        // trace
+        let mut trace = false;
+        for opt in opt_vec {
+            match opt[0] {
+                "trace" => {
+                    match opt[1] {
+                        "true"  => { trace = true; },
+                        "false" => { trace = false; },
+                        _ => { panic!(
+                            "abs_normal opt_vec: invalid value for trace"
+                        ); }
+                    }
+                },
+                _ => panic!("abs_normal opt_vec: invalid key"),
+            }
+        }
+        //
+        // switch_op
+        // operator index and kind for each switching variable
+        let mut switch_op : Vec<(usize, Switch)> = Vec::new();
+        for (op_index, op_id) in self.var.id_all.iter().enumerate() {
+            if let Some(kind) = switch_kind(*op_id) {
+                switch_op.push( (op_index, kind) );
+            }
+        }
+        let n_switch = switch_op.len();
+        if trace {
+            println!( "Begin Trace: abs_normal: n_switch = {}", n_switch );
+            println!( "switch_index, op_index, kind" );
+            for (i_switch, (op_index, kind)) in switch_op.iter().enumerate() {
+                let name = match kind {
+                    Switch::Abs => "abs",
+                    Switch::Min => "min",
+                    Switch::Max => "max",
+                };
+                println!( "{}, {}, {}", i_switch, op_index, name );
+            }
+            println!( "End Trace: abs_normal" );
+        }
+        //
+        // zero_v, half, n_dyp_dom, n_var_dom, no_opt
+        let zero_v    : V = FConst::zero();
+        let half          = AD::from( V::from(0.5f32) );
+        let n_dyp_dom     = self.dyp.n_dom;
+        let n_var_dom     = self.var.n_dom;
+        let no_opt : Vec<[&str; 2]> = Vec::new();
+        //
+        // dyp_dom
+        let dyp_dom = if n_dyp_dom == 0 {
+            None
+        } else {
+            Some( vec![ zero_v.clone(); n_dyp_dom ] )
+        };
+        // -------------------------------------------------------------------
+        // g
+        // -------------------------------------------------------------------
+        let xu             = vec![ zero_v.clone(); n_var_dom + n_switch ];
+        let (ap, axu)      = start_recording( dyp_dom.clone(), xu );
+        let dyp_all        = if n_dyp_dom == 0 {
+            Vec::new()
+        } else {
+            self.forward_dyp_ad(ap, &no_opt)
+        };
+        let g_range = self.abs_normal_g_range(&dyp_all, axu, &switch_op, &half);
+        let g       = stop_recording(g_range);
+        // -------------------------------------------------------------------
+        // a
+        // -------------------------------------------------------------------
+        let x              = vec![ zero_v; n_var_dom ];
+        let (ap, ax)       = start_recording( dyp_dom, x );
+        let dyp_all        = if n_dyp_dom == 0 {
+            Vec::new()
+        } else {
+            self.forward_dyp_ad(ap, &no_opt)
+        };
+        let (_, var_all)   = self.forward_var_ad( Some(&dyp_all), ax, &no_opt );
+        let mut a_range    = Vec::with_capacity(n_switch);
+        for (op_index, kind) in switch_op.iter() {
+            let res = n_var_dom + op_index;
+            if *kind == Switch::Abs {
+                a_range.push( var_all[res].clone() );
+            } else {
+                let (lhs, rhs) = self.abs_normal_operands(
+                    &dyp_all, &var_all, *op_index
+                );
+                a_range.push( (&lhs - &rhs).abs() );
+            }
+        }
+        let a = stop_recording(a_range);
+        //
+        ( g, a, n_switch )
+    }
+    //
+    // abs_normal_operands
+    // The left and right operands for an operator with two arguments.
+    fn abs_normal_operands(
+        &self,
+        dyp_all  : &[AD<V>] ,
+        var_all  : &[AD<V>] ,
+        op_index : usize    ,
+    ) -> ( AD<V>, AD<V> ) {
+        let start    = self.var.arg_start[op_index] as usize;
+        let arg      = &self.var.arg_all[start .. start + 2];
+        let arg_type = &self.var.arg_type_all[start .. start + 2];
+        let lhs      = arg_value(
+            &self.cop, dyp_all, var_all, arg[0] as usize, arg_type[0]
+        );
+        let rhs      = arg_value(
+            &self.cop, dyp_all, var_all, arg[1] as usize, arg_type[1]
+        );
+        ( lhs, rhs )
+    }
+    //
+    // abs_normal_g_range
+    // Evaluate the variable operators in f replacing each abs result by the
+    // corresponding u and return the range ( y, z ) for g .
+    fn abs_normal_g_range(
+        &self,
+        dyp_all   : &[AD<V>]          ,
+        axu       : Vec< AD<V> >      ,
+        switch_op : &[(usize, Switch)] ,
+        half      : &AD<V>            ,
+    ) -> Vec< AD<V> > {
+        //
+        // n_var_dom, n_switch, op_fns_vec
+        let n_var_dom  = self.var.n_dom;
+        let n_switch   = switch_op.len();
+        let op_fns_vec = GlobalOpFnsVec::get();
+        //
+        // au, var_all
+        let au          = axu[n_var_dom ..].to_vec();
+        let mut var_all = axu;
+        var_all.truncate(n_var_dom);
+        var_all.resize( n_var_dom + self.var.n_dep, AD::from( V::nan() ) );
+        //
+        // cop, bool_all, str_all
+        let cop      = &self.cop;
+        let bool_all = &self.var.bool_all;
+        let str_all  = &self.var.str_all;
+        //
+        // var_all, az
+        let mut az       = Vec::with_capacity(n_switch);
+        let mut i_switch = 0;
+        for op_index in 0 .. self.var.id_all.len() {
+            let res = n_var_dom + op_index;
+            if i_switch < n_switch && switch_op[i_switch].0 == op_index {
+                let u = au[i_switch].clone();
+                if switch_op[i_switch].1 == Switch::Abs {
+                    let start = self.var.arg_start[op_index] as usize;
+                    let index = self.var.arg_all[start] as usize;
+                    az.push( var_all[index].clone() );
+                    var_all[res] = u;
+                } else {
+                    let (lhs, rhs) = self.abs_normal_operands(
+                        dyp_all, &var_all, op_index
+                    );
+                    let sum  = &lhs + &rhs;
+                    let diff = &lhs - &rhs;
+                    let both = if switch_op[i_switch].1 == Switch::Min {
+                        &sum - &u
+                    } else {
+                        &sum + &u
+                    };
+                    az.push( diff );
+                    var_all[res] = half * &both;
+                }
+                i_switch += 1;
+            } else {
+                let op_id = self.var.id_all[op_index] as usize;
+                let start = self.var.arg_start[op_index] as usize;
+                let end   = self.var.arg_start[op_index + 1] as usize;
+                let const_data = ConstData {
+                    cop,
+                    bool_all,
+                    str_all,
+                    arg      : &self.var.arg_all[start .. end],
+                    arg_type : &self.var.arg_type_all[start .. end],
+                    res,
+                };
+                let forward_var = op_fns_vec[op_id].forward_var_ad;
+                forward_var( dyp_all, &mut var_all, const_data );
+            }
+        }
+        //
+        // ay
+        let n_range = self.rng_ad_type.len();
+        let mut ay  = Vec::with_capacity(n_range + n_switch);
+        for i in 0 .. n_range {
+            let index = self.rng_index[i] as usize;
+            ay.push( arg_value(
+                cop, dyp_all, &var_all, index, self.rng_ad_type[i]
+            ) );
+        }
+        //
+        // ( y, z )
+        ay.append( &mut az );
+        ay
+    }
+}
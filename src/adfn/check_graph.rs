@@ -0,0 +1,359 @@
+// SPDX-License-Identifier: EPL-2.0 OR GPL-2.0-or-later
+// SPDX-FileCopyrightText: Bradley M. Bell <bradbell@seanet.com>
+// SPDX-FileContributor: 2026 Bradley M. Bell
+// ---------------------------------------------------------------------------
+//
+//! Check the operator graphs of an [ADfn] object that was read by
+//! [ADfn::from_bytes] or [ADfn::from_text] .
+//!
+//! Link to [parent module](super)
+//!
+//! The evaluation routines assume that the graphs were created by recording,
+//! so they index arrays using the operator arguments without checking them.
+//! The checks below ensure that each operator has the number and type
+//! of arguments it expects, that its arguments only refer to values
+//! computed before its result, and that its booleans and string
+//! are in bool_all and str_all .
+// ---------------------------------------------------------------------------
+// use
+//
+use crate::IndexT;
+use crate::ad::ADType;
+use crate::tape::AGraph;
+use crate::atom::sealed::GlobalAtomCallbackVec;
+use crate::op::call::BEGIN_DOM;
+use crate::op::id::{
+    CEIL_OP,
+    SIN_OP,
+    ADD_PP_OP,
+    DIV_VV_OP,
+    POWI_OP,
+    LT_OP,
+    GT_OP,
+    ATAN2_OP,
+    HYPOT_OP,
+    POWF_OP,
+    MIN_PP_OP,
+    MAX_VV_OP,
+    REDUCE_SUM_OP,
+    REDUCE_LEN_OP,
+    CEXP_OP,
+    LOAD_OP,
+    STORE_OP,
+    ZERO_ONE_OP,
+    PRINT_OP,
+    CALL_OP,
+    CALL_RES_OP,
+    NO_OP,
+};
+//
+#[cfg(doc)]
+use crate::ADfn;
+// ---------------------------------------------------------------------------
+//
+// op_bool_str
+/// The ranges of bool_all and str_all that are used by one operator use.
+///
+/// The return value is None if arg is too short for this operator.
+pub(crate) fn op_bool_str(
+    op_id : u8       ,
+    arg   : &[IndexT],
+) -> Option<( std::ops::Range<usize>, std::ops::Range<usize> )> {
+    if op_id == ZERO_ONE_OP {
+        let bool_start = *arg.first()? as usize;
+        let str_range  = *arg.get(1)? as usize .. *arg.get(2)? as usize;
+        Some( ( bool_start .. bool_start + 3, str_range ) )
+    } else if op_id == PRINT_OP {
+        let str_range  = *arg.first()? as usize .. *arg.get(1)? as usize;
+        Some( ( 0 .. 0, str_range ) )
+    } else if op_id == CEXP_OP {
+        let bool_start = *arg.get(1)? as usize;
+        Some( ( bool_start .. bool_start + 2, 0 .. 0 ) )
+    } else if op_id == CALL_OP {
+        let n_rng      = *arg.get(3)? as usize;
+        let bool_start = *arg.get(4)? as usize;
+        Some( ( bool_start .. bool_start + 1 + n_rng, 0 .. 0 ) )
+    } else {
+        Some( ( 0 .. 0, 0 .. 0 ) )
+    }
+}
+//
+// Operand
+/// The type of value that an operator argument must be.
+#[derive(Clone, Copy)]
+enum Operand {
+    /// constant, dynamic parameter, or variable index
+    Any,
+    /// not an index; i.e., the argument type is Empty
+    Empty,
+    /// Any or Empty
+    Optional,
+    /// constant index
+    Constant,
+    /// constant or dynamic parameter index
+    Parameter,
+    /// variable index
+    Variable,
+    /// same type as the result; i.e., dynamic parameter index in the
+    /// dynamic parameter graph and variable index in the variable graph
+    Result,
+}
+//
+// binary_pp_offset
+/// For the name_PP_OP, name_PV_OP, name_VP_OP, name_VV_OP operators
+/// this is 0, 1, 2, 3 respectively; see
+/// [doc_binary_op_id](crate::op::id::doc_binary_op_id) .
+fn binary_pp_offset(op_id : u8) -> u8 {
+    if op_id <= DIV_VV_OP {
+        (op_id - ADD_PP_OP) % 4
+    } else {
+        (op_id - MIN_PP_OP) % 4
+    }
+}
+//
+// is_binary_pp_op
+/// Is this one of the name_PP_OP operators.
+fn is_binary_pp_op(op_id : u8) -> bool {
+    matches!( op_id, ADD_PP_OP ..= DIV_VV_OP | MIN_PP_OP ..= MAX_VV_OP )
+        && binary_pp_offset(op_id) == 0
+}
+//
+// operand_vec
+/// The argument types for one operator use.
+///
+/// The return value is None if arg.len() is not valid for this operator.
+fn operand_vec(op_id : u8, arg : &[IndexT]) -> Option< Vec<Operand> > {
+    use Operand::*;
+    let operand = match op_id {
+        CEIL_OP ..= SIN_OP => vec![ Result ],
+        //
+        // name_PP_OP, name_PV_OP, name_VP_OP, name_VV_OP
+        ADD_PP_OP ..= DIV_VV_OP | MIN_PP_OP ..= MAX_VV_OP => {
+            match binary_pp_offset(op_id) {
+                0 => vec![ Parameter, Parameter ],
+                1 => vec![ Parameter, Variable ],
+                2 => vec![ Variable,  Parameter ],
+                _ => vec![ Variable,  Variable ],
+            }
+        },
+        POWI_OP                         => vec![ Result, Empty, Empty ],
+        //
+        // the optional third argument is the value when recorded
+        LT_OP ..= GT_OP if arg.len() == 3 => vec![ Any, Any, Constant ],
+        LT_OP ..= GT_OP                 => vec![ Any, Any ],
+        ATAN2_OP | HYPOT_OP | POWF_OP   => vec![ Any, Any ],
+        REDUCE_SUM_OP ..= REDUCE_LEN_OP => vec![ Result ],
+        CEXP_OP     => vec![ Empty, Empty, Any, Any, Any, Any ],
+        LOAD_OP     => vec![ Any; arg.len().max(1) ],
+        STORE_OP    => vec![ Any, Constant, Any, Any ],
+        ZERO_ONE_OP => vec![ Empty, Empty, Empty, Result ],
+        PRINT_OP    => vec![ Empty, Empty, Any, Optional ],
+        CALL_OP     => {
+            let n_dom = *arg.get(2)? as usize;
+            let mut operand = vec![ Empty; BEGIN_DOM ];
+            operand.resize(BEGIN_DOM + n_dom, Any);
+            operand
+        },
+        CALL_RES_OP => vec![ Empty ],
+        NO_OP       => vec![ Optional; arg.len() ],
+        _           => return None,
+    };
+    if operand.len() == arg.len() { Some( operand ) } else { None }
+}
+//
+// check_agraph
+/// Check one of the operator graphs in an [ADfn] object.
+///
+/// * agraph :
+///   is the graph. Its vector lengths have already been checked;
+///   i.e., id_all.len() == n_dep and arg_start is a valid partition
+///   of arg_all .
+///
+/// * res_type :
+///   is DynamicP (Variable) for the dynamic parameter (variable) graph.
+///
+/// * n_cop :
+///   is the number of constant parameters.
+///
+/// * n_dyp :
+///   is the number of dynamic parameters.
+///   This is not used for the dynamic parameter graph.
+///
+/// * n_var :
+///   is the number of variables.
+///   This is only used for the dynamic parameter graph where a call
+///   can have variable arguments (their values are not used).
+///
+/// * error :
+///   maps an operator index, in this graph, and a message to an error.
+pub(crate) fn check_agraph<V>(
+    agraph   : &AGraph                          ,
+    res_type : ADType                           ,
+    n_cop    : usize                            ,
+    n_dyp    : usize                            ,
+    n_var    : usize                            ,
+    error    : impl Fn(usize, &str) -> String   ,
+) -> Result<(), String>
+where
+    V : GlobalAtomCallbackVec ,
+{   //
+    // n_atom
+    let n_atom = {
+        let rw_lock   = <V as GlobalAtomCallbackVec>::get();
+        let read_lock = rw_lock.read();
+        assert!( read_lock.is_ok() );
+        read_lock.unwrap().len()
+    };
+    //
+    // n_call_res
+    // number of CALL_RES_OP operators that must follow the previous call
+    let mut n_call_res = 0;
+    //
+    for op_index in 0 .. agraph.n_dep {
+        let op_id    = agraph.id_all[op_index];
+        let begin    = agraph.arg_start[op_index] as usize;
+        let end      = agraph.arg_start[op_index + 1] as usize;
+        let arg      = &agraph.arg_all[begin .. end];
+        let arg_type = &agraph.arg_type_all[begin .. end];
+        let error    = |message : &str| Err( error(op_index, message) );
+        //
+        // operand
+        let Some(operand) = operand_vec(op_id, arg) else {
+            return error( &format!(
+                "operator has the wrong number of arguments {}", arg.len()
+            ) );
+        };
+        //
+        // name_PP_OP
+        // A binary operator with two parameter arguments has a dynamic result.
+        if res_type.is_variable() && is_binary_pp_op(op_id) {
+            return error("operator is only valid for dynamic parameters");
+        }
+        //
+        // n_dyp_arg, n_var_arg
+        // number of dynamic parameters, variables that an argument can use
+        let res = agraph.n_dom + op_index;
+        let (n_dyp_arg, n_var_arg) = if res_type.is_dynamic() {
+            (res, if op_id == CALL_OP { n_var } else { 0 } )
+        } else {
+            (n_dyp, res)
+        };
+        //
+        // arg, arg_type
+        for i_arg in 0 .. arg.len() {
+            let ad_type  = arg_type[i_arg];
+            let ok_type  = match operand[i_arg] {
+                Operand::Any       => ! ad_type.is_empty(),
+                Operand::Empty     => ad_type.is_empty(),
+                Operand::Optional  => true,
+                Operand::Constant  => ad_type.is_constant(),
+                Operand::Parameter =>
+                    ad_type.is_constant() || ad_type.is_dynamic(),
+                Operand::Variable  => ad_type.is_variable(),
+                Operand::Result    => ad_type == res_type,
+            };
+            if ! ok_type {
+                return error( &format!( "argument {i_arg} has the wrong type" ) );
+            }
+            let index = arg[i_arg] as usize;
+            let limit = match ad_type {
+                ADType::ConstantP => n_cop,
+                ADType::DynamicP  => n_dyp_arg,
+                ADType::Variable  => n_var_arg,
+                ADType::Empty     => usize::MAX,
+            };
+            if limit <= index {
+                return error( &format!(
+                    "argument {i_arg} index {index} is out of range"
+                ) );
+            }
+        }
+        //
+        // bool_all, str_all
+        let (bool_range, str_range) = op_bool_str(op_id, arg).unwrap();
+        if agraph.bool_all.len() < bool_range.end {
+            return error("booleans are out of range");
+        }
+        if str_range.end < str_range.start
+            || agraph.str_all.len() < str_range.end
+            || ! agraph.str_all.is_char_boundary(str_range.start)
+            || ! agraph.str_all.is_char_boundary(str_range.end) {
+            return error("string is out of range");
+        }
+        //
+        // CEXP_OP
+        // the comparison codes are 0, ..., 5; see crate::op::cexp::compare
+        if op_id == CEXP_OP && 6 <= arg[0] {
+            return error( &format!( "invalid comparison code {}", arg[0] ) );
+        }
+        //
+        // POWI_OP
+        if op_id == POWI_OP && i32::MAX as IndexT <= arg[1] {
+            return error("powi exponent is out of range");
+        }
+        //
+        // CALL_RES_OP
+        // arg[0] is the offset back to the corresponding CALL_OP
+        if n_call_res > 0 {
+            let offset = 1 + agraph.id_all[.. op_index].iter().rev()
+                .position( |id| *id == CALL_OP ).unwrap();
+            if op_id != CALL_RES_OP || arg[0] as usize != offset {
+                return error("expected a call result operator");
+            }
+            n_call_res -= 1;
+        } else if op_id == CALL_RES_OP {
+            return error("call result operator does not follow a call");
+        }
+        //
+        // CALL_OP
+        if op_id == CALL_OP {
+            let atom_id = arg[0] as usize;
+            if n_atom <= atom_id {
+                return error( &format!(
+                    "atom_id {atom_id} is not a registered atomic function"
+                ) );
+            }
+            let rng_is_dep = &agraph.bool_all[bool_range.start + 1 .. bool_range.end];
+            let n_dep      = rng_is_dep.iter().filter( |is_dep| **is_dep ).count();
+            if n_dep == 0 {
+                return error("call does not have any dependents");
+            }
+            n_call_res = n_dep - 1;
+        }
+    }
+    if n_call_res > 0 {
+        return Err( error(agraph.n_dep, "missing call result operators") );
+    }
+    Ok( () )
+}
+//
+// check_rng
+/// Check the range types and indices for an [ADfn] object.
+///
+/// * n_cop, n_dyp, n_var :
+///   are the number of constant parameters, dynamic parameters,
+///   and variables.
+pub(crate) fn check_rng(
+    rng_ad_type : &[ADType] ,
+    rng_index   : &[IndexT] ,
+    n_cop       : usize     ,
+    n_dyp       : usize     ,
+    n_var       : usize     ,
+) -> Result<(), String> {
+    if rng_ad_type.len() != rng_index.len() {
+        return Err( "rng_ad_type length is not rng_index length".to_string() );
+    }
+    for (ad_type, index) in rng_ad_type.iter().zip( rng_index ) {
+        let index = *index as usize;
+        let limit = match ad_type {
+            ADType::ConstantP => n_cop,
+            ADType::DynamicP  => n_dyp,
+            ADType::Variable  => n_var,
+            ADType::Empty     => 0,
+        };
+        if limit <= index {
+            return Err( format!( "rng index {index} is out of range" ) );
+        }
+    }
+    Ok( () )
+}
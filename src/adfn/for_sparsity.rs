@@ -22,6 +22,11 @@ use crate::{
 use crate::op::id::{
     CALL_OP,
     CALL_RES_OP,
+    CEXP_OP,
+};
+use crate::op::cexp::{
+    NUMBER_ARG,
+    cexp_use_arg,
 };
 use crate::op::info::{
     sealed::GlobalOpFnsVec,
@@ -65,6 +70,15 @@ where
     ///     Otherwise, the sparsity pattern is w.r.t. the domain variables.
     ///     The default for compute_dyp is false.
     ///
+    ///   * cexp :
+    ///     The corresponding value must be both or recorded (default is both).
+    ///     If it is both, the result of a conditional expression depends on
+    ///     its comparison operands and both of its branches;
+    ///     see [AD::select](crate::AD::select).
+    ///     If it is recorded, it only depends on the comparison operands
+    ///     and the branches that were selected during the recording; i.e.,
+    ///     the pattern is for the branches at the recorded point.
+    ///
     /// * pattern :
     ///   The the return value *pattern* is vector of [row, column] pairs.
     ///   Each row is a range index and is less that [ADfn::rng_len] .
@@ -117,9 +131,10 @@ where
         opt_vec : &Vec<[&str; 2]>  ,
     ) -> SparsityPattern
     {   //
-        // trace, compute_dyp
-        let mut trace       = false;
-        let mut compute_dyp = false;
+        // trace, compute_dyp, cexp_recorded
+        let mut trace         = false;
+        let mut compute_dyp   = false;
+        let mut cexp_recorded = false;
         for opt in opt_vec {
             match opt[0] {
                 "trace" => {
@@ -140,6 +155,15 @@ where
                         ); }
                     }
                 },
+                "cexp" => {
+                    match opt[1] {
+                        "both"     => { cexp_recorded = false; },
+                        "recorded" => { cexp_recorded = true; },
+                        _ => { panic!(
+                        "for_sparsity opt_vec: invalid value for cexp"
+                        ); }
+                    }
+                },
                 _ => panic!("for_sparsity opt_vec: invalid key"),
             }
         }
@@ -263,8 +287,18 @@ where
                     let arg        = &arg_all[begin .. end];
                     let arg_type   = &arg_type_all[begin .. end];
                     //
+                    // use_arg
+                    let use_arg = if op_id == CEXP_OP {
+                        cexp_use_arg(&agraph.bool_all, arg, cexp_recorded)
+                    } else {
+                        [true; NUMBER_ARG]
+                    };
+                    //
                     // depend_usize
                     for i in 0 .. arg.len() {
+                        if op_id == CEXP_OP && ! use_arg[i] {
+                            continue;
+                        }
                         if arg_type[i].is_variable() {
                             debug_assert!( i_agraph != 0 || ! compute_dyp );
                             depend_usize.push(  arg[i] as usize + n_dyp );
@@ -180,6 +180,7 @@ V : Clone + std::fmt::Display + GlobalOpFnsVec + FConst + ThisThreadTape,
 }
 
 #[cfg(test)]
+#[allow(clippy::needless_range_loop)]
 mod tests {
     use crate::{
         AD,
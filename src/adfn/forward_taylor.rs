@@ -0,0 +1,339 @@
+// SPDX-License-Identifier: EPL-2.0 OR GPL-2.0-or-later
+// SPDX-FileCopyrightText: Bradley M. Bell <bradbell@seanet.com>
+// SPDX-FileContributor: 2026 Bradley M. Bell
+// ---------------------------------------------------------------------------
+//
+//! Implement the [ADfn] forward_taylor method (Taylor coefficients).
+//!
+//! Link to [parent module](super)
+// ---------------------------------------------------------------------------
+// use
+//
+use crate::{
+    AD,
+    ADfn,
+    FConst,
+};
+use crate::op::info::sealed::GlobalOpFnsVec;
+use crate::tape::sealed::ThisThreadTape;
+use crate::op::info::ConstData;
+//
+#[cfg(doc)]
+use crate::{
+    doc_generic_v,
+    doc_generic_e,
+};
+//
+// -----------------------------------------------------------------------
+// forward_taylor
+/// Arbitrary order forward mode evaluation with dynamic parameters.
+///
+/// * Syntax :
+///   ```text
+///     (rng_taylor, var_taylor) =
+///         f.forward_taylor_value(dyp_all, &var_all, order, dom_taylor, opt_vec)
+///     (rng_taylor, var_taylor) =
+///         f.forward_taylor_ad(dyp_all, &var_all, order, dom_taylor, opt_vec)
+///   ```
+///
+/// * Prototype :
+///   see [ADfn::forward_taylor_value] and [ADfn::forward_taylor_ad]
+///
+/// * V : see [doc_generic_v]
+/// * E : see [doc_generic_e]
+/// * f : is an [ADfn] object.
+///
+/// * dyp_all  :
+///   If there are no dynamic parameters in f, this should be None
+///   or the empty vector.
+///   Otherwise it is the dynamic parameter sub-vectors in the following order:
+///   the domain dynamic parameters followed by the dependent dynamic parameters.
+///   This is normally computed by
+///   [forward_dyp](crate::adfn::forward_dyp::doc_forward_dyp) .
+///
+/// * var_all  :
+///   is both the variable sub-vectors in the following order:
+///   the domain variables followed by the dependent variables.
+///   This is normally computed by
+///   [forward_var](crate::adfn::forward_var::doc_forward_var) .
+///   It is the zero order Taylor coefficient for all the variables.
+///
+/// * order :
+///   is the highest order Taylor coefficient that is computed.
+///   If order is zero, rng_taylor is empty and var_taylor is equal to var_all.
+///
+/// * dom_taylor :
+///   Let n_dom be the number of domain variables.
+///   This vector has length n_dom * order.
+///   For j < n_dom and 0 < k <= order,
+///   dom_taylor\[ j * order + k - 1 \] is the order k Taylor coefficient
+///   for the j-th domain variable.
+///   The domain variables as a function of t are
+///   ```text
+///     x_j(t) = var_all[j] + sum_{k=1}^order dom_taylor[j * order + k - 1] * t^k
+///   ```
+///
+/// * opt_vec :
+///   is an [opt_vec](crate::doc_opt_vec) with the following possible keys:
+///
+///   * trace
+///     The corresponding value must be true of false (default is false).
+///     If it is true, a trace of forward_taylor is printed on stdout.
+///
+/// * rng_taylor :
+///   Let n_rng be the number of range components.
+///   This vector has length n_rng * order.
+///   For i < n_rng and 0 < k <= order,
+///   rng_taylor\[ i * order + k - 1 \] is the order k Taylor coefficient
+///   for the i-th range component as a function of t.
+///   If the i-th range component is a parameter, these coefficients are zero.
+///
+/// * var_taylor :
+///   Let n_var be the number of variables.
+///   This vector has length n_var * (order + 1).
+///   For i < n_var and k <= order,
+///   var_taylor\[ i * (order + 1) + k \] is the order k Taylor coefficient
+///   for the i-th variable.
+///   This is used by
+///   [reverse_taylor](crate::adfn::reverse_taylor::doc_reverse_taylor)
+///   to compute partials of the Taylor coefficients.
+///
+/// * Atomic Functions :
+///   Atomic functions only provide first order derivatives.
+///   If f contains an atomic function call, order must be less than
+///   or equal one.
+///
+/// # Example
+/// Computing the Taylor coefficients for exp( x\[0\] * x\[1\] ) :
+/// ```
+/// use rustad::start_recording;
+/// use rustad::stop_recording;
+/// use rustad::FUnary;
+///
+/// // V
+/// type V = rustad::AzFloat<f64>;
+/// //
+/// // f
+/// // f(x) = exp( x[0] * x[1] )
+/// let x    : Vec<V>   = vec![ V::from(1.0), V::from(1.0) ];
+/// let (_, ax)         = start_recording(None, x);
+/// let aprod           = &ax[0] * &ax[1];
+/// let ay              = vec![ FUnary::exp( &aprod ) ];
+/// let f               = stop_recording(ay);
+/// //
+/// // x(t) = [ t, 2 ] , f( x(t) ) = exp(2 t) = sum_k (2 t)^k / k!
+/// let order           = 3;
+/// let opt_vec : Vec<[&str; 2]> = Vec::new();
+/// let x      : Vec<V> = vec![ V::from(0.0), V::from(2.0) ];
+/// let (_, var)        = f.forward_var_value(None, x, &opt_vec);
+/// let dom_taylor      = vec![
+///     V::from(1.0), V::from(0.0), V::from(0.0), // x_0(t) = t
+///     V::from(0.0), V::from(0.0), V::from(0.0), // x_1(t) = 2
+/// ];
+/// let (y_taylor, _) = f.forward_taylor_value(
+///     None, &var, order, dom_taylor, &opt_vec
+/// );
+/// //
+/// // check
+/// assert_eq!( y_taylor[0], V::from( 2.0 ) );
+/// assert_eq!( y_taylor[1], V::from( 2.0 * 2.0 / 2.0 ) );
+/// assert_eq!( y_taylor[2], V::from( 2.0 * 2.0 * 2.0 / 6.0 ) );
+/// ```
+///
+pub fn doc_forward_taylor() { }
+//
+/// Create the arbitrary order forward mode member functions.
+///
+/// * suffix : is either `value` or `ad` ;
+/// * E      : see [doc_generic_e] .
+///
+/// If *suffix* is `value` , *E must be be the value type *V* .
+/// If *suffix* is `ad` , *E must be be the type `AD<V>` .
+///
+/// See [doc_forward_taylor]
+macro_rules! forward_taylor {
+    ( $suffix:ident, $E:ty ) => { paste::paste! {
+        #[doc = concat!(
+            " `", stringify!($E),
+            "` evaluation of arbitrary order forward mode; ",
+            "see [doc_forward_taylor]",
+        )]
+        pub fn [< forward_taylor_ $suffix >] (
+            &self,
+            dyp_all     : Option< &Vec<$E> >  ,
+            var_all     : &[$E]               ,
+            order       : usize               ,
+            dom_taylor  : Vec<$E>             ,
+            opt_vec     : &Vec<[&str; 2]>     ,
+        ) -> ( Vec<$E>, Vec<$E> )
+        {
+            // trace
+            let mut trace = false;
+            for opt in opt_vec {
+                match opt[0] {
+                    "trace" => {
+                        match opt[1] {
+                            "true"  => { trace = true; },
+                            "false" => { trace = false; },
+                            _ => { panic!(
+                            "forward_taylor opt_vec: invalid value for trace"
+                            ); }
+                        }
+                    },
+                    _ => panic!("forward_taylor opt_vec: invalid key"),
+                }
+            }
+            //
+            // dyp_all
+            let dyp_all  : &Vec<$E> = match dyp_all {
+                None          => &Vec::new(),
+                Some(dyp_all) => dyp_all,
+            };
+            //
+            // n_var
+            let n_var = self.var.n_dom + self.var.n_dep;
+            //
+            // n_dyp
+            let n_dyp = self.dyp.n_dom + self.dyp.n_dep;
+            //
+            assert_eq!( dyp_all.len(), n_dyp,
+                "f.forward_taylor: dyp_all vector length does not match f"
+            );
+            assert_eq!(
+                var_all.len(), n_var,
+                "f.forward_taylor: var_all vector length does not match f"
+            );
+            //
+            assert_eq!(
+                dom_taylor.len(), self.var.n_dom * order,
+                "f.forward_taylor: dom_taylor vector length is not n_dom * order"
+            );
+            //
+            // op_fns_vec
+            let op_fns_vec = GlobalOpFnsVec::get();
+            //
+            // n_tay
+            let n_tay = order + 1;
+            //
+            // var_tay
+            let mut var_tay : Vec<$E> = vec![ $E::zero(); n_var * n_tay ];
+            for i_var in 0 .. n_var {
+                var_tay[i_var * n_tay] = var_all[i_var].clone();
+            }
+            for j in 0 .. self.var.n_dom {
+                for k in 1 .. n_tay {
+                    var_tay[j * n_tay + k] =
+                        dom_taylor[j * order + k - 1].clone();
+                }
+            }
+            //
+            if trace {
+                println!( "Begin Trace: forward_taylor: n_var = {}, order = {}",
+                    n_var, order
+                );
+                println!( "index, bool" );
+                for j in 0 .. self.var.bool_all.len() {
+                    println!( "{}, {:?}", j, self.var.bool_all[j] );
+                }
+                println!( "index, constant" );
+                for j in 0 .. self.cop.len() {
+                    println!( "{}, {}", j, self.cop[j] );
+                }
+                println!( "index, dyp_all" );
+                for j in 0 .. n_dyp {
+                    println!( "{}, {}", j, dyp_all[j] );
+                }
+                println!( "var_index, var_taylor" );
+                for j in 0 .. self.var.n_dom {
+                    let taylor = &var_tay[j * n_tay .. (j + 1) * n_tay];
+                    println!( "{}, {}", j, taylor_string(taylor) );
+                }
+                println!( "var_index, var_taylor, op_name, arg" );
+            }
+            //
+            // cop, bool_all
+            let cop      = &self.cop;
+            let bool_all = &self.var.bool_all;
+            let str_all  = &self.var.str_all;
+            //
+            // var_tay
+            for op_index in 0 .. self.var.id_all.len() {
+                let op_id    = self.var.id_all[op_index] as usize;
+                let start    = self.var.arg_start[op_index] as usize;
+                let end      = self.var.arg_start[op_index + 1] as usize;
+                //
+                let arg      = &self.var.arg_all[start .. end];
+                let arg_type = &self.var.arg_type_all[start .. end];
+                let res      = self.var.n_dom + op_index;
+                //
+                let const_data = ConstData {
+                    cop, bool_all, str_all, arg, arg_type, res
+                };
+                //
+                let forward_taylor =
+                    op_fns_vec[op_id].[< forward_taylor_ $suffix >];
+                forward_taylor(
+                    dyp_all,
+                    &mut var_tay,
+                    n_tay,
+                    const_data,
+                );
+                if trace {
+                    let name   = &op_fns_vec[op_id].name;
+                    let taylor = &var_tay[res * n_tay .. (res + 1) * n_tay];
+                    println!( "{}, {}, {}, {:?}",
+                        res, taylor_string(taylor), name, arg
+                    );
+                }
+            }
+            if trace {
+                println!( "rng_index, var_index, con_index" );
+                for i in 0 .. self.rng_ad_type.len() {
+                    let index = self.rng_index[i] as usize;
+                    if self.rng_ad_type[i].is_variable() {
+                        println!( "{}, {}, ----", i, index);
+                    } else {
+                        println!( "{}, ---- ,{}", i, index);
+                    }
+                }
+                println!( "End Trace: forward_taylor" );
+            }
+            let n_rng = self.rng_ad_type.len();
+            let mut rng_taylor : Vec<$E> = Vec::with_capacity( n_rng * order );
+            for i in 0 .. n_rng {
+                let index = self.rng_index[i] as usize;
+                for k in 1 .. n_tay {
+                    if self.rng_ad_type[i].is_variable() {
+                        rng_taylor.push( var_tay[index * n_tay + k].clone() );
+                    } else {
+                        rng_taylor.push( $E::zero() );
+                    }
+                }
+            }
+            ( rng_taylor, var_tay )
+        }
+    }
+} }
+//
+impl<V> ADfn<V> where
+V : Clone + std::fmt::Display + GlobalOpFnsVec + FConst + ThisThreadTape,
+{   //
+    // forward_taylor
+    forward_taylor!( value, V );
+    forward_taylor!( ad,    AD::<V> );
+}
+//
+// taylor_string
+/// Convert the Taylor coefficients, or their partials, for one variable
+/// to a string (used by the trace option).
+pub(crate) fn taylor_string<V : std::fmt::Display>(taylor : &[V]) -> String
+{   let mut result = String::from("[");
+    for (k, coef) in taylor.iter().enumerate() {
+        if k > 0 {
+            result += ", ";
+        }
+        result += &format!("{}", coef);
+    }
+    result += "]";
+    result
+}
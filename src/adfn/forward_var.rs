@@ -18,6 +18,9 @@ use crate::{
 use crate::op::info::sealed::GlobalOpFnsVec;
 use crate::tape::sealed::ThisThreadTape;
 use crate::op::info::ConstData;
+use crate::op::binary::num_cmp::is_num_cmp_op;
+use crate::tape::AGraph;
+use crate::push_this_thread_message;
 //
 #[cfg(doc)]
 use crate::{
@@ -59,6 +62,31 @@ use crate::{
 ///     The corresponding value must be true of false (default is false).
 ///     If it is true, a trace of forward_var is printed on stdout.
 ///
+///   * compare_change
+///     The corresponding value must be true of false (default is false).
+///     If it is true, each numerical comparison operator
+///     (e.g. [num_lt](crate::FBinary::num_lt) )
+///     whose result is different from its result during recording
+///     is reported using the thread message stack; see
+///     [pop_this_thread_message](crate::pop_this_thread_message) .
+///     The comparisons in the dynamic parameter graph are only checked
+///     when *dyp_all* is not empty.
+///     For numeric vectors, a comparison is different if any element
+///     of its result is different.
+///     Comparisons whose results are not used may be removed by
+///     [optimize](crate::ADfn::optimize) .
+///
+///   * compare_message
+///     The corresponding value is included in each compare_change message
+///     (default is "comparison result different from during recording").
+///     Each message has the form
+///     ```text
+///         compare_change: {graph} operator {op_index}: {compare_message}
+///     ```
+///     where *graph* is dyp or var and *op_index* is the index of the
+///     comparison in the corresponding operator sequence.
+///     The number of changes is the number of messages pushed.
+///
 /// * range :
 ///   is the range vector corresponding to the
 ///   domain variable and parameter values;
@@ -127,6 +155,55 @@ use crate::{
 ///
 pub fn doc_forward_var() { }
 //
+// CompareValue
+/// The value used to check if a comparison result has changed.
+trait CompareValue<V> {
+    fn compare_value(&self) -> &V;
+}
+impl<V> CompareValue<V> for V {
+    fn compare_value(&self) -> &V { self }
+}
+impl<V> CompareValue<V> for AD<V> {
+    fn compare_value(&self) -> &V { &self.value }
+}
+//
+// compare_change
+/// Push a message for each comparison in agraph whose result is different
+/// from its result during recording.
+///
+/// * graph :
+///   is dyp or var and is only used in the messages.
+///
+/// * value_all :
+///   is the value for all the nodes in agraph; i.e.,
+///   dyp_all or var_all.
+fn compare_change<V, E>(
+    graph     : &str      ,
+    agraph    : &AGraph   ,
+    cop       : &[V]      ,
+    value_all : &[E]      ,
+    message   : &str      ,
+)
+where
+    V : PartialEq       ,
+    E : CompareValue<V> ,
+{
+    for op_index in 0 .. agraph.id_all.len() {
+        let op_id = agraph.id_all[op_index];
+        let start = agraph.arg_start[op_index] as usize;
+        let end   = agraph.arg_start[op_index + 1] as usize;
+        if is_num_cmp_op(op_id) && end - start == 3 {
+            let recorded = &cop[ agraph.arg_all[start + 2] as usize ];
+            let res      = agraph.n_dom + op_index;
+            if value_all[res].compare_value() != recorded {
+                push_this_thread_message( &format!(
+                    "compare_change: {graph} operator {op_index}: {message}"
+                ) );
+            }
+        }
+    }
+}
+//
 /// Evaluate the zero order forward mode member functions.
 ///
 /// * suffix : is either `value` or `ad` ;
@@ -151,8 +228,11 @@ macro_rules! forward_var {
             opt_vec     : &Vec<[&str; 2]>    ,
         ) -> ( Vec<$E> , Vec<$E> )
         {
-            // trace
-            let mut trace = false;
+            // trace, check_compare, compare_message
+            let mut trace           = false;
+            let mut check_compare   = false;
+            let mut compare_message =
+                "comparison result different from during recording";
             for opt in opt_vec {
                 match opt[0] {
                     "trace" => {
@@ -164,6 +244,18 @@ macro_rules! forward_var {
                             ); }
                         }
                     },
+                    "compare_change" => {
+                        match opt[1] {
+                            "true"  => { check_compare = true; },
+                            "false" => { check_compare = false; },
+                            _ => { panic!( "forward_var opt_vec: \
+                                invalid value for compare_change"
+                            ); }
+                        }
+                    },
+                    "compare_message" => {
+                        compare_message = opt[1];
+                    },
                     _ => panic!("forward_var opt_vec: invalid key"),
                 }
             }
@@ -248,6 +340,18 @@ macro_rules! forward_var {
                 }
             }
             //
+            // compare_change
+            if check_compare {
+                if ! dyp_all.is_empty() {
+                    compare_change(
+                        "dyp", &self.dyp, cop, dyp_all, compare_message
+                    );
+                }
+                compare_change(
+                    "var", &self.var, cop, &var_all, compare_message
+                );
+            }
+            //
             // n_range
             let n_range = self.rng_ad_type.len();
             //
@@ -0,0 +1,343 @@
+// SPDX-License-Identifier: EPL-2.0 OR GPL-2.0-or-later
+// SPDX-FileCopyrightText: Bradley M. Bell <bradbell@seanet.com>
+// SPDX-FileContributor: 2026 Bradley M. Bell
+//
+//! Implements [ADfn] Hessian sparsity method.
+//!
+//! Link to [parent module](super)
+//!
+// ---------------------------------------------------------------------------
+//
+use crate::vec_set::VecSet;
+use crate::op::info::{
+    OpFns,
+    Linearity,
+};
+use crate::op::call::call_depend;
+use crate::atom::AtomCallback;
+use crate::{
+    ADfn,
+    IndexT,
+    GlobalAtomCallbackVecPublic,
+    SparsityPattern,
+};
+use crate::op::id::{
+    CALL_OP,
+    CALL_RES_OP,
+    CEXP_OP,
+    LOAD_OP,
+    STORE_OP,
+};
+use crate::op::cexp::{
+    BEGIN_COND,
+    cexp_use_arg,
+};
+use crate::op::info::{
+    sealed::GlobalOpFnsVec,
+};
+//
+#[cfg(doc)]
+use crate::doc_generic_v;
+// ----------------------------------------------------------------------------
+// ADfn::hes_sparsity
+impl<V> ADfn<V>
+where
+    V               : GlobalAtomCallbackVecPublic + GlobalOpFnsVec ,
+    AtomCallback<V> : Clone,
+{
+    /// Compute a Hessian sparsity pattern.
+    ///
+    /// See Also : [ADfn::for_sparsity]
+    ///
+    /// * Syntax :
+    ///   ```text
+    ///     pattern = f.hes_sparsity(&select_rng, opt_vec)
+    ///   ```
+    ///
+    /// * V : see [doc_generic_v]
+    ///
+    /// * f :
+    ///   is this [ADfn] object. The sparsity pattern is for the Hessian
+    ///   of a weighted sum of the range components of f.
+    ///
+    /// * select_rng :
+    ///   This vector has length equal to [ADfn::rng_len] .
+    ///   If select_rng\[i\] is true (false), the i-th range component
+    ///   is (is not) included in the weighted sum.
+    ///   The pattern is valid for all weightings of the selected components.
+    ///
+    /// * opt_vec :
+    ///   is an [opt_vec](crate::doc_opt_vec) with the following possible keys:
+    ///
+    ///   * trace
+    ///     The corresponding value must be true of false (default is false).
+    ///     If it is true, a trace of hes_sparsity is printed on stdout.
+    ///
+    ///   * cexp :
+    ///     The corresponding value must be both or recorded (default is both).
+    ///     This has the same meaning as the cexp option for
+    ///     [ADfn::for_sparsity] .
+    ///     The comparison operands in a conditional expression are not
+    ///     included because they do not affect its derivatives.
+    ///
+    /// * pattern :
+    ///   The the return value *pattern* is vector of [row, column] pairs.
+    ///   Each row and column is a domain variable index
+    ///   and is less than [ADfn::var_dom_len] .
+    ///   If a pair [i, j] does not appear, the second partial of the
+    ///   weighted sum w.r.t. the domain variables with index i and j
+    ///   is identically zero.
+    ///   The pattern is symmetric; i.e., if [i, j] appears so does [j, i].
+    ///   The pairs are in row major order.
+    ///
+    /// * Method :
+    ///   A forward pass computes the Jacobian sparsity for each variable.
+    ///   A reverse pass, starting at the selected range components,
+    ///   accumulates the Hessian sparsity using the classification of each
+    ///   operator as linear, a product, a quotient, or nonlinear.
+    ///   Dynamic parameters are treated as constants and
+    ///   atomic function calls are treated as nonlinear in all their
+    ///   variable arguments.
+    ///
+    /// # Example
+    /// ```
+    /// use rustad::AD;
+    /// use rustad::FUnary;
+    /// use rustad::start_recording;
+    /// use rustad::stop_recording;
+    /// //
+    /// // V
+    /// type V = rustad::AzFloat<f64>;
+    /// //
+    /// // f
+    /// // f(x) = [ x[0] * x[1] , sin( x[2] ) + x[3] ]
+    /// let x      : Vec<V>       = vec![ V::from(2.0); 4 ];
+    /// let (_, ax)               = start_recording(None, x);
+    /// let ay_0                  = &ax[0] * &ax[1];
+    /// let ay_1                  = &(&ax[2]).sin() + &ax[3];
+    /// let f                     = stop_recording( vec![ay_0, ay_1] );
+    /// //
+    /// // pattern for Hessian of f_0(x)
+    /// let opt_vec : Vec<[&str; 2]> = Vec::new();
+    /// let pattern = f.hes_sparsity( &[true, false], &opt_vec );
+    /// assert_eq!( pattern, vec![ [0, 1], [1, 0] ] );
+    /// //
+    /// // pattern for Hessian of f_0(x) + f_1(x)
+    /// let pattern = f.hes_sparsity( &[true, true], &opt_vec );
+    /// assert_eq!( pattern, vec![ [0, 1], [1, 0], [2, 2] ] );
+    /// ```
+    ///
+    pub fn hes_sparsity(
+        &self                        ,
+        select_rng : &[bool]         ,
+        opt_vec    : &Vec<[&str; 2]> ,
+    ) -> SparsityPattern
+    {   //
+        // trace, cexp_recorded
+        let mut trace         = false;
+        let mut cexp_recorded = false;
+        for opt in opt_vec {
+            match opt[0] {
+                "trace" => {
+                    match opt[1] {
+                        "true"  => { trace = true; },
+                        "false" => { trace = false; },
+                        _ => { panic!(
+                        "hes_sparsity opt_vec: invalid value for trace"
+                        ); }
+                    }
+                },
+                "cexp" => {
+                    match opt[1] {
+                        "both"     => { cexp_recorded = false; },
+                        "recorded" => { cexp_recorded = true; },
+                        _ => { panic!(
+                        "hes_sparsity opt_vec: invalid value for cexp"
+                        ); }
+                    }
+                },
+                _ => panic!("hes_sparsity opt_vec: invalid key"),
+            }
+        }
+        assert_eq!(
+            select_rng.len(), self.rng_ad_type.len(),
+            "f.hes_sparsity: select_rng length does not match f"
+        );
+        //
+        // op_fns_vec
+        let op_fns_vec : &Vec< OpFns<V> >  = GlobalOpFnsVec::get();
+        //
+        // n_dom, n_dep, n_var, id_all, arg_start, arg_all, arg_type_all
+        let n_dom             = self.var.n_dom;
+        let n_dep             = self.var.n_dep;
+        let n_var             = n_dom + n_dep;
+        let id_all            = &self.var.id_all;
+        let arg_start         = &self.var.arg_start;
+        let arg_all           = &self.var.arg_all;
+        let arg_type_all      = &self.var.arg_type_all;
+        //
+        // atom_depend, cop_depend, dyp_depend, var_depend
+        let mut atom_depend : Vec<usize>  = Vec::new();
+        let mut cop_depend  : Vec<IndexT> = Vec::new();
+        let mut dyp_depend  : Vec<IndexT> = Vec::new();
+        let mut var_depend  : Vec<IndexT> = Vec::new();
+        //
+        // set_var_arg
+        // sets var_arg to the variable arguments for an operator
+        let mut set_var_arg = | var_arg : &mut Vec<usize>, op_index : usize | {
+            var_arg.clear();
+            let op_id = id_all[op_index];
+            if op_id == CALL_OP || op_id == CALL_RES_OP {
+                cop_depend.clear();
+                dyp_depend.clear();
+                var_depend.clear();
+                call_depend::<V>(
+                    &mut atom_depend,
+                    &mut cop_depend,
+                    &mut dyp_depend,
+                    &mut var_depend,
+                    &self.var,
+                    op_index
+                );
+                for dep_index in var_depend.iter() {
+                    var_arg.push( *dep_index as usize );
+                }
+            } else {
+                let begin      = arg_start[op_index] as usize;
+                let end        = arg_start[op_index + 1] as usize;
+                let arg        = &arg_all[begin .. end];
+                let arg_type   = &arg_type_all[begin .. end];
+                if op_id == CEXP_OP {
+                    let mut use_arg = cexp_use_arg(
+                        &self.var.bool_all, arg, cexp_recorded
+                    );
+                    use_arg[BEGIN_COND]     = false;
+                    use_arg[BEGIN_COND + 1] = false;
+                    for i in 0 .. arg.len() {
+                        if use_arg[i] && arg_type[i].is_variable() {
+                            var_arg.push( arg[i] as usize );
+                        }
+                    }
+                } else if op_id == LOAD_OP || op_id == STORE_OP {
+                    // the index does not contribute to the derivative
+                    for i in 1 .. arg.len() {
+                        if arg_type[i].is_variable() {
+                            var_arg.push( arg[i] as usize );
+                        }
+                    }
+                } else {
+                    for i in 0 .. arg.len() {
+                        if arg_type[i].is_variable() {
+                            var_arg.push( arg[i] as usize );
+                        }
+                    }
+                }
+            }
+        };
+        //
+        // set_vec, arg_usize
+        // The set with identifier i_var < n_var is the Jacobian sparsity
+        // for the variable with index i_var.
+        let mut set_vec   : VecSet     = VecSet::new();
+        let mut arg_usize : Vec<usize> = Vec::new();
+        for id_set in 0 .. n_dom {
+            set_vec.singleton( id_set );
+        }
+        for op_index in 0 .. n_dep {
+            set_var_arg(&mut arg_usize, op_index);
+            let id_set = set_vec.union( &arg_usize );
+            assert_eq!( id_set, n_dom + op_index );
+        }
+        //
+        // rev_jac, hes_set
+        // rev_jac[i_var] is true if the weighted sum depends on i_var.
+        // hes_set[i_var] is the set identifier for the Hessian sparsity
+        // of the weighted sum w.r.t. variable i_var and the domain variables.
+        let empty_set   = set_vec.empty();
+        let mut rev_jac = vec![ false; n_var ];
+        let mut hes_set = vec![ empty_set; n_var ];
+        for i in 0 .. select_rng.len() {
+            if select_rng[i] && self.rng_ad_type[i].is_variable() {
+                rev_jac[ self.rng_index[i] as usize ] = true;
+            }
+        }
+        //
+        if trace {
+            println!( "Begin Trace: hes_sparsity: n_dom = {}", n_dom );
+            println!( "var_index, op_name, var_arguments, hes_result" );
+        }
+        //
+        // sub_sets
+        let mut sub_sets : Vec<usize> = Vec::new();
+        //
+        // op_index
+        for op_index in ( 0 .. n_dep ).rev() {
+            //
+            // res
+            let res = n_dom + op_index;
+            if rev_jac[res] {
+                //
+                // linearity
+                let op_id     = id_all[op_index];
+                let linearity = if op_id == CALL_OP || op_id == CALL_RES_OP {
+                    Linearity::Nonlinear
+                } else {
+                    op_fns_vec[op_id as usize].linearity
+                };
+                //
+                // arg_usize
+                set_var_arg(&mut arg_usize, op_index);
+                //
+                if trace {
+                    let op_name = &op_fns_vec[op_id as usize].name;
+                    let set     = set_vec.get( hes_set[res] );
+                    println!(
+                        "{}, {}, {:?}, {:?}", res, op_name, arg_usize, set
+                    );
+                }
+                //
+                // rev_jac, hes_set
+                for (i, index) in arg_usize.iter().enumerate() {
+                    rev_jac[*index] = true;
+                    sub_sets.clear();
+                    sub_sets.push( hes_set[*index] );
+                    sub_sets.push( hes_set[res] );
+                    match linearity {
+                        Linearity::Linear => { },
+                        Linearity::Product => {
+                            debug_assert!( arg_usize.len() == 2 );
+                            sub_sets.push( arg_usize[1 - i] );
+                        },
+                        Linearity::Quotient => {
+                            debug_assert!( arg_usize.len() == 2 );
+                            sub_sets.push( arg_usize[1] );
+                            if i == 1 {
+                                sub_sets.push( arg_usize[0] );
+                            }
+                        },
+                        Linearity::Nonlinear => {
+                            sub_sets.extend_from_slice( &arg_usize );
+                        },
+                    }
+                    hes_set[*index] = set_vec.union( &sub_sets );
+                }
+            }
+        }
+        //
+        // pattern
+        let mut pattern : SparsityPattern = Vec::new();
+        for row in 0 .. n_dom {
+            if rev_jac[row] {
+                let set = set_vec.get( hes_set[row] );
+                for col in set {
+                    pattern.push( [row, *col] );
+                }
+            }
+        }
+        if trace {
+            println!( "n_pattern = {}", pattern.len() );
+            println!( "End Trace: hes_sparsity" );
+        }
+        pattern
+    }
+}
@@ -0,0 +1,207 @@
+// SPDX-License-Identifier: EPL-2.0 OR GPL-2.0-or-later
+// SPDX-FileCopyrightText: Bradley M. Bell <bradbell@seanet.com>
+// SPDX-FileContributor: 2026 Bradley M. Bell
+// ---------------------------------------------------------------------------
+//
+//! Implement the [ADfn] hes_vec method (Hessian times a vector).
+//!
+//! Link to [parent module](super)
+// ---------------------------------------------------------------------------
+// use
+//
+use crate::{
+    AD,
+    ADfn,
+    FConst,
+};
+use crate::op::info::sealed::GlobalOpFnsVec;
+use crate::tape::sealed::ThisThreadTape;
+//
+#[cfg(doc)]
+use crate::{
+    doc_generic_v,
+    doc_generic_e,
+};
+//
+// -----------------------------------------------------------------------
+// hes_vec
+/// Hessian times a vector using forward over reverse mode.
+///
+/// * Syntax :
+///   ```text
+///     hes_dir = f.hes_vec_value(dyp_all, &var_all, rng_weight, direction, opt_vec)
+///     hes_dir = f.hes_vec_ad(dyp_all, &var_all, rng_weight, direction, opt_vec)
+///   ```
+///
+/// * Prototype :
+///   see [ADfn::hes_vec_value] and [ADfn::hes_vec_ad]
+///
+/// * V : see [doc_generic_v]
+/// * E : see [doc_generic_e]
+/// * f : is an [ADfn] object.
+///
+/// * dyp_all  :
+///   If there are no dynamic parameters in f, this should be None
+///   or the empty vector.
+///   Otherwise it is the dynamic parameter sub-vectors in the following order:
+///   the domain dynamic parameters followed by the dependent dynamic parameters.
+///   This is normally computed by
+///   [forward_dyp](crate::adfn::forward_dyp::doc_forward_dyp) .
+///
+/// * var_all  :
+///   is both the variable sub-vectors in the following order:
+///   the domain variables followed by the dependent variables.
+///   This is normally computed by
+///   [forward_var](crate::adfn::forward_var::doc_forward_var) .
+///
+/// * rng_weight :
+///   This vector has length equal to the range dimension for f.
+///   The Hessian is for the scalar function
+///   ```text
+///     s(x) = sum_i rng_weight[i] * f_i(x)
+///   ```
+///
+/// * direction :
+///   This vector has length equal to the number of domain variables
+///   and is the vector that the Hessian is multiplied by.
+///
+/// * opt_vec :
+///   is an [opt_vec](crate::doc_opt_vec) with the following possible keys:
+///
+///   * trace
+///     The corresponding value must be true of false (default is false).
+///     If it is true, a trace of the forward and reverse sweeps is
+///     printed on stdout.
+///
+/// * hes_dir :
+///   The return value has length equal to the number of domain variables
+///   and is the Hessian of s(x) times *direction* .
+///
+/// * Method :
+///   This uses first order
+///   [forward_taylor](crate::adfn::forward_taylor::doc_forward_taylor)
+///   in the direction followed by first order
+///   [reverse_taylor](crate::adfn::reverse_taylor::doc_reverse_taylor) ;
+///   i.e., it does not record a new function.
+///   The partials of the first order coefficients with respect to the
+///   zero order coefficients are the forward mode derivative, in the
+///   direction, of the first order reverse sweep; i.e., this is
+///   forward over reverse mode.
+///   The per operator forward_der and reverse_der functions are not used
+///   because they are only evaluated using V or `AD<V>` .
+///   The forward derivative of a reverse_der sweep would require
+///   evaluating reverse_der_ad while recording a new function.
+///
+/// * Atomic Functions :
+///   Atomic functions only provide first order derivatives.
+///   If f contains an atomic function call, hes_vec will panic.
+///   In this case, record a function that computes the gradient of s(x)
+///   using [reverse_der_ad](crate::adfn::reverse_der::doc_reverse_der)
+///   and use its derivative in the direction.
+///
+/// # Example
+/// ```
+/// use rustad::start_recording;
+/// use rustad::stop_recording;
+/// use rustad::FUnary;
+///
+/// // V
+/// type V = rustad::AzFloat<f64>;
+/// //
+/// // f
+/// // f(x) = [ x[0] * x[1] , sin( x[1] ) ]
+/// let x    : Vec<V>   = vec![ V::from(1.0), V::from(1.0) ];
+/// let (_, ax)         = start_recording(None, x);
+/// let ay_0            = &ax[0] * &ax[1];
+/// let ay_1            = FUnary::sin( &ax[1] );
+/// let f               = stop_recording( vec![ay_0, ay_1] );
+/// //
+/// // hes_dir
+/// let opt_vec : Vec<[&str; 2]> = Vec::new();
+/// let x      : Vec<V> = vec![ V::from(2.0), V::from(3.0) ];
+/// let (_, var_all)    = f.forward_var_value(None, x.clone(), &opt_vec);
+/// let rng_weight      = vec![ V::from(4.0), V::from(5.0) ];
+/// let direction       = vec![ V::from(6.0), V::from(7.0) ];
+/// let hes_dir         = f.hes_vec_value(
+///     None, &var_all, rng_weight, direction, &opt_vec
+/// );
+/// //
+/// // check
+/// // s''(x) = [ 0  , 4                   ]
+/// //          [ 4  , - 5 * sin( x[1] )   ]
+/// let check_1 = V::from(4.0 * 6.0) - V::from(5.0 * 7.0) * x[1].sin();
+/// assert_eq!( hes_dir[0], V::from(4.0 * 7.0) );
+/// assert_eq!( hes_dir[1], check_1 );
+/// ```
+///
+pub fn doc_hes_vec() { }
+//
+/// Create the Hessian times a vector member functions.
+///
+/// * suffix : is either `value` or `ad` ;
+/// * E      : see [doc_generic_e] .
+///
+/// If *suffix* is `value` , *E must be be the value type *V* .
+/// If *suffix* is `ad` , *E must be be the type `AD<V>` .
+///
+/// See [doc_hes_vec]
+macro_rules! hes_vec {
+    ( $suffix:ident, $E:ty ) => { paste::paste! {
+        #[doc = concat!(
+            " `", stringify!($E), "` evaluation of Hessian times a vector; ",
+            "see [doc_hes_vec]",
+        )]
+        pub fn [< hes_vec_ $suffix >] (
+            &self,
+            dyp_all     : Option< &Vec<$E> >  ,
+            var_all     : &[$E]               ,
+            rng_weight  : Vec<$E>             ,
+            direction   : Vec<$E>             ,
+            opt_vec     : &Vec<[&str; 2]>     ,
+        ) -> Vec<$E>
+        {
+            // opt_vec
+            // the value for trace is checked by forward_taylor
+            for opt in opt_vec {
+                match opt[0] {
+                    "trace" => { },
+                    _ => panic!("hes_vec opt_vec: invalid key"),
+                }
+            }
+            //
+            // n_dom
+            let n_dom = self.var.n_dom;
+            assert_eq!(
+                direction.len(), n_dom,
+                "f.hes_vec: direction length is not f.var_dom_len()"
+            );
+            //
+            // var_taylor
+            let (_, var_taylor) = self.[< forward_taylor_ $suffix >](
+                dyp_all, var_all, 1, direction, opt_vec
+            );
+            //
+            // dom_partial
+            let dom_partial = self.[< reverse_taylor_ $suffix >](
+                dyp_all, &var_taylor, 1, rng_weight, opt_vec
+            );
+            //
+            // hes_dir
+            let mut hes_dir : Vec<$E> = Vec::with_capacity(n_dom);
+            for j in 0 .. n_dom {
+                hes_dir.push( dom_partial[j * 2].clone() );
+            }
+            hes_dir
+        }
+    }
+} }
+//
+impl<V> ADfn<V> where
+V : Clone + std::fmt::Display + GlobalOpFnsVec + FConst + ThisThreadTape,
+for<'a> V     : std::ops::AddAssign<&'a V>,
+for<'a> AD<V> : std::ops::AddAssign<&'a AD<V> >,
+{   //
+    // hes_vec
+    hes_vec!( value, V );
+    hes_vec!( ad,    AD::<V> );
+}
@@ -12,13 +12,24 @@
 pub mod forward_dyp;
 pub mod forward_var;
 pub mod forward_der;
+pub mod forward_taylor;
 pub mod reverse_der;
+pub mod reverse_taylor;
 pub mod sub_sparsity;
 pub mod for_sparsity;
+pub mod hes_sparsity;
+pub mod hes_vec;
 pub mod for_sparse_jac;
 pub mod rev_sparse_jac;
+pub mod sparse_hes;
 pub mod subgraph_der;
 pub mod rust_src;
+pub mod to_bytes;
+pub mod to_text;
+pub(crate) mod check_graph;
+pub mod to_dot;
+pub mod abs_normal;
+pub mod to_interval;
 pub mod optimize;
 //
 // ---------------------------------------------------------------------------
@@ -12,6 +12,7 @@
 use crate::{
     ADfn,
     IndexT,
+    FBytes,
 };
 use crate::tape::AGraph;
 use crate::adfn::optimize::Depend;
@@ -21,11 +22,15 @@ use rustc_hash::FxHashMap;
 // ADfn::compress_cop
 impl<V> ADfn<V>
 where
-    V : Clone + Eq + std::fmt::Display + std::hash::Hash,
+    V : Clone + FBytes + std::fmt::Display,
 {   //
     // compress_cop
     /// For each constant, replace its use by the first equal constant.
     ///
+    /// Two constants are equal if they have the same binary representation;
+    /// see [FBytes] . Hence -0 and +0 are not equal, and nan is equal to
+    /// a nan with the same binary representation.
+    ///
     /// * Syntax :
     ///   ```text
     ///     f.compress_cop(depend, trace)
@@ -54,8 +59,15 @@ where
         // n_cop
         let n_cop = self.cop_len();
         //
+        // key
+        let key = |value : &V| {
+            let mut bytes : Vec<u8> = Vec::new();
+            value.to_bytes(&mut bytes);
+            bytes
+        };
+        //
         // hash_map
-        let mut hash_map : FxHashMap<V, IndexT> = FxHashMap::default();
+        let mut hash_map : FxHashMap<Vec<u8>, IndexT> = FxHashMap::default();
         //
         if trace {
             println!("Begin Trace compress_cop");
@@ -65,15 +77,15 @@ where
         // hash_map, depend.cop,
         for i_cop in 0 .. n_cop {
             if depend.cop[i_cop] {
-                let key = &self.cop[i_cop];
-                if ! hash_map.contains_key(key) {
-                    hash_map.insert(key.clone(), i_cop as IndexT );
+                let bytes = key( &self.cop[i_cop] );
+                if ! hash_map.contains_key(&bytes) {
+                    hash_map.insert(bytes.clone(), i_cop as IndexT );
                 } else {
                     depend.cop[i_cop] = false;
                 }
                 if trace {
-                    let index = hash_map.get(key).unwrap();
-                    println!("{}, {}, {}", key, i_cop, index);
+                    let index = hash_map.get(&bytes).unwrap();
+                    println!("{}, {}, {}", self.cop[i_cop], i_cop, index);
                 }
             }
         }
@@ -81,8 +93,9 @@ where
         // self.rng_index
         for i in 0 .. self.rng_index.len() {
             if self.rng_ad_type[i].is_constant() {
-                let key           = &self.cop[ self.rng_index[i] as usize ];
-                let index         = hash_map.get(key).unwrap();
+                let i_cop         = self.rng_index[i] as usize;
+                let bytes         = key( &self.cop[i_cop] );
+                let index         = hash_map.get(&bytes).unwrap();
                 self.rng_index[i] = *index;
             }
         }
@@ -110,8 +123,9 @@ where
                     let arg_type   = &agraph.arg_type_all[start .. end];
                     for i_arg in 0 .. arg.len() {
                         if arg_type[i_arg].is_constant() {
-                            let key    = &self.cop[ arg[i_arg] as usize ];
-                            let index  = hash_map.get(key).unwrap();
+                            let i_cop  = arg[i_arg] as usize;
+                            let bytes  = key( &self.cop[i_cop] );
+                            let index  = hash_map.get(&bytes).unwrap();
                             arg[i_arg] = *index;
                         }
                     }
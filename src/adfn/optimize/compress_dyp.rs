@@ -24,7 +24,7 @@ use crate::adfn::optimize::{
 // ADfn::compress_dyp
 impl<V> ADfn<V>
 where
-    V : Clone + Eq + std::fmt::Display + std::hash::Hash,
+    V : Clone + std::fmt::Display,
 {   //
     // compress_dyp
     /// For each dynamic parameter, replace its use by the first
@@ -24,7 +24,7 @@ use crate::adfn::optimize::{
 // ADfn::compress_var
 impl<V> ADfn<V>
 where
-    V : Clone + Eq + std::fmt::Display + std::hash::Hash,
+    V : Clone + std::fmt::Display,
 {   //
     // compress_var
     /// For each variable, replace its use by the first
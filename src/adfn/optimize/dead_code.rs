@@ -10,7 +10,7 @@
 // use
 //
 use crate::{
-    FConst,
+    FValue,
     ADfn,
     IndexT,
 };
@@ -25,6 +25,14 @@ use crate::op::id::{
     CALL_OP,
     CALL_RES_OP,
     ZERO_ONE_OP,
+    PRINT_OP,
+    CEXP_OP,
+    LOAD_OP,
+    STORE_OP,
+};
+use crate::op::cexp::{
+    BEGIN_COND,
+    NUMBER_ARG,
 };
 use crate::op::call::{
     BEGIN_DOM,
@@ -142,7 +150,8 @@ fn new_binary_op(
     new_agraph   : &mut AGraph ,
     trace        : bool            ,
 ) {
-    assert_eq!( arg.len(), 2);
+    // The numerical comparison operators have a third argument.
+    assert!( arg.len() == 2 || arg.len() == 3 );
     //
     // new_op_index
     let new_op_index = new_agraph.id_all.len();
@@ -153,7 +162,7 @@ fn new_binary_op(
     new_agraph.arg_start.push( new_agraph.arg_all.len() as IndexT );
     //
     // new_agraph: arg_all, arg_type_all
-    for i_arg in 0 .. 2 {
+    for i_arg in 0 .. arg.len() {
         let arg_type_i = arg_type[i_arg];
         let old_index = arg[i_arg] as usize;
         let option    = get_old2new( old2new, &arg_type_i, old_index );
@@ -226,6 +235,152 @@ fn new_zero_one_op(
     set_old2new(old2new, i_agraph, old_index, new_index, trace);
 }
 // -----------------------------------------------------------------------
+// new_print_op
+#[allow(clippy::too_many_arguments)]
+fn new_print_op(
+    old2new      : &mut Old2New    ,
+    i_agraph     : usize           ,
+    op_id        : u8              ,
+    arg          : &[IndexT]       ,
+    arg_type     : &[ADType]       ,
+    old_op_index : usize           ,
+    old_agraph   : &AGraph         ,
+    new_agraph   : &mut AGraph     ,
+    trace        : bool            ,
+) {
+    assert_eq!( arg.len(), 4);
+    for arg_type_i in arg_type.iter().take(2) {
+        debug_assert!( *arg_type_i == ADType::Empty );
+    }
+    //
+    // new_op_index
+    let new_op_index = new_agraph.id_all.len();
+    //
+    // new_agraph: id_all, arg_start, n_dep
+    new_agraph.n_dep += 1;
+    new_agraph.id_all.push( op_id );
+    new_agraph.arg_start.push( new_agraph.arg_all.len() as IndexT );
+    //
+    // new_agraph: arg_all, str_all
+    new_agraph.arg_all.push( new_agraph.str_all.len() as IndexT );
+    let old_str_start   = arg[0] as usize;
+    let old_str_end     = arg[1] as usize;
+    new_agraph.str_all += &old_agraph.str_all[old_str_start .. old_str_end];
+    new_agraph.arg_all.push( new_agraph.str_all.len() as IndexT );
+    new_agraph.arg_type_all.push( ADType::Empty );
+    new_agraph.arg_type_all.push( ADType::Empty );
+    //
+    // new_agraph: arg_all, arg_type_all
+    for i_arg in 2 .. 4 {
+        let ad_type   = arg_type[i_arg];
+        let new_index = if ad_type == ADType::Empty {
+            arg[i_arg]
+        } else {
+            let old_index = arg[i_arg] as usize;
+            get_old2new( old2new, &ad_type, old_index ).unwrap()
+        };
+        new_agraph.arg_all.push( new_index );
+        new_agraph.arg_type_all.push( ad_type );
+    }
+    //
+    // old2new
+    let new_index    = new_op_index + new_agraph.n_dom;
+    let old_index    = old_op_index + old_agraph.n_dom;
+    set_old2new(old2new, i_agraph, old_index, new_index, trace);
+}
+// -----------------------------------------------------------------------
+// new_cexp_op
+#[allow(clippy::too_many_arguments)]
+fn new_cexp_op(
+    old2new      : &mut Old2New    ,
+    i_agraph     : usize           ,
+    arg          : &[IndexT]       ,
+    arg_type     : &[ADType]       ,
+    old_op_index : usize           ,
+    old_agraph   : &AGraph         ,
+    new_agraph   : &mut AGraph     ,
+    trace        : bool            ,
+) {
+    assert_eq!( arg.len(), NUMBER_ARG );
+    //
+    // new_op_index
+    let new_op_index = new_agraph.id_all.len();
+    //
+    // new_agraph: id_all, arg_start, n_dep
+    new_agraph.n_dep += 1;
+    new_agraph.id_all.push( CEXP_OP );
+    new_agraph.arg_start.push( new_agraph.arg_all.len() as IndexT );
+    //
+    // new_agraph: arg_all, arg_type_all, bool_all
+    new_agraph.arg_all.push( arg[0] );
+    new_agraph.arg_all.push( new_agraph.bool_all.len() as IndexT );
+    new_agraph.arg_type_all.push( ADType::Empty );
+    new_agraph.arg_type_all.push( ADType::Empty );
+    let old_bool_start = arg[1] as usize;
+    for i in 0 .. 2 {
+        new_agraph.bool_all.push( old_agraph.bool_all[old_bool_start + i] );
+    }
+    //
+    // new_agraph: arg_all, arg_type_all
+    for i_arg in BEGIN_COND .. NUMBER_ARG {
+        let old_index  = arg[i_arg] as usize;
+        let ad_type    = arg_type[i_arg];
+        let option     = get_old2new(old2new, &ad_type, old_index);
+        if let Some(new_index) = option {
+            new_agraph.arg_all.push( new_index );
+            new_agraph.arg_type_all.push( ad_type );
+        } else {
+            // A branch that was not selected during the recording
+            // gets optimized out when the cexp option is recorded.
+            new_agraph.arg_all.push( 0 ); // nan
+            new_agraph.arg_type_all.push( ADType::ConstantP );
+        }
+    }
+    //
+    // old2new
+    let new_index    = new_op_index + new_agraph.n_dom;
+    let old_index    = old_op_index + old_agraph.n_dom;
+    set_old2new(old2new, i_agraph, old_index, new_index, trace);
+}
+// -----------------------------------------------------------------------
+// new_vec_ad_op
+// Every argument of a load or store operator is a typed index.
+#[allow(clippy::too_many_arguments)]
+fn new_vec_ad_op(
+    old2new      : &mut Old2New    ,
+    i_agraph     : usize           ,
+    op_id        : u8              ,
+    arg          : &[IndexT]       ,
+    arg_type     : &[ADType]       ,
+    old_op_index : usize           ,
+    old_agraph   : &AGraph         ,
+    new_agraph   : &mut AGraph     ,
+    trace        : bool            ,
+) {
+    //
+    // new_op_index
+    let new_op_index = new_agraph.id_all.len();
+    //
+    // new_agraph: id_all, arg_start, n_dep
+    new_agraph.n_dep += 1;
+    new_agraph.id_all.push( op_id );
+    new_agraph.arg_start.push( new_agraph.arg_all.len() as IndexT );
+    //
+    // new_agraph: arg_all, arg_type_all
+    for i_arg in 0 .. arg.len() {
+        let old_index  = arg[i_arg] as usize;
+        let ad_type    = arg_type[i_arg];
+        let option     = get_old2new(old2new, &ad_type, old_index);
+        new_agraph.arg_all.push( option.unwrap() );
+        new_agraph.arg_type_all.push( ad_type );
+    }
+    //
+    // old2new
+    let new_index    = new_op_index + new_agraph.n_dom;
+    let old_index    = old_op_index + old_agraph.n_dom;
+    set_old2new(old2new, i_agraph, old_index, new_index, trace);
+}
+// -----------------------------------------------------------------------
 #[allow(clippy::too_many_arguments)]
 fn new_call_op(
     old2new          : &mut Old2New    ,
@@ -340,12 +495,11 @@ where
     pub(crate) fn dead_code(&self, depend : &Depend, trace : bool,
     ) -> ( Tape<V>, Old2New)
     where
-        V : Clone + FConst + PartialEq ,
+        V : Clone + FValue ,
     {
         //
         // self.cop[0]
-        let nan       = V::nan();
-        assert!( nan == self.cop[0] );
+        assert!( self.cop[0].is_nan() );
         //
         // tape
         let mut tape : Tape<V> = Tape::default();
@@ -465,6 +619,39 @@ where
                         );
                     }
                     old_op_index += 1;
+                } else if op_id == CEXP_OP {
+                    if old_depend[old_res] {
+                        //
+                        // old2new, new_agraph
+                        new_cexp_op(
+                            &mut old2new,
+                            i_agraph,
+                            arg,
+                            arg_type,
+                            old_op_index,
+                            old_agraph,
+                            new_agraph,
+                            trace,
+                        );
+                    }
+                    old_op_index += 1;
+                } else if op_id == LOAD_OP || op_id == STORE_OP {
+                    if old_depend[old_res] {
+                        //
+                        // old2new, new_agraph
+                        new_vec_ad_op(
+                            &mut old2new,
+                            i_agraph,
+                            op_id,
+                            arg,
+                            arg_type,
+                            old_op_index,
+                            old_agraph,
+                            new_agraph,
+                            trace,
+                        );
+                    }
+                    old_op_index += 1;
                 } else if op_id == ZERO_ONE_OP  {
                     //
                     // old2new, new_agraph
@@ -480,6 +667,21 @@ where
                         trace,
                     );
                     old_op_index += 1;
+                } else if op_id == PRINT_OP  {
+                    //
+                    // old2new, new_agraph
+                    new_print_op(
+                        &mut old2new,
+                        i_agraph,
+                        op_id,
+                        arg,
+                        arg_type,
+                        old_op_index,
+                        old_agraph,
+                        new_agraph,
+                        trace,
+                    );
+                    old_op_index += 1;
                 } else if op_id == CALL_OP {
                     let bool_all       = &old_agraph.bool_all;
                     let n_rng          = arg[NUMBER_RNG] as usize;
@@ -11,6 +11,8 @@
 //
 use crate::{
     FConst,
+    FValue,
+    FBytes,
     ADfn,
     IndexT,
     GlobalAtomCallbackVecPublic,
@@ -135,7 +137,15 @@ pub(crate) fn renumber_agraph(
 ///     f.optimize(opt_vec)
 /// ```
 ///
-/// * V : see [doc_generic_v](crate::doc_generic_v)
+/// * V : see [doc_generic_v](crate::doc_generic_v) .
+///   In addition, V must implement [FBytes] .
+///   This replaces the Eq and Hash requirement in previous versions
+///   (f32 and f64 do not implement Eq or Hash), so a value type that
+///   implements Eq and Hash, but not FBytes, can no longer be optimized.
+///   Two constants in f are considered equal, and only one of them is kept,
+///   if they have the same binary representation (not if they are ==).
+///   Hence -0 and +0 are different constants, and two nans are the same
+///   constant only if their binary representations are the same.
 /// * f : is an [ADfn] object
 ///
 /// * opt_vec :
@@ -143,7 +153,16 @@ pub(crate) fn renumber_agraph(
 ///
 ///   * trace
 ///     The corresponding value must be true of false (default is false).
-///     If it is true, a trace of forward_der is printed on stdout.
+///     If it is true, a trace of optimize is printed on stdout.
+///
+///   * cexp
+///     The corresponding value must be both or recorded (default is both).
+///     If it is both, both branches of each conditional expression are kept;
+///     see [AD::select](crate::AD::select).
+///     If it is recorded, only the branches that were selected during the
+///     recording are kept and the other branches are replaced by nan.
+///     This can remove the operations that are only used by the branches
+///     not selected, but the result is nan if the comparison changes.
 ///
 /// # Example
 /// ```
@@ -175,14 +194,15 @@ pub(crate) fn renumber_agraph(
 /// ```
 impl<V> ADfn<V>
 where
-    V : Clone + FConst + Eq + std::fmt::Display + std::hash::Hash,
+    V : Clone + FConst + FValue + FBytes + std::fmt::Display,
     V : GlobalAtomCallbackVecPublic + GlobalOpFnsVecPublic,
 {   //
     // optimize
     pub fn optimize(&mut self, opt_vec : &Vec<[&str; 2]> )
     {   //
-        // trace
-        let mut trace = false;
+        // trace, cexp_recorded
+        let mut trace         = false;
+        let mut cexp_recorded = false;
         for opt in opt_vec {
             match opt[0] {
                 "trace" => {
@@ -190,16 +210,25 @@ where
                         "true"  => { trace = true; },
                         "false" => { trace = false; },
                         _ => { panic!(
-                        "forward_der opt_vec: invalid value for trace"
+                        "optimize opt_vec: invalid value for trace"
+                        ); }
+                    }
+                },
+                "cexp" => {
+                    match opt[1] {
+                        "both"     => { cexp_recorded = false; },
+                        "recorded" => { cexp_recorded = true; },
+                        _ => { panic!(
+                        "optimize opt_vec: invalid value for cexp"
                         ); }
                     }
                 },
-                _ => panic!("forward_der opt_vec: invalid key"),
+                _ => panic!("optimize opt_vec: invalid key"),
             }
         }
         //
         // depend
-        let mut depend = self.reverse_depend(trace);
+        let mut depend = self.reverse_depend(cexp_recorded, trace);
         //
         // self, depend
         self.compress_cop(&mut depend, trace);
@@ -208,7 +208,7 @@ impl OpHashMap {
             //
             // map_value_out
             let arg_match     = [arg_0, arg_1];
-            let key           = BinaryOp::new(op_id, arg_match, arg_type);
+            let key = BinaryOp::new(op_id, arg_match, &arg_type[0 .. 2]);
             let map_value_out =
                 self.binary_hash_map.entry(key).or_insert(map_value_in);
             return Some(*map_value_out);
@@ -20,6 +20,9 @@ use crate::op::{
     id::CALL_OP,
     id::CALL_RES_OP,
     id::ZERO_ONE_OP,
+    id::PRINT_OP,
+    id::CEXP_OP,
+    cexp::cexp_depend,
 };
 use crate::ad::ADType;
 use crate::tape::AGraph;
@@ -36,7 +39,16 @@ where
 {   //
     // reverse_depend
     /// Determine [optimize::Depend] for this [ADfn].
-    pub(crate) fn reverse_depend(&self, trace : bool) -> optimize::Depend {
+    ///
+    /// * cexp_recorded :
+    ///   If this is true, a conditional expression only depends on the
+    ///   branches that were selected during the recording.
+    ///
+    /// * trace :
+    ///   if true, a trace is printed on standard output.
+    pub(crate) fn reverse_depend(
+        &self, cexp_recorded : bool, trace : bool
+    ) -> optimize::Depend {
         //
         // atom_depend, cop_depend, dyp_depend, var_depend
         // work space used to avoid reallocationg vectors
@@ -115,7 +127,7 @@ where
                 // op_id, res_depend
                 let op_id      = agraph.id_all[op_index];
                 let res        = n_dom + op_index;
-                let res_depend = if op_id == ZERO_ONE_OP {
+                let res_depend = if op_id == ZERO_ONE_OP || op_id == PRINT_OP {
                     true
                 } else if i_agraph == 0 {
                     depend.var[res]
@@ -151,6 +163,14 @@ where
                         for dep_index in cop_depend.iter() {
                             depend.cop[*dep_index as usize] = true;
                         }
+                    } else if op_id == CEXP_OP {
+                        cexp_depend(
+                            &mut depend,
+                            bool_all,
+                            arg,
+                            arg_type,
+                            cexp_recorded,
+                        );
                     } else {
                         let reverse_depend =
                             op_fns_vec[op_id as usize].reverse_depend;
@@ -186,6 +206,7 @@ where
 }
 //
 #[cfg(test)]
+#[allow(clippy::let_and_return, clippy::needless_range_loop, clippy::vec_init_then_push)]
 mod tests {
     use crate::{
         AD,
@@ -211,7 +232,7 @@ mod tests {
     {   // range
         let mut range : Vec<V> = Vec::with_capacity( domain.len() );
         for i in 0 .. domain.len() {
-            range.push( domain[i].clone() );
+            range.push( *domain[i] );
         }
         Ok(range)
     }
@@ -237,7 +258,7 @@ mod tests {
         //
         // eye_callback
         let eye_callback = AtomCallback{
-            name               : &"eye",
+            name               : "eye",
             rev_depend         : Some(eye_rev_depend),
             forward_fun_value  : Some(eye_forward_fun_value) ,
             //
@@ -316,7 +337,7 @@ mod tests {
         f.forward_var_value(Some(&p_all), x, &opt_vec);
         //
         // depend
-        let depend = f.reverse_depend(trace);
+        let depend = f.reverse_depend(false, trace);
         //
         // depend.cop
         // TODO: There are four constants, but should only be two;
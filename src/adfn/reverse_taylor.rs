@@ -0,0 +1,303 @@
+// SPDX-License-Identifier: EPL-2.0 OR GPL-2.0-or-later
+// SPDX-FileCopyrightText: Bradley M. Bell <bradbell@seanet.com>
+// SPDX-FileContributor: 2026 Bradley M. Bell
+// ---------------------------------------------------------------------------
+//
+//! Implement the [ADfn] reverse_taylor method (partials of Taylor coefficients).
+//!
+//! Link to [parent module](super)
+// ---------------------------------------------------------------------------
+// use
+//
+use crate::{
+    AD,
+    ADfn,
+    FConst,
+};
+use crate::op::info::sealed::GlobalOpFnsVec;
+use crate::tape::sealed::ThisThreadTape;
+use crate::op::info::ConstData;
+use crate::adfn::forward_taylor::taylor_string;
+//
+#[cfg(doc)]
+use crate::{
+    doc_generic_v,
+    doc_generic_e,
+};
+//
+// -----------------------------------------------------------------------
+// reverse_taylor
+/// Arbitrary order reverse mode evaluation with dynamic parameters.
+///
+/// * Syntax :
+///   ```text
+///     dom_partial =
+///         f.reverse_taylor_value(dyp_all, &var_taylor, order, rng_weight, opt_vec)
+///     dom_partial =
+///         f.reverse_taylor_ad(dyp_all, &var_taylor, order, rng_weight, opt_vec)
+///   ```
+///
+/// * Prototype :
+///   see [ADfn::reverse_taylor_value] and [ADfn::reverse_taylor_ad]
+///
+/// * V : see [doc_generic_v]
+/// * E : see [doc_generic_e]
+/// * f : is an [ADfn] object.
+///
+/// * dyp_all  :
+///   If there are no dynamic parameters in f, this should be None
+///   or the empty vector.
+///   Otherwise it is the dynamic parameter sub-vectors in the following order:
+///   the domain dynamic parameters followed by the dependent dynamic parameters.
+///   This is normally computed by
+///   [forward_dyp](crate::adfn::forward_dyp::doc_forward_dyp) .
+///
+/// * var_taylor :
+///   is the Taylor coefficients for all the variables.
+///   This is normally computed by
+///   [forward_taylor](crate::adfn::forward_taylor::doc_forward_taylor)
+///   using the same value for order.
+///
+/// * order :
+///   is the highest order Taylor coefficient in var_taylor.
+///
+/// * rng_weight :
+///   Let n_rng be the number of range components.
+///   This vector has length n_rng and defines the scalar function
+///   ```text
+///     s = sum_{i=0}^{n_rng-1} rng_weight[i] * y_i[order]
+///   ```
+///   where y_i\[order\] is the order *order* Taylor coefficient
+///   for the i-th range component.
+///
+/// * opt_vec :
+///   is an [opt_vec](crate::doc_opt_vec) with the following possible keys:
+///
+///   * trace
+///     The corresponding value must be true of false (default is false).
+///     If it is true, a trace of reverse_taylor is printed on stdout.
+///
+/// * dom_partial :
+///   Let n_dom be the number of domain variables.
+///   This vector has length n_dom * (order + 1).
+///   For j < n_dom and k <= order,
+///   dom_partial\[ j * (order + 1) + k \] is the partial of s
+///   w.r.t. the order k Taylor coefficient for the j-th domain variable.
+///
+/// * Hessian Times a Vector :
+///   If order is one, the first order domain coefficients are a direction
+///   u, and w is the range weight vector,
+///   the zero order partials are the Hessian of w * f(x) times u.
+///
+/// * Atomic Functions :
+///   Atomic functions only provide first order derivatives.
+///   If f contains an atomic function call, order must be zero.
+///
+/// # Example
+/// Computing a Hessian times a vector :
+/// ```
+/// use rustad::start_recording;
+/// use rustad::stop_recording;
+///
+/// // V
+/// type V = rustad::AzFloat<f64>;
+/// //
+/// // f
+/// // f(x) = x[0] * x[0] * x[1]
+/// let x    : Vec<V>   = vec![ V::from(1.0), V::from(1.0) ];
+/// let (_, ax)         = start_recording(None, x);
+/// let asq             = &ax[0] * &ax[0];
+/// let ay              = vec![ &asq * &ax[1] ];
+/// let f               = stop_recording(ay);
+/// //
+/// // x(t) = [ 2 + 3 t , 4 + 5 t ]
+/// let order           = 1;
+/// let opt_vec : Vec<[&str; 2]> = Vec::new();
+/// let x      : Vec<V> = vec![ V::from(2.0), V::from(4.0) ];
+/// let (_, var)        = f.forward_var_value(None, x, &opt_vec);
+/// let dom_taylor      = vec![ V::from(3.0), V::from(5.0) ];
+/// let (_, var_taylor) = f.forward_taylor_value(
+///     None, &var, order, dom_taylor, &opt_vec
+/// );
+/// let rng_weight      = vec![ V::from(1.0) ];
+/// let dom_partial     = f.reverse_taylor_value(
+///     None, &var_taylor, order, rng_weight, &opt_vec
+/// );
+/// //
+/// // check
+/// // f''(x) = [ 2 * x[1] , 2 * x[0] ]
+/// //          [ 2 * x[0] ,    0     ]
+/// assert_eq!( dom_partial[0], V::from( 2.0 * 4.0 * 3.0 + 2.0 * 2.0 * 5.0 ) );
+/// assert_eq!( dom_partial[2], V::from( 2.0 * 2.0 * 3.0 ) );
+/// //
+/// // the first order partials are the gradient f'(x)
+/// assert_eq!( dom_partial[1], V::from( 2.0 * 2.0 * 4.0 ) );
+/// assert_eq!( dom_partial[3], V::from( 2.0 * 2.0 ) );
+/// ```
+///
+pub fn doc_reverse_taylor() { }
+//
+/// Create the arbitrary order reverse mode member functions.
+///
+/// * suffix : is either `value` or `ad` ;
+/// * E      : see [doc_generic_e] .
+///
+/// If *suffix* is `value` , *E must be be the value type *V* .
+/// If *suffix* is `ad` , *E must be be the type `AD<V>` .
+///
+/// See [doc_reverse_taylor]
+macro_rules! reverse_taylor {
+    ( $suffix:ident, $E:ty ) => { paste::paste! {
+        #[doc = concat!(
+            " `", stringify!($E),
+            "` evaluation of arbitrary order reverse mode; ",
+            "see [doc_reverse_taylor]",
+        )]
+        pub fn [< reverse_taylor_ $suffix >] (
+            &self,
+            dyp_all     : Option< &Vec<$E> >  ,
+            var_taylor  : &[$E]               ,
+            order       : usize               ,
+            rng_weight  : Vec<$E>             ,
+            opt_vec     : &Vec<[&str; 2]>     ,
+        ) -> Vec<$E>
+        {
+            // trace
+            let mut trace = false;
+            for opt in opt_vec {
+                match opt[0] {
+                    "trace" => {
+                        match opt[1] {
+                            "true"  => { trace = true; },
+                            "false" => { trace = false; },
+                            _ => { panic!(
+                            "reverse_taylor opt_vec: invalid value for trace"
+                            ); }
+                        }
+                    },
+                    _ => panic!("reverse_taylor opt_vec: invalid key"),
+                }
+            }
+            //
+            // dyp_all
+            let dyp_all  : &Vec<$E> = match dyp_all {
+                None          => &Vec::new(),
+                Some(dyp_all) => dyp_all,
+            };
+            //
+            // n_var
+            let n_var = self.var.n_dom + self.var.n_dep;
+            //
+            // n_tay
+            let n_tay = order + 1;
+            //
+            assert_eq!(
+                rng_weight.len(), self.rng_ad_type.len(),
+                "f.reverse_taylor: rng_weight length does not match f"
+            );
+            assert_eq!(
+                var_taylor.len(), n_var * n_tay,
+                "f.reverse_taylor: var_taylor length is not n_var * (order + 1)"
+            );
+            //
+            // op_fns_vec
+            let op_fns_vec = GlobalOpFnsVec::get();
+            //
+            // var_par
+            let mut var_par = vec![ $E::zero(); n_var * n_tay ];
+            for i in 0 .. self.rng_ad_type.len() {
+                if self.rng_ad_type[i].is_variable() {
+                    let index = self.rng_index[i] as usize;
+                    var_par[index * n_tay + order] += &rng_weight[i];
+                }
+            }
+            //
+            if trace {
+                println!( "Begin Trace: reverse_taylor: n_var = {}, order = {}",
+                    n_var, order
+                );
+                println!( "index, bool" );
+                for j in 0 .. self.var.bool_all.len() {
+                    println!( "{}, {:?}", j, self.var.bool_all[j] );
+                }
+                println!( "index, constant" );
+                for j in 0 .. self.cop.len() {
+                    println!( "{}, {}", j, self.cop[j] );
+                }
+                println!( "index, dyp_all" );
+                for j in 0 .. dyp_all.len() {
+                    println!( "{}, {}", j, dyp_all[j] );
+                }
+                println!( "rng_index, var_index, rng_weight" );
+                for i in 0 .. self.rng_ad_type.len() {
+                    if self.rng_ad_type[i].is_variable() {
+                        let index = self.rng_index[i] as usize;
+                        println!( "{}, {}, {}", i, index, rng_weight[i] );
+                    }
+                }
+                println!( "var_index, var_partial, op_name, arg" );
+            }
+            //
+            // cop, bool_all
+            let cop      = &self.cop;
+            let bool_all = &self.var.bool_all;
+            let str_all  = &self.var.str_all;
+            //
+            // var_par
+            for op_index in ( 0 .. self.var.id_all.len() ).rev() {
+                let op_id     = self.var.id_all[op_index] as usize;
+                let start     = self.var.arg_start[op_index] as usize;
+                let end       = self.var.arg_start[op_index + 1] as usize;
+                //
+                let arg       = &self.var.arg_all[start .. end];
+                let arg_type  = &self.var.arg_type_all[start .. end];
+                let res       = self.var.n_dom + op_index;
+                //
+                let const_data = ConstData {
+                    cop, bool_all, str_all, arg, arg_type, res
+                };
+                //
+                if trace {
+                    let name    = &op_fns_vec[op_id].name;
+                    let partial = &var_par[res * n_tay .. (res + 1) * n_tay];
+                    println!( "{}, {}, {}, {:?}",
+                        res, taylor_string(partial), name, arg
+                    );
+                }
+                let reverse_taylor =
+                    op_fns_vec[op_id].[< reverse_taylor_ $suffix >];
+                reverse_taylor(
+                    dyp_all,
+                    var_taylor,
+                    &mut var_par,
+                    n_tay,
+                    const_data,
+                );
+            }
+            if trace {
+                println!( "var_index, dom_partial" );
+                for j in 0 .. self.var.n_dom {
+                    let partial = &var_par[j * n_tay .. (j + 1) * n_tay];
+                    println!( "{}, {}", j, taylor_string(partial) );
+                }
+                println!( "End Trace: reverse_taylor" );
+            }
+            //
+            // dom_partial
+            let mut dom_partial = var_par;
+            dom_partial.truncate(self.var.n_dom * n_tay);
+            dom_partial.shrink_to_fit();
+            dom_partial
+        }
+    }
+} }
+//
+impl<V> ADfn<V> where
+V : Clone + std::fmt::Display + GlobalOpFnsVec + FConst + ThisThreadTape,
+for<'a> V     : std::ops::AddAssign<&'a V>,
+for<'a> AD<V> : std::ops::AddAssign<&'a AD<V> >,
+{   //
+    // reverse_taylor
+    reverse_taylor!( value, V );
+    reverse_taylor!( ad,    AD::<V> );
+}
@@ -57,7 +57,10 @@ pub type RustSrcFn<V> = fn(
 // prototype_src
 fn prototype_src(fn_name : &str, v_str : &str) -> String {
     let mut src = String::new();
-    for name in [ "traits", "az_float", "num_vec" ] {
+    for name in [
+        "traits", "az_float", "num_vec", "complex", "interval", "primitive",
+        "double_double", "erf",
+    ] {
         src = src + "#[allow(unused)]\n" + &format!( "pub mod {name};\n" );
     }
     src = src +
@@ -67,9 +70,15 @@ fn prototype_src(fn_name : &str, v_str : &str) -> String {
         "   traits::FConst,\n" +
         "   traits::FUnary,\n" +
         "   traits::FBinary,\n" +
+        "   traits::FReduce,\n" +
         "   traits::FValue,\n" +
+        "   traits::FBytes,\n" +
+        "   traits::FText,\n" +
         "   az_float::AzFloat,\n" +
         "   num_vec::NumVec,\n" +
+        "   complex::Complex,\n" +
+        "   interval::Interval,\n" +
+        "   double_double::DoubleDouble,\n" +
         "};\n" +
         "#[allow(unused)]\n" +
         "use std::ops::{\n" +
@@ -122,6 +131,15 @@ where
         let v_str   = v_str.replace(
             "rustad::float::num_vec::NumVec", "NumVec"
         );
+        let v_str   = v_str.replace(
+            "rustad::float::complex::Complex", "Complex"
+        );
+        let v_str   = v_str.replace(
+            "rustad::float::interval::Interval", "Interval"
+        );
+        let v_str   = v_str.replace(
+            "rustad::float::double_double::DoubleDouble", "DoubleDouble"
+        );
         //
         // prototype
         let mut src = prototype_src(fn_name, &v_str);
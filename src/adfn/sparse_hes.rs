@@ -0,0 +1,301 @@
+// SPDX-License-Identifier: EPL-2.0 OR GPL-2.0-or-later
+// SPDX-FileCopyrightText: Bradley M. Bell <bradbell@seanet.com>
+// SPDX-FileContributor: 2026 Bradley M. Bell
+// ---------------------------------------------------------------------------
+//
+//! Implement the [ADfn] sparse Hessian methods.
+//!
+//! Link to [parent module](super)
+// ---------------------------------------------------------------------------
+// use
+//
+use crate::{
+    AD,
+    ADfn,
+    FConst,
+    SparsityPattern,
+};
+use crate::op::info::sealed::GlobalOpFnsVec;
+use crate::tape::sealed::ThisThreadTape;
+//
+#[cfg(doc)]
+use crate::{
+    doc_generic_v,
+    doc_generic_e,
+    star_coloring,
+};
+//
+// -----------------------------------------------------------------------
+// sparse_hes
+/// Sparse Hessian evaluation using forward over reverse mode.
+///
+/// * Syntax :
+///   ```text
+///     hessian = f.sparse_hes_value(
+///         dyp_all, &var_all, rng_weight, &pattern, &sub_pattern, &color_vec,
+///         opt_vec
+///     )
+///     hessian = f.sparse_hes_ad(
+///         dyp_all, &var_all, rng_weight, &pattern, &sub_pattern, &color_vec,
+///         opt_vec
+///     )
+///   ```
+///
+/// * Prototype :
+///   see [ADfn::sparse_hes_value] and  [ADfn::sparse_hes_ad]
+///
+/// * V : see [doc_generic_v]
+/// * E : see [doc_generic_e]
+/// * f : is an [ADfn] object.
+///
+/// * dyp_all  :
+///   If there are no dynamic parameters in f, this should be None
+///   or the empty vector.
+///   Otherwise it is the dynamic parameter sub-vectors in the following order:
+///   domain dynamic parameters followed by dependent dynamic parameters.
+///   This is normally computed by
+///   [forward_dyp](crate::adfn::forward_dyp::doc_forward_dyp) .
+///
+/// * var_all  :
+///   is both the variable sub-vectors in the following order:
+///   the domain variables followed by the dependent variables.
+///   This is normally computed by
+///   [forward_var](crate::adfn::forward_var::doc_forward_var) .
+///
+/// * rng_weight :
+///   This vector has length equal to the range dimension for f.
+///   The Hessian is for the scalar function
+///   ```text
+///     s(x) = sum_i rng_weight[i] * f_i(x)
+///   ```
+///
+/// * pattern :
+///   This is a sparsity pattern for the Hessian of s(x); e.g.,
+///   it can be computed using [ADfn::hes_sparsity] .
+///
+/// * sub_pattern :
+///   This is the subset of *pattern* that we wish to calculate.
+///
+/// * color_vec :
+///   This is a symmetric coloring for the Hessian of s(x) evaluated
+///   on the subset specified by *sub_pattern*; e.g., it can be computed using
+///   [star_coloring] .
+///
+/// * opt_vec :
+///   is an [opt_vec](crate::doc_opt_vec) with the following possible keys:
+///
+///   * trace
+///     The corresponding value must be true of false (the default is false).
+///     If it is true, a trace of sparse_hes is printed on stdout.
+///
+/// * hessian :
+///   The return is the Hessian on the subset sparsity pattern.
+///   To be specific, it has the same length as *sub_pattern* and for each k,
+///   `hessian[k]` is the Hessian of s(x) at row index `sub_pattern[k][0]`
+///   and column index `sub_pattern[k][1]` .
+///
+/// * Method :
+///   For each color, the domain direction that is one (zero) for the
+///   indices with (without) that color is used to compute a
+///   [Hessian times a vector](crate::adfn::hes_vec::doc_hes_vec) .
+///   The star coloring property is used to determine which of these
+///   products each entry in *sub_pattern* is recovered from.
+///
+/// * Atomic Functions :
+///   Atomic functions only provide first order derivatives.
+///   If f contains an atomic function call, sparse_hes will panic.
+///
+/// # Example
+/// ```
+/// use rustad::start_recording;
+/// use rustad::stop_recording;
+/// use rustad::star_coloring;
+/// //
+/// // V
+/// type V = rustad::AzFloat<f64>;
+/// //
+/// // f
+/// // f(x) = x[0] * x[1] + x[1] * x[2] + x[2] * x[2]
+/// let x    : Vec<V>  = vec![ V::from(1.0); 3 ];
+/// let (_, ax)        = start_recording(None, x);
+/// let aterm_0        = &ax[0] * &ax[1];
+/// let aterm_1        = &ax[1] * &ax[2];
+/// let aterm_2        = &ax[2] * &ax[2];
+/// let ay             = vec![ &( &aterm_0 + &aterm_1 ) + &aterm_2 ];
+/// let f              = stop_recording(ay);
+/// //
+/// // pattern, color_vec
+/// let opt_vec : Vec<[&str; 2]> = Vec::new();
+/// let pattern   = f.hes_sparsity( &[true], &opt_vec );
+/// let color_vec = star_coloring(3, &pattern, &pattern);
+/// //
+/// // hessian
+/// let x      : Vec<V> = vec![ V::from(2.0), V::from(3.0), V::from(4.0) ];
+/// let (_, var_all)    = f.forward_var_value(None, x, &opt_vec);
+/// let rng_weight      = vec![ V::from(1.0) ];
+/// let hessian         = f.sparse_hes_value(
+///     None, &var_all, rng_weight, &pattern, &pattern, &color_vec, &opt_vec
+/// );
+/// //
+/// // check
+/// assert_eq!( pattern, vec![ [0, 1], [1, 0], [1, 2], [2, 1], [2, 2] ] );
+/// for k in 0 .. pattern.len() {
+///     let [i, j] = pattern[k];
+///     let check  = if i == j { 2.0 } else { 1.0 };
+///     assert_eq!( hessian[k], V::from(check) );
+/// }
+/// ```
+pub fn doc_sparse_hes() {}
+//
+/// Create the sparse_hes functions
+///
+/// * suffix : is either `value` or `ad` ;
+/// * E      : see [doc_generic_e] .
+///
+/// If *suffix* is `value` , *E must be be the value type *V* .
+/// If *suffix* is `ad` , *E must be be the type `AD<V>` .
+///
+/// See [doc_sparse_hes]
+macro_rules! sparse_hes {
+    ($suffix:ident, $E:ty) => {paste::paste! {
+        #[doc = concat!(
+            "`", stringify!($E), "` evaluation of of sparse Hessians; ",
+            "see [doc_sparse_hes]",
+        )]
+        pub fn [< sparse_hes_ $suffix >] (
+            &self,
+            dyp_all      : Option< &Vec<$E> >  ,
+            var_all      : &[$E]               ,
+            rng_weight   : Vec<$E>             ,
+            pattern      : &SparsityPattern    ,
+            sub_pattern  : &SparsityPattern    ,
+            color_vec    : &[usize]            ,
+            opt_vec      : &Vec<[&str; 2]>     ,
+        ) -> Vec<$E>
+        {   //
+            // trace
+            let mut trace = false;
+            for opt in opt_vec {
+                match opt[0] {
+                    "trace" => {
+                        match opt[1] {
+                            "true"  => { trace = true; },
+                            "false" => { trace = false; },
+                            _ => { panic!(
+                            "sparse_hes opt_vec: invalid value for trace"
+                            ); }
+                        }
+                    },
+                    _ => panic!("sparse_hes opt_vec: invalid key"),
+                }
+            }
+            //
+            // n
+            let n = self.var_dom_len();
+            assert_eq!(
+                color_vec.len(), n,
+                "f.sparse_hes: color_vec length is not f.var_dom_len()"
+            );
+            //
+            // n_color
+            let n_color = color_vec.iter().filter(|&k| k < &n ).max()
+                .map_or(0, |k| k + 1);
+            //
+            // neighbor
+            // neighbor[i] is the indices, in the colored graph,
+            // that are connected to i by an off diagonal entry in pattern.
+            let mut neighbor : Vec< Vec<usize> > = vec![ Vec::new(); n ];
+            for [i, j] in pattern {
+                if i != j && color_vec[*i] < n && color_vec[*j] < n {
+                    neighbor[*i].push( *j );
+                    neighbor[*j].push( *i );
+                }
+            }
+            //
+            // recover
+            // The sub_pattern[ell] entry is recovered from component
+            // recover[ell][0] of the Hessian times the direction for
+            // color recover[ell][1] .
+            let mut recover : Vec<[usize; 2]> = Vec::new();
+            for [i, j] in sub_pattern {
+                let (i, j) = (*i, *j);
+                assert!( color_vec[i] < n && color_vec[j] < n,
+                    "f.sparse_hes: color_vec is not for this sub_pattern"
+                );
+                let other = neighbor[i].iter().any(
+                    |k| *k != j && color_vec[*k] == color_vec[j]
+                );
+                if other {
+                    recover.push( [j, color_vec[i]] );
+                } else {
+                    recover.push( [i, color_vec[j]] );
+                }
+            }
+            //
+            // order
+            let mut order : Vec<usize> = (0 .. sub_pattern.len()).collect();
+            order.sort_by_key( |&ell| recover[ell][1] );
+            //
+            // zero_e, one_e
+            let zero_e      = $E::zero();
+            let one_e       = $E::one();
+            //
+            // index
+            let mut index = 0;
+            //
+            // hessian
+            let mut hessian = vec![zero_e.clone(); sub_pattern.len()];
+            //
+            if trace {
+                println!("Begin Trace: sparse_hes: n = {}", n);
+                println!("color_vec = {:?}", color_vec);
+            }
+            //
+            // color
+            for color in 0 .. n_color {
+                if trace {
+                    println!( "color = {}", color);
+                }
+                //
+                // direction
+                let mut direction : Vec<$E> = Vec::with_capacity(n);
+                for j in 0 .. n {
+                    if color_vec[j] == color {
+                        direction.push( one_e.clone() );
+                    } else {
+                        direction.push( zero_e.clone() );
+                    }
+                }
+                //
+                // hes_dir
+                let hes_dir = self. [< hes_vec_ $suffix >](
+                    dyp_all, var_all, rng_weight.clone(), direction, opt_vec
+                );
+                //
+                // hessian
+                while index < sub_pattern.len()
+                    && recover[ order[index] ][1] == color {
+                    let row = recover[ order[index] ][0];
+                    hessian[ order[index] ] = hes_dir[row].clone();
+                    index                  += 1;
+                }
+            }
+            debug_assert!( index == sub_pattern.len() );
+            if trace {
+                println!("End Trace: sparse_hes");
+            }
+            //
+            hessian
+        }
+    }
+}}
+//
+impl<V> ADfn<V> where
+V : Clone + std::fmt::Display + GlobalOpFnsVec + FConst + ThisThreadTape,
+for<'a> V     : std::ops::AddAssign<&'a V>,
+for<'a> AD<V> : std::ops::AddAssign<&'a AD<V> >,
+{   //
+    // sparse_hes
+    sparse_hes!( value, V );
+    sparse_hes!( ad,    AD::<V> );
+}
@@ -23,6 +23,11 @@ use crate::op::info::{
 use crate::op::id::{
     CALL_OP,
     CALL_RES_OP,
+    CEXP_OP,
+};
+use crate::op::cexp::{
+    NUMBER_ARG,
+    cexp_use_arg,
 };
 //
 #[cfg(doc)]
@@ -65,6 +70,11 @@ where
     ///     the dynamic parameter pattern dyp_pattern is (is not) computed.
     ///     THe default for compute_dyp is false;
     ///
+    ///   * cexp :
+    ///     The corresponding value must be both or recorded (default is both).
+    ///     This has the same meaning as the cexp option for
+    ///     [ADfn::for_sparsity] .
+    ///
     /// * dyp_pattern :
     ///   This return is vector of [row, column] pairs.
     ///   Each row (column) is less than the range (dynamic parameter domain)
@@ -125,9 +135,10 @@ where
         opt_vec : &Vec<[&str; 2]> ,
     ) -> ( SparsityPattern, SparsityPattern )
     {   //
-        // trace, compute_dyp
-        let mut trace       = false;
-        let mut compute_dyp = false;
+        // trace, compute_dyp, cexp_recorded
+        let mut trace         = false;
+        let mut compute_dyp   = false;
+        let mut cexp_recorded = false;
         for opt in opt_vec {
             match opt[0] {
                 "trace" => {
@@ -148,6 +159,15 @@ where
                         ); }
                     }
                 },
+                "cexp" => {
+                    match opt[1] {
+                        "both"     => { cexp_recorded = false; },
+                        "recorded" => { cexp_recorded = true; },
+                        _ => { panic!(
+                        "sub_sparsity opt_vec: invalid value for cexp"
+                        ); }
+                    }
+                },
                 _ => panic!("sub_sparsity opt_vec: invalid key"),
             }
         }
@@ -274,8 +294,20 @@ where
                             let arg      = &var_arg_all[begin .. end];
                             let arg_type = &var_arg_type_all[begin .. end];
                             //
+                            // use_arg
+                            let use_arg = if op_id == CEXP_OP {
+                                cexp_use_arg(
+                                    &self.var.bool_all, arg, cexp_recorded
+                                )
+                            } else {
+                                [true; NUMBER_ARG]
+                            };
+                            //
                             // var_index_stack
                             for i in 0 .. arg.len() {
+                                if op_id == CEXP_OP && ! use_arg[i] {
+                                    continue;
+                                }
                                 if arg_type[i].is_variable() {
                                     var_index_stack.push( arg[i] );
                                 } else if arg_type[i].is_dynamic() {
@@ -339,9 +371,21 @@ where
                             let arg      = &dyp_arg_all[begin .. end];
                             let arg_type = &dyp_arg_type_all[begin .. end];
                             //
+                            // use_arg
+                            let use_arg = if op_id == CEXP_OP {
+                                cexp_use_arg(
+                                    &self.dyp.bool_all, arg, cexp_recorded
+                                )
+                            } else {
+                                [true; NUMBER_ARG]
+                            };
+                            //
                             // dyp_index_stack
                             for i in 0 .. arg.len() {
                                 debug_assert!( ! arg_type[i].is_variable() );
+                                if op_id == CEXP_OP && ! use_arg[i] {
+                                    continue;
+                                }
                                 if arg_type[i].is_dynamic() {
                                     dyp_index_stack.push( arg[i] );
                                 }
@@ -0,0 +1,512 @@
+// SPDX-License-Identifier: EPL-2.0 OR GPL-2.0-or-later
+// SPDX-FileCopyrightText: Bradley M. Bell <bradbell@seanet.com>
+// SPDX-FileContributor: 2026 Bradley M. Bell
+// ---------------------------------------------------------------------------
+//
+//! Implement the [ADfn] binary serialization methods.
+//!
+//! Link to [parent module](super)
+// ---------------------------------------------------------------------------
+// use
+//
+use crate::{
+    ADfn,
+    FBytes,
+    IndexT,
+};
+use crate::ad::ADType;
+use crate::tape::AGraph;
+use crate::op::info::OpFns;
+use crate::op::info::sealed::GlobalOpFnsVec;
+use crate::atom::sealed::GlobalAtomCallbackVec;
+use crate::adfn::check_graph::{
+    check_agraph,
+    check_rng,
+};
+//
+#[cfg(doc)]
+use crate::doc_generic_v;
+// ---------------------------------------------------------------------------
+//
+// BYTES_MAGIC
+/// first bytes in the binary representation of an [ADfn] object
+const BYTES_MAGIC : &[u8; 8] = b"RUSTADFN";
+//
+// BYTES_VERSION
+/// version number for the binary representation of an [ADfn] object
+const BYTES_VERSION : u32 = 1;
+// ---------------------------------------------------------------------------
+// Writer
+//
+// put_u8
+fn put_u8(bytes : &mut Vec<u8>, value : u8) {
+    bytes.push(value);
+}
+//
+// put_u32
+fn put_u32(bytes : &mut Vec<u8>, value : u32) {
+    bytes.extend_from_slice( &value.to_le_bytes() );
+}
+//
+// put_u64
+fn put_u64(bytes : &mut Vec<u8>, value : usize) {
+    bytes.extend_from_slice( &(value as u64).to_le_bytes() );
+}
+//
+// put_str
+fn put_str(bytes : &mut Vec<u8>, value : &str) {
+    put_u64(bytes, value.len());
+    bytes.extend_from_slice( value.as_bytes() );
+}
+//
+// ad_type_code
+fn ad_type_code(ad_type : &ADType) -> u8 {
+    match ad_type {
+        ADType::ConstantP => 0,
+        ADType::DynamicP  => 1,
+        ADType::Variable  => 2,
+        ADType::Empty     => 3,
+    }
+}
+//
+// put_ad_type_vec
+fn put_ad_type_vec(bytes : &mut Vec<u8>, vec : &[ADType]) {
+    put_u64(bytes, vec.len());
+    for ad_type in vec {
+        put_u8(bytes, ad_type_code(ad_type) );
+    }
+}
+//
+// put_index_vec
+fn put_index_vec(bytes : &mut Vec<u8>, vec : &[IndexT]) {
+    put_u64(bytes, vec.len());
+    for index in vec {
+        put_u32(bytes, *index);
+    }
+}
+//
+// put_agraph
+fn put_agraph(bytes : &mut Vec<u8>, agraph : &AGraph) {
+    put_u64(bytes, agraph.n_dom);
+    put_u64(bytes, agraph.n_dep);
+    put_u64(bytes, agraph.id_all.len());
+    bytes.extend_from_slice( &agraph.id_all );
+    put_index_vec(bytes, &agraph.arg_start);
+    put_index_vec(bytes, &agraph.arg_all);
+    put_ad_type_vec(bytes, &agraph.arg_type_all);
+    put_u64(bytes, agraph.bool_all.len());
+    for value in agraph.bool_all.iter() {
+        put_u8(bytes, *value as u8);
+    }
+    put_str(bytes, &agraph.str_all);
+}
+// ---------------------------------------------------------------------------
+// Reader
+//
+/// Reads values from a binary representation of an [ADfn] object.
+struct Reader<'a> {
+    //
+    // bytes
+    /// the binary representation
+    bytes : &'a [u8],
+    //
+    // start
+    /// index in bytes of the next value
+    start : usize,
+}
+impl<'a> Reader<'a> {
+    //
+    // slice
+    // the next n bytes
+    fn slice(&mut self, n : usize) -> Result<&'a [u8], String> {
+        if self.bytes.len() - self.start < n {
+            return Err( "ADfn::from_bytes: unexpected end of bytes".to_string() );
+        }
+        let begin   = self.start;
+        self.start += n;
+        Ok( &self.bytes[begin .. self.start] )
+    }
+    //
+    // get_u8
+    fn get_u8(&mut self) -> Result<u8, String> {
+        Ok( self.slice(1)?[0] )
+    }
+    //
+    // get_u32
+    fn get_u32(&mut self) -> Result<u32, String> {
+        let mut array = [0u8; 4];
+        array.copy_from_slice( self.slice(4)? );
+        Ok( u32::from_le_bytes(array) )
+    }
+    //
+    // get_u64
+    fn get_u64(&mut self) -> Result<usize, String> {
+        let mut array = [0u8; 8];
+        array.copy_from_slice( self.slice(8)? );
+        let value = u64::from_le_bytes(array);
+        usize::try_from(value).map_err( |_|
+            "ADfn::from_bytes: length does not fit in usize".to_string()
+        )
+    }
+    //
+    // get_len
+    // a vector length where each element has at least size bytes
+    fn get_len(&mut self, size : usize) -> Result<usize, String> {
+        let len = self.get_u64()?;
+        if (self.bytes.len() - self.start) / size < len {
+            return Err( "ADfn::from_bytes: unexpected end of bytes".to_string() );
+        }
+        Ok( len )
+    }
+    //
+    // get_str
+    fn get_str(&mut self) -> Result<String, String> {
+        let len   = self.get_len(1)?;
+        let slice = self.slice(len)?;
+        String::from_utf8( slice.to_vec() ).map_err( |_|
+            "ADfn::from_bytes: invalid utf8 string".to_string()
+        )
+    }
+    //
+    // get_ad_type_vec
+    fn get_ad_type_vec(&mut self) -> Result<Vec<ADType>, String> {
+        let len     = self.get_len(1)?;
+        let mut vec = Vec::with_capacity(len);
+        for _i in 0 .. len {
+            let ad_type = match self.get_u8()? {
+                0 => ADType::ConstantP,
+                1 => ADType::DynamicP,
+                2 => ADType::Variable,
+                3 => ADType::Empty,
+                _ => return Err(
+                    "ADfn::from_bytes: invalid AD type code".to_string()
+                ),
+            };
+            vec.push(ad_type);
+        }
+        Ok( vec )
+    }
+    //
+    // get_index_vec
+    fn get_index_vec(&mut self) -> Result<Vec<IndexT>, String> {
+        let len     = self.get_len(4)?;
+        let mut vec = Vec::with_capacity(len);
+        for _i in 0 .. len {
+            vec.push( self.get_u32()? );
+        }
+        Ok( vec )
+    }
+    //
+    // get_agraph
+    // op_id_map maps operator ids in bytes to operator ids in this version
+    // of rustad.
+    fn get_agraph(&mut self, op_id_map : &[Option<u8>]) -> Result<AGraph, String>
+    {   //
+        let n_dom   = self.get_u64()?;
+        let n_dep   = self.get_u64()?;
+        //
+        // id_all
+        let len        = self.get_len(1)?;
+        let mut id_all = Vec::with_capacity(len);
+        for _i in 0 .. len {
+            let op_id = self.get_u8()? as usize;
+            match op_id_map.get(op_id) {
+                Some( Some(new_id) ) => id_all.push( *new_id ),
+                _ => return Err( format!(
+                    "ADfn::from_bytes: operator id {op_id} is not available"
+                ) ),
+            }
+        }
+        //
+        let arg_start    = self.get_index_vec()?;
+        let arg_all      = self.get_index_vec()?;
+        let arg_type_all = self.get_ad_type_vec()?;
+        //
+        // bool_all
+        let len          = self.get_len(1)?;
+        let mut bool_all = Vec::with_capacity(len);
+        for _i in 0 .. len {
+            bool_all.push( self.get_u8()? != 0 );
+        }
+        //
+        let str_all = self.get_str()?;
+        //
+        // check
+        if id_all.len() != n_dep {
+            return Err( "ADfn::from_bytes: id_all length is not n_dep".into() );
+        }
+        if ! ( arg_start.len() == n_dep + 1 || arg_start.is_empty() && n_dep == 0 )
+        {   return Err(
+                "ADfn::from_bytes: arg_start length is not n_dep + 1".into()
+            );
+        }
+        if arg_type_all.len() != arg_all.len() {
+            return Err(
+                "ADfn::from_bytes: arg_type_all length is not arg_all length"
+                .into()
+            );
+        }
+        for i in 0 .. arg_start.len() {
+            let end   = arg_start[i] as usize;
+            let begin = if i == 0 { 0 } else { arg_start[i-1] as usize };
+            if end < begin || arg_all.len() < end {
+                return Err( "ADfn::from_bytes: invalid arg_start".into() );
+            }
+        }
+        if let Some( end ) = arg_start.last()
+            && *end as usize != arg_all.len() {
+            return Err( "ADfn::from_bytes: invalid arg_start".into() );
+        }
+        Ok( AGraph {
+            n_dom,
+            n_dep,
+            id_all,
+            arg_start,
+            arg_all,
+            arg_type_all,
+            bool_all,
+            str_all,
+        } )
+    }
+}
+// ---------------------------------------------------------------------------
+// ADfn::to_bytes, ADfn::from_bytes
+impl<V> ADfn<V>
+where
+    V : FBytes + GlobalOpFnsVec + GlobalAtomCallbackVec ,
+{
+    /// Convert this function object to a stable binary representation.
+    ///
+    /// * Syntax :
+    ///   ```text
+    ///     bytes = f.to_bytes()
+    ///   ```
+    ///
+    /// * V : see [doc_generic_v] .
+    ///   This type must implement [FBytes] .
+    ///
+    /// * f : is this [ADfn] object.
+    ///
+    /// * bytes :
+    ///   is the binary representation of f . It can be converted back to
+    ///   an ADfn object using [ADfn::from_bytes] ,
+    ///   possibly in a different process.
+    ///
+    /// * Format :
+    ///   All numbers are little endian and all lengths are u64.
+    ///   The representation begins with the characters `RUSTADFN` ,
+    ///   followed by a u32 format version number, followed by the
+    ///   [FBytes::type_name] for V .
+    ///   This is followed by the names of the operators so that
+    ///   the operator identifiers can be mapped between versions of rustad.
+    ///   The rest of the representation is the dynamic parameter graph,
+    ///   the variable graph, the range types, the range indices,
+    ///   and the constant parameters.
+    ///
+    /// * Atomic Functions :
+    ///   An atomic function call is stored using its atom_id .
+    ///   The atomic functions must be registered in the same order
+    ///   in the process that uses [ADfn::from_bytes] .
+    ///
+    /// # Example
+    /// ```
+    /// use rustad::{
+    ///     ADfn,
+    ///     FUnary,
+    ///     start_recording,
+    ///     stop_recording,
+    /// };
+    /// //
+    /// // V
+    /// type V = rustad::AzFloat<f64>;
+    /// //
+    /// // f
+    /// // f(x) = [ sin( x[0] ) * x[1] ]
+    /// let x      : Vec<V> = vec![ V::from(1.0), V::from(2.0) ];
+    /// let (_, ax)         = start_recording(None, x.clone());
+    /// let ay              = vec![ &(&ax[0]).sin() * &ax[1] ];
+    /// let f               = stop_recording(ay);
+    /// //
+    /// // g
+    /// let bytes           = f.to_bytes();
+    /// let g : ADfn<V>     = ADfn::from_bytes(&bytes).unwrap();
+    /// //
+    /// // check
+    /// let opt_vec : Vec<[&str; 2]> = Vec::new();
+    /// let (f_y, _)        = f.forward_var_value(None, x.clone(), &opt_vec);
+    /// let (g_y, _)        = g.forward_var_value(None, x, &opt_vec);
+    /// assert_eq!( f_y, g_y );
+    /// ```
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes : Vec<u8> = Vec::new();
+        //
+        // header
+        bytes.extend_from_slice( BYTES_MAGIC );
+        put_u32(&mut bytes, BYTES_VERSION);
+        put_str(&mut bytes, &V::type_name() );
+        //
+        // operator names
+        let op_fns_vec : &Vec< OpFns<V> > = GlobalOpFnsVec::get();
+        put_u64(&mut bytes, op_fns_vec.len() );
+        for op_fns in op_fns_vec.iter() {
+            put_str(&mut bytes, op_fns.name);
+        }
+        //
+        // dyp, var
+        put_agraph(&mut bytes, &self.dyp);
+        put_agraph(&mut bytes, &self.var);
+        //
+        // rng_ad_type, rng_index
+        put_ad_type_vec(&mut bytes, &self.rng_ad_type);
+        put_index_vec(&mut bytes, &self.rng_index);
+        //
+        // cop
+        put_u64(&mut bytes, self.cop.len() );
+        for value in self.cop.iter() {
+            value.to_bytes(&mut bytes);
+        }
+        bytes
+    }
+    //
+    /// Convert a binary representation to a function object.
+    ///
+    /// * Syntax :
+    ///   ```text
+    ///     f = ADfn::from_bytes(&bytes)
+    ///   ```
+    ///
+    /// * V : see [doc_generic_v] .
+    ///   This type must implement [FBytes] .
+    ///
+    /// * bytes :
+    ///   is a binary representation created by [ADfn::to_bytes] .
+    ///
+    /// * f :
+    ///   If the return value is Ok, it contains an [ADfn] object that is
+    ///   equivalent to the one that created *bytes* .
+    ///   Otherwise it contains an error message; e.g., if *bytes* was
+    ///   created using a different value type V ,
+    ///   an operator has the wrong number or type of arguments,
+    ///   an index in *bytes* is out of range for its type
+    ///   (this includes references to results of later operators),
+    ///   or the booleans, strings, or atom used by an operator are invalid.
+    ///
+    /// # Example
+    /// see [ADfn::to_bytes]
+    pub fn from_bytes(bytes : &[u8]) -> Result<ADfn<V>, String> {
+        let mut reader = Reader{ bytes, start : 0 };
+        //
+        // magic
+        if reader.slice( BYTES_MAGIC.len() ).ok() != Some( &BYTES_MAGIC[..] ) {
+            return Err(
+                "ADfn::from_bytes: this is not a rustad ADfn binary".to_string()
+            );
+        }
+        //
+        // version
+        let version = reader.get_u32()?;
+        if version != BYTES_VERSION {
+            return Err( format!(
+                "ADfn::from_bytes: format version {version} is not supported"
+            ) );
+        }
+        //
+        // type_name
+        let type_name = reader.get_str()?;
+        if type_name != V::type_name() {
+            return Err( format!(
+                "ADfn::from_bytes: value type is {type_name} not {}",
+                V::type_name()
+            ) );
+        }
+        //
+        // op_id_map
+        let op_fns_vec : &Vec< OpFns<V> > = GlobalOpFnsVec::get();
+        let n_op            = reader.get_len(8)?;
+        let mut op_id_map   = Vec::with_capacity(n_op);
+        for _i in 0 .. n_op {
+            let name   = reader.get_str()?;
+            let new_id = op_fns_vec.iter().position( |op_fns|
+                op_fns.name == name && name != "panic"
+            );
+            op_id_map.push( new_id.map( |id| id as u8 ) );
+        }
+        //
+        // dyp, var
+        let dyp = reader.get_agraph(&op_id_map)?;
+        let var = reader.get_agraph(&op_id_map)?;
+        //
+        // rng_ad_type, rng_index
+        let rng_ad_type = reader.get_ad_type_vec()?;
+        let rng_index   = reader.get_index_vec()?;
+        //
+        // cop
+        let n_cop   = reader.get_len(1)?;
+        let mut cop = Vec::with_capacity(n_cop);
+        for _i in 0 .. n_cop {
+            cop.push( V::from_bytes(reader.bytes, &mut reader.start)? );
+        }
+        if reader.start != bytes.len() {
+            return Err(
+                "ADfn::from_bytes: extra bytes at the end".to_string()
+            );
+        }
+        //
+        // check
+        let n_cop = cop.len();
+        let n_dyp = dyp.n_dom + dyp.n_dep;
+        let n_var = var.n_dom + var.n_dep;
+        for (name, agraph, res_type) in [
+            ("dyp", &dyp, ADType::DynamicP), ("var", &var, ADType::Variable)
+        ] {
+            check_agraph::<V>(agraph, res_type, n_cop, n_dyp, n_var,
+                |op_index, message| format!(
+                    "ADfn::from_bytes: {name} operator {op_index}: {message}"
+                )
+            )?;
+        }
+        check_rng(&rng_ad_type, &rng_index, n_cop, n_dyp, n_var).map_err(
+            |message| format!( "ADfn::from_bytes: {message}" )
+        )?;
+        Ok( ADfn { dyp, var, rng_ad_type, rng_index, cop } )
+    }
+    //
+    /// Write the binary representation of this function object to a file.
+    ///
+    /// * Syntax :
+    ///   ```text
+    ///     result = f.to_bytes_file(file_name)
+    ///   ```
+    ///
+    /// * f : is this [ADfn] object.
+    ///
+    /// * file_name :
+    ///   is the name of the file that [ADfn::to_bytes] is written to.
+    ///
+    /// * result :
+    ///   is Ok(()) if the write succeeded and an error message otherwise.
+    pub fn to_bytes_file(&self, file_name : &str) -> Result<(), String> {
+        std::fs::write( file_name, self.to_bytes() ).map_err( |error|
+            format!( "ADfn::to_bytes_file: cannot write {file_name}: {error}" )
+        )
+    }
+    //
+    /// Read a function object from a file created by [ADfn::to_bytes_file] .
+    ///
+    /// * Syntax :
+    ///   ```text
+    ///     f = ADfn::from_bytes_file(file_name)
+    ///   ```
+    ///
+    /// * file_name : is the name of the file that is read.
+    ///
+    /// * f :
+    ///   is the result of [ADfn::from_bytes] for the contents of the file,
+    ///   or an error message if the file cannot be read.
+    pub fn from_bytes_file(file_name : &str) -> Result<ADfn<V>, String> {
+        let bytes = std::fs::read(file_name).map_err( |error|
+            format!( "ADfn::from_bytes_file: cannot read {file_name}: {error}" )
+        )?;
+        ADfn::from_bytes(&bytes)
+    }
+}
@@ -0,0 +1,285 @@
+// SPDX-License-Identifier: EPL-2.0 OR GPL-2.0-or-later
+// SPDX-FileCopyrightText: Bradley M. Bell <bradbell@seanet.com>
+// SPDX-FileContributor: 2026 Bradley M. Bell
+// ---------------------------------------------------------------------------
+//
+//! Implement the [ADfn] to_dot method.
+//!
+//! Link to [parent module](super)
+// ---------------------------------------------------------------------------
+// use
+//
+use crate::ADfn;
+use crate::ad::ADType;
+use crate::tape::AGraph;
+use crate::op::info::OpFns;
+use crate::op::info::sealed::GlobalOpFnsVec;
+use crate::op::id::{
+    CALL_OP,
+    CALL_RES_OP,
+};
+//
+#[cfg(doc)]
+use crate::doc_generic_v;
+// ---------------------------------------------------------------------------
+//
+// node_name
+// DOT node name for an argument or result; e.g., c3, d1, v4 .
+fn node_name(ad_type : &ADType, index : usize) -> String {
+    match ad_type {
+        ADType::ConstantP => format!( "c{index}" ),
+        ADType::DynamicP  => format!( "d{index}" ),
+        ADType::Variable  => format!( "v{index}" ),
+        ADType::Empty     => panic!( "to_dot: node_name: empty AD type" ),
+    }
+}
+//
+// node_attribute
+// DOT attributes that distinguish constants, dynamic parameters, variables.
+fn node_attribute(ad_type : &ADType) -> &'static str {
+    match ad_type {
+        ADType::ConstantP =>
+            "shape=box, style=filled, fillcolor=lightgray",
+        ADType::DynamicP  =>
+            "shape=ellipse, color=blue, fontcolor=blue",
+        ADType::Variable  =>
+            "shape=ellipse, color=black",
+        ADType::Empty     => panic!( "to_dot: node_attribute: empty AD type" ),
+    }
+}
+//
+// agraph_dot
+// Appends the DOT nodes for one of the acyclic graphs to dot
+// and the corresponding edges to edges.
+fn agraph_dot<V>(
+    dot         : &mut String         ,
+    edges       : &mut String         ,
+    ad_type     : ADType              ,
+    agraph      : &AGraph             ,
+    op_fns_vec  : &[ OpFns<V> ]       ,
+    constant    : bool                ,
+    cop_used    : &mut [bool]         ,
+) {
+    let (label, name) = if ad_type == ADType::DynamicP {
+        ( "dynamic parameters", "dyp" )
+    } else {
+        ( "variables", "var" )
+    };
+    let attribute = node_attribute(&ad_type);
+    *dot += &format!( "  subgraph cluster_{name} {{\n" );
+    *dot += &format!( "    label=\"{label}\";\n" );
+    //
+    // domain nodes
+    for index in 0 .. agraph.n_dom {
+        *dot += &format!(
+            "    {} [label=\"{}\\ndomain\", {}];\n",
+            node_name(&ad_type, index), node_name(&ad_type, index), attribute
+        );
+    }
+    //
+    // op_index
+    let mut op_index = 0;
+    while op_index < agraph.n_dep {
+        //
+        // n_call
+        // number of operators in this call cluster (zero if not a call)
+        let mut n_call = 0;
+        if agraph.id_all[op_index] == CALL_OP {
+            n_call = 1;
+            while op_index + n_call < agraph.n_dep &&
+                agraph.id_all[op_index + n_call] == CALL_RES_OP {
+                n_call += 1;
+            }
+            *dot += &format!( "    subgraph cluster_{name}_call_{op_index} {{\n" );
+            *dot += "      label=\"call\";\n";
+            *dot += "      style=dashed;\n";
+        }
+        for op_call in op_index .. op_index + usize::max(n_call, 1) {
+            let op_id    = agraph.id_all[op_call];
+            let res      = node_name(&ad_type, agraph.n_dom + op_call);
+            let op_name  = &op_fns_vec[op_id as usize].name;
+            let indent   = if n_call > 0 { "      " } else { "    " };
+            *dot += &format!(
+                "{indent}{res} [label=\"{res}\\n{op_name}\", {attribute}];\n"
+            );
+            let begin    = agraph.arg_start[op_call] as usize;
+            let end      = agraph.arg_start[op_call + 1] as usize;
+            for i in begin .. end {
+                let arg_type = &agraph.arg_type_all[i];
+                let arg      = agraph.arg_all[i] as usize;
+                let show     = match arg_type {
+                    ADType::Empty     => false,
+                    ADType::ConstantP => constant,
+                    _                 => true,
+                };
+                if show {
+                    if arg_type.is_constant() {
+                        cop_used[arg] = true;
+                    }
+                    *edges += &format!(
+                        "  {} -> {};\n", node_name(arg_type, arg), res
+                    );
+                }
+            }
+        }
+        if n_call > 0 {
+            *dot += "    }\n";
+        }
+        op_index += usize::max(n_call, 1);
+    }
+    *dot += "  }\n";
+}
+// ---------------------------------------------------------------------------
+// ADfn::to_dot
+impl<V> ADfn<V>
+where
+    V : std::fmt::Display + GlobalOpFnsVec ,
+{
+    /// Graphviz DOT representation of the acyclic graphs for this function.
+    ///
+    /// * Syntax :
+    ///   ```text
+    ///     dot = f.to_dot(opt_vec)
+    ///   ```
+    ///
+    /// * V : see [doc_generic_v]
+    ///
+    /// * f : is this [ADfn] object.
+    ///
+    /// * opt_vec :
+    ///   is an [opt_vec](crate::doc_opt_vec) with the following possible keys:
+    ///
+    ///   * constant
+    ///     The corresponding value must be true of false (default is true).
+    ///     If it is true, the constant parameters that are operator arguments,
+    ///     or range components, are included in the graph.
+    ///
+    ///   * range
+    ///     The corresponding value must be true of false (default is true).
+    ///     If it is true, the range components are included in the graph.
+    ///
+    /// * dot :
+    ///   The return value is a DOT digraph that can be displayed using
+    ///   the graphviz programs; e.g., `dot -Tsvg -o f.svg f.dot` .
+    ///
+    ///   * Nodes :
+    ///     The node names c *index* , d *index* , and v *index* denote the
+    ///     constant parameter, dynamic parameter, and variable with the
+    ///     specified index.
+    ///     Constants are gray boxes, dynamic parameters are blue ellipses,
+    ///     and variables are black ellipses.
+    ///     Each dependent node is labeled by its operator name.
+    ///     The range nodes are y *index* and are plain text.
+    ///
+    ///   * Edges :
+    ///     There is an edge from each operator argument to its result.
+    ///
+    ///   * Clusters :
+    ///     The dynamic parameter and variable graphs are in separate clusters.
+    ///     The CALL_OP and CALL_RES_OP operators for each atomic function
+    ///     call are in a dashed cluster labeled call.
+    ///
+    /// # Example
+    /// ```
+    /// use rustad::{
+    ///     AD,
+    ///     start_recording,
+    ///     stop_recording,
+    /// };
+    /// //
+    /// // V
+    /// type V = rustad::AzFloat<f64>;
+    /// //
+    /// // f
+    /// // f(p, x) = [ p[0] * x[0] + 2 ]
+    /// let p      : Vec<V> = vec![ V::from(1.0) ];
+    /// let x      : Vec<V> = vec![ V::from(1.0) ];
+    /// let (ap, ax)        = start_recording( Some(p), x);
+    /// let aterm           = &ap[0] * &ax[0];
+    /// let ay              = &aterm + &AD::from( V::from(2.0) );
+    /// let f               = stop_recording( vec![ay] );
+    /// //
+    /// // dot
+    /// let opt_vec : Vec<[&str; 2]> = Vec::new();
+    /// let dot = f.to_dot(&opt_vec);
+    /// assert!( dot.starts_with("digraph adfn {\n") );
+    /// assert!( dot.contains("v1 [label=\"v1\\nmul_pv\"") );
+    /// assert!( dot.contains("d0 -> v1;\n") );
+    /// assert!( dot.contains("c1 [label=\"c1\\n2\"") );
+    /// assert!( dot.contains("c1 -> v2;\n") );
+    /// assert!( dot.contains("v2 -> y0;\n") );
+    /// //
+    /// // dot without constants or range nodes
+    /// let opt_vec = vec![ ["constant", "false"], ["range", "false"] ];
+    /// let dot = f.to_dot(&opt_vec);
+    /// assert!( ! dot.contains("c1") );
+    /// assert!( ! dot.contains("y0") );
+    /// ```
+    pub fn to_dot(&self, opt_vec : &Vec<[&str; 2]>) -> String {
+        //
+        // constant, range
+        let mut constant = true;
+        let mut range    = true;
+        for opt in opt_vec {
+            let value = match opt[1] {
+                "true"  => true,
+                "false" => false,
+                _ => panic!( "to_dot opt_vec: invalid value for {}", opt[0] ),
+            };
+            match opt[0] {
+                "constant" => { constant = value; },
+                "range"    => { range    = value; },
+                _ => panic!("to_dot opt_vec: invalid key"),
+            }
+        }
+        //
+        // op_fns_vec
+        let op_fns_vec : &Vec< OpFns<V> > = GlobalOpFnsVec::get();
+        //
+        // dot, edges, cop_used
+        // The edges are written after all the nodes so that a node does not
+        // get placed in a cluster because one of its edges is in the cluster.
+        let mut dot      = "digraph adfn {\n".to_string();
+        let mut edges    = String::new();
+        let mut cop_used = vec![ false; self.cop.len() ];
+        agraph_dot(
+            &mut dot, &mut edges, ADType::DynamicP, &self.dyp, op_fns_vec,
+            constant, &mut cop_used
+        );
+        agraph_dot(
+            &mut dot, &mut edges, ADType::Variable, &self.var, op_fns_vec,
+            constant, &mut cop_used
+        );
+        //
+        // range nodes
+        if range {
+            for i in 0 .. self.rng_ad_type.len() {
+                let ad_type = &self.rng_ad_type[i];
+                let index   = self.rng_index[i] as usize;
+                if constant || ! ad_type.is_constant() {
+                    if ad_type.is_constant() {
+                        cop_used[index] = true;
+                    }
+                    dot   += &format!( "  y{i} [shape=plaintext];\n" );
+                    edges += &format!(
+                        "  {} -> y{};\n", node_name(ad_type, index), i
+                    );
+                }
+            }
+        }
+        //
+        // constant nodes
+        let attribute = node_attribute(&ADType::ConstantP);
+        for (index, used) in cop_used.iter().enumerate() {
+            if *used {
+                dot += &format!(
+                    "  c{} [label=\"c{}\\n{}\", {}];\n",
+                    index, index, self.cop[index], attribute
+                );
+            }
+        }
+        dot += &edges;
+        dot += "}\n";
+        dot
+    }
+}
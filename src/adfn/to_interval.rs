@@ -0,0 +1,109 @@
+// SPDX-License-Identifier: EPL-2.0 OR GPL-2.0-or-later
+// SPDX-FileCopyrightText: Bradley M. Bell <bradbell@seanet.com>
+// SPDX-FileContributor: 2026 Bradley M. Bell
+// ---------------------------------------------------------------------------
+//
+//! Implement the [ADfn] to_interval method (range bounding).
+//!
+//! Link to [parent module](super)
+// ---------------------------------------------------------------------------
+// use
+//
+use crate::{
+    ADfn,
+    AzFloat,
+    Interval,
+};
+use crate::op::id::CALL_OP;
+// ---------------------------------------------------------------------------
+// to_interval
+impl<B> ADfn< AzFloat<B> >
+where
+    B           : Copy ,
+    Interval<B> : From< AzFloat<B> > ,
+{
+    /// Convert to a function that evaluates using intervals.
+    ///
+    /// * Syntax :
+    ///   ```text
+    ///     g = f.to_interval()
+    ///   ```
+    ///
+    /// * B : is the floating point base type; i.e., f32 or f64 .
+    ///
+    /// * f : is an `ADfn< AzFloat<B> >` object.
+    ///   It must not contain calls to atomic functions or checkpoints.
+    ///
+    /// * g : is an `ADfn< Interval<B> >` object with the same
+    ///   operation sequence as f. Each constant parameter in f is
+    ///   converted to the interval that only contains its value.
+    ///
+    /// * Range Enclosure :
+    ///   If the domain values for `g.forward_var_value` are a box of intervals,
+    ///   the range values contain the values of the operation sequence,
+    ///   evaluated using exact real arithmetic, for every point in the box;
+    ///   see [Interval] .
+    ///   This is guaranteed when the operation sequence only uses
+    ///   +, -, *, / and sqrt . The other functions are widened heuristically,
+    ///   so for them the enclosure is expected but not proven.
+    ///   Comparison results that depend on the point in the box are [0, 1] ;
+    ///   e.g., both branches of a conditional expression contribute
+    ///   to its enclosure.
+    ///
+    /// * Derivative Enclosure :
+    ///   If the domain values for `g.forward_der_value` are points,
+    ///   its range values contain the corresponding directional derivatives
+    ///   for every point in the box.
+    ///   The same holds for `g.reverse_der_value` and partial derivatives.
+    ///   As for the range, this is only guaranteed when the derivatives
+    ///   do not use the library functions.
+    ///
+    /// # Example
+    /// ```
+    /// use rustad::{
+    ///     AzFloat,
+    ///     Interval,
+    ///     FUnary,
+    ///     start_recording,
+    ///     stop_recording,
+    /// };
+    /// type V = AzFloat<f64>;
+    /// type I = Interval<f64>;
+    /// let opt_vec : Vec<[&str; 2]> = Vec::new();
+    /// //
+    /// // f(x) = x * x - x
+    /// let x       = vec![ V::from(1.0) ];
+    /// let (_, ax) = start_recording(None, x);
+    /// let ay      = &( &ax[0] * &ax[0] ) - &ax[0];
+    /// let f       = stop_recording( vec![ay] );
+    /// //
+    /// // g = f evaluated using intervals
+    /// let g       = f.to_interval();
+    /// let x       = vec![ I::new(0.0, 2.0) ];
+    /// let (y, v)  = g.forward_var_value(None, x, &opt_vec);
+    /// assert_eq!( y[0], I::new(-2.0, 4.0) );
+    /// //
+    /// // f'(x) = 2 * x - 1
+    /// let dx      = vec![ I::from(1.0) ];
+    /// let dy      = g.forward_der_value(None, &v, dx, &opt_vec);
+    /// assert_eq!( dy[0], I::new(-1.0, 3.0) );
+    /// ```
+    pub fn to_interval(&self) -> ADfn< Interval<B> > {
+        for graph in [ &self.dyp, &self.var ] {
+            if graph.id_all.contains( &CALL_OP ) {
+                panic!(
+                    "to_interval: f contains a call to an atomic function"
+                );
+            }
+        }
+        let cop : Vec< Interval<B> > =
+            self.cop.iter().map( |c| Interval::from(*c) ).collect();
+        ADfn {
+            dyp         : self.dyp.clone(),
+            var         : self.var.clone(),
+            rng_ad_type : self.rng_ad_type.clone(),
+            rng_index   : self.rng_index.clone(),
+            cop,
+        }
+    }
+}
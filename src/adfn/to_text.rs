@@ -0,0 +1,501 @@
+// SPDX-License-Identifier: EPL-2.0 OR GPL-2.0-or-later
+// SPDX-FileCopyrightText: Bradley M. Bell <bradbell@seanet.com>
+// SPDX-FileContributor: 2026 Bradley M. Bell
+// ---------------------------------------------------------------------------
+//
+//! Implement the [ADfn] text representation methods.
+//!
+//! Link to [parent module](super)
+// ---------------------------------------------------------------------------
+// use
+//
+use crate::{
+    ADfn,
+    FText,
+    IndexT,
+};
+use crate::ad::ADType;
+use crate::tape::AGraph;
+use crate::op::info::OpFns;
+use crate::op::info::sealed::GlobalOpFnsVec;
+use crate::atom::sealed::GlobalAtomCallbackVec;
+use crate::op::id::{
+    ZERO_ONE_OP,
+    PRINT_OP,
+};
+use crate::adfn::check_graph::{
+    op_bool_str,
+    check_agraph,
+    check_rng,
+};
+//
+#[cfg(doc)]
+use crate::doc_generic_v;
+// ---------------------------------------------------------------------------
+//
+// TEXT_HEADER
+/// first line in the text representation of an [ADfn] object
+const TEXT_HEADER : &str = "rustad ADfn text 1";
+// ---------------------------------------------------------------------------
+// Writer
+//
+// ad_type_char
+fn ad_type_char(ad_type : &ADType) -> char {
+    match ad_type {
+        ADType::ConstantP => 'c',
+        ADType::DynamicP  => 'd',
+        ADType::Variable  => 'v',
+        ADType::Empty     => 'e',
+    }
+}
+//
+// typed_index_text
+// text for a vector of (type, index) pairs; e.g., [ c0, v3 ]
+fn typed_index_text(ad_type : &[ADType], index : &[IndexT]) -> String {
+    let mut text = "[".to_string();
+    for i in 0 .. index.len() {
+        if i > 0 {
+            text.push(',');
+        }
+        text += &format!( " {}{}", ad_type_char( &ad_type[i] ), index[i] );
+    }
+    text += " ]";
+    text
+}
+//
+// agraph_text
+fn agraph_text<V>(
+    text       : &mut String    ,
+    name       : &str           ,
+    agraph     : &AGraph        ,
+    op_fns_vec : &[ OpFns<V> ]  ,
+) {
+    *text += &format!(
+        "{} n_dom {} n_dep {}\n", name, agraph.n_dom, agraph.n_dep
+    );
+    for op_index in 0 .. agraph.n_dep {
+        let op_id    = agraph.id_all[op_index];
+        let begin    = agraph.arg_start[op_index] as usize;
+        let end      = agraph.arg_start[op_index + 1] as usize;
+        let arg      = &agraph.arg_all[begin .. end];
+        let arg_type = &agraph.arg_type_all[begin .. end];
+        *text += &format!(
+            "{} {} {}",
+            agraph.n_dom + op_index,
+            op_fns_vec[op_id as usize].name,
+            typed_index_text(arg_type, arg),
+        );
+        let (bool_range, str_range) = op_bool_str(op_id, arg).unwrap();
+        if ! bool_range.is_empty() {
+            *text += " bool [";
+            for (i, value) in agraph.bool_all[bool_range].iter().enumerate() {
+                if i > 0 {
+                    text.push(',');
+                }
+                *text += &format!( " {value}" );
+            }
+            *text += " ]";
+        }
+        if op_id == ZERO_ONE_OP || op_id == PRINT_OP {
+            *text += &format!( " str {:?}", &agraph.str_all[str_range] );
+        }
+        text.push('\n');
+    }
+}
+// ---------------------------------------------------------------------------
+// Parser
+//
+// parse_error
+fn parse_error(line_number : usize, message : &str) -> String {
+    format!( "ADfn::from_text: line {line_number}: {message}" )
+}
+//
+// parse_bracket
+// splits text into the contents of the leading square brackets
+// and the rest of the text.
+fn parse_bracket(text : &str) -> Option<(&str, &str)> {
+    let text        = text.trim_start().strip_prefix('[')?;
+    let end         = text.find(']')?;
+    Some( ( &text[.. end], &text[end + 1 ..] ) )
+}
+//
+// parse_typed_index
+// inverse of typed_index_text
+fn parse_typed_index(
+    text     : &str             ,
+    ad_type  : &mut Vec<ADType> ,
+    index    : &mut Vec<IndexT> ,
+) -> Option<()> {
+    if text.trim().is_empty() {
+        return Some( () );
+    }
+    for element in text.split(',') {
+        let element  = element.trim();
+        let type_one = match element.chars().next()? {
+            'c' => ADType::ConstantP,
+            'd' => ADType::DynamicP,
+            'v' => ADType::Variable,
+            'e' => ADType::Empty,
+            _   => return None,
+        };
+        ad_type.push( type_one );
+        index.push( element[1 ..].parse::<IndexT>().ok()? );
+    }
+    Some( () )
+}
+//
+// parse_quoted
+// inverse of the {:?} format for a str
+fn parse_quoted(text : &str) -> Option<String> {
+    let text       = text.trim().strip_prefix('"')?.strip_suffix('"')?;
+    let mut result = String::new();
+    let mut chars  = text.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            result.push(c);
+        } else {
+            match chars.next()? {
+                'n'  => result.push('\n'),
+                'r'  => result.push('\r'),
+                't'  => result.push('\t'),
+                '0'  => result.push('\0'),
+                '\\' => result.push('\\'),
+                '"'  => result.push('"'),
+                '\'' => result.push('\''),
+                'u'  => {
+                    if chars.next()? != '{' {
+                        return None;
+                    }
+                    let mut hex = String::new();
+                    loop {
+                        match chars.next()? {
+                            '}' => break,
+                            h   => hex.push(h),
+                        }
+                    }
+                    let code = u32::from_str_radix(&hex, 16).ok()?;
+                    result.push( char::from_u32(code)? );
+                },
+                _    => return None,
+            }
+        }
+    }
+    Some( result )
+}
+//
+// parse_header
+// parses lines of the form: name n_dom value n_dep value
+fn parse_header(
+    line        : &str  ,
+    line_number : usize ,
+    name        : &str  ,
+) -> Result<(usize, usize), String> {
+    let token : Vec<&str> = line.split_whitespace().collect();
+    if token.len() != 5 || token[0] != name
+        || token[1] != "n_dom" || token[3] != "n_dep" {
+        return Err( parse_error(line_number,
+            &format!( "expected: {name} n_dom value n_dep value" )
+        ) );
+    }
+    let n_dom = token[2].parse::<usize>().ok();
+    let n_dep = token[4].parse::<usize>().ok();
+    match (n_dom, n_dep) {
+        ( Some(n_dom), Some(n_dep) ) => Ok( (n_dom, n_dep) ),
+        _ => Err( parse_error(line_number, "invalid n_dom or n_dep") ),
+    }
+}
+//
+// parse_agraph
+fn parse_agraph<V>(
+    lines       : &mut std::iter::Enumerate< std::str::Lines >  ,
+    name        : &str                                          ,
+    op_fns_vec  : &[ OpFns<V> ]                                 ,
+) -> Result<AGraph, String> {
+    let mut agraph = AGraph::new();
+    //
+    // n_dom, n_dep
+    let (line_index, line) = lines.next().unwrap_or( (0, "") );
+    let (n_dom, n_dep) = parse_header(line, line_index + 1, name)?;
+    agraph.n_dom = n_dom;
+    agraph.n_dep = n_dep;
+    //
+    for op_index in 0 .. n_dep {
+        let (line_index, line) = lines.next().unwrap_or( (0, "") );
+        let line_number        = line_index + 1;
+        let error = |message : &str| parse_error(line_number, message);
+        //
+        // res, op_name, rest
+        let mut split = line.splitn(3, ' ');
+        let res       = split.next().unwrap_or("");
+        let op_name   = split.next().unwrap_or("");
+        let rest      = split.next().unwrap_or("");
+        if res.parse::<usize>() != Ok(n_dom + op_index) {
+            return Err( error( &format!(
+                "expected result index {}", n_dom + op_index
+            ) ) );
+        }
+        //
+        // op_id
+        let op_id = op_fns_vec.iter().position(
+            |op_fns| op_fns.name == op_name && op_name != "panic"
+        );
+        let op_id = match op_id {
+            Some(op_id) => op_id as u8,
+            None        => return Err( error(
+                &format!( "unknown operator name {op_name}" )
+            ) ),
+        };
+        agraph.id_all.push( op_id );
+        //
+        // arg_start, arg_all, arg_type_all
+        let begin = agraph.arg_all.len();
+        agraph.arg_start.push( begin as IndexT );
+        let (arg_text, rest) = parse_bracket(rest).ok_or_else(
+            || error("expected [ arguments ]")
+        )?;
+        parse_typed_index(
+            arg_text, &mut agraph.arg_type_all, &mut agraph.arg_all
+        ).ok_or_else( || error("invalid arguments") )?;
+        //
+        // bool_range, str_range
+        let (bool_range, str_range) = op_bool_str(
+            op_id, &agraph.arg_all[begin ..]
+        ).ok_or_else( || error("not enough arguments") )?;
+        //
+        // bool_all
+        let bool_begin = agraph.bool_all.len();
+        let mut rest   = rest.trim();
+        if let Some(bool_text) = rest.strip_prefix("bool") {
+            let (bool_text, after) = parse_bracket(bool_text).ok_or_else(
+                || error("expected bool [ values ]")
+            )?;
+            for value in bool_text.split(',') {
+                match value.trim() {
+                    "true"  => agraph.bool_all.push(true),
+                    "false" => agraph.bool_all.push(false),
+                    _ => return Err( error("invalid boolean") ),
+                }
+            }
+            rest = after.trim();
+        }
+        if bool_range.is_empty() && bool_begin != agraph.bool_all.len()
+            || ! bool_range.is_empty()
+            && bool_range != ( bool_begin .. agraph.bool_all.len() ) {
+            return Err( error("booleans do not match arguments") );
+        }
+        //
+        // str_all
+        let str_begin = agraph.str_all.len();
+        if let Some(str_text) = rest.strip_prefix("str") {
+            let value = parse_quoted(str_text).ok_or_else(
+                || error("invalid quoted string")
+            )?;
+            agraph.str_all += &value;
+            rest = "";
+        }
+        if ! rest.is_empty() {
+            return Err( error( &format!( "unexpected text {rest}" ) ) );
+        }
+        if str_range.is_empty() && str_begin != agraph.str_all.len()
+            || ! str_range.is_empty()
+            && str_range != ( str_begin .. agraph.str_all.len() ) {
+            return Err( error("string does not match arguments") );
+        }
+    }
+    agraph.arg_start.push( agraph.arg_all.len() as IndexT );
+    Ok( agraph )
+}
+// ---------------------------------------------------------------------------
+// ADfn::to_text, ADfn::from_text
+impl<V> ADfn<V>
+where
+    V : FText + GlobalOpFnsVec + GlobalAtomCallbackVec ,
+{
+    /// Convert this function object to a human readable text representation.
+    ///
+    /// * Syntax :
+    ///   ```text
+    ///     text = f.to_text()
+    ///   ```
+    ///
+    /// * V : see [doc_generic_v] .
+    ///   This type must implement [FText] .
+    ///
+    /// * f : is this [ADfn] object.
+    ///
+    /// * text :
+    ///   is the text representation of f . It can be converted back to
+    ///   an equivalent ADfn object using [ADfn::from_text] .
+    ///   Converting that object back to text gives the same *text* .
+    ///
+    /// * Format :
+    ///   The first line is `rustad ADfn text 1` where 1 is the format version.
+    ///   The dynamic parameter graph starts with the line
+    ///   ```text
+    ///     dyp n_dom n_dom_value n_dep n_dep_value
+    ///   ```
+    ///   and the variable graph starts with a similar line that begins
+    ///   with `var` . Each of these lines is followed by one line
+    ///   for each dependent; e.g.,
+    ///   ```text
+    ///     3 mul_vv [ v2, v1 ]
+    ///   ```
+    ///   The first value is the dynamic parameter or variable index
+    ///   for the result of the operator,
+    ///   the second is the operator name, and the square brackets contain
+    ///   its arguments. The argument prefixes c, d, v, and e denote a constant,
+    ///   dynamic parameter, variable, or other index.
+    ///   If an operator uses booleans (strings) they follow the arguments
+    ///   as `bool [` *values* `]` ( `str "` *text* `"` ).
+    ///   The next line begins with `rng` and contains the range indices.
+    ///   The rest of the lines begin with `cop` , followed by the
+    ///   constant parameter index and its [FText] value.
+    ///
+    /// * Atomic Functions :
+    ///   An atomic function call is stored using its atom_id .
+    ///   The atomic functions must be registered in the same order
+    ///   in the process that uses [ADfn::from_text] .
+    ///
+    /// # Example
+    /// ```
+    /// use rustad::{
+    ///     ADfn,
+    ///     FUnary,
+    ///     start_recording,
+    ///     stop_recording,
+    /// };
+    /// //
+    /// // V
+    /// type V = rustad::AzFloat<f64>;
+    /// //
+    /// // f
+    /// // f(x) = [ sin( x[0] ) * x[1] , 2 ]
+    /// let x      : Vec<V> = vec![ V::from(1.0), V::from(2.0) ];
+    /// let (_, ax)         = start_recording(None, x.clone());
+    /// let ay_0            = &(&ax[0]).sin() * &ax[1];
+    /// let ay_1            = rustad::AD::from( V::from(2.0) );
+    /// let f               = stop_recording( vec![ay_0, ay_1] );
+    /// //
+    /// // text
+    /// let text  = f.to_text();
+    /// let check = "rustad ADfn text 1\n\
+    ///              dyp n_dom 0 n_dep 0\n\
+    ///              var n_dom 2 n_dep 2\n\
+    ///              2 sin [ v0 ]\n\
+    ///              3 mul_vv [ v2, v1 ]\n\
+    ///              rng [ v3, c1 ]\n\
+    ///              cop 0 NaN\n\
+    ///              cop 1 2.0\n";
+    /// assert_eq!( text, check );
+    /// //
+    /// // g
+    /// let g : ADfn<V> = ADfn::from_text(&text).unwrap();
+    /// assert_eq!( g.to_text(), text );
+    /// ```
+    pub fn to_text(&self) -> String {
+        let op_fns_vec : &Vec< OpFns<V> > = GlobalOpFnsVec::get();
+        let mut text = TEXT_HEADER.to_string() + "\n";
+        agraph_text(&mut text, "dyp", &self.dyp, op_fns_vec);
+        agraph_text(&mut text, "var", &self.var, op_fns_vec);
+        text += "rng ";
+        text += &typed_index_text(&self.rng_ad_type, &self.rng_index);
+        text.push('\n');
+        for (i, value) in self.cop.iter().enumerate() {
+            text += &format!( "cop {} {}\n", i, value.to_text() );
+        }
+        text
+    }
+    //
+    /// Convert a text representation to a function object.
+    ///
+    /// * Syntax :
+    ///   ```text
+    ///     f = ADfn::from_text(&text)
+    ///   ```
+    ///
+    /// * V : see [doc_generic_v] .
+    ///   This type must implement [FText] .
+    ///
+    /// * text :
+    ///   is a text representation created by [ADfn::to_text]
+    ///   (or written by hand using the same format).
+    ///
+    /// * f :
+    ///   If the return value is Ok, it contains the corresponding
+    ///   [ADfn] object.
+    ///   Otherwise it contains an error message that includes the
+    ///   line number where the error was detected.
+    ///   Operator arguments and range indices are checked the same way as in
+    ///   [ADfn::from_bytes]; e.g., an index that refers to the result of a
+    ///   later operator is an error.
+    ///
+    /// # Example
+    /// see [ADfn::to_text]
+    pub fn from_text(text : &str) -> Result<ADfn<V>, String> {
+        let op_fns_vec : &Vec< OpFns<V> > = GlobalOpFnsVec::get();
+        let mut lines = text.lines().enumerate();
+        //
+        // header
+        if lines.next().map( |(_, line)| line.trim() ) != Some(TEXT_HEADER) {
+            return Err( parse_error(1, &format!( "expected {TEXT_HEADER}" ) ) );
+        }
+        //
+        // dyp, var
+        let dyp = parse_agraph(&mut lines, "dyp", op_fns_vec)?;
+        let var = parse_agraph(&mut lines, "var", op_fns_vec)?;
+        //
+        // rng_ad_type, rng_index
+        let mut rng_ad_type : Vec<ADType> = Vec::new();
+        let mut rng_index   : Vec<IndexT> = Vec::new();
+        let (line_index, line) = lines.next().unwrap_or( (0, "") );
+        let rng_text = line.strip_prefix("rng").and_then( parse_bracket );
+        let ok       = match rng_text {
+            Some( (rng_text, rest) ) => rest.trim().is_empty() &&
+                parse_typed_index(
+                    rng_text, &mut rng_ad_type, &mut rng_index
+                ).is_some(),
+            None => false,
+        };
+        if ! ok {
+            return Err( parse_error(line_index + 1, "expected rng [ indices ]") );
+        }
+        //
+        // cop
+        let mut cop : Vec<V> = Vec::new();
+        for (line_index, line) in lines {
+            let line_number = line_index + 1;
+            let mut split   = line.splitn(3, ' ');
+            let label       = split.next().unwrap_or("");
+            let index       = split.next().unwrap_or("");
+            let value       = split.next().unwrap_or("");
+            if label != "cop" || index.parse::<usize>() != Ok( cop.len() ) {
+                return Err( parse_error(line_number,
+                    &format!( "expected cop {} value", cop.len() )
+                ) );
+            }
+            let value = V::from_text(value).map_err(
+                |message| parse_error(line_number, &message)
+            )?;
+            cop.push( value );
+        }
+        //
+        // check indices
+        // The first operator of dyp is on line 3 and of var is on
+        // line 4 + dyp.n_dep; the rng line follows the var operators.
+        let n_cop = cop.len();
+        let n_dyp = dyp.n_dom + dyp.n_dep;
+        let n_var = var.n_dom + var.n_dep;
+        for (first_line, agraph, res_type) in [
+            (3,                 &dyp, ADType::DynamicP),
+            (4 + dyp.n_dep,     &var, ADType::Variable),
+        ] {
+            check_agraph::<V>(agraph, res_type, n_cop, n_dyp, n_var,
+                |op_index, message| parse_error(first_line + op_index, message)
+            )?;
+        }
+        check_rng(&rng_ad_type, &rng_index, n_cop, n_dyp, n_var).map_err(
+            |message| parse_error(4 + dyp.n_dep + var.n_dep, &message)
+        )?;
+        Ok( ADfn { dyp, var, rng_ad_type, rng_index, cop } )
+    }
+}
@@ -378,29 +378,23 @@ pub (crate) mod sealed {
 ///
 /// * V : see [doc_generic_v]
 ///
-/// This macro must be executed once for any type *V*  where
-/// `AD<V>` is used. The rustad package automatically executes it
-/// for the following types: `f32` , `f64` , `NumVec<f32>`, `NumVec<f64>`.
-///
-/// This macro can be invoked from anywhere given the following use statements:
-/// ```text
-///     use std::sync::RwLock;
-/// ```
+/// This macro is used by [setup_value_type](crate::setup_value_type) .
+#[doc(hidden)]
+#[macro_export]
 macro_rules! impl_global_atom_callback_vec{ ($V:ty) => {
     #[doc = concat!(
         "The atomic evaluation vector for value type `", stringify!($V), "`"
     ) ]
-    impl crate::atom::sealed::GlobalAtomCallbackVec for $V {
+    impl $crate::setup::private::GlobalAtomCallbackVec for $V {
         fn get() -> &'static
-        RwLock< Vec< crate::atom::AtomCallback<$V> > > {
+        std::sync::RwLock< Vec< $crate::atom::AtomCallback<$V> > > {
             pub(crate) static ATOM_CALLBACK_VEC :
-                RwLock< Vec< crate::atom::AtomCallback<$V> > > =
-                    RwLock::new( Vec::new() );
+                std::sync::RwLock< Vec< $crate::atom::AtomCallback<$V> > > =
+                    std::sync::RwLock::new( Vec::new() );
             &ATOM_CALLBACK_VEC
         }
     }
 } }
-pub(crate) use impl_global_atom_callback_vec;
 // ----------------------------------------------------------------------------
 // register_atom
 /// Register an atomic function.
@@ -1,6 +1,7 @@
 // SPDX-License-Identifier: EPL-2.0 OR GPL-2.0-or-later
 // SPDX-FileCopyrightText: Bradley M. Bell <bradbell@seanet.com>
 // SPDX-FileContributor: 2025-26 Bradley M. Bell
+#![allow(clippy::needless_range_loop)]
 //
 use rustad::utility::avg_seconds_to_execute;
 use rustad::{
@@ -36,6 +37,11 @@ fn six_times_normsq() -> usize
     2 * n3 + 3 * n2 + n
 }
 //
+// N_VEC
+// number of elements in the long vector cases; i.e., the number of sums
+// of squares computed at the same time.
+const N_VEC : usize = 1000;
+//
 // f32
 pub fn normsq_f32()
 {   let mut sumsq  = 0 as f32;
@@ -101,6 +107,106 @@ pub fn normsq_ad_nv_az_f64()
         six_times_normsq() as f64
     );
 }
+//
+// Vec< AzFloat<f32> >
+// the operations in the NumVec case using element by element loops
+pub fn normsq_loop_az_f32()
+{   let x          = vec![ AzFloat(1f32); N_VEC ];
+    let mut sumsq  = vec![ AzFloat(0 as f32); N_VEC ];
+    for j in 1 .. (N_SUM+1) {
+        let mut x_j = Vec::with_capacity(N_VEC);
+        for i in 0 .. N_VEC {
+            x_j.push( x[i] * AzFloat(j as f32) );
+        }
+        let mut sq_j = Vec::with_capacity(N_VEC);
+        for i in 0 .. N_VEC {
+            sq_j.push( x_j[i] * x_j[i] );
+        }
+        for i in 0 .. N_VEC {
+            sumsq[i] += &sq_j[i];
+        }
+    }
+    for i in 0 .. N_VEC {
+        assert_eq!( 6.0 * sumsq[i].to_inner(), six_times_normsq() as f32 );
+    }
+}
+//
+// NumVec< AzFloat<f32> >
+// element-wise operations on a long vector
+pub fn normsq_nv_long_az_f32()
+{   let x          = NumVec::new( vec![ AzFloat(1f32); N_VEC ] );
+    let mut sumsq  = NumVec::from( AzFloat(0 as f32) );
+    for j in 1 .. (N_SUM+1) {
+        let x_j   = &x * &NumVec::from( AzFloat(j as f32) );
+        sumsq    += &( &x_j * &x_j );
+    }
+    for i in 0 .. N_VEC {
+        assert_eq!(
+            6.0 * sumsq.get(i).to_inner(),
+            six_times_normsq() as f32
+        );
+    }
+}
+//
+// Vec< AzFloat<f64> >
+// the operations in the NumVec case using element by element loops
+pub fn normsq_loop_az_f64()
+{   let x          = vec![ AzFloat(1f64); N_VEC ];
+    let mut sumsq  = vec![ AzFloat(0 as f64); N_VEC ];
+    for j in 1 .. (N_SUM+1) {
+        let mut x_j = Vec::with_capacity(N_VEC);
+        for i in 0 .. N_VEC {
+            x_j.push( x[i] * AzFloat(j as f64) );
+        }
+        let mut sq_j = Vec::with_capacity(N_VEC);
+        for i in 0 .. N_VEC {
+            sq_j.push( x_j[i] * x_j[i] );
+        }
+        for i in 0 .. N_VEC {
+            sumsq[i] += &sq_j[i];
+        }
+    }
+    for i in 0 .. N_VEC {
+        assert_eq!( 6.0 * sumsq[i].to_inner(), six_times_normsq() as f64 );
+    }
+}
+//
+// NumVec< AzFloat<f64> >
+// element-wise operations on a long vector
+pub fn normsq_nv_long_az_f64()
+{   let x          = NumVec::new( vec![ AzFloat(1f64); N_VEC ] );
+    let mut sumsq  = NumVec::from( AzFloat(0 as f64) );
+    for j in 1 .. (N_SUM+1) {
+        let x_j   = &x * &NumVec::from( AzFloat(j as f64) );
+        sumsq    += &( &x_j * &x_j );
+    }
+    for i in 0 .. N_VEC {
+        assert_eq!(
+            6.0 * sumsq.get(i).to_inner(),
+            six_times_normsq() as f64
+        );
+    }
+}
+//
+// AD< NumVec< AzFloat<f64> > >
+// element-wise operations on a long vector
+pub fn normsq_ad_nv_long_az_f64()
+{   let x_nv       = NumVec::new( vec![ AzFloat(1f64); N_VEC ] );
+    let x          = AD::from( x_nv );
+    let mut sumsq  = AD::from( NumVec::from( AzFloat(0 as f64) ) );
+    for j in 1 .. (N_SUM+1) {
+        let ad_j  = AD::from( NumVec::from( AzFloat(j as f64) ) );
+        let x_j   = &x * &ad_j;
+        sumsq    += &( &x_j * &x_j );
+    }
+    let sumsq = sumsq.to_value();
+    for i in 0 .. N_VEC {
+        assert_eq!(
+            6.0 * sumsq.get(i).to_inner(),
+            six_times_normsq() as f64
+        );
+    }
+}
 
 fn bench( name : &str, test_case : fn() ) {
     let min_seconds = 0.25;
@@ -117,4 +223,11 @@ fn main() {
     bench( "normsq_nv_az_f64" ,     normsq_nv_az_f64 );
     bench( "normsq_ad_az_f64" ,     normsq_ad_az_f64 );
     bench( "normsq_ad_nv_az_f64" ,  normsq_ad_nv_az_f64 );
+    //
+    // long vectors
+    bench( "normsq_loop_az_f32" ,       normsq_loop_az_f32 );
+    bench( "normsq_nv_long_az_f32" ,    normsq_nv_long_az_f32 );
+    bench( "normsq_loop_az_f64" ,       normsq_loop_az_f64 );
+    bench( "normsq_nv_long_az_f64" ,    normsq_nv_long_az_f64 );
+    bench( "normsq_ad_nv_long_az_f64" , normsq_ad_nv_long_az_f64 );
 }
@@ -154,40 +154,34 @@ pub(crate) mod sealed {
 ///
 /// * V : see [doc_generic_v]
 ///
-/// This macro must be executed once for any type *V*  where
-/// `AD<V>` is used. The rustad package automatically executes it
-/// for the following types: `f32` , `f64` , `NumVec<f32>`, `NumVec<f64>`.
-///
-/// This macro can be invoked from anywhere given the following use statements:
-/// ```text
-///     use std::sync::RwLock;
-/// ```
+/// This macro is used by [setup_value_type](crate::setup_value_type) .
+#[doc(hidden)]
+#[macro_export]
 macro_rules! impl_global_checkpoint_info{ ($V:ty) => {
     #[doc = concat!(
         "The global Checkpoint vector for value type `", stringify!($V), "`"
     ) ]
-    impl crate::checkpoint::sealed::GlobalCheckpointInfoVec for $V {
-        fn get() -> &'static
-        RwLock< Vec< crate::checkpoint::sealed::CheckpointInfo<$V> > > {
-            pub(crate) static CHECKPOINT_VEC :
-                RwLock< Vec< crate::checkpoint::sealed::CheckpointInfo<$V> > > =
-                    RwLock::new( Vec::new() );
+    impl $crate::setup::private::GlobalCheckpointInfoVec for $V {
+        fn get() -> &'static std::sync::RwLock<
+            Vec< $crate::setup::private::CheckpointInfo<$V> >
+        > {
+            pub(crate) static CHECKPOINT_VEC : std::sync::RwLock<
+                Vec< $crate::setup::private::CheckpointInfo<$V> >
+            > = std::sync::RwLock::new( Vec::new() );
             &CHECKPOINT_VEC
         }
-        fn atom_id() -> &'static LazyLock<crate::IndexT> {
-            pub static ATOM_ID : LazyLock<crate::IndexT> = LazyLock::new(
-                || crate::checkpoint::register_checkpoint_atom::<$V>()
-            );
+        fn atom_id() -> &'static std::sync::LazyLock<$crate::IndexT> {
+            pub static ATOM_ID : std::sync::LazyLock<$crate::IndexT> =
+                std::sync::LazyLock::new(
+                    || $crate::setup::private::register_checkpoint_atom::<$V>()
+                );
             &ATOM_ID
         }
-
-
-
     }
 } }
-pub(crate) use impl_global_checkpoint_info;
 // -------------------------------------------------------------------------
-pub(crate) fn register_checkpoint_atom<V>()-> IndexT
+#[doc(hidden)]
+pub fn register_checkpoint_atom<V>()-> IndexT
 where
     V : Clone + From<f32> + std::fmt::Display,
     V : GlobalOpFnsVec + GlobalCheckpointInfoVec + GlobalAtomCallbackVec,
@@ -24,14 +24,16 @@ fn write_src_file(src_dir : &str, name : &str, source : &str) {
 /// * src_dir  :
 ///   is the directory we are creating. If it already exists, any files
 ///   there are left in place except for:
-///   lib.rs, triats.rs, az_float.rs, num_vec.rs.
+///   lib.rs, triats.rs, az_float.rs, num_vec.rs, complex.rs, interval.rs,
+///   primitive.rs, double_double.rs, erf.rs.
 ///
 /// * lib_src :
 ///   is an in memory representation of the data that is written to the
 ///   file *src_dir* `/lib.rs` .
 ///
 /// * Other Files :
-///   triats.rs, az_float.rs, num_vec.rs
+///   triats.rs, az_float.rs, num_vec.rs, complex.rs, interval.rs,
+///   primitive.rs, double_double.rs, erf.rs
 ///   are copies of the corresponding rustad files in src/float.
 ///
 pub fn create_src_dir(
@@ -46,6 +48,11 @@ pub fn create_src_dir(
     write_src_file(src_dir, "traits.rs",   crate::TRAITS_RS);
     write_src_file(src_dir, "az_float.rs", crate::AZ_FLOAT_RS);
     write_src_file(src_dir, "num_vec.rs",  crate::NUM_VEC_RS);
+    write_src_file(src_dir, "complex.rs",  crate::COMPLEX_RS);
+    write_src_file(src_dir, "interval.rs", crate::INTERVAL_RS);
+    write_src_file(src_dir, "primitive.rs", crate::PRIMITIVE_RS);
+    write_src_file(src_dir, "double_double.rs", crate::DOUBLE_DOUBLE_RS);
+    write_src_file(src_dir, "erf.rs",      crate::ERF_RS);
 }
 
 // ----------------------------------------------------------------------------
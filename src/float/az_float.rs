@@ -29,7 +29,10 @@ use crate::{
     FConst,
     FUnary,
     FBinary,
+    FReduce,
     FValue,
+    FBytes,
+    FText,
 };
 // ---------------------------------------------------------------------------
 /// The Absolute Zero Floating point class.
@@ -63,7 +66,8 @@ use crate::{
 ///       Note that this works element wise when x, y, u, v
 ///       are numeric vectors.
 ///
-/// * B : the floating point base class is either f32 or f64
+/// * B : the floating point base class is either f32, f64, or
+///   [DoubleDouble](crate::float::double_double::DoubleDouble) .
 ///
 /// * Zero : is an absolute zero; i.e. multiplication by zero
 ///   always results in zero (even if the other operand is nan).
@@ -420,6 +424,7 @@ macro_rules! impl_float_const{ ($B:ident) => {
         fn zero()         -> AzFloat<$B> { Self( 0 as $B ) }
         fn epsilon()      -> AzFloat<$B> { Self( $B::EPSILON ) }
         fn min_positive() -> AzFloat<$B> { Self( $B::MIN_POSITIVE ) }
+        const ABSOLUTE_ZERO : bool = true;
     }
 }}
 impl_float_const!(f32);
@@ -469,6 +474,12 @@ macro_rules! impl_float_unary{ ($B:ident) => {
         type Output = AzFloat<$B>;
         //
         // use float_unary_function
+        float_unary_function!($B, acos);
+        float_unary_function!($B, acosh);
+        float_unary_function!($B, asin);
+        float_unary_function!($B, asinh);
+        float_unary_function!($B, atan);
+        float_unary_function!($B, atanh);
         float_unary_function!($B, ln_1p);
         float_unary_function!($B, exp_m1);
         float_unary_function!($B, ln);
@@ -482,6 +493,14 @@ macro_rules! impl_float_unary{ ($B:ident) => {
         float_unary_function!($B, exp);
         float_unary_function!($B, cos);
         float_unary_function!($B, sin);
+        float_unary_function!($B, cbrt);
+        float_unary_function!($B, exp2);
+        float_unary_function!($B, log10);
+        float_unary_function!($B, log2);
+        float_unary_function!($B, ceil);
+        float_unary_function!($B, floor);
+        float_unary_function!($B, round);
+        float_unary_function!($B, trunc);
         //
         // square implements differently
         #[doc = "see [doc_f_unary_az_float]" ]
@@ -491,6 +510,16 @@ macro_rules! impl_float_unary{ ($B:ident) => {
         #[doc = "see [doc_f_unary_az_float]" ]
         fn minus(self) -> AzFloat<$B> { AzFloat( - self.0 ) }
         //
+        // erf, erfc are not yet stable in std; see src/float/erf.rs
+        #[doc = "see [doc_f_unary_az_float]" ]
+        fn erf(self) -> AzFloat<$B> {
+            AzFloat( super::erf::erf( f64::from(self.0) ) as $B )
+        }
+        #[doc = "see [doc_f_unary_az_float]" ]
+        fn erfc(self) -> AzFloat<$B> {
+            AzFloat( super::erf::erfc( f64::from(self.0) ) as $B )
+        }
+        //
         // binary functions, but it only has one float
         #[doc = "see [doc_f_unary_az_float]" ]
         fn powi(self, rhs : i32) -> AzFloat<$B>{
@@ -501,6 +530,12 @@ macro_rules! impl_float_unary{ ($B:ident) => {
         type Output = AzFloat<$B>;
         //
         // use float_unary_function
+        float_unary_function!($B, acos);
+        float_unary_function!($B, acosh);
+        float_unary_function!($B, asin);
+        float_unary_function!($B, asinh);
+        float_unary_function!($B, atan);
+        float_unary_function!($B, atanh);
         float_unary_function!($B, ln_1p);
         float_unary_function!($B, exp_m1);
         float_unary_function!($B, ln);
@@ -514,6 +549,14 @@ macro_rules! impl_float_unary{ ($B:ident) => {
         float_unary_function!($B, exp);
         float_unary_function!($B, cos);
         float_unary_function!($B, sin);
+        float_unary_function!($B, cbrt);
+        float_unary_function!($B, exp2);
+        float_unary_function!($B, log10);
+        float_unary_function!($B, log2);
+        float_unary_function!($B, ceil);
+        float_unary_function!($B, floor);
+        float_unary_function!($B, round);
+        float_unary_function!($B, trunc);
         //
         // square implements differently
         #[doc = "see [doc_f_unary_az_float]" ]
@@ -523,6 +566,16 @@ macro_rules! impl_float_unary{ ($B:ident) => {
         #[doc = "see [doc_f_unary_az_float]" ]
         fn minus(self) -> AzFloat<$B> { AzFloat( - self.0 ) }
         //
+        // erf, erfc are not yet stable in std; see src/float/erf.rs
+        #[doc = "see [doc_f_unary_az_float]" ]
+        fn erf(self) -> AzFloat<$B> {
+            AzFloat( super::erf::erf( f64::from(self.0) ) as $B )
+        }
+        #[doc = "see [doc_f_unary_az_float]" ]
+        fn erfc(self) -> AzFloat<$B> {
+            AzFloat( super::erf::erfc( f64::from(self.0) ) as $B )
+        }
+        //
         // binary functions, but it only has one float
         #[doc = "see [doc_f_unary_az_float]" ]
         fn powi(self, rhs : i32) -> AzFloat<$B>{
@@ -607,6 +660,8 @@ macro_rules! impl_f_binary_borrow{ ($B:ident) => {
         impl_f_binary_function_borrow!( $B, atan2 );
         impl_f_binary_function_borrow!( $B, hypot );
         impl_f_binary_function_borrow!( $B, powf );
+        impl_f_binary_function_borrow!( $B, min );
+        impl_f_binary_function_borrow!( $B, max );
     }
 } }
 impl_f_binary_borrow!(f32);
@@ -637,6 +692,41 @@ where
     impl_f_binary_function_own!( atan2 );
     impl_f_binary_function_own!( hypot );
     impl_f_binary_function_own!( powf );
+    impl_f_binary_function_own!( min );
+    impl_f_binary_function_own!( max );
+}
+// ----------------------------------------------------------------------------
+// FReduce
+/// Implement [FReduce] for `&AzFloat<B>` .
+///
+/// An `AzFloat<B>` is treated as a vector of length one;
+/// i.e., the sum, mean, and max are the value itself and the length is one.
+///
+/// # Example
+/// ```
+/// use rustad::{
+///     AzFloat,
+///     FReduce,
+/// };
+/// let two   = AzFloat(2f64);
+/// let three = AzFloat(3f64);
+/// assert_eq!( (&two).reduce_sum(), two );
+/// assert_eq!( (&two).reduce_len(), AzFloat(1f64) );
+/// assert_eq!( (&two).reduce_dot(&three), AzFloat(6f64) );
+/// ```
+impl<B> FReduce for &AzFloat<B>
+where
+    B          : Copy,
+    AzFloat<B> : FConst,
+    for<'a> &'a AzFloat<B> : Mul<&'a AzFloat<B>, Output = AzFloat<B> >,
+{
+    type Output = AzFloat<B>;
+    //
+    fn reduce_sum(self)  -> AzFloat<B> { *self }
+    fn reduce_mean(self) -> AzFloat<B> { *self }
+    fn reduce_max(self)  -> AzFloat<B> { *self }
+    fn reduce_len(self)  -> AzFloat<B> { AzFloat::one() }
+    fn reduce_dot(self, rhs : Self) -> AzFloat<B> { self * rhs }
 }
 // ----------------------------------------------------------------------------
 // FValue
@@ -686,3 +776,106 @@ macro_rules! impl_f_value{ ($B:ident) => {
 } }
 impl_f_value!(f32);
 impl_f_value!(f64);
+// ----------------------------------------------------------------------------
+// FBytes
+// doc_f_bytes_az_float
+/// [FBytes] for AzFloat.
+///
+/// * B : is the floating point base type
+///
+/// * type_name : is `AzFloat<f32>` or `AzFloat<f64>` .
+///
+/// * bytes : is the little endian representation of the base type value.
+///
+/// # Example
+/// ```
+/// use rustad::{
+///     AzFloat,
+///     FBytes,
+/// };
+/// type V = AzFloat<f32>;
+/// //
+/// let x         = V::from(3.0);
+/// let mut bytes = Vec::new();
+/// x.to_bytes(&mut bytes);
+/// assert_eq!( bytes.len(), 4 );
+/// //
+/// let mut start = 0;
+/// let y         = V::from_bytes(&bytes, &mut start).unwrap();
+/// assert_eq!( x, y );
+/// assert_eq!( start, 4 );
+/// assert_eq!( V::type_name(), "AzFloat<f32>" );
+/// ```
+pub fn doc_f_bytes_az_float() {}
+//
+macro_rules! impl_f_bytes{ ($B:ident) => {
+    impl FBytes for AzFloat<$B> {
+        fn type_name() -> String {
+            "AzFloat<".to_string() + stringify!($B) + ">"
+        }
+        fn to_bytes(&self, bytes : &mut Vec<u8>) {
+            bytes.extend_from_slice( &self.0.to_le_bytes() );
+        }
+        fn from_bytes(bytes : &[u8], start : &mut usize) -> Result<Self, String> {
+            let size = std::mem::size_of::<$B>();
+            let end  = *start + size;
+            if bytes.len() < end {
+                return Err( format!(
+                    "AzFloat<{}>::from_bytes: unexpected end of bytes",
+                    stringify!($B)
+                ) );
+            }
+            let mut array = [0u8; std::mem::size_of::<$B>()];
+            array.copy_from_slice( &bytes[*start .. end] );
+            *start = end;
+            Ok( AzFloat( $B::from_le_bytes(array) ) )
+        }
+    }
+} }
+impl_f_bytes!(f32);
+impl_f_bytes!(f64);
+// ----------------------------------------------------------------------------
+// FText
+// doc_f_text_az_float
+/// [FText] for AzFloat.
+///
+/// * B : is the floating point base type
+///
+/// * text :
+///   is the shortest decimal representation that converts back to the
+///   same base type value; e.g., `1.0` , `1e-7` , `-inf` , or `NaN` .
+///
+/// # Example
+/// ```
+/// use rustad::{
+///     AzFloat,
+///     FText,
+/// };
+/// type V = AzFloat<f64>;
+/// //
+/// let x    = V::from(0.1);
+/// let text = x.to_text();
+/// assert_eq!( text, "0.1" );
+/// assert_eq!( V::from_text(&text), Ok(x) );
+/// assert!( V::from_text("one").is_err() );
+/// ```
+pub fn doc_f_text_az_float() {}
+//
+macro_rules! impl_f_text{ ($B:ident) => {
+    impl FText for AzFloat<$B> {
+        fn to_text(&self) -> String {
+            format!( "{:?}", self.0 )
+        }
+        fn from_text(text : &str) -> Result<Self, String> {
+            match text.trim().parse::<$B>() {
+                Ok(value) => Ok( AzFloat(value) ),
+                Err(_)    => Err( format!(
+                    "AzFloat<{}>::from_text: invalid text: {text}",
+                    stringify!($B)
+                ) ),
+            }
+        }
+    }
+} }
+impl_f_text!(f32);
+impl_f_text!(f64);
@@ -0,0 +1,928 @@
+// ---------------------------------------------------------------------------
+// SPDX-License-Identifier: EPL-2.0 OR GPL-2.0-or-later
+// SPDX-FileCopyrightText: Bradley M. Bell <bradbell@seanet.com>
+// SPDX-FileContributor: 2026 Bradley M. Bell
+// ---------------------------------------------------------------------------
+//
+//! This pub module defines the rustad Complex class.
+//!
+//! Link to [parent module](super)
+//!
+//! This module does not have dependencies outside standard rust and src/float.
+//! This enables src/float to be directly included as part of a Dll library.
+//!
+// ---------------------------------------------------------------------------
+// use
+//
+use std::ops::{
+    Add,
+    Sub,
+    Mul,
+    Div,
+    AddAssign,
+    SubAssign,
+    MulAssign,
+    DivAssign,
+};
+//
+use crate::{
+    AzFloat,
+    FConst,
+    FUnary,
+    FBinary,
+    FReduce,
+    FValue,
+    FBytes,
+    FText,
+};
+// ---------------------------------------------------------------------------
+/// The Complex number class.
+///
+/// * S : is the type of the real and imaginary parts;
+///   i.e., `AzFloat<f32>` or `AzFloat<f64>` .
+///
+/// * Zero : Because the parts are [AzFloat] objects,
+///   multiplication by the complex zero always results in zero
+///   (even if the other operand is nan).
+///
+/// * Nan : Two complex numbers are equal if their real parts are equal
+///   and their imaginary parts are equal; e.g., nan is equal to nan.
+///
+/// * Copy : The Copy trait is implemented for these types.
+///
+/// * Analytic Functions :
+///   The [FUnary] and [FBinary] functions that are analytic
+///   use the principal branch; e.g., ln, sqrt, powf.
+///   Derivatives of these functions are complex derivatives.
+///
+/// * Other Functions :
+///   abs returns the modulus (as a complex number with zero imaginary part)
+///   and signum returns z / abs(z) .
+///   ceil, floor, round, and trunc act on the real and imaginary parts.
+///   num_eq and num_ne compare both parts.
+///   The functions that require an ordering
+///   (num_lt, num_le, num_ge, num_gt, atan2, min, max)
+///   use the real parts when both imaginary parts are zero
+///   and otherwise return nan; see [doc_f_binary_complex] .
+///   erf and erfc return nan.
+///
+/// # Example
+/// ```
+/// use rustad::{
+///     AzFloat,
+///     Complex,
+/// };
+/// type S    = AzFloat<f64>;
+/// type V    = Complex<S>;
+/// //
+/// let i     = V::new( S::from(0.0), S::from(1.0) );
+/// let prod  = &i * &i;
+/// assert_eq!( prod, V::from(-1.0) );
+/// //
+/// let zero  = V::from(0.0);
+/// let nan   = V::from( f64::NAN );
+/// assert_eq!( &zero * &nan, zero );
+/// assert_eq!( nan, nan );
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Complex<S> {
+    /// real part
+    pub re : S,
+    /// imaginary part
+    pub im : S,
+}
+//
+impl<S> Complex<S> {
+    //
+    // new
+    /// Create a complex number from its real and imaginary parts
+    pub fn new(re : S, im : S) -> Self {
+        Self{ re, im }
+    }
+}
+// ---------------------------------------------------------------------------
+/// Complex From
+///
+/// * `Complex< AzFloat<f32> >` :
+///   From is implemented for usize, f32, and `AzFloat<f32>` .
+///
+/// * `Complex< AzFloat<f64> >` :
+///   From is implemented for usize, f32, f64, and `AzFloat<f64>` .
+///
+/// The imaginary part of the result is zero.
+pub fn doc_impl_from() {}
+//
+/// see [doc_impl_from]
+impl<S> From<S> for Complex<S>
+where
+    S : FConst ,
+{
+    fn from(re : S) -> Complex<S> {
+        Complex{ re, im : S::zero() }
+    }
+}
+macro_rules! impl_from_primitive{ ($P:ident, $T:ident) => {
+    #[doc = "see [doc_impl_from]" ]
+    impl From<$P> for Complex< AzFloat<$T> > {
+        fn from(p : $P) -> Complex< AzFloat<$T> > {
+            Complex{ re : AzFloat( p as $T ), im : AzFloat( 0 as $T ) }
+        }
+    }
+} }
+impl_from_primitive!(usize, f32);
+impl_from_primitive!(f32, f32);
+impl_from_primitive!(usize, f64);
+impl_from_primitive!(f32, f64);
+impl_from_primitive!(f64, f64);
+// ---------------------------------------------------------------------------
+// Complex Op Complex
+/// Complex binary operations
+///
+/// * Syntax :
+/// ```text
+///     z = x Op y
+/// ```
+///
+/// * S : is the type of the real and imaginary parts
+///
+/// * Op : is the source code token for this binary operator;
+///   i.e., `+` , `-` , `*` , or `/` .
+///
+/// * x : left hand side `Complex<S>` or `&Complex<S>` object
+/// * y : right hand side `Complex<S>` or `&Complex<S>` object
+/// * z : result `Complex<S>` object
+///
+/// If the left or right operand is borrowed (&), then both operands
+/// must be borrowed.
+///
+/// # Example :
+/// ```
+/// use rustad::{
+///     AzFloat,
+///     Complex,
+/// };
+/// type S = AzFloat<f64>;
+/// type V = Complex<S>;
+/// //
+/// let x  = V::new( S::from(1.0), S::from(2.0) );
+/// let y  = V::new( S::from(3.0), S::from(4.0) );
+/// let z  = x + y;
+/// assert_eq!( z, V::new( S::from(4.0), S::from(6.0) ) );
+/// let z  = &x * &y;
+/// assert_eq!( z, V::new( S::from(-5.0), S::from(10.0) ) );
+/// let z  = &z / &y;
+/// assert_eq!( z, x );
+/// ```
+pub fn doc_binary_operator() { }
+//
+/// see [doc_binary_operator]
+impl<S> Add< &Complex<S> > for &Complex<S>
+where
+    S : Copy + Add<Output=S> ,
+{
+    type Output = Complex<S>;
+    fn add(self, rhs : &Complex<S>) -> Complex<S> {
+        Complex{ re : self.re + rhs.re, im : self.im + rhs.im }
+    }
+}
+/// see [doc_binary_operator]
+impl<S> Sub< &Complex<S> > for &Complex<S>
+where
+    S : Copy + Sub<Output=S> ,
+{
+    type Output = Complex<S>;
+    fn sub(self, rhs : &Complex<S>) -> Complex<S> {
+        Complex{ re : self.re - rhs.re, im : self.im - rhs.im }
+    }
+}
+/// see [doc_binary_operator]
+impl<S> Mul< &Complex<S> > for &Complex<S>
+where
+    S : Copy + Add<Output=S> + Sub<Output=S> + Mul<Output=S> ,
+{
+    type Output = Complex<S>;
+    fn mul(self, rhs : &Complex<S>) -> Complex<S> {
+        Complex{
+            re : self.re * rhs.re - self.im * rhs.im ,
+            im : self.re * rhs.im + self.im * rhs.re ,
+        }
+    }
+}
+/// see [doc_binary_operator]
+impl<S> Div< &Complex<S> > for &Complex<S>
+where
+    S : Copy + Add<Output=S> + Sub<Output=S> + Mul<Output=S> + Div<Output=S>,
+{
+    type Output = Complex<S>;
+    fn div(self, rhs : &Complex<S>) -> Complex<S> {
+        let den = rhs.re * rhs.re + rhs.im * rhs.im;
+        Complex{
+            re : (self.re * rhs.re + self.im * rhs.im) / den ,
+            im : (self.im * rhs.re - self.re * rhs.im) / den ,
+        }
+    }
+}
+macro_rules! impl_binary_operator{ ($Name:ident, $name:ident) =>  {
+    #[doc = "see [doc_binary_operator]"]
+    impl<S> $Name for Complex<S>
+    where
+        for<'a> &'a Complex<S> : $Name<&'a Complex<S>, Output=Complex<S> >,
+    {
+        type Output = Complex<S>;
+        fn $name(self, rhs : Self) -> Complex<S> {
+            (&self).$name(&rhs)
+        }
+    }
+} }
+impl_binary_operator!(Add, add);
+impl_binary_operator!(Sub, sub);
+impl_binary_operator!(Mul, mul);
+impl_binary_operator!(Div, div);
+// ---------------------------------------------------------------------------
+// Complex Op &Complex
+/// Complex binary assign operations
+///
+/// * S : is the type of the real and imaginary parts
+///
+/// * Syntax :
+///   ```text
+///     lhs op rhs
+///     lhs op &rhs
+///   ```
+///
+/// * lhs : is the `&mut Complex<S>` left operand
+/// * rhs : is the `Complex<S>` or `&Complex<S>` right operand
+/// * op  : is one of `+=` , `-=` , `*=` , `/=`
+///
+/// # Example :
+/// ```
+/// use rustad::{
+///     AzFloat,
+///     Complex,
+/// };
+/// type V = Complex< AzFloat<f32> >;
+/// //
+/// let mut x = V::from(12.0);
+/// let y     = V::from(4.0);
+/// //
+/// x        /= &y;
+/// assert_eq!( x, V::from(3.0) );
+/// //
+/// x        -= y;
+/// assert_eq!( x, V::from(-1.0) );
+/// ```
+pub fn doc_binary_assign() {}
+//
+macro_rules! impl_binary_assign{ ($Name:ident, $name:ident, $Op:ident, $op:tt) => {
+    #[doc = "see [doc_binary_assign]"]
+    impl<S> $Name <&Complex<S> > for Complex<S>
+    where
+        for<'a> &'a Complex<S> : $Op<&'a Complex<S>, Output=Complex<S> >,
+    {
+        fn $name(&mut self, rhs : &Complex<S> ) {
+            *self = &*self $op rhs;
+        }
+    }
+    #[doc = "see [doc_binary_assign]"]
+    impl<S> $Name <Complex<S> > for Complex<S>
+    where
+        for<'a> &'a Complex<S> : $Op<&'a Complex<S>, Output=Complex<S> >,
+    {
+        fn $name(&mut self, rhs : Complex<S> ) {
+            *self = &*self $op &rhs;
+        }
+    }
+} }
+impl_binary_assign!(AddAssign, add_assign, Add, +);
+impl_binary_assign!(SubAssign, sub_assign, Sub, -);
+impl_binary_assign!(MulAssign, mul_assign, Mul, *);
+impl_binary_assign!(DivAssign, div_assign, Div, /);
+// ---------------------------------------------------------------------------
+// PartialEq, Eq
+/// Complex Eq Operator
+///
+/// * S : is the type of the real and imaginary parts
+///
+/// # Example
+/// ```
+/// use rustad::{
+///     AzFloat,
+///     Complex,
+/// };
+/// type S  = AzFloat<f32>;
+/// let nan = Complex::new( S::from(1.0), S::from(f32::NAN) );
+/// assert_eq!( nan, nan );
+/// ```
+impl<S> PartialEq for Complex<S>
+where
+    S : PartialEq ,
+{
+    fn eq(&self, rhs : &Self) -> bool {
+        self.re == rhs.re && self.im == rhs.im
+    }
+}
+impl<S: PartialEq> Eq for Complex<S> { }
+// ---------------------------------------------------------------------------
+/// Display a Complex object
+///
+/// * S : is the type of the real and imaginary parts
+///
+/// # Example
+/// ```
+/// use rustad::{
+///     AzFloat,
+///     Complex,
+/// };
+/// type S = AzFloat<f64>;
+/// let z  = Complex::new( S::from(1.0), S::from(-2.5) );
+/// assert_eq!( format!("{z}"), "1-2.5i" );
+/// let z  = Complex::new( S::from(1.0), S::from(2.5) );
+/// assert_eq!( format!("{z}"), "1+2.5i" );
+/// ```
+impl<S> std::fmt::Display for Complex<S>
+where
+    S : std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let im = self.im.to_string();
+        if im.starts_with('-') {
+            write!(f, "{}{}i", self.re, im)
+        } else {
+            write!(f, "{}+{}i", self.re, im)
+        }
+    }
+}
+// ---------------------------------------------------------------------------
+/// Hash function for Complex objects
+///
+/// * S : is the type of the real and imaginary parts
+///
+/// # Example
+/// ```
+/// use rustc_hash::FxHashMap;
+/// use rustad::{
+///     AzFloat,
+///     Complex,
+/// };
+/// type S      = AzFloat<f64>;
+/// let mut map : FxHashMap<Complex<S>, u32> = FxHashMap::default();
+/// let z1      = Complex::new( S::from(1.0), S::from(2.0) );
+/// let z2      = Complex::new( S::from(2.0), S::from(1.0) );
+/// map.insert(z1, 1u32);
+///
+/// let option  = map.get_key_value(&z1);
+/// assert_eq!(option, Some( (&z1, &1u32) ) );
+///
+/// let option  = map.get_key_value(&z2);
+/// assert_eq!(option, None );
+/// ```
+impl<S> std::hash::Hash for Complex<S>
+where
+    S : std::hash::Hash,
+{
+    fn hash<H : std::hash::Hasher>(&self, state : &mut H) {
+        self.re.hash(state);
+        self.im.hash(state);
+    }
+}
+// ---------------------------------------------------------------------------
+// FConst
+/// Implements the FConst trait for Complex types;
+/// the imaginary part of these constants is zero.
+impl<S> FConst for Complex<S>
+where
+    S : FConst ,
+{
+    fn pi()           -> Complex<S> { Self::from( S::pi() ) }
+    fn nan()          -> Complex<S> { Complex{ re : S::nan(), im : S::nan() } }
+    fn one()          -> Complex<S> { Self::from( S::one() ) }
+    fn zero()         -> Complex<S> { Self::from( S::zero() ) }
+    fn epsilon()      -> Complex<S> { Self::from( S::epsilon() ) }
+    fn min_positive() -> Complex<S> { Self::from( S::min_positive() ) }
+    const ABSOLUTE_ZERO : bool = S::ABSOLUTE_ZERO;
+}
+// ----------------------------------------------------------------------------
+// doc_f_unary_complex
+/// Complex unary functions
+///
+/// * Syntax : `y = x.Name()`
+///
+/// * B : is the floating point base type; i.e., f32 or f64
+///
+/// * Name : is the name of one of the [FUnary] functions.
+///
+/// * x : is a `Complex< AzFloat<B> >` or `&Complex< AzFloat<B> >` object.
+///
+/// * y : is the `Complex< AzFloat<B> >` result.
+///
+/// See [Complex] for the meaning of the functions that are not analytic.
+///
+/// # Example
+/// ```
+/// use rustad::{
+///     AzFloat,
+///     Complex,
+///     FConst,
+///     FUnary,
+///     nearly_eq,
+/// };
+/// type S = AzFloat<f64>;
+/// type V = Complex<S>;
+/// let opt_vec : Vec<[&str; 2]> = Vec::new();
+/// //
+/// // exp( i pi ) = -1
+/// let i_pi    = V::new( S::from(0.0), S::pi() );
+/// let y       = i_pi.exp();
+/// assert!( nearly_eq::<V>(&y, &V::from(-1.0), &opt_vec) );
+/// //
+/// // sqrt(-4) = 2 i
+/// let y       = FUnary::sqrt( &V::from(-4.0) );
+/// assert_eq!( y, V::new( S::from(0.0), S::from(2.0) ) );
+/// ```
+pub fn doc_f_unary_complex() {}
+//
+macro_rules! complex_unary_function{ ($B:ident, $name:ident) => {
+    #[doc = "see [doc_f_unary_complex]" ]
+    fn $name(self) -> Complex< AzFloat<$B> > { (*self).$name() }
+} }
+macro_rules! impl_complex_unary{ ($B:ident) => {
+    impl FUnary for Complex< AzFloat<$B> > {
+        type Output = Complex< AzFloat<$B> >;
+        // BEGIN_SORT_THIS_LINE_PLUS_1
+        fn abs(self) -> Self {
+            Self::from( self.re.hypot(self.im) )
+        }
+        fn acos(self) -> Self {
+            let pi_2 = Self::from( AzFloat::<$B>::pi() / AzFloat::from(2.0) );
+            pi_2 - self.asin()
+        }
+        fn acosh(self) -> Self {
+            let one = Self::one();
+            let w   = (self + one).sqrt() * (self - one).sqrt();
+            (self + w).ln()
+        }
+        fn asin(self) -> Self {
+            // -i * ln( i * z + sqrt(1 - z^2) )
+            let one = Self::one();
+            let iz  = Self::new( self.im.minus(), self.re );
+            let w   = ( iz + (one - self * self).sqrt() ).ln();
+            Self::new( w.im, w.re.minus() )
+        }
+        fn asinh(self) -> Self {
+            let one = Self::one();
+            ( self + (self * self + one).sqrt() ).ln()
+        }
+        fn atan(self) -> Self {
+            // (i / 2) * ( ln(1 - i * z) - ln(1 + i * z) )
+            let one = Self::one();
+            let two = AzFloat::<$B>::from(2.0);
+            let iz  = Self::new( self.im.minus(), self.re );
+            let w   = (one - iz).ln() - (one + iz).ln();
+            Self::new( w.im.minus() / two, w.re / two )
+        }
+        fn atanh(self) -> Self {
+            let one = Self::one();
+            let two = Self::from(2.0);
+            ( (one + self).ln() - (one - self).ln() ) / two
+        }
+        fn cbrt(self) -> Self {
+            if self.is_zero() {
+                return self;
+            }
+            ( self.ln() / Self::from(3.0) ).exp()
+        }
+        fn ceil(self) -> Self {
+            Self::new( self.re.ceil(), self.im.ceil() )
+        }
+        fn cos(self) -> Self {
+            Self::new(
+                self.re.cos() * self.im.cosh(),
+                ( self.re.sin() * self.im.sinh() ).minus(),
+            )
+        }
+        fn cosh(self) -> Self {
+            Self::new(
+                self.re.cosh() * self.im.cos(),
+                self.re.sinh() * self.im.sin(),
+            )
+        }
+        fn erf(self) -> Self {
+            Self::nan()
+        }
+        fn erfc(self) -> Self {
+            Self::nan()
+        }
+        fn exp(self) -> Self {
+            let exp_re = self.re.exp();
+            Self::new( exp_re * self.im.cos(), exp_re * self.im.sin() )
+        }
+        fn exp2(self) -> Self {
+            let ln_2 = Self::from( std::$B::consts::LN_2 );
+            ( self * ln_2 ).exp()
+        }
+        fn exp_m1(self) -> Self {
+            self.exp() - Self::one()
+        }
+        fn floor(self) -> Self {
+            Self::new( self.re.floor(), self.im.floor() )
+        }
+        fn ln(self) -> Self {
+            Self::new( self.re.hypot(self.im).ln(), self.im.atan2(self.re) )
+        }
+        fn ln_1p(self) -> Self {
+            ( Self::one() + self ).ln()
+        }
+        fn log10(self) -> Self {
+            self.ln() / Self::from( std::$B::consts::LN_10 )
+        }
+        fn log2(self) -> Self {
+            self.ln() / Self::from( std::$B::consts::LN_2 )
+        }
+        fn minus(self) -> Self {
+            Self::new( self.re.minus(), self.im.minus() )
+        }
+        fn round(self) -> Self {
+            Self::new( self.re.round(), self.im.round() )
+        }
+        fn signum(self) -> Self {
+            if self.is_zero() {
+                return self;
+            }
+            self / self.abs()
+        }
+        fn sin(self) -> Self {
+            Self::new(
+                self.re.sin() * self.im.cosh(),
+                self.re.cos() * self.im.sinh(),
+            )
+        }
+        fn sinh(self) -> Self {
+            Self::new(
+                self.re.sinh() * self.im.cos(),
+                self.re.cosh() * self.im.sin(),
+            )
+        }
+        fn sqrt(self) -> Self {
+            // principal branch: the real part is not negative
+            let two = AzFloat::<$B>::from(2.0);
+            let r   = self.re.hypot(self.im);
+            let re  = ( (r + self.re) / two ).sqrt();
+            let im  = ( (r - self.re) / two ).sqrt() * self.im.signum();
+            Self::new(re, im)
+        }
+        fn square(self) -> Self {
+            self * self
+        }
+        fn tan(self) -> Self {
+            self.sin() / self.cos()
+        }
+        fn tanh(self) -> Self {
+            self.sinh() / self.cosh()
+        }
+        fn trunc(self) -> Self {
+            Self::new( self.re.trunc(), self.im.trunc() )
+        }
+        // END_SORT_THIS_LINE_MINUS_1
+        //
+        // binary functions, but it only has one complex
+        fn powi(self, rhs : i32) -> Self {
+            let mut base   = if rhs < 0 { Self::one() / self } else { self };
+            let mut result = Self::one();
+            let mut n      = rhs.unsigned_abs();
+            while n > 0 {
+                if n % 2 == 1 {
+                    result *= base;
+                }
+                base = base * base;
+                n   /= 2;
+            }
+            result
+        }
+    }
+    impl FUnary for &Complex< AzFloat<$B> > {
+        type Output = Complex< AzFloat<$B> >;
+        //
+        // use complex_unary_function
+        complex_unary_function!($B, abs);
+        complex_unary_function!($B, acos);
+        complex_unary_function!($B, acosh);
+        complex_unary_function!($B, asin);
+        complex_unary_function!($B, asinh);
+        complex_unary_function!($B, atan);
+        complex_unary_function!($B, atanh);
+        complex_unary_function!($B, cbrt);
+        complex_unary_function!($B, ceil);
+        complex_unary_function!($B, cos);
+        complex_unary_function!($B, cosh);
+        complex_unary_function!($B, erf);
+        complex_unary_function!($B, erfc);
+        complex_unary_function!($B, exp);
+        complex_unary_function!($B, exp2);
+        complex_unary_function!($B, exp_m1);
+        complex_unary_function!($B, floor);
+        complex_unary_function!($B, ln);
+        complex_unary_function!($B, ln_1p);
+        complex_unary_function!($B, log10);
+        complex_unary_function!($B, log2);
+        complex_unary_function!($B, minus);
+        complex_unary_function!($B, round);
+        complex_unary_function!($B, signum);
+        complex_unary_function!($B, sin);
+        complex_unary_function!($B, sinh);
+        complex_unary_function!($B, sqrt);
+        complex_unary_function!($B, square);
+        complex_unary_function!($B, tan);
+        complex_unary_function!($B, tanh);
+        complex_unary_function!($B, trunc);
+        //
+        #[doc = "see [doc_f_unary_complex]" ]
+        fn powi(self, rhs : i32) -> Complex< AzFloat<$B> > {
+            (*self).powi(rhs)
+        }
+    }
+} }
+impl_complex_unary!(f32);
+impl_complex_unary!(f64);
+// ---------------------------------------------------------------------------
+// FBinary for Complex
+/// Implement [FBinary] when both operands are `Complex< AzFloat<B> >`
+/// or `&Complex< AzFloat<B> >` .
+///
+/// * B : is the floating point base type; i.e., f32 or f64
+///
+/// * num_eq, num_ne :
+///   return one (zero) if both parts are equal (not equal).
+///
+/// * hypot, powf :
+///   hypot(x, y) is sqrt( x^2 + y^2 ) and powf(x, y) is exp( y * ln(x) ) .
+///
+/// * Other functions :
+///   num_lt, num_le, num_ge, num_gt, atan2, min, max
+///   use the real parts when both imaginary parts are zero.
+///   Otherwise they return nan.
+///
+/// # Example
+/// ```
+/// use rustad::{
+///     AzFloat,
+///     Complex,
+///     FBinary,
+///     FValue,
+///     nearly_eq,
+/// };
+/// type S     = AzFloat<f64>;
+/// type V     = Complex<S>;
+/// let opt_vec : Vec<[&str; 2]> = Vec::new();
+/// //
+/// let i      = V::new( S::from(0.0), S::from(1.0) );
+/// let two    = V::from(2.0);
+/// let minus  = V::from(-1.0);
+/// assert!( nearly_eq::<V>( &i.powf(two), &minus, &opt_vec ) );
+/// assert!( i.num_eq(i).is_one() );
+/// assert!( i.num_lt(two).is_nan() );
+/// assert!( minus.num_lt(two).is_one() );
+/// ```
+pub fn doc_f_binary_complex() {}
+//
+macro_rules! complex_ordered_function{ ($B:ident, $name:ident) => {
+    #[doc = "see [doc_f_binary_complex]" ]
+    fn $name(self, rhs : &Complex< AzFloat<$B> >) -> Complex< AzFloat<$B> > {
+        if self.im.is_zero() && rhs.im.is_zero() {
+            Complex::from( self.re.$name(rhs.re) )
+        } else {
+            Complex::nan()
+        }
+    }
+} }
+macro_rules! impl_complex_binary{ ($B:ident) => {
+    impl FBinary< &Complex< AzFloat<$B> > > for &Complex< AzFloat<$B> >
+    {
+        type Output = Complex< AzFloat<$B> >;
+        //
+        complex_ordered_function!($B, num_lt);
+        complex_ordered_function!($B, num_le);
+        fn num_eq(self, rhs : &Complex< AzFloat<$B> >) -> Self::Output {
+            if self.re.0 == rhs.re.0 && self.im.0 == rhs.im.0 {
+                Complex::one()
+            } else {
+                Complex::zero()
+            }
+        }
+        fn num_ne(self, rhs : &Complex< AzFloat<$B> >) -> Self::Output {
+            Complex::one() - self.num_eq(rhs)
+        }
+        complex_ordered_function!($B, num_ge);
+        complex_ordered_function!($B, num_gt);
+        complex_ordered_function!($B, atan2);
+        fn hypot(self, rhs : &Complex< AzFloat<$B> >) -> Self::Output {
+            ( self * self + rhs * rhs ).sqrt()
+        }
+        fn powf(self, rhs : &Complex< AzFloat<$B> >) -> Self::Output {
+            if self.is_zero() {
+                if rhs.is_zero() {
+                    return Complex::one();
+                }
+                return Complex::zero();
+            }
+            ( rhs * &self.ln() ).exp()
+        }
+        complex_ordered_function!($B, min);
+        complex_ordered_function!($B, max);
+    }
+} }
+impl_complex_binary!(f32);
+impl_complex_binary!(f64);
+//
+/// see [doc_f_binary_complex]
+macro_rules! impl_f_binary_function_own{ ($name:ident) => {
+    #[doc = concat!( " Complex::", stringify!($name)  ) ]
+    fn $name(self : Complex<S>, rhs : Complex<S>) -> Complex<S> {
+        FBinary::$name( &self,  &rhs )
+    }
+} }
+//
+impl<S> FBinary< Complex<S> > for Complex<S>
+where
+    for<'a> &'a Complex<S> : FBinary< &'a Complex<S>, Output = Complex<S> >,
+{
+    type Output = Complex<S>;
+    //
+    impl_f_binary_function_own!( num_lt );
+    impl_f_binary_function_own!( num_le );
+    impl_f_binary_function_own!( num_eq );
+    impl_f_binary_function_own!( num_ne );
+    impl_f_binary_function_own!( num_ge );
+    impl_f_binary_function_own!( num_gt );
+    impl_f_binary_function_own!( atan2 );
+    impl_f_binary_function_own!( hypot );
+    impl_f_binary_function_own!( powf );
+    impl_f_binary_function_own!( min );
+    impl_f_binary_function_own!( max );
+}
+// ----------------------------------------------------------------------------
+// FReduce
+/// Implement [FReduce] for `&Complex<S>` .
+///
+/// * S : is the type of the real and imaginary parts
+///
+/// The sum, mean, and max of a complex value are the value itself
+/// and its length is one.
+impl<S> FReduce for &Complex<S>
+where
+    S           : Copy,
+    Complex<S>  : FConst,
+    for<'a> &'a Complex<S> : Mul<&'a Complex<S>, Output = Complex<S> >,
+{
+    type Output = Complex<S>;
+    //
+    fn reduce_sum(self)  -> Complex<S> { *self }
+    fn reduce_mean(self) -> Complex<S> { *self }
+    fn reduce_max(self)  -> Complex<S> { *self }
+    fn reduce_len(self)  -> Complex<S> { Complex::one() }
+    fn reduce_dot(self, rhs : Self) -> Complex<S> { self * rhs }
+}
+// ----------------------------------------------------------------------------
+// FValue
+// doc_f_value_complex
+/// [FValue] for Complex.
+///
+/// * S : is the type of the real and imaginary parts
+///
+/// * is_zero, is_one :
+///   the real part is zero, one and the imaginary part is zero.
+///
+/// * is_nan :
+///   the real part or the imaginary part is nan.
+///
+/// # Example
+/// ```
+/// use rustad::{
+///     AzFloat,
+///     Complex,
+///     FConst,
+///     FValue,
+/// };
+/// type V = Complex< AzFloat<f64> >;
+/// //
+/// let one = V::one();
+/// assert!( one.is_one() );
+/// assert!( ! one.is_zero() );
+/// assert_eq!( one.to_src(), "Complex::new( AzFloat(1 as f64), AzFloat(0 as f64) )" );
+/// ```
+pub fn doc_f_value_complex() {}
+impl<S> FValue for Complex<S>
+where
+    S : FValue ,
+{
+    fn is_zero(&self) -> bool { self.re.is_zero() && self.im.is_zero() }
+    fn is_one(&self)  -> bool { self.re.is_one()  && self.im.is_zero() }
+    fn is_nan(&self)  -> bool { self.re.is_nan()  || self.im.is_nan() }
+    fn to_src(&self)  -> String {
+        "Complex::new( ".to_string() +
+            &self.re.to_src() + ", " + &self.im.to_src() +
+        " )"
+    }
+}
+// ----------------------------------------------------------------------------
+// FBytes
+// doc_f_bytes_complex
+/// [FBytes] for Complex.
+///
+/// * S : is the type of the real and imaginary parts
+///
+/// * type_name : is `Complex<` *name* `>` where *name* is the type name for S.
+///
+/// * bytes :
+///   is the binary representation of the real part followed by
+///   the binary representation of the imaginary part.
+///
+/// # Example
+/// ```
+/// use rustad::{
+///     AzFloat,
+///     Complex,
+///     FBytes,
+/// };
+/// type S = AzFloat<f64>;
+/// type V = Complex<S>;
+/// //
+/// let x         = V::new( S::from(1.0), S::from(2.0) );
+/// let mut bytes = Vec::new();
+/// x.to_bytes(&mut bytes);
+/// assert_eq!( bytes.len(), 2 * 8 );
+/// //
+/// let mut start = 0;
+/// let y         = V::from_bytes(&bytes, &mut start).unwrap();
+/// assert_eq!( x, y );
+/// assert_eq!( V::type_name(), "Complex<AzFloat<f64>>" );
+/// ```
+pub fn doc_f_bytes_complex() {}
+impl<S> FBytes for Complex<S>
+where
+    S : FBytes ,
+{
+    fn type_name() -> String {
+        "Complex<".to_string() + &S::type_name() + ">"
+    }
+    fn to_bytes(&self, bytes : &mut Vec<u8>) {
+        self.re.to_bytes(bytes);
+        self.im.to_bytes(bytes);
+    }
+    fn from_bytes(bytes : &[u8], start : &mut usize) -> Result<Self, String> {
+        let re = S::from_bytes(bytes, start)?;
+        let im = S::from_bytes(bytes, start)?;
+        Ok( Complex{ re, im } )
+    }
+}
+// ----------------------------------------------------------------------------
+// FText
+// doc_f_text_complex
+/// [FText] for Complex.
+///
+/// * S : is the type of the real and imaginary parts
+///
+/// * text :
+///   is the [FText] representation of the real and imaginary parts,
+///   separated by a comma and enclosed in parenthesis.
+///
+/// # Example
+/// ```
+/// use rustad::{
+///     AzFloat,
+///     Complex,
+///     FText,
+/// };
+/// type S = AzFloat<f64>;
+/// type V = Complex<S>;
+/// //
+/// let x    = V::new( S::from(1.0), S::from(-2.5) );
+/// let text = x.to_text();
+/// assert_eq!( text, "( 1.0, -2.5 )" );
+/// assert_eq!( V::from_text(&text), Ok(x) );
+/// ```
+pub fn doc_f_text_complex() {}
+impl<S> FText for Complex<S>
+where
+    S : FText ,
+{
+    fn to_text(&self) -> String {
+        "( ".to_string() + &self.re.to_text() + ", " + &self.im.to_text() + " )"
+    }
+    fn from_text(text : &str) -> Result<Self, String> {
+        let inner = text.trim().strip_prefix('(').and_then(
+            |rest| rest.strip_suffix(')')
+        );
+        let parts : Vec<&str> = match inner {
+            Some(inner) => inner.split(',').collect(),
+            None        => Vec::new(),
+        };
+        if parts.len() != 2 {
+            return Err( format!(
+                "Complex::from_text: expected ( re, im ): {text}"
+            ) );
+        }
+        let re = S::from_text( parts[0] )?;
+        let im = S::from_text( parts[1] )?;
+        Ok( Complex{ re, im } )
+    }
+}
@@ -0,0 +1,1561 @@
+// ---------------------------------------------------------------------------
+// SPDX-License-Identifier: EPL-2.0 OR GPL-2.0-or-later
+// SPDX-FileCopyrightText: Bradley M. Bell <bradbell@seanet.com>
+// SPDX-FileContributor: 2026 Bradley M. Bell
+// ---------------------------------------------------------------------------
+//
+//! This pub module defines the rustad DoubleDouble class.
+//!
+//! Link to [parent module](super)
+//!
+//! This module does not have dependencies outside standard rust and src/float.
+//! This enables src/float to be directly included as part of a Dll library.
+//!
+//! * Representation :
+//!   A DoubleDouble value is the unevaluated sum hi + lo of two f64 values
+//!   where hi is the f64 value closest to hi + lo .
+//!   This has about 106 bits of mantissa (about 32 decimal digits)
+//!   and the same exponent range as f64 .
+//!
+//! * Arithmetic :
+//!   The +, -, *, / and sqrt operations use error free transformations
+//!   and have a relative error that is a small multiple of
+//!   [DoubleDouble::EPSILON] .
+//!
+//! * Functions :
+//!   The other [FUnary] and [FBinary] functions use series, continued
+//!   fractions, or a Newton step starting at the corresponding f64 function.
+//!   Their relative error is also a small multiple of epsilon,
+//!   except where the function is ill-conditioned; e.g., near its zeros,
+//!   or for sin, cos, tan of large arguments (the argument reduction
+//!   uses a DoubleDouble approximation for pi).
+//!
+//! * Value Types :
+//!   A DoubleDouble follows the IEEE rules for zero and nan (like f64);
+//!   i.e., [ABSOLUTE_ZERO](crate::FConst::ABSOLUTE_ZERO) is false.
+//!   The value type `AzFloat<DoubleDouble>` has an absolute zero.
+//!
+// ---------------------------------------------------------------------------
+// use
+//
+use std::ops::{
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Neg,
+    AddAssign,
+    SubAssign,
+    MulAssign,
+    DivAssign,
+};
+use std::cmp::Ordering;
+use std::f64::consts;
+//
+use crate::{
+    AzFloat,
+    FConst,
+    FUnary,
+    FBinary,
+    FReduce,
+    FValue,
+    FBytes,
+    FText,
+};
+// ---------------------------------------------------------------------------
+/// The DoubleDouble class.
+///
+/// * hi : is the f64 value closest to hi + lo .
+/// * lo : is the rest of the value; i.e., |lo| is at most half a unit
+///   in the last place of hi .
+///
+/// * Copy : The Copy trait is implemented for this type.
+///
+/// # Example
+/// ```
+/// use rustad::{
+///     DoubleDouble,
+///     FConst,
+/// };
+/// type V = DoubleDouble;
+/// //
+/// // one + epsilon is not one
+/// let one     = V::one();
+/// let epsilon = V::epsilon();
+/// assert!( one + epsilon != one );
+/// assert!( (one + epsilon).hi == 1.0 );
+/// //
+/// // 1/3 is closer to its exact value than an f64
+/// let third   = V::from(1.0) / V::from(3.0);
+/// let error   = V::from(1.0) - third * V::from(3.0);
+/// assert!( error.abs() < V::from(1e-31) );
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct DoubleDouble {
+    /// high order part
+    pub hi : f64,
+    /// low order part
+    pub lo : f64,
+}
+// ---------------------------------------------------------------------------
+// Error free transformations
+//
+// two_sum
+// returns (s, e) where s = fl(a + b) and s + e = a + b exactly
+fn two_sum(a : f64, b : f64) -> (f64, f64) {
+    let s  = a + b;
+    let bb = s - a;
+    let e  = (a - (s - bb)) + (b - bb);
+    (s, e)
+}
+//
+// quick_two_sum
+// same as two_sum but requires |a| >= |b|
+fn quick_two_sum(a : f64, b : f64) -> (f64, f64) {
+    let s = a + b;
+    let e = b - (s - a);
+    (s, e)
+}
+//
+// two_prod
+// returns (p, e) where p = fl(a * b) and p + e = a * b exactly
+fn two_prod(a : f64, b : f64) -> (f64, f64) {
+    let p = a * b;
+    let e = a.mul_add(b, -p);
+    (p, e)
+}
+//
+// SERIES_TOL
+// a series is terminated when the next term, relative to the sum,
+// is less than this value.
+const SERIES_TOL : f64 = 1e-34;
+//
+// LN_2_PARTS, PI_2_PARTS
+// ln(2) and pi/2 as the sum of three f64 values.
+const LN_2_PARTS : [f64; 3] =
+    [ consts::LN_2, 2.3190468138462996e-17, 5.707708438416212e-34 ];
+const PI_2_PARTS : [f64; 3] =
+    [ consts::FRAC_PI_2, 6.123233995736766e-17, -1.4973849048591698e-33 ];
+// ---------------------------------------------------------------------------
+// DoubleDouble
+impl DoubleDouble {
+    /// difference between one and the next larger DoubleDouble; i.e., 2^-104
+    pub const EPSILON      : Self =
+        Self{ hi : 4.930380657631324e-32, lo : 0.0 };
+    /// natural log of 10
+    pub const LN_10        : Self =
+        Self{ hi : consts::LN_10, lo : -2.1707562233822494e-16 };
+    /// natural log of 2
+    pub const LN_2         : Self =
+        Self{ hi : consts::LN_2, lo : 2.3190468138462996e-17 };
+    /// 2 / sqrt(pi)
+    pub const FRAC_2_SQRT_PI : Self =
+        Self{ hi : consts::FRAC_2_SQRT_PI, lo : 1.533545961316588e-17 };
+    /// smallest positive value that has full precision; i.e., 2^-969
+    pub const MIN_POSITIVE : Self =
+        Self{ hi : 2.004168360008973e-292, lo : 0.0 };
+    /// not a number
+    pub const NAN          : Self = Self{ hi : f64::NAN, lo : 0.0 };
+    /// minus infinity
+    pub const NEG_INFINITY : Self = Self{ hi : f64::NEG_INFINITY, lo : 0.0 };
+    /// plus infinity
+    pub const INFINITY     : Self = Self{ hi : f64::INFINITY, lo : 0.0 };
+    /// the ratio of a circle's circumference to its diameter
+    pub const PI           : Self =
+        Self{ hi : consts::PI, lo : 1.2246467991473532e-16 };
+    //
+    // new
+    /// Create a DoubleDouble equal to hi + lo (rounded to DoubleDouble).
+    pub fn new(hi : f64, lo : f64) -> Self {
+        if lo == 0.0 {
+            return Self{ hi, lo };
+        }
+        let (s, e) = two_sum(hi, lo);
+        Self::finite_or(s, e)
+    }
+    //
+    // finite_or
+    // returns (s, e) if s is finite and (s, 0) otherwise.
+    fn finite_or(s : f64, e : f64) -> Self {
+        if s.is_finite() {
+            Self{ hi : s, lo : e }
+        } else {
+            Self{ hi : s, lo : 0.0 }
+        }
+    }
+    //
+    // renormalize
+    // returns the DoubleDouble closest to s + e where |s| >= |e| .
+    fn renormalize(s : f64, e : f64) -> Self {
+        let (s, e) = quick_two_sum(s, e);
+        Self::finite_or(s, e)
+    }
+    //
+    // mul_pwr2
+    // multiply by p which must be a power of two
+    fn mul_pwr2(self, p : f64) -> Self {
+        Self{ hi : self.hi * p, lo : self.lo * p }
+    }
+    //
+    // ldexp
+    // multiply by 2^m (in two steps so that 2^m need not be an f64)
+    fn ldexp(self, m : i32) -> Self {
+        let m1 = m / 2;
+        let m2 = m - m1;
+        self.mul_pwr2( 2f64.powi(m1) ).mul_pwr2( 2f64.powi(m2) )
+    }
+    //
+    // reduce
+    // returns self - j * (c[0] + c[1] + c[2]) where j is an integer with
+    // |j| < 2^52 ; the products j * c[0] and j * c[1] are computed exactly.
+    fn reduce(self, j : f64, c : &[f64; 3]) -> Self {
+        let (p0, e0) = two_prod(j, c[0]);
+        let (p1, e1) = two_prod(j, c[1]);
+        self - Self{ hi : p0, lo : e0 } - Self{ hi : p1, lo : e1 } - j * c[2]
+    }
+    //
+    // is_nan
+    /// Determine if this object is nan
+    pub fn is_nan(self) -> bool {
+        self.hi.is_nan()
+    }
+    //
+    // recip
+    /// Reciprocal; i.e., 1 / self
+    pub fn recip(self) -> Self {
+        Self::from(1.0) / self
+    }
+}
+// ---------------------------------------------------------------------------
+/// DoubleDouble From
+///
+/// From is implemented for usize, f32, and f64 .
+/// The conversion is exact for all of these types.
+///
+/// # Example
+/// ```
+/// use rustad::DoubleDouble;
+/// let n = usize::MAX;
+/// let x = DoubleDouble::from(n);
+/// assert_eq!( x.hi as u128 as i128 + x.lo as i128, n as i128 );
+/// ```
+pub fn doc_impl_from() {}
+//
+impl From<f64> for DoubleDouble {
+    /// see [doc_impl_from]
+    fn from(hi : f64) -> Self {
+        Self{ hi, lo : 0.0 }
+    }
+}
+impl From<f32> for DoubleDouble {
+    /// see [doc_impl_from]
+    fn from(f : f32) -> Self {
+        Self::from( f64::from(f) )
+    }
+}
+impl From<usize> for DoubleDouble {
+    /// see [doc_impl_from]
+    fn from(n : usize) -> Self {
+        let hi = n as f64;
+        let lo = ( n as i128 - hi as i128 ) as f64;
+        Self::new(hi, lo)
+    }
+}
+// ---------------------------------------------------------------------------
+/// DoubleDouble binary operators
+///
+/// * Syntax :
+///   ```text
+///     z = x Op y
+///   ```
+///
+/// * Op : is the source code token for this binary operator;
+///   i.e., `+` , `-` , `*` , or `/` .
+///
+/// * x : left hand side `DoubleDouble` or `&DoubleDouble` object
+/// * y : right hand side `DoubleDouble` or `&DoubleDouble` object
+///   (or `f64` when x is a `DoubleDouble` ).
+/// * z : result `DoubleDouble` object
+///
+/// # Example
+/// ```
+/// use rustad::DoubleDouble;
+/// type V = DoubleDouble;
+/// //
+/// let x = V::from(1.0) + V::from(1e-20);
+/// assert_eq!( x.hi, 1.0 );
+/// assert_eq!( x.lo, 1e-20 );
+/// assert_eq!( &x - &V::from(1.0), V::from(1e-20) );
+/// assert_eq!( x * 2.0, V::new(2.0, 2e-20) );
+/// ```
+pub fn doc_binary_operator() { }
+//
+// Add
+impl Add for DoubleDouble {
+    type Output = DoubleDouble;
+    /// see [doc_binary_operator]
+    fn add(self, rhs : Self) -> Self {
+        if self.hi == 0.0 && rhs.hi == 0.0 {
+            // IEEE sign of a zero sum
+            return Self::from( self.hi + rhs.hi );
+        }
+        let (s1, s2) = two_sum(self.hi, rhs.hi);
+        if ! s1.is_finite() {
+            return Self::from(s1);
+        }
+        let (t1, t2) = two_sum(self.lo, rhs.lo);
+        let s2       = s2 + t1;
+        let (s1, s2) = quick_two_sum(s1, s2);
+        let s2       = s2 + t2;
+        Self::renormalize(s1, s2)
+    }
+}
+//
+// Sub
+impl Sub for DoubleDouble {
+    type Output = DoubleDouble;
+    /// see [doc_binary_operator]
+    fn sub(self, rhs : Self) -> Self {
+        self + ( - rhs )
+    }
+}
+//
+// Mul
+impl Mul for DoubleDouble {
+    type Output = DoubleDouble;
+    /// see [doc_binary_operator]
+    fn mul(self, rhs : Self) -> Self {
+        let (p1, p2) = two_prod(self.hi, rhs.hi);
+        if p1 == 0.0 || ! p1.is_finite() {
+            return Self::from(p1);
+        }
+        let p2 = p2 + ( self.hi * rhs.lo + self.lo * rhs.hi );
+        Self::renormalize(p1, p2)
+    }
+}
+//
+// Div
+impl Div for DoubleDouble {
+    type Output = DoubleDouble;
+    /// see [doc_binary_operator]
+    fn div(self, rhs : Self) -> Self {
+        let q1 = self.hi / rhs.hi;
+        if q1 == 0.0 || ! q1.is_finite() {
+            return Self::from(q1);
+        }
+        let r        = self - rhs * q1;
+        let q2       = r.hi / rhs.hi;
+        let r        = r - rhs * q2;
+        let q3       = r.hi / rhs.hi;
+        let (q1, q2) = quick_two_sum(q1, q2);
+        Self::finite_or(q1, q2) + q3
+    }
+}
+//
+// Neg
+impl Neg for DoubleDouble {
+    type Output = DoubleDouble;
+    fn neg(self) -> Self {
+        Self{ hi : - self.hi, lo : - self.lo }
+    }
+}
+impl Neg for &DoubleDouble {
+    type Output = DoubleDouble;
+    fn neg(self) -> DoubleDouble {
+        - *self
+    }
+}
+//
+// DoubleDouble Op &DoubleDouble, DoubleDouble Op f64
+macro_rules! impl_binary_operator{ ($Name:ident, $name:ident) =>  {
+    #[doc = "see [doc_binary_operator]"]
+    impl $Name<&DoubleDouble> for &DoubleDouble {
+        type Output = DoubleDouble;
+        fn $name(self, rhs : &DoubleDouble) -> DoubleDouble {
+            $Name::$name(*self, *rhs)
+        }
+    }
+    #[doc = "see [doc_binary_operator]"]
+    impl $Name<f64> for DoubleDouble {
+        type Output = DoubleDouble;
+        fn $name(self, rhs : f64) -> DoubleDouble {
+            $Name::$name( self, DoubleDouble::from(rhs) )
+        }
+    }
+} }
+impl_binary_operator!(Add, add);
+impl_binary_operator!(Sub, sub);
+impl_binary_operator!(Mul, mul);
+impl_binary_operator!(Div, div);
+// ---------------------------------------------------------------------------
+/// DoubleDouble compound assignment operators
+///
+/// * Syntax :
+///   ```text
+///     lhs op rhs
+///   ```
+///
+/// * lhs : is the `&mut DoubleDouble` left operand
+/// * rhs : is the `DoubleDouble` or `&DoubleDouble` right operand
+/// * op  : is one of `+=` , `-=` , `*=` , `/=`
+///
+/// # Example
+/// ```
+/// use rustad::DoubleDouble;
+/// let mut x = DoubleDouble::from(3.0);
+/// x        /= DoubleDouble::from(2.0);
+/// x        -= &DoubleDouble::from(0.5);
+/// assert_eq!( x, DoubleDouble::from(1.0) );
+/// ```
+pub fn doc_binary_assign() {}
+//
+macro_rules! impl_binary_assign{ ($Name:ident, $name:ident, $Op:ident, $op:tt) => {
+    #[doc = "see [doc_binary_assign]"]
+    impl $Name<&DoubleDouble> for DoubleDouble {
+        fn $name(&mut self, rhs : &DoubleDouble) {
+            *self = *self $op *rhs;
+        }
+    }
+    #[doc = "see [doc_binary_assign]"]
+    impl $Name<DoubleDouble> for DoubleDouble {
+        fn $name(&mut self, rhs : DoubleDouble) {
+            *self = *self $op rhs;
+        }
+    }
+} }
+impl_binary_assign!(AddAssign, add_assign, Add, +);
+impl_binary_assign!(SubAssign, sub_assign, Sub, -);
+impl_binary_assign!(MulAssign, mul_assign, Mul, *);
+impl_binary_assign!(DivAssign, div_assign, Div, /);
+// ---------------------------------------------------------------------------
+// PartialEq, PartialOrd
+/// DoubleDouble comparison operators
+///
+/// These follow the IEEE rules for the corresponding f64 operators;
+/// e.g., nan is not equal to nan and +0 is equal to -0 .
+///
+/// # Example
+/// ```
+/// use rustad::DoubleDouble;
+/// type V    = DoubleDouble;
+/// let one   = V::from(1.0);
+/// let above = V::new(1.0, 1e-20);
+/// assert!( one < above );
+/// assert!( V::NAN != V::NAN );
+/// ```
+impl PartialEq for DoubleDouble {
+    fn eq(&self, rhs : &Self) -> bool {
+        self.hi == rhs.hi && self.lo == rhs.lo
+    }
+}
+impl PartialOrd for DoubleDouble {
+    fn partial_cmp(&self, rhs : &Self) -> Option<Ordering> {
+        match self.hi.partial_cmp(&rhs.hi) {
+            Some(Ordering::Equal) => self.lo.partial_cmp(&rhs.lo),
+            other                 => other,
+        }
+    }
+}
+// ---------------------------------------------------------------------------
+/// Display a DoubleDouble object
+///
+/// If the low order part is zero, or the value is not finite,
+/// the high order part is displayed using its f64 representation.
+/// Otherwise, 32 significant decimal digits are displayed in scientific
+/// notation (with trailing zeros removed).
+///
+/// # Example
+/// ```
+/// use rustad::DoubleDouble;
+/// type V    = DoubleDouble;
+/// let x     = V::from(2.5);
+/// assert_eq!( format!("{x}"), "2.5" );
+/// let third = V::from(1.0) / V::from(3.0);
+/// assert_eq!( format!("{third}"), "3.3333333333333333333333333333333e-1" );
+/// ```
+impl std::fmt::Display for DoubleDouble {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        if self.lo == 0.0 || ! self.hi.is_finite() {
+            return write!(f, "{}", self.hi);
+        }
+        //
+        // r, exponent
+        // |self| = r * 10^exponent where 1 <= r < 10
+        let mut exponent = self.hi.abs().log10().floor() as i32;
+        let mut r        = self.abs() / Self::from(10.0).powi(exponent);
+        if r.hi >= 10.0 {
+            r         = r / 10.0;
+            exponent += 1;
+        } else if r.hi < 1.0 {
+            r         = r * 10.0;
+            exponent -= 1;
+        }
+        //
+        // digits
+        // one more digit than displayed so that the result can be rounded
+        let n_digit = 32;
+        let mut digits : Vec<u8> = Vec::with_capacity(n_digit + 1);
+        for _ in 0 ..= n_digit {
+            let d = r.floor().hi.clamp(0.0, 9.0);
+            digits.push( d as u8 );
+            r = (r - d) * 10.0;
+        }
+        let round_up = digits.pop().unwrap() >= 5;
+        if round_up {
+            let mut i = n_digit;
+            while i > 0 && digits[i-1] == 9 {
+                digits[i-1] = 0;
+                i          -= 1;
+            }
+            if i == 0 {
+                digits.insert(0, 1);
+                digits.pop();
+                exponent += 1;
+            } else {
+                digits[i-1] += 1;
+            }
+        }
+        while digits.len() > 1 && *digits.last().unwrap() == 0 {
+            digits.pop();
+        }
+        //
+        // text
+        let mut text = String::new();
+        if self.hi < 0.0 {
+            text.push('-');
+        }
+        text.push( (b'0' + digits[0]) as char );
+        if digits.len() > 1 {
+            text.push('.');
+            for d in &digits[1 ..] {
+                text.push( (b'0' + d) as char );
+            }
+        }
+        write!(f, "{text}e{exponent}")
+    }
+}
+// ---------------------------------------------------------------------------
+// DoubleDouble functions
+//
+// These methods have the same names and semantics as the corresponding
+// f64 methods. They are used by the FUnary and FBinary implementations.
+impl DoubleDouble {
+    //
+    // abs
+    /// absolute value
+    pub fn abs(self) -> Self {
+        if self.hi.is_sign_negative() { - self } else { self }
+    }
+    //
+    // signum
+    /// one with the sign of self (nan if self is nan)
+    pub fn signum(self) -> Self {
+        Self::from( self.hi.signum() )
+    }
+    //
+    // square
+    /// self * self
+    pub fn square(self) -> Self {
+        self * self
+    }
+    //
+    // floor
+    /// largest integer less than or equal self
+    pub fn floor(self) -> Self {
+        let hi = self.hi.floor();
+        if hi == self.hi {
+            Self::renormalize( hi, self.lo.floor() )
+        } else {
+            Self::from(hi)
+        }
+    }
+    //
+    // ceil
+    /// smallest integer greater than or equal self
+    pub fn ceil(self) -> Self {
+        let hi = self.hi.ceil();
+        if hi == self.hi {
+            Self::renormalize( hi, self.lo.ceil() )
+        } else {
+            Self::from(hi)
+        }
+    }
+    //
+    // trunc
+    /// integer part of self
+    pub fn trunc(self) -> Self {
+        if self.hi.is_sign_negative() { self.ceil() } else { self.floor() }
+    }
+    //
+    // round
+    /// nearest integer to self (half way cases are rounded away from zero)
+    pub fn round(self) -> Self {
+        let floor = self.floor();
+        let diff  = self - floor;
+        match diff.partial_cmp( &Self::from(0.5) ) {
+            Some(Ordering::Less)    => floor,
+            Some(Ordering::Greater) => floor + 1.0,
+            Some(Ordering::Equal)   => {
+                if self.hi > 0.0 { floor + 1.0 } else { floor }
+            },
+            None                    => floor,
+        }
+    }
+    //
+    // sqrt
+    /// square root
+    pub fn sqrt(self) -> Self {
+        if self.hi == 0.0 || ! self.hi.is_finite() || self.hi < 0.0 {
+            return Self::from( self.hi.sqrt() );
+        }
+        // one Newton step starting at the f64 square root
+        let x  = 1.0 / self.hi.sqrt();
+        let ax = Self::from( self.hi * x );
+        ax + (self - ax.square()).hi * (x * 0.5)
+    }
+    //
+    // cbrt
+    /// cube root
+    pub fn cbrt(self) -> Self {
+        if self.hi == 0.0 || ! self.hi.is_finite() {
+            return Self::from( self.hi.cbrt() );
+        }
+        // one Newton step starting at the f64 cube root
+        let y = Self::from( self.hi.cbrt() );
+        y - ( y * y.square() - self ) / ( y.square() * 3.0 )
+    }
+    //
+    // powi
+    /// self to an integer power
+    pub fn powi(self, n : i32) -> Self {
+        let mut m      = n.unsigned_abs();
+        let mut base   = self;
+        let mut result = Self::from(1.0);
+        while m > 0 {
+            if m & 1 == 1 {
+                result *= base;
+            }
+            m >>= 1;
+            if m > 0 {
+                base = base.square();
+            }
+        }
+        if n < 0 { result.recip() } else { result }
+    }
+    //
+    // expm1_small
+    // e^x - 1 for |x| <= 1 using a Taylor series for e^(x/512) - 1
+    // followed by nine applications of e^(2y) - 1 = (e^y - 1) (e^y + 1) .
+    fn expm1_small(self) -> Self {
+        let r     = self.mul_pwr2( 1.0 / 512.0 );
+        let mut s = r;
+        let mut t = r;
+        let mut k = 1.0;
+        while t.hi.abs() > s.hi.abs() * SERIES_TOL {
+            k += 1.0;
+            t  = t * r / k;
+            s += t;
+        }
+        for _ in 0 .. 9 {
+            s = s * (s + 2.0);
+        }
+        s
+    }
+    //
+    // exp
+    /// exponential function
+    pub fn exp(self) -> Self {
+        if self.hi.is_nan() {
+            return self;
+        }
+        if self.hi > 709.8 {
+            return Self::INFINITY;
+        }
+        if self.hi < -745.2 {
+            return Self::from(0.0);
+        }
+        // self = m * ln(2) + r where |r| <= ln(2) / 2
+        let m = ( self.hi / Self::LN_2.hi ).round();
+        let r = self.reduce(m, &LN_2_PARTS);
+        ( r.expm1_small() + 1.0 ).ldexp( m as i32 )
+    }
+    //
+    // exp_m1
+    /// e^self - 1
+    pub fn exp_m1(self) -> Self {
+        if self.hi.abs() <= 1.0 {
+            self.expm1_small()
+        } else {
+            self.exp() - 1.0
+        }
+    }
+    //
+    // exp2
+    /// 2^self
+    pub fn exp2(self) -> Self {
+        if ! self.hi.is_finite() {
+            return Self::from( self.hi.exp2() );
+        }
+        // self = m + r where |r| <= 1/2
+        let m = self.round();
+        let r = self - m;
+        if m.hi.abs() > 2048.0 {
+            return Self::from( m.hi.exp2() );
+        }
+        ( r * Self::LN_2 ).exp().ldexp( m.hi as i32 )
+    }
+    //
+    // ln
+    /// natural logarithm
+    pub fn ln(self) -> Self {
+        if self.hi == 0.0 || ! self.hi.is_finite() || self.hi < 0.0 {
+            return Self::from( self.hi.ln() );
+        }
+        // two Newton steps for exp(x) = self starting at the f64 logarithm
+        let mut x = Self::from( self.hi.ln() );
+        for _ in 0 .. 2 {
+            x = x + self * (-x).exp() - 1.0;
+        }
+        x
+    }
+    //
+    // ln_1p
+    /// ln(1 + self)
+    pub fn ln_1p(self) -> Self {
+        if self.hi.abs() >= 0.5 || self.hi.is_nan() {
+            return ( self + 1.0 ).ln();
+        }
+        // Newton step for exp_m1(y) = self starting at the f64 value
+        let y  = Self::from( self.hi.ln_1p() );
+        let em = y.exp_m1();
+        y - ( em - self ) / ( em + 1.0 )
+    }
+    //
+    // log2
+    /// base 2 logarithm
+    pub fn log2(self) -> Self {
+        self.ln() / Self::LN_2
+    }
+    //
+    // log10
+    /// base 10 logarithm
+    pub fn log10(self) -> Self {
+        self.ln() / Self::LN_10
+    }
+    //
+    // sin_cos_reduced
+    // sin and cos of r for |r| <= pi/4 using their Taylor series
+    fn sin_cos_reduced(self) -> (Self, Self) {
+        let r2    = self.square();
+        //
+        let mut sin = self;
+        let mut t   = self;
+        let mut k   = 1.0;
+        while t.hi.abs() > sin.hi.abs() * SERIES_TOL {
+            t    = - t * r2 / ( (k + 1.0) * (k + 2.0) );
+            k   += 2.0;
+            sin += t;
+        }
+        //
+        let mut cos = Self::from(1.0);
+        let mut t   = Self::from(1.0);
+        let mut k   = 0.0;
+        while t.hi.abs() > cos.hi.abs() * SERIES_TOL {
+            t    = - t * r2 / ( (k + 1.0) * (k + 2.0) );
+            k   += 2.0;
+            cos += t;
+        }
+        (sin, cos)
+    }
+    //
+    // sin_cos
+    /// sin and cos of self
+    pub fn sin_cos(self) -> (Self, Self) {
+        if ! self.hi.is_finite() {
+            return (Self::NAN, Self::NAN);
+        }
+        if self.hi == 0.0 {
+            return ( self, Self::from(1.0) );
+        }
+        // self = j * (pi/2) + r where |r| <= pi/4
+        let pi_2     = Self::PI.mul_pwr2(0.5);
+        let j        = (self / pi_2).round();
+        let r        = if j.hi.abs() < 2f64.powi(52) {
+            self.reduce(j.hi, &PI_2_PARTS)
+        } else {
+            self - pi_2 * j
+        };
+        let (s, c)   = r.sin_cos_reduced();
+        let quadrant = ( (j.hi % 4.0) + (j.lo % 4.0) ).rem_euclid(4.0);
+        match quadrant as usize {
+            0 => (   s,   c ),
+            1 => (   c, - s ),
+            2 => ( - s, - c ),
+            _ => ( - c,   s ),
+        }
+    }
+    //
+    // sin
+    /// sine function
+    pub fn sin(self) -> Self {
+        self.sin_cos().0
+    }
+    //
+    // cos
+    /// cosine function
+    pub fn cos(self) -> Self {
+        self.sin_cos().1
+    }
+    //
+    // tan
+    /// tangent function
+    pub fn tan(self) -> Self {
+        let (s, c) = self.sin_cos();
+        s / c
+    }
+    //
+    // hypot
+    /// sqrt( self^2 + rhs^2 ) without unnecessary overflow or underflow
+    pub fn hypot(self, rhs : Self) -> Self {
+        let mut a = self.abs();
+        let mut b = rhs.abs();
+        if a.hi.is_infinite() || b.hi.is_infinite() {
+            return Self::INFINITY;
+        }
+        if a.is_nan() || b.is_nan() {
+            return Self::NAN;
+        }
+        if a < b {
+            std::mem::swap(&mut a, &mut b);
+        }
+        if a.hi == 0.0 {
+            return a;
+        }
+        let r = b / a;
+        a * ( r.square() + 1.0 ).sqrt()
+    }
+    //
+    // atan2
+    /// four quadrant arctangent of self / rhs
+    pub fn atan2(self, rhs : Self) -> Self {
+        let y = self;
+        let x = rhs;
+        if y.is_nan() || x.is_nan() {
+            return Self::NAN;
+        }
+        let z = y.hi.atan2(x.hi);
+        if y.hi == 0.0 || x.hi == 0.0 ||
+            y.hi.is_infinite() || x.hi.is_infinite() {
+            // z is an integer multiple of pi/4
+            let quarter = ( z * 4.0 / consts::PI ).round() * 0.25;
+            return Self::PI * quarter;
+        }
+        // Newton step for (cos(z), sin(z)) = (x, y) / r
+        let r      = x.hypot(y);
+        let xx     = x / r;
+        let yy     = y / r;
+        let z      = Self::from(z);
+        let (s, c) = z.sin_cos();
+        if xx.hi.abs() > yy.hi.abs() {
+            z + (yy - s) / c
+        } else {
+            z - (xx - c) / s
+        }
+    }
+    //
+    // atan
+    /// arctangent function
+    pub fn atan(self) -> Self {
+        self.atan2( Self::from(1.0) )
+    }
+    //
+    // asin
+    /// arcsine function
+    pub fn asin(self) -> Self {
+        if self.hi.abs() > 1.0 {
+            return Self::NAN;
+        }
+        let one = Self::from(1.0);
+        let c   = ( (one - self) * (one + self) ).sqrt();
+        self.atan2(c)
+    }
+    //
+    // acos
+    /// arccosine function
+    pub fn acos(self) -> Self {
+        if self.hi.abs() > 1.0 {
+            return Self::NAN;
+        }
+        let one = Self::from(1.0);
+        let s   = ( (one - self) * (one + self) ).sqrt();
+        s.atan2(self)
+    }
+    //
+    // sinh
+    /// hyperbolic sine
+    pub fn sinh(self) -> Self {
+        if self.hi.abs() < 1.0 {
+            // sinh(x) = u * (u + 2) / (2 * (u + 1)) where u = exp(x) - 1
+            let u = self.exp_m1();
+            return ( u * (u + 2.0) / (u + 1.0) ).mul_pwr2(0.5);
+        }
+        let e = self.exp();
+        ( e - e.recip() ).mul_pwr2(0.5)
+    }
+    //
+    // cosh
+    /// hyperbolic cosine
+    pub fn cosh(self) -> Self {
+        let e = self.exp();
+        ( e + e.recip() ).mul_pwr2(0.5)
+    }
+    //
+    // tanh
+    /// hyperbolic tangent
+    pub fn tanh(self) -> Self {
+        if self.hi.abs() > 40.0 {
+            return self.signum();
+        }
+        // tanh(x) = v / (v + 2) where v = exp(2 x) - 1
+        let v = self.mul_pwr2(2.0).exp_m1();
+        v / (v + 2.0)
+    }
+    //
+    // asinh
+    /// inverse hyperbolic sine
+    pub fn asinh(self) -> Self {
+        let a = self.abs();
+        let y = if a.hi > 1e150 {
+            a.ln() + Self::LN_2
+        } else {
+            // ln(a + sqrt(a^2 + 1)) = ln_1p( a + a^2 / (1 + sqrt(a^2 + 1)) )
+            let a2 = a.square();
+            ( a + a2 / ( (a2 + 1.0).sqrt() + 1.0 ) ).ln_1p()
+        };
+        if self.hi.is_sign_negative() { - y } else { y }
+    }
+    //
+    // acosh
+    /// inverse hyperbolic cosine
+    pub fn acosh(self) -> Self {
+        if self.hi < 1.0 {
+            return Self::NAN;
+        }
+        if self.hi > 1e150 {
+            return self.ln() + Self::LN_2;
+        }
+        // ln(x + sqrt(x^2 - 1)) = ln_1p( t + sqrt(t * (t + 2)) ), t = x - 1
+        let t = self - 1.0;
+        ( t + ( t * (t + 2.0) ).sqrt() ).ln_1p()
+    }
+    //
+    // atanh
+    /// inverse hyperbolic tangent
+    pub fn atanh(self) -> Self {
+        // atanh(x) = ln_1p( 2 x / (1 - x) ) / 2
+        let one = Self::from(1.0);
+        ( self.mul_pwr2(2.0) / (one - self) ).ln_1p().mul_pwr2(0.5)
+    }
+    //
+    // erf_series
+    // erf(x) for |x| < 1 using the series
+    // (2 / sqrt(pi)) exp(-x^2) sum_k 2^k x^(2k+1) / ( 1 3 5 ... (2k+1) ) .
+    // All the terms in this series have the same sign.
+    fn erf_series(self) -> Self {
+        debug_assert!( self.hi.abs() < 1.0 );
+        let x2    = self.square();
+        let mut s = self;
+        let mut t = self;
+        let mut k = 0.0;
+        while t.hi.abs() > s.hi.abs() * SERIES_TOL {
+            k += 1.0;
+            t  = t * x2 * 2.0 / (2.0 * k + 1.0);
+            s += t;
+        }
+        Self::FRAC_2_SQRT_PI * (-x2).exp() * s
+    }
+    //
+    // erfc_fraction
+    // erfc(x) for x >= 1 using the continued fraction
+    // exp(-x^2) / ( sqrt(pi) * (x + (1/2) / (x + 1 / (x + (3/2) / ...))) )
+    // evaluated with the modified Lentz method.
+    fn erfc_fraction(self) -> Self {
+        debug_assert!( self.hi >= 1.0 );
+        let x     = self;
+        let mut f = x;
+        let mut c = x;
+        let mut d = Self::from(0.0);
+        let mut n = 0.0;
+        loop {
+            n        += 1.0;
+            let a     = n * 0.5;
+            d         = ( x + d * a ).recip();
+            c         = x + Self::from(a) / c;
+            let delta = c * d;
+            f        *= delta;
+            if (delta - 1.0).hi.abs() <= SERIES_TOL || n >= 10_000.0 {
+                break;
+            }
+        }
+        Self::FRAC_2_SQRT_PI.mul_pwr2(0.5) * (-x.square()).exp() / f
+    }
+    //
+    // erf
+    /// error function
+    pub fn erf(self) -> Self {
+        if self.is_nan() {
+            return self;
+        }
+        if self.hi.abs() < 1.0 {
+            self.erf_series()
+        } else if self.hi > 0.0 {
+            - self.erfc_fraction() + 1.0
+        } else {
+            (-self).erfc_fraction() - 1.0
+        }
+    }
+    //
+    // erfc
+    /// complementary error function; i.e., 1 - erf(self)
+    pub fn erfc(self) -> Self {
+        if self.is_nan() {
+            return self;
+        }
+        if self.hi >= 1.0 {
+            self.erfc_fraction()
+        } else if self.hi > -1.0 {
+            - self.erf_series() + 1.0
+        } else {
+            - (-self).erfc_fraction() + 2.0
+        }
+    }
+    //
+    // powf
+    /// self to a DoubleDouble power
+    pub fn powf(self, rhs : Self) -> Self {
+        if rhs.hi == 0.0 {
+            return Self::from(1.0);
+        }
+        if self.is_nan() || rhs.is_nan() {
+            return Self::NAN;
+        }
+        let is_integer = rhs.floor() == rhs;
+        if is_integer && rhs.hi.abs() <= i32::MAX as f64 {
+            return self.powi( rhs.hi as i32 );
+        }
+        if self.hi == 0.0 {
+            if rhs.hi > 0.0 {
+                return Self::from(0.0);
+            }
+            return Self::INFINITY;
+        }
+        if self.hi < 0.0 && ! is_integer {
+            return Self::NAN;
+        }
+        let result = ( rhs * self.abs().ln() ).exp();
+        let half   = rhs.mul_pwr2(0.5);
+        if self.hi < 0.0 && half.floor() != half {
+            - result
+        } else {
+            result
+        }
+    }
+    //
+    // min
+    /// minimum of self and rhs (if one is nan, the other is returned)
+    pub fn min(self, rhs : Self) -> Self {
+        if self.is_nan() || rhs < self { rhs } else { self }
+    }
+    //
+    // max
+    /// maximum of self and rhs (if one is nan, the other is returned)
+    pub fn max(self, rhs : Self) -> Self {
+        if self.is_nan() || rhs > self { rhs } else { self }
+    }
+}
+// ---------------------------------------------------------------------------
+// FConst
+/// FConst trait for DoubleDouble
+///
+/// * epsilon : is [DoubleDouble::EPSILON]
+/// * min_positive : is [DoubleDouble::MIN_POSITIVE]
+impl FConst for DoubleDouble {
+    fn pi()           -> Self { Self::PI }
+    fn nan()          -> Self { Self::NAN }
+    fn one()          -> Self { Self::from(1.0) }
+    fn zero()         -> Self { Self::from(0.0) }
+    fn epsilon()      -> Self { Self::EPSILON }
+    fn min_positive() -> Self { Self::MIN_POSITIVE }
+    const ABSOLUTE_ZERO : bool = false;
+}
+// ----------------------------------------------------------------------------
+// doc_f_unary_double_double
+/// DoubleDouble unary functions
+///
+/// * Syntax : `y = x.Name()`
+///
+/// * Name : is the name of one of the [FUnary] functions.
+///
+/// * x : is a `DoubleDouble` or `&DoubleDouble` object.
+///
+/// * y : is the `DoubleDouble` result.
+///
+/// # Example
+/// ```
+/// use rustad::{
+///     DoubleDouble,
+///     FConst,
+///     FUnary,
+///     nearly_eq,
+/// };
+/// type V = DoubleDouble;
+/// let opt_vec : Vec<[&str; 2]> = Vec::new();
+/// //
+/// let pi      = V::pi();
+/// let pi_4    = pi / V::from(4.0);
+/// let y       = FUnary::tan(pi_4);
+/// assert!( nearly_eq::<V>(&y, &V::from(1.0), &opt_vec) );
+/// let y       = FUnary::sqrt( &V::from(2.0) ).square();
+/// assert!( nearly_eq::<V>(&y, &V::from(2.0), &opt_vec) );
+/// ```
+pub fn doc_f_unary_double_double() {}
+//
+macro_rules! double_double_unary_function{ ( $($name:ident),* ) => {
+    $(
+        #[doc = "see [doc_f_unary_double_double]" ]
+        fn $name(self) -> DoubleDouble { DoubleDouble::$name(*self) }
+    )*
+} }
+macro_rules! double_double_unary_own{ ( $($name:ident),* ) => {
+    $(
+        #[doc = "see [doc_f_unary_double_double]" ]
+        fn $name(self) -> DoubleDouble { FUnary::$name(&self) }
+    )*
+} }
+impl FUnary for &DoubleDouble {
+    type Output = DoubleDouble;
+    double_double_unary_function!(
+        abs, acos, acosh, asin, asinh, atan, atanh, cbrt, ceil, cos, cosh,
+        erf, erfc, exp, exp2, exp_m1, floor, ln, ln_1p, log10, log2,
+        round, signum, sin, sinh, sqrt, square, tan, tanh, trunc
+    );
+    //
+    // minus implements differently
+    #[doc = "see [doc_f_unary_double_double]" ]
+    fn minus(self) -> DoubleDouble { - *self }
+    //
+    // binary functions, but it only has one float
+    #[doc = "see [doc_f_unary_double_double]" ]
+    fn powi(self, rhs : i32) -> DoubleDouble { DoubleDouble::powi(*self, rhs) }
+}
+impl FUnary for DoubleDouble {
+    type Output = DoubleDouble;
+    double_double_unary_own!(
+        abs, acos, acosh, asin, asinh, atan, atanh, cbrt, ceil, cos, cosh,
+        erf, erfc, exp, exp2, exp_m1, floor, ln, ln_1p, log10, log2, minus,
+        round, signum, sin, sinh, sqrt, square, tan, tanh, trunc
+    );
+    #[doc = "see [doc_f_unary_double_double]" ]
+    fn powi(self, rhs : i32) -> DoubleDouble { FUnary::powi(&self, rhs) }
+}
+// ---------------------------------------------------------------------------
+// FBinary
+/// Implement [FBinary] when both operands are `DoubleDouble`
+/// or `&DoubleDouble` .
+///
+/// The numerical comparisons return one (zero) for true (false).
+/// If either operand is nan, all the comparisons except num_ne are false.
+///
+/// # Example
+/// ```
+/// use rustad::{
+///     DoubleDouble,
+///     FBinary,
+/// };
+/// type V     = DoubleDouble;
+/// let two    = V::from(2.0);
+/// let three  = V::from(3.0);
+/// assert_eq!( two.powf(three), V::from(8.0) );
+/// assert_eq!( FBinary::num_lt(&two, &three), V::from(1.0) );
+/// assert_eq!( FBinary::num_lt(V::NAN, three), V::from(0.0) );
+/// ```
+pub fn doc_f_binary_double_double() {}
+//
+macro_rules! double_double_binary_function{
+    ($name:ident) => {
+        #[doc = "see [doc_f_binary_double_double]" ]
+        fn $name(self, rhs : &DoubleDouble) -> DoubleDouble {
+            DoubleDouble::$name(*self, *rhs)
+        }
+    };
+    ($name:ident, $op:tt) => {
+        #[doc = "see [doc_f_binary_double_double]" ]
+        fn $name(self, rhs : &DoubleDouble) -> DoubleDouble {
+            if self $op rhs {
+                DoubleDouble::one()
+            } else {
+                DoubleDouble::zero()
+            }
+        }
+    };
+}
+macro_rules! double_double_binary_own{ ( $($name:ident),* ) => {
+    $(
+        #[doc = "see [doc_f_binary_double_double]" ]
+        fn $name(self, rhs : DoubleDouble) -> DoubleDouble {
+            FBinary::$name(&self, &rhs)
+        }
+    )*
+} }
+impl FBinary<&DoubleDouble> for &DoubleDouble {
+    type Output = DoubleDouble;
+    //
+    double_double_binary_function!( num_lt, <  );
+    double_double_binary_function!( num_le, <= );
+    double_double_binary_function!( num_eq, == );
+    double_double_binary_function!( num_ne, != );
+    double_double_binary_function!( num_ge, >= );
+    double_double_binary_function!( num_gt, >  );
+    double_double_binary_function!( atan2 );
+    double_double_binary_function!( hypot );
+    double_double_binary_function!( powf );
+    double_double_binary_function!( min );
+    double_double_binary_function!( max );
+}
+impl FBinary<DoubleDouble> for DoubleDouble {
+    type Output = DoubleDouble;
+    double_double_binary_own!(
+        num_lt, num_le, num_eq, num_ne, num_ge, num_gt,
+        atan2, hypot, powf, min, max
+    );
+}
+// ----------------------------------------------------------------------------
+// FReduce
+/// [FReduce] for DoubleDouble.
+///
+/// The reduce_len function returns one and reduce_dot is multiplication.
+/// The other reductions return a copy of the value.
+impl FReduce for &DoubleDouble {
+    type Output = DoubleDouble;
+    //
+    fn reduce_sum(self)  -> DoubleDouble { *self }
+    fn reduce_mean(self) -> DoubleDouble { *self }
+    fn reduce_max(self)  -> DoubleDouble { *self }
+    fn reduce_len(self)  -> DoubleDouble { DoubleDouble::one() }
+    fn reduce_dot(self, rhs : Self) -> DoubleDouble { *self * *rhs }
+}
+// ----------------------------------------------------------------------------
+// FValue
+// doc_f_value_double_double
+/// [FValue] for DoubleDouble.
+///
+/// * is_zero, is_one : the value is zero, one.
+///
+/// * to_src :
+///   is source code that uses [DoubleDouble::new] to create
+///   the same value.
+///
+/// # Example
+/// ```
+/// use rustad::{
+///     DoubleDouble,
+///     FConst,
+///     FValue,
+/// };
+/// type V = DoubleDouble;
+/// //
+/// let zero = V::zero();
+/// assert!( zero.is_zero() );
+/// assert!( ! zero.is_one() );
+/// let x    = V::new(1.0, 1e-20);
+/// assert_eq!( x.to_src(), "DoubleDouble::new(1.0, 1e-20)" );
+/// ```
+pub fn doc_f_value_double_double() {}
+//
+impl DoubleDouble {
+    //
+    // part_src
+    // source code for one of the parts
+    fn part_src(x : f64) -> String {
+        if x.is_nan() {
+            "f64::NAN".to_string()
+        } else if x == f64::INFINITY {
+            "f64::INFINITY".to_string()
+        } else if x == f64::NEG_INFINITY {
+            "f64::NEG_INFINITY".to_string()
+        } else {
+            format!( "{x:?}" )
+        }
+    }
+}
+impl FValue for DoubleDouble {
+    fn is_zero(&self) -> bool { self.hi == 0.0 }
+    fn is_one(&self)  -> bool { self.hi == 1.0 && self.lo == 0.0 }
+    fn is_nan(&self)  -> bool { self.hi.is_nan() }
+    fn to_src(&self)  -> String {
+        "DoubleDouble::new(".to_string() +
+            &Self::part_src(self.hi) + ", " +
+            &Self::part_src(self.lo) +
+        ")"
+    }
+}
+// ----------------------------------------------------------------------------
+// FBytes
+// doc_f_bytes_double_double
+/// [FBytes] for DoubleDouble.
+///
+/// * type_name : is `DoubleDouble` .
+///
+/// * bytes :
+///   is the little endian representation of the high order part followed by
+///   the little endian representation of the low order part.
+///
+/// # Example
+/// ```
+/// use rustad::{
+///     DoubleDouble,
+///     FBytes,
+/// };
+/// type V = DoubleDouble;
+/// //
+/// let x         = V::new(1.0, 1e-20);
+/// let mut bytes = Vec::new();
+/// x.to_bytes(&mut bytes);
+/// assert_eq!( bytes.len(), 2 * 8 );
+/// //
+/// let mut start = 0;
+/// let y         = V::from_bytes(&bytes, &mut start).unwrap();
+/// assert_eq!( x, y );
+/// assert_eq!( V::type_name(), "DoubleDouble" );
+/// ```
+pub fn doc_f_bytes_double_double() {}
+//
+impl FBytes for DoubleDouble {
+    fn type_name() -> String {
+        "DoubleDouble".to_string()
+    }
+    fn to_bytes(&self, bytes : &mut Vec<u8>) {
+        AzFloat(self.hi).to_bytes(bytes);
+        AzFloat(self.lo).to_bytes(bytes);
+    }
+    fn from_bytes(bytes : &[u8], start : &mut usize) -> Result<Self, String> {
+        let hi = AzFloat::<f64>::from_bytes(bytes, start)?;
+        let lo = AzFloat::<f64>::from_bytes(bytes, start)?;
+        Ok( DoubleDouble{ hi : hi.0, lo : lo.0 } )
+    }
+}
+// ----------------------------------------------------------------------------
+// FText
+// doc_f_text_double_double
+/// [FText] for DoubleDouble.
+///
+/// * text :
+///   is the [AzFloat] text representation of the high and low order parts,
+///   separated by a comma and enclosed in parenthesis.
+///
+/// # Example
+/// ```
+/// use rustad::{
+///     DoubleDouble,
+///     FText,
+/// };
+/// type V = DoubleDouble;
+/// //
+/// let x    = V::new(-1.0, 1e-20);
+/// let text = x.to_text();
+/// assert_eq!( text, "( -1.0, 1e-20 )" );
+/// assert_eq!( V::from_text(&text), Ok(x) );
+/// ```
+pub fn doc_f_text_double_double() {}
+//
+impl FText for DoubleDouble {
+    fn to_text(&self) -> String {
+        "( ".to_string() +
+            &AzFloat(self.hi).to_text() + ", " +
+            &AzFloat(self.lo).to_text() +
+        " )"
+    }
+    fn from_text(text : &str) -> Result<Self, String> {
+        let inner = text.trim().strip_prefix('(').and_then(
+            |rest| rest.strip_suffix(')')
+        );
+        let parts : Vec<&str> = match inner {
+            Some(inner) => inner.split(',').collect(),
+            None        => Vec::new(),
+        };
+        if parts.len() != 2 {
+            return Err( format!(
+                "DoubleDouble::from_text: expected ( hi, lo ): {text}"
+            ) );
+        }
+        let hi = AzFloat::<f64>::from_text( parts[0] )?;
+        let lo = AzFloat::<f64>::from_text( parts[1] )?;
+        Ok( DoubleDouble{ hi : hi.0, lo : lo.0 } )
+    }
+}
+// ===========================================================================
+// AzFloat<DoubleDouble>
+// ===========================================================================
+//
+// From
+/// `AzFloat<DoubleDouble>` From
+///
+/// From is implemented for usize, f32, f64 , and DoubleDouble .
+///
+/// # Example
+/// ```
+/// use rustad::{
+///     AzFloat,
+///     DoubleDouble,
+///     FConst,
+/// };
+/// type V = AzFloat<DoubleDouble>;
+/// //
+/// let zero = V::from(0);
+/// let nan  = V::from( f64::NAN );
+/// assert_eq!( zero * nan, zero );
+/// assert_eq!( V::from( DoubleDouble::from(2.0) ), V::from(2.0) );
+/// ```
+pub fn doc_az_float_from() {}
+//
+macro_rules! impl_az_float_from{ ($P:ident) => {
+    #[doc = "see [doc_az_float_from]" ]
+    impl From<$P> for AzFloat<DoubleDouble> {
+        fn from(x : $P) -> Self {
+            AzFloat( DoubleDouble::from(x) )
+        }
+    }
+} }
+impl_az_float_from!(usize);
+impl_az_float_from!(f32);
+impl_az_float_from!(f64);
+impl From<DoubleDouble> for AzFloat<DoubleDouble> {
+    /// see [doc_az_float_from]
+    fn from(x : DoubleDouble) -> Self {
+        AzFloat(x)
+    }
+}
+//
+// Hash
+/// see [doc_hash_trait](crate::float::az_float::doc_hash_trait)
+impl std::hash::Hash for AzFloat<DoubleDouble> {
+    fn hash<H : std::hash::Hasher>(&self, state : &mut H) {
+        self.0.hi.to_bits().hash(state);
+        self.0.lo.to_bits().hash(state);
+    }
+}
+//
+// FConst
+/// FConst trait for `AzFloat<DoubleDouble>`
+impl FConst for AzFloat<DoubleDouble> {
+    fn pi()           -> Self { AzFloat( DoubleDouble::pi() ) }
+    fn nan()          -> Self { AzFloat( DoubleDouble::nan() ) }
+    fn one()          -> Self { AzFloat( DoubleDouble::one() ) }
+    fn zero()         -> Self { AzFloat( DoubleDouble::zero() ) }
+    fn epsilon()      -> Self { AzFloat( DoubleDouble::epsilon() ) }
+    fn min_positive() -> Self { AzFloat( DoubleDouble::min_positive() ) }
+    const ABSOLUTE_ZERO : bool = true;
+}
+//
+// FUnary
+macro_rules! az_float_unary_function{ ( $($name:ident),* ) => {
+    $(
+        #[doc = "see [doc_f_unary_double_double]" ]
+        fn $name(self) -> AzFloat<DoubleDouble> {
+            AzFloat( FUnary::$name(&self.0) )
+        }
+    )*
+} }
+impl FUnary for &AzFloat<DoubleDouble> {
+    type Output = AzFloat<DoubleDouble>;
+    az_float_unary_function!(
+        abs, acos, acosh, asin, asinh, atan, atanh, cbrt, ceil, cos, cosh,
+        erf, erfc, exp, exp2, exp_m1, floor, ln, ln_1p, log10, log2, minus,
+        round, signum, sin, sinh, sqrt, square, tan, tanh, trunc
+    );
+    #[doc = "see [doc_f_unary_double_double]" ]
+    fn powi(self, rhs : i32) -> AzFloat<DoubleDouble> {
+        AzFloat( self.0.powi(rhs) )
+    }
+}
+impl FUnary for AzFloat<DoubleDouble> {
+    type Output = AzFloat<DoubleDouble>;
+    az_float_unary_function!(
+        abs, acos, acosh, asin, asinh, atan, atanh, cbrt, ceil, cos, cosh,
+        erf, erfc, exp, exp2, exp_m1, floor, ln, ln_1p, log10, log2, minus,
+        round, signum, sin, sinh, sqrt, square, tan, tanh, trunc
+    );
+    #[doc = "see [doc_f_unary_double_double]" ]
+    fn powi(self, rhs : i32) -> AzFloat<DoubleDouble> {
+        AzFloat( self.0.powi(rhs) )
+    }
+}
+//
+// FBinary
+// (the AzFloat<B> op AzFloat<B> case is implemented in az_float.rs)
+macro_rules! az_float_binary_function{ ( $($name:ident),* ) => {
+    $(
+        #[doc = "see [doc_f_binary_double_double]" ]
+        fn $name(self, rhs : &AzFloat<DoubleDouble>) -> AzFloat<DoubleDouble> {
+            AzFloat( FBinary::$name(&self.0, &rhs.0) )
+        }
+    )*
+} }
+impl FBinary< &AzFloat<DoubleDouble> > for &AzFloat<DoubleDouble> {
+    type Output = AzFloat<DoubleDouble>;
+    az_float_binary_function!(
+        num_lt, num_le, num_eq, num_ne, num_ge, num_gt,
+        atan2, hypot, powf, min, max
+    );
+}
+//
+// FValue
+/// [FValue] for `AzFloat<DoubleDouble>`
+///
+/// # Example
+/// ```
+/// use rustad::{
+///     AzFloat,
+///     DoubleDouble,
+///     FValue,
+/// };
+/// type V = AzFloat<DoubleDouble>;
+/// let x  = V::from(2.0);
+/// assert_eq!( x.to_src(), "AzFloat( DoubleDouble::new(2.0, 0.0) )" );
+/// ```
+impl FValue for AzFloat<DoubleDouble> {
+    fn is_zero(&self) -> bool   { self.0.is_zero() }
+    fn is_one(&self)  -> bool   { self.0.is_one() }
+    fn is_nan(&self)  -> bool   { self.0.is_nan() }
+    fn to_src(&self)  -> String {
+        "AzFloat( ".to_string() + &self.0.to_src() + " )"
+    }
+}
+//
+// FBytes
+/// [FBytes] for `AzFloat<DoubleDouble>` ;
+/// type_name is `AzFloat<DoubleDouble>` and the bytes are the same as for
+/// DoubleDouble.
+impl FBytes for AzFloat<DoubleDouble> {
+    fn type_name() -> String {
+        "AzFloat<DoubleDouble>".to_string()
+    }
+    fn to_bytes(&self, bytes : &mut Vec<u8>) {
+        self.0.to_bytes(bytes)
+    }
+    fn from_bytes(bytes : &[u8], start : &mut usize) -> Result<Self, String> {
+        Ok( AzFloat( DoubleDouble::from_bytes(bytes, start)? ) )
+    }
+}
+//
+// FText
+/// [FText] for `AzFloat<DoubleDouble>` ;
+/// the text is the same as for DoubleDouble.
+impl FText for AzFloat<DoubleDouble> {
+    fn to_text(&self) -> String {
+        self.0.to_text()
+    }
+    fn from_text(text : &str) -> Result<Self, String> {
+        Ok( AzFloat( DoubleDouble::from_text(text)? ) )
+    }
+}
@@ -0,0 +1,111 @@
+// ---------------------------------------------------------------------------
+// SPDX-License-Identifier: EPL-2.0 OR GPL-2.0-or-later
+// SPDX-FileCopyrightText: Bradley M. Bell <bradbell@seanet.com>
+// SPDX-FileContributor: 2026 Bradley M. Bell
+// ---------------------------------------------------------------------------
+//
+//! This pub module evaluates the error function and its complement.
+//!
+//! Link to [parent module](super)
+//!
+//! This module does not have dependencies outside standard rust and src/float.
+//! This enables src/float to be directly included as part of a Dll library.
+//! (The standard library erf functions are not yet stable.)
+//!
+//! * Method :
+//!   For |x| < 0.5, erf(x) is evaluated using its power series.
+//!   Otherwise, erfc(|x|) is evaluated using a Chebyshev expansion
+//!   of the form t * exp( - x * x + p(t) ) where t = 2 / (2 + |x|) ;
+//!   see Numerical Recipes, Third Edition, Section 6.2.2.
+//!   The relative error is less than 1e-14 for |x| < 6 .
+//!
+// ---------------------------------------------------------------------------
+//
+// ERFC_COF
+// Chebyshev coefficients for erfc_cheb
+const ERFC_COF : [f64; 28] = [
+    -1.3026537197817094,   6.419697923564902e-1,
+     1.9476473204185836e-2,-9.56151478680863e-3,
+    -9.46595344482036e-4,   3.66839497852761e-4,
+     4.2523324806907e-5,   -2.0278578112534e-5,
+    -1.624290004647e-6,     1.303655835580e-6,
+     1.5626441722e-8,      -8.5238095915e-8,
+     6.529054439e-9,        5.059343495e-9,
+    -9.91364156e-10,       -2.27365122e-10,
+     9.6467911e-11,         2.394038e-12,
+    -6.886027e-12,          8.94487e-13,
+     3.13092e-13,          -1.12708e-13,
+     3.81e-16,              7.106e-15,
+    -1.523e-15,            -9.4e-17,
+     1.21e-16,             -2.8e-17,
+];
+//
+// erfc_cheb
+// erfc(z) for z >= 0 (or z nan) using the Chebyshev expansion.
+fn erfc_cheb(z : f64) -> f64 {
+    debug_assert!( z >= 0.0 || z.is_nan() );
+    let t      = 2.0 / (2.0 + z);
+    let ty     = 4.0 * t - 2.0;
+    let mut d  = 0.0;
+    let mut dd = 0.0;
+    for cof in ERFC_COF[1 ..].iter().rev() {
+        let temp = d;
+        d        = ty * d - dd + cof;
+        dd       = temp;
+    }
+    t * ( - z * z + 0.5 * (ERFC_COF[0] + ty * d) - dd ).exp()
+}
+//
+// erf_series
+// erf(x) for |x| < 0.5 using its power series; i.e.,
+// (2 / sqrt(pi)) sum_k (-1)^k x^(2k+1) / ( k! (2k+1) ) .
+fn erf_series(x : f64) -> f64 {
+    debug_assert!( x.abs() < 0.5 );
+    let x_sq     = x * x;
+    let mut term = x;
+    let mut sum  = x;
+    let mut k    = 0.0;
+    while term.abs() > f64::EPSILON * sum.abs() {
+        k    += 1.0;
+        term *= - x_sq / k;
+        sum  += term / (2.0 * k + 1.0);
+    }
+    std::f64::consts::FRAC_2_SQRT_PI * sum
+}
+//
+// erf
+/// Error function; i.e., (2 / sqrt(pi)) times the integral of exp(-t * t)
+/// for t between zero and x .
+///
+/// # Example
+/// ```
+/// let y = rustad::float::erf::erf(0.5);
+/// assert!( (y - 0.5204998778130465).abs() < 1e-15 );
+/// ```
+pub fn erf(x : f64) -> f64 {
+    if x.abs() < 0.5 {
+        erf_series(x)
+    } else if x >= 0.0 {
+        1.0 - erfc_cheb(x)
+    } else {
+        erfc_cheb(-x) - 1.0
+    }
+}
+//
+// erfc
+/// Complementary error function; i.e., 1 - erf(x) .
+///
+/// This is more accurate than 1 - erf(x) when x is large.
+///
+/// # Example
+/// ```
+/// let y = rustad::float::erf::erfc(3.0);
+/// assert!( (y / 2.209049699858544e-5 - 1.0).abs() < 1e-14 );
+/// ```
+pub fn erfc(x : f64) -> f64 {
+    if x >= 0.0 || x.is_nan() {
+        erfc_cheb(x)
+    } else {
+        2.0 - erfc_cheb(-x)
+    }
+}
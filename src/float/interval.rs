@@ -0,0 +1,1359 @@
+// ---------------------------------------------------------------------------
+// SPDX-License-Identifier: EPL-2.0 OR GPL-2.0-or-later
+// SPDX-FileCopyrightText: Bradley M. Bell <bradbell@seanet.com>
+// SPDX-FileContributor: 2026 Bradley M. Bell
+// ---------------------------------------------------------------------------
+//
+//! This pub module defines the rustad Interval class.
+//!
+//! Link to [parent module](super)
+//!
+//! This module does not have dependencies outside standard rust and src/float.
+//! This enables src/float to be directly included as part of a Dll library.
+//!
+//! * Outward Rounding :
+//!   The standard library does not provide control of the rounding mode.
+//!   The results for +, -, *, / and sqrt are rounded outward
+//!   using error free transformations; i.e., a bound is only moved
+//!   to the next floating point number when the rounded result is not exact.
+//!   These are rigorous enclosures.
+//!
+//! * Library Functions :
+//!   The results for the other standard library functions
+//!   (e.g. exp, sin, powf) are moved outward by two units in the last place.
+//!   The standard library does not document an error bound for these
+//!   functions, so this widening is a heuristic and not a proof.
+//!   The erf and erfc bounds are widened by a relative tolerance based on
+//!   the observed accuracy of src/float/erf.rs , which is also a heuristic.
+//!
+// ---------------------------------------------------------------------------
+// use
+//
+use std::ops::{
+    Add,
+    Sub,
+    Mul,
+    Div,
+    AddAssign,
+    SubAssign,
+    MulAssign,
+    DivAssign,
+};
+//
+use crate::{
+    AzFloat,
+    FConst,
+    FUnary,
+    FBinary,
+    FReduce,
+    FValue,
+    FBytes,
+    FText,
+};
+// ---------------------------------------------------------------------------
+/// The Interval class.
+///
+/// * B : is the floating point base type; i.e., f32 or f64 .
+///
+/// * Enclosure :
+///   The result of +, -, *, / and sqrt contains the result of the
+///   corresponding exact real operation for every choice of
+///   arguments in the operand intervals.
+///   The other functions are widened heuristically and their results
+///   are expected, but not proven, to contain the exact result;
+///   see [Library Functions](crate::float::interval) .
+///   The lower bound must be less than or equal the upper bound.
+///
+/// * Nan :
+///   If either bound is nan, the interval is nan.
+///   This is the result when an operand is nan or when an operand interval
+///   is not contained in the domain of a function; e.g., ln( [-1, 1] ) .
+///   Two nan intervals are equal.
+///
+/// * Zero : As with [AzFloat],
+///   multiplication by the interval [0, 0] always results in [0, 0]
+///   (even if the other operand is nan or has an infinite bound).
+///
+/// * Unbounded :
+///   Division by an interval that contains zero, and tan of an interval that
+///   contains a pole, result in the interval [-inf, +inf] .
+///
+/// * Comparisons :
+///   The functions num_lt, num_le, num_eq, num_ne, num_ge, num_gt
+///   return [1, 1] ( [0, 0] ) if the comparison is true (false) for
+///   every choice of values in the operands. Otherwise they return [0, 1] .
+///
+/// * Copy : The Copy trait is implemented for these types.
+///
+/// # Example
+/// ```
+/// use rustad::Interval;
+/// type V    = Interval<f64>;
+/// //
+/// let x     = V::new(1.0, 2.0);
+/// let y     = V::new(-1.0, 3.0);
+/// assert_eq!( &x + &y, V::new(0.0, 5.0) );
+/// assert_eq!( &x * &y, V::new(-2.0, 6.0) );
+/// //
+/// let third = &V::from(1.0) / &V::from(3.0);
+/// assert!( third.lower < third.upper );
+/// assert!( third.contains( 1.0 / 3.0 ) );
+/// //
+/// let zero  = V::from(0.0);
+/// let nan   = V::from( f64::NAN );
+/// assert_eq!( &zero * &nan, zero );
+/// assert_eq!( nan, nan );
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Interval<B> {
+    /// lower bound
+    pub lower : B,
+    /// upper bound
+    pub upper : B,
+}
+//
+impl<B> Interval<B> {
+    //
+    // new
+    /// Create an interval from its lower and upper bounds
+    pub fn new(lower : B, upper : B) -> Self {
+        Self{ lower, upper }
+    }
+}
+// ---------------------------------------------------------------------------
+// Interval<B> rounding
+macro_rules! impl_interval_round{ ($B:ident) => {
+    impl Interval<$B> {
+        //
+        // contains
+        /// Is a value in this interval
+        pub fn contains(&self, x : $B) -> bool {
+            self.lower <= x && x <= self.upper
+        }
+        //
+        // ULPS
+        // units in the last place that library function results are moved.
+        // This is a heuristic; libm does not document an error bound.
+        const ULPS : u32 = 2;
+        //
+        // TINY
+        // below this magnitude, the error in a product or quotient
+        // may not be representable
+        const TINY : $B = 4.0 * $B::MIN_POSITIVE / $B::EPSILON;
+        //
+        // checked
+        // the interval [lower, upper] or nan if either bound is nan
+        fn checked(lower : $B, upper : $B) -> Self {
+            if lower.is_nan() || upper.is_nan() {
+                Self::nan()
+            } else {
+                Self::new(lower, upper)
+            }
+        }
+        //
+        // entire
+        // the interval [-inf, +inf]
+        fn entire() -> Self {
+            Self::new( $B::NEG_INFINITY, $B::INFINITY )
+        }
+        //
+        // down, up
+        // s is a rounded result, e has the sign of the exact result minus s
+        // (e is nan if the sign is not known).
+        fn down(s : $B, e : $B) -> $B {
+            if e.is_nan() || e < 0.0 { s.next_down() } else { s }
+        }
+        fn up(s : $B, e : $B) -> $B {
+            if e.is_nan() || e > 0.0 { s.next_up() } else { s }
+        }
+        //
+        // add_err
+        fn add_err(a : $B, b : $B, s : $B) -> $B {
+            let bb = s - a;
+            (a - (s - bb)) + (b - bb)
+        }
+        fn add_down(a : $B, b : $B) -> $B {
+            let s = a + b;
+            Self::down(s, Self::add_err(a, b, s) )
+        }
+        fn add_up(a : $B, b : $B) -> $B {
+            let s = a + b;
+            Self::up(s, Self::add_err(a, b, s) )
+        }
+        //
+        // mul_err
+        // p is the product of a and b using absolute zero multiplication
+        fn mul_err(a : $B, b : $B, p : $B) -> $B {
+            if a == 0.0 || b == 0.0 {
+                0.0
+            } else if p.abs() < Self::TINY {
+                $B::NAN
+            } else {
+                a.mul_add(b, -p)
+            }
+        }
+        fn mul_near(a : $B, b : $B) -> $B {
+            if a == 0.0 || b == 0.0 { 0.0 } else { a * b }
+        }
+        fn mul_down(a : $B, b : $B) -> $B {
+            let p = Self::mul_near(a, b);
+            Self::down(p, Self::mul_err(a, b, p) )
+        }
+        fn mul_up(a : $B, b : $B) -> $B {
+            let p = Self::mul_near(a, b);
+            Self::up(p, Self::mul_err(a, b, p) )
+        }
+        //
+        // div_err
+        // q is the quotient of a divided by b (b is not zero)
+        fn div_err(a : $B, b : $B, q : $B) -> $B {
+            if a == 0.0 {
+                0.0
+            } else if q.abs() < Self::TINY || a.abs() < Self::TINY {
+                $B::NAN
+            } else {
+                let r = (-q).mul_add(b, a);
+                if b < 0.0 { -r } else { r }
+            }
+        }
+        fn div_down(a : $B, b : $B) -> $B {
+            let q = a / b;
+            Self::down(q, Self::div_err(a, b, q) )
+        }
+        fn div_up(a : $B, b : $B) -> $B {
+            let q = a / b;
+            Self::up(q, Self::div_err(a, b, q) )
+        }
+        //
+        // sqrt_err
+        // s is the square root of a (a is not negative)
+        fn sqrt_err(a : $B, s : $B) -> $B {
+            if a == 0.0 {
+                0.0
+            } else if a < Self::TINY {
+                $B::NAN
+            } else {
+                (-s).mul_add(s, a)
+            }
+        }
+        //
+        // lib_down, lib_up
+        // move a standard library function result outward
+        fn lib_down(s : $B) -> $B {
+            let mut s = s;
+            for _ in 0 .. Self::ULPS {
+                s = s.next_down();
+            }
+            s
+        }
+        fn lib_up(s : $B) -> $B {
+            let mut s = s;
+            for _ in 0 .. Self::ULPS {
+                s = s.next_up();
+            }
+            s
+        }
+        //
+        // increasing, decreasing
+        // result for a monotone standard library function
+        fn increasing(self, f : fn($B) -> $B) -> Self {
+            Self::checked(
+                Self::lib_down( f(self.lower) ), Self::lib_up( f(self.upper) )
+            )
+        }
+        fn decreasing(self, f : fn($B) -> $B) -> Self {
+            Self::checked(
+                Self::lib_down( f(self.upper) ), Self::lib_up( f(self.lower) )
+            )
+        }
+        //
+        // in_domain
+        fn in_domain(&self, lower : $B, upper : $B) -> bool {
+            lower <= self.lower && self.upper <= upper
+        }
+        //
+        // clamp
+        // intersect with an interval known to contain the exact result
+        fn clamp(self, lower : $B, upper : $B) -> Self {
+            if self.is_nan() {
+                return self;
+            }
+            Self::new( self.lower.max(lower), self.upper.min(upper) )
+        }
+        //
+        // may_contain
+        // Is there possibly an integer k such that
+        // period * (k + offset) is in this interval.
+        fn may_contain(&self, period : $B, offset : $B) -> bool {
+            let a   = self.lower / period - offset;
+            let b   = self.upper / period - offset;
+            let tol = 8.0 * $B::EPSILON * ( 1.0 + a.abs().max( b.abs() ) );
+            (a - tol).ceil() <= b + tol
+        }
+        //
+        // pow_down, pow_up
+        // bounds for x^n where x is not negative
+        fn pow_down(x : $B, n : u32) -> $B {
+            let mut base   = x;
+            let mut result = 1.0;
+            let mut n      = n;
+            while n > 0 {
+                if n % 2 == 1 {
+                    result = Self::mul_down(result, base);
+                }
+                base = Self::mul_down(base, base);
+                n   /= 2;
+            }
+            result
+        }
+        fn pow_up(x : $B, n : u32) -> $B {
+            let mut base   = x;
+            let mut result = 1.0;
+            let mut n      = n;
+            while n > 0 {
+                if n % 2 == 1 {
+                    result = Self::mul_up(result, base);
+                }
+                base = Self::mul_up(base, base);
+                n   /= 2;
+            }
+            result
+        }
+        //
+        // erf_bounds, erfc_bounds
+        // bounds for erf(x), erfc(x) using the accuracy of src/float/erf.rs
+        fn erf_bounds(x : $B) -> ($B, $B) {
+            let x = f64::from(x);
+            let (lower, upper) = if x.is_nan() {
+                (f64::NAN, f64::NAN)
+            } else if 6.0 <= x {
+                ( (1.0f64).next_down(), 1.0 )
+            } else if x <= -6.0 {
+                ( -1.0, (-1.0f64).next_up() )
+            } else {
+                let y   = super::erf::erf(x);
+                let tol = 1e-13 * y.abs() + f64::MIN_POSITIVE;
+                ( (y - tol).max(-1.0), (y + tol).min(1.0) )
+            };
+            ( Self::narrow_down(lower), Self::narrow_up(upper) )
+        }
+        fn erfc_bounds(x : $B) -> ($B, $B) {
+            let x = f64::from(x);
+            let (lower, upper) = if x.is_nan() {
+                (f64::NAN, f64::NAN)
+            } else if 6.0 <= x {
+                ( 0.0, 1e-16 )
+            } else if x <= -6.0 {
+                ( (2.0f64).next_down(), 2.0 )
+            } else {
+                let y   = super::erf::erfc(x);
+                let tol = 1e-13 * y + f64::MIN_POSITIVE;
+                ( (y - tol).max(0.0), (y + tol).min(2.0) )
+            };
+            ( Self::narrow_down(lower), Self::narrow_up(upper) )
+        }
+        //
+        // narrow_down, narrow_up
+        // convert an f64 bound to the base type
+        fn narrow_down(x : f64) -> $B {
+            let y = x as $B;
+            if f64::from(y) > x { y.next_down() } else { y }
+        }
+        fn narrow_up(x : f64) -> $B {
+            let y = x as $B;
+            if f64::from(y) < x { y.next_up() } else { y }
+        }
+    }
+} }
+impl_interval_round!(f32);
+impl_interval_round!(f64);
+// ---------------------------------------------------------------------------
+/// Interval From
+///
+/// * `Interval<f32>` :
+///   From is implemented for usize, f32, and `AzFloat<f32>` .
+///
+/// * `Interval<f64>` :
+///   From is implemented for usize, f32, f64, and `AzFloat<f64>` .
+///
+/// The result is the interval containing only the value
+/// (or the smallest interval containing a usize value that is not exactly
+/// representable).
+pub fn doc_impl_from() {}
+//
+macro_rules! impl_from_primitive{ ($P:ident, $T:ident) => {
+    #[doc = "see [doc_impl_from]" ]
+    impl From<$P> for Interval<$T> {
+        fn from(p : $P) -> Interval<$T> {
+            let x = p as $T;
+            Interval::new(x, x)
+        }
+    }
+} }
+impl_from_primitive!(f32, f32);
+impl_from_primitive!(f32, f64);
+impl_from_primitive!(f64, f64);
+//
+macro_rules! impl_from_usize{ ($T:ident) => {
+    #[doc = "see [doc_impl_from]" ]
+    impl From<usize> for Interval<$T> {
+        fn from(p : usize) -> Interval<$T> {
+            let x = p as $T;
+            if (x as u128) == (p as u128) {
+                Interval::new(x, x)
+            } else {
+                Interval::new( x.next_down(), x.next_up() )
+            }
+        }
+    }
+    #[doc = "see [doc_impl_from]" ]
+    impl From< AzFloat<$T> > for Interval<$T> {
+        fn from(x : AzFloat<$T>) -> Interval<$T> {
+            Interval::new(x.0, x.0)
+        }
+    }
+} }
+impl_from_usize!(f32);
+impl_from_usize!(f64);
+// ---------------------------------------------------------------------------
+// Interval Op Interval
+/// Interval binary operations
+///
+/// * Syntax :
+/// ```text
+///     z = x Op y
+/// ```
+///
+/// * B : is the floating point base type; i.e., f32 or f64 .
+///
+/// * Op : is the source code token for this binary operator;
+///   i.e., `+` , `-` , `*` , or `/` .
+///
+/// * x : left hand side `Interval<B>` or `&Interval<B>` object
+/// * y : right hand side `Interval<B>` or `&Interval<B>` object
+/// * z : result `Interval<B>` object
+///
+/// If the left or right operand is borrowed (&), then both operands
+/// must be borrowed.
+///
+/// # Example :
+/// ```
+/// use rustad::Interval;
+/// type V = Interval<f64>;
+/// //
+/// let x  = V::new(1.0, 2.0);
+/// let y  = V::new(4.0, 8.0);
+/// assert_eq!( x - y, V::new(-7.0, -2.0) );
+/// assert_eq!( &y / &x, V::new(2.0, 8.0) );
+/// //
+/// // division by an interval that contains zero
+/// let z  = V::new(-1.0, 1.0);
+/// assert_eq!( &x / &z, V::new(f64::NEG_INFINITY, f64::INFINITY) );
+/// ```
+pub fn doc_binary_operator() { }
+//
+macro_rules! impl_interval_arithmetic{ ($B:ident) => {
+    #[doc = "see [doc_binary_operator]"]
+    impl Add< &Interval<$B> > for &Interval<$B> {
+        type Output = Interval<$B>;
+        fn add(self, rhs : &Interval<$B>) -> Interval<$B> {
+            Interval::<$B>::checked(
+                Interval::<$B>::add_down(self.lower, rhs.lower) ,
+                Interval::<$B>::add_up(self.upper, rhs.upper)   ,
+            )
+        }
+    }
+    #[doc = "see [doc_binary_operator]"]
+    impl Sub< &Interval<$B> > for &Interval<$B> {
+        type Output = Interval<$B>;
+        fn sub(self, rhs : &Interval<$B>) -> Interval<$B> {
+            Interval::<$B>::checked(
+                Interval::<$B>::add_down(self.lower, - rhs.upper) ,
+                Interval::<$B>::add_up(self.upper, - rhs.lower)   ,
+            )
+        }
+    }
+    #[doc = "see [doc_binary_operator]"]
+    impl Mul< &Interval<$B> > for &Interval<$B> {
+        type Output = Interval<$B>;
+        fn mul(self, rhs : &Interval<$B>) -> Interval<$B> {
+            if self.is_zero() || rhs.is_zero() {
+                return Interval::<$B>::zero();
+            }
+            if self.is_nan() || rhs.is_nan() {
+                return Interval::<$B>::nan();
+            }
+            let mut lower = $B::INFINITY;
+            let mut upper = $B::NEG_INFINITY;
+            for a in [self.lower, self.upper] {
+                for b in [rhs.lower, rhs.upper] {
+                    lower = lower.min( Interval::<$B>::mul_down(a, b) );
+                    upper = upper.max( Interval::<$B>::mul_up(a, b) );
+                }
+            }
+            Interval::new(lower, upper)
+        }
+    }
+    #[doc = "see [doc_binary_operator]"]
+    impl Div< &Interval<$B> > for &Interval<$B> {
+        type Output = Interval<$B>;
+        fn div(self, rhs : &Interval<$B>) -> Interval<$B> {
+            if self.is_nan() || rhs.is_nan() || rhs.is_zero() {
+                return Interval::<$B>::nan();
+            }
+            if rhs.contains(0.0) {
+                return Interval::<$B>::entire();
+            }
+            let mut lower = $B::INFINITY;
+            let mut upper = $B::NEG_INFINITY;
+            for a in [self.lower, self.upper] {
+                for b in [rhs.lower, rhs.upper] {
+                    lower = lower.min( Interval::<$B>::div_down(a, b) );
+                    upper = upper.max( Interval::<$B>::div_up(a, b) );
+                }
+            }
+            Interval::<$B>::checked(lower, upper)
+        }
+    }
+} }
+impl_interval_arithmetic!(f32);
+impl_interval_arithmetic!(f64);
+//
+macro_rules! impl_binary_operator{ ($Name:ident, $name:ident) =>  {
+    #[doc = "see [doc_binary_operator]"]
+    impl<B> $Name for Interval<B>
+    where
+        for<'a> &'a Interval<B> : $Name<&'a Interval<B>, Output=Interval<B> >,
+    {
+        type Output = Interval<B>;
+        fn $name(self, rhs : Self) -> Interval<B> {
+            (&self).$name(&rhs)
+        }
+    }
+} }
+impl_binary_operator!(Add, add);
+impl_binary_operator!(Sub, sub);
+impl_binary_operator!(Mul, mul);
+impl_binary_operator!(Div, div);
+// ---------------------------------------------------------------------------
+// Interval Op &Interval
+/// Interval binary assign operations
+///
+/// * B : is the floating point base type; i.e., f32 or f64 .
+///
+/// * Syntax :
+///   ```text
+///     lhs op rhs
+///     lhs op &rhs
+///   ```
+///
+/// * lhs : is the `&mut Interval<B>` left operand
+/// * rhs : is the `Interval<B>` or `&Interval<B>` right operand
+/// * op  : is one of `+=` , `-=` , `*=` , `/=`
+///
+/// # Example :
+/// ```
+/// use rustad::Interval;
+/// type V = Interval<f32>;
+/// //
+/// let mut x = V::new(2.0, 4.0);
+/// let y     = V::from(2.0);
+/// //
+/// x        /= &y;
+/// assert_eq!( x, V::new(1.0, 2.0) );
+/// //
+/// x        -= y;
+/// assert_eq!( x, V::new(-1.0, 0.0) );
+/// ```
+pub fn doc_binary_assign() {}
+//
+macro_rules! impl_binary_assign{ ($Name:ident, $name:ident, $Op:ident, $op:tt) => {
+    #[doc = "see [doc_binary_assign]"]
+    impl<B> $Name <&Interval<B> > for Interval<B>
+    where
+        for<'a> &'a Interval<B> : $Op<&'a Interval<B>, Output=Interval<B> >,
+    {
+        fn $name(&mut self, rhs : &Interval<B> ) {
+            *self = &*self $op rhs;
+        }
+    }
+    #[doc = "see [doc_binary_assign]"]
+    impl<B> $Name <Interval<B> > for Interval<B>
+    where
+        for<'a> &'a Interval<B> : $Op<&'a Interval<B>, Output=Interval<B> >,
+    {
+        fn $name(&mut self, rhs : Interval<B> ) {
+            *self = &*self $op &rhs;
+        }
+    }
+} }
+impl_binary_assign!(AddAssign, add_assign, Add, +);
+impl_binary_assign!(SubAssign, sub_assign, Sub, -);
+impl_binary_assign!(MulAssign, mul_assign, Mul, *);
+impl_binary_assign!(DivAssign, div_assign, Div, /);
+// ---------------------------------------------------------------------------
+// PartialEq, Eq
+/// Interval Eq Operator
+///
+/// * B : is the floating point base type; i.e., f32 or f64 .
+///
+/// Two intervals are equal if their lower bounds are equal
+/// and their upper bounds are equal (where nan is equal to nan).
+///
+/// # Example
+/// ```
+/// use rustad::Interval;
+/// let nan = Interval::new( 1.0, f32::NAN );
+/// assert_eq!( nan, nan );
+/// ```
+impl<B> PartialEq for Interval<B>
+where
+    B          : Copy ,
+    AzFloat<B> : PartialEq ,
+{
+    fn eq(&self, rhs : &Self) -> bool {
+        AzFloat(self.lower) == AzFloat(rhs.lower) &&
+        AzFloat(self.upper) == AzFloat(rhs.upper)
+    }
+}
+impl<B> Eq for Interval<B>
+where
+    B          : Copy ,
+    AzFloat<B> : PartialEq ,
+{ }
+// ---------------------------------------------------------------------------
+/// Display an Interval object
+///
+/// * B : is the floating point base type; i.e., f32 or f64 .
+///
+/// # Example
+/// ```
+/// use rustad::Interval;
+/// let x  = Interval::new(1.0, 2.5);
+/// assert_eq!( format!("{x}"), "[1, 2.5]" );
+/// ```
+impl<B> std::fmt::Display for Interval<B>
+where
+    B : std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "[{}, {}]", self.lower, self.upper)
+    }
+}
+// ---------------------------------------------------------------------------
+/// Hash function for Interval objects
+///
+/// * B : is the floating point base type; i.e., f32 or f64 .
+///
+/// # Example
+/// ```
+/// use rustc_hash::FxHashMap;
+/// use rustad::Interval;
+/// let mut map : FxHashMap<Interval<f64>, u32> = FxHashMap::default();
+/// let x1      = Interval::new(1.0, 2.0);
+/// let x2      = Interval::new(1.0, 3.0);
+/// map.insert(x1, 1u32);
+///
+/// let option  = map.get_key_value(&x1);
+/// assert_eq!(option, Some( (&x1, &1u32) ) );
+///
+/// let option  = map.get_key_value(&x2);
+/// assert_eq!(option, None );
+/// ```
+impl<B> std::hash::Hash for Interval<B>
+where
+    B          : Copy ,
+    AzFloat<B> : std::hash::Hash,
+{
+    fn hash<H : std::hash::Hasher>(&self, state : &mut H) {
+        AzFloat(self.lower).hash(state);
+        AzFloat(self.upper).hash(state);
+    }
+}
+// ---------------------------------------------------------------------------
+// FConst
+/// Implements the FConst trait for Interval types;
+/// pi is the smallest interval that contains pi
+/// and the other constants contain one value.
+macro_rules! impl_interval_const{ ($B:ident) => {
+    impl FConst for Interval<$B> {
+        fn pi()           -> Interval<$B> {
+            let pi = std::$B::consts::PI;
+            Interval::new( pi.next_down(), pi.next_up() )
+        }
+        fn nan()          -> Interval<$B> { Self::from( $B::NAN ) }
+        fn one()          -> Interval<$B> { Self::from( 1.0 as $B ) }
+        fn zero()         -> Interval<$B> { Self::from( 0.0 as $B ) }
+        fn epsilon()      -> Interval<$B> { Self::from( $B::EPSILON ) }
+        fn min_positive() -> Interval<$B> { Self::from( $B::MIN_POSITIVE ) }
+        const ABSOLUTE_ZERO : bool = true;
+    }
+} }
+impl_interval_const!(f32);
+impl_interval_const!(f64);
+// ----------------------------------------------------------------------------
+// doc_f_unary_interval
+/// Interval unary functions
+///
+/// * Syntax : `y = x.Name()`
+///
+/// * B : is the floating point base type; i.e., f32 or f64
+///
+/// * Name : is the name of one of the [FUnary] functions.
+///
+/// * x : is an `Interval<B>` or `&Interval<B>` object.
+///
+/// * y : is the `Interval<B>` result.
+///   It contains the function value for every point in x .
+///   It is nan if x is not contained in the domain of the function.
+///
+/// * signum, ceil, floor, round, trunc :
+///   These results contain the corresponding [AzFloat] function values;
+///   e.g., signum(0) is one.
+///
+/// # Example
+/// ```
+/// use rustad::{
+///     Interval,
+///     FUnary,
+///     FValue,
+/// };
+/// type V = Interval<f64>;
+/// //
+/// let x   = V::new(0.0, 7.0);
+/// let y   = x.sin();
+/// assert_eq!( y, V::new(-1.0, 1.0) );
+/// //
+/// let y   = FUnary::sqrt( &V::new(4.0, 9.0) );
+/// assert_eq!( y, V::new(2.0, 3.0) );
+/// //
+/// let y   = V::new(-2.0, 1.0).square();
+/// assert_eq!( y, V::new(0.0, 4.0) );
+/// //
+/// let y   = V::new(-1.0, 1.0).ln();
+/// assert!( y.is_nan() );
+/// ```
+pub fn doc_f_unary_interval() {}
+//
+macro_rules! interval_unary_function{ ($B:ident, $name:ident) => {
+    #[doc = "see [doc_f_unary_interval]" ]
+    fn $name(self) -> Interval<$B> { (*self).$name() }
+} }
+macro_rules! impl_interval_unary{ ($B:ident) => {
+    impl FUnary for Interval<$B> {
+        type Output = Interval<$B>;
+        // BEGIN_SORT_THIS_LINE_PLUS_1
+        fn abs(self) -> Self {
+            if self.is_nan() || 0.0 <= self.lower {
+                self
+            } else if self.upper <= 0.0 {
+                Self::new( - self.upper, - self.lower )
+            } else {
+                Self::new( 0.0, self.upper.max( - self.lower ) )
+            }
+        }
+        fn acos(self) -> Self {
+            if ! self.in_domain(-1.0, 1.0) {
+                return Self::nan();
+            }
+            self.decreasing( $B::acos ).clamp(0.0, $B::INFINITY)
+        }
+        fn acosh(self) -> Self {
+            if ! self.in_domain(1.0, $B::INFINITY) {
+                return Self::nan();
+            }
+            self.increasing( $B::acosh ).clamp(0.0, $B::INFINITY)
+        }
+        fn asin(self) -> Self {
+            if ! self.in_domain(-1.0, 1.0) {
+                return Self::nan();
+            }
+            self.increasing( $B::asin )
+        }
+        fn asinh(self) -> Self {
+            self.increasing( $B::asinh )
+        }
+        fn atan(self) -> Self {
+            self.increasing( $B::atan )
+        }
+        fn atanh(self) -> Self {
+            if ! self.in_domain(-1.0, 1.0) {
+                return Self::nan();
+            }
+            self.increasing( $B::atanh )
+        }
+        fn cbrt(self) -> Self {
+            self.increasing( $B::cbrt )
+        }
+        fn ceil(self) -> Self {
+            Self::checked( self.lower.ceil(), self.upper.ceil() )
+        }
+        fn cos(self) -> Self {
+            if self.is_nan() {
+                return self;
+            }
+            let two_pi    = 2.0 * std::$B::consts::PI;
+            let cos_lower = self.lower.cos();
+            let cos_upper = self.upper.cos();
+            let mut lower = Self::lib_down( cos_lower.min(cos_upper) );
+            let mut upper = Self::lib_up( cos_lower.max(cos_upper) );
+            if self.may_contain(two_pi, 0.0) {
+                upper = 1.0;
+            }
+            if self.may_contain(two_pi, 0.5) {
+                lower = -1.0;
+            }
+            Self::new(lower, upper).clamp(-1.0, 1.0)
+        }
+        fn cosh(self) -> Self {
+            self.abs().increasing( $B::cosh ).clamp(1.0, $B::INFINITY)
+        }
+        fn erf(self) -> Self {
+            let (lower, _) = Self::erf_bounds(self.lower);
+            let (_, upper) = Self::erf_bounds(self.upper);
+            Self::checked(lower, upper)
+        }
+        fn erfc(self) -> Self {
+            let (lower, _) = Self::erfc_bounds(self.upper);
+            let (_, upper) = Self::erfc_bounds(self.lower);
+            Self::checked(lower, upper)
+        }
+        fn exp(self) -> Self {
+            self.increasing( $B::exp ).clamp(0.0, $B::INFINITY)
+        }
+        fn exp2(self) -> Self {
+            self.increasing( $B::exp2 ).clamp(0.0, $B::INFINITY)
+        }
+        fn exp_m1(self) -> Self {
+            self.increasing( $B::exp_m1 ).clamp(-1.0, $B::INFINITY)
+        }
+        fn floor(self) -> Self {
+            Self::checked( self.lower.floor(), self.upper.floor() )
+        }
+        fn ln(self) -> Self {
+            if ! self.in_domain(0.0, $B::INFINITY) {
+                return Self::nan();
+            }
+            self.increasing( $B::ln )
+        }
+        fn ln_1p(self) -> Self {
+            if ! self.in_domain(-1.0, $B::INFINITY) {
+                return Self::nan();
+            }
+            self.increasing( $B::ln_1p )
+        }
+        fn log10(self) -> Self {
+            if ! self.in_domain(0.0, $B::INFINITY) {
+                return Self::nan();
+            }
+            self.increasing( $B::log10 )
+        }
+        fn log2(self) -> Self {
+            if ! self.in_domain(0.0, $B::INFINITY) {
+                return Self::nan();
+            }
+            self.increasing( $B::log2 )
+        }
+        fn minus(self) -> Self {
+            Self::new( - self.upper, - self.lower )
+        }
+        fn round(self) -> Self {
+            Self::checked( self.lower.round(), self.upper.round() )
+        }
+        fn signum(self) -> Self {
+            Self::checked( self.lower.signum(), self.upper.signum() )
+        }
+        fn sin(self) -> Self {
+            if self.is_nan() {
+                return self;
+            }
+            let two_pi    = 2.0 * std::$B::consts::PI;
+            let sin_lower = self.lower.sin();
+            let sin_upper = self.upper.sin();
+            let mut lower = Self::lib_down( sin_lower.min(sin_upper) );
+            let mut upper = Self::lib_up( sin_lower.max(sin_upper) );
+            if self.may_contain(two_pi, 0.25) {
+                upper = 1.0;
+            }
+            if self.may_contain(two_pi, 0.75) {
+                lower = -1.0;
+            }
+            Self::new(lower, upper).clamp(-1.0, 1.0)
+        }
+        fn sinh(self) -> Self {
+            self.increasing( $B::sinh )
+        }
+        fn sqrt(self) -> Self {
+            if ! self.in_domain(0.0, $B::INFINITY) {
+                return Self::nan();
+            }
+            let lower = self.lower.sqrt();
+            let upper = self.upper.sqrt();
+            Self::new(
+                Self::down( lower, Self::sqrt_err(self.lower, lower) ),
+                Self::up( upper, Self::sqrt_err(self.upper, upper) ),
+            ).clamp(0.0, $B::INFINITY)
+        }
+        fn square(self) -> Self {
+            let a = self.abs();
+            Self::checked(
+                Self::mul_down(a.lower, a.lower), Self::mul_up(a.upper, a.upper)
+            )
+        }
+        fn tan(self) -> Self {
+            if self.is_nan() {
+                return self;
+            }
+            if self.may_contain(std::$B::consts::PI, 0.5) {
+                return Self::entire();
+            }
+            self.increasing( $B::tan )
+        }
+        fn tanh(self) -> Self {
+            self.increasing( $B::tanh ).clamp(-1.0, 1.0)
+        }
+        fn trunc(self) -> Self {
+            Self::checked( self.lower.trunc(), self.upper.trunc() )
+        }
+        // END_SORT_THIS_LINE_MINUS_1
+        //
+        // binary functions, but it only has one interval
+        fn powi(self, rhs : i32) -> Self {
+            if rhs == 0 {
+                return Self::one();
+            }
+            if self.is_nan() {
+                return self;
+            }
+            let n     = rhs.unsigned_abs();
+            let power = if n % 2 == 0 {
+                let a = self.abs();
+                Self::new( Self::pow_down(a.lower, n), Self::pow_up(a.upper, n) )
+            } else {
+                let lower = if 0.0 <= self.lower {
+                    Self::pow_down(self.lower, n)
+                } else {
+                    - Self::pow_up( - self.lower, n)
+                };
+                let upper = if 0.0 <= self.upper {
+                    Self::pow_up(self.upper, n)
+                } else {
+                    - Self::pow_down( - self.upper, n)
+                };
+                Self::new(lower, upper)
+            };
+            if rhs < 0 {
+                Self::one() / power
+            } else {
+                power
+            }
+        }
+    }
+    impl FUnary for &Interval<$B> {
+        type Output = Interval<$B>;
+        //
+        // use interval_unary_function
+        interval_unary_function!($B, abs);
+        interval_unary_function!($B, acos);
+        interval_unary_function!($B, acosh);
+        interval_unary_function!($B, asin);
+        interval_unary_function!($B, asinh);
+        interval_unary_function!($B, atan);
+        interval_unary_function!($B, atanh);
+        interval_unary_function!($B, cbrt);
+        interval_unary_function!($B, ceil);
+        interval_unary_function!($B, cos);
+        interval_unary_function!($B, cosh);
+        interval_unary_function!($B, erf);
+        interval_unary_function!($B, erfc);
+        interval_unary_function!($B, exp);
+        interval_unary_function!($B, exp2);
+        interval_unary_function!($B, exp_m1);
+        interval_unary_function!($B, floor);
+        interval_unary_function!($B, ln);
+        interval_unary_function!($B, ln_1p);
+        interval_unary_function!($B, log10);
+        interval_unary_function!($B, log2);
+        interval_unary_function!($B, minus);
+        interval_unary_function!($B, round);
+        interval_unary_function!($B, signum);
+        interval_unary_function!($B, sin);
+        interval_unary_function!($B, sinh);
+        interval_unary_function!($B, sqrt);
+        interval_unary_function!($B, square);
+        interval_unary_function!($B, tan);
+        interval_unary_function!($B, tanh);
+        interval_unary_function!($B, trunc);
+        //
+        #[doc = "see [doc_f_unary_interval]" ]
+        fn powi(self, rhs : i32) -> Interval<$B> {
+            (*self).powi(rhs)
+        }
+    }
+} }
+impl_interval_unary!(f32);
+impl_interval_unary!(f64);
+// ---------------------------------------------------------------------------
+// FBinary for Interval
+/// Implement [FBinary] when both operands are `Interval<B>`
+/// or `&Interval<B>` .
+///
+/// * B : is the floating point base type; i.e., f32 or f64
+///
+/// * Comparisons :
+///   num_lt, num_le, num_eq, num_ne, num_ge, num_gt
+///   return [1, 1] ( [0, 0] ) if the comparison is true (false) for
+///   every choice of values in the operands. Otherwise they return [0, 1] .
+///
+/// * atan2 :
+///   If the left operand y is not strictly positive or strictly negative,
+///   and the right operand x is not strictly positive,
+///   the result is the smallest interval containing [-pi, pi] .
+///
+/// * powf :
+///   If the left operand x is not negative, powf(x, y) is exp( y * ln(x) ) .
+///   Otherwise, if y is an integer, it is powi(x, y) .
+///   Otherwise, the result is nan.
+///
+/// * hypot, min, max :
+///   These results contain the function value for every
+///   choice of values in the operands.
+///
+/// # Example
+/// ```
+/// use rustad::{
+///     Interval,
+///     FBinary,
+/// };
+/// type V     = Interval<f64>;
+/// //
+/// let x      = V::new(1.0, 2.0);
+/// let y      = V::new(3.0, 4.0);
+/// assert_eq!( x.num_lt(y), V::from(1.0) );
+/// assert_eq!( y.num_lt(x), V::from(0.0) );
+/// assert_eq!( x.num_lt( V::new(0.0, 3.0) ), V::new(0.0, 1.0) );
+/// assert_eq!( x.max( V::new(0.0, 3.0) ), V::new(1.0, 3.0) );
+/// assert_eq!( V::new(-2.0, 1.0).powf( V::from(3.0) ), V::new(-8.0, 1.0) );
+/// ```
+pub fn doc_f_binary_interval() {}
+//
+macro_rules! impl_interval_binary{ ($B:ident) => {
+    impl Interval<$B> {
+        //
+        // compare
+        // result for a comparison that is true everywhere (always) or
+        // false everywhere (never) .
+        fn compare(lhs : &Self, rhs : &Self, always : bool, never : bool) -> Self {
+            if lhs.is_nan() || rhs.is_nan() {
+                Self::nan()
+            } else if always {
+                Self::one()
+            } else if never {
+                Self::zero()
+            } else {
+                Self::new(0.0, 1.0)
+            }
+        }
+    }
+    impl FBinary< &Interval<$B> > for &Interval<$B>
+    {
+        type Output = Interval<$B>;
+        //
+        fn num_lt(self, rhs : &Interval<$B>) -> Self::Output {
+            let always = self.upper < rhs.lower;
+            let never  = rhs.upper <= self.lower;
+            Interval::<$B>::compare(self, rhs, always, never)
+        }
+        fn num_le(self, rhs : &Interval<$B>) -> Self::Output {
+            let always = self.upper <= rhs.lower;
+            let never  = rhs.upper < self.lower;
+            Interval::<$B>::compare(self, rhs, always, never)
+        }
+        fn num_eq(self, rhs : &Interval<$B>) -> Self::Output {
+            let always = self.lower == self.upper &&
+                rhs.lower == rhs.upper && self.lower == rhs.lower;
+            let never  = self.upper < rhs.lower || rhs.upper < self.lower;
+            Interval::<$B>::compare(self, rhs, always, never)
+        }
+        fn num_ne(self, rhs : &Interval<$B>) -> Self::Output {
+            let always = self.upper < rhs.lower || rhs.upper < self.lower;
+            let never  = self.lower == self.upper &&
+                rhs.lower == rhs.upper && self.lower == rhs.lower;
+            Interval::<$B>::compare(self, rhs, always, never)
+        }
+        fn num_ge(self, rhs : &Interval<$B>) -> Self::Output {
+            rhs.num_le(self)
+        }
+        fn num_gt(self, rhs : &Interval<$B>) -> Self::Output {
+            rhs.num_lt(self)
+        }
+        fn atan2(self, rhs : &Interval<$B>) -> Self::Output {
+            // self = y, rhs = x
+            if self.is_nan() || rhs.is_nan() {
+                return Interval::<$B>::nan();
+            }
+            let pi     = Interval::<$B>::pi();
+            let pi_2   = &pi / &Interval::<$B>::from(2.0);
+            if 0.0 < rhs.lower {
+                ( self / rhs ).atan()
+            } else if 0.0 < self.lower {
+                &pi_2 - &( rhs / self ).atan()
+            } else if self.upper < 0.0 {
+                &pi_2.minus() - &( rhs / self ).atan()
+            } else {
+                Interval::new( - pi.upper, pi.upper )
+            }
+        }
+        fn hypot(self, rhs : &Interval<$B>) -> Self::Output {
+            ( self.square() + rhs.square() ).sqrt()
+        }
+        fn powf(self, rhs : &Interval<$B>) -> Self::Output {
+            if 0.0 <= self.lower {
+                return ( rhs * &self.ln() ).exp();
+            }
+            let y = rhs.lower;
+            let is_int = y == rhs.upper && y == y.trunc() &&
+                y.abs() <= (i32::MAX as $B);
+            if is_int {
+                self.powi( y as i32 )
+            } else {
+                Interval::<$B>::nan()
+            }
+        }
+        fn min(self, rhs : &Interval<$B>) -> Self::Output {
+            if self.is_nan() || rhs.is_nan() {
+                return Interval::<$B>::nan();
+            }
+            Interval::new(
+                self.lower.min(rhs.lower), self.upper.min(rhs.upper)
+            )
+        }
+        fn max(self, rhs : &Interval<$B>) -> Self::Output {
+            if self.is_nan() || rhs.is_nan() {
+                return Interval::<$B>::nan();
+            }
+            Interval::new(
+                self.lower.max(rhs.lower), self.upper.max(rhs.upper)
+            )
+        }
+    }
+} }
+impl_interval_binary!(f32);
+impl_interval_binary!(f64);
+//
+/// see [doc_f_binary_interval]
+macro_rules! impl_f_binary_function_own{ ($name:ident) => {
+    #[doc = concat!( " Interval::", stringify!($name)  ) ]
+    fn $name(self : Interval<B>, rhs : Interval<B>) -> Interval<B> {
+        FBinary::$name( &self,  &rhs )
+    }
+} }
+//
+impl<B> FBinary< Interval<B> > for Interval<B>
+where
+    for<'a> &'a Interval<B> : FBinary< &'a Interval<B>, Output = Interval<B> >,
+{
+    type Output = Interval<B>;
+    //
+    impl_f_binary_function_own!( num_lt );
+    impl_f_binary_function_own!( num_le );
+    impl_f_binary_function_own!( num_eq );
+    impl_f_binary_function_own!( num_ne );
+    impl_f_binary_function_own!( num_ge );
+    impl_f_binary_function_own!( num_gt );
+    impl_f_binary_function_own!( atan2 );
+    impl_f_binary_function_own!( hypot );
+    impl_f_binary_function_own!( powf );
+    impl_f_binary_function_own!( min );
+    impl_f_binary_function_own!( max );
+}
+// ----------------------------------------------------------------------------
+// FReduce
+/// Implement [FReduce] for `&Interval<B>` .
+///
+/// * B : is the floating point base type; i.e., f32 or f64
+///
+/// An interval is one element (not a vector of elements);
+/// e.g., its reduce_max is the interval itself.
+impl<B> FReduce for &Interval<B>
+where
+    B           : Copy,
+    Interval<B> : FConst,
+    for<'a> &'a Interval<B> : Mul<&'a Interval<B>, Output = Interval<B> >,
+{
+    type Output = Interval<B>;
+    //
+    fn reduce_sum(self)  -> Interval<B> { *self }
+    fn reduce_mean(self) -> Interval<B> { *self }
+    fn reduce_max(self)  -> Interval<B> { *self }
+    fn reduce_len(self)  -> Interval<B> { Interval::one() }
+    fn reduce_dot(self, rhs : Self) -> Interval<B> { self * rhs }
+}
+// ----------------------------------------------------------------------------
+// FValue
+// doc_f_value_interval
+/// [FValue] for Interval.
+///
+/// * B : is the floating point base type; i.e., f32 or f64
+///
+/// * is_zero, is_one :
+///   both bounds are zero, one.
+///
+/// * is_nan :
+///   the lower bound or the upper bound is nan.
+///
+/// # Example
+/// ```
+/// use rustad::{
+///     Interval,
+///     FConst,
+///     FValue,
+/// };
+/// type V = Interval<f64>;
+/// //
+/// let one = V::one();
+/// assert!( one.is_one() );
+/// assert!( ! one.is_zero() );
+/// assert!( ! V::new(0.0, 1.0).is_one() );
+/// assert_eq!( one.to_src(), "Interval::new(1 as f64, 1 as f64)" );
+/// ```
+pub fn doc_f_value_interval() {}
+//
+macro_rules! impl_f_value{ ($B:ident) => {
+    impl Interval<$B> {
+        //
+        // bound_src
+        // source code for one of the bounds
+        fn bound_src(x : $B) -> String {
+            if x.is_nan() {
+                "f32::NAN as ".to_string() + stringify!($B)
+            } else if x == $B::INFINITY {
+                stringify!($B).to_string() + "::INFINITY"
+            } else if x == $B::NEG_INFINITY {
+                stringify!($B).to_string() + "::NEG_INFINITY"
+            } else {
+                x.to_string() + " as " + stringify!($B)
+            }
+        }
+    }
+    impl FValue for Interval<$B> {
+        fn is_zero(&self) -> bool { self.lower == 0.0 && self.upper == 0.0 }
+        fn is_one(&self)  -> bool { self.lower == 1.0 && self.upper == 1.0 }
+        fn is_nan(&self)  -> bool {
+            self.lower.is_nan() || self.upper.is_nan()
+        }
+        fn to_src(&self)  -> String {
+            "Interval::new(".to_string() +
+                &Self::bound_src(self.lower) + ", " +
+                &Self::bound_src(self.upper) +
+            ")"
+        }
+    }
+} }
+impl_f_value!(f32);
+impl_f_value!(f64);
+// ----------------------------------------------------------------------------
+// FBytes
+// doc_f_bytes_interval
+/// [FBytes] for Interval.
+///
+/// * B : is the floating point base type; i.e., f32 or f64
+///
+/// * type_name : is `Interval<f32>` or `Interval<f64>` .
+///
+/// * bytes :
+///   is the little endian representation of the lower bound followed by
+///   the little endian representation of the upper bound.
+///
+/// # Example
+/// ```
+/// use rustad::{
+///     Interval,
+///     FBytes,
+/// };
+/// type V = Interval<f64>;
+/// //
+/// let x         = V::new(1.0, 2.0);
+/// let mut bytes = Vec::new();
+/// x.to_bytes(&mut bytes);
+/// assert_eq!( bytes.len(), 2 * 8 );
+/// //
+/// let mut start = 0;
+/// let y         = V::from_bytes(&bytes, &mut start).unwrap();
+/// assert_eq!( x, y );
+/// assert_eq!( V::type_name(), "Interval<f64>" );
+/// ```
+pub fn doc_f_bytes_interval() {}
+//
+macro_rules! impl_f_bytes{ ($B:ident) => {
+    impl FBytes for Interval<$B> {
+        fn type_name() -> String {
+            "Interval<".to_string() + stringify!($B) + ">"
+        }
+        fn to_bytes(&self, bytes : &mut Vec<u8>) {
+            AzFloat(self.lower).to_bytes(bytes);
+            AzFloat(self.upper).to_bytes(bytes);
+        }
+        fn from_bytes(bytes : &[u8], start : &mut usize) -> Result<Self, String> {
+            let lower = AzFloat::<$B>::from_bytes(bytes, start)?;
+            let upper = AzFloat::<$B>::from_bytes(bytes, start)?;
+            Ok( Interval::new(lower.0, upper.0) )
+        }
+    }
+} }
+impl_f_bytes!(f32);
+impl_f_bytes!(f64);
+// ----------------------------------------------------------------------------
+// FText
+// doc_f_text_interval
+/// [FText] for Interval.
+///
+/// * B : is the floating point base type; i.e., f32 or f64
+///
+/// * text :
+///   is the [AzFloat] text representation of the lower and upper bounds,
+///   separated by a comma and enclosed in square brackets.
+///
+/// # Example
+/// ```
+/// use rustad::{
+///     Interval,
+///     FText,
+/// };
+/// type V = Interval<f64>;
+/// //
+/// let x    = V::new(-1.0, 2.5);
+/// let text = x.to_text();
+/// assert_eq!( text, "[ -1.0, 2.5 ]" );
+/// assert_eq!( V::from_text(&text), Ok(x) );
+/// ```
+pub fn doc_f_text_interval() {}
+impl<B> FText for Interval<B>
+where
+    B          : Copy ,
+    AzFloat<B> : FText ,
+{
+    fn to_text(&self) -> String {
+        "[ ".to_string() +
+            &AzFloat(self.lower).to_text() + ", " +
+            &AzFloat(self.upper).to_text() +
+        " ]"
+    }
+    fn from_text(text : &str) -> Result<Self, String> {
+        let inner = text.trim().strip_prefix('[').and_then(
+            |rest| rest.strip_suffix(']')
+        );
+        let parts : Vec<&str> = match inner {
+            Some(inner) => inner.split(',').collect(),
+            None        => Vec::new(),
+        };
+        if parts.len() != 2 {
+            return Err( format!(
+                "Interval::from_text: expected [ lower, upper ]: {text}"
+            ) );
+        }
+        let lower = AzFloat::<B>::from_text( parts[0] )?;
+        let upper = AzFloat::<B>::from_text( parts[1] )?;
+        Ok( Interval::new(lower.0, upper.0) )
+    }
+}
@@ -17,3 +17,8 @@
 pub mod traits;
 pub mod az_float;
 pub mod num_vec;
+pub mod complex;
+pub mod interval;
+pub mod primitive;
+pub mod double_double;
+pub mod erf;
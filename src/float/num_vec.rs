@@ -19,9 +19,29 @@ use crate::{
     FConst,
     FUnary,
     FBinary,
+    FReduce,
     FValue,
+    FBytes,
+    FText,
 };
 //
+// LANES
+/// Number of elements in each of the aligned chunks that store a `NumVec` .
+///
+/// The element-wise loops over a chunk have this fixed length,
+/// so the compiler can vectorize them (on stable rust) when the
+/// element operations are inlined; e.g., for `AzFloat<f32>` and
+/// `AzFloat<f64>` arithmetic.
+pub const LANES : usize = 8;
+//
+// Chunk
+// A block of LANES elements aligned to 32 bytes. For f32 and f64 elements
+// the size of a chunk is a multiple of its alignment, so the elements in
+// a vector of chunks are contiguous.
+#[derive(Debug, Clone, Copy)]
+#[repr(C, align(32))]
+struct Chunk<S>( [S; LANES] );
+//
 // NumVec
 /// The numeric vector class.
 ///
@@ -36,12 +56,20 @@ use crate::{
 /// * Copy, Clone :
 ///   The NumVec types implement Clone, but not the Copy trait.
 ///
-#[derive(Debug,Clone)]
+/// * Storage :
+///   The elements of a vector are stored in aligned chunks of [LANES]
+///   elements. The last chunk is padded with copies of the last element,
+///   so the padding stays in the domain of the functions that are
+///   applied to the vector. The padding is not part of the vector.
+///
+#[derive(Clone)]
 pub struct NumVec<S> {
-    /// The elements of this numeric vector
-    vec : Vec<S> ,
+    /// The elements of this numeric vector (empty if it has one element)
+    chunks : Vec< Chunk<S> > ,
+    /// Number of elements in this numeric vector (if chunks is not empty)
+    n_elem : usize ,
     /// Value if this vector has only one element
-    s   : S ,
+    s      : S ,
 }
 //
 // new
@@ -53,10 +81,15 @@ where
     pub fn new( v : Vec<S> ) -> NumVec<S> {
         assert_ne!( v.len(), 0);
         if v.len() == 1 {
-            Self { vec: Vec::new() , s : v[0]}
-        } else {
-            Self { vec: v , s : f32::NAN.into() }
+            return Self::scalar( v[0] );
+        }
+        let n_elem  = v.len();
+        let n_chunk = n_elem.div_ceil(LANES);
+        let mut chunks = vec![ Chunk( [ v[n_elem - 1]; LANES ] ); n_chunk ];
+        for (j, s) in v.into_iter().enumerate() {
+            chunks[j / LANES].0[j % LANES] = s;
         }
+        Self { chunks, n_elem, s : f32::NAN.into() }
     }
 }
 //
@@ -65,10 +98,10 @@ impl<S> NumVec<S>
 {   //
     /// Length of this numeric vector
     pub fn len(self : &NumVec<S> ) -> usize {
-        if self.vec.is_empty() {
+        if self.chunks.is_empty() {
             1
         } else {
-            self.vec.len()
+            self.n_elem
         }
     }
 }
@@ -84,7 +117,140 @@ where
             debug_assert!( index == 0);
             self.s
         } else {
-            self.vec[index]
+            assert!( index < self.n_elem );
+            self.chunks[index / LANES].0[index % LANES]
+        }
+    }
+}
+// ---------------------------------------------------------------------------
+// Element-wise kernels
+impl<S> NumVec<S>
+where
+    S : Copy ,
+{   //
+    // scalar
+    // numeric vector with one element
+    fn scalar(s : S) -> NumVec<S> {
+        NumVec { chunks : Vec::new(), n_elem : 1, s }
+    }
+    //
+    // map_unary
+    // z[j] = f( x[j] ) where x is self.
+    // If z is not a scalar, its scalar value is nan.
+    #[inline]
+    fn map_unary<F>(&self, nan : S, f : F) -> NumVec<S>
+    where
+        F : Fn(S) -> S ,
+    {   if self.len() == 1 {
+            return Self::scalar( f(self.s) );
+        }
+        let chunks = self.chunks.iter().map( |x| {
+            let mut z = *x;
+            for k in 0 .. LANES {
+                z.0[k] = f( x.0[k] );
+            }
+            z
+        } ).collect();
+        NumVec { chunks, n_elem : self.n_elem, s : nan }
+    }
+    //
+    // map_binary
+    // z[j] = f( x[j], y[j] ) where x is self and y is rhs.
+    // A scalar operand is broadcast to a full chunk.
+    // If z is not a scalar, its scalar value is nan.
+    #[inline]
+    fn map_binary<F>(&self, rhs : &NumVec<S>, nan : S, f : F) -> NumVec<S>
+    where
+        F : Fn(S, S) -> S ,
+    {   let lanes = |x : &Chunk<S>, y : &Chunk<S>| {
+            let mut z = *x;
+            for k in 0 .. LANES {
+                z.0[k] = f( x.0[k], y.0[k] );
+            }
+            z
+        };
+        let (chunks, n_elem) = if self.len() == 1 {
+            if rhs.len() == 1 {
+                return Self::scalar( f(self.s, rhs.s) );
+            }
+            let x = Chunk( [self.s; LANES] );
+            let chunks = rhs.chunks.iter().map( |y| lanes(&x, y) ).collect();
+            (chunks, rhs.n_elem)
+        } else if rhs.len() == 1 {
+            let y = Chunk( [rhs.s; LANES] );
+            let chunks = self.chunks.iter().map( |x| lanes(x, &y) ).collect();
+            (chunks, self.n_elem)
+        } else {
+            assert_eq!( self.len(), rhs.len() );
+            let chunks = self.chunks.iter().zip( rhs.chunks.iter() ).map(
+                |(x, y)| lanes(x, y)
+            ).collect();
+            (chunks, self.n_elem)
+        };
+        NumVec { chunks, n_elem, s : nan }
+    }
+    //
+    // fold
+    // f( ... f( f(x[0], x[1]), x[2] ) ... , x[n-1] ) where x is self.
+    // The full chunks are folded lane by lane and then the lanes are
+    // folded; i.e., the order of the operations is not sequential.
+    #[inline]
+    fn fold<F>(&self, f : F) -> S
+    where
+        F : Fn(S, S) -> S ,
+    {   if self.len() == 1 {
+            return self.s;
+        }
+        let n_full = self.n_elem / LANES;
+        let n_rest = self.n_elem % LANES;
+        if n_full == 0 {
+            let x = &self.chunks[0].0;
+            return x[1 .. n_rest].iter().fold( x[0], |a, b| f(a, *b) );
+        }
+        let mut acc = self.chunks[0];
+        for x in &self.chunks[1 .. n_full] {
+            for k in 0 .. LANES {
+                acc.0[k] = f( acc.0[k], x.0[k] );
+            }
+        }
+        if n_rest > 0 {
+            let x = &self.chunks[n_full];
+            for k in 0 .. n_rest {
+                acc.0[k] = f( acc.0[k], x.0[k] );
+            }
+        }
+        acc.0[1 ..].iter().fold( acc.0[0], |a, b| f(a, *b) )
+    }
+    //
+    // map_assign
+    // f( &mut x[j], &y[j] ) where x is self and y is rhs.
+    // If self is a scalar and rhs is not, self is first broadcast to
+    // the length of rhs.
+    #[inline]
+    fn map_assign<'a, F>(&mut self, rhs : &'a NumVec<S>, f : F)
+    where
+        F : Fn(&mut S, &'a S) ,
+    {   if self.len() == 1 {
+            if rhs.len() == 1 {
+                f( &mut self.s, &rhs.s );
+                return;
+            }
+            self.chunks = vec![ Chunk( [self.s; LANES] ); rhs.chunks.len() ];
+            self.n_elem = rhs.n_elem;
+        }
+        if rhs.len() == 1 {
+            for x in self.chunks.iter_mut() {
+                for k in 0 .. LANES {
+                    f( &mut x.0[k], &rhs.s );
+                }
+            }
+        } else {
+            assert_eq!( self.len(), rhs.len() );
+            for (x, y) in self.chunks.iter_mut().zip( rhs.chunks.iter() ) {
+                for k in 0 .. LANES {
+                    f( &mut x.0[k], &y.0[k] );
+                }
+            }
         }
     }
 }
@@ -135,34 +301,7 @@ macro_rules! num_vec_binary_op { ($Name:ident, $name:ident, $Op:tt) => {
         //
         fn $name(self : &'a NumVec<S>, rhs : &'a NumVec<S> )
         -> NumVec<S>
-        {   let mut v : Vec<S>;
-            let e     : S;
-            if self.len() == 1 {
-                if rhs.len() == 1 {
-                    e = self.s $Op rhs.s;
-                    v = Vec::new();
-                } else {
-                    e = f32::NAN.into();
-                    v = Vec::with_capacity( rhs.len() );
-                    for j in 0 .. rhs.len() { v.push(
-                        self.s $Op rhs.vec[j]
-                    ); }
-                }
-            } else {
-                e = f32::NAN.into();
-                v = Vec::with_capacity( self.len() );
-                if rhs.len() == 1 {
-                    for j in 0 .. self.len() { v.push(
-                        self.vec[j] $Op rhs.s
-                    ); }
-                } else {
-                    assert_eq!( self.len(), rhs.len() );
-                    for j in 0 .. self.len() { v.push(
-                        self.vec[j] $Op rhs.vec[j]
-                    ); }
-                }
-            }
-            NumVec{ vec : v, s : e }
+        {   self.map_binary( rhs, f32::NAN.into(), |x, y| x $Op y )
         }
     }
 } }
@@ -216,28 +355,7 @@ macro_rules! num_vec_compound_op { ($Name:ident, $name:ident, $Op:tt) => {
         S : Copy + std::ops::$Name<&'a S>,
     {   //
         fn $name(&mut self, rhs : &'a NumVec<S> )
-        {   //
-            if self.len() == 1 {
-                if rhs.len() == 1 {
-                    self.s $Op &(rhs.s);
-                } else {
-                    self.vec = vec![ self.s ; rhs.len() ];
-                    for j in 0 .. rhs.len() {
-                        self.vec[j] $Op &(rhs.vec[j]);
-                    }
-                }
-            } else {
-                if rhs.len() == 1 {
-                    for j in 0 .. self.len() {
-                        self.vec[j] $Op &(rhs.s);
-                    }
-                } else {
-                    assert_eq!( self.len(), rhs.len() );
-                    for j in 0 .. self.len() {
-                        self.vec[j] $Op &(rhs.vec[j]);
-                    }
-                }
-            }
+        {   self.map_assign( rhs, |x, y| *x $Op y )
         }
     }
 } }
@@ -270,13 +388,28 @@ impl<S : std::fmt::Display> std::fmt::Display for NumVec<S> {
             write!(f, "{}, ", self.s)?;
         } else {
             for j in 0 .. self.len() {
-                write!(f, "{}, ", self.vec[j])?;
+                write!(f, "{}, ", self.chunks[j / LANES].0[j % LANES])?;
             }
         }
         write!(f, "]")
     }
 }
 // ----------------------------------------------------------------------------`
+// Debug
+/// Debug output for a `NumVec` < *S* > object lists its elements
+/// (the padding in its last chunk is not included).
+impl<S : std::fmt::Debug> std::fmt::Debug for NumVec<S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        if self.len() == 1 {
+            return f.debug_tuple("NumVec").field( &[&self.s] ).finish();
+        }
+        let elements : Vec<&S> = (0 .. self.len()).map(
+            |j| &self.chunks[j / LANES].0[j % LANES]
+        ).collect();
+        f.debug_tuple("NumVec").field( &elements ).finish()
+    }
+}
+// ----------------------------------------------------------------------------`
 // From
 /// Convert a scalar to a NumVec object with one element.
 ///
@@ -322,7 +455,7 @@ macro_rules! impl_from_scalar { ($F:ty, $T:ty ) => {
     #[doc = "see [doc_from_scalar]"]
     impl From<$F> for NumVec<$T> {
         fn from( scalar : $F )-> NumVec<$T> {
-            NumVec { vec : Vec::new(), s : scalar.into() }
+            NumVec::scalar( scalar.into() )
         }
     }
 } }
@@ -379,7 +512,10 @@ where
         } else if self.len() == 1 {
             self.s == rhs.s
         } else {
-            self.vec == rhs.vec
+            (0 .. self.len()).all( |j|
+                self.chunks[j / LANES].0[j % LANES] ==
+                rhs.chunks[j / LANES].0[j % LANES]
+            )
         }
     }
 }
@@ -426,7 +562,10 @@ where
         if self.len() == 1 {
             self.s.hash(state);
         } else {
-            self.vec.hash(state);
+            self.len().hash(state);
+            for j in 0 .. self.len() {
+                self.chunks[j / LANES].0[j % LANES].hash(state);
+            }
         }
     }
  }
@@ -444,24 +583,20 @@ where
     fn zero()         -> NumVec<S> { Self::from( S::zero() ) }
     fn epsilon()      -> NumVec<S> { Self::from( S::epsilon() ) }
     fn min_positive() -> NumVec<S> { Self::from( S::min_positive() ) }
+    const ABSOLUTE_ZERO : bool = S::ABSOLUTE_ZERO;
 }
 // ---------------------------------------------------------------------------
 // FUnary
 macro_rules! float_unary_function{ ($name:ident) => {
     #[ doc = concat!( "`NumVec<S>.`", stringify!($name), "()" )]
     fn $name(self) -> NumVec<S> {
-        if self.len() == 1 {
-            NumVec { s : self.s.$name() , vec : Vec::new() }
-        } else {
-            let v = self.vec.iter().map( |s| s.$name() ).collect();
-            NumVec { s : f32::NAN.into() , vec : v }
-        }
+        self.map_unary( f32::NAN.into(), |x| (&x).$name() )
     }
 } }
 /// Implements the FUnary trait for &NumVec types
 impl<S> FUnary for &NumVec<S>
 where
-    S             : From<f32>,
+    S             : From<f32> + Copy,
     for<'a> &'a S : FUnary<Output=S>,
     NumVec<S>     : From<S>
 {
@@ -469,6 +604,14 @@ where
     //
     // use float_unary_function
     float_unary_function!(square);
+    float_unary_function!(erf);
+    float_unary_function!(erfc);
+    float_unary_function!(acos);
+    float_unary_function!(acosh);
+    float_unary_function!(asin);
+    float_unary_function!(asinh);
+    float_unary_function!(atan);
+    float_unary_function!(atanh);
     float_unary_function!(ln_1p);
     float_unary_function!(exp_m1);
     float_unary_function!(ln);
@@ -483,16 +626,19 @@ where
     float_unary_function!(minus);
     float_unary_function!(cos);
     float_unary_function!(sin);
+    float_unary_function!(cbrt);
+    float_unary_function!(exp2);
+    float_unary_function!(log10);
+    float_unary_function!(log2);
+    float_unary_function!(ceil);
+    float_unary_function!(floor);
+    float_unary_function!(round);
+    float_unary_function!(trunc);
     //
     // powi
     /// `NumVec<S>`.powi(`i32`)
     fn powi(self, rhs : i32) -> NumVec<S> {
-        if self.len() == 1 {
-            NumVec { s : self.s.powi(rhs) , vec : Vec::new() }
-        } else {
-            let v = self.vec.iter().map( |s| s.powi(rhs) ).collect();
-            NumVec { s : f32::NAN.into() , vec : v }
-        }
+        self.map_unary( f32::NAN.into(), |x| (&x).powi(rhs) )
     }
 }
 // ---------------------------------------------------------------------------
@@ -545,42 +691,13 @@ pub fn doc_f_binary_num_vec() {}
 macro_rules! impl_f_binary_num_vec_borrow{ ($name:ident) => {
     #[doc = concat!( "NumVec::", stringify!( $name ) ) ]
     fn $name(self, rhs : & NumVec<S> ) -> NumVec<S> {
-        //
-        let mut v : Vec<S>;
-        let e     : S;
-        //
-        if self.len() == 1 {
-            if rhs.len() == 1 {
-                e = self.s.$name( &rhs.s );
-                v = Vec::new();
-            } else {
-                e = FConst::nan();
-                v = Vec::with_capacity( rhs.len() );
-                for j in 0 .. rhs.len() { v.push(
-                    self.s.$name(&rhs.vec[j])
-                ); }
-            }
-        } else {
-            e = FConst::nan();
-            v = Vec::with_capacity( self.len() );
-            if rhs.len() == 1 {
-                for j in 0 .. self.len() { v.push(
-                    self.vec[j].$name(&rhs.s )
-                ); }
-            } else {
-                assert_eq!( self.len(), rhs.len() );
-                for j in 0 .. self.len() { v.push(
-                    self.vec[j].$name(& rhs.vec[j] )
-                ); }
-            }
-        }
-        NumVec{ vec : v, s : e }
+        self.map_binary( rhs, FConst::nan(), |x, y| (&x).$name(&y) )
     }
 } }
 //
 impl<S> FBinary< &NumVec<S> > for &NumVec<S>
 where
-    S              : FConst,
+    S              : FConst + Copy,
     for<'a> &'a S  : FBinary<&'a S, Output = S>,
 {
     type Output = NumVec<S>;
@@ -594,6 +711,8 @@ where
     impl_f_binary_num_vec_borrow!( atan2 );
     impl_f_binary_num_vec_borrow!( hypot );
     impl_f_binary_num_vec_borrow!( powf );
+    impl_f_binary_num_vec_borrow!( min );
+    impl_f_binary_num_vec_borrow!( max );
 }
 //
 /// see [doc_f_binary_num_vec]
@@ -621,6 +740,63 @@ where
     impl_f_binary_num_vec_own!( atan2 );
     impl_f_binary_num_vec_own!( hypot );
     impl_f_binary_num_vec_own!( powf );
+    impl_f_binary_num_vec_own!( min );
+    impl_f_binary_num_vec_own!( max );
+}
+// ---------------------------------------------------------------------------
+// FReduce
+/// Implement [FReduce] for `&NumVec<S>` .
+///
+/// * S : is the type of the elements of the numeric vector.
+///
+/// The result is a `NumVec<S>` with length one; i.e., a scalar.
+/// The reduce_max function ignores nan elements
+/// (the same as the max function for *S* ).
+///
+/// # Example
+/// ```
+/// use rustad::{
+///     AzFloat,
+///     NumVec,
+///     FReduce,
+/// };
+/// type S = AzFloat<f64>;
+/// let x    = NumVec::new( vec![ S::from(1), S::from(4), S::from(1) ] );
+/// let y    = NumVec::new( vec![ S::from(2), S::from(3), S::from(4) ] );
+/// let sum  = (&x).reduce_sum();
+/// assert_eq!( sum.len(), 1 );
+/// assert_eq!( sum.get(0), S::from(6) );
+/// assert_eq!( (&x).reduce_mean().get(0), S::from(2) );
+/// assert_eq!( (&x).reduce_max().get(0), S::from(4) );
+/// assert_eq!( (&x).reduce_len().get(0), S::from(3) );
+/// assert_eq!( (&x).reduce_dot(&y).get(0), S::from(18) );
+/// ```
+impl<S> FReduce for &NumVec<S>
+where
+    S             : FConst + From<f32> + Copy +
+                    std::ops::Add<Output = S> +
+                    std::ops::Mul<Output = S> +
+                    std::ops::Div<Output = S> ,
+    for<'a> &'a S : FBinary<&'a S, Output = S>,
+{
+    type Output = NumVec<S>;
+    //
+    fn reduce_sum(self) -> NumVec<S> {
+        NumVec::scalar( self.fold( |a, b| a + b ) )
+    }
+    fn reduce_mean(self) -> NumVec<S> {
+        let n = S::from( self.len() as f32 );
+        NumVec::scalar( self.fold( |a, b| a + b ) / n )
+    }
+    fn reduce_max(self) -> NumVec<S> {
+        NumVec::scalar( self.fold( |a, b| (&a).max(&b) ) )
+    }
+    fn reduce_len(self) -> NumVec<S> {
+        NumVec::scalar( S::from( self.len() as f32 ) )
+    }
+    fn reduce_dot(self, rhs : Self) -> NumVec<S> {
+        (self * rhs).reduce_sum()
+    }
 }
 // ---------------------------------------------------------------------------
 // FValue
@@ -655,7 +831,7 @@ where
 pub fn doc_f_value_az_float() {}
 impl<V> FValue for NumVec<V>
 where
-    V : FValue + Copy,
+    V : FValue + Copy + From<f32>,
 {
     // is_zero
     fn is_zero(&self)  -> bool {
@@ -681,6 +857,15 @@ where
         }
         all_nan
     }
+    // select
+    fn select(&self, if_true : &Self, if_false : &Self) -> Self {
+        let n    = self.len().max( if_true.len() ).max( if_false.len() );
+        let elem = |x : &Self, j : usize| x.get( if x.len() == 1 { 0 } else { j } );
+        let vec  = (0 .. n).map( |j|
+            if elem(self, j).is_one() { elem(if_true, j) } else { elem(if_false, j) }
+        ).collect();
+        NumVec::new(vec)
+    }
     // to_src
     fn to_src(&self) -> String {
         let mut src = "NumVec::new( vec![ ".to_string();
@@ -691,3 +876,136 @@ where
         src
     }
 }
+// ---------------------------------------------------------------------------
+// FBytes
+// doc_f_bytes_num_vec
+/// [FBytes] for NumVec.
+///
+/// * S : is the scalar type for this numeric vector.
+///
+/// * type_name : is `NumVec<` *name* `>` where *name* is the type name for S.
+///
+/// * bytes :
+///   is the length of the vector, as a little endian u64,
+///   followed by the binary representation of each of its elements.
+///
+/// # Example
+/// ```
+/// use rustad::{
+///     NumVec,
+///     AzFloat,
+///     FBytes,
+/// };
+/// type S = AzFloat<f64>;
+/// type V = NumVec<S>;
+/// //
+/// let x         = V::new( vec![ S::from(1.0), S::from(2.0) ] );
+/// let mut bytes = Vec::new();
+/// x.to_bytes(&mut bytes);
+/// assert_eq!( bytes.len(), 8 + 2 * 8 );
+/// //
+/// let mut start = 0;
+/// let y         = V::from_bytes(&bytes, &mut start).unwrap();
+/// assert_eq!( x, y );
+/// assert_eq!( V::type_name(), "NumVec<AzFloat<f64>>" );
+/// ```
+pub fn doc_f_bytes_num_vec() {}
+impl<S> FBytes for NumVec<S>
+where
+    S : FBytes + From<f32> + Copy,
+{
+    // type_name
+    fn type_name() -> String {
+        "NumVec<".to_string() + &S::type_name() + ">"
+    }
+    // to_bytes
+    fn to_bytes(&self, bytes : &mut Vec<u8>) {
+        let n = self.len() as u64;
+        bytes.extend_from_slice( &n.to_le_bytes() );
+        for i in 0 .. self.len() {
+            self.get(i).to_bytes(bytes);
+        }
+    }
+    // from_bytes
+    fn from_bytes(bytes : &[u8], start : &mut usize) -> Result<Self, String> {
+        let end = *start + 8;
+        if bytes.len() < end {
+            return Err(
+                "NumVec::from_bytes: unexpected end of bytes".to_string()
+            );
+        }
+        let mut array = [0u8; 8];
+        array.copy_from_slice( &bytes[*start .. end] );
+        *start = end;
+        let n  = u64::from_le_bytes(array) as usize;
+        if n == 0 {
+            return Err( "NumVec::from_bytes: vector has length zero".to_string() );
+        }
+        let mut vec : Vec<S> = Vec::new();
+        for _i in 0 .. n {
+            vec.push( S::from_bytes(bytes, start)? );
+        }
+        Ok( NumVec::new(vec) )
+    }
+}
+// ---------------------------------------------------------------------------
+// FText
+// doc_f_text_num_vec
+/// [FText] for NumVec.
+///
+/// * S : is the scalar type for this numeric vector.
+///
+/// * text :
+///   is the [FText] representation of the elements of the vector,
+///   separated by commas and enclosed in square brackets.
+///
+/// # Example
+/// ```
+/// use rustad::{
+///     NumVec,
+///     AzFloat,
+///     FText,
+/// };
+/// type S = AzFloat<f64>;
+/// type V = NumVec<S>;
+/// //
+/// let x    = V::new( vec![ S::from(1.0), S::from(2.5) ] );
+/// let text = x.to_text();
+/// assert_eq!( text, "[ 1.0, 2.5 ]" );
+/// assert_eq!( V::from_text(&text), Ok(x) );
+/// ```
+pub fn doc_f_text_num_vec() {}
+impl<S> FText for NumVec<S>
+where
+    S : FText + From<f32> + Copy,
+{
+    // to_text
+    fn to_text(&self) -> String {
+        let mut text = "[ ".to_string();
+        for i in 0 .. self.len() {
+            if i > 0 {
+                text += ", ";
+            }
+            text += &self.get(i).to_text();
+        }
+        text += " ]";
+        text
+    }
+    // from_text
+    fn from_text(text : &str) -> Result<Self, String> {
+        let inner = text.trim().strip_prefix('[').and_then(
+            |rest| rest.strip_suffix(']')
+        );
+        let inner = match inner {
+            Some(inner) => inner,
+            None        => return Err( format!(
+                "NumVec::from_text: missing square brackets: {text}"
+            ) ),
+        };
+        let mut vec : Vec<S> = Vec::new();
+        for element in inner.split(',') {
+            vec.push( S::from_text(element)? );
+        }
+        Ok( NumVec::new(vec) )
+    }
+}
@@ -0,0 +1,414 @@
+// ---------------------------------------------------------------------------
+// SPDX-License-Identifier: EPL-2.0 OR GPL-2.0-or-later
+// SPDX-FileCopyrightText: Bradley M. Bell <bradbell@seanet.com>
+// SPDX-FileContributor: 2026 Bradley M. Bell
+// ---------------------------------------------------------------------------
+//
+//! This pub module implements the rustad floating point traits for the
+//! primitive types f32 and f64.
+//!
+//! Link to [parent module](super)
+//!
+//! This module does not have dependencies outside standard rust and src/float.
+//! This enables src/float to be directly included as part of a Dll library.
+//!
+//! # IEEE Semantics
+//! These value types follow the IEEE floating point rules exactly;
+//! i.e., zero times nan is nan and nan is not equal to nan.
+//! This is different from [AzFloat](super::az_float::AzFloat) which has an
+//! absolute zero. Hence, for these value types:
+//!
+//! * Multiplications by the constant zero are recorded; i.e.,
+//!   [ABSOLUTE_ZERO](crate::FConst::ABSOLUTE_ZERO) is false.
+//! * A nan in a partial derivative of an operator may result in a nan
+//!   for a derivative that does not depend on that partial.
+//! * A nan (or infinity) in the branch that is not selected by a conditional
+//!   expression, a [VecAD](crate::VecAD) load, min, or max does not affect
+//!   its value or derivative, because these operators use [FValue::select]
+//!   instead of multiplying the branch by zero;
+//!   see [AD::select](crate::AD::select) .
+//!   (In reverse mode, the operators that computed the unselected branch
+//!   still multiply the zero partial they receive by their own partials.)
+//!
+//! # Example
+//! ```
+//! use rustad::{
+//!     FConst,
+//!     FValue,
+//!     start_recording,
+//!     stop_recording,
+//! };
+//! let opt_vec : Vec<[&str; 2]> = Vec::new();
+//! let x       = vec![ 2f64 ];
+//! let (_, ax) = start_recording(None, x.clone());
+//! let ay      = &0f64 * &ax[0];
+//! let f       = stop_recording( vec![ay] );
+//! let (y, _v) = f.forward_var_value(None, vec![ f64::nan() ], &opt_vec);
+//! assert!( y[0].is_nan() );
+//! ```
+//!
+// ---------------------------------------------------------------------------
+// use
+//
+use crate::{
+    FConst,
+    FUnary,
+    FBinary,
+    FReduce,
+    FValue,
+    FBytes,
+    FText,
+};
+// ---------------------------------------------------------------------------
+// FConst
+/// FConst trait for the primitive float types
+///
+/// * B : is the primitive type f32 or f64
+macro_rules! impl_primitive_const{ ($B:ident) => {
+    impl FConst for $B {
+        fn pi()           -> $B { std::$B::consts::PI }
+        fn nan()          -> $B { $B::NAN }
+        fn one()          -> $B { 1 as $B }
+        fn zero()         -> $B { 0 as $B }
+        fn epsilon()      -> $B { $B::EPSILON }
+        fn min_positive() -> $B { $B::MIN_POSITIVE }
+        const ABSOLUTE_ZERO : bool = false;
+    }
+}}
+impl_primitive_const!(f32);
+impl_primitive_const!(f64);
+// ----------------------------------------------------------------------------
+// doc_f_unary_primitive
+/// Primitive float unary functions
+///
+/// * Syntax : `y = x.Name()`
+///
+/// * B : is the primitive type f32 or f64
+///
+/// * Name : is the name of one of the [FUnary] functions.
+///   If B has a method with the same name, it is used.
+///
+/// * x : is a `B` or `&B` object.
+///
+/// * y : is the `B` result.
+///
+/// # Example
+/// ```
+/// use rustad::FUnary;
+/// let x : f64 = 2.0;
+/// assert_eq!( FUnary::square(&x), 4.0 );
+/// assert_eq!( FUnary::minus(x), -2.0 );
+/// assert_eq!( FUnary::erf(0f32), 0f32 );
+/// ```
+pub fn doc_f_unary_primitive() {}
+//
+macro_rules! primitive_unary_function{ ($B:ident, $name:ident) => {
+    #[doc = "see [doc_f_unary_primitive]" ]
+    fn $name(self) -> $B { $B::$name(*self) }
+} }
+macro_rules! primitive_unary_own{ ( $B:ident, $($name:ident),* ) => {
+    $(
+        #[doc = "see [doc_f_unary_primitive]" ]
+        fn $name(self) -> $B { FUnary::$name(&self) }
+    )*
+} }
+macro_rules! impl_primitive_unary{ ($B:ident) => {
+    impl FUnary for &$B {
+        type Output = $B;
+        //
+        // use primitive_unary_function
+        primitive_unary_function!($B, acos);
+        primitive_unary_function!($B, acosh);
+        primitive_unary_function!($B, asin);
+        primitive_unary_function!($B, asinh);
+        primitive_unary_function!($B, atan);
+        primitive_unary_function!($B, atanh);
+        primitive_unary_function!($B, ln_1p);
+        primitive_unary_function!($B, exp_m1);
+        primitive_unary_function!($B, ln);
+        primitive_unary_function!($B, sqrt);
+        primitive_unary_function!($B, tanh);
+        primitive_unary_function!($B, tan);
+        primitive_unary_function!($B, sinh);
+        primitive_unary_function!($B, cosh);
+        primitive_unary_function!($B, abs);
+        primitive_unary_function!($B, signum);
+        primitive_unary_function!($B, exp);
+        primitive_unary_function!($B, cos);
+        primitive_unary_function!($B, sin);
+        primitive_unary_function!($B, cbrt);
+        primitive_unary_function!($B, exp2);
+        primitive_unary_function!($B, log10);
+        primitive_unary_function!($B, log2);
+        primitive_unary_function!($B, ceil);
+        primitive_unary_function!($B, floor);
+        primitive_unary_function!($B, round);
+        primitive_unary_function!($B, trunc);
+        //
+        // square implements differently
+        #[doc = "see [doc_f_unary_primitive]" ]
+        fn square(self) -> $B { self * self }
+        //
+        // minus implements differently
+        #[doc = "see [doc_f_unary_primitive]" ]
+        fn minus(self) -> $B { - self }
+        //
+        // erf, erfc are not yet stable in std; see src/float/erf.rs
+        #[doc = "see [doc_f_unary_primitive]" ]
+        fn erf(self) -> $B {
+            super::erf::erf( f64::from(*self) ) as $B
+        }
+        #[doc = "see [doc_f_unary_primitive]" ]
+        fn erfc(self) -> $B {
+            super::erf::erfc( f64::from(*self) ) as $B
+        }
+        //
+        // binary functions, but it only has one float
+        #[doc = "see [doc_f_unary_primitive]" ]
+        fn powi(self, rhs : i32) -> $B { $B::powi(*self, rhs) }
+    }
+    impl FUnary for $B {
+        type Output = $B;
+        primitive_unary_own!( $B,
+            abs, acos, acosh, asin, asinh, atan, atanh, cbrt, ceil, cos, cosh,
+            erf, erfc, exp, exp2, exp_m1, floor, ln, ln_1p, log10, log2, minus,
+            round, signum, sin, sinh, sqrt, square, tan, tanh, trunc
+        );
+        #[doc = "see [doc_f_unary_primitive]" ]
+        fn powi(self, rhs : i32) -> $B { FUnary::powi(&self, rhs) }
+    }
+} }
+impl_primitive_unary!(f32);
+impl_primitive_unary!(f64);
+// ---------------------------------------------------------------------------
+// FBinary
+/// Implement [FBinary] when both operands are `B` or `&B` .
+///
+/// * B : is the primitive type f32 or f64
+///
+/// The numerical comparisons return one (zero) for true (false).
+/// If either operand is nan, all the comparisons except num_ne are false.
+///
+/// # Example
+/// ```
+/// use rustad::FBinary;
+/// let two   = 2f64;
+/// let three = 3f64;
+/// assert_eq!( two.powf(three), 8.0 );
+/// assert_eq!( FBinary::num_lt(&two, &three), 1.0 );
+/// assert_eq!( FBinary::num_lt(f64::NAN, three), 0.0 );
+/// ```
+pub fn doc_f_binary_primitive() {}
+//
+/// see [doc_f_binary_primitive]
+macro_rules! primitive_binary_function{
+    ($B:ident, $name:ident) => {
+        #[doc = "see [doc_f_binary_primitive]" ]
+        fn $name(self, rhs : &$B ) -> $B { $B::$name(*self, *rhs) }
+    };
+    ($B:ident, $name:ident, $op:tt) => {
+        #[doc = "see [doc_f_binary_primitive]" ]
+        fn $name(self, rhs : &$B ) -> $B {
+            if *self $op *rhs { 1 as $B } else { 0 as $B }
+        }
+    };
+}
+//
+/// see [doc_f_binary_primitive]
+macro_rules! primitive_binary_own{ ( $B:ident, $($name:ident),* ) => {
+    $(
+        #[doc = "see [doc_f_binary_primitive]" ]
+        fn $name(self, rhs : $B) -> $B { FBinary::$name(&self, &rhs) }
+    )*
+} }
+//
+macro_rules! impl_primitive_binary{ ($B:ident) => {
+    impl FBinary< &$B > for &$B
+    {
+        type Output = $B;
+        //
+        primitive_binary_function!( $B, num_lt, <  );
+        primitive_binary_function!( $B, num_le, <= );
+        primitive_binary_function!( $B, num_eq, == );
+        primitive_binary_function!( $B, num_ne, != );
+        primitive_binary_function!( $B, num_ge, >= );
+        primitive_binary_function!( $B, num_gt, >  );
+        primitive_binary_function!( $B, atan2 );
+        primitive_binary_function!( $B, hypot );
+        primitive_binary_function!( $B, powf );
+        primitive_binary_function!( $B, min );
+        primitive_binary_function!( $B, max );
+    }
+    impl FBinary< $B > for $B
+    {
+        type Output = $B;
+        primitive_binary_own!( $B,
+            num_lt, num_le, num_eq, num_ne, num_ge, num_gt,
+            atan2, hypot, powf, min, max
+        );
+    }
+} }
+impl_primitive_binary!(f32);
+impl_primitive_binary!(f64);
+// ----------------------------------------------------------------------------
+// FReduce
+/// [FReduce] for the primitive float types.
+///
+/// * B : is the primitive type f32 or f64
+///
+/// These reductions act on one element; e.g., reduce_len is one.
+///
+/// # Example
+/// ```
+/// use rustad::FReduce;
+/// let two = 2f32;
+/// assert_eq!( (&two).reduce_mean(), 2.0 );
+/// assert_eq!( (&two).reduce_dot(&two), 4.0 );
+/// ```
+pub fn doc_f_reduce_primitive() {}
+//
+macro_rules! impl_primitive_reduce{ ($B:ident) => {
+    #[doc = "see [doc_f_reduce_primitive]" ]
+    impl FReduce for &$B {
+        type Output = $B;
+        //
+        fn reduce_sum(self)  -> $B { *self }
+        fn reduce_mean(self) -> $B { *self }
+        fn reduce_max(self)  -> $B { *self }
+        fn reduce_len(self)  -> $B { 1 as $B }
+        fn reduce_dot(self, rhs : Self) -> $B { *self * *rhs }
+    }
+} }
+impl_primitive_reduce!(f32);
+impl_primitive_reduce!(f64);
+// ----------------------------------------------------------------------------
+// FValue
+// doc_f_value_primitive
+/// [FValue] for the primitive float types.
+///
+/// * B : is the primitive type f32 or f64
+///
+/// * to_src : uses `B::NAN` for nan values and `x as B` otherwise.
+///
+/// # Example
+/// ```
+/// use rustad::FValue;
+/// let zero = 0f32;
+/// assert!( zero.is_zero() );
+/// assert!( (-0f32).is_zero() );
+/// assert!( ! zero.is_one() );
+/// assert_eq!( 1.5f64.to_src(), "1.5 as f64" );
+/// assert_eq!( FValue::is_nan(&f64::NAN), true );
+/// ```
+pub fn doc_f_value_primitive() {}
+//
+macro_rules! impl_primitive_value{ ($B:ident) => {
+    impl FValue for $B {
+        fn is_zero(&self)  -> bool { *self == ( 0 as $B ) }
+        fn is_one(&self)   -> bool { *self == ( 1 as $B ) }
+        fn is_nan(&self)   -> bool { $B::is_nan(*self) }
+        fn to_src(&self)   -> String {
+            if $B::is_nan(*self) {
+                stringify!($B).to_string() + "::NAN"
+            } else if $B::is_infinite(*self) {
+                let sign = if *self < (0 as $B) { "-" } else { "" };
+                sign.to_string() + stringify!($B) + "::INFINITY"
+            } else {
+                format!( "{:?} as {}", self, stringify!($B) )
+            }
+        }
+    }
+} }
+impl_primitive_value!(f32);
+impl_primitive_value!(f64);
+// ----------------------------------------------------------------------------
+// FBytes
+// doc_f_bytes_primitive
+/// [FBytes] for the primitive float types.
+///
+/// * B : is the primitive type f32 or f64
+///
+/// * type_name : is `f32` or `f64` .
+///
+/// * bytes : is the little endian representation of the value.
+///
+/// # Example
+/// ```
+/// use rustad::FBytes;
+/// let x         = 3f32;
+/// let mut bytes = Vec::new();
+/// x.to_bytes(&mut bytes);
+/// assert_eq!( bytes.len(), 4 );
+/// //
+/// let mut start = 0;
+/// let y         = f32::from_bytes(&bytes, &mut start).unwrap();
+/// assert_eq!( x, y );
+/// assert_eq!( start, 4 );
+/// assert_eq!( f32::type_name(), "f32" );
+/// ```
+pub fn doc_f_bytes_primitive() {}
+//
+macro_rules! impl_primitive_bytes{ ($B:ident) => {
+    impl FBytes for $B {
+        fn type_name() -> String {
+            stringify!($B).to_string()
+        }
+        fn to_bytes(&self, bytes : &mut Vec<u8>) {
+            bytes.extend_from_slice( &self.to_le_bytes() );
+        }
+        fn from_bytes(bytes : &[u8], start : &mut usize) -> Result<Self, String> {
+            let size = std::mem::size_of::<$B>();
+            let end  = *start + size;
+            if bytes.len() < end {
+                return Err( format!(
+                    "{}::from_bytes: unexpected end of bytes", stringify!($B)
+                ) );
+            }
+            let mut array = [0u8; std::mem::size_of::<$B>()];
+            array.copy_from_slice( &bytes[*start .. end] );
+            *start = end;
+            Ok( $B::from_le_bytes(array) )
+        }
+    }
+} }
+impl_primitive_bytes!(f32);
+impl_primitive_bytes!(f64);
+// ----------------------------------------------------------------------------
+// FText
+// doc_f_text_primitive
+/// [FText] for the primitive float types.
+///
+/// * B : is the primitive type f32 or f64
+///
+/// * text :
+///   is the shortest decimal representation that converts back to the
+///   same value; e.g., `1.0` , `-0.0` , `1e-7` , `-inf` , or `NaN` .
+///
+/// # Example
+/// ```
+/// use rustad::FText;
+/// let x    = -0f64;
+/// let text = x.to_text();
+/// assert_eq!( text, "-0.0" );
+/// assert_eq!( f64::from_text(&text).unwrap().to_bits(), x.to_bits() );
+/// assert!( f64::from_text("one").is_err() );
+/// ```
+pub fn doc_f_text_primitive() {}
+//
+macro_rules! impl_primitive_text{ ($B:ident) => {
+    impl FText for $B {
+        fn to_text(&self) -> String {
+            format!( "{:?}", self )
+        }
+        fn from_text(text : &str) -> Result<Self, String> {
+            match text.trim().parse::<$B>() {
+                Ok(value) => Ok( value ),
+                Err(_)    => Err( format!(
+                    "{}::from_text: invalid text: {text}", stringify!($B)
+                ) ),
+            }
+        }
+    }
+} }
+impl_primitive_text!(f32);
+impl_primitive_text!(f64);
@@ -26,6 +26,14 @@ pub trait FConst {
     fn zero()         -> Self;
     fn epsilon()      -> Self;
     fn min_positive() -> Self;
+    // ------------------------------------------------------------------------
+    // Associated Constants
+    // ------------------------------------------------------------------------
+    /// is true if zero times any value, including nan, is zero.
+    /// In this case, multiplications by the constant zero
+    /// are not recorded and the result is the constant zero.
+    /// The default value is true.
+    const ABSOLUTE_ZERO : bool = true;
 }
 // ----------------------------------------------------------------------------
 /// The floating point unary function trait
@@ -37,13 +45,28 @@ pub trait FUnary {
     // ------------------------------------------------------------------------
     // BEGIN_SORT_THIS_LINE_PLUS_1
     fn abs(self) -> Self::Output;
+    fn acos(self) -> Self::Output;
+    fn acosh(self) -> Self::Output;
+    fn asin(self) -> Self::Output;
+    fn asinh(self) -> Self::Output;
+    fn atan(self) -> Self::Output;
+    fn atanh(self) -> Self::Output;
+    fn cbrt(self) -> Self::Output;
+    fn ceil(self) -> Self::Output;
     fn cos(self) -> Self::Output;
     fn cosh(self) -> Self::Output;
+    fn erf(self) -> Self::Output;
+    fn erfc(self) -> Self::Output;
     fn exp(self) -> Self::Output;
+    fn exp2(self) -> Self::Output;
     fn exp_m1(self) -> Self::Output;
+    fn floor(self) -> Self::Output;
     fn ln(self) -> Self::Output;
     fn ln_1p(self) -> Self::Output;
+    fn log10(self) -> Self::Output;
+    fn log2(self) -> Self::Output;
     fn minus(self) -> Self::Output;
+    fn round(self) -> Self::Output;
     fn signum(self) -> Self::Output;
     fn sin(self) -> Self::Output;
     fn sinh(self) -> Self::Output;
@@ -51,6 +74,7 @@ pub trait FUnary {
     fn square(self) -> Self::Output;
     fn tan(self) -> Self::Output;
     fn tanh(self) -> Self::Output;
+    fn trunc(self) -> Self::Output;
     // END_SORT_THIS_LINE_MINUS_1
     // ------------------------------------------------------------------------
     fn powi(self, rhs : i32) -> Self::Output;
@@ -75,6 +99,13 @@ pub trait FUnary {
 ///   These function return the floating point value
 ///   one for true and zero for false.
 ///
+/// * min, max :
+///   The min (max) function returns the smaller (larger) of its arguments.
+///   If one argument is nan, the other argument is returned.
+///   For AD types, the derivative at a tie (when lhs and rhs are equal)
+///   is the derivative of lhs; i.e., the order of the arguments
+///   selects the subgradient used at a tie.
+///
 /// Example
 /// See the file examples/f_binary.rs
 ///
@@ -107,6 +138,64 @@ pub trait FBinary<Rhs> {
     //
     /// self^rhs
     fn powf(self, rhs : Rhs) -> Self::Output;
+    //
+    /// minimum of self and rhs
+    fn min(self, rhs : Rhs) -> Self::Output;
+    //
+    /// maximum of self and rhs
+    fn max(self, rhs : Rhs) -> Self::Output;
+}
+//
+// FReduce
+/// The floating point reduction trait
+///
+/// * Syntax :
+///   ```text
+///     res = arg.name()
+///     res = lhs.reduce_dot(rhs)
+///   ```
+///
+/// * arg  : is the function argument.
+/// * res  : is the function result. If the argument is a vector
+///   (see [NumVec](crate::NumVec) ) the result has length one; i.e.,
+///   it is a scalar. Otherwise the argument is treated as a vector
+///   of length one.
+///
+/// * reduce_max :
+///   Elements of the argument that are nan are ignored.
+///   For AD types, the derivative is divided equally between the
+///   elements that are equal to the maximum.
+///   The same weights are used for all the Taylor coefficients;
+///   i.e., the order k coefficient of the result is the average of the
+///   order k coefficients for the elements whose value is the maximum.
+///   The elements are not compared using their higher order coefficients,
+///   so this is not the Taylor coefficient of the maximum when these
+///   coefficients are different; e.g., for x(t) = [ 1 + t , 1 + 3 t ]
+///   the first order coefficient of the result is 2 (not 3).
+///
+/// * reduce_len :
+///   The number of elements in the argument (as a floating point value).
+///
+/// Example
+/// See the file examples/f_reduce.rs
+///
+pub trait FReduce {
+    type Output;
+    //
+    /// sum of the elements of self
+    fn reduce_sum(self) -> Self::Output;
+    //
+    /// average of the elements of self
+    fn reduce_mean(self) -> Self::Output;
+    //
+    /// maximum of the elements of self
+    fn reduce_max(self) -> Self::Output;
+    //
+    /// number of elements in self
+    fn reduce_len(self) -> Self::Output;
+    //
+    /// sum of the element-wise product of self and rhs
+    fn reduce_dot(self, rhs : Self) -> Self::Output;
 }
 // ----------------------------------------------------------------------------
 /// Floating point operations that are not implemented for AD types.
@@ -118,4 +207,43 @@ pub trait FValue {
     fn is_one(&self)  -> bool;
     fn is_nan(&self)  -> bool;
     fn to_src(&self)  -> String;
+    //
+    /// if_true where self is one and if_false where self is zero .
+    ///
+    /// For vector types this is element wise. The branch that is not
+    /// selected does not affect the result, even if it is nan or infinite.
+    /// This is used by conditional expressions and [VecAD](crate::VecAD)
+    /// when [ABSOLUTE_ZERO](FConst::ABSOLUTE_ZERO) is false.
+    /// The default implementation is for scalar types.
+    fn select(&self, if_true : &Self, if_false : &Self) -> Self
+    where
+        Self : Sized + Clone ,
+    {   if self.is_one() { if_true.clone() } else { if_false.clone() } }
+}
+// ----------------------------------------------------------------------------
+/// Conversion of values to and from a stable binary representation.
+///
+/// All the numbers in the binary representation are little endian.
+/// This is used by [ADfn::to_bytes](crate::adfn::ADfn::to_bytes) to save
+/// the constant parameters in a function object.
+pub trait FBytes : Sized {
+    /// name that identifies this type in a binary representation
+    fn type_name() -> String;
+    /// append the binary representation of self to the end of bytes
+    fn to_bytes(&self, bytes : &mut Vec<u8>);
+    /// read a value starting at bytes\[*start*\] and advance *start*
+    /// to the end of the value.
+    fn from_bytes(bytes : &[u8], start : &mut usize) -> Result<Self, String>;
+}
+// ----------------------------------------------------------------------------
+/// Conversion of values to and from text that represents them exactly.
+///
+/// This is used by [ADfn::to_text](crate::adfn::ADfn::to_text) to write
+/// the constant parameters in a function object.
+/// The text does not contain any newline characters.
+pub trait FText : Sized {
+    /// text that [FText::from_text] converts back to self
+    fn to_text(&self) -> String;
+    /// convert the text created by [FText::to_text] to a value
+    fn from_text(text : &str) -> Result<Self, String>;
 }
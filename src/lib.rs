@@ -37,6 +37,9 @@ pub mod float;
 // nearly_eq
 pub mod nearly_eq;
 //
+// setup
+pub mod setup;
+//
 // sparse
 pub mod sparse;
 //
@@ -49,9 +52,6 @@ pub(crate) mod vec_set;
 // op
 pub(crate) mod op;
 //
-// setup
-pub(crate) mod setup;
-//
 // ----------------------------------------------------------------------------
 // use
 // https://doc.rust-lang.org/rustdoc/write-documentation/re-exports.html
@@ -63,6 +63,8 @@ pub use ad::{
     ad_from_vector,
     ad_to_vector,
     doc_generic_v,
+    cexp::CompareOp,
+    vec_ad::VecAD,
 };
 pub use adfn::{
     ADfn,
@@ -94,15 +96,22 @@ pub use float::{
     traits::FConst,
     traits::FUnary,
     traits::FBinary,
+    traits::FReduce,
     traits::FValue,
+    traits::FBytes,
+    traits::FText,
     az_float::AzFloat,
     num_vec::NumVec,
+    complex::Complex,
+    interval::Interval,
+    double_double::DoubleDouble,
 };
 pub use nearly_eq::{
     nearly_eq,
 };
 pub use sparse::{
     coloring,
+    star_coloring,
 };
 // ---------------------------------------------------------------------------
 // Sealed Traits
@@ -176,6 +185,36 @@ pub const AZ_FLOAT_RS : &str = include_str!( "float/az_float.rs" );
 /// [ADfn::rust_src] .
 pub const NUM_VEC_RS : &str = include_str!( "float/num_vec.rs" );
 //
+// COMPLEX_RS
+/// is the source code for the [complex](crate::float::complex) module.
+/// This is needed at the beginning of a dll library that include
+/// [ADfn::rust_src] .
+pub const COMPLEX_RS : &str = include_str!( "float/complex.rs" );
+//
+// INTERVAL_RS
+/// is the source code for the [interval](crate::float::interval) module.
+/// This is needed at the beginning of a dll library that include
+/// [ADfn::rust_src] .
+pub const INTERVAL_RS : &str = include_str!( "float/interval.rs" );
+//
+// PRIMITIVE_RS
+/// is the source code for the [primitive](crate::float::primitive) module.
+/// This is needed at the beginning of a dll library that include
+/// [ADfn::rust_src] .
+pub const PRIMITIVE_RS : &str = include_str!( "float/primitive.rs" );
+//
+// DOUBLE_DOUBLE_RS
+/// is the source code for the [double_double](crate::float::double_double)
+/// module. This is needed at the beginning of a dll library that include
+/// [ADfn::rust_src] .
+pub const DOUBLE_DOUBLE_RS : &str = include_str!( "float/double_double.rs" );
+//
+// ERF_RS
+/// is the source code for the [erf](crate::float::erf) module.
+/// This is needed at the beginning of a dll library that include
+/// [ADfn::rust_src] .
+pub const ERF_RS : &str = include_str!( "float/erf.rs" );
+//
 // ----------------------------------------------------------------------------
 // opt_vec
 /// Convention for a variable number of options to a function.
@@ -220,3 +259,64 @@ pub fn pop_this_thread_message()->Option<String>
         |vec_str| vec_str.pop()
      )
 }
+// ---------------------------------------------------------------------------
+// PrintSink
+/// Function that receives the lines printed by the print operators;
+/// see [set_this_thread_print_sink] .
+pub type PrintSink = Box< dyn FnMut(&str) >;
+//
+// THIS_THREAD_PRINT_SINK
+thread_local! {
+    static THIS_THREAD_PRINT_SINK :
+        std::cell::RefCell< Option<PrintSink> > =
+            const { std::cell::RefCell::new( None ) };
+}
+//
+// print_this_thread
+// The sink is taken out of THIS_THREAD_PRINT_SINK while it is called,
+// so a sink that evaluates print operators does not borrow it twice;
+// the lines it prints go to standard output.
+pub(crate) fn print_this_thread(line : &str)
+{   let local_key = &THIS_THREAD_PRINT_SINK;
+    match local_key.take() {
+        Some(mut sink) => {
+            sink(line);
+            // keep a sink that was set during the call
+            local_key.with_borrow_mut( |current| {
+                if current.is_none() {
+                    *current = Some(sink);
+                }
+            } );
+        },
+        None => println!("{line}"),
+    }
+}
+//
+// set_this_thread_print_sink
+/// Set where the print operators send their output for this thread;
+/// see [doc_print_on_forward](crate::ad::print::doc_print_on_forward).
+///
+/// * Syntax :
+///   ```text
+///     old_sink = set_this_thread_print_sink(new_sink)
+///   ```
+///
+/// * new_sink :
+///   If this is None, the lines are printed on standard output
+///   (this is the default). Otherwise, each line (without a newline)
+///   is passed to the new_sink function.
+///
+/// * old_sink :
+///   is the sink that was used for this thread before this call.
+///
+/// * Re-entrant Sinks :
+///   While a sink is receiving a line, it is not the sink for this thread.
+///   If it evaluates functions that contain print operators,
+///   those lines are printed on standard output.
+///   If it sets a new sink, the new sink replaces it when it returns.
+pub fn set_this_thread_print_sink(
+    new_sink : Option<PrintSink>
+) -> Option<PrintSink>
+{   let local_key = &THIS_THREAD_PRINT_SINK;
+    local_key.replace( new_sink )
+}
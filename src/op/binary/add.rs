@@ -30,17 +30,25 @@ use crate::ad::ADType;
 use crate::{
     AD,
     FConst,
+    FUnary,
 };
 //
 use crate::op::binary::common;
 use crate::tape::sealed::ThisThreadTape;
+use crate::op::taylor::{
+    TaylorValue,
+    TaylorRef,
+};
 use crate::op::info::{
     OpFns,
 
     ConstData,
+    Linearity,
     panic_dyp,
     panic_var,
     panic_der,
+    panic_taylor,
+    panic_reverse_taylor,
 };
 use crate::op::id::{
     ADD_PP_OP,
@@ -56,6 +64,12 @@ use crate::op::info::{
 // -------------------------------------------------------------------------
 // add_rust_src
 common::binary_rust_src!(add);
+//
+// add_forward_taylor
+common::binary_forward_taylor!(add, E);
+//
+// add_reverse_taylor
+common::binary_reverse_taylor!(add, E);
 // -------------------------------------------------------------------------
 // add_forward_dyp
 // add_pv_forward_var
@@ -199,6 +213,10 @@ where
     for<'a> &'a V : Add<&'a V, Output = V> ,
         for<'a> V : AddAssign<&'a V>,
                 V : Clone + FConst + PartialEq + ThisThreadTape,
+    //
+    // forward_taylor
+    V             : TaylorValue ,
+    for<'a> &'a V : TaylorRef<'a, V> ,
 {
     op_fns_vec[ADD_PP_OP as usize] = OpFns{
         name              : "add_pp",
@@ -210,8 +228,13 @@ where
         forward_der_ad    : panic_der::<V, AD<V> >,
         reverse_der_value : panic_der::<V, V>,
         reverse_der_ad    : panic_der::<V, AD<V> >,
+        forward_taylor_value : panic_taylor::<V, V>,
+        forward_taylor_ad    : panic_taylor::<V, AD<V> >,
+        reverse_taylor_value : panic_reverse_taylor::<V, V>,
+        reverse_taylor_ad    : panic_reverse_taylor::<V, AD<V> >,
         rust_src          : add_rust_src,
         reverse_depend    : common::binary_reverse_depend,
+        linearity         : Linearity::Linear,
     };
     op_fns_vec[ADD_PV_OP as usize] = OpFns{
         name              : "add_pv",
@@ -223,8 +246,13 @@ where
         forward_der_ad    : add_pv_forward_der::<V, AD<V> >,
         reverse_der_value : add_pv_reverse_der::<V, V>,
         reverse_der_ad    : add_pv_reverse_der::<V, AD<V> >,
+        forward_taylor_value : add_forward_taylor::<V, V>,
+        forward_taylor_ad    : add_forward_taylor::<V, AD<V> >,
+        reverse_taylor_value : add_reverse_taylor::<V, V>,
+        reverse_taylor_ad    : add_reverse_taylor::<V, AD<V> >,
         rust_src          : add_rust_src,
         reverse_depend    : common::binary_reverse_depend,
+        linearity         : Linearity::Linear,
     };
     op_fns_vec[ADD_VP_OP as usize] = OpFns{
         name              : "add_vp",
@@ -236,8 +264,13 @@ where
         forward_der_ad    : add_vp_forward_der::<V, AD<V> >,
         reverse_der_value : add_vp_reverse_der::<V, V>,
         reverse_der_ad    : add_vp_reverse_der::<V, AD<V> >,
+        forward_taylor_value : add_forward_taylor::<V, V>,
+        forward_taylor_ad    : add_forward_taylor::<V, AD<V> >,
+        reverse_taylor_value : add_reverse_taylor::<V, V>,
+        reverse_taylor_ad    : add_reverse_taylor::<V, AD<V> >,
         rust_src          : add_rust_src,
         reverse_depend    : common::binary_reverse_depend,
+        linearity         : Linearity::Linear,
     };
     op_fns_vec[ADD_VV_OP as usize] = OpFns{
         name              : "add_vv",
@@ -249,7 +282,12 @@ where
         forward_der_ad    : add_vv_forward_der::<V, AD<V> >,
         reverse_der_value : add_vv_reverse_der::<V, V>,
         reverse_der_ad    : add_vv_reverse_der::<V, AD<V> >,
+        forward_taylor_value : add_forward_taylor::<V, V>,
+        forward_taylor_ad    : add_forward_taylor::<V, AD<V> >,
+        reverse_taylor_value : add_reverse_taylor::<V, V>,
+        reverse_taylor_ad    : add_reverse_taylor::<V, AD<V> >,
         rust_src          : add_rust_src,
         reverse_depend    : common::binary_reverse_depend,
+        linearity         : Linearity::Linear,
     };
 }
@@ -29,8 +29,13 @@ use crate::{
 };
 //
 use crate::op::binary::common;
+use crate::op::taylor::{
+    TaylorValue,
+    TaylorRef,
+};
 use crate::op::info::OpFns;
 use crate::op::info::ConstData;
+use crate::op::info::Linearity;
 use crate::op::id::ATAN2_OP;
 use crate::tape::sealed::ThisThreadTape;
 //
@@ -44,6 +49,12 @@ use crate::op::info::{
 // atan2_forward_dyp
 // atan2_forward_var
 common::f_binary_function!(atan2);
+//
+// atan2_forward_taylor
+common::binary_forward_taylor!(atan2);
+//
+// atan2_reverse_taylor
+common::binary_reverse_taylor!(atan2, E);
 // ---------------------------------------------------------------------------
 //
 // atan2_forward_der
@@ -200,6 +211,10 @@ where
     for<'a> &'a AD<V> : FUnary<Output = AD<V>>,
     //
     for<'a> &'a V     : FBinary<&'a V, Output = V>,
+    //
+    // forward_taylor
+    V             : TaylorValue ,
+    for<'a> &'a V : TaylorRef<'a, V> ,
 {
     op_fns_vec[ATAN2_OP as usize] = OpFns{
         name              : "atan2_pp",
@@ -211,7 +226,12 @@ where
         forward_der_ad    : atan2_forward_der::<V, AD<V> >,
         reverse_der_value : atan2_reverse_der::<V, V>,
         reverse_der_ad    : atan2_reverse_der::<V, AD<V> >,
+        forward_taylor_value : atan2_forward_taylor::<V, V>,
+        forward_taylor_ad    : atan2_forward_taylor::<V, AD<V> >,
+        reverse_taylor_value : atan2_reverse_taylor::<V, V>,
+        reverse_taylor_ad    : atan2_reverse_taylor::<V, AD<V> >,
         rust_src          : atan2_rust_src,
         reverse_depend    : common::binary_reverse_depend,
+        linearity         : Linearity::Nonlinear,
     };
 }
@@ -45,6 +45,16 @@ pub(crate) fn is_binary_op(op_id : u8) -> bool {
         id::HYPOT_OP  => true ,
         id::POWF_OP   => true ,
         //
+        id::MIN_PP_OP => true ,
+        id::MIN_PV_OP => true ,
+        id::MIN_VP_OP => true ,
+        id::MIN_VV_OP => true ,
+        //
+        id::MAX_PP_OP => true ,
+        id::MAX_PV_OP => true ,
+        id::MAX_VP_OP => true ,
+        id::MAX_VV_OP => true ,
+        //
         _             => false,
     }
 }
@@ -199,7 +209,7 @@ macro_rules! binary_rust_src { ($name:ident) => { paste::paste! {
     {   //
         let ConstData {arg, arg_type, res, ..} = const_data;
         //
-        debug_assert!( arg.len() == 2);
+        debug_assert!( arg.len() == 2 || arg.len() == 3 );
         //
         // lhs_str
         let lhs_str : String;
@@ -293,6 +303,133 @@ macro_rules! binary_rust_src { ($name:ident) => { paste::paste! {
 } } }
 pub(crate) use binary_rust_src;
 // ---------------------------------------------------------------------------
+// binary_forward_taylor
+/// Arbitrary order forward mode for binary operators.
+///
+/// * V    : see [doc_generic_v](crate::doc_generic_v)
+/// * E    : see [doc_generic_e](crate::doc_generic_e)
+/// * name : add, sub, mul, div, atan2, hypot, powf
+/// * T    : type arguments for the recurrence, V, E by default;
+///   recurrences that do not use V are called with E alone.
+///
+/// This defines the following function in the current module:
+/// ```text
+///     {name}_forward_taylor<V, E>
+/// ```
+/// The Taylor coefficient recurrence for the operator is
+/// crate::op::taylor::{name} .
+/// Either argument may be a parameter or a variable, so the same function
+/// is used for the PV, VP, and VV versions of an operator.
+macro_rules! binary_forward_taylor {
+    ($name:ident) => {
+        crate::op::binary::common::binary_forward_taylor!($name, V, E);
+    };
+    ($name:ident, $($T:ident),+) => { paste::paste! {
+    #[doc = concat!(
+        " Taylor coefficients for ", stringify!( $name ),
+        "; see [ForwardTaylor](crate::op::info::ForwardTaylor)"
+    ) ]
+    fn [< $name _forward_taylor >]<V, E> (
+        dyp_all     : &[E]        ,
+        var_tay     : &mut [E]    ,
+        n_tay       : usize       ,
+        const_data : ConstData<V> )
+    where
+        V             : Clone + From<f32> ,
+        E             : Clone + From<V> + FConst ,
+        for<'a> E     : std::ops::AddAssign<&'a E> ,
+        for<'a> E     : std::ops::SubAssign<&'a E> ,
+        for<'a> &'a E : std::ops::Add<&'a E, Output=E> ,
+        for<'a> &'a E : std::ops::Sub<&'a E, Output=E> ,
+        for<'a> &'a E : std::ops::Mul<&'a E, Output=E> ,
+        for<'a> &'a E : std::ops::Div<&'a E, Output=E> ,
+        for<'a> &'a E : FUnary<Output=E>,
+    {   //
+        let ConstData {cop, arg, arg_type, res, ..} = const_data;
+        //
+        debug_assert!( arg.len() == 2);
+        debug_assert!( arg_type[0].is_variable() || arg_type[1].is_variable() );
+        let (before, z) = crate::op::taylor::split_var_tay(var_tay, n_tay, res);
+        let x = crate::op::taylor::arg_taylor::<V, E>(
+            dyp_all, cop, before, n_tay, arg[0], arg_type[0]
+        );
+        let y = crate::op::taylor::arg_taylor::<V, E>(
+            dyp_all, cop, before, n_tay, arg[1], arg_type[1]
+        );
+        crate::op::taylor::$name::<$($T),+>(&x, &y, z);
+    }
+}};
+}
+pub(crate) use binary_forward_taylor;
+// ---------------------------------------------------------------------------
+// binary_reverse_taylor
+/// Arbitrary order reverse mode for binary operators.
+///
+/// * V    : see [doc_generic_v](crate::doc_generic_v)
+/// * E    : see [doc_generic_e](crate::doc_generic_e)
+/// * name : add, sub, mul, div, atan2, hypot, powf
+/// * T    : type arguments for the recurrence, V, E by default;
+///   recurrences that do not use V are called with E alone.
+///
+/// This defines the following function in the current module:
+/// ```text
+///     {name}_reverse_taylor<V, E>
+/// ```
+/// The partials of the operator are computed by
+/// crate::op::taylor::{name}_partial .
+/// Either argument may be a parameter or a variable, so the same function
+/// is used for the PV, VP, and VV versions of an operator.
+macro_rules! binary_reverse_taylor {
+    ($name:ident) => {
+        crate::op::binary::common::binary_reverse_taylor!($name, V, E);
+    };
+    ($name:ident, $($T:ident),+) => { paste::paste! {
+    #[doc = concat!(
+        " Taylor coefficient partials for ", stringify!( $name ),
+        "; see [ReverseTaylor](crate::op::info::ReverseTaylor)"
+    ) ]
+    fn [< $name _reverse_taylor >]<V, E> (
+        dyp_all     : &[E]        ,
+        var_tay     : &[E]        ,
+        var_par     : &mut [E]    ,
+        n_tay       : usize       ,
+        const_data : ConstData<V> )
+    where
+        V             : Clone + From<f32> ,
+        E             : Clone + From<V> + FConst ,
+        for<'a> E     : std::ops::AddAssign<&'a E> ,
+        for<'a> E     : std::ops::SubAssign<&'a E> ,
+        for<'a> &'a E : std::ops::Add<&'a E, Output=E> ,
+        for<'a> &'a E : std::ops::Sub<&'a E, Output=E> ,
+        for<'a> &'a E : std::ops::Mul<&'a E, Output=E> ,
+        for<'a> &'a E : std::ops::Div<&'a E, Output=E> ,
+        for<'a> &'a E : FUnary<Output=E>,
+    {   //
+        let ConstData {cop, arg, arg_type, res, ..} = const_data;
+        //
+        debug_assert!( arg.len() == 2);
+        debug_assert!( arg_type[0].is_variable() || arg_type[1].is_variable() );
+        let x = crate::op::taylor::arg_taylor::<V, E>(
+            dyp_all, cop, var_tay, n_tay, arg[0], arg_type[0]
+        );
+        let y = crate::op::taylor::arg_taylor::<V, E>(
+            dyp_all, cop, var_tay, n_tay, arg[1], arg_type[1]
+        );
+        let z          = &var_tay[res * n_tay .. (res + 1) * n_tay];
+        let (d_x, d_y) = crate::op::taylor::[< $name _partial >]::<$($T),+>(&x, &y, z);
+        let (before, pz) = crate::op::taylor::split_var_par(var_par, n_tay, res);
+        for (i_arg, d) in [ d_x, d_y ].iter().enumerate() {
+            if arg_type[i_arg].is_variable() {
+                let index = arg[i_arg] as usize;
+                let px    = &mut before[index * n_tay .. (index + 1) * n_tay];
+                crate::op::taylor::reverse_partial::<E>(pz, d, px);
+            }
+        }
+    }
+}};
+}
+pub(crate) use binary_reverse_taylor;
+// ---------------------------------------------------------------------------
 // f_binary_function
 /// Define FBinary functions by name
 ///
@@ -327,7 +464,7 @@ macro_rules! f_binary_function { ($name:ident) => { paste::paste! {
     {
         let ConstData {cop, arg, arg_type, res, ..} = const_data;
         //
-        debug_assert!( arg.len() == 2);
+        debug_assert!( arg.len() == 2 || arg.len() == 3 );
         debug_assert!(
             ! ( arg_type[0].is_constant() && arg_type[1].is_constant() )
         );
@@ -374,7 +511,7 @@ macro_rules! f_binary_function { ($name:ident) => { paste::paste! {
     {
         let ConstData {cop, arg, arg_type, res, ..} = const_data;
         //
-        debug_assert!( arg.len() == 2);
+        debug_assert!( arg.len() == 2 || arg.len() == 3 );
         //
         // lhs, rhs
         let lhs = arg[0] as usize;
@@ -424,6 +561,8 @@ pub(crate) use f_binary_function;
 /// Reverse dependency analysis for a binary operator;
 /// see [ReverseDepend](crate::op::info::ReverseDepend)
 ///
+/// The numerical comparison operators have a third argument; see
+/// [num_cmp](crate::op::binary::num_cmp) .
 pub(crate) fn binary_reverse_depend(
     depend    : &mut optimize::Depend ,
     _bool_all : &[bool]               ,
@@ -432,12 +571,12 @@ pub(crate) fn binary_reverse_depend(
     res       : usize                 ,
     res_type  : ADType                ,
 ) { //
-    debug_assert_eq!(arg.len(), 2);
-    debug_assert_eq!(arg_type.len(), 2);
+    debug_assert!( arg.len() == 2 || arg.len() == 3 );
+    debug_assert_eq!(arg_type.len(), arg.len());
     //
     if res_type.is_variable() {
         debug_assert!( depend.var[res] );
-        for i_arg in 0 .. 2 {
+        for i_arg in 0 .. arg.len() {
             let index = arg[i_arg] as usize;
             match arg_type[i_arg] {
                 //
@@ -450,7 +589,7 @@ pub(crate) fn binary_reverse_depend(
     } else {
         debug_assert!( res_type.is_dynamic() );
         debug_assert!( depend.dyp[res] );
-        for i_arg in 0 .. 2 {
+        for i_arg in 0 .. arg.len() {
             let index = arg[i_arg] as usize;
             match arg_type[i_arg] {
                 //
@@ -461,3 +600,203 @@ pub(crate) fn binary_reverse_depend(
         }
     }
 }
+// ---------------------------------------------------------------------------
+// min_max_derivative
+/// Derivatives for the min and max operators.
+///
+/// * V    : see [doc_generic_v](crate::doc_generic_v)
+/// * E    : see [doc_generic_e](crate::doc_generic_e)
+/// * name : min or max
+///
+/// This defines the following functions in the current module:
+/// ```text
+///     {name}_forward_der<V, E>
+///     {name}_reverse_der<V, E>
+///     {name}_forward_taylor<V, E>
+///     {name}_reverse_taylor<V, E>
+/// ```
+/// Either argument may be a parameter or a variable, so the same functions
+/// are used for the PV, VP, and VV versions of an operator.
+///
+/// * Ties :
+///   The result z is equal to one of the operands.
+///   If z is equal to the left operand, its derivative is used,
+///   otherwise the derivative of the right operand is used.
+///   Hence, when the operands are equal, the derivative of the left operand
+///   is used. For numeric vectors, this choice is element wise.
+///
+/// * Branches :
+///   If E does not have an absolute zero, the derivative that is not chosen
+///   does not affect the result; e.g., it may be nan or infinite.
+///   This is done using [FValue::select](crate::FValue) in the same way as
+///   for [load](crate::op::vec_ad) .
+macro_rules! min_max_derivative { ($name:ident) => { paste::paste! {
+    #[doc = concat!(
+        " first order forward for ", stringify!( $name ),
+        "; see [ForwardDer](crate::op::info::ForwardDer)"
+    ) ]
+    fn [< $name _forward_der >]<V, E> (
+        dyp_all    :   &[E]        ,
+        var_all    :   &[E]        ,
+        var_der    :   &mut [E]    ,
+        const_data : ConstData<V> )
+    where
+        V             : Clone ,
+        E             : Clone + From<V> + FConst + crate::op::cexp::BranchSelect ,
+        for<'a> E     : AddAssign<&'a E> ,
+        for<'a> &'a E : FBinary<&'a E, Output = E> ,
+        for<'a> &'a E : Add<&'a E, Output = E> ,
+        for<'a> &'a E : Sub<&'a E, Output = E> ,
+        for<'a> &'a E : Mul<&'a E, Output = E> ,
+    {
+        let ConstData {cop, arg, arg_type, res, ..} = const_data;
+        debug_assert!( arg.len() == 2 );
+        //
+        let x      = crate::op::binary::common::lhs_value::<V, E>(
+            cop, dyp_all, var_all, arg, arg_type
+        );
+        let c      = FBinary::num_eq( &var_all[res], &x );
+        let not_c  = &E::one() - &c;
+        let mut dz = E::zero();
+        for (i_arg, factor) in [ (0, &c), (1, &not_c) ] {
+            if arg_type[i_arg].is_variable() {
+                let index = arg[i_arg] as usize;
+                dz       += &crate::op::cexp::select_mul::<E>(
+                    factor, &var_der[index]
+                );
+            }
+        }
+        var_der[res] = dz;
+    }
+    #[doc = concat!(
+        " first order reverse for ", stringify!( $name ),
+        "; see [ReverseDer](crate::op::info::ReverseDer)"
+    ) ]
+    fn [< $name _reverse_der >]<V, E> (
+        dyp_all    :   &[E]        ,
+        var_all    :   &[E]        ,
+        var_der    :   &mut [E]    ,
+        const_data : ConstData<V> )
+    where
+        V             : Clone ,
+        E             : Clone + From<V> + FConst + crate::op::cexp::BranchSelect ,
+        for<'a> E     : AddAssign<&'a E> ,
+        for<'a> &'a E : FBinary<&'a E, Output = E> ,
+        for<'a> &'a E : Add<&'a E, Output = E> ,
+        for<'a> &'a E : Sub<&'a E, Output = E> ,
+        for<'a> &'a E : Mul<&'a E, Output = E> ,
+    {
+        let ConstData {cop, arg, arg_type, res, ..} = const_data;
+        debug_assert!( arg.len() == 2 );
+        //
+        let x      = crate::op::binary::common::lhs_value::<V, E>(
+            cop, dyp_all, var_all, arg, arg_type
+        );
+        let c      = FBinary::num_eq( &var_all[res], &x );
+        let not_c  = &E::one() - &c;
+        for (i_arg, factor) in [ (0, &c), (1, &not_c) ] {
+            if arg_type[i_arg].is_variable() {
+                let index       = arg[i_arg] as usize;
+                let term        = crate::op::cexp::select_mul::<E>(
+                    factor, &var_der[res]
+                );
+                var_der[index] += &term;
+            }
+        }
+    }
+    #[doc = concat!(
+        " Taylor coefficients for ", stringify!( $name ),
+        "; see [ForwardTaylor](crate::op::info::ForwardTaylor)"
+    ) ]
+    fn [< $name _forward_taylor >]<V, E> (
+        dyp_all    :   &[E]        ,
+        var_tay    :   &mut [E]    ,
+        n_tay      :   usize       ,
+        const_data : ConstData<V> )
+    where
+        V             : Clone ,
+        E             : Clone + From<V> + FConst + crate::op::cexp::BranchSelect ,
+        for<'a> E     : AddAssign<&'a E> ,
+        for<'a> &'a E : FBinary<&'a E, Output = E> ,
+        for<'a> &'a E : Add<&'a E, Output = E> ,
+        for<'a> &'a E : Sub<&'a E, Output = E> ,
+        for<'a> &'a E : Mul<&'a E, Output = E> ,
+    {
+        let ConstData {cop, arg, arg_type, res, ..} = const_data;
+        debug_assert!( arg.len() == 2 );
+        debug_assert!( arg_type[0].is_variable() || arg_type[1].is_variable() );
+        //
+        let (before, z) = crate::op::taylor::split_var_tay(var_tay, n_tay, res);
+        let x = crate::op::taylor::arg_taylor::<V, E>(
+            dyp_all, cop, before, n_tay, arg[0], arg_type[0]
+        );
+        let y = crate::op::taylor::arg_taylor::<V, E>(
+            dyp_all, cop, before, n_tay, arg[1], arg_type[1]
+        );
+        let c     = FBinary::num_eq( &z[0], &x[0] );
+        for k in 1 .. n_tay {
+            z[k] = crate::op::cexp::select::<E>(&c, &x[k], &y[k]);
+        }
+    }
+    #[doc = concat!(
+        " Taylor coefficient partials for ", stringify!( $name ),
+        "; see [ReverseTaylor](crate::op::info::ReverseTaylor)"
+    ) ]
+    fn [< $name _reverse_taylor >]<V, E> (
+        dyp_all    :   &[E]        ,
+        var_tay    :   &[E]        ,
+        var_par    :   &mut [E]    ,
+        n_tay      :   usize       ,
+        const_data : ConstData<V> )
+    where
+        V             : Clone ,
+        E             : Clone + From<V> + FConst + crate::op::cexp::BranchSelect ,
+        for<'a> E     : AddAssign<&'a E> ,
+        for<'a> &'a E : FBinary<&'a E, Output = E> ,
+        for<'a> &'a E : Add<&'a E, Output = E> ,
+        for<'a> &'a E : Sub<&'a E, Output = E> ,
+        for<'a> &'a E : Mul<&'a E, Output = E> ,
+    {
+        let ConstData {cop, arg, arg_type, res, ..} = const_data;
+        debug_assert!( arg.len() == 2 );
+        debug_assert!( arg_type[0].is_variable() || arg_type[1].is_variable() );
+        //
+        let x = crate::op::taylor::arg_taylor::<V, E>(
+            dyp_all, cop, var_tay, n_tay, arg[0], arg_type[0]
+        );
+        let c            = FBinary::num_eq( &var_tay[res * n_tay], &x[0] );
+        let not_c        = &E::one() - &c;
+        let (before, pz) = crate::op::taylor::split_var_par(var_par, n_tay, res);
+        for (i_arg, factor) in [ (0, &c), (1, &not_c) ] {
+            if arg_type[i_arg].is_variable() {
+                let index = arg[i_arg] as usize;
+                for k in 0 .. n_tay {
+                    before[index * n_tay + k] +=
+                        &crate::op::cexp::select_mul::<E>(factor, &pz[k]);
+                }
+            }
+        }
+    }
+} } }
+pub(crate) use min_max_derivative;
+//
+// lhs_value
+/// Value of the left operand for a binary operator.
+pub(crate) fn lhs_value<V, E>(
+    cop      : &[V]      ,
+    dyp_all  : &[E]      ,
+    var_all  : &[E]      ,
+    arg      : &[IndexT] ,
+    arg_type : &[ADType] ,
+) -> E
+where
+    V : Clone ,
+    E : Clone + From<V> ,
+{   let lhs = arg[0] as usize;
+    match arg_type[0] {
+        ADType::ConstantP => E::from( cop[lhs].clone() ),
+        ADType::DynamicP  => dyp_all[lhs].clone(),
+        ADType::Variable  => var_all[lhs].clone(),
+        ADType::Empty     => panic!( "binary operator: empty argument type" ),
+    }
+}
@@ -38,13 +38,20 @@ use crate::{
 //
 use crate::op::binary::common;
 use crate::tape::sealed::ThisThreadTape;
+use crate::op::taylor::{
+    TaylorValue,
+    TaylorRef,
+};
 use crate::op::info::{
     OpFns,
 
     ConstData,
+    Linearity,
     panic_dyp,
     panic_var,
     panic_der,
+    panic_taylor,
+    panic_reverse_taylor,
 };
 use crate::op::id::{
     DIV_PP_OP,
@@ -60,6 +67,12 @@ use crate::op::info::{
 // -------------------------------------------------------------------------
 // div_rust_src
 common::binary_rust_src!(div);
+//
+// div_forward_taylor
+common::binary_forward_taylor!(div, E);
+//
+// div_reverse_taylor
+common::binary_reverse_taylor!(div, E);
 // -------------------------------------------------------------------------
 // div_forward_dyp
 // div_pv_forward_var
@@ -260,6 +273,10 @@ where
     V             : Clone + FConst ,
     for<'a> &'a V : FUnary<Output=V>,
     V             : PartialEq + ThisThreadTape ,
+    //
+    // forward_taylor
+    V             : TaylorValue ,
+    for<'a> &'a V : TaylorRef<'a, V> ,
 {
     op_fns_vec[DIV_PP_OP as usize] = OpFns{
         name              : "div_pp",
@@ -271,8 +288,13 @@ where
         forward_der_ad    : panic_der::<V, AD<V> >,
         reverse_der_value : panic_der::<V, V>,
         reverse_der_ad    : panic_der::<V, AD<V> >,
+        forward_taylor_value : panic_taylor::<V, V>,
+        forward_taylor_ad    : panic_taylor::<V, AD<V> >,
+        reverse_taylor_value : panic_reverse_taylor::<V, V>,
+        reverse_taylor_ad    : panic_reverse_taylor::<V, AD<V> >,
         rust_src          : div_rust_src,
         reverse_depend    : common::binary_reverse_depend,
+        linearity         : Linearity::Linear,
     };
     op_fns_vec[DIV_PV_OP as usize] = OpFns{
         name              : "div_pv",
@@ -284,8 +306,13 @@ where
         forward_der_ad    : div_pv_forward_der::<V, AD<V> >,
         reverse_der_value : div_pv_reverse_der::<V, V>,
         reverse_der_ad    : div_pv_reverse_der::<V, AD<V> >,
+        forward_taylor_value : div_forward_taylor::<V, V>,
+        forward_taylor_ad    : div_forward_taylor::<V, AD<V> >,
+        reverse_taylor_value : div_reverse_taylor::<V, V>,
+        reverse_taylor_ad    : div_reverse_taylor::<V, AD<V> >,
         rust_src          : div_rust_src,
         reverse_depend    : common::binary_reverse_depend,
+        linearity         : Linearity::Nonlinear,
     };
     op_fns_vec[DIV_VP_OP as usize] = OpFns{
         name              : "div_vp",
@@ -297,8 +324,13 @@ where
         forward_der_ad    : div_vp_forward_der::<V, AD<V> >,
         reverse_der_value : div_vp_reverse_der::<V, V>,
         reverse_der_ad    : div_vp_reverse_der::<V, AD<V> >,
+        forward_taylor_value : div_forward_taylor::<V, V>,
+        forward_taylor_ad    : div_forward_taylor::<V, AD<V> >,
+        reverse_taylor_value : div_reverse_taylor::<V, V>,
+        reverse_taylor_ad    : div_reverse_taylor::<V, AD<V> >,
         rust_src          : div_rust_src,
         reverse_depend    : common::binary_reverse_depend,
+        linearity         : Linearity::Linear,
     };
     op_fns_vec[DIV_VV_OP as usize] = OpFns{
         name              : "div_vv",
@@ -310,7 +342,12 @@ where
         forward_der_ad    : div_vv_forward_der::<V, AD<V> >,
         reverse_der_value : div_vv_reverse_der::<V, V>,
         reverse_der_ad    : div_vv_reverse_der::<V, AD<V> >,
+        forward_taylor_value : div_forward_taylor::<V, V>,
+        forward_taylor_ad    : div_forward_taylor::<V, AD<V> >,
+        reverse_taylor_value : div_reverse_taylor::<V, V>,
+        reverse_taylor_ad    : div_reverse_taylor::<V, AD<V> >,
         rust_src          : div_rust_src,
         reverse_depend    : common::binary_reverse_depend,
+        linearity         : Linearity::Quotient,
     };
 }
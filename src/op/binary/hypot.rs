@@ -23,12 +23,18 @@ use crate::ad::ADType;
 use crate::{
     AD,
     FConst,
+    FUnary,
     FBinary,
 };
 //
 use crate::op::binary::common;
+use crate::op::taylor::{
+    TaylorValue,
+    TaylorRef,
+};
 use crate::op::info::OpFns;
 use crate::op::info::ConstData;
+use crate::op::info::Linearity;
 use crate::op::id::HYPOT_OP;
 use crate::tape::sealed::ThisThreadTape;
 //
@@ -43,6 +49,12 @@ use crate::op::info::{
 // hypot_forward_var
 common::f_binary_function!(hypot);
 //
+// hypot_forward_taylor
+common::binary_forward_taylor!(hypot);
+//
+// hypot_reverse_taylor
+common::binary_reverse_taylor!(hypot, E);
+//
 // ---------------------------------------------------------------------------
 //
 // hypot_forward_der
@@ -131,6 +143,10 @@ where
     for<'a> &'a AD<V> : Div<&'a AD<V>, Output = AD<V>> ,
     //
     for<'a> &'a V     : FBinary<&'a V, Output = V>,
+    //
+    // forward_taylor
+    V             : TaylorValue ,
+    for<'a> &'a V : TaylorRef<'a, V> ,
 {
     op_fns_vec[HYPOT_OP as usize] = OpFns{
         name              : "hypot_pp",
@@ -142,7 +158,12 @@ where
         forward_der_ad    : hypot_forward_der::<V, AD<V> >,
         reverse_der_value : hypot_reverse_der::<V, V>,
         reverse_der_ad    : hypot_reverse_der::<V, AD<V> >,
+        forward_taylor_value : hypot_forward_taylor::<V, V>,
+        forward_taylor_ad    : hypot_forward_taylor::<V, AD<V> >,
+        reverse_taylor_value : hypot_reverse_taylor::<V, V>,
+        reverse_taylor_ad    : hypot_reverse_taylor::<V, AD<V> >,
         rust_src          : hypot_rust_src,
         reverse_depend    : common::binary_reverse_depend,
+        linearity         : Linearity::Nonlinear,
     };
 }
@@ -0,0 +1,171 @@
+// SPDX-License-Identifier: EPL-2.0 OR GPL-2.0-or-later
+// SPDX-FileCopyrightText: Bradley M. Bell <bradbell@seanet.com>
+// SPDX-FileContributor: 2026 Bradley M. Bell
+//
+//! Evaluate the Min operators
+//!
+//! Link to [parent module](super)
+//!
+//! * V : see [doc_generic_v](crate::doc_generic_v)
+//! * E : see [doc_generic_e](crate::adfn::doc_generic_e)
+//!
+//! * [op::id](crate::op::id)
+//!     * MIN_PP_OP : min(parameter, parameter)
+//!     * MIN_PV_OP : min(parameter, variable)
+//!     * MIN_VP_OP : min(variable, parameter)
+//!     * MIN_VV_OP : min(variable, variable)
+//!
+//! * arg
+//!     * arg\[0\]:  Variable or parameter index of left operand.
+//!     * arg\[1\]:  Variable or parameter index of right operand.
+//!
+//! * Derivative :
+//!   The derivative is the derivative of the operand that is equal to
+//!   the result. If the operands are equal, the derivative of the
+//!   left operand is used; see
+//!   [min_max_derivative](crate::op::binary::common::min_max_derivative) .
+// --------------------------------------------------------------------------
+// use
+//
+use std::ops::{
+    AddAssign,
+    Add,
+    Sub,
+    Mul,
+};
+//
+use crate::ad::ADType;
+use crate::{
+    AD,
+    FConst,
+    FBinary,
+};
+//
+use crate::op::binary::common;
+use crate::op::cexp::BranchSelect;
+use crate::tape::sealed::ThisThreadTape;
+use crate::op::info::{
+    OpFns,
+
+    ConstData,
+    Linearity,
+    panic_dyp,
+    panic_var,
+    panic_der,
+    panic_taylor,
+    panic_reverse_taylor,
+};
+use crate::op::id::{
+    MIN_PP_OP,
+    MIN_PV_OP,
+    MIN_VP_OP,
+    MIN_VV_OP,
+};
+// -------------------------------------------------------------------------
+// min_rust_src
+common::binary_rust_src!(min);
+// -------------------------------------------------------------------------
+// min_forward_dyp
+// min_pv_forward_var
+// min_vp_forward_var
+// min_vv_forward_var
+common::binary_arithmetic_function!(FBinary, min);
+// -------------------------------------------------------------------------
+// min_forward_der
+// min_reverse_der
+// min_forward_taylor
+// min_reverse_taylor
+common::min_max_derivative!(min);
+// ---------------------------------------------------------------------------
+// set_op_fns
+//
+/// Set the operator functions for all the Min operators.
+///
+/// * op_fns_vec :
+///   The map from [op::id](crate::op::id) to operator functions.
+///   The the map results for
+///   MIN_PP_OP, MIN_PV_OP, MIN_VP_OP, and MIN_VV_OP are set.
+pub fn set_op_fns<V>( op_fns_vec : &mut [OpFns<V>] )
+where
+    for<'a> &'a V : FBinary<&'a AD<V>, Output = AD<V> > ,
+    for<'a> &'a V : FBinary<&'a V, Output = V> ,
+    for<'a> &'a V : Add<&'a V, Output = V> ,
+    for<'a> &'a V : Sub<&'a V, Output = V> ,
+    for<'a> &'a V : Mul<&'a V, Output = V> ,
+        for<'a> V : AddAssign<&'a V>,
+                V : Clone + FConst + PartialEq + ThisThreadTape + BranchSelect,
+            AD<V> : BranchSelect,
+{
+    op_fns_vec[MIN_PP_OP as usize] = OpFns{
+        name              : "min_pp",
+        forward_dyp_value : min_forward_dyp::<V, V>,
+        forward_dyp_ad    : min_forward_dyp::<V, AD<V> >,
+        forward_var_value : panic_var::<V, V>,
+        forward_var_ad    : panic_var::<V, AD<V> >,
+        forward_der_value : panic_der::<V, V>,
+        forward_der_ad    : panic_der::<V, AD<V> >,
+        reverse_der_value : panic_der::<V, V>,
+        reverse_der_ad    : panic_der::<V, AD<V> >,
+        forward_taylor_value : panic_taylor::<V, V>,
+        forward_taylor_ad    : panic_taylor::<V, AD<V> >,
+        reverse_taylor_value : panic_reverse_taylor::<V, V>,
+        reverse_taylor_ad    : panic_reverse_taylor::<V, AD<V> >,
+        rust_src          : min_rust_src,
+        reverse_depend    : common::binary_reverse_depend,
+        linearity         : Linearity::Linear,
+    };
+    op_fns_vec[MIN_PV_OP as usize] = OpFns{
+        name              : "min_pv",
+        forward_dyp_value : panic_dyp::<V, V>,
+        forward_dyp_ad    : panic_dyp::<V, AD<V> >,
+        forward_var_value : min_pv_forward_var::<V, V>,
+        forward_var_ad    : min_pv_forward_var::<V, AD<V> >,
+        forward_der_value : min_forward_der::<V, V>,
+        forward_der_ad    : min_forward_der::<V, AD<V> >,
+        reverse_der_value : min_reverse_der::<V, V>,
+        reverse_der_ad    : min_reverse_der::<V, AD<V> >,
+        forward_taylor_value : min_forward_taylor::<V, V>,
+        forward_taylor_ad    : min_forward_taylor::<V, AD<V> >,
+        reverse_taylor_value : min_reverse_taylor::<V, V>,
+        reverse_taylor_ad    : min_reverse_taylor::<V, AD<V> >,
+        rust_src          : min_rust_src,
+        reverse_depend    : common::binary_reverse_depend,
+        linearity         : Linearity::Linear,
+    };
+    op_fns_vec[MIN_VP_OP as usize] = OpFns{
+        name              : "min_vp",
+        forward_dyp_value : panic_dyp::<V, V>,
+        forward_dyp_ad    : panic_dyp::<V, AD<V> >,
+        forward_var_value : min_vp_forward_var::<V, V>,
+        forward_var_ad    : min_vp_forward_var::<V, AD<V> >,
+        forward_der_value : min_forward_der::<V, V>,
+        forward_der_ad    : min_forward_der::<V, AD<V> >,
+        reverse_der_value : min_reverse_der::<V, V>,
+        reverse_der_ad    : min_reverse_der::<V, AD<V> >,
+        forward_taylor_value : min_forward_taylor::<V, V>,
+        forward_taylor_ad    : min_forward_taylor::<V, AD<V> >,
+        reverse_taylor_value : min_reverse_taylor::<V, V>,
+        reverse_taylor_ad    : min_reverse_taylor::<V, AD<V> >,
+        rust_src          : min_rust_src,
+        reverse_depend    : common::binary_reverse_depend,
+        linearity         : Linearity::Linear,
+    };
+    op_fns_vec[MIN_VV_OP as usize] = OpFns{
+        name              : "min_vv",
+        forward_dyp_value : panic_dyp::<V, V>,
+        forward_dyp_ad    : panic_dyp::<V, AD<V> >,
+        forward_var_value : min_vv_forward_var::<V, V>,
+        forward_var_ad    : min_vv_forward_var::<V, AD<V> >,
+        forward_der_value : min_forward_der::<V, V>,
+        forward_der_ad    : min_forward_der::<V, AD<V> >,
+        reverse_der_value : min_reverse_der::<V, V>,
+        reverse_der_ad    : min_reverse_der::<V, AD<V> >,
+        forward_taylor_value : min_forward_taylor::<V, V>,
+        forward_taylor_ad    : min_forward_taylor::<V, AD<V> >,
+        reverse_taylor_value : min_reverse_taylor::<V, V>,
+        reverse_taylor_ad    : min_reverse_taylor::<V, AD<V> >,
+        rust_src          : min_rust_src,
+        reverse_depend    : common::binary_reverse_depend,
+        linearity         : Linearity::Linear,
+    };
+}
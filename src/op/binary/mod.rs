@@ -11,6 +11,9 @@
 //! | ----- | ------- |
 //! | 0     | Variable, dynamic, or constant index for left hand side  |
 //! | 1     | Variable, dynamic, or constant index for right hand side |
+//!
+//! The numerical comparison operators have a third argument;
+//! see [num_cmp] .
 // ---------------------------------------------------------------------------
 // sub-modules
 pub mod common;
@@ -22,3 +25,5 @@ pub mod num_cmp;
 pub mod atan2;
 pub mod hypot;
 pub mod powf;
+pub mod min;
+pub mod max;
@@ -31,17 +31,25 @@ use crate::ad::ADType;
 use crate::{
     AD,
     FConst,
+    FUnary,
 };
 //
 use crate::op::binary::common;
 use crate::tape::sealed::ThisThreadTape;
+use crate::op::taylor::{
+    TaylorValue,
+    TaylorRef,
+};
 use crate::op::info::{
     OpFns,
 
     ConstData,
+    Linearity,
     panic_dyp,
     panic_var,
     panic_der,
+    panic_taylor,
+    panic_reverse_taylor,
 };
 use crate::op::id::{
     MUL_PP_OP,
@@ -59,6 +67,12 @@ use crate::op::info::{
 // -------------------------------------------------------------------------
 // mul_rust_src
 common::binary_rust_src!(mul);
+//
+// mul_forward_taylor
+common::binary_forward_taylor!(mul, E);
+//
+// mul_reverse_taylor
+common::binary_reverse_taylor!(mul, E);
 // -------------------------------------------------------------------------
 // forward_var
 // -------------------------------------------------------------------------
@@ -240,6 +254,10 @@ where
     for<'a> &'a V : Mul<&'a V, Output = V> ,
     V             : Clone + FConst ,
     V             : PartialEq + ThisThreadTape ,
+    //
+    // forward_taylor
+    V             : TaylorValue ,
+    for<'a> &'a V : TaylorRef<'a, V> ,
 {
     op_fns_vec[MUL_PP_OP as usize] = OpFns{
         name              : "mul_pp",
@@ -251,8 +269,13 @@ where
         forward_der_ad    : panic_der::<V, AD<V> >,
         reverse_der_value : panic_der::<V, V>,
         reverse_der_ad    : panic_der::<V, AD<V> >,
+        forward_taylor_value : panic_taylor::<V, V>,
+        forward_taylor_ad    : panic_taylor::<V, AD<V> >,
+        reverse_taylor_value : panic_reverse_taylor::<V, V>,
+        reverse_taylor_ad    : panic_reverse_taylor::<V, AD<V> >,
         rust_src          : mul_rust_src,
         reverse_depend    : common::binary_reverse_depend,
+        linearity         : Linearity::Linear,
     };
     op_fns_vec[MUL_PV_OP as usize] = OpFns{
         name              : "mul_pv",
@@ -264,8 +287,13 @@ where
         forward_der_ad    : mul_pv_forward_der::<V, AD<V> >,
         reverse_der_value : mul_pv_reverse_der::<V, V>,
         reverse_der_ad    : mul_pv_reverse_der::<V, AD<V> >,
+        forward_taylor_value : mul_forward_taylor::<V, V>,
+        forward_taylor_ad    : mul_forward_taylor::<V, AD<V> >,
+        reverse_taylor_value : mul_reverse_taylor::<V, V>,
+        reverse_taylor_ad    : mul_reverse_taylor::<V, AD<V> >,
         rust_src          : mul_rust_src,
         reverse_depend    : common::binary_reverse_depend,
+        linearity         : Linearity::Linear,
     };
     op_fns_vec[MUL_VP_OP as usize] = OpFns{
         name              : "mul_vp",
@@ -277,8 +305,13 @@ where
         forward_der_ad    : mul_vp_forward_der::<V, AD<V> >,
         reverse_der_value : mul_vp_reverse_der::<V, V>,
         reverse_der_ad    : mul_vp_reverse_der::<V, AD<V> >,
+        forward_taylor_value : mul_forward_taylor::<V, V>,
+        forward_taylor_ad    : mul_forward_taylor::<V, AD<V> >,
+        reverse_taylor_value : mul_reverse_taylor::<V, V>,
+        reverse_taylor_ad    : mul_reverse_taylor::<V, AD<V> >,
         rust_src          : mul_rust_src,
         reverse_depend    : common::binary_reverse_depend,
+        linearity         : Linearity::Linear,
     };
     op_fns_vec[MUL_VV_OP as usize] = OpFns{
         name              : "mul_vv",
@@ -290,7 +323,12 @@ where
         forward_der_ad    : mul_vv_forward_der::<V, AD<V> >,
         reverse_der_value : mul_vv_reverse_der::<V, V>,
         reverse_der_ad    : mul_vv_reverse_der::<V, AD<V> >,
+        forward_taylor_value : mul_forward_taylor::<V, V>,
+        forward_taylor_ad    : mul_forward_taylor::<V, AD<V> >,
+        reverse_taylor_value : mul_reverse_taylor::<V, V>,
+        reverse_taylor_ad    : mul_reverse_taylor::<V, AD<V> >,
         rust_src          : mul_rust_src,
         reverse_depend    : common::binary_reverse_depend,
+        linearity         : Linearity::Product,
     };
 }
@@ -5,6 +5,17 @@
 //! Utilities used by the comparison operators.
 //!
 //! Link to [parent module](super)
+//!
+//! # Operator Arguments
+//! | Index | Meaning |
+//! | ----- | ------- |
+//! | 0     | Variable, dynamic, or constant index for left hand side  |
+//! | 1     | Variable, dynamic, or constant index for right hand side |
+//! | 2     | Constant index for the result during recording           |
+//!
+//! The result during recording is used to detect when a comparison
+//! has a different result; see the compare_change key in
+//! [doc_forward_var](crate::adfn::forward_var::doc_forward_var) .
 // ---------------------------------------------------------------------------
 //
 use crate::{
@@ -16,6 +27,11 @@ use crate::ad::ADType;
 use crate::op::id;
 use crate::op::info::OpFns;
 use crate::op::info::ConstData;
+use crate::op::info::Linearity;
+use crate::op::no_op::{
+    no_op_taylor,
+    no_op_reverse_taylor,
+};
 use crate::op::binary::common;
 // ---------------------------------------------------------------------------
 // For each name, define
@@ -30,6 +46,14 @@ common::f_binary_function!( num_ne );
 common::f_binary_function!( num_ge );
 common::f_binary_function!( num_gt );
 // ---------------------------------------------------------------------------
+// is_num_cmp_op
+/// Is this a numerical comparison operator.
+pub(crate) fn is_num_cmp_op(op_id : u8) -> bool {
+    matches!( op_id,
+        id::LT_OP | id::LE_OP | id::EQ_OP | id::NE_OP | id::GE_OP | id::GT_OP
+    )
+}
+// ---------------------------------------------------------------------------
 // zero_forward_der
 fn zero_forward_der<V, E>  (
     _dyp_all  : &[E]        ,
@@ -81,8 +105,13 @@ where
         forward_der_ad    : zero_forward_der::<V, AD<V> >,
         reverse_der_value : zero_reverse_der::<V, V>,
         reverse_der_ad    : zero_reverse_der::<V, AD<V> >,
+        forward_taylor_value : no_op_taylor::<V, V>,
+        forward_taylor_ad    : no_op_taylor::<V, AD<V> >,
+        reverse_taylor_value : no_op_reverse_taylor::<V, V>,
+        reverse_taylor_ad    : no_op_reverse_taylor::<V, AD<V> >,
         rust_src          : num_lt_rust_src,
         reverse_depend    : common::binary_reverse_depend,
+        linearity         : Linearity::Linear,
     };
     op_fns_vec[id::LE_OP as usize] = OpFns{
         name              : "num_le",
@@ -94,8 +123,13 @@ where
         forward_der_ad    : zero_forward_der::<V, AD<V> >,
         reverse_der_value : zero_reverse_der::<V, V>,
         reverse_der_ad    : zero_reverse_der::<V, AD<V> >,
+        forward_taylor_value : no_op_taylor::<V, V>,
+        forward_taylor_ad    : no_op_taylor::<V, AD<V> >,
+        reverse_taylor_value : no_op_reverse_taylor::<V, V>,
+        reverse_taylor_ad    : no_op_reverse_taylor::<V, AD<V> >,
         rust_src          : num_le_rust_src,
         reverse_depend    : common::binary_reverse_depend,
+        linearity         : Linearity::Linear,
     };
     op_fns_vec[id::EQ_OP as usize] = OpFns{
         name              : "num_eq",
@@ -107,8 +141,13 @@ where
         forward_der_ad    : zero_forward_der::<V, AD<V> >,
         reverse_der_value : zero_reverse_der::<V, V>,
         reverse_der_ad    : zero_reverse_der::<V, AD<V> >,
+        forward_taylor_value : no_op_taylor::<V, V>,
+        forward_taylor_ad    : no_op_taylor::<V, AD<V> >,
+        reverse_taylor_value : no_op_reverse_taylor::<V, V>,
+        reverse_taylor_ad    : no_op_reverse_taylor::<V, AD<V> >,
         rust_src          : num_eq_rust_src,
         reverse_depend    : common::binary_reverse_depend,
+        linearity         : Linearity::Linear,
     };
     op_fns_vec[id::NE_OP as usize] = OpFns{
         name              : "num_ne",
@@ -120,8 +159,13 @@ where
         forward_der_ad    : zero_forward_der::<V, AD<V> >,
         reverse_der_value : zero_reverse_der::<V, V>,
         reverse_der_ad    : zero_reverse_der::<V, AD<V> >,
+        forward_taylor_value : no_op_taylor::<V, V>,
+        forward_taylor_ad    : no_op_taylor::<V, AD<V> >,
+        reverse_taylor_value : no_op_reverse_taylor::<V, V>,
+        reverse_taylor_ad    : no_op_reverse_taylor::<V, AD<V> >,
         rust_src          : num_ne_rust_src,
         reverse_depend    : common::binary_reverse_depend,
+        linearity         : Linearity::Linear,
     };
     op_fns_vec[id::GE_OP as usize] = OpFns{
         name              : "num_ge",
@@ -133,8 +177,13 @@ where
         forward_der_ad    : zero_forward_der::<V, AD<V> >,
         reverse_der_value : zero_reverse_der::<V, V>,
         reverse_der_ad    : zero_reverse_der::<V, AD<V> >,
+        forward_taylor_value : no_op_taylor::<V, V>,
+        forward_taylor_ad    : no_op_taylor::<V, AD<V> >,
+        reverse_taylor_value : no_op_reverse_taylor::<V, V>,
+        reverse_taylor_ad    : no_op_reverse_taylor::<V, AD<V> >,
         rust_src          : num_ge_rust_src,
         reverse_depend    : common::binary_reverse_depend,
+        linearity         : Linearity::Linear,
     };
     op_fns_vec[id::GT_OP as usize] = OpFns{
         name              : "num_gt",
@@ -146,7 +195,12 @@ where
         forward_der_ad    : zero_forward_der::<V, AD<V> >,
         reverse_der_value : zero_reverse_der::<V, V>,
         reverse_der_ad    : zero_reverse_der::<V, AD<V> >,
+        forward_taylor_value : no_op_taylor::<V, V>,
+        forward_taylor_ad    : no_op_taylor::<V, AD<V> >,
+        reverse_taylor_value : no_op_reverse_taylor::<V, V>,
+        reverse_taylor_ad    : no_op_reverse_taylor::<V, AD<V> >,
         rust_src          : num_gt_rust_src,
         reverse_depend    : common::binary_reverse_depend,
+        linearity         : Linearity::Linear,
     };
 }
@@ -28,8 +28,13 @@ use crate::{
 };
 //
 use crate::op::binary::common;
+use crate::op::taylor::{
+    TaylorValue,
+    TaylorRef,
+};
 use crate::op::info::OpFns;
 use crate::op::info::ConstData;
+use crate::op::info::Linearity;
 use crate::op::id::POWF_OP;
 use crate::tape::sealed::ThisThreadTape;
 //
@@ -43,6 +48,12 @@ use crate::op::info::{
 // powf_forward_dyp
 // powf_forward_var
 common::f_binary_function!(powf);
+//
+// powf_forward_taylor
+common::binary_forward_taylor!(powf);
+//
+// powf_reverse_taylor
+common::binary_reverse_taylor!(powf);
 // ---------------------------------------------------------------------------
 //
 // powf_forward_der
@@ -195,6 +206,10 @@ where
     for<'a> &'a AD<V> : FUnary<Output = AD<V>>,
     //
     for<'a> &'a V     : FBinary<&'a V, Output = V>,
+    //
+    // forward_taylor
+    V             : TaylorValue ,
+    for<'a> &'a V : TaylorRef<'a, V> ,
 {
     op_fns_vec[POWF_OP as usize] = OpFns{
         name              : "powf_pp",
@@ -206,7 +221,12 @@ where
         forward_der_ad    : powf_forward_der::<V, AD<V> >,
         reverse_der_value : powf_reverse_der::<V, V>,
         reverse_der_ad    : powf_reverse_der::<V, AD<V> >,
+        forward_taylor_value : powf_forward_taylor::<V, V>,
+        forward_taylor_ad    : powf_forward_taylor::<V, AD<V> >,
+        reverse_taylor_value : powf_reverse_taylor::<V, V>,
+        reverse_taylor_ad    : powf_reverse_taylor::<V, AD<V> >,
         rust_src          : powf_rust_src,
         reverse_depend    : common::binary_reverse_depend,
+        linearity         : Linearity::Nonlinear,
     };
 }
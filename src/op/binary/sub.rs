@@ -28,17 +28,25 @@ use crate::ad::ADType;
 use crate::{
     AD,
     FConst,
+    FUnary,
 };
 //
 use crate::op::binary::common;
 use crate::tape::sealed::ThisThreadTape;
+use crate::op::taylor::{
+    TaylorValue,
+    TaylorRef,
+};
 use crate::op::info::{
     OpFns,
 
     ConstData,
+    Linearity,
     panic_dyp,
     panic_var,
     panic_der,
+    panic_taylor,
+    panic_reverse_taylor,
 };
 use crate::op::id::{
     SUB_PP_OP,
@@ -54,6 +62,12 @@ use crate::op::info::{
 // -------------------------------------------------------------------------
 // sub_rust_src
 common::binary_rust_src!(sub);
+//
+// sub_forward_taylor
+common::binary_forward_taylor!(sub, E);
+//
+// sub_reverse_taylor
+common::binary_reverse_taylor!(sub, E);
 // -------------------------------------------------------------------------
 // sub_forward_dyp
 // sub_pv_forward_var
@@ -200,7 +214,11 @@ where
     for<'a> &'a V : Sub<&'a V, Output = V> + Sub<&'a AD<V>, Output = AD<V> > ,
     V             : Clone + FConst ,
     V             : PartialEq + ThisThreadTape ,
-    AD<V>         : From<V>
+    AD<V>         : From<V>,
+    //
+    // forward_taylor
+    V             : TaylorValue ,
+    for<'a> &'a V : TaylorRef<'a, V> ,
 {
     op_fns_vec[SUB_PP_OP as usize] = OpFns{
         name              : "sub_pp",
@@ -212,8 +230,13 @@ where
         forward_der_ad    : panic_der::<V, AD<V> >,
         reverse_der_value : panic_der::<V, V>,
         reverse_der_ad    : panic_der::<V, AD<V> >,
+        forward_taylor_value : panic_taylor::<V, V>,
+        forward_taylor_ad    : panic_taylor::<V, AD<V> >,
+        reverse_taylor_value : panic_reverse_taylor::<V, V>,
+        reverse_taylor_ad    : panic_reverse_taylor::<V, AD<V> >,
         rust_src          : sub_rust_src,
         reverse_depend    : common::binary_reverse_depend,
+        linearity         : Linearity::Linear,
     };
     op_fns_vec[SUB_PV_OP as usize] = OpFns{
         name              : "sub_pv",
@@ -225,8 +248,13 @@ where
         forward_der_ad    : sub_pv_forward_der::<V, AD<V>>,
         reverse_der_value : sub_pv_reverse_der::<V, V>,
         reverse_der_ad    : sub_pv_reverse_der::<V, AD<V> >,
+        forward_taylor_value : sub_forward_taylor::<V, V>,
+        forward_taylor_ad    : sub_forward_taylor::<V, AD<V> >,
+        reverse_taylor_value : sub_reverse_taylor::<V, V>,
+        reverse_taylor_ad    : sub_reverse_taylor::<V, AD<V> >,
         rust_src          : sub_rust_src,
         reverse_depend    : common::binary_reverse_depend,
+        linearity         : Linearity::Linear,
     };
     op_fns_vec[SUB_VP_OP as usize] = OpFns{
         name              : "sub_vp",
@@ -238,8 +266,13 @@ where
         forward_der_ad    : sub_vp_forward_der::<V, AD<V> >,
         reverse_der_value : sub_vp_reverse_der::<V, V>,
         reverse_der_ad    : sub_vp_reverse_der::<V, AD<V> >,
+        forward_taylor_value : sub_forward_taylor::<V, V>,
+        forward_taylor_ad    : sub_forward_taylor::<V, AD<V> >,
+        reverse_taylor_value : sub_reverse_taylor::<V, V>,
+        reverse_taylor_ad    : sub_reverse_taylor::<V, AD<V> >,
         rust_src          : sub_rust_src,
         reverse_depend    : common::binary_reverse_depend,
+        linearity         : Linearity::Linear,
     };
     op_fns_vec[SUB_VV_OP as usize] = OpFns{
         name              : "sub_vv",
@@ -251,7 +284,12 @@ where
         forward_der_ad    : sub_vv_forward_der::<V, AD<V> >,
         reverse_der_value : sub_vv_reverse_der::<V, V>,
         reverse_der_ad    : sub_vv_reverse_der::<V, AD<V> >,
+        forward_taylor_value : sub_forward_taylor::<V, V>,
+        forward_taylor_ad    : sub_forward_taylor::<V, AD<V> >,
+        reverse_taylor_value : sub_reverse_taylor::<V, V>,
+        reverse_taylor_ad    : sub_reverse_taylor::<V, AD<V> >,
         rust_src          : sub_rust_src,
         reverse_depend    : common::binary_reverse_depend,
+        linearity         : Linearity::Linear,
     };
 }
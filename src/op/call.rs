@@ -51,6 +51,7 @@ use crate::ad::ADType;
 use crate::tape::AGraph;
 use crate::op::info::OpFns;
 use crate::op::info::ConstData;
+use crate::op::info::Linearity;
 use crate::op::info::panic_reverse_depend;
 use crate::atom::sealed::GlobalAtomCallbackVec;
 use crate::op::id::{
@@ -750,6 +751,65 @@ where
         }
     }
 }
+//
+// call_reverse_taylor_value
+/// Call operator V evaluation of Taylor coefficient partials;
+/// see [ReverseTaylor](crate::op::info::ReverseTaylor)
+///
+/// Atomic functions only supply first order reverse mode, so this
+/// panics if n_tay is greater than one.
+fn call_reverse_taylor_value<V> (
+    dyp_all    : &[V]          ,
+    var_tay    : &[V]          ,
+    var_par    : &mut [V]      ,
+    n_tay      : usize         ,
+    const_data : ConstData<V> )
+where
+    for<'a> V       : PartialEq + GlobalAtomCallbackVec + AddAssign<&'a V>  + From<f32>,
+    AtomCallback<V> : Clone,
+{   //
+    if n_tay > 1 {
+        let atom_id  = const_data.arg[0] as usize;
+        let callback = get_callback::<V>(atom_id);
+        panic!(
+            "{} : atomic functions only support zero order Taylor partials",
+            callback.name,
+        );
+    }
+    // When n_tay is one, var_tay is the value of all the variables and
+    // var_par is the partial w.r.t. all the variables.
+    call_reverse_der_value(dyp_all, var_tay, var_par, const_data);
+}
+//
+// call_reverse_taylor_ad
+/// Call operator `AD<V>` evaluation of Taylor coefficient partials;
+/// see [ReverseTaylor](crate::op::info::ReverseTaylor)
+///
+/// Atomic functions only supply first order reverse mode, so this
+/// panics if n_tay is greater than one.
+fn call_reverse_taylor_ad<V> (
+    adyp_all   : &[ AD<V> ]          ,
+    avar_tay   : &[ AD<V> ]          ,
+    avar_par   : &mut [ AD<V> ]      ,
+    n_tay      : usize               ,
+    const_data : ConstData<V> )
+where
+    V                 : PartialEq + GlobalAtomCallbackVec + Clone + From<f32>,
+    for<'a> AD<V> : AddAssign<&'a AD<V> >,
+    AtomCallback<V>   : Clone,
+{   //
+    if n_tay > 1 {
+        let atom_id  = const_data.arg[0] as usize;
+        let callback = get_callback::<V>(atom_id);
+        panic!(
+            "{} : atomic functions only support zero order Taylor partials",
+            callback.name,
+        );
+    }
+    // When n_tay is one, avar_tay is the value of all the variables and
+    // avar_par is the partial w.r.t. all the variables.
+    call_reverse_der_ad(adyp_all, avar_tay, avar_par, const_data);
+}
 // --------------------------------------------------------------------------
 // call_reverse_der_ad
 /// Call operator `AD<V>` evaluation of reverse mode derivatives;
@@ -823,6 +883,193 @@ where
         }
     }
 }
+// ==========================================================================
+// call_forward_taylor
+// ==========================================================================
+//
+// call_forward_taylor_value
+/// Call operator V evaluation of Taylor coefficients;
+/// see [ForwardTaylor](crate::op::info::ForwardTaylor)
+///
+/// Atomic functions only supply first order forward mode, so this
+/// panics if n_tay is greater than two.
+fn call_forward_taylor_value<V> (
+    dyp_all    : &[V]          ,
+    var_tay    : &mut [V]      ,
+    n_tay      : usize         ,
+    const_data : ConstData<V> )
+where
+    V               : Clone + PartialEq + GlobalAtomCallbackVec + From<f32>,
+    AtomCallback<V> : Clone,
+{   //
+    let ConstData {cop, bool_all, arg, arg_type, res, ..} = const_data;
+    // ----------------------------------------------------------------------
+    let (
+        atom_id,
+        call_info,
+        n_dom,
+        n_rng,
+        trace,
+        rng_is_dep,
+    ) = extract_call_info(arg, bool_all);
+    let callback = get_callback(atom_id);
+    //
+    if n_tay < 2 {
+        return;
+    }
+    if n_tay > 2 {
+        panic!(
+            "{} : atomic functions only support first order Taylor coefficients",
+            callback.name,
+        );
+    }
+    //
+    // forward_der_value
+    let forward_der_value  = &callback.forward_der_value;
+    if forward_der_value.is_none() {
+        panic!(
+            "{} : forward_der_value not implemented for this atomic function",
+            callback.name,
+        );
+    }
+    let forward_der_value  = forward_der_value.unwrap();
+    //
+    // domain, domain_der
+    let zero_v : V = 0f32.into();
+    let mut domain     : Vec<&V> = Vec::with_capacity( n_dom );
+    let mut domain_der : Vec<&V> = Vec::with_capacity( n_dom );
+    for i_dom in 0 .. n_dom {
+        let index   = arg[BEGIN_DOM + i_dom] as usize;
+        let ad_type = arg_type[BEGIN_DOM + i_dom];
+        if ad_type.is_variable() {
+            domain.push( &var_tay[index * n_tay] );
+            domain_der.push( &var_tay[index * n_tay + 1] );
+        } else {
+            if ad_type.is_constant() {
+                domain.push( &cop[index] );
+            } else {
+                debug_assert!( ad_type.is_dynamic() );
+                domain.push( &dyp_all[index] );
+            }
+            domain_der.push( &zero_v );
+        }
+    }
+    // range_der
+    let result = forward_der_value(
+        rng_is_dep, &domain, &domain_der, call_info, trace
+    );
+    let mut range_der = match result {
+        Err(msg) => { panic!(
+            "atom {} forward_der_value error : {}", callback.name, msg);
+        },
+        Ok(range) => range,
+    };
+    assert_eq!( range_der.len(), n_rng);
+    //
+    // var_tay
+    let mut dep_index = 0;
+    for rng_index in 0 .. n_rng {
+        if rng_is_dep[rng_index] {
+            let index = (res + dep_index) * n_tay + 1;
+            swap( &mut var_tay[index], &mut range_der[rng_index] );
+            dep_index += 1;
+        }
+    }
+}
+//
+// call_forward_taylor_ad
+/// Call operator `AD<V>` evaluation of Taylor coefficients;
+/// see [ForwardTaylor](crate::op::info::ForwardTaylor)
+///
+/// Atomic functions only supply first order forward mode, so this
+/// panics if n_tay is greater than two.
+fn call_forward_taylor_ad<V> (
+    adyp_all   : &[ AD<V> ]          ,
+    avar_tay   : &mut [ AD<V> ]      ,
+    n_tay      : usize               ,
+    const_data : ConstData<V> )
+where
+    V               : PartialEq + From<f32> + Clone + GlobalAtomCallbackVec ,
+    AtomCallback<V> : Clone,
+{   //
+    let ConstData {cop, bool_all, arg, arg_type, res, ..} = const_data;
+    // ----------------------------------------------------------------------
+    let (
+        atom_id,
+        call_info,
+        n_dom,
+        n_rng,
+        trace,
+        rng_is_dep,
+    ) = extract_call_info(arg, bool_all);
+    let callback = get_callback(atom_id);
+    //
+    if n_tay < 2 {
+        return;
+    }
+    if n_tay > 2 {
+        panic!(
+            "{} : atomic functions only support first order Taylor coefficients",
+            callback.name,
+        );
+    }
+    //
+    // forward_der_ad
+    let forward_der_ad       = callback.forward_der_ad;
+    if forward_der_ad.is_none() {
+        panic!(
+            "{} : forward_der_ad is not implemented for this atomic function",
+            callback.name,
+        );
+    }
+    let forward_der_ad = forward_der_ad.unwrap();
+    //
+    // adomain, adomain_der
+    let acop       = domain_acop(cop, arg, arg_type, n_dom);
+    let zero_v : V = 0.0f32.into();
+    let azero      = AD::from(zero_v);
+    let mut j_cop  = 0;
+    let mut adomain     : Vec<& AD<V> > = Vec::with_capacity(n_dom);
+    let mut adomain_der : Vec<& AD<V> > = Vec::with_capacity(n_dom);
+    for i_dom in 0 .. n_dom {
+        let index   = arg[BEGIN_DOM + i_dom] as usize;
+        let ad_type = arg_type[BEGIN_DOM + i_dom];
+        if ad_type.is_variable() {
+            adomain.push( &avar_tay[index * n_tay] );
+            adomain_der.push( &avar_tay[index * n_tay + 1] );
+        } else {
+            if ad_type.is_constant() {
+                adomain.push( &acop[j_cop] );
+                j_cop += 1;
+            } else {
+                debug_assert!( ad_type.is_dynamic() );
+                adomain.push( &adyp_all[index] );
+            }
+            adomain_der.push( &azero );
+        }
+    }
+    // arange_der
+    let result = forward_der_ad(
+        rng_is_dep, &adomain, &adomain_der, call_info, trace
+    );
+    let mut arange_der = match result {
+        Err(msg) => { panic!(
+            "atom {} forward_der_ad error : {}", callback.name, msg);
+        },
+        Ok(range) => range,
+    };
+    assert_eq!( arange_der.len(), n_rng);
+    //
+    // avar_tay
+    let mut dep_index = 0;
+    for rng_index in 0 .. n_rng {
+        if rng_is_dep[rng_index] {
+            let index = (res + dep_index) * n_tay + 1;
+            swap( &mut avar_tay[index], &mut arange_der[rng_index] );
+            dep_index += 1;
+        }
+    }
+}
 // ---------------------------------------------------------------------------
 //
 // call_res_dyp
@@ -850,6 +1097,27 @@ fn call_res_der<V, E>(
     _const_data : ConstData<V> ,
 ) { }
 //
+// call_res_taylor
+/// [ForwardTaylor](crate::op::info::ForwardTaylor) function for
+/// call result operator
+fn call_res_taylor<V, E>(
+    _dyp_all  : &[E]        ,
+    _var_tay  : &mut [E]    ,
+    _n_tay    : usize       ,
+    _const_data : ConstData<V> ,
+) { }
+//
+// call_res_reverse_taylor
+/// [ReverseTaylor](crate::op::info::ReverseTaylor) function for
+/// call result operator
+fn call_res_reverse_taylor<V, E>(
+    _dyp_all  : &[E]        ,
+    _var_tay  : &[E]        ,
+    _var_par  : &mut [E]    ,
+    _n_tay    : usize       ,
+    _const_data : ConstData<V> ,
+) { }
+//
 // call_res_rust_src
 /// [RustSrc](crate::op::info::RustSrc) function for call result operator
 fn call_res_rust_src<V> (
@@ -1009,8 +1277,13 @@ where
         forward_der_ad    : call_forward_der_ad::<V>,
         reverse_der_value : call_reverse_der_value::<V>,
         reverse_der_ad    : call_reverse_der_ad::<V>,
+        forward_taylor_value : call_forward_taylor_value::<V>,
+        forward_taylor_ad    : call_forward_taylor_ad::<V>,
+        reverse_taylor_value : call_reverse_taylor_value::<V>,
+        reverse_taylor_ad    : call_reverse_taylor_ad::<V>,
         rust_src          : call_rust_src::<V>,
         reverse_depend    : panic_reverse_depend,
+        linearity         : Linearity::Nonlinear,
     };
     op_fns_vec[CALL_RES_OP as usize] = OpFns{
         name              : "call_res" ,
@@ -1022,8 +1295,13 @@ where
         forward_der_ad    : call_res_der::<V, AD<V> >,
         reverse_der_value : call_res_der::<V, V>,
         reverse_der_ad    : call_res_der::<V, AD<V> >,
+        forward_taylor_value : call_res_taylor::<V, V>,
+        forward_taylor_ad    : call_res_taylor::<V, AD<V> >,
+        reverse_taylor_value : call_res_reverse_taylor::<V, V>,
+        reverse_taylor_ad    : call_res_reverse_taylor::<V, AD<V> >,
         rust_src          : call_res_rust_src::<V>,
         reverse_depend    : panic_reverse_depend,
+        linearity         : Linearity::Nonlinear,
     };
 }
 // ===========================================================================
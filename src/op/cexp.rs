@@ -0,0 +1,587 @@
+// SPDX-License-Identifier: EPL-2.0 OR GPL-2.0-or-later
+// SPDX-FileCopyrightText: Bradley M. Bell <bradbell@seanet.com>
+// SPDX-FileContributor: 2026 Bradley M. Bell
+// --------------------------------------------------------------------------
+//! This module defines the conditional expression operator
+//!
+//! Link to [parent module](super)
+//!
+//! * Operator : CEXP_OP
+//!
+//! * Value :
+//!   The result is if_true (if_false) where the comparison
+//!   cond_lhs *cmp* cond_rhs is true (false).
+//!   This acts element wise for numeric vectors and
+//!   a nan (or infinity) in the branch that is not selected
+//!   does not affect the result.
+//!   If V has an absolute zero, and c is the result of the comparison
+//!   (zero or one), the result is computed as
+//!   c * if_true + (1 - c) * if_false .
+//!   Otherwise, the branch is chosen using [FValue::select](crate::FValue) ;
+//!   see [ABSOLUTE_ZERO](crate::FConst::ABSOLUTE_ZERO) .
+//!
+//! # Operator Arguments
+//! | Index | Meaning |
+//! | ----- | ------- |
+//! | 0     | comparison code: 0, ..., 5 for lt, le, eq, ne, ge, gt         |
+//! | 1     | Index in bool_all of first boolean for this operator          |
+//! | 2     | Variable, dynamic, or constant index for cond_lhs             |
+//! | 3     | Variable, dynamic, or constant index for cond_rhs             |
+//! | 4     | Variable, dynamic, or constant index for if_true              |
+//! | 5     | Variable, dynamic, or constant index for if_false             |
+//!
+//! # Operator Booleans
+//! | Index | Meaning |
+//! | ----- | ------- |
+//! | 0     | was if_true selected (for some element) during the recording  |
+//! | 1     | was if_false selected (for some element) during the recording |
+// --------------------------------------------------------------------------
+// use
+use std::ops::{
+    Add,
+    Sub,
+    Mul,
+    AddAssign,
+};
+use crate::{
+    AD,
+    FConst,
+    FValue,
+    FBinary,
+    IndexT,
+    CompareOp,
+};
+use crate::ad::ADType;
+use crate::ad::cexp::sealed::SelectValue;
+use crate::tape::sealed::ThisThreadTape;
+use crate::adfn::optimize;
+use crate::op::id::CEXP_OP;
+use crate::op::info::{
+    OpFns,
+    ConstData,
+    Linearity,
+};
+use crate::op::taylor;
+// --------------------------------------------------------------------------
+//
+// BEGIN_COND
+/// Index in the arguments of cond_lhs (cond_rhs follows it).
+pub(crate) const BEGIN_COND : usize = 2;
+//
+// BEGIN_BRANCH
+/// Index in the arguments of if_true (if_false follows it).
+pub(crate) const BEGIN_BRANCH : usize = 4;
+//
+// NUMBER_ARG
+/// Number of arguments for a CEXP_OP operator.
+pub(crate) const NUMBER_ARG : usize = 6;
+// --------------------------------------------------------------------------
+// compare
+/// Numerical comparison corresponding to a comparison code;
+/// see the [module](self) documentation.
+pub(crate) fn compare<E>(cmp : IndexT, left : &E, right : &E) -> E
+where
+    for<'a> &'a E : FBinary<&'a E, Output = E> ,
+{
+    match cmp {
+        0 => left.num_lt(right),
+        1 => left.num_le(right),
+        2 => left.num_eq(right),
+        3 => left.num_ne(right),
+        4 => left.num_ge(right),
+        5 => left.num_gt(right),
+        _ => panic!( "cexp: invalid comparison code {cmp}" ),
+    }
+}
+//
+// BranchSelect
+/// Choose between two values using the result of a comparison.
+pub trait BranchSelect : Sized {
+    /// Returns if_true where c is one and if_false where c is zero;
+    /// the value that is not chosen does not affect the result.
+    fn branch_select(c : &Self, if_true : &Self, if_false : &Self) -> Self;
+}
+impl<V> BranchSelect for V
+where
+    V : Clone + FValue ,
+{
+    fn branch_select(c : &V, if_true : &V, if_false : &V) -> V {
+        c.select(if_true, if_false)
+    }
+}
+impl<V> BranchSelect for AD<V>
+where
+    V     : Clone + FConst + ThisThreadTape + SelectValue ,
+    AD<V> : From<V> ,
+{
+    // records a conditional expression when c is a variable or dynamic
+    fn branch_select(
+        c : &AD<V>, if_true : &AD<V>, if_false : &AD<V>
+    ) -> AD<V> {
+        let one = AD::from( V::one() );
+        AD::select(c, &one, CompareOp::Eq, if_true, if_false)
+    }
+}
+//
+// select
+/// Returns if_true where c is one and if_false where c is zero .
+///
+/// If E has an absolute zero, this is computed as
+/// c * if_true + (1 - c) * if_false .
+pub(crate) fn select<E>(c : &E, if_true : &E, if_false : &E) -> E
+where
+    E             : FConst + BranchSelect ,
+    for<'a> &'a E : Add<&'a E, Output = E> ,
+    for<'a> &'a E : Sub<&'a E, Output = E> ,
+    for<'a> &'a E : Mul<&'a E, Output = E> ,
+{
+    if E::ABSOLUTE_ZERO {
+        let not_c = &E::one() - c;
+        &( c * if_true ) + &( &not_c * if_false )
+    } else {
+        E::branch_select(c, if_true, if_false)
+    }
+}
+//
+// select_mul
+/// Returns c * x where c is zero or one .
+///
+/// If E does not have an absolute zero, x is not multiplied by zero;
+/// i.e., the result is zero where c is zero even if x is nan or infinite.
+pub(crate) fn select_mul<E>(c : &E, x : &E) -> E
+where
+    E             : FConst + BranchSelect ,
+    for<'a> &'a E : Mul<&'a E, Output = E> ,
+{
+    if E::ABSOLUTE_ZERO {
+        c * x
+    } else {
+        E::branch_select(c, x, &E::zero())
+    }
+}
+//
+// cexp_use_arg
+/// Which of the arguments for a CEXP_OP operator are used.
+///
+/// * recorded :
+///   If this is false, all the arguments, except for the first two,
+///   are used. Otherwise, if_true (if_false) is only used if it was
+///   selected during the recording.
+pub(crate) fn cexp_use_arg(
+    bool_all : &[bool]   ,
+    arg      : &[IndexT] ,
+    recorded : bool      ,
+) -> [bool; NUMBER_ARG] {
+    debug_assert!( arg.len() == NUMBER_ARG );
+    let start = arg[1] as usize;
+    let mut use_arg = [ false, false, true, true, true, true ];
+    if recorded {
+        use_arg[BEGIN_BRANCH]     = bool_all[start];
+        use_arg[BEGIN_BRANCH + 1] = bool_all[start + 1];
+    }
+    use_arg
+}
+//
+// arg_value
+/// Value of one of the arguments for a CEXP_OP operator.
+fn arg_value<V, E>(
+    cop      : &[V]   ,
+    dyp_all  : &[E]   ,
+    var_all  : &[E]   ,
+    index    : IndexT ,
+    ad_type  : ADType ,
+) -> E
+where
+    V : Clone ,
+    E : Clone + From<V> ,
+{   let index = index as usize;
+    match ad_type {
+        ADType::ConstantP => E::from( cop[index].clone() ),
+        ADType::DynamicP  => dyp_all[index].clone(),
+        ADType::Variable  => var_all[index].clone(),
+        ADType::Empty     => panic!( "cexp: empty argument type" ),
+    }
+}
+//
+// condition
+/// Value of the comparison for a CEXP_OP operator.
+fn condition<V, E>(
+    cop      : &[V]      ,
+    dyp_all  : &[E]      ,
+    var_all  : &[E]      ,
+    arg      : &[IndexT] ,
+    arg_type : &[ADType] ,
+) -> E
+where
+    V             : Clone ,
+    E             : Clone + From<V> ,
+    for<'a> &'a E : FBinary<&'a E, Output = E> ,
+{   let mut cond : Vec<E> = Vec::with_capacity(2);
+    for i_arg in BEGIN_COND .. BEGIN_COND + 2 {
+        cond.push( arg_value::<V, E>(
+            cop, dyp_all, var_all, arg[i_arg], arg_type[i_arg]
+        ) );
+    }
+    compare::<E>(arg[0], &cond[0], &cond[1])
+}
+// --------------------------------------------------------------------------
+// cexp_forward_dyp
+/// E evaluation of a conditional expression with dynamic result;
+/// see [ForwardDyp](crate::op::info::ForwardDyp)
+fn cexp_forward_dyp<V, E> (
+    dyp_all    : &mut [E]      ,
+    const_data : ConstData<V>  )
+where
+    V             : Clone ,
+    E             : Clone + From<V> + FConst + BranchSelect ,
+    for<'a> &'a E : FBinary<&'a E, Output = E> ,
+    for<'a> &'a E : Add<&'a E, Output = E> ,
+    for<'a> &'a E : Sub<&'a E, Output = E> ,
+    for<'a> &'a E : Mul<&'a E, Output = E> ,
+{   //
+    let ConstData {cop, arg, arg_type, res, ..} = const_data;
+    debug_assert!( arg.len() == NUMBER_ARG );
+    debug_assert!( ! arg_type.iter().any( |t| t.is_variable() ) );
+    //
+    let var_all : &[E] = &[];
+    let c        = condition::<V, E>(cop, dyp_all, var_all, arg, arg_type);
+    let if_true  = arg_value::<V, E>(
+        cop, dyp_all, var_all, arg[BEGIN_BRANCH], arg_type[BEGIN_BRANCH]
+    );
+    let if_false = arg_value::<V, E>(
+        cop, dyp_all, var_all, arg[BEGIN_BRANCH+1], arg_type[BEGIN_BRANCH+1]
+    );
+    dyp_all[res] = select::<E>(&c, &if_true, &if_false);
+}
+//
+// cexp_forward_var
+/// E evaluation of a conditional expression with variable result;
+/// see [ForwardVar](crate::op::info::ForwardVar)
+fn cexp_forward_var<V, E> (
+    dyp_all    : &[E]          ,
+    var_all    : &mut [E]      ,
+    const_data : ConstData<V>  )
+where
+    V             : Clone ,
+    E             : Clone + From<V> + FConst + BranchSelect ,
+    for<'a> &'a E : FBinary<&'a E, Output = E> ,
+    for<'a> &'a E : Add<&'a E, Output = E> ,
+    for<'a> &'a E : Sub<&'a E, Output = E> ,
+    for<'a> &'a E : Mul<&'a E, Output = E> ,
+{   //
+    let ConstData {cop, arg, arg_type, res, ..} = const_data;
+    debug_assert!( arg.len() == NUMBER_ARG );
+    //
+    let c        = condition::<V, E>(cop, dyp_all, var_all, arg, arg_type);
+    let if_true  = arg_value::<V, E>(
+        cop, dyp_all, var_all, arg[BEGIN_BRANCH], arg_type[BEGIN_BRANCH]
+    );
+    let if_false = arg_value::<V, E>(
+        cop, dyp_all, var_all, arg[BEGIN_BRANCH+1], arg_type[BEGIN_BRANCH+1]
+    );
+    var_all[res] = select::<E>(&c, &if_true, &if_false);
+}
+// --------------------------------------------------------------------------
+// src_operand
+/// Rust source for a reference to one of the arguments.
+fn src_operand(
+    index     : IndexT ,
+    ad_type   : ADType ,
+    dyp_n_dom : usize  ,
+    var_n_dom : usize  ,
+) -> String {
+    let index = index as usize;
+    match ad_type {
+        ADType::ConstantP => format!("&cop[{index}]"),
+        ADType::DynamicP  => if index < dyp_n_dom {
+            format!("dyp_dom[{index}]")
+        } else {
+            format!("&dyp_dep[{}]", index - dyp_n_dom)
+        },
+        ADType::Variable  => if index < var_n_dom {
+            format!("var_dom[{index}]")
+        } else {
+            format!("&var_dep[{}]", index - var_n_dom)
+        },
+        ADType::Empty     => panic!( "cexp_rust_src: empty argument type" ),
+    }
+}
+//
+// cexp_rust_src
+/// rust source for a conditional expression;
+/// see [RustSrc](crate::op::info::RustSrc)
+fn cexp_rust_src<V> (
+    res_type    : ADType      ,
+    dyp_n_dom   : usize       ,
+    var_n_dom   : usize       ,
+    const_data : ConstData<V> ) -> String
+{   //
+    let ConstData {arg, arg_type, res, ..} = const_data;
+    debug_assert!( arg.len() == NUMBER_ARG );
+    //
+    // operand
+    let mut operand : Vec<String> = Vec::with_capacity(4);
+    for i_arg in BEGIN_COND .. NUMBER_ARG {
+        operand.push(
+            src_operand(arg[i_arg], arg_type[i_arg], dyp_n_dom, var_n_dom)
+        );
+    }
+    //
+    // cmp_name
+    let cmp_name = match arg[0] {
+        0 => "num_lt",
+        1 => "num_le",
+        2 => "num_eq",
+        3 => "num_ne",
+        4 => "num_ge",
+        5 => "num_gt",
+        _ => panic!( "cexp_rust_src: invalid comparison code" ),
+    };
+    //
+    // res_str
+    let res_str = if res_type.is_dynamic() {
+        format!("dyp_dep[{}]", res - dyp_n_dom)
+    } else {
+        debug_assert!( res_type.is_variable() );
+        format!("var_dep[{}]", res - var_n_dom)
+    };
+    //
+    // src
+    String::from("   ") + &res_str + " = {\n" +
+        "      let c     = (" + &operand[0] + ")." + cmp_name +
+            "(" + &operand[1] + ");\n" +
+        "      if V::ABSOLUTE_ZERO {\n" +
+        "         let not_c = &V::one() - &c;\n" +
+        "         &( &c * " + &operand[2] + " ) + " +
+            "&( &not_c * " + &operand[3] + " )\n" +
+        "      } else {\n" +
+        "         FValue::select(&c, " + &operand[2] + ", " +
+            &operand[3] + ")\n" +
+        "      }\n" +
+        "   };\n"
+}
+// --------------------------------------------------------------------------
+// cexp_depend
+/// Reverse dependency analysis for conditional expressions.
+///
+/// * recorded : see [cexp_use_arg]
+pub(crate) fn cexp_depend(
+    depend    : &mut optimize::Depend ,
+    bool_all  : &[bool]               ,
+    arg       : &[IndexT]             ,
+    arg_type  : &[ADType]             ,
+    recorded  : bool                  ,
+) { //
+    debug_assert_eq!(arg.len(), NUMBER_ARG);
+    debug_assert_eq!(arg_type.len(), NUMBER_ARG);
+    //
+    let use_arg = cexp_use_arg(bool_all, arg, recorded);
+    for i_arg in BEGIN_COND .. NUMBER_ARG {
+        if use_arg[i_arg] {
+            let index = arg[i_arg] as usize;
+            match arg_type[i_arg] {
+                ADType::ConstantP => { depend.cop[index] = true; },
+                ADType::DynamicP  => { depend.dyp[index] = true; },
+                ADType::Variable  => { depend.var[index] = true; },
+                _ => { panic!("in cexp operator reverse_depend"); },
+            }
+        }
+    }
+}
+//
+// cexp_reverse_depend
+/// Reverse dependency analysis for conditional expressions
+/// (both branches are used);
+/// see [ReverseDepend](crate::op::info::ReverseDepend)
+fn cexp_reverse_depend(
+    depend    : &mut optimize::Depend ,
+    bool_all  : &[bool]               ,
+    arg       : &[IndexT]             ,
+    arg_type  : &[ADType]             ,
+    _res      : usize                 ,
+    _res_type : ADType                ,
+) { cexp_depend(depend, bool_all, arg, arg_type, false); }
+// --------------------------------------------------------------------------
+// cexp_forward_der
+/// First order forward mode for conditional expressions;
+/// see [ForwardDer](crate::op::info::ForwardDer)
+fn cexp_forward_der<V, E>(
+    dyp_all    :   &[E]        ,
+    var_all    :   &[E]        ,
+    var_der    :   &mut [E]    ,
+    const_data : ConstData<V> )
+where
+    V             : Clone ,
+    E             : Clone + From<V> + FConst + BranchSelect ,
+    for<'a> E     : AddAssign<&'a E> ,
+    for<'a> &'a E : FBinary<&'a E, Output = E> ,
+    for<'a> &'a E : Sub<&'a E, Output = E> ,
+    for<'a> &'a E : Mul<&'a E, Output = E> ,
+{
+    let ConstData {cop, arg, arg_type, res, ..} = const_data;
+    debug_assert!( arg.len() == NUMBER_ARG );
+    //
+    let c      = condition::<V, E>(cop, dyp_all, var_all, arg, arg_type);
+    let not_c  = &E::one() - &c;
+    let mut dz = E::zero();
+    for (i_arg, factor) in [ (BEGIN_BRANCH, &c), (BEGIN_BRANCH+1, &not_c) ] {
+        if arg_type[i_arg].is_variable() {
+            let index = arg[i_arg] as usize;
+            dz       += &select_mul::<E>(factor, &var_der[index]);
+        }
+    }
+    var_der[res] = dz;
+}
+//
+// cexp_reverse_der
+/// First order reverse mode for conditional expressions;
+/// see [ReverseDer](crate::op::info::ReverseDer)
+fn cexp_reverse_der<V, E>(
+    dyp_all    :   &[E]        ,
+    var_all    :   &[E]        ,
+    var_der    :   &mut [E]    ,
+    const_data : ConstData<V> )
+where
+    V             : Clone ,
+    E             : Clone + From<V> + FConst + BranchSelect ,
+    for<'a> E     : AddAssign<&'a E> ,
+    for<'a> &'a E : FBinary<&'a E, Output = E> ,
+    for<'a> &'a E : Sub<&'a E, Output = E> ,
+    for<'a> &'a E : Mul<&'a E, Output = E> ,
+{
+    let ConstData {cop, arg, arg_type, res, ..} = const_data;
+    debug_assert!( arg.len() == NUMBER_ARG );
+    //
+    let c      = condition::<V, E>(cop, dyp_all, var_all, arg, arg_type);
+    let not_c  = &E::one() - &c;
+    for (i_arg, factor) in [ (BEGIN_BRANCH, &c), (BEGIN_BRANCH+1, &not_c) ] {
+        if arg_type[i_arg].is_variable() {
+            let index       = arg[i_arg] as usize;
+            let term        = select_mul::<E>(factor, &var_der[res]);
+            var_der[index] += &term;
+        }
+    }
+}
+//
+// cexp_forward_taylor
+/// Arbitrary order forward mode for conditional expressions;
+/// see [ForwardTaylor](crate::op::info::ForwardTaylor)
+fn cexp_forward_taylor<V, E>(
+    dyp_all    :   &[E]        ,
+    var_tay    :   &mut [E]    ,
+    n_tay      :   usize       ,
+    const_data : ConstData<V> )
+where
+    V             : Clone ,
+    E             : Clone + From<V> + FConst + BranchSelect ,
+    for<'a> E     : AddAssign<&'a E> ,
+    for<'a> &'a E : FBinary<&'a E, Output = E> ,
+    for<'a> &'a E : Sub<&'a E, Output = E> ,
+    for<'a> &'a E : Mul<&'a E, Output = E> ,
+{
+    let ConstData {cop, arg, arg_type, res, ..} = const_data;
+    debug_assert!( arg.len() == NUMBER_ARG );
+    //
+    let (before, z) = taylor::split_var_tay(var_tay, n_tay, res);
+    let mut cond : Vec<E> = Vec::with_capacity(2);
+    for i_arg in BEGIN_COND .. BEGIN_COND + 2 {
+        let x = taylor::arg_taylor::<V, E>(
+            dyp_all, cop, before, n_tay, arg[i_arg], arg_type[i_arg]
+        );
+        cond.push( x[0].clone() );
+    }
+    let c     = compare::<E>(arg[0], &cond[0], &cond[1]);
+    let not_c = &E::one() - &c;
+    for z_k in z.iter_mut().skip(1) {
+        *z_k = E::zero();
+    }
+    for (i_arg, factor) in [ (BEGIN_BRANCH, &c), (BEGIN_BRANCH+1, &not_c) ] {
+        if arg_type[i_arg].is_variable() {
+            let index = arg[i_arg] as usize;
+            for k in 1 .. n_tay {
+                z[k] += &select_mul::<E>(factor, &before[index * n_tay + k]);
+            }
+        }
+    }
+}
+//
+// cexp_reverse_taylor
+/// Arbitrary order reverse mode for conditional expressions;
+/// see [ReverseTaylor](crate::op::info::ReverseTaylor)
+fn cexp_reverse_taylor<V, E>(
+    dyp_all    :   &[E]        ,
+    var_tay    :   &[E]        ,
+    var_par    :   &mut [E]    ,
+    n_tay      :   usize       ,
+    const_data : ConstData<V> )
+where
+    V             : Clone ,
+    E             : Clone + From<V> + FConst + BranchSelect ,
+    for<'a> E     : AddAssign<&'a E> ,
+    for<'a> &'a E : FBinary<&'a E, Output = E> ,
+    for<'a> &'a E : Sub<&'a E, Output = E> ,
+    for<'a> &'a E : Mul<&'a E, Output = E> ,
+{
+    let ConstData {cop, arg, arg_type, res, ..} = const_data;
+    debug_assert!( arg.len() == NUMBER_ARG );
+    //
+    let mut cond : Vec<E> = Vec::with_capacity(2);
+    for i_arg in BEGIN_COND .. BEGIN_COND + 2 {
+        let x = taylor::arg_taylor::<V, E>(
+            dyp_all, cop, var_tay, n_tay, arg[i_arg], arg_type[i_arg]
+        );
+        cond.push( x[0].clone() );
+    }
+    let c            = compare::<E>(arg[0], &cond[0], &cond[1]);
+    let not_c        = &E::one() - &c;
+    let (before, pz) = taylor::split_var_par(var_par, n_tay, res);
+    for (i_arg, factor) in [ (BEGIN_BRANCH, &c), (BEGIN_BRANCH+1, &not_c) ] {
+        if arg_type[i_arg].is_variable() {
+            let index = arg[i_arg] as usize;
+            for k in 0 .. n_tay {
+                before[index * n_tay + k] += &select_mul::<E>(factor, &pz[k]);
+            }
+        }
+    }
+}
+// --------------------------------------------------------------------------
+// set_op_fns
+/// Set the operator functions for the CEXP_OP operator.
+///
+/// * op_fns_vec :
+///   The map from [op::id](crate::op::id) to operator functions.
+///   The the map results for CEXP_OP are set.
+pub fn set_op_fns<V>( op_fns_vec : &mut [OpFns<V>] )
+where
+    V                 : Clone + FConst + BranchSelect ,
+    AD<V>             : From<V> + FConst + BranchSelect ,
+    for<'a> V         : AddAssign<&'a V> ,
+    for<'a> AD<V>     : AddAssign<&'a AD<V> > ,
+    for<'a> &'a V     : FBinary<&'a V, Output = V> ,
+    for<'a> &'a V     : Add<&'a V, Output = V> ,
+    for<'a> &'a V     : Sub<&'a V, Output = V> ,
+    for<'a> &'a V     : Mul<&'a V, Output = V> ,
+    for<'a> &'a AD<V> : FBinary<&'a AD<V>, Output = AD<V> > ,
+    for<'a> &'a AD<V> : Add<&'a AD<V>, Output = AD<V> > ,
+    for<'a> &'a AD<V> : Sub<&'a AD<V>, Output = AD<V> > ,
+    for<'a> &'a AD<V> : Mul<&'a AD<V>, Output = AD<V> > ,
+{
+    op_fns_vec[CEXP_OP as usize] = OpFns{
+        name              : "cexp",
+        forward_dyp_value : cexp_forward_dyp::<V, V>,
+        forward_dyp_ad    : cexp_forward_dyp::<V, AD<V> >,
+        forward_var_value : cexp_forward_var::<V, V>,
+        forward_var_ad    : cexp_forward_var::<V, AD<V> >,
+        forward_der_value : cexp_forward_der::<V, V>,
+        forward_der_ad    : cexp_forward_der::<V, AD<V> >,
+        reverse_der_value : cexp_reverse_der::<V, V>,
+        reverse_der_ad    : cexp_reverse_der::<V, AD<V> >,
+        forward_taylor_value : cexp_forward_taylor::<V, V>,
+        forward_taylor_ad    : cexp_forward_taylor::<V, AD<V> >,
+        reverse_taylor_value : cexp_reverse_taylor::<V, V>,
+        reverse_taylor_ad    : cexp_reverse_taylor::<V, AD<V> >,
+        rust_src          : cexp_rust_src,
+        reverse_depend    : cexp_reverse_depend,
+        linearity         : Linearity::Linear,
+    };
+}
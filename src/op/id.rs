@@ -7,7 +7,7 @@
 //! Link to [parent module](super)
 //
 // check_binary_op_id
-/// For name equal ADD, SUB, MUL, DIV, check that:
+/// For name equal ADD, SUB, MUL, DIV, MIN, MAX, check that:
 /// ```text
 ///     name_PV_OP == name_PP_OP + 1
 ///     name_VP_OP == name_PP_OP + 2
@@ -33,6 +33,14 @@ const _ : () = {
     assert!( DIV_PV_OP == DIV_PP_OP + 1 );
     assert!( DIV_VP_OP == DIV_PP_OP + 2 );
     assert!( DIV_VV_OP == DIV_PP_OP + 3 );
+    // MIN
+    assert!( MIN_PV_OP == MIN_PP_OP + 1 );
+    assert!( MIN_VP_OP == MIN_PP_OP + 2 );
+    assert!( MIN_VV_OP == MIN_PP_OP + 3 );
+    // MAX
+    assert!( MAX_PV_OP == MAX_PP_OP + 1 );
+    assert!( MAX_VP_OP == MAX_PP_OP + 2 );
+    assert!( MAX_VV_OP == MAX_PP_OP + 3 );
 };
 //
 // set_operator_ids
@@ -74,6 +82,38 @@ macro_rules! set_operator_ids {
 // This ensures that the number of operators is less that u8::MAX.
 set_operator_ids!(
     // Unary Operators
+    /// ceil
+    CEIL_OP,
+    /// floor
+    FLOOR_OP,
+    /// round
+    ROUND_OP,
+    /// trunc
+    TRUNC_OP,
+    /// cbrt
+    CBRT_OP,
+    /// exp2
+    EXP2_OP,
+    /// log10
+    LOG10_OP,
+    /// log2
+    LOG2_OP,
+    /// erf
+    ERF_OP,
+    /// erfc
+    ERFC_OP,
+    /// acos
+    ACOS_OP,
+    /// acosh
+    ACOSH_OP,
+    /// asin
+    ASIN_OP,
+    /// asinh
+    ASINH_OP,
+    /// atan
+    ATAN_OP,
+    /// atanh
+    ATANH_OP,
     /// square
     SQUARE_OP,
     /// ln_1p
@@ -167,10 +207,52 @@ set_operator_ids!(
     /// powf(lhs, rhs)
     POWF_OP,
     //
+    // MIN
+    /// min(parameter, parameter)
+    MIN_PP_OP,
+    /// min(parameter, variable)
+    MIN_PV_OP,
+    /// min(variable, parameter)
+    MIN_VP_OP,
+    /// min(variable, variable)
+    MIN_VV_OP,
+    //
+    // MAX
+    /// max(parameter, parameter)
+    MAX_PP_OP,
+    /// max(parameter, variable)
+    MAX_PV_OP,
+    /// max(variable, parameter)
+    MAX_VP_OP,
+    /// max(variable, variable)
+    MAX_VV_OP,
+    //
+    // REDUCE
+    /// sum of the elements of a vector
+    REDUCE_SUM_OP,
+    /// maximum of the elements of a vector
+    REDUCE_MAX_OP,
+    /// number of elements in a vector
+    REDUCE_LEN_OP,
+    //
+    // CEXP
+    /// conditional expression; i.e., AD::select
+    CEXP_OP,
+    //
+    // VEC_AD
+    /// load an element of a VecAD vector
+    LOAD_OP,
+    /// store an element of a VecAD vector
+    STORE_OP,
+    //
     // ZERO_ONE
     /// is_zero or is_one
     ZERO_ONE_OP,
     //
+    // PRINT
+    /// print_on_forward or print_on_forward_if
+    PRINT_OP,
+    //
     // CALL
     /// callback to an atomic function
     CALL_OP,
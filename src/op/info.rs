@@ -182,6 +182,85 @@ pub(crate) fn panic_der<V, E>  (
     _const_data : ConstData<V> ,
 ) { panic!(); }
 // ---------------------------------------------------------------------------
+// ForwardTaylor
+/// Evaluation of Taylor coefficients of arbitrary order.
+///
+/// * dyp_all :
+///   contains the value of all the dynamic parameters.
+///
+/// * var_tay :
+///   The Taylor coefficient of order k for variable i_var is
+///   var_tay\[ i_var * n_tay + k \] .
+///   The zero order coefficients are inputs for all the variables
+///   (they were computed by zero order forward mode).
+///   The other coefficients are inputs for i_var < res and outputs
+///   for the results of this operator.
+///
+/// * n_tay :
+///   is the number of Taylor coefficients for each variable; i.e.,
+///   one plus the highest order being computed.
+///
+/// * const_data :  see [ConstData]
+pub(crate) type ForwardTaylor<V, E> = fn(
+    _dyp_all  : &[E]        ,
+    _var_tay  : &mut [E]    ,
+    _n_tay    : usize       ,
+    _const_data : ConstData<V> ,
+);
+//
+// panic_taylor
+/// Default [ForwardTaylor] function will panic.
+/// This can be used for operators that only have parameter arguments
+/// (because they should not be in the variable acyclic graph).
+pub(crate) fn panic_taylor<V, E>  (
+    _dyp_all  : &[E]        ,
+    _var_tay  : &mut [E]    ,
+    _n_tay    : usize       ,
+    _const_data : ConstData<V> ,
+) { panic!(); }
+//
+// ReverseTaylor
+/// Evaluation of partials of Taylor coefficients (reverse mode).
+///
+/// * dyp_all :
+///   contains the value of all the dynamic parameters.
+///
+/// * var_tay :
+///   contains the Taylor coefficients for all the variables; see [ForwardTaylor].
+///
+/// * var_par :
+///   A scalar function is defined by a weighted sum of the highest order
+///   Taylor coefficients for the range components.
+///   The partial of the scalar w.r.t. the order k coefficient of
+///   variable i_var is var_par\[ i_var * n_tay + k \] .
+///   On input, var_par contains the partials as a function of the
+///   variables i_var <= res + n_res - 1
+///   (where n_res is the number of results for the current operator).
+///   On output, var_par contains the partials as a function of the
+///   variables i_var < res.
+///
+/// * n_tay :
+///   is the number of Taylor coefficients for each variable.
+///
+/// * const_data :  see [ConstData]
+pub(crate) type ReverseTaylor<V, E> = fn(
+    _dyp_all  : &[E]        ,
+    _var_tay  : &[E]        ,
+    _var_par  : &mut [E]    ,
+    _n_tay    : usize       ,
+    _const_data : ConstData<V> ,
+);
+//
+// panic_reverse_taylor
+/// Default [ReverseTaylor] function will panic.
+pub(crate) fn panic_reverse_taylor<V, E>  (
+    _dyp_all  : &[E]        ,
+    _var_tay  : &[E]        ,
+    _var_par  : &mut [E]    ,
+    _n_tay    : usize       ,
+    _const_data : ConstData<V> ,
+) { panic!(); }
+// ---------------------------------------------------------------------------
 // RustSrc
 /// Generate source code corresponding to forward_dyp and forward_var
 /// evaluation.
@@ -252,6 +331,26 @@ pub(crate) fn panic_reverse_depend(
     _res      : usize                 ,
     _res_type : ADType                ,
 ) { panic!(); }
+// ----------------------------------------------------------------------------
+// Linearity
+/// Classification of an operator for Hessian sparsity calculations.
+///
+/// This only concerns the second partials of the operator result
+/// w.r.t. its variable arguments; i.e., the arguments that are
+/// parameters are treated as constants.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum Linearity {
+    /// All the second partials are identically zero; e.g., x + y .
+    Linear,
+    /// The second partials w.r.t. one argument are zero but the
+    /// cross partial w.r.t. the two arguments is not; e.g., x * y .
+    Product,
+    /// The second partial w.r.t. the first argument is zero but the other
+    /// second partials are not; e.g., x / y .
+    Quotient,
+    /// All the second partials may be non-zero; e.g., sin(x) or powf(x, y).
+    Nonlinear,
+}
 // ---------------------------------------------------------------------------
 /// Information for one operator
 #[derive(Clone)]
@@ -284,18 +383,34 @@ pub struct OpFns<V> {
     /// first order reverse mode `AD<V>` evaluation for this operator
     pub(crate) reverse_der_ad  : ReverseDer<V, AD<V> >,
     //
+    /// arbitrary order forward mode V evaluation for this operator
+    pub(crate) forward_taylor_value : ForwardTaylor<V, V>,
+    //
+    /// arbitrary order forward mode `AD<V>` evaluation for this operator
+    pub(crate) forward_taylor_ad    : ForwardTaylor<V, AD<V> >,
+    //
+    /// arbitrary order reverse mode V evaluation for this operator
+    pub(crate) reverse_taylor_value : ReverseTaylor<V, V>,
+    //
+    /// arbitrary order reverse mode `AD<V>` evaluation for this operator
+    pub(crate) reverse_taylor_ad    : ReverseTaylor<V, AD<V> >,
+    //
     /// generate rust source code for this operator
     pub(crate) rust_src        : RustSrc<V>,
     //
     /// reverse dependency analysis for this operator
     pub(crate) reverse_depend  : ReverseDepend,
+    //
+    /// classification of this operator for Hessian sparsity calculations
+    pub(crate) linearity       : Linearity,
 }
 // ---------------------------------------------------------------------------
 // op_fns_vec
 /// returns the vector of length [NUMBER_OP]
 /// that maps each operator id to it's [OpFns] .
 ///
-pub(crate) fn op_fns_vec<V>() -> Vec< OpFns<V> >
+#[doc(hidden)]
+pub fn op_fns_vec<V>() -> Vec< OpFns<V> >
 where
     // add_assign, sub_assign
     for<'a> V : std::ops::AddAssign<&'a V> ,
@@ -314,6 +429,7 @@ where
     for<'a> &'a V : std::ops::Div<&'a V, Output = V> ,
     //
     V     : Clone + From<f32> + PartialEq + FConst + FValue,
+    V     : std::fmt::Display,
     for<'a> &'a V : FUnary<Output=V>,
     V     : ThisThreadTape + GlobalAtomCallbackVec,
     for<'a> &'a V : FBinary<&'a V, Output = V> ,
@@ -329,8 +445,13 @@ where
         forward_der_ad     : panic_der::<V, AD<V>>,
         reverse_der_value  : panic_der::<V, V>,
         reverse_der_ad     : panic_der::<V, AD<V>>,
+        forward_taylor_value : panic_taylor::<V, V>,
+        forward_taylor_ad    : panic_taylor::<V, AD<V>>,
+        reverse_taylor_value : panic_reverse_taylor::<V, V>,
+        reverse_taylor_ad    : panic_reverse_taylor::<V, AD<V>>,
         rust_src           : panic_rust_src,
         reverse_depend     : panic_reverse_depend,
+        linearity          : Linearity::Nonlinear,
     };
     let mut result : Vec< OpFns<V> > = vec![empty ; NUMBER_OP as usize];
     //
@@ -343,8 +464,26 @@ where
     crate::op::binary::atan2::set_op_fns::<V>(&mut result);
     crate::op::binary::hypot::set_op_fns::<V>(&mut result);
     crate::op::binary::powf::set_op_fns::<V>(&mut result);
+    crate::op::binary::min::set_op_fns::<V>(&mut result);
+    crate::op::binary::max::set_op_fns::<V>(&mut result);
     //
     // unary operators
+    crate::op::unary::ceil::set_op_fns::<V>(&mut result);
+    crate::op::unary::floor::set_op_fns::<V>(&mut result);
+    crate::op::unary::round::set_op_fns::<V>(&mut result);
+    crate::op::unary::trunc::set_op_fns::<V>(&mut result);
+    crate::op::unary::cbrt::set_op_fns::<V>(&mut result);
+    crate::op::unary::exp2::set_op_fns::<V>(&mut result);
+    crate::op::unary::log10::set_op_fns::<V>(&mut result);
+    crate::op::unary::log2::set_op_fns::<V>(&mut result);
+    crate::op::unary::erf::set_op_fns::<V>(&mut result);
+    crate::op::unary::erfc::set_op_fns::<V>(&mut result);
+    crate::op::unary::acos::set_op_fns::<V>(&mut result);
+    crate::op::unary::acosh::set_op_fns::<V>(&mut result);
+    crate::op::unary::asin::set_op_fns::<V>(&mut result);
+    crate::op::unary::asinh::set_op_fns::<V>(&mut result);
+    crate::op::unary::atan::set_op_fns::<V>(&mut result);
+    crate::op::unary::atanh::set_op_fns::<V>(&mut result);
     crate::op::unary::square::set_op_fns::<V>(&mut result);
     crate::op::unary::ln_1p::set_op_fns::<V>(&mut result);
     crate::op::unary::exp_m1::set_op_fns::<V>(&mut result);
@@ -361,10 +500,13 @@ where
     crate::op::unary::cos::set_op_fns::<V>(&mut result);
     crate::op::unary::sin::set_op_fns::<V>(&mut result);
     //
-    // call, no_op, powi, zero_one
+    // call, cexp, no_op, powi, print, vec_ad, zero_one
     crate::op::call::set_op_fns::<V>(&mut result);
+    crate::op::cexp::set_op_fns::<V>(&mut result);
     crate::op::no_op::set_op_fns::<V>(&mut result);
     crate::op::powi::set_op_fns::<V>(&mut result);
+    crate::op::print::set_op_fns::<V>(&mut result);
+    crate::op::vec_ad::set_op_fns::<V>(&mut result);
     crate::op::zero_one::set_op_fns::<V>(&mut result);
     //
     result
@@ -382,7 +524,7 @@ pub(crate) mod sealed {
     //
     pub trait GlobalOpFnsVec
     where
-        Self : Sized + 'static,
+        Self : Sized + PartialEq + 'static,
     {
         /// Returns a reference to the map from operator id to [OpFns]
         ///
@@ -401,26 +543,37 @@ pub(crate) mod sealed {
 // impl_global_op_fns_vec!
 /// Implement GlobalOpFnsVec for the value type *V* ; see [doc_generic_v]
 ///
-/// This macro can be invoked from anywhere given the following use statements:
-/// ```text
-///     use std::thread::LocalKey;
-///     use std::cell::RefCell;
-///     use crate::ad::AD;
-/// ```
-macro_rules! impl_global_op_fns_vec{ ($V:ty) => {
-    impl crate::op::info::sealed::GlobalOpFnsVec for $V {
+/// This macro is used by [setup_value_type](crate::setup_value_type) .
+#[doc(hidden)]
+#[macro_export]
+macro_rules! impl_global_op_fns_vec{
+    ($V:ty) => {
+        $crate::impl_global_op_fns_vec!(@impl $V, );
+    };
+    ($V:ty, reduce) => {
+        impl $crate::setup::private::ReduceValue for $V { }
+        $crate::impl_global_op_fns_vec!(@impl $V,
+            $crate::setup::private::set_reduce_op_fns::<$V>
+        );
+    };
+    (@impl $V:ty, $($set_reduce:path)?) => {
+    impl $crate::setup::private::GlobalOpFnsVec for $V {
         #[doc = concat!(
             "Operator functions used to evaluate `",
             stringify!($V), "`, and `AD<", stringify!($V), ">` operations"
         ) ]
-        fn get() -> &'static Vec< crate::op::info::OpFns<$V> > {
-            pub static OP_FNS_VEC :
-                LazyLock< Vec< crate::op::info::OpFns<$V> > > =
-                    LazyLock::new(
-                        || crate::op::info::op_fns_vec::<$V>()
-                    );
+        fn get() -> &'static Vec< $crate::setup::private::OpFns<$V> > {
+            pub static OP_FNS_VEC : std::sync::LazyLock<
+                Vec< $crate::setup::private::OpFns<$V> >
+            > = std::sync::LazyLock::new( || {
+                #[allow(unused_mut)]
+                let mut op_fns_vec =
+                    $crate::setup::private::op_fns_vec::<$V>();
+                $( $set_reduce(&mut op_fns_vec); )?
+                op_fns_vec
+            } );
             &*OP_FNS_VEC
         }
     }
-} }
-pub(crate) use impl_global_op_fns_vec;
+    };
+}
@@ -15,6 +15,11 @@ pub mod unary;
 pub mod binary;
 //
 pub mod call;
+pub mod cexp;
 pub mod no_op;
 pub mod powi;
+pub mod print;
+pub mod reduce;
+pub mod taylor;
+pub mod vec_ad;
 pub mod zero_one;
@@ -21,6 +21,7 @@ use crate::adfn::optimize;
 //
 use crate::op::info::OpFns;
 use crate::op::info::ConstData;
+use crate::op::info::Linearity;
 use crate::op::id::NO_OP;
 //
 // no_op_dyp
@@ -44,6 +45,23 @@ pub(crate) fn no_op_der<V, E>  (
     _const_data : ConstData<V> ,
 ) {  }
 //
+// no_op_taylor
+pub(crate) fn no_op_taylor<V, E>  (
+    _dyp_all  : &[E]        ,
+    _var_tay  : &mut [E]    ,
+    _n_tay    : usize       ,
+    _const_data : ConstData<V> ,
+) {  }
+//
+// no_op_reverse_taylor
+pub(crate) fn no_op_reverse_taylor<V, E>  (
+    _dyp_all  : &[E]        ,
+    _var_tay  : &[E]        ,
+    _var_par  : &mut [E]    ,
+    _n_tay    : usize       ,
+    _const_data : ConstData<V> ,
+) {  }
+//
 // no_op_rust_src
 pub(crate) fn no_op_rust_src<V>(
     _res_type  : ADType      ,
@@ -80,7 +98,12 @@ pub fn set_op_fns<V>( op_fns_vec : &mut [OpFns<V>] )
         forward_der_ad    : no_op_der::<V, AD<V> >,
         reverse_der_value : no_op_der::<V, V>,
         reverse_der_ad    : no_op_der::<V, AD<V> >,
+        forward_taylor_value : no_op_taylor::<V, V>,
+        forward_taylor_ad    : no_op_taylor::<V, AD<V> >,
+        reverse_taylor_value : no_op_reverse_taylor::<V, V>,
+        reverse_taylor_ad    : no_op_reverse_taylor::<V, AD<V> >,
         rust_src          : no_op_rust_src,
         reverse_depend    : no_op_reverse_depend::<V>,
+        linearity         : Linearity::Linear,
     };
 }
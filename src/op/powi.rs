@@ -17,7 +17,9 @@
 // ---------------------------------------------------------------------------
 use std::ops::{
     Mul,
+    Div,
     AddAssign,
+    SubAssign,
 };
 use crate::{
     AD,
@@ -30,7 +32,9 @@ use crate::ad::ADType;
 use crate::adfn::optimize;
 use crate::op::info::OpFns;
 use crate::op::info::ConstData;
+use crate::op::info::Linearity;
 use crate::op::id::POWI_OP;
+use crate::op::taylor;
 // ---------------------------------------------------------------------------
 //
 // pow_forward_dyp
@@ -200,6 +204,59 @@ where
         var_der[res] = &dpowi *  &var_der[lhs];
     }
 }
+// powi_forward_taylor
+/// Arbitrary order forward mode for powi(variable);
+/// see [ForwardTaylor](crate::op::info::ForwardTaylor)
+fn powi_forward_taylor<V, E>(
+    _dyp_all   :   &[E]        ,
+    var_tay    :   &mut [E]    ,
+    n_tay      :   usize       ,
+    const_data : ConstData<V> )
+where
+    E             : Clone + FConst ,
+    for<'a> E     : AddAssign<&'a E> + SubAssign<&'a E> ,
+    for<'a> &'a E : Mul<&'a E, Output=E> + Div<&'a E, Output=E> ,
+{
+    let ConstData {arg, arg_type, res, ..} = const_data;
+    //
+    debug_assert!( arg.len() == 3 );
+    debug_assert!( arg_type[0].is_variable() );
+    let positive    = arg[2] == 0;
+    let rhs         = if positive { arg[1] as i32 } else { - (arg[1] as i32) };
+    let lhs         = arg[0] as usize;
+    let (before, z) = taylor::split_var_tay(var_tay, n_tay, res);
+    let x           = &before[lhs * n_tay .. (lhs + 1) * n_tay];
+    taylor::powi::<E>(x, rhs, z);
+}
+// powi_reverse_taylor
+/// Arbitrary order reverse mode for powi(variable);
+/// see [ReverseTaylor](crate::op::info::ReverseTaylor)
+fn powi_reverse_taylor<V, E>(
+    _dyp_all   :   &[E]        ,
+    var_tay    :   &[E]        ,
+    var_par    :   &mut [E]    ,
+    n_tay      :   usize       ,
+    const_data : ConstData<V> )
+where
+    V             : From<f32> ,
+    E             : Clone + From<V> + FConst ,
+    for<'a> E     : AddAssign<&'a E> + SubAssign<&'a E> ,
+    for<'a> &'a E : Mul<&'a E, Output=E> + Div<&'a E, Output=E> ,
+    for<'a> &'a E : FUnary<Output=E>,
+{
+    let ConstData {arg, arg_type, res, ..} = const_data;
+    //
+    debug_assert!( arg.len() == 3 );
+    debug_assert!( arg_type[0].is_variable() );
+    let positive     = arg[2] == 0;
+    let rhs          = if positive { arg[1] as i32 } else { - (arg[1] as i32) };
+    let lhs          = arg[0] as usize;
+    let x            = &var_tay[lhs * n_tay .. (lhs + 1) * n_tay];
+    let d            = taylor::powi_partial::<V, E>(x, rhs);
+    let (before, pz) = taylor::split_var_par(var_par, n_tay, res);
+    let px           = &mut before[lhs * n_tay .. (lhs + 1) * n_tay];
+    taylor::reverse_partial::<E>(pz, &d, px);
+}
 // powi_reverse_der
 /// First order reverse mode for powi(variable);
 /// see [ForwardDer](crate::op::info::ForwardDer)
@@ -240,10 +297,12 @@ pub fn set_op_fns<V>( op_fns_vec : &mut [OpFns<V>] ) where
     for<'a> &'a AD<V> : Mul<&'a AD<V>, Output = AD<V> > ,
     for<'a> &'a AD<V> : Mul<&'a V, Output = AD<V> > ,
     for<'a> &'a V     : Mul<&'a V, Output = V> ,
-    V                 : Clone + FConst + ThisThreadTape + From<f32>,
+    V                 : Clone + FConst + ThisThreadTape + From<f32> + PartialEq,
     for<'a> &'a V     : FUnary<Output=V>,
     for<'a> V         : AddAssign<&'a V>,
     for<'a> AD<V>     : AddAssign<&'a AD<V> >,
+    for<'a> V         : SubAssign<&'a V>,
+    for<'a> &'a V     : Div<&'a V, Output = V> ,
 {
     op_fns_vec[POWI_OP as usize] = OpFns{
         name              : "powi",
@@ -255,7 +314,12 @@ pub fn set_op_fns<V>( op_fns_vec : &mut [OpFns<V>] ) where
         forward_der_ad    : powi_forward_der::<V, AD<V> >,
         reverse_der_value : powi_reverse_der::<V, V>,
         reverse_der_ad    : powi_reverse_der::<V, AD<V> >,
+        forward_taylor_value : powi_forward_taylor::<V, V>,
+        forward_taylor_ad    : powi_forward_taylor::<V, AD<V> >,
+        reverse_taylor_value : powi_reverse_taylor::<V, V>,
+        reverse_taylor_ad    : powi_reverse_taylor::<V, AD<V> >,
         rust_src          : powi_rust_src,
         reverse_depend    : powi_reverse_depend,
+        linearity         : Linearity::Nonlinear,
     };
 }
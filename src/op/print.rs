@@ -0,0 +1,250 @@
+// SPDX-License-Identifier: EPL-2.0 OR GPL-2.0-or-later
+// SPDX-FileCopyrightText: Bradley M. Bell <bradbell@seanet.com>
+// SPDX-FileContributor: 2026 Bradley M. Bell
+// --------------------------------------------------------------------------
+//! Operator that prints a value during forward evaluation
+//!
+//! Link to [parent module](super)
+//!
+//! # PRINT_OP
+//!
+//! # Operator Arguments
+//! | Index    | Meaning |
+//! | -------  | ------- |
+//! | 0        | Index in str_all of beginning of label for this operator    |
+//! | 1        | Index in str_all of the end of label for this operator      |
+//! | 2        | Variable, dynamic, or constant index for value printed      |
+//! | 3        | Variable, dynamic, or constant index for condition          |
+//!
+//! The argument type for the condition is Empty if there is no condition;
+//! i.e., the value is always printed.
+//! Otherwise it is only printed when the condition is positive;
+//! for numeric vectors, when any element of the condition is positive.
+//!
+//! The result for this operator is never used.
+// --------------------------------------------------------------------------
+// use
+use crate::{
+    AD,
+    FConst,
+    FValue,
+    FBinary,
+    IndexT,
+};
+use crate::op::id;
+use crate::ad::ADType;
+use crate::print_this_thread;
+use crate::adfn::optimize;
+use crate::op::no_op::{
+    no_op_dyp,
+    no_op_var,
+    no_op_der,
+    no_op_taylor,
+    no_op_reverse_taylor,
+};
+use crate::op::info::{
+    OpFns,
+    ConstData,
+    Linearity,
+};
+// --------------------------------------------------------------------------
+// print_value
+/// Print the label and value for a PRINT_OP operator.
+///
+/// * value :
+///   is the value of argument 2.
+///
+/// * cond :
+///   is the value of argument 3 (None if there is no condition).
+fn print_value<V>(label : &str, value : &V, cond : Option<&V>)
+where
+    V             : FConst + FValue + std::fmt::Display ,
+    for<'a> &'a V : FBinary<&'a V, Output = V> ,
+{
+    if let Some(cond) = cond && cond.num_gt( &V::zero() ).is_zero() {
+        return;
+    }
+    print_this_thread( &format!( "{label}{value}" ) );
+}
+//
+// arg_value
+/// Value of argument 2 or 3 for a PRINT_OP operator.
+fn arg_value<'a, V>(
+    cop      : &'a [V] ,
+    dyp_all  : &'a [V] ,
+    var_all  : &'a [V] ,
+    index    : IndexT  ,
+    ad_type  : ADType  ,
+) -> Option<&'a V> {
+    let index = index as usize;
+    match ad_type {
+        ADType::ConstantP => Some( &cop[index] ),
+        ADType::DynamicP  => Some( &dyp_all[index] ),
+        ADType::Variable  => Some( &var_all[index] ),
+        ADType::Empty     => None,
+    }
+}
+// --------------------------------------------------------------------------
+// print_forward_dyp_value
+/// Print operator V evaluation of dynamic parameters;
+/// see [ForwardDyp](crate::op::info::ForwardDyp)
+fn print_forward_dyp_value<V> (
+    dyp_all    : &mut [V]      ,
+    const_data : ConstData<V>  )
+where
+    V             : FConst + FValue + std::fmt::Display ,
+    for<'a> &'a V : FBinary<&'a V, Output = V> ,
+{   //
+    let ConstData{cop, str_all, arg, arg_type, ..} = const_data;
+    //
+    debug_assert!( arg_type.len() == 4 );
+    debug_assert!( ! arg_type.iter().any( |t| t.is_variable() ) );
+    //
+    // value, cond
+    let var_all : &[V] = &[];
+    let value = arg_value(cop, dyp_all, var_all, arg[2], arg_type[2]);
+    let cond  = arg_value(cop, dyp_all, var_all, arg[3], arg_type[3]);
+    //
+    let label = &str_all[arg[0] as usize .. arg[1] as usize];
+    print_value::<V>( label, value.unwrap(), cond );
+}
+// --------------------------------------------------------------------------
+// print_forward_var_value
+/// Print operator V evaluation of variables;
+/// see [ForwardVar](crate::op::info::ForwardVar)
+fn print_forward_var_value<V> (
+    dyp_all    : &[V]          ,
+    var_all    : &mut [V]      ,
+    const_data : ConstData<V>  )
+where
+    V             : FConst + FValue + std::fmt::Display ,
+    for<'a> &'a V : FBinary<&'a V, Output = V> ,
+{   //
+    let ConstData{cop, str_all, arg, arg_type, ..} = const_data;
+    //
+    debug_assert!( arg_type.len() == 4 );
+    //
+    // value, cond
+    let value = arg_value(cop, dyp_all, var_all, arg[2], arg_type[2]);
+    let cond  = arg_value(cop, dyp_all, var_all, arg[3], arg_type[3]);
+    //
+    let label = &str_all[arg[0] as usize .. arg[1] as usize];
+    print_value::<V>( label, value.unwrap(), cond );
+}
+// --------------------------------------------------------------------------
+// print_rust_src
+fn print_rust_src<V> (
+    res_type    : ADType      ,
+    dyp_n_dom   : usize       ,
+    var_n_dom   : usize       ,
+    const_data : ConstData<V> ) -> String
+{   //
+    let ConstData{str_all, arg, arg_type, res, ..} = const_data;
+    //
+    debug_assert!( arg_type.len() == 4 );
+    debug_assert!( res_type.is_dynamic() || res_type.is_variable());
+    //
+    // operand
+    let operand = |index : IndexT, ad_type : ADType| -> String {
+        let index = index as usize;
+        match ad_type {
+            ADType::ConstantP => format!("cop[{index}]"),
+            ADType::DynamicP  => if index < dyp_n_dom {
+                format!("dyp_dom[{index}]")
+            } else {
+                format!("dyp_dep[{}]", index - dyp_n_dom)
+            },
+            ADType::Variable  => if index < var_n_dom {
+                format!("var_dom[{index}]")
+            } else {
+                format!("var_dep[{}]", index - var_n_dom)
+            },
+            ADType::Empty     => panic!( "print rust_src: empty value" ),
+        }
+    };
+    //
+    // label
+    let label = &str_all[arg[0] as usize .. arg[1] as usize];
+    //
+    // res_str
+    let res_str = if res_type.is_dynamic() {
+        format!("dyp_dep[{}]", res - dyp_n_dom)
+    } else {
+        format!("var_dep[{}]", res - var_n_dom)
+    };
+    //
+    // src
+    // The result for this operator is never used.
+    let value   = operand(arg[2], arg_type[2]);
+    let print   = format!( "println!(\"{{}}{{}}\", {label:?}, {value});" );
+    let mut src = String::from("   ") + &res_str + " = V::nan();\n";
+    if arg_type[3] == ADType::Empty {
+        src = src + "   " + &print + "\n";
+    } else {
+        let cond = operand(arg[3], arg_type[3]);
+        src = src +
+            "   if ! (" + &cond + ").num_gt(&V::zero()).is_zero() {\n" +
+            "      " + &print + "\n" +
+            "   }\n";
+    }
+    src
+}
+// ---------------------------------------------------------------------------
+//  reverse_depend
+/// Reverse dependency analysis for a PRINT_OP;
+/// see [ReverseDepend](crate::op::info::ReverseDepend)
+pub(crate) fn print_reverse_depend(
+    depend    : &mut optimize::Depend ,
+    _bool_all : &[bool]               ,
+    arg       : &[IndexT]             ,
+    arg_type  : &[ADType]             ,
+    res       : usize                 ,
+    _res_type : ADType                ,
+) { //
+    debug_assert_eq!(arg.len(), 4);
+    debug_assert_eq!(arg_type.len(), 4);
+    //
+    for i_arg in 2 .. 4 {
+        let index = arg[i_arg] as usize;
+        match arg_type[i_arg] {
+            ADType::ConstantP => { depend.cop[index] = true; },
+            ADType::DynamicP  => { depend.dyp[index] = true; },
+            ADType::Variable  => {
+                debug_assert!( index < res );
+                depend.var[index] = true;
+            },
+            ADType::Empty     => { },
+        }
+    }
+}
+// ---------------------------------------------------------------------------
+// set_op_fns
+/// Set the operator functions for the PRINT_OP operator.
+///
+/// * op_fns_vec :
+///   The map from [op::id](crate::op::id) to operator functions.
+///   The the map results for PRINT_OP are set.
+pub fn set_op_fns<V>( op_fns_vec : &mut [OpFns<V>] )
+where
+    V             : FConst + FValue + std::fmt::Display ,
+    for<'a> &'a V : FBinary<&'a V, Output = V> ,
+{
+    op_fns_vec[id::PRINT_OP as usize] = OpFns{
+        name              : "print",
+        forward_dyp_value : print_forward_dyp_value::<V>,
+        forward_dyp_ad    : no_op_dyp::<V, AD<V> >,
+        forward_var_value : print_forward_var_value::<V>,
+        forward_var_ad    : no_op_var::<V, AD<V> >,
+        forward_der_value : no_op_der::<V, V>,
+        forward_der_ad    : no_op_der::<V, AD<V> >,
+        reverse_der_value : no_op_der::<V, V>,
+        reverse_der_ad    : no_op_der::<V, AD<V> >,
+        forward_taylor_value : no_op_taylor::<V, V>,
+        forward_taylor_ad    : no_op_taylor::<V, AD<V> >,
+        reverse_taylor_value : no_op_reverse_taylor::<V, V>,
+        reverse_taylor_ad    : no_op_reverse_taylor::<V, AD<V> >,
+        rust_src          : print_rust_src,
+        reverse_depend    : print_reverse_depend,
+        linearity         : Linearity::Linear,
+    };
+}
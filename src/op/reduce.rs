@@ -0,0 +1,462 @@
+// SPDX-License-Identifier: EPL-2.0 OR GPL-2.0-or-later
+// SPDX-FileCopyrightText: Bradley M. Bell <bradbell@seanet.com>
+// SPDX-FileContributor: 2026 Bradley M. Bell
+//
+//! This module defines the reduction operators
+//!
+//! Link to [parent module](super)
+//!
+//! * Operators : REDUCE_SUM_OP, REDUCE_MAX_OP, REDUCE_LEN_OP
+//!
+//! # Operator Arguments
+//! | Index | Meaning |
+//! | ----- | ------- |
+//! | 0     | Variable or dynamic index for the vector being reduced |
+//!
+//! # Broadcasting
+//! A derivative for a vector argument may have length one; i.e.,
+//! be the same for all the elements of the vector.
+//! The forward mode derivatives for reduce_sum take this into account.
+//! The reverse mode partials for reduce_sum are the same for all the
+//! elements of the argument; they are expanded to the length of the
+//! argument before they are added to its partial.
+// ---------------------------------------------------------------------------
+// z   = reduce_sum(x)
+// z_x = 1 for all the elements of x
+//
+// z   = reduce_max(x)
+// p   = num_eq(x, z)
+// z_x = p / reduce_sum(p)
+//
+// z   = reduce_len(x)
+// z_x = 0
+// ---------------------------------------------------------------------------
+use std::ops::{
+    Add,
+    Mul,
+    Div,
+    AddAssign,
+};
+use crate::{
+    AD,
+    FConst,
+    FBinary,
+    FReduce,
+};
+use crate::ad::ADType;
+use crate::op::id::{
+    REDUCE_SUM_OP,
+    REDUCE_MAX_OP,
+    REDUCE_LEN_OP,
+};
+use crate::op::info::OpFns;
+use crate::op::info::ConstData;
+use crate::op::info::Linearity;
+use crate::op::no_op::{
+    no_op_taylor,
+    no_op_reverse_taylor,
+};
+use crate::op::taylor;
+use crate::op::unary::common;
+// ---------------------------------------------------------------------------
+// For each name, define
+// {name}_forward_dyp<V, E>
+// {name}_forward_var<V, E>
+// {name}_rust_src<V>
+macro_rules! reduce_function{ ($name:ident) => { paste::paste! {
+    //
+    #[doc = concat!(
+        " E evaluation of ", stringify!( $name ), " for dynamic parameters",
+        "; see [ForwardDyp](crate::op::info::ForwardDyp)"
+    ) ]
+    fn [< $name _forward_dyp >] <V, E> (
+        dyp_all     : &mut [E]    ,
+        const_data : ConstData<V> )
+    where
+        for<'a> &'a E : FReduce<Output=E>,
+    {   //
+        let ConstData {arg, arg_type, res, ..} = const_data;
+        //
+        // index
+        let index = arg[0] as usize;
+        debug_assert!( index < res );
+        //
+        debug_assert!( arg.len() == 1);
+        debug_assert!( arg_type[0].is_dynamic() );
+        dyp_all[ res ] = dyp_all[index].$name();
+    }
+    //
+    #[doc = concat!(
+        " E evaluation of ", stringify!( $name ), " for variables",
+        "; see [ForwardVar](crate::op::info::ForwardVar)"
+    ) ]
+    fn [< $name _forward_var >] <V, E> (
+        _dyp_all    : &[E]        ,
+        var_all     : &mut [E]    ,
+        const_data : ConstData<V> )
+    where
+        for<'a> &'a E : FReduce<Output=E>,
+    {   //
+        let ConstData {arg, arg_type, res, ..} = const_data;
+        //
+        // index
+        let index = arg[0] as usize;
+        debug_assert!( index < res );
+        //
+        debug_assert!( arg.len() == 1);
+        debug_assert!( arg_type[0].is_variable() );
+        var_all[ res ] = var_all[index].$name();
+    }
+    //
+    // {name}_rust_src
+    common::rust_src!($name);
+} } }
+reduce_function!(reduce_sum);
+reduce_function!(reduce_max);
+reduce_function!(reduce_len);
+// ---------------------------------------------------------------------------
+// sum_scale
+// If dx has the same length as x, the return value is sum(dx).
+// If dx has length one, it is the same for all the elements of x and
+// the return value is len(x) * dx.
+fn sum_scale<E>(x : &E, dx : &E) -> E
+where
+    for<'a> &'a E : FReduce<Output=E> ,
+    for<'a> &'a E : Mul<&'a E, Output=E> + Div<&'a E, Output=E> ,
+{   let n_x  = x.reduce_len();
+    let n_dx = dx.reduce_len();
+    let sum  = dx.reduce_sum();
+    &( &sum * &n_x ) / &n_dx
+}
+//
+// reduce_sum_forward_der
+/// First order forward mode for reduce_sum(variable);
+/// see [ForwardDer](crate::op::info::ForwardDer)
+fn reduce_sum_forward_der<V, E>(
+    _dyp_all   :   &[E]        ,
+    var_all    :   &[E]        ,
+    var_der    :   &mut [E]    ,
+    const_data : ConstData<V> )
+where
+    for<'a> &'a E : FReduce<Output=E> ,
+    for<'a> &'a E : Mul<&'a E, Output=E> + Div<&'a E, Output=E> ,
+{
+    let ConstData {arg, arg_type, res, ..} = const_data;
+    //
+    debug_assert!( arg.len() == 1 );
+    debug_assert!( arg_type[0].is_variable() );
+    let x        = arg[0] as usize;
+    var_der[res] = sum_scale::<E>( &var_all[x], &var_der[x] );
+}
+//
+// expand
+// Returns a vector with the same length as x and all its elements equal
+// to the scalar pz. The comparison x < x is zero for all x, including nan.
+fn expand<E>(x : &E, pz : &E) -> E
+where
+    for<'a> &'a E : FBinary<&'a E, Output=E> + Add<&'a E, Output=E> ,
+{   let zero = x.num_lt(x);
+    &zero + pz
+}
+//
+// reduce_sum_reverse_der
+/// First order reverse mode for reduce_sum(variable);
+/// see [ReverseDer](crate::op::info::ReverseDer)
+fn reduce_sum_reverse_der<V, E>(
+    _dyp_all   :   &[E]        ,
+    var_all    :   &[E]        ,
+    var_der    :   &mut [E]    ,
+    const_data : ConstData<V> )
+where
+    for<'a> E     : AddAssign<&'a E> ,
+    for<'a> &'a E : FBinary<&'a E, Output=E> + Add<&'a E, Output=E> ,
+{
+    let ConstData {arg, arg_type, res, ..} = const_data;
+    //
+    debug_assert!( arg.len() == 1 );
+    debug_assert!( arg_type[0].is_variable() );
+    let x        = arg[0] as usize;
+    let px       = expand::<E>( &var_all[x], &var_der[res] );
+    var_der[x]  += &px;
+}
+//
+// reduce_sum_forward_taylor
+/// Arbitrary order forward mode for reduce_sum(variable);
+/// see [ForwardTaylor](crate::op::info::ForwardTaylor)
+fn reduce_sum_forward_taylor<V, E>(
+    _dyp_all   :   &[E]        ,
+    var_tay    :   &mut [E]    ,
+    n_tay      :   usize       ,
+    const_data : ConstData<V> )
+where
+    for<'a> &'a E : FReduce<Output=E> ,
+    for<'a> &'a E : Mul<&'a E, Output=E> + Div<&'a E, Output=E> ,
+{
+    let ConstData {arg, arg_type, res, ..} = const_data;
+    //
+    debug_assert!( arg.len() == 1 );
+    debug_assert!( arg_type[0].is_variable() );
+    let index       = arg[0] as usize;
+    let (before, z) = taylor::split_var_tay(var_tay, n_tay, res);
+    let x           = &before[index * n_tay .. (index + 1) * n_tay];
+    for k in 1 .. n_tay {
+        z[k] = sum_scale::<E>( &x[0], &x[k] );
+    }
+}
+//
+// reduce_sum_reverse_taylor
+/// Arbitrary order reverse mode for reduce_sum(variable);
+/// see [ReverseTaylor](crate::op::info::ReverseTaylor)
+fn reduce_sum_reverse_taylor<V, E>(
+    _dyp_all   :   &[E]        ,
+    var_tay    :   &[E]        ,
+    var_par    :   &mut [E]    ,
+    n_tay      :   usize       ,
+    const_data : ConstData<V> )
+where
+    for<'a> E     : AddAssign<&'a E> ,
+    for<'a> &'a E : FBinary<&'a E, Output=E> + Add<&'a E, Output=E> ,
+{
+    let ConstData {arg, arg_type, res, ..} = const_data;
+    //
+    debug_assert!( arg.len() == 1 );
+    debug_assert!( arg_type[0].is_variable() );
+    let index        = arg[0] as usize;
+    let x            = &var_tay[index * n_tay];
+    let (before, pz) = taylor::split_var_par(var_par, n_tay, res);
+    let px           = &mut before[index * n_tay .. (index + 1) * n_tay];
+    for k in 0 .. n_tay {
+        px[k] += &expand::<E>(x, &pz[k]);
+    }
+}
+// ---------------------------------------------------------------------------
+// max_weight
+// p / sum(p) where p = num_eq(x, z) is one where x is equal to z = max(x).
+// The derivative of the max is divided equally between these elements.
+fn max_weight<E>(x : &E, z : &E) -> E
+where
+    for<'a> &'a E : FReduce<Output=E> + FBinary<&'a E, Output=E> ,
+    for<'a> &'a E : Div<&'a E, Output=E> ,
+{   let p     = FBinary::num_eq(x, z);
+    let count = (&p).reduce_sum();
+    &p / &count
+}
+//
+// reduce_max_forward_der
+/// First order forward mode for reduce_max(variable);
+/// see [ForwardDer](crate::op::info::ForwardDer)
+fn reduce_max_forward_der<V, E>(
+    _dyp_all   :   &[E]        ,
+    var_all    :   &[E]        ,
+    var_der    :   &mut [E]    ,
+    const_data : ConstData<V> )
+where
+    for<'a> &'a E : FReduce<Output=E> + FBinary<&'a E, Output=E> ,
+    for<'a> &'a E : Mul<&'a E, Output=E> + Div<&'a E, Output=E> ,
+{
+    let ConstData {arg, arg_type, res, ..} = const_data;
+    //
+    debug_assert!( arg.len() == 1 );
+    debug_assert!( arg_type[0].is_variable() );
+    let x        = arg[0] as usize;
+    let w        = max_weight::<E>( &var_all[x], &var_all[res] );
+    var_der[res] = (&w).reduce_dot( &var_der[x] );
+}
+//
+// reduce_max_reverse_der
+/// First order reverse mode for reduce_max(variable);
+/// see [ReverseDer](crate::op::info::ReverseDer)
+fn reduce_max_reverse_der<V, E>(
+    _dyp_all   :   &[E]        ,
+    var_all    :   &[E]        ,
+    var_der    :   &mut [E]    ,
+    const_data : ConstData<V> )
+where
+    for<'a> E     : AddAssign<&'a E> ,
+    for<'a> &'a E : FReduce<Output=E> + FBinary<&'a E, Output=E> ,
+    for<'a> &'a E : Mul<&'a E, Output=E> + Div<&'a E, Output=E> ,
+{
+    let ConstData {arg, arg_type, res, ..} = const_data;
+    //
+    debug_assert!( arg.len() == 1 );
+    debug_assert!( arg_type[0].is_variable() );
+    let x        = arg[0] as usize;
+    let w        = max_weight::<E>( &var_all[x], &var_all[res] );
+    let term     = &w * &var_der[res];
+    var_der[x]  += &term;
+}
+//
+// reduce_max_forward_taylor
+/// Arbitrary order forward mode for reduce_max(variable);
+/// see [ForwardTaylor](crate::op::info::ForwardTaylor)
+fn reduce_max_forward_taylor<V, E>(
+    _dyp_all   :   &[E]        ,
+    var_tay    :   &mut [E]    ,
+    n_tay      :   usize       ,
+    const_data : ConstData<V> )
+where
+    for<'a> &'a E : FReduce<Output=E> + FBinary<&'a E, Output=E> ,
+    for<'a> &'a E : Mul<&'a E, Output=E> + Div<&'a E, Output=E> ,
+{
+    let ConstData {arg, arg_type, res, ..} = const_data;
+    //
+    debug_assert!( arg.len() == 1 );
+    debug_assert!( arg_type[0].is_variable() );
+    let index       = arg[0] as usize;
+    let (before, z) = taylor::split_var_tay(var_tay, n_tay, res);
+    let x           = &before[index * n_tay .. (index + 1) * n_tay];
+    let w           = max_weight::<E>( &x[0], &z[0] );
+    for k in 1 .. n_tay {
+        z[k] = (&w).reduce_dot( &x[k] );
+    }
+}
+//
+// reduce_max_reverse_taylor
+/// Arbitrary order reverse mode for reduce_max(variable);
+/// see [ReverseTaylor](crate::op::info::ReverseTaylor)
+fn reduce_max_reverse_taylor<V, E>(
+    _dyp_all   :   &[E]        ,
+    var_tay    :   &[E]        ,
+    var_par    :   &mut [E]    ,
+    n_tay      :   usize       ,
+    const_data : ConstData<V> )
+where
+    for<'a> E     : AddAssign<&'a E> ,
+    for<'a> &'a E : FReduce<Output=E> + FBinary<&'a E, Output=E> ,
+    for<'a> &'a E : Mul<&'a E, Output=E> + Div<&'a E, Output=E> ,
+{
+    let ConstData {arg, arg_type, res, ..} = const_data;
+    //
+    debug_assert!( arg.len() == 1 );
+    debug_assert!( arg_type[0].is_variable() );
+    let index        = arg[0] as usize;
+    let x            = &var_tay[index * n_tay .. (index + 1) * n_tay];
+    let z            = &var_tay[res * n_tay .. (res + 1) * n_tay];
+    let w            = max_weight::<E>( &x[0], &z[0] );
+    let (before, pz) = taylor::split_var_par(var_par, n_tay, res);
+    let px           = &mut before[index * n_tay .. (index + 1) * n_tay];
+    for k in 0 .. n_tay {
+        px[k] += &( &w * &pz[k] );
+    }
+}
+// ---------------------------------------------------------------------------
+// zero_forward_der
+fn zero_forward_der<V, E>  (
+    _dyp_all  : &[E]        ,
+    _var_all  : &[E]        ,
+    var_der   : &mut [E]    ,
+    const_data : ConstData<V> ,
+)
+where
+    E : FConst,
+{
+    let ConstData {res, ..} = const_data;
+    //
+    var_der [ res ] = FConst::zero();
+}
+// ---------------------------------------------------------------------------
+// zero_reverse_der
+fn zero_reverse_der<V, E>  (
+    _dyp_all  : &[E]        ,
+    _var_all  : &[E]        ,
+    _var_der  : &mut [E]    ,
+    _const_data : ConstData<V> ,
+) {  }
+// ---------------------------------------------------------------------------
+// set_op_fns
+/// Set the operator functions for the reduction operators.
+///
+/// This is called by `setup_value_type!(V, reduce)`; see
+/// [setup_value_type](crate::setup_value_type) .
+///
+/// * op_fns_vec :
+///   The map from operator id to operator functions.
+///   The the map results for REDUCE_SUM_OP, REDUCE_MAX_OP, and REDUCE_LEN_OP
+///   are set.
+pub fn set_op_fns<V>( op_fns_vec : &mut [OpFns<V>] )
+where
+    V                 : Clone + FConst ,
+    for<'a> V         : AddAssign<&'a V> ,
+    for<'a> &'a V     : FReduce<Output = V> ,
+    for<'a> &'a V     : FBinary<&'a V, Output = V> ,
+    for<'a> &'a V     : Add<&'a V, Output = V> ,
+    for<'a> &'a V     : Mul<&'a V, Output = V> + Div<&'a V, Output = V> ,
+    //
+    AD<V>             : Clone + FConst ,
+    for<'a> AD<V>     : AddAssign<&'a AD<V> > ,
+    for<'a> &'a AD<V> : FReduce<Output = AD<V> > ,
+    for<'a> &'a AD<V> : FBinary<&'a AD<V>, Output = AD<V> > ,
+    for<'a> &'a AD<V> : Add<&'a AD<V>, Output = AD<V> > ,
+    for<'a> &'a AD<V> : Mul<&'a AD<V>, Output = AD<V> > ,
+    for<'a> &'a AD<V> : Div<&'a AD<V>, Output = AD<V> > ,
+{
+    op_fns_vec[REDUCE_SUM_OP as usize] = OpFns{
+        name              : "reduce_sum",
+        forward_dyp_value : reduce_sum_forward_dyp::<V, V>,
+        forward_dyp_ad    : reduce_sum_forward_dyp::<V, AD<V> >,
+        forward_var_value : reduce_sum_forward_var::<V, V>,
+        forward_var_ad    : reduce_sum_forward_var::<V, AD<V> >,
+        forward_der_value : reduce_sum_forward_der::<V, V>,
+        forward_der_ad    : reduce_sum_forward_der::<V, AD<V> >,
+        reverse_der_value : reduce_sum_reverse_der::<V, V>,
+        reverse_der_ad    : reduce_sum_reverse_der::<V, AD<V> >,
+        forward_taylor_value : reduce_sum_forward_taylor::<V, V>,
+        forward_taylor_ad    : reduce_sum_forward_taylor::<V, AD<V> >,
+        reverse_taylor_value : reduce_sum_reverse_taylor::<V, V>,
+        reverse_taylor_ad    : reduce_sum_reverse_taylor::<V, AD<V> >,
+        rust_src          : reduce_sum_rust_src,
+        reverse_depend    : common::reverse_depend,
+        linearity         : Linearity::Linear,
+    };
+    op_fns_vec[REDUCE_MAX_OP as usize] = OpFns{
+        name              : "reduce_max",
+        forward_dyp_value : reduce_max_forward_dyp::<V, V>,
+        forward_dyp_ad    : reduce_max_forward_dyp::<V, AD<V> >,
+        forward_var_value : reduce_max_forward_var::<V, V>,
+        forward_var_ad    : reduce_max_forward_var::<V, AD<V> >,
+        forward_der_value : reduce_max_forward_der::<V, V>,
+        forward_der_ad    : reduce_max_forward_der::<V, AD<V> >,
+        reverse_der_value : reduce_max_reverse_der::<V, V>,
+        reverse_der_ad    : reduce_max_reverse_der::<V, AD<V> >,
+        forward_taylor_value : reduce_max_forward_taylor::<V, V>,
+        forward_taylor_ad    : reduce_max_forward_taylor::<V, AD<V> >,
+        reverse_taylor_value : reduce_max_reverse_taylor::<V, V>,
+        reverse_taylor_ad    : reduce_max_reverse_taylor::<V, AD<V> >,
+        rust_src          : reduce_max_rust_src,
+        reverse_depend    : common::reverse_depend,
+        linearity         : Linearity::Linear,
+    };
+    op_fns_vec[REDUCE_LEN_OP as usize] = OpFns{
+        name              : "reduce_len",
+        forward_dyp_value : reduce_len_forward_dyp::<V, V>,
+        forward_dyp_ad    : reduce_len_forward_dyp::<V, AD<V> >,
+        forward_var_value : reduce_len_forward_var::<V, V>,
+        forward_var_ad    : reduce_len_forward_var::<V, AD<V> >,
+        forward_der_value : zero_forward_der::<V, V>,
+        forward_der_ad    : zero_forward_der::<V, AD<V> >,
+        reverse_der_value : zero_reverse_der::<V, V>,
+        reverse_der_ad    : zero_reverse_der::<V, AD<V> >,
+        forward_taylor_value : no_op_taylor::<V, V>,
+        forward_taylor_ad    : no_op_taylor::<V, AD<V> >,
+        reverse_taylor_value : no_op_reverse_taylor::<V, V>,
+        reverse_taylor_ad    : no_op_reverse_taylor::<V, AD<V> >,
+        rust_src          : reduce_len_rust_src,
+        reverse_depend    : common::reverse_depend,
+        linearity         : Linearity::Linear,
+    };
+}
+// ---------------------------------------------------------------------------
+// sealed::ReduceValue
+pub(crate) mod sealed {
+    //! Value types for which the reduction operators are set up
+    //
+    // ReduceValue
+    /// This is implemented for a value type V by
+    /// `setup_value_type!(V, reduce)`; see
+    /// [setup_value_type](crate::setup_value_type) .
+    ///
+    /// `&AD<V>` only implements [FReduce](crate::FReduce) when V implements
+    /// this trait, so the reduction operators are only recorded for
+    /// value types where their operator functions are set.
+    pub trait ReduceValue { }
+}
@@ -0,0 +1,1542 @@
+// SPDX-License-Identifier: EPL-2.0 OR GPL-2.0-or-later
+// SPDX-FileCopyrightText: Bradley M. Bell <bradbell@seanet.com>
+// SPDX-FileContributor: 2026 Bradley M. Bell
+//
+//! Taylor coefficient recurrences used by the operator
+//! [ForwardTaylor](crate::op::info::ForwardTaylor) and
+//! [ReverseTaylor](crate::op::info::ReverseTaylor) functions.
+//!
+//! Link to [parent module](super)
+//!
+//! * Notation :
+//!   For a Taylor coefficient vector x, n = x.len() is the number of
+//!   coefficients and x\[k\] is the k-th order coefficient.
+//!
+//! * V, E :
+//!   V is the value type; see [doc_generic_v](crate::doc_generic_v) .
+//!   E is the type used for the Taylor coefficients;
+//!   see [doc_generic_e](crate::adfn::doc_generic_e) .
+//!   Integer factors in the recurrences are converted to V and then to E,
+//!   so only the recurrences that use integer factors have a V parameter.
+//!
+//! * z\[0\] :
+//!   Unless otherwise specified, z\[0\] is an input
+//!   (it was computed by zero order forward mode) and
+//!   z\[k\] for 0 < k < n is an output.
+// ---------------------------------------------------------------------------
+// use
+//
+use std::ops::{
+    Add,
+    Sub,
+    Mul,
+    Div,
+    AddAssign,
+    SubAssign,
+};
+//
+use crate::{
+    FConst,
+    FUnary,
+    IndexT,
+};
+use crate::ad::ADType;
+// ---------------------------------------------------------------------------
+// bounds
+// ---------------------------------------------------------------------------
+//
+// TaylorValue
+/// Operations on a value type V used to register the Taylor functions
+/// for an operator.
+///
+/// This trait is implemented for every type that has these operations.
+/// The `set_op_fns` functions use the bounds
+/// ```text
+///     V             : TaylorValue ,
+///     for<'a> &'a V : TaylorRef<'a, V> ,
+/// ```
+/// in place of listing the operations that the recurrences use.
+pub trait TaylorValue : Sized + Clone + From<f32> + FConst
+    + for<'a> AddAssign<&'a Self> + for<'a> SubAssign<&'a Self>
+{ }
+impl<V> TaylorValue for V
+where
+    V : Sized + Clone + From<f32> + FConst ,
+    for<'a> V : AddAssign<&'a V> + SubAssign<&'a V> ,
+{ }
+//
+// TaylorRef
+/// Operations on references to a value type V used to register the
+/// Taylor functions for an operator; see [TaylorValue] .
+pub trait TaylorRef<'a, V : 'a> :
+    Add<&'a V, Output=V> + Sub<&'a V, Output=V> +
+    Mul<&'a V, Output=V> + Div<&'a V, Output=V> + FUnary<Output=V>
+{ }
+impl<'a, V : 'a, R> TaylorRef<'a, V> for R
+where
+    R : Add<&'a V, Output=V> + Sub<&'a V, Output=V> ,
+    R : Mul<&'a V, Output=V> + Div<&'a V, Output=V> ,
+    R : FUnary<Output=V> ,
+{ }
+// ---------------------------------------------------------------------------
+// utilities
+// ---------------------------------------------------------------------------
+//
+// split_var_tay
+/// Split the Taylor coefficients into the arguments and result for
+/// an operator.
+///
+/// * var_tay :
+///   The k-th order coefficient for variable i_var is
+///   var_tay\[ i_var * n_tay + k \] .
+///
+/// * n_tay :
+///   is the number of Taylor coefficients for each variable.
+///
+/// * res :
+///   is the variable index for the first result of this operator.
+///
+/// * return :
+///   The first return is the coefficients for variables with index less
+///   than res. The second return is the coefficients for res.
+pub(crate) fn split_var_tay<E>(
+    var_tay : &mut [E] ,
+    n_tay   : usize    ,
+    res     : usize    ,
+) -> ( &[E], &mut [E] )
+{   let (before, after) = var_tay.split_at_mut( res * n_tay );
+    ( before, &mut after[0 .. n_tay] )
+}
+//
+// split_var_par
+/// Split the Taylor coefficient partials into the arguments and result for
+/// an operator.
+///
+/// * var_par :
+///   The partial w.r.t. the k-th order coefficient for variable i_var is
+///   var_par\[ i_var * n_tay + k \] .
+///
+/// * n_tay :
+///   is the number of Taylor coefficients for each variable.
+///
+/// * res :
+///   is the variable index for the first result of this operator.
+///
+/// * return :
+///   The first return is the partials for variables with index less
+///   than res. The second return is the partials for res.
+pub(crate) fn split_var_par<E>(
+    var_par : &mut [E] ,
+    n_tay   : usize    ,
+    res     : usize    ,
+) -> ( &mut [E], &[E] )
+{   let (before, after) = var_par.split_at_mut( res * n_tay );
+    ( before, &after[0 .. n_tay] )
+}
+//
+// arg_taylor
+/// Taylor coefficients for one operator argument.
+///
+/// * dyp_all  : is the value of all the dynamic parameters.
+/// * cop      : is the value of all the constant parameters.
+/// * var_tay  : Taylor coefficients for variables; see [split_var_tay] .
+/// * n_tay    : is the number of Taylor coefficients for each variable.
+/// * index    : is the argument index.
+/// * ad_type  : is the argument type.
+///
+/// * return :
+///   is the Taylor coefficients for the argument.
+///   If the argument is a parameter, the coefficients of order greater
+///   than zero are zero.
+pub(crate) fn arg_taylor<V, E>(
+    dyp_all  : &[E]     ,
+    cop      : &[V]     ,
+    var_tay  : &[E]     ,
+    n_tay    : usize    ,
+    index    : IndexT   ,
+    ad_type  : ADType   ,
+) -> Vec<E>
+where
+    V : Clone ,
+    E : Clone + FConst + From<V> ,
+{   let index = index as usize;
+    if ad_type.is_variable() {
+        return var_tay[index * n_tay .. (index + 1) * n_tay].to_vec();
+    }
+    let mut x = vec![ E::zero(); n_tay ];
+    if ad_type.is_constant() {
+        x[0] = E::from( cop[index].clone() );
+    } else {
+        debug_assert!( ad_type.is_dynamic() );
+        x[0] = dyp_all[index].clone();
+    }
+    x
+}
+//
+// product
+/// Taylor coefficients for x * y, including order zero.
+fn product<E>( x : &[E], y : &[E] ) -> Vec<E>
+where
+    E             : Clone ,
+    for<'a> E     : AddAssign<&'a E> ,
+    for<'a> &'a E : Mul<&'a E, Output=E> ,
+{   let mut z = vec![ &x[0] * &y[0] ; x.len() ];
+    mul::<E>(x, y, &mut z);
+    z
+}
+//
+// sum_of_squares
+/// Taylor coefficients for x * x + y * y, including order zero.
+fn sum_of_squares<E>( x : &[E], y : &[E] ) -> Vec<E>
+where
+    E             : Clone ,
+    for<'a> E     : AddAssign<&'a E> ,
+    for<'a> &'a E : Mul<&'a E, Output=E> ,
+{   let mut z  = product::<E>(x, x);
+    let y_sq   = product::<E>(y, y);
+    for k in 0 .. z.len() {
+        z[k] += &y_sq[k];
+    }
+    z
+}
+//
+// factor
+/// converts the integer k to a value
+fn factor<V, E>(k : usize) -> E
+where
+    V : From<f32> ,
+    E : From<V> ,
+{   E::from( V::from( k as f32 ) ) }
+//
+// ln_factor
+/// converts the integer k to a value and returns its natural log
+fn ln_factor<V, E>(k : usize) -> E
+where
+    V             : From<f32> ,
+    E             : From<V> ,
+    for<'a> &'a E : FUnary<Output=E> ,
+{   ( &factor::<V, E>(k) ).ln() }
+// ---------------------------------------------------------------------------
+// binary recurrences
+// ---------------------------------------------------------------------------
+//
+// add
+/// z = x + y
+pub(crate) fn add<E>( x : &[E], y : &[E], z : &mut [E] )
+where
+    for<'a> &'a E : Add<&'a E, Output=E> ,
+{   for k in 1 .. z.len() {
+        z[k] = &x[k] + &y[k];
+    }
+}
+//
+// sub
+/// z = x - y
+pub(crate) fn sub<E>( x : &[E], y : &[E], z : &mut [E] )
+where
+    for<'a> &'a E : Sub<&'a E, Output=E> ,
+{   for k in 1 .. z.len() {
+        z[k] = &x[k] - &y[k];
+    }
+}
+//
+// mul
+/// z = x * y
+pub(crate) fn mul<E>( x : &[E], y : &[E], z : &mut [E] )
+where
+    for<'a> E     : AddAssign<&'a E> ,
+    for<'a> &'a E : Mul<&'a E, Output=E> ,
+{   for k in 1 .. z.len() {
+        let mut sum = &x[0] * &y[k];
+        for j in 1 ..= k {
+            sum += &( &x[j] * &y[k-j] );
+        }
+        z[k] = sum;
+    }
+}
+//
+// div
+/// z = x / y
+///
+/// ```text
+///     z[k] = ( x[k] - sum_{j=1}^k y[j] * z[k-j] ) / y[0]
+/// ```
+pub(crate) fn div<E>( x : &[E], y : &[E], z : &mut [E] )
+where
+    E             : Clone ,
+    for<'a> E     : SubAssign<&'a E> ,
+    for<'a> &'a E : Mul<&'a E, Output=E> ,
+    for<'a> &'a E : Div<&'a E, Output=E> ,
+{   for k in 1 .. z.len() {
+        let mut sum = x[k].clone();
+        for j in 1 ..= k {
+            sum -= &( &y[j] * &z[k-j] );
+        }
+        z[k] = &sum / &y[0];
+    }
+}
+//
+// powf
+/// z = x^y = exp( y * ln(x) )
+pub(crate) fn powf<V, E>( x : &[E], y : &[E], z : &mut [E] )
+where
+    V             : From<f32> ,
+    E             : Clone + From<V> + FConst ,
+    for<'a> E     : AddAssign<&'a E> + SubAssign<&'a E> ,
+    for<'a> &'a E : Mul<&'a E, Output=E> + Div<&'a E, Output=E> ,
+    for<'a> &'a E : FUnary<Output=E> ,
+{   //
+    // log_x = ln(x)
+    let mut log_x = vec![ x[0].ln() ; x.len() ];
+    ln::<V, E>(x, &mut log_x);
+    //
+    // prod = y * ln(x)
+    let prod = product::<E>(y, &log_x);
+    //
+    // z = exp( y * ln(x) )
+    exp::<V, E>(&prod, z);
+}
+//
+// atan2
+/// z = atan2(y, x)
+///
+/// The derivative z' = ( x * y' - y * x' ) / ( x * x + y * y ) is computed
+/// using [mul] and [div]; i.e.,
+/// the order k-1 coefficient of z' is k * z\[k\] .
+pub(crate) fn atan2<V, E>( y : &[E], x : &[E], z : &mut [E] )
+where
+    V             : From<f32> ,
+    E             : Clone + From<V> ,
+    for<'a> E     : AddAssign<&'a E> + SubAssign<&'a E> ,
+    for<'a> &'a E : Mul<&'a E, Output=E> + Div<&'a E, Output=E> ,
+    for<'a> &'a E : Sub<&'a E, Output=E> ,
+{   let n = z.len();
+    if n < 2 {
+        return;
+    }
+    let m = n - 1;
+    //
+    // dx, dy
+    let dx : Vec<E> = (0 .. m).map( |i| &factor::<V, E>(i+1) * &x[i+1] ).collect();
+    let dy : Vec<E> = (0 .. m).map( |i| &factor::<V, E>(i+1) * &y[i+1] ).collect();
+    //
+    // num = x * y' - y * x'
+    let x_dy     = product::<E>( &x[0 .. m], &dy );
+    let y_dx     = product::<E>( &y[0 .. m], &dx );
+    let num : Vec<E> = (0 .. m).map( |i| &x_dy[i] - &y_dx[i] ).collect();
+    //
+    // den = x * x + y * y
+    let den      = sum_of_squares::<E>( &x[0 .. m], &y[0 .. m] );
+    //
+    // dz = num / den
+    let mut dz   = vec![ &num[0] / &den[0] ; m ];
+    div::<E>(&num, &den, &mut dz);
+    //
+    // z
+    for k in 1 .. n {
+        z[k] = &dz[k-1] / &factor::<V, E>(k);
+    }
+}
+//
+// hypot
+/// z = hypot(x, y) = sqrt( x * x + y * y )
+pub(crate) fn hypot<V, E>( x : &[E], y : &[E], z : &mut [E] )
+where
+    V             : From<f32> ,
+    E             : Clone + From<V> ,
+    for<'a> E     : AddAssign<&'a E> + SubAssign<&'a E> ,
+    for<'a> &'a E : Mul<&'a E, Output=E> + Div<&'a E, Output=E> ,
+{   let sum_sq = sum_of_squares::<E>(x, y);
+    sqrt::<V, E>(&sum_sq, z);
+}
+// ---------------------------------------------------------------------------
+// unary recurrences
+// ---------------------------------------------------------------------------
+//
+// integrate
+/// z = g(x) where the Taylor coefficients d for g'(x) are known.
+///
+/// ```text
+///     z[k] = (1/k) sum_{j=1}^k j * x[j] * d[k-j]
+/// ```
+/// This is used for functions, like the inverse trigonometric functions
+/// and the error function, whose derivatives only depend on x.
+fn integrate<V, E>( x : &[E], d : &[E], z : &mut [E] )
+where
+    V             : From<f32> ,
+    E             : From<V> + FConst ,
+    for<'a> E     : AddAssign<&'a E> ,
+    for<'a> &'a E : Mul<&'a E, Output=E> + Div<&'a E, Output=E> ,
+{   for k in 1 .. z.len() {
+        let mut sum = E::zero();
+        for j in 1 ..= k {
+            let term = &factor::<V, E>(j) * &x[j];
+            sum     += &( &term * &d[k-j] );
+        }
+        z[k] = &sum / &factor::<V, E>(k);
+    }
+}
+//
+// abs
+/// z = abs(x) ; i.e., z\[k\] = signum( x\[0\] ) * x\[k\]
+pub(crate) fn abs<E>( x : &[E], z : &mut [E] )
+where
+    for<'a> &'a E : Mul<&'a E, Output=E> ,
+    for<'a> &'a E : FUnary<Output=E> ,
+{   let sign = x[0].signum();
+    for k in 1 .. z.len() {
+        z[k] = &sign * &x[k];
+    }
+}
+//
+// acos
+/// z = acos(x) ; see [integrate]
+pub(crate) fn acos<V, E>( x : &[E], z : &mut [E] )
+where
+    V             : From<f32> ,
+    E             : Clone + From<V> + FConst ,
+    for<'a> E     : AddAssign<&'a E> + SubAssign<&'a E> ,
+    for<'a> &'a E : Add<&'a E, Output=E> + Sub<&'a E, Output=E> ,
+    for<'a> &'a E : Mul<&'a E, Output=E> + Div<&'a E, Output=E> ,
+    for<'a> &'a E : FUnary<Output=E> ,
+{   let d = acos_partial::<V, E>(x, z);
+    integrate::<V, E>(x, &d, z);
+}
+//
+// acosh
+/// z = acosh(x) ; see [integrate]
+pub(crate) fn acosh<V, E>( x : &[E], z : &mut [E] )
+where
+    V             : From<f32> ,
+    E             : Clone + From<V> + FConst ,
+    for<'a> E     : AddAssign<&'a E> + SubAssign<&'a E> ,
+    for<'a> &'a E : Add<&'a E, Output=E> + Sub<&'a E, Output=E> ,
+    for<'a> &'a E : Mul<&'a E, Output=E> + Div<&'a E, Output=E> ,
+    for<'a> &'a E : FUnary<Output=E> ,
+{   let d = acosh_partial::<V, E>(x, z);
+    integrate::<V, E>(x, &d, z);
+}
+//
+// asin
+/// z = asin(x) ; see [integrate]
+pub(crate) fn asin<V, E>( x : &[E], z : &mut [E] )
+where
+    V             : From<f32> ,
+    E             : Clone + From<V> + FConst ,
+    for<'a> E     : AddAssign<&'a E> + SubAssign<&'a E> ,
+    for<'a> &'a E : Add<&'a E, Output=E> + Sub<&'a E, Output=E> ,
+    for<'a> &'a E : Mul<&'a E, Output=E> + Div<&'a E, Output=E> ,
+    for<'a> &'a E : FUnary<Output=E> ,
+{   let d = asin_partial::<V, E>(x, z);
+    integrate::<V, E>(x, &d, z);
+}
+//
+// asinh
+/// z = asinh(x) ; see [integrate]
+pub(crate) fn asinh<V, E>( x : &[E], z : &mut [E] )
+where
+    V             : From<f32> ,
+    E             : Clone + From<V> + FConst ,
+    for<'a> E     : AddAssign<&'a E> + SubAssign<&'a E> ,
+    for<'a> &'a E : Add<&'a E, Output=E> + Sub<&'a E, Output=E> ,
+    for<'a> &'a E : Mul<&'a E, Output=E> + Div<&'a E, Output=E> ,
+    for<'a> &'a E : FUnary<Output=E> ,
+{   let d = asinh_partial::<V, E>(x, z);
+    integrate::<V, E>(x, &d, z);
+}
+//
+// atan
+/// z = atan(x) ; see [integrate]
+pub(crate) fn atan<V, E>( x : &[E], z : &mut [E] )
+where
+    V             : From<f32> ,
+    E             : Clone + From<V> + FConst ,
+    for<'a> E     : AddAssign<&'a E> + SubAssign<&'a E> ,
+    for<'a> &'a E : Add<&'a E, Output=E> + Sub<&'a E, Output=E> ,
+    for<'a> &'a E : Mul<&'a E, Output=E> + Div<&'a E, Output=E> ,
+    for<'a> &'a E : FUnary<Output=E> ,
+{   let d = atan_partial::<V, E>(x, z);
+    integrate::<V, E>(x, &d, z);
+}
+//
+// atanh
+/// z = atanh(x) ; see [integrate]
+pub(crate) fn atanh<V, E>( x : &[E], z : &mut [E] )
+where
+    V             : From<f32> ,
+    E             : Clone + From<V> + FConst ,
+    for<'a> E     : AddAssign<&'a E> + SubAssign<&'a E> ,
+    for<'a> &'a E : Add<&'a E, Output=E> + Sub<&'a E, Output=E> ,
+    for<'a> &'a E : Mul<&'a E, Output=E> + Div<&'a E, Output=E> ,
+    for<'a> &'a E : FUnary<Output=E> ,
+{   let d = atanh_partial::<V, E>(x, z);
+    integrate::<V, E>(x, &d, z);
+}
+//
+// cbrt
+/// z = cbrt(x)
+///
+/// Differentiating z^3 = x gives 3 * x * z' = z * x' , hence
+/// ```text
+///     z[k] = ( sum_{j=1}^k j * x[j] * z[k-j]
+///            - 3 * sum_{j=1}^{k-1} j * z[j] * x[k-j] ) / (3 * k * x[0])
+/// ```
+pub(crate) fn cbrt<V, E>( x : &[E], z : &mut [E] )
+where
+    V             : From<f32> ,
+    E             : Clone + From<V> + FConst ,
+    for<'a> E     : AddAssign<&'a E> + SubAssign<&'a E> ,
+    for<'a> &'a E : Mul<&'a E, Output=E> + Div<&'a E, Output=E> ,
+{   let three = factor::<V, E>(3);
+    for k in 1 .. z.len() {
+        let mut sum = E::zero();
+        for j in 1 ..= k {
+            let term = &factor::<V, E>(j) * &x[j];
+            sum     += &( &term * &z[k-j] );
+        }
+        for j in 1 .. k {
+            let term = &factor::<V, E>(3 * j) * &z[j];
+            sum     -= &( &term * &x[k-j] );
+        }
+        let den = &( &three * &factor::<V, E>(k) ) * &x[0];
+        z[k]    = &sum / &den;
+    }
+}
+//
+// ceil
+/// z = ceil(x) ; i.e., z\[k\] = 0 for k > 0
+pub(crate) fn ceil<E>( x : &[E], z : &mut [E] )
+where
+    E : FConst ,
+{   signum::<E>(x, z)
+}
+//
+// cos
+/// z = cos(x) ; see [sin_cos]
+pub(crate) fn cos<V, E>( x : &[E], z : &mut [E] )
+where
+    V             : From<f32> ,
+    E             : Clone + From<V> + FConst ,
+    for<'a> E     : AddAssign<&'a E> + SubAssign<&'a E> ,
+    for<'a> &'a E : Mul<&'a E, Output=E> + Div<&'a E, Output=E> ,
+    for<'a> &'a E : FUnary<Output=E> ,
+{   let mut s = vec![ x[0].sin() ; x.len() ];
+    sin_cos::<V, E>(x, &mut s, z, false);
+}
+//
+// cosh
+/// z = cosh(x) ; see [sin_cos]
+pub(crate) fn cosh<V, E>( x : &[E], z : &mut [E] )
+where
+    V             : From<f32> ,
+    E             : Clone + From<V> + FConst ,
+    for<'a> E     : AddAssign<&'a E> + SubAssign<&'a E> ,
+    for<'a> &'a E : Mul<&'a E, Output=E> + Div<&'a E, Output=E> ,
+    for<'a> &'a E : FUnary<Output=E> ,
+{   let mut s = vec![ x[0].sinh() ; x.len() ];
+    sin_cos::<V, E>(x, &mut s, z, true);
+}
+//
+// erf
+/// z = erf(x) ; see [integrate]
+pub(crate) fn erf<V, E>( x : &[E], z : &mut [E] )
+where
+    V             : From<f32> ,
+    E             : Clone + From<V> + FConst ,
+    for<'a> E     : AddAssign<&'a E> + SubAssign<&'a E> ,
+    for<'a> &'a E : Add<&'a E, Output=E> + Sub<&'a E, Output=E> ,
+    for<'a> &'a E : Mul<&'a E, Output=E> + Div<&'a E, Output=E> ,
+    for<'a> &'a E : FUnary<Output=E> ,
+{   let d = erf_partial::<V, E>(x, z);
+    integrate::<V, E>(x, &d, z);
+}
+//
+// erfc
+/// z = erfc(x) ; see [integrate]
+pub(crate) fn erfc<V, E>( x : &[E], z : &mut [E] )
+where
+    V             : From<f32> ,
+    E             : Clone + From<V> + FConst ,
+    for<'a> E     : AddAssign<&'a E> + SubAssign<&'a E> ,
+    for<'a> &'a E : Add<&'a E, Output=E> + Sub<&'a E, Output=E> ,
+    for<'a> &'a E : Mul<&'a E, Output=E> + Div<&'a E, Output=E> ,
+    for<'a> &'a E : FUnary<Output=E> ,
+{   let d = erfc_partial::<V, E>(x, z);
+    integrate::<V, E>(x, &d, z);
+}
+//
+// exp
+/// z = exp(x)
+///
+/// ```text
+///     z[k] = (1/k) * sum_{j=1}^k j * x[j] * z[k-j]
+/// ```
+pub(crate) fn exp<V, E>( x : &[E], z : &mut [E] )
+where
+    V             : From<f32> ,
+    E             : Clone + From<V> + FConst ,
+    for<'a> E     : AddAssign<&'a E> ,
+    for<'a> &'a E : Mul<&'a E, Output=E> + Div<&'a E, Output=E> ,
+{   for k in 1 .. z.len() {
+        let mut sum = E::zero();
+        for j in 1 ..= k {
+            let term = &factor::<V, E>(j) * &x[j];
+            sum     += &( &term * &z[k-j] );
+        }
+        z[k] = &sum / &factor::<V, E>(k);
+    }
+}
+//
+// exp2
+/// z = exp2(x) = exp( ln(2) * x )
+pub(crate) fn exp2<V, E>( x : &[E], z : &mut [E] )
+where
+    V             : From<f32> ,
+    E             : Clone + From<V> + FConst ,
+    for<'a> E     : AddAssign<&'a E> ,
+    for<'a> &'a E : Mul<&'a E, Output=E> + Div<&'a E, Output=E> ,
+    for<'a> &'a E : FUnary<Output=E> ,
+{   let w = scale::<E>( &ln_factor::<V, E>(2), x );
+    exp::<V, E>(&w, z);
+}
+//
+// exp_m1
+/// z = exp(x) - 1
+pub(crate) fn exp_m1<V, E>( x : &[E], z : &mut [E] )
+where
+    V             : From<f32> ,
+    E             : Clone + From<V> + FConst ,
+    for<'a> E     : AddAssign<&'a E> ,
+    for<'a> &'a E : Mul<&'a E, Output=E> + Div<&'a E, Output=E> ,
+    for<'a> &'a E : FUnary<Output=E> ,
+{   let mut e = z.to_vec();
+    e[0]      = x[0].exp();
+    exp::<V, E>(x, &mut e);
+    z[1 ..].clone_from_slice( &e[1 ..] );
+}
+//
+// floor
+/// z = floor(x) ; i.e., z\[k\] = 0 for k > 0
+pub(crate) fn floor<E>( x : &[E], z : &mut [E] )
+where
+    E : FConst ,
+{   signum::<E>(x, z)
+}
+//
+// ln
+/// z = ln(x)
+///
+/// ```text
+///     z[k] = ( k * x[k] - sum_{j=1}^{k-1} j * z[j] * x[k-j] ) / (k * x[0])
+/// ```
+pub(crate) fn ln<V, E>( x : &[E], z : &mut [E] )
+where
+    V             : From<f32> ,
+    E             : Clone + From<V> ,
+    for<'a> E     : SubAssign<&'a E> ,
+    for<'a> &'a E : Mul<&'a E, Output=E> + Div<&'a E, Output=E> ,
+{   for k in 1 .. z.len() {
+        let mut sum = &factor::<V, E>(k) * &x[k];
+        for j in 1 .. k {
+            let term = &factor::<V, E>(j) * &z[j];
+            sum     -= &( &term * &x[k-j] );
+        }
+        let den = &factor::<V, E>(k) * &x[0];
+        z[k]    = &sum / &den;
+    }
+}
+//
+// ln_1p
+/// z = ln(1 + x)
+pub(crate) fn ln_1p<V, E>( x : &[E], z : &mut [E] )
+where
+    V             : From<f32> ,
+    E             : Clone + From<V> + FConst ,
+    for<'a> E     : SubAssign<&'a E> ,
+    for<'a> &'a E : Add<&'a E, Output=E> ,
+    for<'a> &'a E : Mul<&'a E, Output=E> + Div<&'a E, Output=E> ,
+{   let mut one_plus_x = x.to_vec();
+    one_plus_x[0]      = &E::one() + &x[0];
+    ln::<V, E>(&one_plus_x, z);
+}
+//
+// log_base
+/// z = ln(x) / ln(base)
+fn log_base<V, E>( base : usize, x : &[E], z : &mut [E] )
+where
+    V             : From<f32> ,
+    E             : Clone + From<V> ,
+    for<'a> E     : SubAssign<&'a E> ,
+    for<'a> &'a E : Mul<&'a E, Output=E> + Div<&'a E, Output=E> ,
+    for<'a> &'a E : FUnary<Output=E> ,
+{   let mut w = z.to_vec();
+    ln::<V, E>(x, &mut w);
+    let ln_base = ln_factor::<V, E>(base);
+    for k in 1 .. z.len() {
+        z[k] = &w[k] / &ln_base;
+    }
+}
+//
+// log10
+/// z = log10(x) ; see [log_base]
+pub(crate) fn log10<V, E>( x : &[E], z : &mut [E] )
+where
+    V             : From<f32> ,
+    E             : Clone + From<V> ,
+    for<'a> E     : SubAssign<&'a E> ,
+    for<'a> &'a E : Mul<&'a E, Output=E> + Div<&'a E, Output=E> ,
+    for<'a> &'a E : FUnary<Output=E> ,
+{   log_base::<V, E>(10, x, z);
+}
+//
+// log2
+/// z = log2(x) ; see [log_base]
+pub(crate) fn log2<V, E>( x : &[E], z : &mut [E] )
+where
+    V             : From<f32> ,
+    E             : Clone + From<V> ,
+    for<'a> E     : SubAssign<&'a E> ,
+    for<'a> &'a E : Mul<&'a E, Output=E> + Div<&'a E, Output=E> ,
+    for<'a> &'a E : FUnary<Output=E> ,
+{   log_base::<V, E>(2, x, z);
+}
+//
+// minus
+/// z = - x
+pub(crate) fn minus<E>( x : &[E], z : &mut [E] )
+where
+    for<'a> &'a E : FUnary<Output=E> ,
+{   for k in 1 .. z.len() {
+        z[k] = x[k].minus();
+    }
+}
+//
+// powi
+/// z = x^i where i is an integer.
+///
+/// The positive power is computed by repeated squaring using [mul].
+/// A negative power is computed as the reciprocal of the positive power
+/// using [div].
+pub(crate) fn powi<E>( x : &[E], exponent : i32, z : &mut [E] )
+where
+    E             : Clone + FConst ,
+    for<'a> E     : AddAssign<&'a E> + SubAssign<&'a E> ,
+    for<'a> &'a E : Mul<&'a E, Output=E> + Div<&'a E, Output=E> ,
+{   let n = z.len();
+    if exponent == 0 {
+        for z_k in z[1 ..].iter_mut() {
+            *z_k = E::zero();
+        }
+        return;
+    }
+    //
+    // power = x^|exponent|
+    let mut remainder         = exponent.unsigned_abs();
+    let mut base              = x.to_vec();
+    let mut power : Option< Vec<E> > = None;
+    loop {
+        if remainder % 2 == 1 {
+            power = match power {
+                None        => Some( base.clone() ),
+                Some(power) => Some( product::<E>(&power, &base) ),
+            };
+        }
+        remainder /= 2;
+        if remainder == 0 {
+            break;
+        }
+        base = product::<E>(&base, &base);
+    }
+    let power = power.unwrap();
+    //
+    // z
+    if 0 < exponent {
+        z[1 ..].clone_from_slice( &power[1 ..] );
+    } else {
+        let mut one = vec![ E::zero(); n ];
+        one[0]      = E::one();
+        div::<E>(&one, &power, z);
+    }
+}
+//
+// round
+/// z = round(x) ; i.e., z\[k\] = 0 for k > 0
+pub(crate) fn round<E>( x : &[E], z : &mut [E] )
+where
+    E : FConst ,
+{   signum::<E>(x, z)
+}
+//
+// signum
+/// z = signum(x) ; i.e., z\[k\] = 0 for k > 0
+pub(crate) fn signum<E>( _x : &[E], z : &mut [E] )
+where
+    E : FConst ,
+{   for z_k in z[1 ..].iter_mut() {
+        *z_k = E::zero();
+    }
+}
+//
+// sin
+/// z = sin(x) ; see [sin_cos]
+pub(crate) fn sin<V, E>( x : &[E], z : &mut [E] )
+where
+    V             : From<f32> ,
+    E             : Clone + From<V> + FConst ,
+    for<'a> E     : AddAssign<&'a E> + SubAssign<&'a E> ,
+    for<'a> &'a E : Mul<&'a E, Output=E> + Div<&'a E, Output=E> ,
+    for<'a> &'a E : FUnary<Output=E> ,
+{   let mut c = vec![ x[0].cos() ; x.len() ];
+    sin_cos::<V, E>(x, z, &mut c, false);
+}
+//
+// sin_cos
+/// s = sin(x) and c = cos(x)
+/// (or s = sinh(x) and c = cosh(x) when hyperbolic is true).
+///
+/// ```text
+///     s[k] =   (1/k) sum_{j=1}^k j * x[j] * c[k-j]
+///     c[k] = -+(1/k) sum_{j=1}^k j * x[j] * s[k-j]
+/// ```
+/// where the minus (plus) sign is for the trigonometric (hyperbolic) case.
+/// Both s\[0\] and c\[0\] are inputs.
+pub(crate) fn sin_cos<V, E>(
+    x          : &[E]     ,
+    s          : &mut [E] ,
+    c          : &mut [E] ,
+    hyperbolic : bool     ,
+)
+where
+    V             : From<f32> ,
+    E             : Clone + From<V> + FConst ,
+    for<'a> E     : AddAssign<&'a E> + SubAssign<&'a E> ,
+    for<'a> &'a E : Mul<&'a E, Output=E> + Div<&'a E, Output=E> ,
+{   for k in 1 .. s.len() {
+        let mut s_sum = E::zero();
+        let mut c_sum = E::zero();
+        for j in 1 ..= k {
+            let term = &factor::<V, E>(j) * &x[j];
+            s_sum   += &( &term * &c[k-j] );
+            if hyperbolic {
+                c_sum += &( &term * &s[k-j] );
+            } else {
+                c_sum -= &( &term * &s[k-j] );
+            }
+        }
+        s[k] = &s_sum / &factor::<V, E>(k);
+        c[k] = &c_sum / &factor::<V, E>(k);
+    }
+}
+//
+// sinh
+/// z = sinh(x) ; see [sin_cos]
+pub(crate) fn sinh<V, E>( x : &[E], z : &mut [E] )
+where
+    V             : From<f32> ,
+    E             : Clone + From<V> + FConst ,
+    for<'a> E     : AddAssign<&'a E> + SubAssign<&'a E> ,
+    for<'a> &'a E : Mul<&'a E, Output=E> + Div<&'a E, Output=E> ,
+    for<'a> &'a E : FUnary<Output=E> ,
+{   let mut c = vec![ x[0].cosh() ; x.len() ];
+    sin_cos::<V, E>(x, z, &mut c, true);
+}
+//
+// sqrt
+/// z = sqrt(x)
+///
+/// ```text
+///     z[k] = ( x[k] - sum_{j=1}^{k-1} z[j] * z[k-j] ) / ( 2 * z[0] )
+/// ```
+pub(crate) fn sqrt<V, E>( x : &[E], z : &mut [E] )
+where
+    V             : From<f32> ,
+    E             : Clone + From<V> ,
+    for<'a> E     : SubAssign<&'a E> ,
+    for<'a> &'a E : Mul<&'a E, Output=E> + Div<&'a E, Output=E> ,
+{   let two_z0 = &factor::<V, E>(2) * &z[0];
+    for k in 1 .. z.len() {
+        let mut sum = x[k].clone();
+        for j in 1 .. k {
+            sum -= &( &z[j] * &z[k-j] );
+        }
+        z[k] = &sum / &two_z0;
+    }
+}
+//
+// square
+/// z = x * x
+pub(crate) fn square<E>( x : &[E], z : &mut [E] )
+where
+    for<'a> E     : AddAssign<&'a E> ,
+    for<'a> &'a E : Mul<&'a E, Output=E> ,
+{   mul::<E>(x, x, z);
+}
+//
+// tan
+/// z = tan(x) ; see [tan_tanh]
+pub(crate) fn tan<V, E>( x : &[E], z : &mut [E] )
+where
+    V             : From<f32> ,
+    E             : Clone + From<V> + FConst ,
+    for<'a> E     : AddAssign<&'a E> + SubAssign<&'a E> ,
+    for<'a> &'a E : Add<&'a E, Output=E> + Sub<&'a E, Output=E> ,
+    for<'a> &'a E : Mul<&'a E, Output=E> + Div<&'a E, Output=E> ,
+{   let mut w = vec![ E::zero() ; x.len() ];
+    tan_tanh::<V, E>(x, z, &mut w, false);
+}
+//
+// tan_tanh
+/// z = tan(x) (or z = tanh(x) when hyperbolic is true).
+///
+/// ```text
+///     w    = 1 +- z * z
+///     z[k] = (1/k) sum_{j=1}^k j * x[j] * w[k-j]
+/// ```
+/// where the plus (minus) sign is for the trigonometric (hyperbolic) case.
+/// The vector w is an output.
+pub(crate) fn tan_tanh<V, E>(
+    x          : &[E]     ,
+    z          : &mut [E] ,
+    w          : &mut [E] ,
+    hyperbolic : bool     ,
+)
+where
+    V             : From<f32> ,
+    E             : Clone + From<V> + FConst ,
+    for<'a> E     : AddAssign<&'a E> + SubAssign<&'a E> ,
+    for<'a> &'a E : Add<&'a E, Output=E> + Sub<&'a E, Output=E> ,
+    for<'a> &'a E : Mul<&'a E, Output=E> + Div<&'a E, Output=E> ,
+{   let n  = z.len();
+    let z0_sq = &z[0] * &z[0];
+    w[0] = if hyperbolic {
+        &E::one() - &z0_sq
+    } else {
+        &E::one() + &z0_sq
+    };
+    for k in 1 .. n {
+        //
+        // z[k]
+        let mut sum = E::zero();
+        for j in 1 ..= k {
+            let term = &factor::<V, E>(j) * &x[j];
+            sum     += &( &term * &w[k-j] );
+        }
+        z[k] = &sum / &factor::<V, E>(k);
+        //
+        // w[k]
+        let mut sum = E::zero();
+        for j in 0 ..= k {
+            sum += &( &z[j] * &z[k-j] );
+        }
+        w[k] = if hyperbolic {
+            &E::zero() - &sum
+        } else {
+            sum
+        };
+    }
+}
+//
+// tanh
+/// z = tanh(x) ; see [tan_tanh]
+pub(crate) fn tanh<V, E>( x : &[E], z : &mut [E] )
+where
+    V             : From<f32> ,
+    E             : Clone + From<V> + FConst ,
+    for<'a> E     : AddAssign<&'a E> + SubAssign<&'a E> ,
+    for<'a> &'a E : Add<&'a E, Output=E> + Sub<&'a E, Output=E> ,
+    for<'a> &'a E : Mul<&'a E, Output=E> + Div<&'a E, Output=E> ,
+{   let mut w = vec![ E::zero() ; x.len() ];
+    tan_tanh::<V, E>(x, z, &mut w, true);
+}
+//
+// trunc
+/// z = trunc(x) ; i.e., z\[k\] = 0 for k > 0
+pub(crate) fn trunc<E>( x : &[E], z : &mut [E] )
+where
+    E : FConst ,
+{   signum::<E>(x, z)
+}
+// ---------------------------------------------------------------------------
+// reverse mode
+// ---------------------------------------------------------------------------
+//
+// If z(t) = g( x(t) ) and d(t) = g'( x(t) ), then for j <= k
+//
+//      partial z[k] w.r.t. x[j] = d[k-j]
+//
+// (because changing x[j] by delta changes z(t) by delta * t^j * d(t)).
+// The {name}_partial functions below compute the Taylor coefficients d
+// for each argument of an operator and [reverse_partial] uses them to
+// propagate partials from the result to the arguments.
+//
+// reverse_partial
+/// Propagate partials from a result to one of its arguments.
+///
+/// * pz : pz\[k\] is the partial of the scalar w.r.t. z\[k\] .
+/// * d  : is the Taylor coefficients of the partial of z w.r.t. x .
+/// * px :
+///   On input and output, px\[j\] is the partial of the scalar w.r.t. x\[j\]
+///   (with the output including the dependence of z on x).
+pub(crate) fn reverse_partial<E>( pz : &[E], d : &[E], px : &mut [E] )
+where
+    for<'a> E     : AddAssign<&'a E> ,
+    for<'a> &'a E : Mul<&'a E, Output=E> ,
+{   let n = pz.len();
+    for j in 0 .. n {
+        for k in j .. n {
+            px[j] += &( &pz[k] * &d[k-j] );
+        }
+    }
+}
+//
+// constant
+/// Taylor coefficients for the constant function equal to c.
+fn constant<E>( c : E, n : usize ) -> Vec<E>
+where
+    E : Clone + FConst ,
+{   let mut d = vec![ E::zero() ; n ];
+    d[0]      = c;
+    d
+}
+//
+// scale
+/// Taylor coefficients for c * x .
+fn scale<E>( c : &E, x : &[E] ) -> Vec<E>
+where
+    for<'a> &'a E : Mul<&'a E, Output=E> ,
+{   x.iter().map( |x_k| c * x_k ).collect()
+}
+//
+// quotient
+/// Taylor coefficients for x / y, including order zero.
+fn quotient<E>( x : &[E], y : &[E] ) -> Vec<E>
+where
+    E             : Clone ,
+    for<'a> E     : SubAssign<&'a E> ,
+    for<'a> &'a E : Mul<&'a E, Output=E> + Div<&'a E, Output=E> ,
+{   let mut z = vec![ &x[0] / &y[0] ; x.len() ];
+    div::<E>(x, y, &mut z);
+    z
+}
+//
+// reciprocal
+/// Taylor coefficients for 1 / x, including order zero.
+fn reciprocal<E>( x : &[E] ) -> Vec<E>
+where
+    E             : Clone + FConst ,
+    for<'a> E     : SubAssign<&'a E> ,
+    for<'a> &'a E : Mul<&'a E, Output=E> + Div<&'a E, Output=E> ,
+{   let one = constant::<E>( E::one(), x.len() );
+    quotient::<E>(&one, x)
+}
+//
+// shift_square
+/// Taylor coefficients for c + x * x (c - x * x when negative is true),
+/// including order zero.
+fn shift_square<E>( c : &E, x : &[E], negative : bool ) -> Vec<E>
+where
+    E             : Clone ,
+    for<'a> E     : AddAssign<&'a E> ,
+    for<'a> &'a E : Add<&'a E, Output=E> + Mul<&'a E, Output=E> ,
+    for<'a> &'a E : FUnary<Output=E> ,
+{   let x_sq  = product::<E>(x, x);
+    let mut w = if negative {
+        x_sq.iter().map( |x_sq_k| x_sq_k.minus() ).collect()
+    } else {
+        x_sq
+    };
+    w[0] = c + &w[0];
+    w
+}
+//
+// square_root
+/// Taylor coefficients for sqrt(x), including order zero.
+fn square_root<V, E>( x : &[E] ) -> Vec<E>
+where
+    V             : From<f32> ,
+    E             : Clone + From<V> ,
+    for<'a> E     : SubAssign<&'a E> ,
+    for<'a> &'a E : Mul<&'a E, Output=E> + Div<&'a E, Output=E> ,
+    for<'a> &'a E : FUnary<Output=E> ,
+{   let mut z = vec![ x[0].sqrt() ; x.len() ];
+    sqrt::<V, E>(x, &mut z);
+    z
+}
+//
+// abs_partial
+/// partial of z = abs(x) is signum(x)
+pub(crate) fn abs_partial<E>( x : &[E], _z : &[E] ) -> Vec<E>
+where
+    E             : Clone + FConst ,
+    for<'a> &'a E : FUnary<Output=E> ,
+{   constant::<E>( x[0].signum(), x.len() )
+}
+//
+// acos_partial
+/// partial of z = acos(x) is - 1 / sqrt(1 - x * x)
+pub(crate) fn acos_partial<V, E>( x : &[E], z : &[E] ) -> Vec<E>
+where
+    V             : From<f32> ,
+    E             : Clone + From<V> + FConst ,
+    for<'a> E     : AddAssign<&'a E> + SubAssign<&'a E> ,
+    for<'a> &'a E : Add<&'a E, Output=E> + Sub<&'a E, Output=E> ,
+    for<'a> &'a E : Mul<&'a E, Output=E> + Div<&'a E, Output=E> ,
+    for<'a> &'a E : FUnary<Output=E> ,
+{   let d = asin_partial::<V, E>(x, z);
+    d.iter().map( |d_k| d_k.minus() ).collect()
+}
+//
+// acosh_partial
+/// partial of z = acosh(x) is 1 / sqrt(x * x - 1)
+pub(crate) fn acosh_partial<V, E>( x : &[E], _z : &[E] ) -> Vec<E>
+where
+    V             : From<f32> ,
+    E             : Clone + From<V> + FConst ,
+    for<'a> E     : AddAssign<&'a E> + SubAssign<&'a E> ,
+    for<'a> &'a E : Add<&'a E, Output=E> + Sub<&'a E, Output=E> ,
+    for<'a> &'a E : Mul<&'a E, Output=E> + Div<&'a E, Output=E> ,
+    for<'a> &'a E : FUnary<Output=E> ,
+{   let w = shift_square::<E>( &E::one().minus(), x, false );
+    reciprocal::<E>( &square_root::<V, E>(&w) )
+}
+//
+// asin_partial
+/// partial of z = asin(x) is 1 / sqrt(1 - x * x)
+pub(crate) fn asin_partial<V, E>( x : &[E], _z : &[E] ) -> Vec<E>
+where
+    V             : From<f32> ,
+    E             : Clone + From<V> + FConst ,
+    for<'a> E     : AddAssign<&'a E> + SubAssign<&'a E> ,
+    for<'a> &'a E : Add<&'a E, Output=E> + Sub<&'a E, Output=E> ,
+    for<'a> &'a E : Mul<&'a E, Output=E> + Div<&'a E, Output=E> ,
+    for<'a> &'a E : FUnary<Output=E> ,
+{   let w = shift_square::<E>( &E::one(), x, true );
+    reciprocal::<E>( &square_root::<V, E>(&w) )
+}
+//
+// asinh_partial
+/// partial of z = asinh(x) is 1 / sqrt(1 + x * x)
+pub(crate) fn asinh_partial<V, E>( x : &[E], _z : &[E] ) -> Vec<E>
+where
+    V             : From<f32> ,
+    E             : Clone + From<V> + FConst ,
+    for<'a> E     : AddAssign<&'a E> + SubAssign<&'a E> ,
+    for<'a> &'a E : Add<&'a E, Output=E> + Sub<&'a E, Output=E> ,
+    for<'a> &'a E : Mul<&'a E, Output=E> + Div<&'a E, Output=E> ,
+    for<'a> &'a E : FUnary<Output=E> ,
+{   let w = shift_square::<E>( &E::one(), x, false );
+    reciprocal::<E>( &square_root::<V, E>(&w) )
+}
+//
+// atan_partial
+/// partial of z = atan(x) is 1 / (1 + x * x)
+pub(crate) fn atan_partial<V, E>( x : &[E], _z : &[E] ) -> Vec<E>
+where
+    V             : From<f32> ,
+    E             : Clone + From<V> + FConst ,
+    for<'a> E     : AddAssign<&'a E> + SubAssign<&'a E> ,
+    for<'a> &'a E : Add<&'a E, Output=E> + Sub<&'a E, Output=E> ,
+    for<'a> &'a E : Mul<&'a E, Output=E> + Div<&'a E, Output=E> ,
+    for<'a> &'a E : FUnary<Output=E> ,
+{   let w = shift_square::<E>( &E::one(), x, false );
+    reciprocal::<E>(&w)
+}
+//
+// atanh_partial
+/// partial of z = atanh(x) is 1 / (1 - x * x)
+pub(crate) fn atanh_partial<V, E>( x : &[E], _z : &[E] ) -> Vec<E>
+where
+    V             : From<f32> ,
+    E             : Clone + From<V> + FConst ,
+    for<'a> E     : AddAssign<&'a E> + SubAssign<&'a E> ,
+    for<'a> &'a E : Add<&'a E, Output=E> + Sub<&'a E, Output=E> ,
+    for<'a> &'a E : Mul<&'a E, Output=E> + Div<&'a E, Output=E> ,
+    for<'a> &'a E : FUnary<Output=E> ,
+{   let w = shift_square::<E>( &E::one(), x, true );
+    reciprocal::<E>(&w)
+}
+//
+// cbrt_partial
+/// partial of z = cbrt(x) is 1 / (3 * z * z)
+pub(crate) fn cbrt_partial<V, E>( _x : &[E], z : &[E] ) -> Vec<E>
+where
+    V             : From<f32> ,
+    E             : Clone + From<V> + FConst ,
+    for<'a> E     : AddAssign<&'a E> + SubAssign<&'a E> ,
+    for<'a> &'a E : Mul<&'a E, Output=E> + Div<&'a E, Output=E> ,
+{   let z_sq = product::<E>(z, z);
+    reciprocal::<E>( &scale::<E>( &factor::<V, E>(3), &z_sq ) )
+}
+//
+// ceil_partial
+/// partial of z = ceil(x) is zero
+pub(crate) fn ceil_partial<E>( x : &[E], z : &[E] ) -> Vec<E>
+where
+    E : Clone + FConst ,
+{   signum_partial::<E>(x, z)
+}
+//
+// cos_partial
+/// partial of z = cos(x) is - sin(x)
+pub(crate) fn cos_partial<V, E>( x : &[E], z : &[E] ) -> Vec<E>
+where
+    V             : From<f32> ,
+    E             : Clone + From<V> + FConst ,
+    for<'a> E     : AddAssign<&'a E> + SubAssign<&'a E> ,
+    for<'a> &'a E : Mul<&'a E, Output=E> + Div<&'a E, Output=E> ,
+    for<'a> &'a E : FUnary<Output=E> ,
+{   let mut s = vec![ x[0].sin() ; x.len() ];
+    let mut c = z.to_vec();
+    sin_cos::<V, E>(x, &mut s, &mut c, false);
+    s.iter().map( |s_k| s_k.minus() ).collect()
+}
+//
+// cosh_partial
+/// partial of z = cosh(x) is sinh(x)
+pub(crate) fn cosh_partial<V, E>( x : &[E], z : &[E] ) -> Vec<E>
+where
+    V             : From<f32> ,
+    E             : Clone + From<V> + FConst ,
+    for<'a> E     : AddAssign<&'a E> + SubAssign<&'a E> ,
+    for<'a> &'a E : Mul<&'a E, Output=E> + Div<&'a E, Output=E> ,
+    for<'a> &'a E : FUnary<Output=E> ,
+{   let mut s = vec![ x[0].sinh() ; x.len() ];
+    let mut c = z.to_vec();
+    sin_cos::<V, E>(x, &mut s, &mut c, true);
+    s
+}
+//
+// erf_partial
+/// partial of z = erf(x) is (2 / sqrt(pi)) * exp(- x * x)
+pub(crate) fn erf_partial<V, E>( x : &[E], _z : &[E] ) -> Vec<E>
+where
+    V             : From<f32> ,
+    E             : Clone + From<V> + FConst ,
+    for<'a> E     : AddAssign<&'a E> + SubAssign<&'a E> ,
+    for<'a> &'a E : Add<&'a E, Output=E> + Sub<&'a E, Output=E> ,
+    for<'a> &'a E : Mul<&'a E, Output=E> + Div<&'a E, Output=E> ,
+    for<'a> &'a E : FUnary<Output=E> ,
+{   let w     = shift_square::<E>( &E::zero(), x, true );
+    let mut e = vec![ w[0].exp() ; x.len() ];
+    exp::<V, E>(&w, &mut e);
+    let c     = &factor::<V, E>(2) / &( &E::pi() ).sqrt();
+    scale::<E>(&c, &e)
+}
+//
+// erfc_partial
+/// partial of z = erfc(x) is - (2 / sqrt(pi)) * exp(- x * x)
+pub(crate) fn erfc_partial<V, E>( x : &[E], z : &[E] ) -> Vec<E>
+where
+    V             : From<f32> ,
+    E             : Clone + From<V> + FConst ,
+    for<'a> E     : AddAssign<&'a E> + SubAssign<&'a E> ,
+    for<'a> &'a E : Add<&'a E, Output=E> + Sub<&'a E, Output=E> ,
+    for<'a> &'a E : Mul<&'a E, Output=E> + Div<&'a E, Output=E> ,
+    for<'a> &'a E : FUnary<Output=E> ,
+{   let d = erf_partial::<V, E>(x, z);
+    d.iter().map( |d_k| d_k.minus() ).collect()
+}
+//
+// exp_partial
+/// partial of z = exp(x) is z
+pub(crate) fn exp_partial<E>( _x : &[E], z : &[E] ) -> Vec<E>
+where
+    E : Clone ,
+{   z.to_vec()
+}
+//
+// exp2_partial
+/// partial of z = exp2(x) is ln(2) * z
+pub(crate) fn exp2_partial<V, E>( _x : &[E], z : &[E] ) -> Vec<E>
+where
+    V             : From<f32> ,
+    E             : From<V> ,
+    for<'a> &'a E : Mul<&'a E, Output=E> ,
+    for<'a> &'a E : FUnary<Output=E> ,
+{   scale::<E>( &ln_factor::<V, E>(2), z )
+}
+//
+// exp_m1_partial
+/// partial of z = exp(x) - 1 is z + 1
+pub(crate) fn exp_m1_partial<E>( _x : &[E], z : &[E] ) -> Vec<E>
+where
+    E             : Clone + FConst ,
+    for<'a> &'a E : Add<&'a E, Output=E> ,
+{   let mut d = z.to_vec();
+    d[0]      = &z[0] + &E::one();
+    d
+}
+//
+// floor_partial
+/// partial of z = floor(x) is zero
+pub(crate) fn floor_partial<E>( x : &[E], z : &[E] ) -> Vec<E>
+where
+    E : Clone + FConst ,
+{   signum_partial::<E>(x, z)
+}
+//
+// ln_partial
+/// partial of z = ln(x) is 1 / x
+pub(crate) fn ln_partial<E>( x : &[E], _z : &[E] ) -> Vec<E>
+where
+    E             : Clone + FConst ,
+    for<'a> E     : SubAssign<&'a E> ,
+    for<'a> &'a E : Mul<&'a E, Output=E> + Div<&'a E, Output=E> ,
+{   reciprocal::<E>(x)
+}
+//
+// ln_1p_partial
+/// partial of z = ln(1 + x) is 1 / (1 + x)
+pub(crate) fn ln_1p_partial<E>( x : &[E], _z : &[E] ) -> Vec<E>
+where
+    E             : Clone + FConst ,
+    for<'a> E     : SubAssign<&'a E> ,
+    for<'a> &'a E : Add<&'a E, Output=E> ,
+    for<'a> &'a E : Mul<&'a E, Output=E> + Div<&'a E, Output=E> ,
+{   let mut one_plus_x = x.to_vec();
+    one_plus_x[0]      = &E::one() + &x[0];
+    reciprocal::<E>(&one_plus_x)
+}
+//
+// log10_partial
+/// partial of z = log10(x) is 1 / ( ln(10) * x )
+pub(crate) fn log10_partial<V, E>( x : &[E], _z : &[E] ) -> Vec<E>
+where
+    V             : From<f32> ,
+    E             : Clone + From<V> + FConst ,
+    for<'a> E     : SubAssign<&'a E> ,
+    for<'a> &'a E : Mul<&'a E, Output=E> + Div<&'a E, Output=E> ,
+    for<'a> &'a E : FUnary<Output=E> ,
+{   reciprocal::<E>( &scale::<E>( &ln_factor::<V, E>(10), x ) )
+}
+//
+// log2_partial
+/// partial of z = log2(x) is 1 / ( ln(2) * x )
+pub(crate) fn log2_partial<V, E>( x : &[E], _z : &[E] ) -> Vec<E>
+where
+    V             : From<f32> ,
+    E             : Clone + From<V> + FConst ,
+    for<'a> E     : SubAssign<&'a E> ,
+    for<'a> &'a E : Mul<&'a E, Output=E> + Div<&'a E, Output=E> ,
+    for<'a> &'a E : FUnary<Output=E> ,
+{   reciprocal::<E>( &scale::<E>( &ln_factor::<V, E>(2), x ) )
+}
+//
+// minus_partial
+/// partial of z = - x is - 1
+pub(crate) fn minus_partial<E>( x : &[E], _z : &[E] ) -> Vec<E>
+where
+    E             : Clone + FConst ,
+    for<'a> &'a E : FUnary<Output=E> ,
+{   constant::<E>( E::one().minus(), x.len() )
+}
+//
+// powi_partial
+/// partial of z = x^i is i * x^(i-1)
+pub(crate) fn powi_partial<V, E>( x : &[E], exponent : i32 ) -> Vec<E>
+where
+    V             : From<f32> ,
+    E             : Clone + From<V> + FConst ,
+    for<'a> E     : AddAssign<&'a E> + SubAssign<&'a E> ,
+    for<'a> &'a E : Mul<&'a E, Output=E> + Div<&'a E, Output=E> ,
+    for<'a> &'a E : FUnary<Output=E> ,
+{   if exponent == 0 {
+        return constant::<E>( E::zero(), x.len() );
+    }
+    let mut power = vec![ x[0].powi(exponent - 1) ; x.len() ];
+    powi::<E>(x, exponent - 1, &mut power);
+    scale::<E>( &E::from( V::from(exponent as f32) ), &power )
+}
+//
+// round_partial
+/// partial of z = round(x) is zero
+pub(crate) fn round_partial<E>( x : &[E], z : &[E] ) -> Vec<E>
+where
+    E : Clone + FConst ,
+{   signum_partial::<E>(x, z)
+}
+//
+// signum_partial
+/// partial of z = signum(x) is zero
+pub(crate) fn signum_partial<E>( x : &[E], _z : &[E] ) -> Vec<E>
+where
+    E : Clone + FConst ,
+{   constant::<E>( E::zero(), x.len() )
+}
+//
+// sin_partial
+/// partial of z = sin(x) is cos(x)
+pub(crate) fn sin_partial<V, E>( x : &[E], z : &[E] ) -> Vec<E>
+where
+    V             : From<f32> ,
+    E             : Clone + From<V> + FConst ,
+    for<'a> E     : AddAssign<&'a E> + SubAssign<&'a E> ,
+    for<'a> &'a E : Mul<&'a E, Output=E> + Div<&'a E, Output=E> ,
+    for<'a> &'a E : FUnary<Output=E> ,
+{   let mut s = z.to_vec();
+    let mut c = vec![ x[0].cos() ; x.len() ];
+    sin_cos::<V, E>(x, &mut s, &mut c, false);
+    c
+}
+//
+// sinh_partial
+/// partial of z = sinh(x) is cosh(x)
+pub(crate) fn sinh_partial<V, E>( x : &[E], z : &[E] ) -> Vec<E>
+where
+    V             : From<f32> ,
+    E             : Clone + From<V> + FConst ,
+    for<'a> E     : AddAssign<&'a E> + SubAssign<&'a E> ,
+    for<'a> &'a E : Mul<&'a E, Output=E> + Div<&'a E, Output=E> ,
+    for<'a> &'a E : FUnary<Output=E> ,
+{   let mut s = z.to_vec();
+    let mut c = vec![ x[0].cosh() ; x.len() ];
+    sin_cos::<V, E>(x, &mut s, &mut c, true);
+    c
+}
+//
+// sqrt_partial
+/// partial of z = sqrt(x) is 1 / (2 * z)
+pub(crate) fn sqrt_partial<V, E>( _x : &[E], z : &[E] ) -> Vec<E>
+where
+    V             : From<f32> ,
+    E             : Clone + From<V> + FConst ,
+    for<'a> E     : SubAssign<&'a E> ,
+    for<'a> &'a E : Mul<&'a E, Output=E> + Div<&'a E, Output=E> ,
+{   let two_z = scale::<E>( &factor::<V, E>(2), z );
+    reciprocal::<E>(&two_z)
+}
+//
+// square_partial
+/// partial of z = x * x is 2 * x
+pub(crate) fn square_partial<V, E>( x : &[E], _z : &[E] ) -> Vec<E>
+where
+    V             : From<f32> ,
+    E             : From<V> ,
+    for<'a> &'a E : Mul<&'a E, Output=E> ,
+{   scale::<E>( &factor::<V, E>(2), x )
+}
+//
+// tan_partial
+/// partial of z = tan(x) is 1 + z * z
+pub(crate) fn tan_partial<E>( _x : &[E], z : &[E] ) -> Vec<E>
+where
+    E             : Clone + FConst ,
+    for<'a> E     : AddAssign<&'a E> ,
+    for<'a> &'a E : Add<&'a E, Output=E> + Mul<&'a E, Output=E> ,
+{   let mut d = product::<E>(z, z);
+    d[0]      = &E::one() + &d[0];
+    d
+}
+//
+// tanh_partial
+/// partial of z = tanh(x) is 1 - z * z
+pub(crate) fn tanh_partial<E>( _x : &[E], z : &[E] ) -> Vec<E>
+where
+    E             : Clone + FConst ,
+    for<'a> E     : AddAssign<&'a E> ,
+    for<'a> &'a E : Sub<&'a E, Output=E> + Mul<&'a E, Output=E> ,
+    for<'a> &'a E : FUnary<Output=E> ,
+{   let z_sq  = product::<E>(z, z);
+    let mut d : Vec<E> = z_sq.iter().map( |z_sq_k| z_sq_k.minus() ).collect();
+    d[0]      = &E::one() - &z_sq[0];
+    d
+}
+//
+// trunc_partial
+/// partial of z = trunc(x) is zero
+pub(crate) fn trunc_partial<E>( x : &[E], z : &[E] ) -> Vec<E>
+where
+    E : Clone + FConst ,
+{   signum_partial::<E>(x, z)
+}
+//
+// add_partial
+/// partials of z = x + y are 1 and 1
+pub(crate) fn add_partial<E>( x : &[E], _y : &[E], _z : &[E] )
+-> ( Vec<E>, Vec<E> )
+where
+    E : Clone + FConst ,
+{   let one = constant::<E>( E::one(), x.len() );
+    ( one.clone(), one )
+}
+//
+// sub_partial
+/// partials of z = x - y are 1 and -1
+pub(crate) fn sub_partial<E>( x : &[E], _y : &[E], _z : &[E] )
+-> ( Vec<E>, Vec<E> )
+where
+    E             : Clone + FConst ,
+    for<'a> &'a E : FUnary<Output=E> ,
+{   let n = x.len();
+    ( constant::<E>( E::one(), n ), constant::<E>( E::one().minus(), n ) )
+}
+//
+// mul_partial
+/// partials of z = x * y are y and x
+pub(crate) fn mul_partial<E>( x : &[E], y : &[E], _z : &[E] )
+-> ( Vec<E>, Vec<E> )
+where
+    E : Clone ,
+{   ( y.to_vec(), x.to_vec() )
+}
+//
+// div_partial
+/// partials of z = x / y are 1 / y and - z / y
+pub(crate) fn div_partial<E>( _x : &[E], y : &[E], z : &[E] )
+-> ( Vec<E>, Vec<E> )
+where
+    E             : Clone + FConst ,
+    for<'a> E     : SubAssign<&'a E> ,
+    for<'a> &'a E : Mul<&'a E, Output=E> + Div<&'a E, Output=E> ,
+    for<'a> &'a E : FUnary<Output=E> ,
+{   let d_x     = reciprocal::<E>(y);
+    let z_div_y = quotient::<E>(z, y);
+    let d_y     = z_div_y.iter().map( |v| v.minus() ).collect();
+    ( d_x, d_y )
+}
+//
+// powf_partial
+/// partials of z = x^y are y * z / x and ln(x) * z
+pub(crate) fn powf_partial<V, E>( x : &[E], y : &[E], z : &[E] )
+-> ( Vec<E>, Vec<E> )
+where
+    V             : From<f32> ,
+    E             : Clone + From<V> + FConst ,
+    for<'a> E     : AddAssign<&'a E> + SubAssign<&'a E> ,
+    for<'a> &'a E : Mul<&'a E, Output=E> + Div<&'a E, Output=E> ,
+    for<'a> &'a E : FUnary<Output=E> ,
+{   let y_z       = product::<E>(y, z);
+    let d_x       = quotient::<E>(&y_z, x);
+    let mut log_x = vec![ x[0].ln() ; x.len() ];
+    ln::<V, E>(x, &mut log_x);
+    let d_y       = product::<E>(&log_x, z);
+    ( d_x, d_y )
+}
+//
+// atan2_partial
+/// partials of z = atan2(y, x) are x / (x * x + y * y) and
+/// - y / (x * x + y * y)
+pub(crate) fn atan2_partial<E>( y : &[E], x : &[E], _z : &[E] )
+-> ( Vec<E>, Vec<E> )
+where
+    E             : Clone + FConst ,
+    for<'a> E     : AddAssign<&'a E> + SubAssign<&'a E> ,
+    for<'a> &'a E : Mul<&'a E, Output=E> + Div<&'a E, Output=E> ,
+    for<'a> &'a E : FUnary<Output=E> ,
+{   let den     = sum_of_squares::<E>(x, y);
+    let d_y     = quotient::<E>(x, &den);
+    let y_div   = quotient::<E>(y, &den);
+    let d_x     = y_div.iter().map( |v| v.minus() ).collect();
+    ( d_y, d_x )
+}
+//
+// hypot_partial
+/// partials of z = hypot(x, y) are x / z and y / z
+pub(crate) fn hypot_partial<E>( x : &[E], y : &[E], z : &[E] )
+-> ( Vec<E>, Vec<E> )
+where
+    E             : Clone ,
+    for<'a> E     : SubAssign<&'a E> ,
+    for<'a> &'a E : Mul<&'a E, Output=E> + Div<&'a E, Output=E> ,
+{   ( quotient::<E>(x, z), quotient::<E>(y, z) )
+}
@@ -0,0 +1,152 @@
+// SPDX-License-Identifier: EPL-2.0 OR GPL-2.0-or-later
+// SPDX-FileCopyrightText: Bradley M. Bell <bradbell@seanet.com>
+// SPDX-FileContributor: 2026 Bradley M. Bell
+//
+//! Evaluate the acosh operator
+//!
+//! Link to [parent module](super)
+// --------------------------------------------------------------------------
+// z   = acosh(x) = ln( x + sqrt(x^2 - 1) )
+// z_x = 1 / sqrt(x^2 - 1)
+// --------------------------------------------------------------------------
+// use
+//
+use std::ops::{
+    Mul,
+    Div,
+    Sub,
+    AddAssign,
+};
+//
+use crate::{
+    AD,
+    FConst,
+    FUnary,
+};
+//
+use crate::ad::ADType;
+use crate::op::unary::common;
+use crate::tape::sealed::ThisThreadTape;
+use crate::op::taylor::{
+    TaylorValue,
+    TaylorRef,
+};
+use crate::op::info::OpFns;
+use crate::op::info::ConstData;
+use crate::op::info::Linearity;
+use crate::op::id::ACOSH_OP;
+// -------------------------------------------------------------------------
+// acosh_forward_dyp
+common::forward_dyp!(acosh);
+//
+// acosh_forward_var
+common::forward_var!(acosh);
+//
+// acosh_rust_src
+common::rust_src!(acosh);
+//
+// acosh_forward_taylor
+common::forward_taylor!(acosh);
+//
+// acosh_reverse_taylor
+common::reverse_taylor!(acosh);
+//
+// acosh_forward_der
+/// First order forward mode for acosh(variable);
+/// see [ForwardDer](crate::op::info::ForwardDer)
+fn acosh_forward_der<V, E>(
+    _dyp_all   :   &[E]        ,
+    var_all    :   &[E]        ,
+    var_der    :   &mut [E]    ,
+    const_data : ConstData<V> )
+where
+    E             : FConst ,
+    for<'a> &'a E : FUnary<Output=E>,
+    for<'a> &'a E : Mul<&'a E, Output=E>,
+    for<'a> &'a E : Div<&'a E, Output=E>,
+    for<'a> &'a E : Sub<&'a E, Output=E>,
+{
+    let ConstData {arg, arg_type, res, ..} = const_data;
+    //
+    debug_assert!( arg.len() == 1 );
+    debug_assert!( arg_type[0].is_variable() );
+    let one      = E::one();
+    let x        = arg[0] as usize;
+    let z        = res;
+    let x_sq     = &var_all[x] * &var_all[x];
+    let den      = ( &( &x_sq - &one ) ).sqrt();
+    var_der[z]   = &var_der[x] / &den;
+}
+// acosh_reverse_der
+/// First order reverse mode for acosh(variable);
+/// see [ForwardDer](crate::op::info::ForwardDer)
+fn acosh_reverse_der<V, E>(
+    _dyp_all   :   &[E]        ,
+    var_all    :   &[E]        ,
+    var_der    :   &mut [E]    ,
+    const_data : ConstData<V> )
+where
+    for<'a> E     : AddAssign<&'a E> ,
+    E             : FConst ,
+    for<'a> &'a E : FUnary<Output=E>,
+    for<'a> &'a E : Mul<&'a E, Output=E>,
+    for<'a> &'a E : Div<&'a E, Output=E>,
+    for<'a> &'a E : Sub<&'a E, Output=E>,
+{
+    let ConstData {arg, arg_type, res, ..} = const_data;
+    //
+    debug_assert!( arg.len() == 1 );
+    debug_assert!( arg_type[0].is_variable() );
+    let one         = E::one();
+    let x           = arg[0] as usize;
+    let z           = res;
+    let x_sq        = &var_all[x] * &var_all[x];
+    let den         = ( &( &x_sq - &one ) ).sqrt();
+    var_der[x]     += &( &var_der[z] / &den );
+}
+// ---------------------------------------------------------------------------
+// set_op_fns
+/// Set the operator functions for all the ACOSH_OP operator.
+///
+/// * op_fns_vec :
+///   The map from [op::id](crate::op::id) to operator functions.
+///   The the map results for ACOSH_OP are set.
+pub fn set_op_fns<V>( op_fns_vec : &mut [OpFns<V>] ) where
+    for<'a> &'a AD<V> : Mul<&'a AD<V>, Output = AD<V> > ,
+    for<'a> &'a V     : Mul<&'a V, Output = V> ,
+    //
+    for<'a> &'a AD<V> : Div<&'a AD<V>, Output = AD<V> > ,
+    for<'a> &'a V     : Div<&'a V, Output = V> ,
+    //
+    for<'a> &'a AD<V> : Sub<&'a AD<V>, Output = AD<V> > ,
+    for<'a> &'a V     : Sub<&'a V, Output = V> ,
+    //
+    for<'a> V         : AddAssign<&'a V>,
+    for<'a> AD<V>     : AddAssign<&'a AD<V> >,
+    //
+    V                 : Clone + FConst + ThisThreadTape ,
+    for<'a> &'a V     : FUnary<Output=V>,
+    //
+    // forward_taylor
+    V             : TaylorValue + PartialEq ,
+    for<'a> &'a V : TaylorRef<'a, V> ,
+{
+    op_fns_vec[ACOSH_OP as usize] = OpFns{
+        name              : "acosh",
+        forward_dyp_value : acosh_forward_dyp::<V, V>,
+        forward_dyp_ad    : acosh_forward_dyp::<V, AD<V> >,
+        forward_var_value : acosh_forward_var::<V, V>,
+        forward_var_ad    : acosh_forward_var::<V, AD<V> >,
+        forward_der_value : acosh_forward_der::<V, V>,
+        forward_der_ad    : acosh_forward_der::<V, AD<V> >,
+        reverse_der_value : acosh_reverse_der::<V, V>,
+        reverse_der_ad    : acosh_reverse_der::<V, AD<V> >,
+        forward_taylor_value : acosh_forward_taylor::<V, V>,
+        forward_taylor_ad    : acosh_forward_taylor::<V, AD<V> >,
+        reverse_taylor_value : acosh_reverse_taylor::<V, V>,
+        reverse_taylor_ad    : acosh_reverse_taylor::<V, AD<V> >,
+        rust_src          : acosh_rust_src,
+        reverse_depend    : common::reverse_depend,
+        linearity         : Linearity::Nonlinear,
+    };
+}
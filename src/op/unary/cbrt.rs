@@ -0,0 +1,146 @@
+// SPDX-License-Identifier: EPL-2.0 OR GPL-2.0-or-later
+// SPDX-FileCopyrightText: Bradley M. Bell <bradbell@seanet.com>
+// SPDX-FileContributor: 2026 Bradley M. Bell
+//
+//! Evaluate the cbrt operator
+//!
+//! Link to [parent module](super)
+// --------------------------------------------------------------------------
+// z   = cbrt(x)
+// z_x = 1 / ( 3 * z^2 )
+// --------------------------------------------------------------------------
+// use
+//
+use std::ops::{
+    Mul,
+    Div,
+    AddAssign,
+};
+//
+use crate::{
+    AD,
+    FConst,
+    FUnary,
+};
+//
+use crate::ad::ADType;
+use crate::op::unary::common;
+use crate::tape::sealed::ThisThreadTape;
+use crate::op::taylor::{
+    TaylorValue,
+    TaylorRef,
+};
+use crate::op::info::OpFns;
+use crate::op::info::ConstData;
+use crate::op::info::Linearity;
+use crate::op::id::CBRT_OP;
+// -------------------------------------------------------------------------
+// cbrt_forward_dyp
+common::forward_dyp!(cbrt);
+//
+// cbrt_forward_var
+common::forward_var!(cbrt);
+//
+// cbrt_rust_src
+common::rust_src!(cbrt);
+//
+// cbrt_forward_taylor
+common::forward_taylor!(cbrt);
+//
+// cbrt_reverse_taylor
+common::reverse_taylor!(cbrt);
+//
+// cbrt_forward_der
+/// First order forward mode for cbrt(variable);
+/// see [ForwardDer](crate::op::info::ForwardDer)
+fn cbrt_forward_der<V, E>(
+    _dyp_all   :   &[E]        ,
+    var_all    :   &[E]        ,
+    var_der    :   &mut [E]    ,
+    const_data : ConstData<V> )
+where
+    E             : FConst ,
+    V             : From<f32>,
+    for<'a> &'a V : FUnary<Output=V>,
+    for<'a> &'a E : FUnary<Output=E>,
+    for<'a> &'a V : Mul<&'a E, Output=E>,
+    for<'a> &'a E : Div<&'a E, Output=E>,
+{
+    let ConstData {arg, arg_type, res, ..} = const_data;
+    //
+    debug_assert!( arg.len() == 1 );
+    debug_assert!( arg_type[0].is_variable() );
+    let x           = arg[0] as usize;
+    let z           = res;
+    let three_z_sq  = &V::from(3.0) * &( &var_all[z] ).square();
+    var_der[z]      = &var_der[x] / &three_z_sq;
+}
+// cbrt_reverse_der
+/// First order reverse mode for cbrt(variable);
+/// see [ForwardDer](crate::op::info::ForwardDer)
+fn cbrt_reverse_der<V, E>(
+    _dyp_all   :   &[E]        ,
+    var_all    :   &[E]        ,
+    var_der    :   &mut [E]    ,
+    const_data : ConstData<V> )
+where
+    for<'a> E     : AddAssign<&'a E> ,
+    E             : FConst ,
+    V             : From<f32>,
+    for<'a> &'a V : FUnary<Output=V>,
+    for<'a> &'a E : FUnary<Output=E>,
+    for<'a> &'a V : Mul<&'a E, Output=E>,
+    for<'a> &'a E : Div<&'a E, Output=E>,
+{
+    let ConstData {arg, arg_type, res, ..} = const_data;
+    //
+    debug_assert!( arg.len() == 1 );
+    debug_assert!( arg_type[0].is_variable() );
+    let x           = arg[0] as usize;
+    let z           = res;
+    let three_z_sq  = &V::from(3.0) * &( &var_all[z] ).square();
+    var_der[x]     += &( &var_der[z] / &three_z_sq );
+}
+// ---------------------------------------------------------------------------
+// set_op_fns
+/// Set the operator functions for all the CBRT_OP operator.
+///
+/// * op_fns_vec :
+///   The map from [op::id](crate::op::id) to operator functions.
+///   The the map results for CBRT_OP are set.
+pub fn set_op_fns<V>( op_fns_vec : &mut [OpFns<V>] ) where
+    for<'a> &'a V     : Mul<&'a AD<V>, Output = AD<V> > ,
+    for<'a> &'a V     : Mul<&'a V, Output = V> ,
+    //
+    for<'a> &'a AD<V> : Div<&'a AD<V>, Output = AD<V> > ,
+    for<'a> &'a V     : Div<&'a V, Output = V> ,
+    //
+    for<'a> V         : AddAssign<&'a V>,
+    for<'a> AD<V>     : AddAssign<&'a AD<V> >,
+    //
+    V                 : Clone + FConst + ThisThreadTape + From<f32>,
+    for<'a> &'a V     : FUnary<Output=V>,
+    //
+    // forward_taylor
+    V             : TaylorValue + PartialEq ,
+    for<'a> &'a V : TaylorRef<'a, V> ,
+{
+    op_fns_vec[CBRT_OP as usize] = OpFns{
+        name              : "cbrt",
+        forward_dyp_value : cbrt_forward_dyp::<V, V>,
+        forward_dyp_ad    : cbrt_forward_dyp::<V, AD<V> >,
+        forward_var_value : cbrt_forward_var::<V, V>,
+        forward_var_ad    : cbrt_forward_var::<V, AD<V> >,
+        forward_der_value : cbrt_forward_der::<V, V>,
+        forward_der_ad    : cbrt_forward_der::<V, AD<V> >,
+        reverse_der_value : cbrt_reverse_der::<V, V>,
+        reverse_der_ad    : cbrt_reverse_der::<V, AD<V> >,
+        forward_taylor_value : cbrt_forward_taylor::<V, V>,
+        forward_taylor_ad    : cbrt_forward_taylor::<V, AD<V> >,
+        reverse_taylor_value : cbrt_reverse_taylor::<V, V>,
+        reverse_taylor_ad    : cbrt_reverse_taylor::<V, AD<V> >,
+        rust_src          : cbrt_rust_src,
+        reverse_depend    : common::reverse_depend,
+        linearity         : Linearity::Nonlinear,
+    };
+}
@@ -0,0 +1,126 @@
+// SPDX-License-Identifier: EPL-2.0 OR GPL-2.0-or-later
+// SPDX-FileCopyrightText: Bradley M. Bell <bradbell@seanet.com>
+// SPDX-FileContributor: 2026 Bradley M. Bell
+//
+//! Evaluate the ceil operator
+//!
+//! Link to [parent module](super)
+// --------------------------------------------------------------------------
+// z   = ceil(x) ; i.e., the smallest integer greater than or equal to x
+// z_x = 0
+// --------------------------------------------------------------------------
+// use
+//
+use std::ops::{
+    Mul,
+    AddAssign,
+};
+//
+use crate::{
+    AD,
+    FConst,
+    FUnary,
+};
+//
+use crate::ad::ADType;
+use crate::op::unary::common;
+use crate::tape::sealed::ThisThreadTape;
+use crate::op::taylor::{
+    TaylorValue,
+    TaylorRef,
+};
+use crate::op::info::OpFns;
+use crate::op::info::ConstData;
+use crate::op::info::Linearity;
+use crate::op::id::CEIL_OP;
+// -------------------------------------------------------------------------
+// ceil_forward_dyp
+common::forward_dyp!(ceil);
+//
+// ceil_forward_var
+common::forward_var!(ceil);
+//
+// ceil_rust_src
+common::rust_src!(ceil);
+//
+// ceil_forward_taylor
+common::forward_taylor!(ceil, E);
+//
+// ceil_reverse_taylor
+common::reverse_taylor!(ceil, E);
+//
+// ceil_forward_der
+/// First order forward mode for ceil(variable);
+/// see [ForwardDer](crate::op::info::ForwardDer)
+fn ceil_forward_der<V, E>(
+    _dyp_all   :   &[E]        ,
+    _var_all   :   &[E]        ,
+    var_der    :   &mut [E]    ,
+    const_data : ConstData<V> )
+where
+    E             : FConst ,
+    for<'a> &'a E : FUnary<Output=E>,
+    for<'a> &'a E : Mul<&'a E, Output=E>,
+{
+    let ConstData {arg, arg_type, res, ..} = const_data;
+    //
+    debug_assert!( arg.len() == 1 );
+    debug_assert!( arg_type[0].is_variable() );
+    var_der[res] = FConst::zero();
+}
+// ceil_reverse_der
+/// First order reverse mode for ceil(variable);
+/// see [ReverseDer](crate::op::info::ReverseDer)
+fn ceil_reverse_der<V, E>(
+    _dyp_all   :   &[E]        ,
+    _var_all   :   &[E]        ,
+    _var_der   :   &mut [E]    ,
+    _const_data : ConstData<V> )
+where
+    for<'a> E     : AddAssign<&'a E> ,
+    E             : FConst ,
+    for<'a> &'a E : FUnary<Output=E>,
+    for<'a> &'a E : Mul<&'a E, Output=E>,
+{
+    // The derivative is zero so there is nothing to add to var_der[arg[0]]
+}
+// ---------------------------------------------------------------------------
+// set_op_fns
+/// Set the operator functions for all the CEIL_OP operator.
+///
+/// * op_fns_vec :
+///   The map from [op::id](crate::op::id) to operator functions.
+///   The the map results for CEIL_OP are set.
+pub fn set_op_fns<V>( op_fns_vec : &mut [OpFns<V>] ) where
+    for<'a> &'a AD<V> : Mul<&'a AD<V>, Output = AD<V> > ,
+    for<'a> &'a V     : Mul<&'a V, Output = V> ,
+    //
+    for<'a> V         : AddAssign<&'a V>,
+    for<'a> AD<V>     : AddAssign<&'a AD<V> >,
+    //
+    V                 : Clone + FConst + ThisThreadTape ,
+    for<'a> &'a V     : FUnary<Output=V>,
+    //
+    // forward_taylor
+    V             : TaylorValue + PartialEq ,
+    for<'a> &'a V : TaylorRef<'a, V> ,
+{
+    op_fns_vec[CEIL_OP as usize] = OpFns{
+        name              : "ceil",
+        forward_dyp_value : ceil_forward_dyp::<V, V>,
+        forward_dyp_ad    : ceil_forward_dyp::<V, AD<V> >,
+        forward_var_value : ceil_forward_var::<V, V>,
+        forward_var_ad    : ceil_forward_var::<V, AD<V> >,
+        forward_der_value : ceil_forward_der::<V, V>,
+        forward_der_ad    : ceil_forward_der::<V, AD<V> >,
+        reverse_der_value : ceil_reverse_der::<V, V>,
+        reverse_der_ad    : ceil_reverse_der::<V, AD<V> >,
+        forward_taylor_value : ceil_forward_taylor::<V, V>,
+        forward_taylor_ad    : ceil_forward_taylor::<V, AD<V> >,
+        reverse_taylor_value : ceil_reverse_taylor::<V, V>,
+        reverse_taylor_ad    : ceil_reverse_taylor::<V, AD<V> >,
+        rust_src          : ceil_rust_src,
+        reverse_depend    : common::reverse_depend,
+        linearity         : Linearity::Linear,
+    };
+}
@@ -17,13 +17,31 @@ pub(crate) fn is_unary_op(op_id : u8) -> bool {
     match op_id {
         // BEGIN_SORT_THIS_LINE_PLUS_1
         id::ABS_OP      => true,
+        id::ACOSH_OP    => true,
+        id::ACOS_OP     => true,
+        id::ASINH_OP    => true,
+        id::ASIN_OP     => true,
+        id::ATANH_OP    => true,
+        id::ATAN_OP     => true,
+        id::CBRT_OP     => true,
+        id::CEIL_OP     => true,
         id::COSH_OP     => true,
         id::COS_OP      => true,
+        id::ERFC_OP     => true,
+        id::ERF_OP      => true,
+        id::EXP2_OP     => true,
         id::EXP_M1_OP   => true,
         id::EXP_OP      => true,
+        id::FLOOR_OP    => true,
         id::LN_1P_OP    => true,
         id::LN_OP       => true,
+        id::LOG10_OP    => true,
+        id::LOG2_OP     => true,
         id::MINUS_OP    => true,
+        id::REDUCE_LEN_OP => true,
+        id::REDUCE_MAX_OP => true,
+        id::REDUCE_SUM_OP => true,
+        id::ROUND_OP    => true,
         id::SIGNUM_OP   => true,
         id::SINH_OP     => true,
         id::SIN_OP      => true,
@@ -31,6 +49,7 @@ pub(crate) fn is_unary_op(op_id : u8) -> bool {
         id::SQUARE_OP   => true,
         id::TANH_OP     => true,
         id::TAN_OP      => true,
+        id::TRUNC_OP    => true,
         // END_SORT_THIS_LINE_MINUS_1
         //
         _               => false,
@@ -160,6 +179,124 @@ macro_rules! rust_src { ($name:ident) => { paste::paste! {
 }}}
 pub(crate) use rust_src;
 //
+// forward_taylor
+/// Arbitrary order forward mode for unary operators.
+///
+/// * V      : see [doc_generic_v](crate::doc_generic_v)
+/// * E      : see [doc_generic_e](crate::doc_generic_e)
+/// * name   : is sin, ...
+/// * T      : type arguments for the recurrence, V, E by default;
+///   recurrences that do not use V are called with E alone.
+///
+/// This defines the following function in the the current module:
+/// ```text
+///     {name}_forward_taylor<V, E>
+/// ```
+/// The Taylor coefficient recurrence for the operator is
+/// crate::op::taylor::{name} .
+macro_rules! forward_taylor {
+    ($name:ident) => {
+        crate::op::unary::common::forward_taylor!($name, V, E);
+    };
+    ($name:ident, $($T:ident),+) => { paste::paste! {
+    //
+    #[doc = concat!(
+        " Taylor coefficients for ", stringify!( $name ), " of a variable",
+        "; see [ForwardTaylor](crate::op::info::ForwardTaylor)"
+    ) ]
+    fn [< $name _forward_taylor >] <V, E> (
+        _dyp_all    : &[E]        ,
+        var_tay     : &mut [E]    ,
+        n_tay       : usize       ,
+        const_data : ConstData<V> )
+    where
+        V             : Clone + From<f32> ,
+        E             : Clone + From<V> + FConst ,
+        for<'a> E     : std::ops::AddAssign<&'a E> ,
+        for<'a> E     : std::ops::SubAssign<&'a E> ,
+        for<'a> &'a E : std::ops::Add<&'a E, Output=E> ,
+        for<'a> &'a E : std::ops::Sub<&'a E, Output=E> ,
+        for<'a> &'a E : std::ops::Mul<&'a E, Output=E> ,
+        for<'a> &'a E : std::ops::Div<&'a E, Output=E> ,
+        for<'a> &'a E : FUnary<Output=E>,
+    {
+        let ConstData {arg, arg_type, res, ..} = const_data;
+        //
+        // index
+        let index = arg[0] as usize;
+        debug_assert!( index < res );
+        //
+        debug_assert!( arg.len() == 1);
+        debug_assert!( arg_type[0].is_variable() );
+        let (before, z) = crate::op::taylor::split_var_tay(var_tay, n_tay, res);
+        let x           = &before[index * n_tay .. (index + 1) * n_tay];
+        crate::op::taylor::$name::<$($T),+>(x, z);
+    }
+}};
+}
+pub(crate) use forward_taylor;
+//
+// reverse_taylor
+/// Arbitrary order reverse mode for unary operators.
+///
+/// * V      : see [doc_generic_v](crate::doc_generic_v)
+/// * E      : see [doc_generic_e](crate::doc_generic_e)
+/// * name   : is sin, ...
+/// * T      : type arguments for the recurrence, V, E by default;
+///   recurrences that do not use V are called with E alone.
+///
+/// This defines the following function in the the current module:
+/// ```text
+///     {name}_reverse_taylor<V, E>
+/// ```
+/// The partial of the operator is computed by
+/// crate::op::taylor::{name}_partial .
+macro_rules! reverse_taylor {
+    ($name:ident) => {
+        crate::op::unary::common::reverse_taylor!($name, V, E);
+    };
+    ($name:ident, $($T:ident),+) => { paste::paste! {
+    //
+    #[doc = concat!(
+        " Taylor coefficient partials for ", stringify!( $name ),
+        " of a variable; see [ReverseTaylor](crate::op::info::ReverseTaylor)"
+    ) ]
+    fn [< $name _reverse_taylor >] <V, E> (
+        _dyp_all    : &[E]        ,
+        var_tay     : &[E]        ,
+        var_par     : &mut [E]    ,
+        n_tay       : usize       ,
+        const_data : ConstData<V> )
+    where
+        V             : Clone + From<f32> ,
+        E             : Clone + From<V> + FConst ,
+        for<'a> E     : std::ops::AddAssign<&'a E> ,
+        for<'a> E     : std::ops::SubAssign<&'a E> ,
+        for<'a> &'a E : std::ops::Add<&'a E, Output=E> ,
+        for<'a> &'a E : std::ops::Sub<&'a E, Output=E> ,
+        for<'a> &'a E : std::ops::Mul<&'a E, Output=E> ,
+        for<'a> &'a E : std::ops::Div<&'a E, Output=E> ,
+        for<'a> &'a E : FUnary<Output=E>,
+    {
+        let ConstData {arg, arg_type, res, ..} = const_data;
+        //
+        // index
+        let index = arg[0] as usize;
+        debug_assert!( index < res );
+        //
+        debug_assert!( arg.len() == 1);
+        debug_assert!( arg_type[0].is_variable() );
+        let x  = &var_tay[index * n_tay .. (index + 1) * n_tay];
+        let z  = &var_tay[res * n_tay .. (res + 1) * n_tay];
+        let d  = crate::op::taylor::[< $name _partial >]::<$($T),+>(x, z);
+        let (before, pz) = crate::op::taylor::split_var_par(var_par, n_tay, res);
+        let px = &mut before[index * n_tay .. (index + 1) * n_tay];
+        crate::op::taylor::reverse_partial::<E>(pz, &d, px);
+    }
+}};
+}
+pub(crate) use reverse_taylor;
+//
 /// Reverse dependency analysis for a unary operators;
 /// see [ReverseDepend](crate::op::info::ReverseDepend)
 pub(crate) fn reverse_depend(
@@ -0,0 +1,163 @@
+// SPDX-License-Identifier: EPL-2.0 OR GPL-2.0-or-later
+// SPDX-FileCopyrightText: Bradley M. Bell <bradbell@seanet.com>
+// SPDX-FileContributor: 2026 Bradley M. Bell
+//
+//! Evaluate the erf operator
+//!
+//! Link to [parent module](super)
+// --------------------------------------------------------------------------
+// z   = erf(x) = 2 / sqrt(pi) * integral_0^x exp( - t^2 ) dt
+// z_x = 2 / sqrt(pi) * exp( - x^2 )
+// --------------------------------------------------------------------------
+// use
+//
+use std::ops::{
+    Mul,
+    Div,
+    Add,
+    AddAssign,
+};
+//
+use crate::{
+    AD,
+    FConst,
+    FUnary,
+};
+//
+use crate::ad::ADType;
+use crate::op::unary::common;
+use crate::tape::sealed::ThisThreadTape;
+use crate::op::taylor::{
+    TaylorValue,
+    TaylorRef,
+};
+use crate::op::info::OpFns;
+use crate::op::info::ConstData;
+use crate::op::info::Linearity;
+use crate::op::id::ERF_OP;
+// -------------------------------------------------------------------------
+// erf_forward_dyp
+common::forward_dyp!(erf);
+//
+// erf_forward_var
+common::forward_var!(erf);
+//
+// erf_rust_src
+common::rust_src!(erf);
+//
+// erf_forward_taylor
+common::forward_taylor!(erf);
+//
+// erf_reverse_taylor
+common::reverse_taylor!(erf);
+//
+// erf_derivative
+/// Derivative of erf at x; i.e., 2 / sqrt(pi) * exp( - x^2 ) .
+pub(crate) fn erf_derivative<E>(x : &E) -> E
+where
+    E             : FConst ,
+    for<'a> &'a E : FUnary<Output=E>,
+    for<'a> &'a E : Mul<&'a E, Output=E>,
+    for<'a> &'a E : Div<&'a E, Output=E>,
+    for<'a> &'a E : Add<&'a E, Output=E>,
+{   let one      = E::one();
+    let two      = &one + &one;
+    let minus_sq = ( &( x * x ) ).minus();
+    &( &two / &( &E::pi() ).sqrt() ) * &( &minus_sq ).exp()
+}
+//
+// erf_forward_der
+/// First order forward mode for erf(variable);
+/// see [ForwardDer](crate::op::info::ForwardDer)
+fn erf_forward_der<V, E>(
+    _dyp_all   :   &[E]        ,
+    var_all    :   &[E]        ,
+    var_der    :   &mut [E]    ,
+    const_data : ConstData<V> )
+where
+    E             : FConst ,
+    for<'a> &'a E : FUnary<Output=E>,
+    for<'a> &'a E : Mul<&'a E, Output=E>,
+    for<'a> &'a E : Div<&'a E, Output=E>,
+    for<'a> &'a E : Add<&'a E, Output=E>,
+{
+    let ConstData {arg, arg_type, res, ..} = const_data;
+    //
+    debug_assert!( arg.len() == 1 );
+    debug_assert!( arg_type[0].is_variable() );
+    let x        = arg[0] as usize;
+    let z        = res;
+    let z_x      = erf_derivative::<E>( &var_all[x] );
+    var_der[z]   = &z_x * &var_der[x];
+}
+// erf_reverse_der
+/// First order reverse mode for erf(variable);
+/// see [ForwardDer](crate::op::info::ForwardDer)
+fn erf_reverse_der<V, E>(
+    _dyp_all   :   &[E]        ,
+    var_all    :   &[E]        ,
+    var_der    :   &mut [E]    ,
+    const_data : ConstData<V> )
+where
+    for<'a> E     : AddAssign<&'a E> ,
+    E             : FConst ,
+    for<'a> &'a E : FUnary<Output=E>,
+    for<'a> &'a E : Mul<&'a E, Output=E>,
+    for<'a> &'a E : Div<&'a E, Output=E>,
+    for<'a> &'a E : Add<&'a E, Output=E>,
+{
+    let ConstData {arg, arg_type, res, ..} = const_data;
+    //
+    debug_assert!( arg.len() == 1 );
+    debug_assert!( arg_type[0].is_variable() );
+    let x           = arg[0] as usize;
+    let z           = res;
+    let z_x         = erf_derivative::<E>( &var_all[x] );
+    var_der[x]     += &( &z_x * &var_der[z] );
+}
+// ---------------------------------------------------------------------------
+// set_op_fns
+/// Set the operator functions for all the ERF_OP operator.
+///
+/// * op_fns_vec :
+///   The map from [op::id](crate::op::id) to operator functions.
+///   The the map results for ERF_OP are set.
+pub fn set_op_fns<V>( op_fns_vec : &mut [OpFns<V>] ) where
+    for<'a> &'a AD<V> : Mul<&'a AD<V>, Output = AD<V> > ,
+    for<'a> &'a V     : Mul<&'a V, Output = V> ,
+    //
+    for<'a> &'a AD<V> : Div<&'a AD<V>, Output = AD<V> > ,
+    for<'a> &'a V     : Div<&'a V, Output = V> ,
+    //
+    for<'a> &'a AD<V> : Add<&'a AD<V>, Output = AD<V> > ,
+    for<'a> &'a V     : Add<&'a V, Output = V> ,
+    //
+    for<'a> V         : AddAssign<&'a V>,
+    for<'a> AD<V>     : AddAssign<&'a AD<V> >,
+    //
+    V                 : Clone + FConst + ThisThreadTape ,
+    for<'a> &'a V     : FUnary<Output=V>,
+    //
+    // forward_taylor
+    V             : TaylorValue + PartialEq ,
+    for<'a> &'a V : TaylorRef<'a, V> ,
+{
+    op_fns_vec[ERF_OP as usize] = OpFns{
+        name              : "erf",
+        forward_dyp_value : erf_forward_dyp::<V, V>,
+        forward_dyp_ad    : erf_forward_dyp::<V, AD<V> >,
+        forward_var_value : erf_forward_var::<V, V>,
+        forward_var_ad    : erf_forward_var::<V, AD<V> >,
+        forward_der_value : erf_forward_der::<V, V>,
+        forward_der_ad    : erf_forward_der::<V, AD<V> >,
+        reverse_der_value : erf_reverse_der::<V, V>,
+        reverse_der_ad    : erf_reverse_der::<V, AD<V> >,
+        forward_taylor_value : erf_forward_taylor::<V, V>,
+        forward_taylor_ad    : erf_forward_taylor::<V, AD<V> >,
+        reverse_taylor_value : erf_reverse_taylor::<V, V>,
+        reverse_taylor_ad    : erf_reverse_taylor::<V, AD<V> >,
+        rust_src          : erf_rust_src,
+        reverse_depend    : common::reverse_depend,
+        linearity         : Linearity::Nonlinear,
+    };
+}
@@ -22,8 +22,13 @@ use crate::{
 use crate::ad::ADType;
 use crate::op::unary::common;
 use crate::tape::sealed::ThisThreadTape;
+use crate::op::taylor::{
+    TaylorValue,
+    TaylorRef,
+};
 use crate::op::info::OpFns;
 use crate::op::info::ConstData;
+use crate::op::info::Linearity;
 use crate::op::id::EXP_OP;
 // -------------------------------------------------------------------------
 // z   = exp(x)
@@ -38,6 +43,12 @@ common::forward_var!(exp);
 // exp_rust_src
 common::rust_src!(exp);
 //
+// exp_forward_taylor
+common::forward_taylor!(exp);
+//
+// exp_reverse_taylor
+common::reverse_taylor!(exp, E);
+//
 // exp_forward_der
 /// First order forward mode for exp(variable);
 /// see [ForwardDer](crate::op::info::ForwardDer)
@@ -99,6 +110,10 @@ pub fn set_op_fns<V>( op_fns_vec : &mut [OpFns<V>] ) where
     //
     V                 : Clone + FConst + ThisThreadTape ,
     for<'a> &'a V     : FUnary<Output=V>,
+    //
+    // forward_taylor
+    V             : TaylorValue + PartialEq ,
+    for<'a> &'a V : TaylorRef<'a, V> ,
 {
     op_fns_vec[EXP_OP as usize] = OpFns{
         name              : "exp",
@@ -110,7 +125,12 @@ pub fn set_op_fns<V>( op_fns_vec : &mut [OpFns<V>] ) where
         forward_der_ad    : exp_forward_der::<V, AD<V> >,
         reverse_der_value : exp_reverse_der::<V, V>,
         reverse_der_ad    : exp_reverse_der::<V, AD<V> >,
+        forward_taylor_value : exp_forward_taylor::<V, V>,
+        forward_taylor_ad    : exp_forward_taylor::<V, AD<V> >,
+        reverse_taylor_value : exp_reverse_taylor::<V, V>,
+        reverse_taylor_ad    : exp_reverse_taylor::<V, AD<V> >,
         rust_src          : exp_rust_src,
         reverse_depend    : common::reverse_depend,
+        linearity         : Linearity::Nonlinear,
     };
 }
@@ -0,0 +1,145 @@
+// SPDX-License-Identifier: EPL-2.0 OR GPL-2.0-or-later
+// SPDX-FileCopyrightText: Bradley M. Bell <bradbell@seanet.com>
+// SPDX-FileContributor: 2026 Bradley M. Bell
+//
+//! Evaluate the exp2 operator
+//!
+//! Link to [parent module](super)
+// --------------------------------------------------------------------------
+// z   = exp2(x)
+// z_x = ln(2) * z
+// --------------------------------------------------------------------------
+// use
+//
+use std::ops::{
+    Mul,
+    AddAssign,
+};
+//
+use crate::{
+    AD,
+    FConst,
+    FUnary,
+};
+//
+use crate::ad::ADType;
+use crate::op::unary::common;
+use crate::tape::sealed::ThisThreadTape;
+use crate::op::taylor::{
+    TaylorValue,
+    TaylorRef,
+};
+use crate::op::info::OpFns;
+use crate::op::info::ConstData;
+use crate::op::info::Linearity;
+use crate::op::id::EXP2_OP;
+// -------------------------------------------------------------------------
+// exp2_forward_dyp
+common::forward_dyp!(exp2);
+//
+// exp2_forward_var
+common::forward_var!(exp2);
+//
+// exp2_rust_src
+common::rust_src!(exp2);
+//
+// exp2_forward_taylor
+common::forward_taylor!(exp2);
+//
+// exp2_reverse_taylor
+common::reverse_taylor!(exp2);
+//
+// exp2_forward_der
+/// First order forward mode for exp2(variable);
+/// see [ForwardDer](crate::op::info::ForwardDer)
+fn exp2_forward_der<V, E>(
+    _dyp_all   :   &[E]        ,
+    var_all    :   &[E]        ,
+    var_der    :   &mut [E]    ,
+    const_data : ConstData<V> )
+where
+    E             : FConst ,
+    V             : From<f32>,
+    for<'a> &'a V : FUnary<Output=V>,
+    for<'a> &'a E : FUnary<Output=E>,
+    for<'a> &'a V : Mul<&'a E, Output=E>,
+    for<'a> &'a E : Mul<&'a E, Output=E>,
+{
+    let ConstData {arg, arg_type, res, ..} = const_data;
+    //
+    debug_assert!( arg.len() == 1 );
+    debug_assert!( arg_type[0].is_variable() );
+    let x           = arg[0] as usize;
+    let z           = res;
+    let ln_two      = ( &V::from(2.0) ).ln();
+    let z_x         = &ln_two * &var_all[z];
+    var_der[z]      = &z_x * &var_der[x];
+}
+// exp2_reverse_der
+/// First order reverse mode for exp2(variable);
+/// see [ForwardDer](crate::op::info::ForwardDer)
+fn exp2_reverse_der<V, E>(
+    _dyp_all   :   &[E]        ,
+    var_all    :   &[E]        ,
+    var_der    :   &mut [E]    ,
+    const_data : ConstData<V> )
+where
+    for<'a> E     : AddAssign<&'a E> ,
+    E             : FConst ,
+    V             : From<f32>,
+    for<'a> &'a V : FUnary<Output=V>,
+    for<'a> &'a E : FUnary<Output=E>,
+    for<'a> &'a V : Mul<&'a E, Output=E>,
+    for<'a> &'a E : Mul<&'a E, Output=E>,
+{
+    let ConstData {arg, arg_type, res, ..} = const_data;
+    //
+    debug_assert!( arg.len() == 1 );
+    debug_assert!( arg_type[0].is_variable() );
+    let x           = arg[0] as usize;
+    let z           = res;
+    let ln_two      = ( &V::from(2.0) ).ln();
+    let z_x         = &ln_two * &var_all[z];
+    var_der[x]     += &( &z_x * &var_der[z] );
+}
+// ---------------------------------------------------------------------------
+// set_op_fns
+/// Set the operator functions for all the EXP2_OP operator.
+///
+/// * op_fns_vec :
+///   The map from [op::id](crate::op::id) to operator functions.
+///   The the map results for EXP2_OP are set.
+pub fn set_op_fns<V>( op_fns_vec : &mut [OpFns<V>] ) where
+    for<'a> &'a V     : Mul<&'a AD<V>, Output = AD<V> > ,
+    for<'a> &'a AD<V> : Mul<&'a AD<V>, Output = AD<V> > ,
+    for<'a> &'a V     : Mul<&'a V, Output = V> ,
+    //
+    for<'a> V         : AddAssign<&'a V>,
+    for<'a> AD<V>     : AddAssign<&'a AD<V> >,
+    //
+    V                 : Clone + FConst + ThisThreadTape + From<f32>,
+    for<'a> &'a V     : FUnary<Output=V>,
+    //
+    // forward_taylor
+    V             : TaylorValue + PartialEq ,
+    for<'a> &'a V : TaylorRef<'a, V> ,
+{
+    op_fns_vec[EXP2_OP as usize] = OpFns{
+        name              : "exp2",
+        forward_dyp_value : exp2_forward_dyp::<V, V>,
+        forward_dyp_ad    : exp2_forward_dyp::<V, AD<V> >,
+        forward_var_value : exp2_forward_var::<V, V>,
+        forward_var_ad    : exp2_forward_var::<V, AD<V> >,
+        forward_der_value : exp2_forward_der::<V, V>,
+        forward_der_ad    : exp2_forward_der::<V, AD<V> >,
+        reverse_der_value : exp2_reverse_der::<V, V>,
+        reverse_der_ad    : exp2_reverse_der::<V, AD<V> >,
+        forward_taylor_value : exp2_forward_taylor::<V, V>,
+        forward_taylor_ad    : exp2_forward_taylor::<V, AD<V> >,
+        reverse_taylor_value : exp2_reverse_taylor::<V, V>,
+        reverse_taylor_ad    : exp2_reverse_taylor::<V, AD<V> >,
+        rust_src          : exp2_rust_src,
+        reverse_depend    : common::reverse_depend,
+        linearity         : Linearity::Nonlinear,
+    };
+}
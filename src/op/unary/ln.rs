@@ -25,8 +25,13 @@ use crate::{
 use crate::ad::ADType;
 use crate::op::unary::common;
 use crate::tape::sealed::ThisThreadTape;
+use crate::op::taylor::{
+    TaylorValue,
+    TaylorRef,
+};
 use crate::op::info::OpFns;
 use crate::op::info::ConstData;
+use crate::op::info::Linearity;
 use crate::op::id::LN_OP;
 // -------------------------------------------------------------------------
 // ln_forward_dyp
@@ -38,6 +43,12 @@ common::forward_var!(ln);
 // ln_rust_src
 common::rust_src!(ln);
 //
+// ln_forward_taylor
+common::forward_taylor!(ln);
+//
+// ln_reverse_taylor
+common::reverse_taylor!(ln, E);
+//
 // ln_forward_der
 /// First order forward mode for ln(variable);
 /// see [ForwardDer](crate::op::info::ForwardDer)
@@ -99,6 +110,10 @@ pub fn set_op_fns<V>( op_fns_vec : &mut [OpFns<V>] ) where
     //
     V                 : Clone + FConst + ThisThreadTape ,
     for<'a> &'a V     : FUnary<Output=V>,
+    //
+    // forward_taylor
+    V             : TaylorValue + PartialEq ,
+    for<'a> &'a V : TaylorRef<'a, V> ,
 {
     op_fns_vec[LN_OP as usize] = OpFns{
         name              : "ln",
@@ -110,7 +125,12 @@ pub fn set_op_fns<V>( op_fns_vec : &mut [OpFns<V>] ) where
         forward_der_ad    : ln_forward_der::<V, AD<V> >,
         reverse_der_value : ln_reverse_der::<V, V>,
         reverse_der_ad    : ln_reverse_der::<V, AD<V> >,
+        forward_taylor_value : ln_forward_taylor::<V, V>,
+        forward_taylor_ad    : ln_forward_taylor::<V, AD<V> >,
+        reverse_taylor_value : ln_reverse_taylor::<V, V>,
+        reverse_taylor_ad    : ln_reverse_taylor::<V, AD<V> >,
         rust_src          : ln_rust_src,
         reverse_depend    : common::reverse_depend,
+        linearity         : Linearity::Nonlinear,
     };
 }
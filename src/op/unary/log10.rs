@@ -0,0 +1,148 @@
+// SPDX-License-Identifier: EPL-2.0 OR GPL-2.0-or-later
+// SPDX-FileCopyrightText: Bradley M. Bell <bradbell@seanet.com>
+// SPDX-FileContributor: 2026 Bradley M. Bell
+//
+//! Evaluate the log10 operator
+//!
+//! Link to [parent module](super)
+// --------------------------------------------------------------------------
+// z   = log10(x)
+// z_x = 1 / ( ln(10) * x )
+// --------------------------------------------------------------------------
+// use
+//
+use std::ops::{
+    Mul,
+    Div,
+    AddAssign,
+};
+//
+use crate::{
+    AD,
+    FConst,
+    FUnary,
+};
+//
+use crate::ad::ADType;
+use crate::op::unary::common;
+use crate::tape::sealed::ThisThreadTape;
+use crate::op::taylor::{
+    TaylorValue,
+    TaylorRef,
+};
+use crate::op::info::OpFns;
+use crate::op::info::ConstData;
+use crate::op::info::Linearity;
+use crate::op::id::LOG10_OP;
+// -------------------------------------------------------------------------
+// log10_forward_dyp
+common::forward_dyp!(log10);
+//
+// log10_forward_var
+common::forward_var!(log10);
+//
+// log10_rust_src
+common::rust_src!(log10);
+//
+// log10_forward_taylor
+common::forward_taylor!(log10);
+//
+// log10_reverse_taylor
+common::reverse_taylor!(log10);
+//
+// log10_forward_der
+/// First order forward mode for log10(variable);
+/// see [ForwardDer](crate::op::info::ForwardDer)
+fn log10_forward_der<V, E>(
+    _dyp_all   :   &[E]        ,
+    var_all    :   &[E]        ,
+    var_der    :   &mut [E]    ,
+    const_data : ConstData<V> )
+where
+    E             : FConst ,
+    V             : From<f32>,
+    for<'a> &'a V : FUnary<Output=V>,
+    for<'a> &'a E : FUnary<Output=E>,
+    for<'a> &'a V : Mul<&'a E, Output=E>,
+    for<'a> &'a E : Div<&'a E, Output=E>,
+{
+    let ConstData {arg, arg_type, res, ..} = const_data;
+    //
+    debug_assert!( arg.len() == 1 );
+    debug_assert!( arg_type[0].is_variable() );
+    let x           = arg[0] as usize;
+    let z           = res;
+    let ln_base     = ( &V::from(10.0) ).ln();
+    let den         = &ln_base * &var_all[x];
+    var_der[z]      = &var_der[x] / &den;
+}
+// log10_reverse_der
+/// First order reverse mode for log10(variable);
+/// see [ForwardDer](crate::op::info::ForwardDer)
+fn log10_reverse_der<V, E>(
+    _dyp_all   :   &[E]        ,
+    var_all    :   &[E]        ,
+    var_der    :   &mut [E]    ,
+    const_data : ConstData<V> )
+where
+    for<'a> E     : AddAssign<&'a E> ,
+    E             : FConst ,
+    V             : From<f32>,
+    for<'a> &'a V : FUnary<Output=V>,
+    for<'a> &'a E : FUnary<Output=E>,
+    for<'a> &'a V : Mul<&'a E, Output=E>,
+    for<'a> &'a E : Div<&'a E, Output=E>,
+{
+    let ConstData {arg, arg_type, res, ..} = const_data;
+    //
+    debug_assert!( arg.len() == 1 );
+    debug_assert!( arg_type[0].is_variable() );
+    let x           = arg[0] as usize;
+    let z           = res;
+    let ln_base     = ( &V::from(10.0) ).ln();
+    let den         = &ln_base * &var_all[x];
+    var_der[x]     += &( &var_der[z] / &den );
+}
+// ---------------------------------------------------------------------------
+// set_op_fns
+/// Set the operator functions for all the LOG10_OP operator.
+///
+/// * op_fns_vec :
+///   The map from [op::id](crate::op::id) to operator functions.
+///   The the map results for LOG10_OP are set.
+pub fn set_op_fns<V>( op_fns_vec : &mut [OpFns<V>] ) where
+    for<'a> &'a V     : Mul<&'a AD<V>, Output = AD<V> > ,
+    for<'a> &'a V     : Mul<&'a V, Output = V> ,
+    //
+    for<'a> &'a AD<V> : Div<&'a AD<V>, Output = AD<V> > ,
+    for<'a> &'a V     : Div<&'a V, Output = V> ,
+    //
+    for<'a> V         : AddAssign<&'a V>,
+    for<'a> AD<V>     : AddAssign<&'a AD<V> >,
+    //
+    V                 : Clone + FConst + ThisThreadTape + From<f32>,
+    for<'a> &'a V     : FUnary<Output=V>,
+    //
+    // forward_taylor
+    V             : TaylorValue + PartialEq ,
+    for<'a> &'a V : TaylorRef<'a, V> ,
+{
+    op_fns_vec[LOG10_OP as usize] = OpFns{
+        name              : "log10",
+        forward_dyp_value : log10_forward_dyp::<V, V>,
+        forward_dyp_ad    : log10_forward_dyp::<V, AD<V> >,
+        forward_var_value : log10_forward_var::<V, V>,
+        forward_var_ad    : log10_forward_var::<V, AD<V> >,
+        forward_der_value : log10_forward_der::<V, V>,
+        forward_der_ad    : log10_forward_der::<V, AD<V> >,
+        reverse_der_value : log10_reverse_der::<V, V>,
+        reverse_der_ad    : log10_reverse_der::<V, AD<V> >,
+        forward_taylor_value : log10_forward_taylor::<V, V>,
+        forward_taylor_ad    : log10_forward_taylor::<V, AD<V> >,
+        reverse_taylor_value : log10_reverse_taylor::<V, V>,
+        reverse_taylor_ad    : log10_reverse_taylor::<V, AD<V> >,
+        rust_src          : log10_rust_src,
+        reverse_depend    : common::reverse_depend,
+        linearity         : Linearity::Nonlinear,
+    };
+}
@@ -9,16 +9,32 @@
 //! # Operators :
 //! [comment]: <> (BEGIN_SORT_THIS_LINE_PLUS_1)
 //! ABS_OP,
+//! ACOSH_OP,
+//! ACOS_OP,
+//! ASINH_OP,
+//! ASIN_OP,
+//! ATANH_OP,
+//! ATAN_OP,
+//! CBRT_OP,
+//! CEIL_OP,
 //! COSH_OP,
 //! COS_OP,
+//! ERFC_OP,
+//! ERF_OP,
+//! EXP2_OP,
 //! EXP_OP,
+//! FLOOR_OP,
+//! LOG10_OP,
+//! LOG2_OP,
 //! MINUS_OP,
+//! ROUND_OP,
 //! SIGNUM_OP,
 //! SINH_OP,
 //! SIN_OP,
 //! SQRT_OP,
 //! TANH_OP,
 //! TAN_OP,
+//! TRUNC_OP,
 //! [comment]: <> (END_SORT_THIS_LINE_MINUS_1)
 //!
 //! # Operator Arguments
@@ -29,14 +45,29 @@
 // sub-modules
 // BEGIN_SORT_THIS_LINE_PLUS_1
 pub mod abs;
+pub mod acos;
+pub mod acosh;
+pub mod asin;
+pub mod asinh;
+pub mod atan;
+pub mod atanh;
+pub mod cbrt;
+pub mod ceil;
 pub mod common;
 pub mod cos;
 pub mod cosh;
+pub mod erf;
+pub mod erfc;
 pub mod exp;
+pub mod exp2;
 pub mod exp_m1;
+pub mod floor;
 pub mod ln;
 pub mod ln_1p;
+pub mod log10;
+pub mod log2;
 pub mod minus;
+pub mod round;
 pub mod signum;
 pub mod sin;
 pub mod sinh;
@@ -44,4 +75,5 @@ pub mod sqrt;
 pub mod square;
 pub mod tan;
 pub mod tanh;
+pub mod trunc;
 // END_SORT_THIS_LINE_MINUS_1
@@ -26,8 +26,13 @@ use crate::{
 use crate::ad::ADType;
 use crate::op::unary::common;
 use crate::tape::sealed::ThisThreadTape;
+use crate::op::taylor::{
+    TaylorValue,
+    TaylorRef,
+};
 use crate::op::info::OpFns;
 use crate::op::info::ConstData;
+use crate::op::info::Linearity;
 use crate::op::id::SQUARE_OP;
 // -------------------------------------------------------------------------
 // square_forward_dyp
@@ -39,6 +44,12 @@ common::forward_var!(square);
 // square_rust_src
 common::rust_src!(square);
 //
+// square_forward_taylor
+common::forward_taylor!(square, E);
+//
+// square_reverse_taylor
+common::reverse_taylor!(square);
+//
 // square_forward_der
 /// First order forward mode for square(variable);
 /// see [ForwardDer](crate::op::info::ForwardDer)
@@ -101,6 +112,10 @@ pub fn set_op_fns<V>( op_fns_vec : &mut [OpFns<V>] ) where
     //
     V                 : Clone + FConst + ThisThreadTape ,
     for<'a> &'a V     : FUnary<Output=V>,
+    //
+    // forward_taylor
+    V             : TaylorValue + PartialEq ,
+    for<'a> &'a V : TaylorRef<'a, V> ,
 {
     op_fns_vec[SQUARE_OP as usize] = OpFns{
         name              : "square",
@@ -112,7 +127,12 @@ pub fn set_op_fns<V>( op_fns_vec : &mut [OpFns<V>] ) where
         forward_der_ad    : square_forward_der::<V, AD<V> >,
         reverse_der_value : square_reverse_der::<V, V>,
         reverse_der_ad    : square_reverse_der::<V, AD<V> >,
+        forward_taylor_value : square_forward_taylor::<V, V>,
+        forward_taylor_ad    : square_forward_taylor::<V, AD<V> >,
+        reverse_taylor_value : square_reverse_taylor::<V, V>,
+        reverse_taylor_ad    : square_reverse_taylor::<V, AD<V> >,
         rust_src          : square_rust_src,
         reverse_depend    : common::reverse_depend,
+        linearity         : Linearity::Nonlinear,
     };
 }
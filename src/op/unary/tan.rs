@@ -26,8 +26,13 @@ use crate::{
 use crate::ad::ADType;
 use crate::op::unary::common;
 use crate::tape::sealed::ThisThreadTape;
+use crate::op::taylor::{
+    TaylorValue,
+    TaylorRef,
+};
 use crate::op::info::OpFns;
 use crate::op::info::ConstData;
+use crate::op::info::Linearity;
 use crate::op::id::TAN_OP;
 // -------------------------------------------------------------------------
 // tan_forward_dyp
@@ -39,6 +44,12 @@ common::forward_var!(tan);
 // tan_rust_src
 common::rust_src!(tan);
 //
+// tan_forward_taylor
+common::forward_taylor!(tan);
+//
+// tan_reverse_taylor
+common::reverse_taylor!(tan, E);
+//
 // tan_forward_der
 /// First order forward mode for tan(variable);
 /// see [ForwardDer](crate::op::info::ForwardDer)
@@ -107,6 +118,10 @@ pub fn set_op_fns<V>( op_fns_vec : &mut [OpFns<V>] ) where
     //
     V                 : Clone + FConst + ThisThreadTape ,
     for<'a> &'a V     : FUnary<Output=V>,
+    //
+    // forward_taylor
+    V             : TaylorValue + PartialEq ,
+    for<'a> &'a V : TaylorRef<'a, V> ,
 {
     op_fns_vec[TAN_OP as usize] = OpFns{
         name              : "tan",
@@ -118,7 +133,12 @@ pub fn set_op_fns<V>( op_fns_vec : &mut [OpFns<V>] ) where
         forward_der_ad    : tan_forward_der::<V, AD<V> >,
         reverse_der_value : tan_reverse_der::<V, V>,
         reverse_der_ad    : tan_reverse_der::<V, AD<V> >,
+        forward_taylor_value : tan_forward_taylor::<V, V>,
+        forward_taylor_ad    : tan_forward_taylor::<V, AD<V> >,
+        reverse_taylor_value : tan_reverse_taylor::<V, V>,
+        reverse_taylor_ad    : tan_reverse_taylor::<V, AD<V> >,
         rust_src          : tan_rust_src,
         reverse_depend    : common::reverse_depend,
+        linearity         : Linearity::Nonlinear,
     };
 }
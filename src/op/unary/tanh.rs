@@ -26,8 +26,13 @@ use crate::{
 use crate::ad::ADType;
 use crate::op::unary::common;
 use crate::tape::sealed::ThisThreadTape;
+use crate::op::taylor::{
+    TaylorValue,
+    TaylorRef,
+};
 use crate::op::info::OpFns;
 use crate::op::info::ConstData;
+use crate::op::info::Linearity;
 use crate::op::id::TANH_OP;
 // -------------------------------------------------------------------------
 // tanh_forward_dyp
@@ -39,6 +44,12 @@ common::forward_var!(tanh);
 // tanh_rust_src
 common::rust_src!(tanh);
 //
+// tanh_forward_taylor
+common::forward_taylor!(tanh);
+//
+// tanh_reverse_taylor
+common::reverse_taylor!(tanh, E);
+//
 // tanh_forward_der
 /// First order forward mode for tanh(variable);
 /// see [ForwardDer](crate::op::info::ForwardDer)
@@ -107,6 +118,10 @@ pub fn set_op_fns<V>( op_fns_vec : &mut [OpFns<V>] ) where
     //
     V                 : Clone + FConst + ThisThreadTape ,
     for<'a> &'a V     : FUnary<Output=V>,
+    //
+    // forward_taylor
+    V             : TaylorValue + PartialEq ,
+    for<'a> &'a V : TaylorRef<'a, V> ,
 {
     op_fns_vec[TANH_OP as usize] = OpFns{
         name              : "tanh",
@@ -118,7 +133,12 @@ pub fn set_op_fns<V>( op_fns_vec : &mut [OpFns<V>] ) where
         forward_der_ad    : tanh_forward_der::<V, AD<V> >,
         reverse_der_value : tanh_reverse_der::<V, V>,
         reverse_der_ad    : tanh_reverse_der::<V, AD<V> >,
+        forward_taylor_value : tanh_forward_taylor::<V, V>,
+        forward_taylor_ad    : tanh_forward_taylor::<V, AD<V> >,
+        reverse_taylor_value : tanh_reverse_taylor::<V, V>,
+        reverse_taylor_ad    : tanh_reverse_taylor::<V, AD<V> >,
         rust_src          : tanh_rust_src,
         reverse_depend    : common::reverse_depend,
+        linearity         : Linearity::Nonlinear,
     };
 }
@@ -0,0 +1,661 @@
+// SPDX-License-Identifier: EPL-2.0 OR GPL-2.0-or-later
+// SPDX-FileCopyrightText: Bradley M. Bell <bradbell@seanet.com>
+// SPDX-FileContributor: 2026 Bradley M. Bell
+// --------------------------------------------------------------------------
+//! This module defines the load and store operators for
+//! [VecAD](crate::VecAD) vectors.
+//!
+//! Link to [parent module](super)
+//!
+//! * Operators : LOAD_OP, STORE_OP
+//!
+//! * Load Value :
+//!   If c_j is the result of the comparison index == j (zero or one),
+//!   the result is the sum with respect to j of c_j * e_j
+//!   plus (1 - sum_j c_j) * nan .
+//!   Hence the result is e_j when index equals j and nan when index is not
+//!   one of 0, ..., n-1 . This acts element wise for numeric vectors and
+//!   a nan (or infinity) in an element that is not selected
+//!   does not affect the result.
+//!   If V does not have an absolute zero; e.g., f32 or f64,
+//!   the element is chosen using [FValue::select](crate::FValue)
+//!   instead of multiplying by c_j ;
+//!   see [ABSOLUTE_ZERO](crate::FConst::ABSOLUTE_ZERO) .
+//!
+//! * Store Value :
+//!   If c is the result of the comparison index == j (zero or one),
+//!   the result is c * value + (1 - c) * old ; i.e., the new value
+//!   for element j of the vector.
+//!   As for a load, value or old is chosen using
+//!   [FValue::select](crate::FValue) when V does not have an absolute zero.
+//!
+//! * Derivatives :
+//!   The index does not contribute to the derivative of the result;
+//!   i.e., it is treated as piecewise constant.
+//!
+//! # LOAD_OP Arguments
+//! | Index  | Meaning |
+//! | ------ | ------- |
+//! | 0      | Variable, dynamic, or constant index for index                |
+//! | 1 + j  | Variable, dynamic, or constant index for element e_j          |
+//!
+//! # STORE_OP Arguments
+//! | Index | Meaning |
+//! | ----- | ------- |
+//! | 0     | Variable, dynamic, or constant index for index                 |
+//! | 1     | Constant index for the element number j                        |
+//! | 2     | Variable, dynamic, or constant index for value                 |
+//! | 3     | Variable, dynamic, or constant index for old                   |
+// --------------------------------------------------------------------------
+// use
+use std::ops::{
+    Add,
+    Sub,
+    Mul,
+    Div,
+    AddAssign,
+};
+use crate::{
+    AD,
+    FConst,
+    FBinary,
+    IndexT,
+};
+use crate::ad::ADType;
+use crate::adfn::optimize;
+use crate::op::id::{
+    LOAD_OP,
+    STORE_OP,
+};
+use crate::op::info::{
+    OpFns,
+    ConstData,
+    Linearity,
+};
+use crate::op::taylor;
+use crate::op::cexp::{
+    BranchSelect,
+    select,
+    select_mul,
+};
+// --------------------------------------------------------------------------
+//
+// BEGIN_ELEMENT
+/// Index in the LOAD_OP arguments of the first element of the vector.
+pub(crate) const BEGIN_ELEMENT : usize = 1;
+//
+// NUMBER_STORE_ARG
+/// Number of arguments for a STORE_OP operator.
+pub(crate) const NUMBER_STORE_ARG : usize = 4;
+// --------------------------------------------------------------------------
+// load_factor
+/// Factors c_j and 1 - sum_j c_j for a load; see the [module](self)
+/// documentation.
+///
+/// * index : is the value of the index for this load.
+/// * n_element : is the number of elements in the vector.
+pub(crate) fn load_factor<E>(index : &E, n_element : usize) -> (Vec<E>, E)
+where
+    E             : FConst ,
+    for<'a> &'a E : FBinary<&'a E, Output = E> ,
+    for<'a> &'a E : Add<&'a E, Output = E> ,
+    for<'a> &'a E : Sub<&'a E, Output = E> ,
+{
+    let mut factor    = Vec::with_capacity(n_element);
+    let mut found     = E::zero();
+    let mut j_value   = E::zero();
+    for _j in 0 .. n_element {
+        let c   = index.num_eq(&j_value);
+        found   = &found + &c;
+        j_value = &j_value + &E::one();
+        factor.push(c);
+    }
+    let not_found = &E::one() - &found;
+    (factor, not_found)
+}
+//
+// load
+/// Returns the value of a load; see the [module](self) documentation.
+pub(crate) fn load<E>(index : &E, element : &[E]) -> E
+where
+    E             : FConst + BranchSelect ,
+    for<'a> &'a E : FBinary<&'a E, Output = E> ,
+    for<'a> &'a E : Add<&'a E, Output = E> ,
+    for<'a> &'a E : Sub<&'a E, Output = E> ,
+    for<'a> &'a E : Mul<&'a E, Output = E> ,
+    for<'a> &'a E : Div<&'a E, Output = E> ,
+{
+    let (factor, not_found) = load_factor::<E>(index, element.len());
+    if ! E::ABSOLUTE_ZERO {
+        let mut result = E::nan();
+        for (c, e) in factor.iter().zip( element.iter() ) {
+            result = E::branch_select(c, e, &result);
+        }
+        return result;
+    }
+    let mut sum = &not_found * &E::nan();
+    for (c, e) in factor.iter().zip( element.iter() ) {
+        sum = &sum + &( c * e );
+    }
+    sum
+}
+//
+// arg_value
+/// Value of one of the arguments for a LOAD_OP or STORE_OP operator.
+fn arg_value<V, E>(
+    cop      : &[V]   ,
+    dyp_all  : &[E]   ,
+    var_all  : &[E]   ,
+    index    : IndexT ,
+    ad_type  : ADType ,
+) -> E
+where
+    V : Clone ,
+    E : Clone + From<V> ,
+{   let index = index as usize;
+    match ad_type {
+        ADType::ConstantP => E::from( cop[index].clone() ),
+        ADType::DynamicP  => dyp_all[index].clone(),
+        ADType::Variable  => var_all[index].clone(),
+        ADType::Empty     => panic!( "vec_ad: empty argument type" ),
+    }
+}
+//
+// store_factor
+/// Value of the comparison index == j for a STORE_OP operator.
+fn store_factor<V, E>(
+    cop      : &[V]      ,
+    dyp_all  : &[E]      ,
+    var_all  : &[E]      ,
+    arg      : &[IndexT] ,
+    arg_type : &[ADType] ,
+) -> E
+where
+    V             : Clone ,
+    E             : Clone + From<V> ,
+    for<'a> &'a E : FBinary<&'a E, Output = E> ,
+{   debug_assert!( arg_type[1].is_constant() );
+    let index = arg_value::<V, E>(cop, dyp_all, var_all, arg[0], arg_type[0]);
+    let j     = E::from( cop[ arg[1] as usize ].clone() );
+    index.num_eq(&j)
+}
+//
+// arg_factor
+/// The variable arguments, that the result depends on,
+/// and the corresponding derivative factors.
+///
+/// * index_value :
+///   is the value of the index argument (same type as the derivatives).
+fn arg_factor<V, E>(
+    op_id       : u8        ,
+    cop         : &[V]      ,
+    index_value : &E        ,
+    arg         : &[IndexT] ,
+    arg_type    : &[ADType] ,
+) -> Vec<(usize, E)>
+where
+    V             : Clone ,
+    E             : Clone + From<V> + FConst + BranchSelect ,
+    for<'a> &'a E : FBinary<&'a E, Output = E> ,
+    for<'a> &'a E : Add<&'a E, Output = E> ,
+    for<'a> &'a E : Sub<&'a E, Output = E> ,
+{   let mut result = Vec::new();
+    if op_id == LOAD_OP {
+        let n_element = arg.len() - BEGIN_ELEMENT;
+        let (factor, _) = load_factor::<E>(index_value, n_element);
+        for (j, c) in factor.into_iter().enumerate() {
+            let i_arg = BEGIN_ELEMENT + j;
+            if arg_type[i_arg].is_variable() {
+                result.push( (arg[i_arg] as usize, c) );
+            }
+        }
+    } else {
+        debug_assert!( op_id == STORE_OP );
+        debug_assert!( arg.len() == NUMBER_STORE_ARG );
+        let j     = E::from( cop[ arg[1] as usize ].clone() );
+        let c     = index_value.num_eq(&j);
+        let not_c = &E::one() - &c;
+        for (i_arg, factor) in [ (2, c), (3, not_c) ] {
+            if arg_type[i_arg].is_variable() {
+                result.push( (arg[i_arg] as usize, factor) );
+            }
+        }
+    }
+    result
+}
+// --------------------------------------------------------------------------
+// load_forward_dyp
+/// E evaluation of a load with dynamic result;
+/// see [ForwardDyp](crate::op::info::ForwardDyp)
+fn load_forward_dyp<V, E> (
+    dyp_all    : &mut [E]      ,
+    const_data : ConstData<V>  )
+where
+    V             : Clone ,
+    E             : Clone + From<V> + FConst + BranchSelect ,
+    for<'a> &'a E : FBinary<&'a E, Output = E> ,
+    for<'a> &'a E : Add<&'a E, Output = E> ,
+    for<'a> &'a E : Sub<&'a E, Output = E> ,
+    for<'a> &'a E : Mul<&'a E, Output = E> ,
+    for<'a> &'a E : Div<&'a E, Output = E> ,
+{   //
+    let ConstData {cop, arg, arg_type, res, ..} = const_data;
+    debug_assert!( ! arg_type.iter().any( |t| t.is_variable() ) );
+    //
+    let var_all : &[E] = &[];
+    let mut value : Vec<E> = Vec::with_capacity( arg.len() );
+    for i_arg in 0 .. arg.len() {
+        value.push( arg_value::<V, E>(
+            cop, dyp_all, var_all, arg[i_arg], arg_type[i_arg]
+        ) );
+    }
+    dyp_all[res] = load::<E>( &value[0], &value[BEGIN_ELEMENT ..] );
+}
+//
+// load_forward_var
+/// E evaluation of a load with variable result;
+/// see [ForwardVar](crate::op::info::ForwardVar)
+fn load_forward_var<V, E> (
+    dyp_all    : &[E]          ,
+    var_all    : &mut [E]      ,
+    const_data : ConstData<V>  )
+where
+    V             : Clone ,
+    E             : Clone + From<V> + FConst + BranchSelect ,
+    for<'a> &'a E : FBinary<&'a E, Output = E> ,
+    for<'a> &'a E : Add<&'a E, Output = E> ,
+    for<'a> &'a E : Sub<&'a E, Output = E> ,
+    for<'a> &'a E : Mul<&'a E, Output = E> ,
+    for<'a> &'a E : Div<&'a E, Output = E> ,
+{   //
+    let ConstData {cop, arg, arg_type, res, ..} = const_data;
+    //
+    let mut value : Vec<E> = Vec::with_capacity( arg.len() );
+    for i_arg in 0 .. arg.len() {
+        value.push( arg_value::<V, E>(
+            cop, dyp_all, var_all, arg[i_arg], arg_type[i_arg]
+        ) );
+    }
+    var_all[res] = load::<E>( &value[0], &value[BEGIN_ELEMENT ..] );
+}
+//
+// store_forward_dyp
+/// E evaluation of a store with dynamic result;
+/// see [ForwardDyp](crate::op::info::ForwardDyp)
+fn store_forward_dyp<V, E> (
+    dyp_all    : &mut [E]      ,
+    const_data : ConstData<V>  )
+where
+    V             : Clone ,
+    E             : Clone + From<V> + FConst + BranchSelect ,
+    for<'a> &'a E : FBinary<&'a E, Output = E> ,
+    for<'a> &'a E : Add<&'a E, Output = E> ,
+    for<'a> &'a E : Sub<&'a E, Output = E> ,
+    for<'a> &'a E : Mul<&'a E, Output = E> ,
+{   //
+    let ConstData {cop, arg, arg_type, res, ..} = const_data;
+    debug_assert!( arg.len() == NUMBER_STORE_ARG );
+    debug_assert!( ! arg_type.iter().any( |t| t.is_variable() ) );
+    //
+    let var_all : &[E] = &[];
+    let c     = store_factor::<V, E>(cop, dyp_all, var_all, arg, arg_type);
+    let value = arg_value::<V, E>(cop, dyp_all, var_all, arg[2], arg_type[2]);
+    let old   = arg_value::<V, E>(cop, dyp_all, var_all, arg[3], arg_type[3]);
+    dyp_all[res] = select::<E>(&c, &value, &old);
+}
+//
+// store_forward_var
+/// E evaluation of a store with variable result;
+/// see [ForwardVar](crate::op::info::ForwardVar)
+fn store_forward_var<V, E> (
+    dyp_all    : &[E]          ,
+    var_all    : &mut [E]      ,
+    const_data : ConstData<V>  )
+where
+    V             : Clone ,
+    E             : Clone + From<V> + FConst + BranchSelect ,
+    for<'a> &'a E : FBinary<&'a E, Output = E> ,
+    for<'a> &'a E : Add<&'a E, Output = E> ,
+    for<'a> &'a E : Sub<&'a E, Output = E> ,
+    for<'a> &'a E : Mul<&'a E, Output = E> ,
+{   //
+    let ConstData {cop, arg, arg_type, res, ..} = const_data;
+    debug_assert!( arg.len() == NUMBER_STORE_ARG );
+    //
+    let c     = store_factor::<V, E>(cop, dyp_all, var_all, arg, arg_type);
+    let value = arg_value::<V, E>(cop, dyp_all, var_all, arg[2], arg_type[2]);
+    let old   = arg_value::<V, E>(cop, dyp_all, var_all, arg[3], arg_type[3]);
+    var_all[res] = select::<E>(&c, &value, &old);
+}
+// --------------------------------------------------------------------------
+// src_operand
+/// Rust source for a reference to one of the arguments.
+fn src_operand(
+    index     : IndexT ,
+    ad_type   : ADType ,
+    dyp_n_dom : usize  ,
+    var_n_dom : usize  ,
+) -> String {
+    let index = index as usize;
+    match ad_type {
+        ADType::ConstantP => format!("&cop[{index}]"),
+        ADType::DynamicP  => if index < dyp_n_dom {
+            format!("dyp_dom[{index}]")
+        } else {
+            format!("&dyp_dep[{}]", index - dyp_n_dom)
+        },
+        ADType::Variable  => if index < var_n_dom {
+            format!("var_dom[{index}]")
+        } else {
+            format!("&var_dep[{}]", index - var_n_dom)
+        },
+        ADType::Empty     => panic!( "vec_ad rust_src: empty argument type" ),
+    }
+}
+//
+// res_src
+/// Rust source for the result of an operator.
+fn res_src(
+    res_type    : ADType ,
+    dyp_n_dom   : usize  ,
+    var_n_dom   : usize  ,
+    res         : usize  ,
+) -> String {
+    if res_type.is_dynamic() {
+        format!("dyp_dep[{}]", res - dyp_n_dom)
+    } else {
+        debug_assert!( res_type.is_variable() );
+        format!("var_dep[{}]", res - var_n_dom)
+    }
+}
+//
+// load_rust_src
+/// rust source for a load;
+/// see [RustSrc](crate::op::info::RustSrc)
+fn load_rust_src<V> (
+    res_type    : ADType      ,
+    dyp_n_dom   : usize       ,
+    var_n_dom   : usize       ,
+    const_data : ConstData<V> ) -> String
+{   //
+    let ConstData {arg, arg_type, res, ..} = const_data;
+    //
+    // index, element
+    let index   = src_operand(arg[0], arg_type[0], dyp_n_dom, var_n_dom);
+    let mut element = String::new();
+    for i_arg in BEGIN_ELEMENT .. arg.len() {
+        if i_arg > BEGIN_ELEMENT {
+            element += ", ";
+        }
+        element += &src_operand(
+            arg[i_arg], arg_type[i_arg], dyp_n_dom, var_n_dom
+        );
+    }
+    //
+    // res_str
+    let res_str = res_src(res_type, dyp_n_dom, var_n_dom, res);
+    //
+    // src
+    String::from("   ") + &res_str + " = {\n" +
+        "      let index     = " + &index + ";\n" +
+        "      let mut j     = V::zero();\n" +
+        "      let mut found = V::zero();\n" +
+        "      let mut sum   = V::zero();\n" +
+        "      let mut value = V::nan();\n" +
+        "      for e in [ " + &element + " ] {\n" +
+        "         let c = index.num_eq(&j);\n" +
+        "         if V::ABSOLUTE_ZERO {\n" +
+        "            sum   = &sum + &( &c * e );\n" +
+        "            found = &found + &c;\n" +
+        "         } else {\n" +
+        "            value = FValue::select(&c, e, &value);\n" +
+        "         }\n" +
+        "         j     = &j + &V::one();\n" +
+        "      }\n" +
+        "      if V::ABSOLUTE_ZERO {\n" +
+        "         let not_found = &V::one() - &found;\n" +
+        "         &sum + &( &not_found * &V::nan() )\n" +
+        "      } else {\n" +
+        "         value\n" +
+        "      }\n" +
+        "   };\n"
+}
+//
+// store_rust_src
+/// rust source for a store;
+/// see [RustSrc](crate::op::info::RustSrc)
+fn store_rust_src<V> (
+    res_type    : ADType      ,
+    dyp_n_dom   : usize       ,
+    var_n_dom   : usize       ,
+    const_data : ConstData<V> ) -> String
+{   //
+    let ConstData {arg, arg_type, res, ..} = const_data;
+    debug_assert!( arg.len() == NUMBER_STORE_ARG );
+    //
+    // operand
+    let mut operand : Vec<String> = Vec::with_capacity(NUMBER_STORE_ARG);
+    for i_arg in 0 .. NUMBER_STORE_ARG {
+        operand.push(
+            src_operand(arg[i_arg], arg_type[i_arg], dyp_n_dom, var_n_dom)
+        );
+    }
+    //
+    // res_str
+    let res_str = res_src(res_type, dyp_n_dom, var_n_dom, res);
+    //
+    // src
+    String::from("   ") + &res_str + " = {\n" +
+        "      let c     = (" + &operand[0] + ").num_eq(" +
+            &operand[1] + ");\n" +
+        "      if V::ABSOLUTE_ZERO {\n" +
+        "         let not_c = &V::one() - &c;\n" +
+        "         &( &c * " + &operand[2] + " ) + " +
+            "&( &not_c * " + &operand[3] + " )\n" +
+        "      } else {\n" +
+        "         FValue::select(&c, " + &operand[2] + ", " +
+            &operand[3] + ")\n" +
+        "      }\n" +
+        "   };\n"
+}
+// --------------------------------------------------------------------------
+// vec_ad_reverse_depend
+/// Reverse dependency analysis for load and store operators;
+/// see [ReverseDepend](crate::op::info::ReverseDepend)
+fn vec_ad_reverse_depend(
+    depend    : &mut optimize::Depend ,
+    _bool_all : &[bool]               ,
+    arg       : &[IndexT]             ,
+    arg_type  : &[ADType]             ,
+    _res      : usize                 ,
+    _res_type : ADType                ,
+) { //
+    for i_arg in 0 .. arg.len() {
+        let index = arg[i_arg] as usize;
+        match arg_type[i_arg] {
+            ADType::ConstantP => { depend.cop[index] = true; },
+            ADType::DynamicP  => { depend.dyp[index] = true; },
+            ADType::Variable  => { depend.var[index] = true; },
+            _ => { panic!("in vec_ad operator reverse_depend"); },
+        }
+    }
+}
+// --------------------------------------------------------------------------
+// vec_ad_forward_der
+/// First order forward mode for load and store operators;
+/// see [ForwardDer](crate::op::info::ForwardDer)
+fn vec_ad_forward_der<V, E, const OP_ID : u8>(
+    dyp_all    :   &[E]        ,
+    var_all    :   &[E]        ,
+    var_der    :   &mut [E]    ,
+    const_data : ConstData<V> )
+where
+    V             : Clone ,
+    E             : Clone + From<V> + FConst + BranchSelect ,
+    for<'a> E     : AddAssign<&'a E> ,
+    for<'a> &'a E : FBinary<&'a E, Output = E> ,
+    for<'a> &'a E : Add<&'a E, Output = E> ,
+    for<'a> &'a E : Sub<&'a E, Output = E> ,
+    for<'a> &'a E : Mul<&'a E, Output = E> ,
+{
+    let ConstData {cop, arg, arg_type, res, ..} = const_data;
+    //
+    let index  = arg_value::<V, E>(cop, dyp_all, var_all, arg[0], arg_type[0]);
+    let mut dz = E::zero();
+    for (i_var, factor) in arg_factor::<V, E>(OP_ID, cop, &index, arg, arg_type) {
+        dz += &select_mul::<E>(&factor, &var_der[i_var]);
+    }
+    var_der[res] = dz;
+}
+//
+// vec_ad_reverse_der
+/// First order reverse mode for load and store operators;
+/// see [ReverseDer](crate::op::info::ReverseDer)
+fn vec_ad_reverse_der<V, E, const OP_ID : u8>(
+    dyp_all    :   &[E]        ,
+    var_all    :   &[E]        ,
+    var_der    :   &mut [E]    ,
+    const_data : ConstData<V> )
+where
+    V             : Clone ,
+    E             : Clone + From<V> + FConst + BranchSelect ,
+    for<'a> E     : AddAssign<&'a E> ,
+    for<'a> &'a E : FBinary<&'a E, Output = E> ,
+    for<'a> &'a E : Add<&'a E, Output = E> ,
+    for<'a> &'a E : Sub<&'a E, Output = E> ,
+    for<'a> &'a E : Mul<&'a E, Output = E> ,
+{
+    let ConstData {cop, arg, arg_type, res, ..} = const_data;
+    //
+    let index  = arg_value::<V, E>(cop, dyp_all, var_all, arg[0], arg_type[0]);
+    for (i_var, factor) in arg_factor::<V, E>(OP_ID, cop, &index, arg, arg_type) {
+        let term        = select_mul::<E>(&factor, &var_der[res]);
+        var_der[i_var] += &term;
+    }
+}
+//
+// vec_ad_forward_taylor
+/// Arbitrary order forward mode for load and store operators;
+/// see [ForwardTaylor](crate::op::info::ForwardTaylor)
+fn vec_ad_forward_taylor<V, E, const OP_ID : u8>(
+    dyp_all    :   &[E]        ,
+    var_tay    :   &mut [E]    ,
+    n_tay      :   usize       ,
+    const_data : ConstData<V> )
+where
+    V             : Clone ,
+    E             : Clone + From<V> + FConst + BranchSelect ,
+    for<'a> E     : AddAssign<&'a E> ,
+    for<'a> &'a E : FBinary<&'a E, Output = E> ,
+    for<'a> &'a E : Add<&'a E, Output = E> ,
+    for<'a> &'a E : Sub<&'a E, Output = E> ,
+    for<'a> &'a E : Mul<&'a E, Output = E> ,
+{
+    let ConstData {cop, arg, arg_type, res, ..} = const_data;
+    //
+    let (before, z) = taylor::split_var_tay(var_tay, n_tay, res);
+    let index = taylor::arg_taylor::<V, E>(
+        dyp_all, cop, before, n_tay, arg[0], arg_type[0]
+    );
+    for z_k in z.iter_mut().skip(1) {
+        *z_k = E::zero();
+    }
+    for (i_var, factor) in arg_factor::<V, E>(OP_ID, cop, &index[0], arg, arg_type) {
+        for k in 1 .. n_tay {
+            z[k] += &select_mul::<E>(&factor, &before[i_var * n_tay + k]);
+        }
+    }
+}
+//
+// vec_ad_reverse_taylor
+/// Arbitrary order reverse mode for load and store operators;
+/// see [ReverseTaylor](crate::op::info::ReverseTaylor)
+fn vec_ad_reverse_taylor<V, E, const OP_ID : u8>(
+    dyp_all    :   &[E]        ,
+    var_tay    :   &[E]        ,
+    var_par    :   &mut [E]    ,
+    n_tay      :   usize       ,
+    const_data : ConstData<V> )
+where
+    V             : Clone ,
+    E             : Clone + From<V> + FConst + BranchSelect ,
+    for<'a> E     : AddAssign<&'a E> ,
+    for<'a> &'a E : FBinary<&'a E, Output = E> ,
+    for<'a> &'a E : Add<&'a E, Output = E> ,
+    for<'a> &'a E : Sub<&'a E, Output = E> ,
+    for<'a> &'a E : Mul<&'a E, Output = E> ,
+{
+    let ConstData {cop, arg, arg_type, res, ..} = const_data;
+    //
+    let index = taylor::arg_taylor::<V, E>(
+        dyp_all, cop, var_tay, n_tay, arg[0], arg_type[0]
+    );
+    let factor_vec   = arg_factor::<V, E>(OP_ID, cop, &index[0], arg, arg_type);
+    let (before, pz) = taylor::split_var_par(var_par, n_tay, res);
+    for (i_var, factor) in factor_vec {
+        for k in 0 .. n_tay {
+            before[i_var * n_tay + k] += &select_mul::<E>(&factor, &pz[k]);
+        }
+    }
+}
+// --------------------------------------------------------------------------
+// set_op_fns
+/// Set the operator functions for the LOAD_OP and STORE_OP operators.
+///
+/// * op_fns_vec :
+///   The map from [op::id](crate::op::id) to operator functions.
+///   The the map results for LOAD_OP and STORE_OP are set.
+pub fn set_op_fns<V>( op_fns_vec : &mut [OpFns<V>] )
+where
+    V                 : Clone + FConst + BranchSelect ,
+    AD<V>             : From<V> + FConst + BranchSelect ,
+    for<'a> V         : AddAssign<&'a V> ,
+    for<'a> AD<V>     : AddAssign<&'a AD<V> > ,
+    for<'a> &'a V     : FBinary<&'a V, Output = V> ,
+    for<'a> &'a V     : Add<&'a V, Output = V> ,
+    for<'a> &'a V     : Sub<&'a V, Output = V> ,
+    for<'a> &'a V     : Mul<&'a V, Output = V> ,
+    for<'a> &'a V     : Div<&'a V, Output = V> ,
+    for<'a> &'a AD<V> : FBinary<&'a AD<V>, Output = AD<V> > ,
+    for<'a> &'a AD<V> : Add<&'a AD<V>, Output = AD<V> > ,
+    for<'a> &'a AD<V> : Sub<&'a AD<V>, Output = AD<V> > ,
+    for<'a> &'a AD<V> : Mul<&'a AD<V>, Output = AD<V> > ,
+    for<'a> &'a AD<V> : Div<&'a AD<V>, Output = AD<V> > ,
+{
+    op_fns_vec[LOAD_OP as usize] = OpFns{
+        name              : "load",
+        forward_dyp_value : load_forward_dyp::<V, V>,
+        forward_dyp_ad    : load_forward_dyp::<V, AD<V> >,
+        forward_var_value : load_forward_var::<V, V>,
+        forward_var_ad    : load_forward_var::<V, AD<V> >,
+        forward_der_value : vec_ad_forward_der::<V, V, LOAD_OP>,
+        forward_der_ad    : vec_ad_forward_der::<V, AD<V>, LOAD_OP>,
+        reverse_der_value : vec_ad_reverse_der::<V, V, LOAD_OP>,
+        reverse_der_ad    : vec_ad_reverse_der::<V, AD<V>, LOAD_OP>,
+        forward_taylor_value : vec_ad_forward_taylor::<V, V, LOAD_OP>,
+        forward_taylor_ad    : vec_ad_forward_taylor::<V, AD<V>, LOAD_OP>,
+        reverse_taylor_value : vec_ad_reverse_taylor::<V, V, LOAD_OP>,
+        reverse_taylor_ad    : vec_ad_reverse_taylor::<V, AD<V>, LOAD_OP>,
+        rust_src          : load_rust_src,
+        reverse_depend    : vec_ad_reverse_depend,
+        linearity         : Linearity::Linear,
+    };
+    op_fns_vec[STORE_OP as usize] = OpFns{
+        name              : "store",
+        forward_dyp_value : store_forward_dyp::<V, V>,
+        forward_dyp_ad    : store_forward_dyp::<V, AD<V> >,
+        forward_var_value : store_forward_var::<V, V>,
+        forward_var_ad    : store_forward_var::<V, AD<V> >,
+        forward_der_value : vec_ad_forward_der::<V, V, STORE_OP>,
+        forward_der_ad    : vec_ad_forward_der::<V, AD<V>, STORE_OP>,
+        reverse_der_value : vec_ad_reverse_der::<V, V, STORE_OP>,
+        reverse_der_ad    : vec_ad_reverse_der::<V, AD<V>, STORE_OP>,
+        forward_taylor_value : vec_ad_forward_taylor::<V, V, STORE_OP>,
+        forward_taylor_ad    : vec_ad_forward_taylor::<V, AD<V>, STORE_OP>,
+        reverse_taylor_value : vec_ad_reverse_taylor::<V, V, STORE_OP>,
+        reverse_taylor_ad    : vec_ad_reverse_taylor::<V, AD<V>, STORE_OP>,
+        rust_src          : store_rust_src,
+        reverse_depend    : vec_ad_reverse_depend,
+        linearity         : Linearity::Linear,
+    };
+}
@@ -38,10 +38,13 @@ use crate::op::no_op::{
     no_op_dyp,
     no_op_var,
     no_op_der,
+    no_op_taylor,
+    no_op_reverse_taylor,
 };
 use crate::op::info::{
     OpFns,
     ConstData,
+    Linearity,
 };
 // --------------------------------------------------------------------------
 // zero_one_forward_dyp_value
@@ -294,7 +297,12 @@ where
         forward_der_ad    : no_op_der::<V, AD<V> >,
         reverse_der_value : no_op_der::<V, V>,
         reverse_der_ad    : no_op_der::<V, AD<V> >,
+        forward_taylor_value : no_op_taylor::<V, V>,
+        forward_taylor_ad    : no_op_taylor::<V, AD<V> >,
+        reverse_taylor_value : no_op_reverse_taylor::<V, V>,
+        reverse_taylor_ad    : no_op_reverse_taylor::<V, AD<V> >,
         rust_src          : zero_one_rust_src,
         reverse_depend    : zero_one_reverse_depend,
+        linearity         : Linearity::Linear,
     };
 }
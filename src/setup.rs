@@ -3,50 +3,149 @@
 // SPDX-FileContributor: 2025-26 Bradley M. Bell
 // ---------------------------------------------------------------------------
 //
-//! This pub(crate) module does setup for the possible value types.
+//! This module does setup for the possible value types.
 //!
 //! Link to [parent module](super)
 //!
-//! TODO: Enable this setup from an external crate.
+//! The [setup_value_type](crate::setup_value_type) macro
+//! is the public interface to this module.
 // ----------------------------------------------------------------------------
 //
 // use
-use std::sync::LazyLock;
-use std::sync::RwLock;
-use std::thread::LocalKey;
-use std::cell::RefCell;
 use crate::{
-    AD,
     NumVec,
     AzFloat,
+    Complex,
+    Interval,
+    DoubleDouble,
 };
-///
+//
+#[cfg(doc)]
+use crate::doc_generic_v;
+// ----------------------------------------------------------------------------
+// private
+#[doc(hidden)]
+pub mod private {
+    //! Items used by the [setup_value_type](crate::setup_value_type) macro.
+    //! These are not part of the public API and may change at any time.
+    //
+    pub use crate::tape::sealed::ThisThreadTape;
+    pub use crate::atom::sealed::GlobalAtomCallbackVec;
+    pub use crate::checkpoint::sealed::{
+        GlobalCheckpointInfoVec,
+        CheckpointInfo,
+    };
+    pub use crate::checkpoint::register_checkpoint_atom;
+    pub use crate::op::info::sealed::GlobalOpFnsVec;
+    pub use crate::op::info::{
+        OpFns,
+        op_fns_vec,
+    };
+    pub use crate::op::reduce::sealed::ReduceValue;
+    pub use crate::op::reduce::set_op_fns as set_reduce_op_fns;
+    pub const ADD_PP_OP : u8 = crate::op::id::ADD_PP_OP;
+    pub const SUB_PP_OP : u8 = crate::op::id::SUB_PP_OP;
+    pub const MUL_PP_OP : u8 = crate::op::id::MUL_PP_OP;
+    pub const DIV_PP_OP : u8 = crate::op::id::DIV_PP_OP;
+    pub use crate::ad::binary::record_value_op_ad;
+}
+// ----------------------------------------------------------------------------
+// setup_value_type!
 /// Set up rustad to do calculations with value type V; see
-/// [doc_generic_v](crate::doc_generic_v) .
+/// [doc_generic_v] .
+///
+/// * Syntax :
+///   ```text
+///     rustad::setup_value_type!( V );
+///     rustad::setup_value_type!( V, reduce );
+///   ```
+///
+/// * reduce :
+///   If this argument is present, the reduction operators are set up
+///   and `&AD<V>` implements [FReduce](crate::FReduce) .
+///   In this case `&V` must implement FReduce with `Output = V`
+///   (this is checked when the macro is expanded).
+///   If it is not present, `&AD<V>` does not implement FReduce .
 ///
 /// This macro must be executed once for any type *V*  where `AD<V>` is used.
+/// It must be invoked in the crate that defines *V* (at module level)
+/// because it implements rustad traits for *V* .
 /// The rustad package automatically executes this macro
-/// for the following types: `f32` , `f64` , `NumVec<f32>`, `NumVec<f64>`.
+/// for the following types: `AzFloat<f32>` , `AzFloat<f64>` ,
+/// `NumVec< AzFloat<f32> >`, `NumVec< AzFloat<f64> >` ,
+/// `Complex< AzFloat<f32> >`, `Complex< AzFloat<f64> >` ,
+/// `Interval<f32>`, `Interval<f64>` , `f32` , `f64` ,
+/// `DoubleDouble` , `AzFloat<DoubleDouble>` .
+///
+/// # Required Traits
+/// The type *V* must be `'static` and implement the following traits
+/// (this is checked when the macro is expanded):
 ///
+/// * [Clone] , [PartialEq] , [std::fmt::Display] , `From<f32>`
+/// * [FConst](crate::FConst) , [FValue](crate::FValue) ;
+///   see [ABSOLUTE_ZERO](crate::FConst::ABSOLUTE_ZERO) .
+/// * `&V` : [FUnary](crate::FUnary) with `Output = V` and
+///   [`FBinary<&V>`](crate::FBinary) with `Output = V` .
+/// * `V` : `AddAssign<&V>` and `SubAssign<&V>` .
+/// * `&V` : `Add<&V>` , `Sub<&V>` , `Mul<&V>` , `Div<&V>`
+///   with `Output = V` .
+/// * `AD<V>` : `From<V>` ; this is implemented by rustad for all *V* .
+///
+/// The [FBytes](crate::FBytes) and [FText](crate::FText) traits
+/// are not required by this macro.
+/// [FBytes](crate::FBytes) is needed to use the `ADfn<V>` to and from bytes
+/// functions and [ADfn::optimize](crate::ADfn::optimize)
+/// (which compares constants using their binary representation).
+/// [FText](crate::FText) is needed to use the `ADfn<V>` to and from
+/// text functions.
+///
+/// # Example
+/// The value types in rustad; e.g., [Complex] ,
+/// can be used as a guide for implementing the required traits.
+/// The file tests/setup_value_type.rs defines a value type `MyFloat`
+/// outside of rustad, executes
 /// ```text
-///     use std::sync::LazyLock;
-///     use std::sync::RwLock;
-///     use std::thread::LocalKey;
-///     use std::cell::RefCell;
-///     use crate::ad::AD;
+///     rustad::setup_value_type!( MyFloat );
 /// ```
-macro_rules! setup_this_value_type{ ($V:ty) => {
-        crate::tape::impl_this_thread_tape!($V);
-        crate::ad::binary::impl_value_op_ad!($V);
-        crate::atom::impl_global_atom_callback_vec!($V);
-        crate::checkpoint::impl_global_checkpoint_info!($V);
-        crate::op::info::impl_global_op_fns_vec!($V);
-} }
+/// and then records and evaluates `AD<MyFloat>` operations.
+#[macro_export]
+macro_rules! setup_value_type{
+    ($V:ty) => {
+        $crate::impl_this_thread_tape!($V);
+        $crate::impl_value_op_ad!($V);
+        $crate::impl_global_atom_callback_vec!($V);
+        $crate::impl_global_checkpoint_info!($V);
+        $crate::impl_global_op_fns_vec!($V);
+    };
+    ($V:ty, reduce) => {
+        $crate::impl_this_thread_tape!($V);
+        $crate::impl_value_op_ad!($V);
+        $crate::impl_global_atom_callback_vec!($V);
+        $crate::impl_global_checkpoint_info!($V);
+        $crate::impl_global_op_fns_vec!($V, reduce);
+    };
+}
 //
 // AzFloat value types
-setup_this_value_type!( AzFloat<f32> );
-setup_this_value_type!( AzFloat<f64> );
+setup_value_type!( AzFloat<f32>, reduce );
+setup_value_type!( AzFloat<f64>, reduce );
 //
 // NumVec<AzFloat> value types
-setup_this_value_type!( NumVec< AzFloat<f32> > );
-setup_this_value_type!( NumVec< AzFloat<f64> > );
+setup_value_type!( NumVec< AzFloat<f32> >, reduce );
+setup_value_type!( NumVec< AzFloat<f64> >, reduce );
+//
+// Complex<AzFloat> value types
+setup_value_type!( Complex< AzFloat<f32> >, reduce );
+setup_value_type!( Complex< AzFloat<f64> >, reduce );
+//
+// Interval value types
+setup_value_type!( Interval<f32>, reduce );
+setup_value_type!( Interval<f64>, reduce );
+//
+// primitive value types
+setup_value_type!( f32, reduce );
+setup_value_type!( f64, reduce );
+//
+// double-double value types
+setup_value_type!( DoubleDouble, reduce );
+setup_value_type!( AzFloat<DoubleDouble>, reduce );
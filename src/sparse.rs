@@ -204,3 +204,150 @@ pub fn coloring(
     } }
     color_vec
 }
+// ----------------------------------------------------------------------------
+// star_coloring
+/// Compute a symmetric coloring that can be used for a sparse Hessian
+/// calculation of a subset of a Hessian.
+///
+/// ```text
+///     color_vec = star_coloring(n, pattern, sub_pattern)
+/// ```
+///
+/// * n :
+///   number of rows and columns in the symmetric matrix
+///   the sparsity pattern corresponds to.
+///
+/// * pattern :
+///   If (i, j) is in *pattern* then, i < n, j < n, and the (i,j) entry
+///   in the Hessian may be non-zero.
+///   The pattern is treated as symmetric; i.e., if (i, j) is in *pattern*,
+///   the (j, i) entry may also be non-zero.
+///   This is normally computed by [ADfn::hes_sparsity] .
+///
+/// * sub_pattern :
+///   is a subset of *pattern* that we wish to calculate.
+///
+/// * color_vec :
+///   This is a star coloring for the subset of the Hessian.
+///   If color_vec(j) == n, j does not appear as a row or column index
+///   in *sub_pattern* . Otherwise, color_vec(j) < n.
+///   Only the indices with color_vec(j) < n are included in the graph
+///   defined by the off diagonal entries in *pattern* .
+///
+///   * Distance One :
+///     If i != j and (i, j) is in *pattern*,
+///     color_vec(i) != color_vec(j) .
+///
+///   * Star :
+///     Every path of four vertices in the graph uses at least three colors.
+///     It follows that for each (i, j) in *pattern*, either i has no
+///     other neighbor with color color_vec(j),
+///     or j has no other neighbor with color color_vec(i).
+///
+///   Given the conditions above, this routine tries to minimize the number
+///   of colors used in color. In addition, the colors are sequential
+///   starting at zero. The value *n* in color_vec is not considered a color.
+///   Thus, the number of colors is
+///   ```text
+///     n_color = color_vec.iter().filter( |&k| k < &n ).max().unwrap() + 1
+///   ```
+///   This is the number of Hessian times a vector calculations required
+///   to calculate the Hessian on sub_pattern; see
+///   [sparse_hes](crate::adfn::sparse_hes::doc_sparse_hes) .
+///
+/// * Reference :
+///   See StarColoringAlg1 Algorithm 4.1 of
+///   What Color Is Your Jacobian? Graph Coloring for Computing Derivatives by
+///   Assefaw Gebremedhin, Fredrik Manne, Alex Pothen
+///
+///   The algorithm was modified to only color the indices that appear
+///   in the sub_pattern.
+///
+pub fn star_coloring(
+    n           : usize            ,
+    pattern     : &SparsityPattern ,
+    sub_pattern : &SparsityPattern ,
+) -> Vec<usize> {
+    //
+    // in_sub_pattern
+    let mut in_sub_pattern = vec![false; n];
+    for [i, j] in sub_pattern {
+        in_sub_pattern[*i] = true;
+        in_sub_pattern[*j] = true;
+    }
+    //
+    // neighbor
+    // neighbor[i] is the sorted list of indices in the graph that are
+    // connected to i by an off diagonal entry in pattern.
+    let mut neighbor : Vec< Vec<usize> > = vec![ Vec::new(); n ];
+    for [i, j] in pattern {
+        if i != j && in_sub_pattern[*i] && in_sub_pattern[*j] {
+            neighbor[*i].push( *j );
+            neighbor[*j].push( *i );
+        }
+    }
+    for list in neighbor.iter_mut() {
+        list.sort_unstable();
+        list.dedup();
+    }
+    //
+    // color_vec
+    let mut color_vec = vec![n; n];
+    //
+    // forbidden
+    let mut forbidden = vec![true; n];
+    //
+    // n_color
+    let mut n_color = 0;
+    //
+    // color_vec[v]
+    // determine the final color for index v
+    for v in 0 .. n { if in_sub_pattern[v] {
+        //
+        // forbidden
+        for color in forbidden[0 .. n_color].iter_mut() {
+            *color = false;
+        }
+        //
+        // w
+        for w in &neighbor[v] {
+            let color_w = color_vec[*w];
+            if color_w < n {
+                forbidden[color_w] = true;
+            }
+            //
+            // x
+            for x in &neighbor[*w] {
+                let color_x = color_vec[*x];
+                if *x != v && color_x < n {
+                    if color_w == n {
+                        // v and x must have different colors
+                        // because w may get any color
+                        forbidden[color_x] = true;
+                    } else {
+                        // v, w, x, y would be a two color path if
+                        // v had the same color as x
+                        let two_color = neighbor[*x].iter().any(
+                            |y| y != w && color_vec[*y] == color_w
+                        );
+                        if two_color {
+                            forbidden[color_x] = true;
+                        }
+                    }
+                }
+            }
+        }
+        // color_vec[v]
+        let mut color = 0;
+        while color < n_color && forbidden[color] {
+            color += 1;
+        }
+        color_vec[v] = color;
+        //
+        // n_color
+        if color == n_color {
+            n_color += 1;
+        }
+    } }
+    color_vec
+}
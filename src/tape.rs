@@ -28,6 +28,7 @@ pub type IndexT = u32;
 // AGraph
 /// An acyclic graph is a single assignment representation of
 /// a function; i.e., each dependent value is only assigned once.
+#[derive(Clone)]
 pub(crate) struct AGraph {
     //
     // n_dom
@@ -177,34 +178,27 @@ pub (crate) mod sealed {
 ///
 /// * V : see [doc_generic_v]
 ///
-/// This macro must be executed once for any type *V*  where
-/// `AD<V>` is used. The rustad package automatically executes it
-/// for the following types: `f32` , `f64` , `NumVec<f32>`, `NumVec<f64>`.
-///
-/// This macro can be invoked from anywhere given the following use statements:
-/// ```text
-///     use std::thread::LocalKey;
-///     use std::cell::RefCell;
-/// ```
+/// This macro is used by [setup_value_type](crate::setup_value_type) .
+#[doc(hidden)]
+#[macro_export]
 macro_rules! impl_this_thread_tape{ ($V:ty) => {
     #[doc = concat!(
         "This threads tape for recording ",
         "`AD<" , stringify!($V), ">` operations"
     ) ]
-    impl crate::tape::sealed::ThisThreadTape for $V {
-        fn get() -> &'static LocalKey<
-                RefCell< crate::tape::Tape<$V> >
+    impl $crate::setup::private::ThisThreadTape for $V {
+        fn get() -> &'static std::thread::LocalKey<
+                std::cell::RefCell< $crate::tape::Tape<$V> >
             > {
             thread_local! {
-                pub(crate) static THIS_THREAD_TAPE : RefCell<
-                    crate::tape::Tape<$V>
-                > = RefCell::new( crate::tape::Tape::default() );
+                pub(crate) static THIS_THREAD_TAPE : std::cell::RefCell<
+                    $crate::tape::Tape<$V>
+                > = std::cell::RefCell::new( $crate::tape::Tape::default() );
             }
             &THIS_THREAD_TAPE
         }
     }
 } }
-pub(crate) use impl_this_thread_tape;
 // ----------------------------------------------------------------------------
 //
 // start_recording
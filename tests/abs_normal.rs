@@ -0,0 +1,147 @@
+// SPDX-License-Identifier: EPL-2.0 OR GPL-2.0-or-later
+// SPDX-FileCopyrightText: Bradley M. Bell <bradbell@seanet.com>
+// SPDX-FileContributor: 2026 Bradley M. Bell
+//
+use rustad::{
+    ADfn,
+    AzFloat,
+    FUnary,
+    FBinary,
+    start_recording,
+    stop_recording,
+};
+//
+// V
+type V = AzFloat<f64>;
+//
+// record_f
+// f(p, x) = [ | p[0] - | x[0] | | * x[1], min(x[0], x[1]) + max(p[0], x[1]) ]
+fn record_f() -> ADfn<V> {
+    let p         = vec![ V::from(1.0) ];
+    let x         = vec![ V::from(1.0), V::from(2.0) ];
+    let (ap, ax)  = start_recording( Some(p), x );
+    let ainner    = (&ax[0]).abs();
+    let aouter    = (&ap[0] - &ainner).abs();
+    let ay_0      = &aouter * &ax[1];
+    let amin      = (&ax[0]).min(&ax[1]);
+    let amax      = (&ap[0]).max(&ax[1]);
+    let ay_1      = &amin + &amax;
+    stop_recording( vec![ay_0, ay_1] )
+}
+//
+// test_abs_normal
+fn test_abs_normal() {
+    let opt_vec : Vec<[&str; 2]> = Vec::new();
+    let f = record_f();
+    //
+    // g, a, n_switch
+    let (g, a, n_switch) = f.abs_normal(&opt_vec);
+    assert_eq!( n_switch, 4 );
+    assert_eq!( g.dyp_dom_len(), 1 );
+    assert_eq!( g.var_dom_len(), 2 + n_switch );
+    assert_eq!( g.rng_len(), 2 + n_switch );
+    assert_eq!( a.dyp_dom_len(), 1 );
+    assert_eq!( a.var_dom_len(), 2 );
+    assert_eq!( a.rng_len(), n_switch );
+    //
+    // g is smooth
+    let text = g.to_text();
+    for name in [ "abs", "min_", "max_" ] {
+        assert!( ! text.contains(name) );
+    }
+    //
+    for (p_0, x_0, x_1) in [ (1.0, -3.0, 2.0), (4.0, 2.0, -1.0) ] {
+        let p = vec![ V::from(p_0) ];
+        let x = vec![ V::from(x_0), V::from(x_1) ];
+        //
+        // y = f(p, x)
+        let dyp_all = f.forward_dyp_value(p.clone(), &opt_vec);
+        let (y, _)  = f.forward_var_value(Some(&dyp_all), x.clone(), &opt_vec);
+        //
+        // u = a(p, x)
+        let dyp_all = a.forward_dyp_value(p.clone(), &opt_vec);
+        let (u, _)  = a.forward_var_value(Some(&dyp_all), x.clone(), &opt_vec);
+        let check   = [
+            x[0].abs(),
+            ( p[0] - x[0].abs() ).abs(),
+            ( x[0] - x[1] ).abs(),
+            ( p[0] - x[1] ).abs(),
+        ];
+        assert_eq!( u, check );
+        //
+        // (y, z) = g(p, x, u)
+        let mut xu  = x.clone();
+        xu.extend( u.iter().cloned() );
+        let dyp_all = g.forward_dyp_value(p.clone(), &opt_vec);
+        let (yz, _) = g.forward_var_value(Some(&dyp_all), xu, &opt_vec);
+        //
+        // f(x) = y( x, a(x) )
+        assert_eq!( yz[0], y[0] );
+        assert_eq!( yz[1], y[1] );
+        //
+        // a(x) = | z( x, a(x) ) |
+        for i in 0 .. n_switch {
+            assert_eq!( yz[2 + i].abs(), u[i] );
+        }
+        let check = [ x[0], p[0] - x[0].abs(), x[0] - x[1], p[0] - x[1] ];
+        assert_eq!( &yz[2 ..], check );
+    }
+}
+//
+// test_derivative
+// Away from the kinks, the derivative of f is the derivative of
+// y( x, a(x) ) computed using the derivatives of g and a .
+fn test_derivative() {
+    let opt_vec : Vec<[&str; 2]> = Vec::new();
+    //
+    // f
+    // f(x) = | x[0] * x[1] | + x[0]
+    let x          = vec![ V::from(2.0), V::from(-3.0) ];
+    let (_, ax)    = start_recording( None, x.clone() );
+    let aprod      = &ax[0] * &ax[1];
+    let ay         = &aprod.abs() + &ax[0];
+    let f          = stop_recording( vec![ay] );
+    //
+    let (g, a, n_switch) = f.abs_normal(&opt_vec);
+    assert_eq!( n_switch, 1 );
+    //
+    // df
+    let (_, v)     = f.forward_var_value(None, x.clone(), &opt_vec);
+    let dx         = vec![ V::from(1.0), V::from(0.0) ];
+    let df         = f.forward_der_value(None, &v, dx.clone(), &opt_vec);
+    //
+    // du
+    let (u, v)     = a.forward_var_value(None, x.clone(), &opt_vec);
+    let du         = a.forward_der_value(None, &v, dx.clone(), &opt_vec);
+    //
+    // dg
+    let xu         = vec![ x[0], x[1], u[0] ];
+    let (_, v)     = g.forward_var_value(None, xu, &opt_vec);
+    let dxu        = vec![ dx[0], dx[1], du[0] ];
+    let dg         = g.forward_der_value(None, &v, dxu, &opt_vec);
+    //
+    assert_eq!( dg[0], df[0] );
+    assert_eq!( df[0], V::from(4.0) );
+}
+//
+// test_no_switch
+fn test_no_switch() {
+    let opt_vec : Vec<[&str; 2]> = Vec::new();
+    let x          = vec![ V::from(2.0) ];
+    let (_, ax)    = start_recording( None, x.clone() );
+    let ay         = (&ax[0]).sin();
+    let f          = stop_recording( vec![ay] );
+    //
+    let (g, a, n_switch) = f.abs_normal(&opt_vec);
+    assert_eq!( n_switch, 0 );
+    assert_eq!( a.rng_len(), 0 );
+    let (y, _)     = g.forward_var_value(None, x.clone(), &opt_vec);
+    assert_eq!( y[0], x[0].sin() );
+}
+//
+#[test]
+fn abs_normal() {
+    test_abs_normal();
+    test_derivative();
+    test_no_switch();
+}
@@ -1,6 +1,11 @@
 // SPDX-License-Identifier: EPL-2.0 OR GPL-2.0-or-later
 // SPDX-FileCopyrightText: Bradley M. Bell <bradbell@seanet.com>
 // SPDX-FileContributor: 2025-26 Bradley M. Bell
+#![allow(
+    clippy::let_and_return,
+    clippy::needless_range_loop,
+    clippy::vec_init_then_push,
+)]
 /*
 Test rust_src with an atomic function and dynamic parameters.
 
@@ -70,7 +75,7 @@ pub fn h_forward_fun_value(
     let mut range : Vec<V> = Vec::new();
     range.push( domain[0] * domain[1] );
     range.push( domain[1] * domain[2] );
-    range.push( domain[3].clone() );
+    range.push( *domain[3] );
     //
     if trace {
         println!("Begin Trace: h_forward_fun_value");
@@ -118,7 +123,7 @@ fn register_h()-> IndexT {
     //
     // h_callback
     let h_callback = AtomCallback {
-        name                 : &"h",
+        name                 : "h",
         rev_depend           :  Some( h_rev_depend) ,
         //
         forward_fun_value    :  Some( h_forward_fun_value ),
@@ -155,9 +160,9 @@ fn build_atom_src() -> String {
     // begin_comment, end_comment
     // use concat so that this text does not match during the find below
     let begin_comment = String::new() +
-        "// BEGIN " + &atom_name + "_forward_fun_value\n";
+        "// BEGIN " + atom_name + "_forward_fun_value\n";
     let end_comment = String::new() +
-        "// END " + &atom_name + "_forward_fun_value\n";
+        "// END " + atom_name + "_forward_fun_value\n";
     //
     // atom_src
     let start      = this_src.find(&begin_comment).unwrap();
@@ -166,8 +171,8 @@ fn build_atom_src() -> String {
     let atom_src   = atom_src.replace( &begin_comment, "//\n");
     //
     // atom_src
-    let old_name   = String::new() + &atom_name + "_forward_fun_value";
-    let new_name   = String::new() + "atom_" + &atom_name;
+    let old_name   = String::new() + atom_name + "_forward_fun_value";
+    let new_name   = String::new() + "atom_" + atom_name;
     let atom_src   = atom_src.replace(&old_name, &new_name);
     //
     // atom_src
@@ -252,16 +257,16 @@ fn atom_dyp_src() {
     let lib         = get_lib(src_dir, lib_file, replace_lib);
     //
     // h_fn
-    let h_fn : RustSrcLink<V> = get_rust_src_fn(&lib, &fn_name);
+    let h_fn : RustSrcLink<V> = get_rust_src_fn(&lib, fn_name);
     //
     // p_ref, x_ref
     let mut p_ref : Vec<&V> = Vec::new();
     for p_j in p.iter() {
-        p_ref.push( &p_j );
+        p_ref.push( p_j );
     }
     let mut x_ref : Vec<&V> = Vec::new();
     for x_j in x.iter() {
-        x_ref.push( &x_j )
+        x_ref.push( x_j )
     }
     //
     // check result
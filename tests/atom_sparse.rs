@@ -1,6 +1,11 @@
 // SPDX-License-Identifier: EPL-2.0 OR GPL-2.0-or-later
 // SPDX-FileCopyrightText: Bradley M. Bell <bradbell@seanet.com>
 // SPDX-FileContributor: 2025-26 Bradley M. Bell
+#![allow(
+    clippy::let_and_return,
+    clippy::needless_range_loop,
+    clippy::vec_init_then_push,
+)]
 /*
 Test rust_src with an atomic function and dynamic parameters.
 
@@ -45,7 +50,7 @@ pub fn h_forward_fun_value(
     let mut range : Vec<V> = Vec::new();
     range.push( domain[0] * domain[1] );
     range.push( domain[1] * domain[2] );
-    range.push( domain[3].clone() );
+    range.push( *domain[3] );
     //
     if trace {
         println!("Begin Trace: h_forward_fun_value");
@@ -86,7 +91,7 @@ fn register_h()-> IndexT {
     //
     // h_callback
     let h_callback = AtomCallback {
-        name                 : &"h",
+        name                 : "h",
         //
         rev_depend           :  Some( h_rev_depend ),
         //
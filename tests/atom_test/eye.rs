@@ -28,7 +28,7 @@ where
 }
 //
 // rev_depend
-fn rev_depend<V>(
+fn rev_depend(
     depend       : &mut Vec<usize> ,
     rng_index    : usize           ,
     n_dom        : usize           ,
@@ -51,8 +51,8 @@ where
     //
     // callback
     let callback = AtomCallback{
-        name               : &"eye",
-        rev_depend         : Some(rev_depend::<V>),
+        name               : "eye",
+        rev_depend         : Some(rev_depend),
         forward_fun_value  : Some(forward_fun_value::<V>) ,
         //
         forward_fun_ad     : None,
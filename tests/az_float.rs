@@ -1,6 +1,7 @@
 // SPDX-License-Identifier: EPL-2.0 OR GPL-2.0-or-later
 // SPDX-FileCopyrightText: Bradley M. Bell <bradbell@seanet.com>
 // SPDX-FileContributor: 2025-26 Bradley M. Bell
+#![allow(clippy::needless_range_loop, clippy::op_ref)]
 //
 use rustad::{
     AD,
@@ -10,12 +11,13 @@ use rustad::{
 };
 //
 // test_multiply_op
+#[allow(clippy::eq_op)]
 fn test_multiply_op() {
     let zero  = AzFloat( 0f32 );
     let nan   = AzFloat( f32::NAN );
     let prod  = zero * nan;
     assert_eq!( prod, zero );
-    assert_eq!( nan == nan, true );
+    assert!( nan == nan );
     //
     let three = AzFloat( 3f64 );
     let four  = AzFloat( 4f64 );
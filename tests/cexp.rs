@@ -0,0 +1,269 @@
+// SPDX-License-Identifier: EPL-2.0 OR GPL-2.0-or-later
+// SPDX-FileCopyrightText: Bradley M. Bell <bradbell@seanet.com>
+// SPDX-FileContributor: 2026 Bradley M. Bell
+//
+use rustad::{
+    AD,
+    ADfn,
+    AzFloat,
+    NumVec,
+    CompareOp,
+    FUnary,
+    start_recording,
+    stop_recording,
+};
+//
+// V
+type V = AzFloat<f64>;
+//
+// record_f
+// f(x) = [ if x[0] < 2 { sin( x[0] ) } else { x[1] * x[1] } ]
+fn record_f(x : Vec<V>) -> ADfn<V> {
+    let (_, ax)   = start_recording(None, x);
+    let atwo      = AD::from( V::from(2.0) );
+    let asin      = (&ax[0]).sin();
+    let asq       = &ax[1] * &ax[1];
+    let ay        = AD::select(&ax[0], &atwo, CompareOp::Lt, &asin, &asq);
+    stop_recording( vec![ay] )
+}
+//
+// test_value
+fn test_value() {
+    let opt_vec : Vec<[&str; 2]> = Vec::new();
+    let f = record_f( vec![ V::from(1.0), V::from(3.0) ] );
+    assert_eq!( f.var_dep_len(), 3 );
+    //
+    // true branch
+    let x       = vec![ V::from(1.0), V::from(3.0) ];
+    let (y, _)  = f.forward_var_value(None, x.clone(), &opt_vec);
+    assert_eq!( y[0], x[0].sin() );
+    //
+    // false branch
+    let x       = vec![ V::from(2.0), V::from(3.0) ];
+    let (y, _)  = f.forward_var_value(None, x.clone(), &opt_vec);
+    assert_eq!( y[0], x[1] * x[1] );
+    //
+    // a nan in the branch that is not selected does not affect the result
+    let x       = vec![ V::from(0.5), V::from(f64::NAN) ];
+    let (y, _)  = f.forward_var_value(None, x.clone(), &opt_vec);
+    assert_eq!( y[0], x[0].sin() );
+    //
+    // each comparison operator
+    let cmp_vec = [
+        (CompareOp::Lt, [ true,  false, false ] ),
+        (CompareOp::Le, [ true,  true,  false ] ),
+        (CompareOp::Eq, [ false, true,  false ] ),
+        (CompareOp::Ne, [ true,  false, true  ] ),
+        (CompareOp::Ge, [ false, true,  true  ] ),
+        (CompareOp::Gt, [ false, false, true  ] ),
+    ];
+    for (cmp, check) in cmp_vec {
+        let x       = vec![ V::from(0.0) ];
+        let (_, ax) = start_recording(None, x);
+        let atwo    = AD::from( V::from(2.0) );
+        let aone    = AD::from( V::from(1.0) );
+        let azero   = AD::from( V::from(0.0) );
+        let ay      = AD::select(&ax[0], &atwo, cmp, &aone, &azero);
+        let f       = stop_recording( vec![ay] );
+        for (i, x_0) in [1.0, 2.0, 3.0].iter().enumerate() {
+            let x       = vec![ V::from(*x_0) ];
+            let (y, _)  = f.forward_var_value(None, x, &opt_vec);
+            let value   = if check[i] { 1.0 } else { 0.0 };
+            assert_eq!( y[0], V::from(value) );
+        }
+    }
+}
+//
+// test_constant_condition
+fn test_constant_condition() {
+    let opt_vec : Vec<[&str; 2]> = Vec::new();
+    //
+    // f
+    // The condition does not depend on x so no CEXP_OP is recorded.
+    let x         = vec![ V::from(1.0) ];
+    let (_, ax)   = start_recording(None, x);
+    let aone      = AD::from( V::from(1.0) );
+    let atwo      = AD::from( V::from(2.0) );
+    let asin      = (&ax[0]).sin();
+    let acos      = (&ax[0]).cos();
+    let ay        = AD::select(&aone, &atwo, CompareOp::Gt, &asin, &acos);
+    let f         = stop_recording( vec![ay] );
+    assert_eq!( f.var_dep_len(), 2 );
+    assert!( ! f.to_text().contains("cexp") );
+    //
+    let x         = vec![ V::from(0.5) ];
+    let (y, _)    = f.forward_var_value(None, x.clone(), &opt_vec);
+    assert_eq!( y[0], x[0].cos() );
+}
+//
+// test_dynamic
+// f(p, x) = [ if p[0] <= p[1] { p[0] } else { p[1] } * x[0] ]
+fn test_dynamic() {
+    let opt_vec : Vec<[&str; 2]> = Vec::new();
+    let p         = vec![ V::from(1.0), V::from(2.0) ];
+    let x         = vec![ V::from(3.0) ];
+    let (ap, ax)  = start_recording( Some(p), x.clone() );
+    let amin      = AD::select(&ap[0], &ap[1], CompareOp::Le, &ap[0], &ap[1]);
+    let ay        = &amin * &ax[0];
+    let f         = stop_recording( vec![ay] );
+    assert!( f.to_text().contains("cexp [") );
+    //
+    for (p_0, p_1) in [ (4.0, 5.0), (5.0, 4.0) ] {
+        let p        = vec![ V::from(p_0), V::from(p_1) ];
+        let dyp_all  = f.forward_dyp_value(p, &opt_vec);
+        let (y, _)   = f.forward_var_value(Some(&dyp_all), x.clone(), &opt_vec);
+        assert_eq!( y[0], V::from(4.0) * x[0] );
+    }
+}
+//
+// test_num_vec
+// The comparison and selection are element wise.
+fn test_num_vec() {
+    type S = AzFloat<f64>;
+    type W = NumVec<S>;
+    let opt_vec : Vec<[&str; 2]> = Vec::new();
+    let x0        = W::new( vec![ S::from(1.0), S::from(3.0) ] );
+    let x1        = W::new( vec![ S::from(5.0), S::from(6.0) ] );
+    let (_, ax)   = start_recording(None, vec![ x0.clone(), x1.clone() ] );
+    let atwo      = AD::from( W::from(2.0) );
+    let ay        = AD::select(&ax[0], &atwo, CompareOp::Lt, &ax[0], &ax[1]);
+    let f         = stop_recording( vec![ay] );
+    //
+    let (y, _)    = f.forward_var_value(None, vec![x0, x1], &opt_vec);
+    assert_eq!( y[0], W::new( vec![ S::from(1.0), S::from(6.0) ] ) );
+}
+//
+// test_derivative
+fn test_derivative() {
+    let opt_vec : Vec<[&str; 2]> = Vec::new();
+    let f = record_f( vec![ V::from(1.0), V::from(3.0) ] );
+    //
+    for x in [
+        vec![ V::from(1.0), V::from(3.0) ],
+        vec![ V::from(4.0), V::from(3.0) ],
+    ] {
+        let branch_true = x[0] < V::from(2.0);
+        //
+        // check
+        let check = if branch_true {
+            vec![ x[0].cos(), V::from(0.0) ]
+        } else {
+            vec![ V::from(0.0), V::from(2.0) * x[1] ]
+        };
+        //
+        // forward_der_value
+        let (_, v)  = f.forward_var_value(None, x.clone(), &opt_vec);
+        for j in 0 .. 2 {
+            let mut dx = vec![ V::from(0.0); 2 ];
+            dx[j]      = V::from(1.0);
+            let dy     = f.forward_der_value(None, &v, dx, &opt_vec);
+            assert_eq!( dy[0], check[j] );
+        }
+        //
+        // reverse_der_value
+        let dy = vec![ V::from(1.0) ];
+        let dx = f.reverse_der_value(None, &v, dy, &opt_vec);
+        assert_eq!( dx, check );
+        //
+        // forward_taylor_value
+        // x(t) = x + t * [1, 1]
+        let order         = 2;
+        let dom_taylor    = vec![
+            V::from(1.0), V::from(0.0), V::from(1.0), V::from(0.0)
+        ];
+        let (y_taylor, var_taylor) = f.forward_taylor_value(
+            None, &v, order, dom_taylor, &opt_vec
+        );
+        if branch_true {
+            assert_eq!( y_taylor[0], x[0].cos() );
+            assert_eq!( y_taylor[1], x[0].sin().minus() / V::from(2.0) );
+        } else {
+            assert_eq!( y_taylor[0], V::from(2.0) * x[1] );
+            assert_eq!( y_taylor[1], V::from(1.0) );
+        }
+        //
+        // reverse_taylor_value
+        // partial of y[order] w.r.t. x[order] is the first derivative
+        let rng_weight  = vec![ V::from(1.0) ];
+        let dom_partial = f.reverse_taylor_value(
+            None, &var_taylor, order, rng_weight, &opt_vec
+        );
+        for j in 0 .. 2 {
+            assert_eq!( dom_partial[j * (order + 1) + order], check[j] );
+        }
+    }
+}
+//
+// test_optimize
+fn test_optimize() {
+    let opt_vec : Vec<[&str; 2]> = Vec::new();
+    //
+    // default: both branches are kept
+    let mut f = record_f( vec![ V::from(1.0), V::from(3.0) ] );
+    f.optimize(&opt_vec);
+    assert_eq!( f.var_dep_len(), 3 );
+    //
+    // recorded: the branch that was not selected is removed
+    let mut f = record_f( vec![ V::from(1.0), V::from(3.0) ] );
+    f.optimize( &vec![ ["cexp", "recorded"] ] );
+    assert_eq!( f.var_dep_len(), 2 );
+    //
+    let x       = vec![ V::from(0.5), V::from(3.0) ];
+    let (y, _)  = f.forward_var_value(None, x.clone(), &opt_vec);
+    assert_eq!( y[0], x[0].sin() );
+    //
+    // the result is nan when the comparison result changes
+    let x       = vec![ V::from(2.5), V::from(3.0) ];
+    let (y, _)  = f.forward_var_value(None, x.clone(), &opt_vec);
+    assert!( y[0].to_inner().is_nan() );
+}
+//
+// test_sparsity
+fn test_sparsity() {
+    let both     = vec![ ["cexp", "both"] ];
+    let recorded = vec![ ["cexp", "recorded"] ];
+    let f = record_f( vec![ V::from(1.0), V::from(3.0) ] );
+    //
+    // for_sparsity
+    let mut pattern = f.for_sparsity(&both);
+    pattern.sort();
+    assert_eq!( pattern, vec![ [0, 0], [0, 1] ] );
+    let pattern = f.for_sparsity(&recorded);
+    assert_eq!( pattern, vec![ [0, 0] ] );
+    //
+    // sub_sparsity
+    let (_, mut pattern) = f.sub_sparsity(&both);
+    pattern.sort();
+    assert_eq!( pattern, vec![ [0, 0], [0, 1] ] );
+    let (_, pattern) = f.sub_sparsity(&recorded);
+    assert_eq!( pattern, vec![ [0, 0] ] );
+    //
+    // hes_sparsity
+    let select_rng = vec![ true ];
+    let pattern = f.hes_sparsity(&select_rng, &both);
+    assert_eq!( pattern, vec![ [0, 0], [1, 1] ] );
+    let pattern = f.hes_sparsity(&select_rng, &recorded);
+    assert_eq!( pattern, vec![ [0, 0] ] );
+}
+//
+// test_to_text
+fn test_to_text() {
+    let f = record_f( vec![ V::from(3.0), V::from(3.0) ] );
+    let text         = f.to_text();
+    assert!( text.contains("bool [ false, true ]") );
+    let g : ADfn<V>  = ADfn::from_text(&text).unwrap();
+    assert_eq!( g.to_text(), text );
+    assert_eq!( g.to_bytes(), f.to_bytes() );
+}
+//
+#[test]
+fn cexp() {
+    test_value();
+    test_constant_condition();
+    test_dynamic();
+    test_num_vec();
+    test_derivative();
+    test_optimize();
+    test_sparsity();
+    test_to_text();
+}
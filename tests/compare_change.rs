@@ -0,0 +1,171 @@
+// SPDX-License-Identifier: EPL-2.0 OR GPL-2.0-or-later
+// SPDX-FileCopyrightText: Bradley M. Bell <bradbell@seanet.com>
+// SPDX-FileContributor: 2026 Bradley M. Bell
+//
+use rustad::{
+    ADfn,
+    AzFloat,
+    FBinary,
+    NumVec,
+    start_recording,
+    stop_recording,
+    pop_this_thread_message,
+};
+//
+// V
+type V = AzFloat<f64>;
+//
+// pop_all_messages
+// Messages are returned in the order they were pushed.
+fn pop_all_messages() -> Vec<String> {
+    let mut messages = Vec::new();
+    while let Some(message) = pop_this_thread_message() {
+        messages.push(message);
+    }
+    messages.reverse();
+    messages
+}
+//
+// record_f
+// f(x) = [ x[0] * (x[0] < x[1]) + x[1] * (x[1] >= 2) ]
+fn record_f(x : Vec<V>) -> ADfn<V> {
+    let (_, ax)   = start_recording(None, x);
+    let alt       = (&ax[0]).num_lt( &ax[1] );
+    let age       = (&ax[1]).num_ge( &V::from(2.0) );
+    let ay        = &ax[0] * &alt + &ax[1] * &age;
+    stop_recording( vec![ay] )
+}
+//
+// test_value
+fn test_value() {
+    let opt_vec   = vec![ ["compare_change", "true"] ];
+    let f         = record_f( vec![ V::from(1.0), V::from(2.0) ] );
+    assert!( pop_all_messages().is_empty() );
+    //
+    // no change
+    let x         = vec![ V::from(0.0), V::from(3.0) ];
+    let (y, _)    = f.forward_var_value(None, x, &opt_vec);
+    assert_eq!( y[0], V::from(3.0) );
+    assert!( pop_all_messages().is_empty() );
+    //
+    // first comparison changes
+    let x         = vec![ V::from(3.0), V::from(2.0) ];
+    f.forward_var_value(None, x, &opt_vec);
+    let message   = "comparison result different from during recording";
+    assert_eq!(
+        pop_all_messages(),
+        vec![ format!("compare_change: var operator 0: {message}") ]
+    );
+    //
+    // both comparisons change
+    let x         = vec![ V::from(3.0), V::from(1.0) ];
+    f.forward_var_value(None, x.clone(), &opt_vec);
+    assert_eq!( pop_all_messages().len(), 2 );
+    //
+    // the default is to not check for changes
+    let no_opt : Vec<[&str; 2]> = Vec::new();
+    f.forward_var_value(None, x, &no_opt);
+    assert!( pop_all_messages().is_empty() );
+}
+//
+// test_message
+fn test_message() {
+    let opt_vec   = vec![
+        ["compare_change",  "true"],
+        ["compare_message", "x[0] < x[1] changed"],
+    ];
+    let f         = record_f( vec![ V::from(1.0), V::from(2.0) ] );
+    let x         = vec![ V::from(3.0), V::from(2.0) ];
+    f.forward_var_value(None, x, &opt_vec);
+    assert_eq!(
+        pop_all_messages(),
+        vec![ "compare_change: var operator 0: x[0] < x[1] changed" ]
+    );
+}
+//
+// test_dynamic
+fn test_dynamic() {
+    let no_opt : Vec<[&str; 2]> = Vec::new();
+    let opt_vec   = vec![ ["compare_change", "true"] ];
+    let p         = vec![ V::from(1.0) ];
+    let x         = vec![ V::from(2.0) ];
+    let (ap, ax)  = start_recording( Some(p), x.clone() );
+    let ap_eq     = (&ap[0]).num_eq( &V::from(1.0) );
+    let ay        = &ap_eq * &ax[0];
+    let f         = stop_recording( vec![ay] );
+    //
+    let p         = vec![ V::from(1.0) ];
+    let dyp_all   = f.forward_dyp_value(p, &no_opt);
+    f.forward_var_value( Some(&dyp_all), x.clone(), &opt_vec);
+    assert!( pop_all_messages().is_empty() );
+    //
+    let p         = vec![ V::from(2.0) ];
+    let dyp_all   = f.forward_dyp_value(p, &no_opt);
+    let (y, _)    = f.forward_var_value( Some(&dyp_all), x, &opt_vec);
+    assert_eq!( y[0], V::from(0.0) );
+    let message   = pop_all_messages();
+    assert_eq!( message.len(), 1 );
+    assert!( message[0].starts_with("compare_change: dyp operator 0:") );
+}
+//
+// test_ad
+fn test_ad() {
+    let opt_vec   = vec![ ["compare_change", "true"] ];
+    let f         = record_f( vec![ V::from(1.0), V::from(2.0) ] );
+    let x         = vec![ V::from(3.0), V::from(2.0) ];
+    let (_, ax)   = start_recording(None, x);
+    let (ay, _)   = f.forward_var_ad(None, ax, &opt_vec);
+    let g         = stop_recording(ay);
+    assert_eq!( pop_all_messages().len(), 1 );
+    //
+    // g records the comparisons at the new point
+    let x         = vec![ V::from(3.0), V::from(2.0) ];
+    g.forward_var_value(None, x, &opt_vec);
+    assert!( pop_all_messages().is_empty() );
+}
+//
+// test_num_vec
+// A comparison changes if any element of its result changes.
+fn test_num_vec() {
+    type S = AzFloat<f64>;
+    type W = NumVec<S>;
+    let opt_vec   = vec![ ["compare_change", "true"] ];
+    let x0        = W::new( vec![ S::from(1.0), S::from(3.0) ] );
+    let (_, ax)   = start_recording(None, vec![ x0 ] );
+    let agt       = (&ax[0]).num_gt( &W::from( S::from(2.0) ) );
+    let ay        = &ax[0] * &agt;
+    let f         = stop_recording( vec![ay] );
+    //
+    let x0        = W::new( vec![ S::from(0.0), S::from(4.0) ] );
+    f.forward_var_value(None, vec![x0], &opt_vec);
+    assert!( pop_all_messages().is_empty() );
+    //
+    let x0        = W::new( vec![ S::from(0.0), S::from(1.0) ] );
+    f.forward_var_value(None, vec![x0], &opt_vec);
+    assert_eq!( pop_all_messages().len(), 1 );
+}
+//
+// test_optimize
+// Comparisons that are used are not removed by optimize.
+fn test_optimize() {
+    let opt_vec   = vec![ ["compare_change", "true"] ];
+    let mut f     = record_f( vec![ V::from(1.0), V::from(2.0) ] );
+    f.optimize( &Vec::new() );
+    let x         = vec![ V::from(3.0), V::from(1.0) ];
+    f.forward_var_value(None, x, &opt_vec);
+    assert_eq!( pop_all_messages().len(), 2 );
+    //
+    let text         = f.to_text();
+    let g : ADfn<V>  = ADfn::from_text(&text).unwrap();
+    assert_eq!( g.to_text(), text );
+}
+//
+#[test]
+fn compare_change() {
+    test_value();
+    test_message();
+    test_dynamic();
+    test_ad();
+    test_num_vec();
+    test_optimize();
+}
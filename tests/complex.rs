@@ -0,0 +1,124 @@
+// SPDX-License-Identifier: EPL-2.0 OR GPL-2.0-or-later
+// SPDX-FileCopyrightText: Bradley M. Bell <bradbell@seanet.com>
+// SPDX-FileContributor: 2026 Bradley M. Bell
+//
+use rustad::{
+    ADfn,
+    AzFloat,
+    Complex,
+    FConst,
+    FUnary,
+    FBinary,
+    FValue,
+    nearly_eq,
+    start_recording,
+    stop_recording,
+};
+//
+// S, V
+type S = AzFloat<f64>;
+type V = Complex<S>;
+//
+// test_unary
+// Check some identities for the complex unary functions.
+fn test_unary() {
+    let opt_vec : Vec<[&str; 2]> = Vec::new();
+    let one      = V::one();
+    let z        = V::new( S::from(0.3), S::from(-0.4) );
+    //
+    assert!( nearly_eq::<V>( &z.ln().exp(), &z, &opt_vec ) );
+    assert!( nearly_eq::<V>( &z.sqrt().square(), &z, &opt_vec ) );
+    assert!( nearly_eq::<V>( &z.cbrt().powi(3), &z, &opt_vec ) );
+    assert!( nearly_eq::<V>( &(z.powi(-3) * z.powi(3)), &one, &opt_vec ) );
+    assert!( nearly_eq::<V>( &z.sin().asin(), &z, &opt_vec ) );
+    assert!( nearly_eq::<V>( &z.cos().acos(), &z, &opt_vec ) );
+    assert!( nearly_eq::<V>( &z.tan().atan(), &z, &opt_vec ) );
+    assert!( nearly_eq::<V>( &z.sinh().asinh(), &z, &opt_vec ) );
+    assert!( nearly_eq::<V>( &z.tanh().atanh(), &z, &opt_vec ) );
+    assert!( nearly_eq::<V>( &z.exp2().log2(), &z, &opt_vec ) );
+    assert!( nearly_eq::<V>( &z.exp_m1().ln_1p(), &z, &opt_vec ) );
+    //
+    let w        = V::new( S::from(1.3), S::from(0.4) );
+    assert!( nearly_eq::<V>( &w.cosh().acosh(), &w, &opt_vec ) );
+    //
+    let sum      = z.sin().square() + z.cos().square();
+    assert!( nearly_eq::<V>( &sum, &one, &opt_vec ) );
+    //
+    // abs, signum
+    let z        = V::new( S::from(3.0), S::from(4.0) );
+    assert_eq!( z.abs(), V::from(5.0) );
+    assert_eq!( z.signum(), V::new( S::from(0.6), S::from(0.8) ) );
+    assert!( z.erf().is_nan() );
+}
+//
+// test_binary
+fn test_binary() {
+    let opt_vec : Vec<[&str; 2]> = Vec::new();
+    let x        = V::new( S::from(1.0), S::from(2.0) );
+    let y        = V::new( S::from(0.5), S::from(-1.0) );
+    //
+    let check    = ( y * x.ln() ).exp();
+    assert!( nearly_eq::<V>( &x.powf(y), &check, &opt_vec ) );
+    //
+    assert!( x.num_eq(x).is_one() );
+    assert!( x.num_ne(y).is_one() );
+    assert!( x.num_lt(y).is_nan() );
+    //
+    // real numbers are ordered
+    let two      = V::from(2.0);
+    let three    = V::from(3.0);
+    assert!( two.num_lt(three).is_one() );
+    assert_eq!( two.max(three), three );
+}
+//
+// test_derivative
+// f(z) = sin(z) * exp(z) , f'(z) = ( cos(z) + sin(z) ) * exp(z)
+fn test_derivative() {
+    let opt_vec : Vec<[&str; 2]> = Vec::new();
+    let z        = V::new( S::from(0.5), S::from(1.5) );
+    let (_, az)  = start_recording(None, vec![z] );
+    let ay       = &(&az[0]).sin() * &(&az[0]).exp();
+    let f        = stop_recording( vec![ay] );
+    //
+    let (y, v)   = f.forward_var_value(None, vec![z], &opt_vec);
+    let check    = z.sin() * z.exp();
+    assert!( nearly_eq::<V>( &y[0], &check, &opt_vec ) );
+    //
+    // forward_der
+    let dz       = V::new( S::from(0.0), S::from(1.0) );
+    let dy       = f.forward_der_value(None, &v, vec![dz], &opt_vec);
+    let check    = (z.cos() + z.sin()) * z.exp() * dz;
+    assert!( nearly_eq::<V>( &dy[0], &check, &opt_vec ) );
+    //
+    // reverse_der
+    let dy       = vec![ V::one() ];
+    let dz       = f.reverse_der_value(None, &v, dy, &opt_vec);
+    let check    = (z.cos() + z.sin()) * z.exp();
+    assert!( nearly_eq::<V>( &dz[0], &check, &opt_vec ) );
+}
+//
+// test_to_text
+fn test_to_text() {
+    let z        = V::new( S::from(0.5), S::from(1.5) );
+    let (_, az)  = start_recording(None, vec![z] );
+    let c        = V::new( S::from(2.0), S::from(-1.0) );
+    let ay       = &az[0] * &c;
+    let f        = stop_recording( vec![ay] );
+    //
+    let text         = f.to_text();
+    assert!( text.contains("( 2.0, -1.0 )") );
+    let g : ADfn<V>  = ADfn::from_text(&text).unwrap();
+    assert_eq!( g.to_text(), text );
+    //
+    let bytes        = f.to_bytes();
+    let g : ADfn<V>  = ADfn::from_bytes(&bytes).unwrap();
+    assert_eq!( g.to_bytes(), bytes );
+}
+//
+#[test]
+fn complex() {
+    test_unary();
+    test_binary();
+    test_derivative();
+    test_to_text();
+}
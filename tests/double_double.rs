@@ -0,0 +1,241 @@
+// SPDX-License-Identifier: EPL-2.0 OR GPL-2.0-or-later
+// SPDX-FileCopyrightText: Bradley M. Bell <bradbell@seanet.com>
+// SPDX-FileContributor: 2026 Bradley M. Bell
+#![allow(clippy::needless_range_loop)]
+//
+// Test the DoubleDouble and AzFloat<DoubleDouble> value types.
+//
+use rustad::{
+    AzFloat,
+    DoubleDouble,
+    FConst,
+    FUnary,
+    start_recording,
+    stop_recording,
+    nearly_eq,
+    get_lib,
+    RustSrcLink,
+    get_rust_src_fn,
+    create_src_dir,
+};
+//
+// D
+type D = DoubleDouble;
+//
+// V
+type V = AzFloat<D>;
+//
+// test_epsilon
+// nearly_eq uses the DoubleDouble epsilon
+fn test_epsilon() {
+    let opt_vec  = vec![ ["assert", "false"] ];
+    let one      = D::one();
+    let x        = one + D::epsilon() * 10.0;
+    assert!( x != one );
+    assert!( nearly_eq::<D>(&x, &one, &opt_vec) );
+    //
+    // 1e-20 is much larger than epsilon
+    let x        = one + D::from(1e-20);
+    assert!( ! nearly_eq::<D>(&x, &one, &opt_vec) );
+    //
+    // min_positive
+    let tiny     = D::min_positive();
+    assert!( nearly_eq::<D>(&tiny, &(tiny * 3.0), &opt_vec) );
+}
+//
+// test_functions
+// check some identities that hold to DoubleDouble precision
+fn test_functions() {
+    let opt_vec : Vec<[&str; 2]> = Vec::new();
+    let one      = D::one();
+    let two      = D::from(2.0);
+    let pi       = D::pi();
+    //
+    // 1/3 to 32 digits
+    let third    = one / D::from(3.0);
+    let check    = D::new(0.3333333333333333, 1.850371707708594e-17);
+    assert!( nearly_eq::<D>(&third, &check, &opt_vec) );
+    //
+    for x in [ third, D::from(0.75), D::from(2.5), D::new(7.0, 1e-20) ] {
+        //
+        // exp, ln
+        assert!( nearly_eq::<D>( &x.exp().ln(), &x, &opt_vec) );
+        assert!( nearly_eq::<D>( &x.exp_m1().ln_1p(), &x, &opt_vec) );
+        assert!( nearly_eq::<D>( &x.exp2().log2(), &x, &opt_vec) );
+        //
+        // sqrt, cbrt, powi, powf
+        assert!( nearly_eq::<D>( &x.sqrt().square(), &x, &opt_vec) );
+        assert!( nearly_eq::<D>( &x.cbrt().powi(3), &x, &opt_vec) );
+        let y = x.powf( third );
+        assert!( nearly_eq::<D>( &y, &x.cbrt(), &opt_vec) );
+        //
+        // sin, cos, tan, atan
+        let s = x.sin();
+        let c = x.cos();
+        assert!( nearly_eq::<D>( &(s.square() + c.square()), &one, &opt_vec) );
+        assert!( nearly_eq::<D>( &x.tan(), &(s / c), &opt_vec) );
+        //
+        // sinh, cosh, tanh, asinh, acosh, atanh
+        let sh = x.sinh();
+        let ch = x.cosh();
+        let diff = ch.square() - sh.square();
+        assert!( nearly_eq::<D>( &diff, &one, &opt_vec) );
+        assert!( nearly_eq::<D>( &sh.asinh(), &x, &opt_vec) );
+        assert!( nearly_eq::<D>( &ch.acosh(), &x, &opt_vec) );
+        assert!( nearly_eq::<D>( &x.tanh(), &(sh / ch), &opt_vec) );
+        //
+        // erf, erfc
+        let sum = x.erf() + x.erfc();
+        assert!( nearly_eq::<D>( &sum, &one, &opt_vec) );
+        let sum = (-x).erf() + (-x).erfc();
+        assert!( nearly_eq::<D>( &sum, &one, &opt_vec) );
+    }
+    //
+    // atanh
+    let x = third.atanh();
+    assert!( nearly_eq::<D>( &x.tanh(), &third, &opt_vec) );
+    //
+    // pi
+    let x = ( one / two ).asin() * 6.0;
+    assert!( nearly_eq::<D>( &x, &pi, &opt_vec) );
+    let x = one.atan() * 4.0;
+    assert!( nearly_eq::<D>( &x, &pi, &opt_vec) );
+    let x = (-one).atan2(-one) / 3.0 * (-4.0);
+    assert!( nearly_eq::<D>( &x, &pi, &opt_vec) );
+    //
+    // erf(1/2) and erfc(3) to 32 digits
+    let x     = D::from(0.5).erf();
+    let check = D::new(0.5204998778130465, 1.900077467916287e-17);
+    assert!( nearly_eq::<D>( &x, &check, &opt_vec) );
+    let x     = D::from(3.0).erfc();
+    let check = D::new(2.209049699858544e-5, 1.5563377960343457e-22);
+    assert!( nearly_eq::<D>( &x, &check, &opt_vec) );
+    //
+    // floor, ceil, round, trunc
+    let x = D::new(3.0, -1e-20);
+    assert_eq!( x.floor(), D::from(2.0) );
+    assert_eq!( x.ceil(),  D::from(3.0) );
+    assert_eq!( x.round(), D::from(3.0) );
+    assert_eq!( (-x).trunc(), D::from(-2.0) );
+    assert_eq!( D::from(-2.5).round(), D::from(-3.0) );
+    //
+    // min, max, hypot
+    assert_eq!( D::nan().min(one), one );
+    assert_eq!( one.max( D::nan() ), one );
+    let x = D::from(3.0).hypot( D::from(4.0) );
+    assert!( nearly_eq::<D>( &x, &D::from(5.0), &opt_vec) );
+    //
+    // nan, zero
+    assert!( D::from(-1.0).ln().is_nan() );
+    assert!( ( D::zero() * D::nan() ).is_nan() );
+    assert_eq!( V::zero() * V::nan(), V::zero() );
+}
+//
+// record_fn
+// f(x) = [ exp( x[0] ) * sin( x[1] ) , erf( x[0] ) / x[1] ]
+macro_rules! record_fn{ ($T:ty, $x:expr) => { {
+    let (_, ax)  = start_recording::<$T>(None, $x);
+    let ay_0     = &(&ax[0]).exp() * &(&ax[1]).sin();
+    let ay_1     = &(&ax[0]).erf() / &ax[1];
+    stop_recording( vec![ay_0, ay_1] )
+} } }
+//
+// test_derivative
+fn test_derivative() {
+    let opt_vec : Vec<[&str; 2]> = Vec::new();
+    //
+    // D
+    let x        = vec![ D::from(0.5), D::from(1.0) / D::from(3.0) ];
+    let f        = record_fn!( D, x.clone() );
+    let (y, v)   = f.forward_var_value(None, x.clone(), &opt_vec);
+    assert_eq!( y[0], x[0].exp() * x[1].sin() );
+    let dy       = vec![ D::one(), D::zero() ];
+    let dx       = f.reverse_der_value(None, &v, dy, &opt_vec);
+    let check    = x[0].exp() * x[1].cos();
+    assert!( nearly_eq::<D>( &dx[1], &check, &opt_vec) );
+    //
+    // derivative of erf(x) is 2 * exp(-x^2) / sqrt(pi)
+    let dx       = vec![ D::one(), D::zero() ];
+    let dy       = f.forward_der_value(None, &v, dx, &opt_vec);
+    let check    = (-x[0].square()).exp() * 2.0 / D::pi().sqrt() / x[1];
+    assert!( nearly_eq::<D>( &dy[1], &check, &opt_vec) );
+    //
+    // V
+    let x        = vec![ V::from(0.5), V::from(2.0) ];
+    let f        = record_fn!( V, x.clone() );
+    let (_, v)   = f.forward_var_value(None, x.clone(), &opt_vec);
+    let dy       = vec![ V::zero(), V::one() ];
+    let dx       = f.reverse_der_value(None, &v, dy, &opt_vec);
+    let check    = V::from( (-D::from(0.25)).exp() / D::pi().sqrt() );
+    assert!( nearly_eq::<V>( &dx[0], &check, &opt_vec) );
+}
+//
+// test_ill_conditioned
+// Solve a linear system with the Hilbert matrix of order 8 which has
+// condition number about 1.5e10; f64 only gets about 6 correct digits
+// but DoubleDouble gets more than 15.
+fn test_ill_conditioned() {
+    let n          = 8;
+    let mut a      = vec![ vec![ D::zero(); n ]; n ];
+    let mut b      = vec![ D::zero(); n ];
+    for i in 0 .. n {
+        for j in 0 .. n {
+            a[i][j] = D::one() / D::from( (i + j + 1) as f64 );
+            b[i]   += a[i][j];
+        }
+    }
+    // Gaussian elimination (the solution is all ones)
+    for k in 0 .. n {
+        for i in k + 1 .. n {
+            let factor = a[i][k] / a[k][k];
+            for j in k .. n {
+                let a_kj = a[k][j];
+                a[i][j] -= factor * a_kj;
+            }
+            let b_k = b[k];
+            b[i]   -= factor * b_k;
+        }
+    }
+    let mut x = vec![ D::zero(); n ];
+    for i in (0 .. n).rev() {
+        let mut sum = b[i];
+        for j in i + 1 .. n {
+            sum -= a[i][j] * x[j];
+        }
+        x[i] = sum / a[i][i];
+    }
+    for xi in x {
+        assert!( ( xi - D::one() ).abs() < D::from(1e-15) );
+    }
+}
+//
+// test_rust_src
+fn test_rust_src() {
+    let x        = vec![ V::from(0.5), V::from(2.0) ];
+    let f        = record_fn!( V, x.clone() );
+    //
+    let fn_name  = "double_double";
+    let lib_src  = f.rust_src(fn_name);
+    let src_dir  = "tmp/test_double_double_rust_src";
+    create_src_dir(src_dir, &lib_src);
+    let lib_file = "tmp/test_double_double_rust_src.so";
+    let lib      = get_lib(src_dir, lib_file, true);
+    let f_fn : RustSrcLink<V> = get_rust_src_fn(&lib, fn_name);
+    //
+    let opt_vec : Vec<[&str; 2]> = Vec::new();
+    let x        = vec![ V::from(0.25), V::from(1.0) / V::from(3.0) ];
+    let x_ref    = vec![ &x[0], &x[1] ];
+    let y        = f_fn(&vec![], &x_ref).unwrap();
+    let (check, _) = f.forward_var_value(None, x.clone(), &opt_vec);
+    assert_eq!( y, check );
+    assert!( ! y[1].is_nan() );
+}
+//
+#[test]
+fn double_double() {
+    test_epsilon();
+    test_functions();
+    test_derivative();
+    test_ill_conditioned();
+    test_rust_src();
+}
@@ -66,6 +66,181 @@ fn test_abs() {
     }
 }
 //
+// test_acos
+fn test_acos() {
+    type V      = AzFloat<f64>;
+    let opt_vec : Vec<[&str; 2]> = Vec::new();
+    //
+    let x  : Vec<V>  = vec![ V::from(0.5) ];
+    //
+    let (_, ax)      = start_recording(None,  x.clone() );
+    let ay           = vec! [ FUnary::acos( &ax[0] ) ];
+    let f            = stop_recording(ay);
+    //
+    let (y, v)       = f.forward_var_value(None, x.clone(), &opt_vec);
+    assert_eq!( y[0], x[0].acos() );
+    let dx           = vec![ V::from(3.0) ];
+    let dy           = f.forward_der_value(None, &v, dx.clone(), &opt_vec);
+    //
+    let dacos        = V::from(-1.0) / ( V::from(1.0) - x[0] * x[0] ).sqrt();
+    assert!( nearly_eq::<V>( &dy[0], &(dacos * dx[0]), &opt_vec ) );
+    //
+    let dy           = vec![ V::from(4.0) ];
+    let dx           = f.reverse_der_value(None, &v, dy.clone(), &opt_vec);
+    //
+    assert!( nearly_eq::<V>( &dx[0], &(dacos * dy[0]), &opt_vec ) );
+}
+//
+// test_acosh
+fn test_acosh() {
+    type V      = AzFloat<f64>;
+    let opt_vec : Vec<[&str; 2]> = Vec::new();
+    //
+    let x  : Vec<V>  = vec![ V::from(2.0) ];
+    //
+    let (_, ax)      = start_recording(None,  x.clone() );
+    let ay           = vec! [ FUnary::acosh( &ax[0] ) ];
+    let f            = stop_recording(ay);
+    //
+    let (y, v)       = f.forward_var_value(None, x.clone(), &opt_vec);
+    assert_eq!( y[0], x[0].acosh() );
+    let dx           = vec![ V::from(3.0) ];
+    let dy           = f.forward_der_value(None, &v, dx.clone(), &opt_vec);
+    //
+    let dacosh       = V::from(1.0) / ( x[0] * x[0] - V::from(1.0) ).sqrt();
+    assert!( nearly_eq::<V>( &dy[0], &(dacosh * dx[0]), &opt_vec ) );
+    //
+    let dy           = vec![ V::from(4.0) ];
+    let dx           = f.reverse_der_value(None, &v, dy.clone(), &opt_vec);
+    //
+    assert!( nearly_eq::<V>( &dx[0], &(dacosh * dy[0]), &opt_vec ) );
+}
+//
+// test_asin
+fn test_asin() {
+    type V      = AzFloat<f64>;
+    let opt_vec : Vec<[&str; 2]> = Vec::new();
+    //
+    let x  : Vec<V>  = vec![ V::from(0.5) ];
+    //
+    let (_, ax)      = start_recording(None,  x.clone() );
+    let ay           = vec! [ FUnary::asin( &ax[0] ) ];
+    let f            = stop_recording(ay);
+    //
+    let (y, v)       = f.forward_var_value(None, x.clone(), &opt_vec);
+    assert_eq!( y[0], x[0].asin() );
+    let dx           = vec![ V::from(3.0) ];
+    let dy           = f.forward_der_value(None, &v, dx.clone(), &opt_vec);
+    //
+    let dasin        = V::from(1.0) / ( V::from(1.0) - x[0] * x[0] ).sqrt();
+    assert!( nearly_eq::<V>( &dy[0], &(dasin * dx[0]), &opt_vec ) );
+    //
+    let dy           = vec![ V::from(4.0) ];
+    let dx           = f.reverse_der_value(None, &v, dy.clone(), &opt_vec);
+    //
+    assert!( nearly_eq::<V>( &dx[0], &(dasin * dy[0]), &opt_vec ) );
+}
+//
+// test_asinh
+fn test_asinh() {
+    type V      = AzFloat<f64>;
+    let opt_vec : Vec<[&str; 2]> = Vec::new();
+    //
+    let x  : Vec<V>  = vec![ V::from(2.0) ];
+    //
+    let (_, ax)      = start_recording(None,  x.clone() );
+    let ay           = vec! [ FUnary::asinh( &ax[0] ) ];
+    let f            = stop_recording(ay);
+    //
+    let (y, v)       = f.forward_var_value(None, x.clone(), &opt_vec);
+    assert_eq!( y[0], x[0].asinh() );
+    let dx           = vec![ V::from(3.0) ];
+    let dy           = f.forward_der_value(None, &v, dx.clone(), &opt_vec);
+    //
+    let dasinh       = V::from(1.0) / ( x[0] * x[0] + V::from(1.0) ).sqrt();
+    assert!( nearly_eq::<V>( &dy[0], &(dasinh * dx[0]), &opt_vec ) );
+    //
+    let dy           = vec![ V::from(4.0) ];
+    let dx           = f.reverse_der_value(None, &v, dy.clone(), &opt_vec);
+    //
+    assert!( nearly_eq::<V>( &dx[0], &(dasinh * dy[0]), &opt_vec ) );
+}
+//
+// test_atan
+fn test_atan() {
+    type V      = AzFloat<f64>;
+    let opt_vec : Vec<[&str; 2]> = Vec::new();
+    //
+    let x  : Vec<V>  = vec![ V::from(2.0) ];
+    //
+    let (_, ax)      = start_recording(None,  x.clone() );
+    let ay           = vec! [ FUnary::atan( &ax[0] ) ];
+    let f            = stop_recording(ay);
+    //
+    let (y, v)       = f.forward_var_value(None, x.clone(), &opt_vec);
+    assert_eq!( y[0], x[0].atan() );
+    let dx           = vec![ V::from(3.0) ];
+    let dy           = f.forward_der_value(None, &v, dx.clone(), &opt_vec);
+    //
+    let datan        = V::from(1.0) / ( V::from(1.0) + x[0] * x[0] );
+    assert!( nearly_eq::<V>( &dy[0], &(datan * dx[0]), &opt_vec ) );
+    //
+    let dy           = vec![ V::from(4.0) ];
+    let dx           = f.reverse_der_value(None, &v, dy.clone(), &opt_vec);
+    //
+    assert!( nearly_eq::<V>( &dx[0], &(datan * dy[0]), &opt_vec ) );
+}
+//
+// test_atanh
+fn test_atanh() {
+    type V      = AzFloat<f64>;
+    let opt_vec : Vec<[&str; 2]> = Vec::new();
+    //
+    let x  : Vec<V>  = vec![ V::from(0.5) ];
+    //
+    let (_, ax)      = start_recording(None,  x.clone() );
+    let ay           = vec! [ FUnary::atanh( &ax[0] ) ];
+    let f            = stop_recording(ay);
+    //
+    let (y, v)       = f.forward_var_value(None, x.clone(), &opt_vec);
+    assert_eq!( y[0], x[0].atanh() );
+    let dx           = vec![ V::from(3.0) ];
+    let dy           = f.forward_der_value(None, &v, dx.clone(), &opt_vec);
+    //
+    let datanh       = V::from(1.0) / ( V::from(1.0) - x[0] * x[0] );
+    assert!( nearly_eq::<V>( &dy[0], &(datanh * dx[0]), &opt_vec ) );
+    //
+    let dy           = vec![ V::from(4.0) ];
+    let dx           = f.reverse_der_value(None, &v, dy.clone(), &opt_vec);
+    //
+    assert!( nearly_eq::<V>( &dx[0], &(datanh * dy[0]), &opt_vec ) );
+}
+//
+// test_cbrt
+fn test_cbrt() {
+    type V      = AzFloat<f64>;
+    let opt_vec : Vec<[&str; 2]> = Vec::new();
+    //
+    let x  : Vec<V>  = vec![ V::from(8.0) ];
+    //
+    let (_, ax)      = start_recording(None,  x.clone() );
+    let ay           = vec! [ FUnary::cbrt( &ax[0] ) ];
+    let f            = stop_recording(ay);
+    //
+    let (y, v)       = f.forward_var_value(None, x.clone(), &opt_vec);
+    assert_eq!( y[0], x[0].cbrt() );
+    let dx           = vec![ V::from(3.0) ];
+    let dy           = f.forward_der_value(None, &v, dx.clone(), &opt_vec);
+    //
+    let dcbrt        = V::from(1.0) / ( V::from(3.0) * y[0] * y[0] );
+    assert!( nearly_eq::<V>( &dy[0], &(dcbrt * dx[0]), &opt_vec ) );
+    //
+    let dy           = vec![ V::from(4.0) ];
+    let dx           = f.reverse_der_value(None, &v, dy.clone(), &opt_vec);
+    //
+    assert!( nearly_eq::<V>( &dx[0], &(dcbrt * dy[0]), &opt_vec ) );
+}
+//
 // test_cos
 fn test_cos() {
     type V      = AzFloat<f64>;
@@ -114,6 +289,71 @@ fn test_cosh() {
     assert_eq!( dx[0], FUnary::sinh( &x[0] ) * dy[0] );
 }
 //
+// test_erf
+fn test_erf() {
+    type V      = AzFloat<f64>;
+    let opt_vec : Vec<[&str; 2]> = Vec::new();
+    //
+    let x  : Vec<V>  = vec![ V::from(0.5) ];
+    //
+    let (_, ax)      = start_recording(None,  x.clone() );
+    let ay           = vec! [ FUnary::erf( &ax[0] ) ];
+    let f            = stop_recording(ay);
+    //
+    let (y, v)       = f.forward_var_value(None, x.clone(), &opt_vec);
+    let check        = V::from( 0.5204998778130465 );
+    assert!( nearly_eq::<V>( &y[0], &check, &opt_vec ) );
+    let dx           = vec![ V::from(3.0) ];
+    let dy           = f.forward_der_value(None, &v, dx.clone(), &opt_vec);
+    //
+    let two          = V::from(2.0);
+    let derf         = two / V::pi().sqrt() * ( x[0] * x[0] ).minus().exp();
+    assert!( nearly_eq::<V>( &dy[0], &(derf * dx[0]), &opt_vec ) );
+    //
+    let dy           = vec![ V::from(4.0) ];
+    let dx           = f.reverse_der_value(None, &v, dy.clone(), &opt_vec);
+    //
+    assert!( nearly_eq::<V>( &dx[0], &(derf * dy[0]), &opt_vec ) );
+    //
+    // x(t) = x + t , second order coefficient = erf''(x) / 2 = - x * erf'(x)
+    let order         = 2;
+    let dom_taylor    = vec![ V::from(1.0), V::from(0.0) ];
+    let (y_taylor, _) = f.forward_taylor_value(
+        None, &v, order, dom_taylor, &opt_vec
+    );
+    assert!( nearly_eq::<V>( &y_taylor[0], &derf, &opt_vec ) );
+    let check         = ( x[0] * derf ).minus();
+    assert!( nearly_eq::<V>( &y_taylor[1], &check, &opt_vec ) );
+}
+//
+// test_erfc
+fn test_erfc() {
+    type V      = AzFloat<f64>;
+    let opt_vec : Vec<[&str; 2]> = Vec::new();
+    //
+    let x  : Vec<V>  = vec![ V::from(3.0) ];
+    //
+    let (_, ax)      = start_recording(None,  x.clone() );
+    let ay           = vec! [ FUnary::erfc( &ax[0] ) ];
+    let f            = stop_recording(ay);
+    //
+    let (y, v)       = f.forward_var_value(None, x.clone(), &opt_vec);
+    let check        = V::from( 2.209049699858544e-5 );
+    assert!( nearly_eq::<V>( &y[0], &check, &opt_vec ) );
+    let dx           = vec![ V::from(3.0) ];
+    let dy           = f.forward_der_value(None, &v, dx.clone(), &opt_vec);
+    //
+    let two          = V::from(2.0);
+    let derfc        = ( two / V::pi().sqrt() ).minus()
+                     * ( x[0] * x[0] ).minus().exp();
+    assert!( nearly_eq::<V>( &dy[0], &(derfc * dx[0]), &opt_vec ) );
+    //
+    let dy           = vec![ V::from(4.0) ];
+    let dx           = f.reverse_der_value(None, &v, dy.clone(), &opt_vec);
+    //
+    assert!( nearly_eq::<V>( &dx[0], &(derfc * dy[0]), &opt_vec ) );
+}
+//
 // test_exp
 fn test_exp() {
     type V      = AzFloat<f64>;
@@ -137,6 +377,31 @@ fn test_exp() {
     assert_eq!( dx[0], FUnary::exp( &x[0] ) * dy[0] );
 }
 //
+// test_exp2
+fn test_exp2() {
+    type V      = AzFloat<f64>;
+    let opt_vec : Vec<[&str; 2]> = Vec::new();
+    //
+    let x  : Vec<V>  = vec![ V::from(1.5) ];
+    //
+    let (_, ax)      = start_recording(None,  x.clone() );
+    let ay           = vec! [ FUnary::exp2( &ax[0] ) ];
+    let f            = stop_recording(ay);
+    //
+    let (y, v)       = f.forward_var_value(None, x.clone(), &opt_vec);
+    assert_eq!( y[0], x[0].exp2() );
+    let dx           = vec![ V::from(3.0) ];
+    let dy           = f.forward_der_value(None, &v, dx.clone(), &opt_vec);
+    //
+    let dexp2        = V::from(2.0).ln() * y[0];
+    assert!( nearly_eq::<V>( &dy[0], &(dexp2 * dx[0]), &opt_vec ) );
+    //
+    let dy           = vec![ V::from(4.0) ];
+    let dx           = f.reverse_der_value(None, &v, dy.clone(), &opt_vec);
+    //
+    assert!( nearly_eq::<V>( &dx[0], &(dexp2 * dy[0]), &opt_vec ) );
+}
+//
 // test_exp_m1
 fn test_exp_m1() {
     type V      = AzFloat<f32>;
@@ -206,6 +471,56 @@ fn test_ln_1p() {
     assert_eq!( dx[0], dy[0] / ( x[0] + V::from(1.0) ) );
 }
 //
+// test_log10
+fn test_log10() {
+    type V      = AzFloat<f64>;
+    let opt_vec : Vec<[&str; 2]> = Vec::new();
+    //
+    let x  : Vec<V>  = vec![ V::from(5.0) ];
+    //
+    let (_, ax)      = start_recording(None,  x.clone() );
+    let ay           = vec! [ FUnary::log10( &ax[0] ) ];
+    let f            = stop_recording(ay);
+    //
+    let (y, v)       = f.forward_var_value(None, x.clone(), &opt_vec);
+    assert_eq!( y[0], x[0].log10() );
+    let dx           = vec![ V::from(3.0) ];
+    let dy           = f.forward_der_value(None, &v, dx.clone(), &opt_vec);
+    //
+    let dlog10       = V::from(1.0) / ( V::from(10.0).ln() * x[0] );
+    assert!( nearly_eq::<V>( &dy[0], &(dlog10 * dx[0]), &opt_vec ) );
+    //
+    let dy           = vec![ V::from(4.0) ];
+    let dx           = f.reverse_der_value(None, &v, dy.clone(), &opt_vec);
+    //
+    assert!( nearly_eq::<V>( &dx[0], &(dlog10 * dy[0]), &opt_vec ) );
+}
+//
+// test_log2
+fn test_log2() {
+    type V      = AzFloat<f64>;
+    let opt_vec : Vec<[&str; 2]> = Vec::new();
+    //
+    let x  : Vec<V>  = vec![ V::from(5.0) ];
+    //
+    let (_, ax)      = start_recording(None,  x.clone() );
+    let ay           = vec! [ FUnary::log2( &ax[0] ) ];
+    let f            = stop_recording(ay);
+    //
+    let (y, v)       = f.forward_var_value(None, x.clone(), &opt_vec);
+    assert_eq!( y[0], x[0].log2() );
+    let dx           = vec![ V::from(3.0) ];
+    let dy           = f.forward_der_value(None, &v, dx.clone(), &opt_vec);
+    //
+    let dlog2        = V::from(1.0) / ( V::from(2.0).ln() * x[0] );
+    assert!( nearly_eq::<V>( &dy[0], &(dlog2 * dx[0]), &opt_vec ) );
+    //
+    let dy           = vec![ V::from(4.0) ];
+    let dx           = f.reverse_der_value(None, &v, dy.clone(), &opt_vec);
+    //
+    assert!( nearly_eq::<V>( &dx[0], &(dlog2 * dy[0]), &opt_vec ) );
+}
+//
 // test_minus
 fn test_minus() {
     type V      = AzFloat<f64>;
@@ -401,12 +716,24 @@ fn test_tanh() {
 #[test]
 fn f_unary() {
     test_abs();
+    test_acos();
+    test_acosh();
+    test_asin();
+    test_asinh();
+    test_atan();
+    test_atanh();
+    test_cbrt();
     test_cos();
     test_cosh();
+    test_erf();
+    test_erfc();
     test_exp();
+    test_exp2();
     test_exp_m1();
     test_ln();
     test_ln_1p();
+    test_log10();
+    test_log2();
     test_minus();
     test_signum();
     test_sin();
@@ -0,0 +1,227 @@
+// SPDX-License-Identifier: EPL-2.0 OR GPL-2.0-or-later
+// SPDX-FileCopyrightText: Bradley M. Bell <bradbell@seanet.com>
+// SPDX-FileContributor: 2026 Bradley M. Bell
+//
+use rustad::{
+    AD,
+    ADfn,
+    AzFloat,
+    FConst,
+    FUnary,
+    FBinary,
+    start_recording,
+    stop_recording,
+    nearly_eq,
+};
+//
+// V
+type V = AzFloat<f64>;
+//
+// check_pairs
+// Each even range component, and the following odd range component,
+// are equal functions computed using different operators.
+// Check that their Taylor coefficients are nearly equal.
+fn check_pairs(f : &ADfn<V>, p : Vec<V>, x : Vec<V>, order : usize) {
+    let opt_vec : Vec<[&str; 2]> = Vec::new();
+    //
+    // dyp_all
+    let dyp_all  = if p.is_empty() {
+        Vec::new()
+    } else {
+        f.forward_dyp_value(p, &opt_vec)
+    };
+    //
+    // x(t) = x + t + t^2 / 2 + t^3 / 3 + ...
+    let n_dom          = x.len();
+    let (_, var_all)   = f.forward_var_value(Some(&dyp_all), x, &opt_vec);
+    let mut dom_taylor = Vec::new();
+    for _j in 0 .. n_dom {
+        for k in 1 ..= order {
+            dom_taylor.push( V::from(1.0) / V::from(k as f64) );
+        }
+    }
+    //
+    // rng_taylor
+    let (rng_taylor, var_taylor) = f.forward_taylor_value(
+        Some(&dyp_all), &var_all, order, dom_taylor, &opt_vec
+    );
+    assert_eq!( var_taylor.len(), var_all.len() * (order + 1) );
+    //
+    let n_rng = rng_taylor.len() / order;
+    assert_eq!( n_rng % 2, 0 );
+    for i in 0 .. n_rng / 2 {
+        for k in 0 .. order {
+            let lhs = &rng_taylor[ (2 * i) * order + k ];
+            let rhs = &rng_taylor[ (2 * i + 1) * order + k ];
+            assert!( nearly_eq::<V>(lhs, rhs, &opt_vec) );
+        }
+    }
+}
+//
+// test_exp
+fn test_exp() {
+    let opt_vec : Vec<[&str; 2]> = Vec::new();
+    //
+    // f
+    let x  : Vec<V>  = vec![ V::from(0.0) ];
+    let (_, ax)      = start_recording(None,  x.clone() );
+    let ay           = vec![ FUnary::exp( &ax[0] ) ];
+    let f            = stop_recording(ay);
+    //
+    // x(t) = t , y(t) = exp(t) = sum_k t^k / k!
+    let order        = 5;
+    let (_, v)       = f.forward_var_value(None, x.clone(), &opt_vec);
+    let dom_taylor   = vec![
+        V::from(1.0), V::from(0.0), V::from(0.0), V::from(0.0), V::from(0.0)
+    ];
+    let (y_taylor, _) = f.forward_taylor_value(
+        None, &v, order, dom_taylor, &opt_vec
+    );
+    let mut factorial = V::from(1.0);
+    for k in 1 ..= order {
+        factorial *= V::from(k as f64);
+        let check = V::from(1.0) / factorial;
+        assert!( nearly_eq::<V>( &y_taylor[k-1], &check, &opt_vec ) );
+    }
+}
+//
+// test_mul_div
+fn test_mul_div() {
+    let opt_vec : Vec<[&str; 2]> = Vec::new();
+    //
+    // f
+    let x  : Vec<V>  = vec![ V::from(1.0), V::from(1.0) ];
+    let (_, ax)      = start_recording(None,  x.clone() );
+    let ay           = vec![ &ax[0] * &ax[1], &ax[0] / &ax[1] ];
+    let f            = stop_recording(ay);
+    //
+    // x_0(t) = 2 + t , x_1(t) = 1 - t
+    // y_0(t) = 2 - t - t^2
+    // y_1(t) = (2 + t) * ( 1 + t + t^2 + ... ) = 2 + 3 t + 3 t^2 + ...
+    let order        = 3;
+    let x  : Vec<V>  = vec![ V::from(2.0), V::from(1.0) ];
+    let (_, v)       = f.forward_var_value(None, x, &opt_vec);
+    let dom_taylor   = vec![
+        V::from(1.0),  V::from(0.0), V::from(0.0),
+        V::from(-1.0), V::from(0.0), V::from(0.0),
+    ];
+    let (y_taylor, _) = f.forward_taylor_value(
+        None, &v, order, dom_taylor, &opt_vec
+    );
+    assert_eq!( y_taylor[0], V::from(-1.0) );
+    assert_eq!( y_taylor[1], V::from(-1.0) );
+    assert_eq!( y_taylor[2], V::from(0.0) );
+    assert_eq!( y_taylor[3], V::from(3.0) );
+    assert_eq!( y_taylor[4], V::from(3.0) );
+    assert_eq!( y_taylor[5], V::from(3.0) );
+}
+//
+// test_unary
+fn test_unary() {
+    let half         = V::from(0.5);
+    let one          = V::from(1.0);
+    let x  : Vec<V>  = vec![ V::from(0.7) ];
+    let (_, ax)      = start_recording(None,  x.clone() );
+    let ax           = &ax[0];
+    let aw           = &one + ax;
+    let ay : Vec< AD<V> > = vec![
+        // asin
+        ax.asin(), ( ax / &( &one - &ax.square() ).sqrt() ).atan(),
+        // acos
+        ax.acos(), &pi_2() - &ax.asin(),
+        // atan
+        ax.atan(), ( ax / &( &one + &ax.square() ).sqrt() ).asin(),
+        // asinh
+        ax.asinh(), ( ax + &( &ax.square() + &one ).sqrt() ).ln(),
+        // acosh
+        (&aw).acosh(), ( &aw + &( &(&aw).square() - &one ).sqrt() ).ln(),
+        // atanh
+        ax.atanh(), &half * &( &( &one + ax ) / &( &one - ax ) ).ln(),
+        // erf
+        ax.erf(), ax.minus().erf().minus(),
+        // erfc
+        ax.erfc(), &one - &ax.erf(),
+        // cbrt
+        ax.cbrt(), ( &ax.ln() / &V::from(3.0) ).exp(),
+        // exp2
+        ax.exp2(), ( ax * &V::from(2.0).ln() ).exp(),
+        // log2
+        ax.log2(), &ax.ln() / &V::from(2.0).ln(),
+        // log10
+        ax.log10(), &ax.ln() / &V::from(10.0).ln(),
+        // tan
+        ax.tan(), &ax.sin() / &ax.cos(),
+        // tanh
+        ax.tanh(), &ax.sinh() / &ax.cosh(),
+        // sinh
+        ax.sinh(), &( &ax.exp() - &ax.minus().exp() ) * &half,
+        // cosh
+        ax.cosh(), &( &ax.exp() + &ax.minus().exp() ) * &half,
+        // cos
+        ax.cos(), ( &pi_2() - ax ).sin(),
+        // exp_m1
+        ax.exp_m1(), &ax.exp() - &one,
+        // ln_1p
+        ax.ln_1p(), ( &one + ax ).ln(),
+        // ln
+        ax.ln(), (&one / ax).ln().minus(),
+        // sqrt
+        ax.sqrt(), ( &half * &ax.ln() ).exp(),
+        // square
+        ax.square(), ax * ax,
+        // abs
+        ax.abs(), ax.clone(),
+        // signum
+        ax.signum(), ax.signum(),
+        // minus
+        ax.minus(), &V::zero() - ax,
+        // powi
+        ax.powi(3), &( ax * ax ) * ax,
+        ax.powi(-2), &one / &( ax * ax ),
+    ];
+    let f            = stop_recording(ay);
+    check_pairs(&f, Vec::new(), x, 4);
+}
+//
+// test_binary
+fn test_binary() {
+    let p  : Vec<V>  = vec![ V::from(1.5) ];
+    let x  : Vec<V>  = vec![ V::from(0.5), V::from(2.0) ];
+    let (ap, ax)     = start_recording( Some(p.clone()),  x.clone() );
+    let ap           = &ap[0];
+    let (ax, ay)     = (&ax[0], &ax[1]);
+    let az : Vec< AD<V> > = vec![
+        // add, sub
+        ax + ap, &( ax - ap ) + &( ap + ap ),
+        // mul, div
+        ap * ax, &ax.square() / &( ax / ap ),
+        // powf
+        ax.powf(ay), ( ay * &ax.ln() ).exp(),
+        ax.powf(ap), ( ap * &ax.ln() ).exp(),
+        ap.powf(ax), ( ax * &ap.ln() ).exp(),
+        // min, max
+        &ax.min(ay) + ay, ax + ay,
+        &ax.max(ap) + ax, ap + ax,
+        ay.max(ax), ay.clone(),
+        // hypot
+        ax.hypot(ay), ( &( ax * ax ) + &( ay * ay ) ).sqrt(),
+        // atan2
+        ( &ax.sin() ).atan2( &ax.cos() ), ax.clone(),
+        ay.atan2(ax), &pi_2() - &ax.atan2(ay),
+    ];
+    let f            = stop_recording(az);
+    check_pairs(&f, p, x, 4);
+}
+//
+// pi_2
+fn pi_2() -> V {
+    V::pi() / V::from(2.0)
+}
+//
+#[test]
+fn forward_taylor() {
+    test_exp();
+    test_mul_div();
+    test_unary();
+    test_binary();
+}
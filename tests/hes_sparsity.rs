@@ -0,0 +1,157 @@
+// SPDX-License-Identifier: EPL-2.0 OR GPL-2.0-or-later
+// SPDX-FileCopyrightText: Bradley M. Bell <bradbell@seanet.com>
+// SPDX-FileContributor: 2026 Bradley M. Bell
+#![allow(clippy::needless_range_loop)]
+//
+use rustad::{
+    AD,
+    ADfn,
+    AzFloat,
+    FUnary,
+    FBinary,
+    SparsityPattern,
+    start_recording,
+    stop_recording,
+};
+//
+// V
+type V = AzFloat<f64>;
+//
+// hessian
+// Use reverse_taylor to compute the Hessian of sum_i w[i] * f_i(x).
+fn hessian(
+    f : &ADfn<V>, p : Vec<V>, x : Vec<V>, w : &[V]
+) -> Vec< Vec<V> > {
+    let opt_vec : Vec<[&str; 2]> = Vec::new();
+    let dyp_all  = if p.is_empty() {
+        Vec::new()
+    } else {
+        f.forward_dyp_value(p, &opt_vec)
+    };
+    let n_dom        = x.len();
+    let (_, var_all) = f.forward_var_value(Some(&dyp_all), x, &opt_vec);
+    let mut result   = Vec::new();
+    for j in 0 .. n_dom {
+        let mut dom_taylor = vec![ V::from(0.0); n_dom ];
+        dom_taylor[j]      = V::from(1.0);
+        let (_, var_taylor) = f.forward_taylor_value(
+            Some(&dyp_all), &var_all, 1, dom_taylor, &opt_vec
+        );
+        let dom_partial = f.reverse_taylor_value(
+            Some(&dyp_all), &var_taylor, 1, w.to_vec(), &opt_vec
+        );
+        let mut column = Vec::new();
+        for i in 0 .. n_dom {
+            column.push( dom_partial[i * 2] );
+        }
+        result.push( column );
+    }
+    result
+}
+//
+// check_pattern
+// Check that pattern is equal to the set of non-zero entries in the Hessian
+// (in row major order).
+fn check_pattern(
+    f : &ADfn<V>, p : Vec<V>, x : Vec<V>, select_rng : &[bool]
+) -> SparsityPattern {
+    let opt_vec : Vec<[&str; 2]> = Vec::new();
+    let pattern   = f.hes_sparsity(select_rng, &opt_vec);
+    let mut w     = Vec::new();
+    for select in select_rng {
+        w.push( V::from( if *select { 1.0 } else { 0.0 } ) );
+    }
+    let n_dom     = x.len();
+    let hes       = hessian(f, p, x, &w);
+    let mut check = Vec::new();
+    for i in 0 .. n_dom {
+        for j in 0 .. n_dom {
+            if hes[j][i] != V::from(0.0) {
+                check.push( [i, j] );
+            }
+        }
+    }
+    assert_eq!( pattern, check );
+    pattern
+}
+//
+// test_binary
+fn test_binary() {
+    let p  : Vec<V>  = vec![ V::from(2.0) ];
+    let x  : Vec<V>  = vec![ V::from(0.5), V::from(1.5), V::from(2.5) ];
+    let (ap, ax)     = start_recording( Some(p.clone()), x.clone() );
+    let ap           = &ap[0];
+    let ay : Vec< AD<V> > = vec![
+        &ax[0] + &ax[1],          // 0 : add
+        &ax[0] - ap,              // 1 : sub
+        &ax[0] * ap,              // 2 : mul_vp
+        &ax[0] * &ax[1],          // 3 : mul_vv
+        &ax[2] * &ax[2],          // 4 : mul_vv with same argument
+        &ax[0] / &ax[1],          // 5 : div_vv
+        ap / &ax[2],              // 6 : div_pv
+        (&ax[0]).powf(&ax[1]),    // 7 : powf
+        (&ax[1]).hypot(&ax[1]),   // 8 : hypot with same argument
+        (&ax[0]).atan2(&ax[2]),   // 9 : atan2
+    ];
+    let f = stop_recording(ay);
+    let n_rng = f.rng_len();
+    //
+    // patterns for each range component
+    let mut pattern_vec : Vec<SparsityPattern> = Vec::new();
+    for i in 0 .. n_rng {
+        let mut select_rng = vec![ false; n_rng ];
+        select_rng[i]      = true;
+        let pattern = check_pattern(&f, p.clone(), x.clone(), &select_rng);
+        pattern_vec.push( pattern );
+    }
+    assert!( pattern_vec[0].is_empty() );
+    assert!( pattern_vec[1].is_empty() );
+    assert!( pattern_vec[2].is_empty() );
+    assert_eq!( pattern_vec[3], vec![ [0, 1], [1, 0] ] );
+    assert_eq!( pattern_vec[4], vec![ [2, 2] ] );
+    assert_eq!( pattern_vec[5], vec![ [0, 1], [1, 0], [1, 1] ] );
+    assert_eq!( pattern_vec[6], vec![ [2, 2] ] );
+    assert_eq!( pattern_vec[7], vec![ [0, 0], [0, 1], [1, 0], [1, 1] ] );
+    assert_eq!( pattern_vec[8], vec![ [1, 1] ] );
+    assert_eq!( pattern_vec[9], vec![ [0, 0], [0, 2], [2, 0], [2, 2] ] );
+    //
+    // pattern for all the range components
+    let select_rng = vec![ true; n_rng ];
+    check_pattern(&f, p, x, &select_rng);
+}
+//
+// test_composition
+fn test_composition() {
+    let x  : Vec<V>  = vec![ V::from(0.5), V::from(1.5), V::from(2.5) ];
+    let (_, ax)      = start_recording(None, x.clone() );
+    //
+    // y_0 = exp( x[0] + 2 * x[1] ) , y_1 = - ( x[1] * x[2] ) , y_2 = constant
+    let two          = V::from(2.0);
+    let asum         = &ax[0] + &( &ax[1] * &two );
+    let ay : Vec< AD<V> > = vec![
+        asum.exp(),
+        ( &ax[1] * &ax[2] ).minus(),
+        AD::from( V::from(3.0) ),
+    ];
+    let f = stop_recording(ay);
+    //
+    let select  = [true, false, true];
+    let pattern = check_pattern(&f, Vec::new(), x.clone(), &select);
+    assert_eq!( pattern, vec![ [0, 0], [0, 1], [1, 0], [1, 1] ] );
+    //
+    let select  = [false, true, true];
+    let pattern = check_pattern(&f, Vec::new(), x.clone(), &select);
+    assert_eq!( pattern, vec![ [1, 2], [2, 1] ] );
+    //
+    let select  = [true, true, false];
+    let pattern = check_pattern(&f, Vec::new(), x, &select);
+    assert_eq!(
+        pattern, vec![ [0, 0], [0, 1], [1, 0], [1, 1], [1, 2], [2, 1] ]
+    );
+}
+//
+#[test]
+fn hes_sparsity() {
+    test_binary();
+    test_composition();
+}
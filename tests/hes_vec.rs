@@ -0,0 +1,95 @@
+// SPDX-License-Identifier: EPL-2.0 OR GPL-2.0-or-later
+// SPDX-FileCopyrightText: Bradley M. Bell <bradbell@seanet.com>
+// SPDX-FileContributor: 2026 Bradley M. Bell
+#![allow(clippy::useless_vec)]
+//
+use rustad::{
+    AD,
+    AzFloat,
+    FUnary,
+    start_recording,
+    stop_recording,
+    nearly_eq,
+};
+//
+// V
+type V = AzFloat<f64>;
+//
+// test_value
+// f(p, x) = [ p[0] * exp( x[0] ) * x[1] , x[1] * x[1] * x[2] ]
+fn test_value() {
+    let opt_vec : Vec<[&str; 2]> = Vec::new();
+    let p : Vec<V>   = vec![ V::from(2.0) ];
+    let x : Vec<V>   = vec![ V::from(0.5), V::from(1.5), V::from(2.5) ];
+    let (ap, ax)     = start_recording( Some(p.clone()), x.clone() );
+    let aexp         = (&ax[0]).exp();
+    let ay_0         = &( &ap[0] * &aexp ) * &ax[1];
+    let ay_1         = &( &ax[1] * &ax[1] ) * &ax[2];
+    let f            = stop_recording( vec![ay_0, ay_1] );
+    //
+    // hes_dir
+    let dyp_all      = f.forward_dyp_value(p.clone(), &opt_vec);
+    let (_, var_all) = f.forward_var_value(Some(&dyp_all), x.clone(), &opt_vec);
+    let w            = vec![ V::from(3.0), V::from(-1.0) ];
+    let u            = vec![ V::from(1.0), V::from(2.0), V::from(-3.0) ];
+    let hes_dir      = f.hes_vec_value(
+        Some(&dyp_all), &var_all, w.clone(), u.clone(), &opt_vec
+    );
+    //
+    // check
+    // s(x) = w[0] * p[0] * exp(x[0]) * x[1] + w[1] * x[1] * x[1] * x[2]
+    let e     = x[0].exp();
+    let h_00  = w[0] * p[0] * e * x[1];
+    let h_01  = w[0] * p[0] * e;
+    let h_11  = V::from(2.0) * w[1] * x[2];
+    let h_12  = V::from(2.0) * w[1] * x[1];
+    let check = [
+        h_00 * u[0] + h_01 * u[1],
+        h_01 * u[0] + h_11 * u[1] + h_12 * u[2],
+        h_12 * u[1],
+    ];
+    assert_eq!( hes_dir.len(), 3 );
+    for j in 0 .. 3 {
+        assert!( nearly_eq::<V>( &hes_dir[j], &check[j], &opt_vec ) );
+    }
+}
+//
+// test_ad
+// Record the Hessian times a vector as a function of x.
+fn test_ad() {
+    let opt_vec : Vec<[&str; 2]> = Vec::new();
+    //
+    // f(x) = sin( x[0] ) * cos( x[1] )
+    let x : Vec<V>   = vec![ V::from(0.5), V::from(1.5) ];
+    let (_, ax)      = start_recording(None, x.clone() );
+    let ay           = &(&ax[0]).sin() * &(&ax[1]).cos();
+    let f            = stop_recording( vec![ay] );
+    //
+    // g(x) = f''(x) * u
+    let u            = vec![ V::from(3.0), V::from(4.0) ];
+    let (_, ax)      = start_recording(None, x.clone() );
+    let (_, avar)    = f.forward_var_ad(None, ax, &opt_vec);
+    let aw           = vec![ AD::from( V::from(1.0) ) ];
+    let au : Vec< AD<V> > = u.iter().map( |u_j| AD::from(*u_j) ).collect();
+    let ahes_dir     = f.hes_vec_ad(None, &avar, aw, au, &opt_vec);
+    let g            = stop_recording(ahes_dir);
+    //
+    // check
+    let x : Vec<V>   = vec![ V::from(1.0), V::from(2.0) ];
+    let (hes_dir, _) = g.forward_var_value(None, x.clone(), &opt_vec);
+    let (s0, c0)     = ( x[0].sin(), x[0].cos() );
+    let (s1, c1)     = ( x[1].sin(), x[1].cos() );
+    let check        = [
+        (s0 * c1).minus() * u[0] - c0 * s1 * u[1],
+        (c0 * s1).minus() * u[0] - s0 * c1 * u[1],
+    ];
+    for j in 0 .. 2 {
+        assert!( nearly_eq::<V>( &hes_dir[j], &check[j], &opt_vec ) );
+    }
+}
+//
+#[test]
+fn hes_vec() {
+    test_value();
+    test_ad();
+}
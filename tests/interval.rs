@@ -0,0 +1,190 @@
+// SPDX-License-Identifier: EPL-2.0 OR GPL-2.0-or-later
+// SPDX-FileCopyrightText: Bradley M. Bell <bradbell@seanet.com>
+// SPDX-FileContributor: 2026 Bradley M. Bell
+#![allow(clippy::op_ref, clippy::useless_vec)]
+//
+use rustad::{
+    AD,
+    ADfn,
+    AzFloat,
+    Interval,
+    CompareOp,
+    FBinary,
+    FUnary,
+    FValue,
+    start_recording,
+    stop_recording,
+};
+//
+// V, I
+type V = AzFloat<f64>;
+type I = Interval<f64>;
+//
+// sample
+// equally spaced points in an interval (including the bounds)
+fn sample(x : &I) -> Vec<f64> {
+    let n = 20;
+    (0 ..= n).map(
+        |k| x.lower + (x.upper - x.lower) * (k as f64) / (n as f64)
+    ).collect()
+}
+//
+// test_unary
+// Check that the unary function enclosures contain sampled values.
+fn test_unary() {
+    type Pair = ( fn(I) -> I, fn(f64) -> f64 );
+    let function : Vec<Pair> = vec![
+        ( |x| x.abs(),    f64::abs    ),
+        ( |x| x.atan(),   f64::atan   ),
+        ( |x| x.cbrt(),   f64::cbrt   ),
+        ( |x| x.cos(),    f64::cos    ),
+        ( |x| x.cosh(),   f64::cosh   ),
+        ( |x| x.exp(),    f64::exp    ),
+        ( |x| x.exp_m1(), f64::exp_m1 ),
+        ( |x| x.sin(),    f64::sin    ),
+        ( |x| x.sinh(),   f64::sinh   ),
+        ( |x| x.square(), |x| x * x   ),
+        ( |x| x.tanh(),   f64::tanh   ),
+        ( |x| x.erf(),    |x| V::from(x).erf().0 ),
+        ( |x| x.powi(3),  |x| x.powi(3) ),
+        ( |x| x.powi(-2), |x| x.powi(-2) ),
+    ];
+    let domain = vec![
+        I::new(-3.0, -0.5), I::new(-1.0, 2.0), I::new(0.5, 7.0),
+    ];
+    for x in domain.iter() {
+        for (f_interval, f_float) in function.iter() {
+            let y = f_interval(*x);
+            for xi in sample(x) {
+                assert!( y.contains( f_float(xi) ), "{y} {xi}" );
+            }
+        }
+    }
+    //
+    // functions with a restricted domain
+    let x = I::new(0.25, 0.75);
+    assert!( x.asin().contains( (0.5f64).asin() ) );
+    assert!( x.acos().contains( (0.5f64).acos() ) );
+    assert!( x.atanh().contains( (0.5f64).atanh() ) );
+    assert!( x.ln().contains( (0.5f64).ln() ) );
+    assert!( x.sqrt().contains( (0.5f64).sqrt() ) );
+    assert!( I::new(-1.0, 1.0).ln().is_nan() );
+    assert!( I::new(-1.0, 1.0).sqrt().is_nan() );
+    assert!( I::new(0.0, 2.0).asin().is_nan() );
+    //
+    // exact results are not widened
+    assert_eq!( I::new(4.0, 9.0).sqrt(), I::new(2.0, 3.0) );
+    assert_eq!( I::new(-2.0, 1.0).powi(2), I::new(0.0, 4.0) );
+    assert_eq!( I::new(-2.0, 1.0).powi(3), I::new(-8.0, 1.0) );
+    assert_eq!( I::new(0.0, 7.0).sin(), I::new(-1.0, 1.0) );
+    //
+    // tan of an interval that contains a pole
+    let y = I::new(1.0, 2.0).tan();
+    assert_eq!( y, I::new(f64::NEG_INFINITY, f64::INFINITY) );
+}
+//
+// test_binary
+fn test_binary() {
+    let x = I::new(1.0, 3.0);
+    let y = I::new(-2.0, 0.5);
+    let z = &x / &I::new(2.0, 4.0);
+    assert_eq!( z, I::new(0.25, 1.5) );
+    //
+    // outward rounding
+    let third = &I::from(1.0) / &I::from(3.0);
+    assert_eq!( third.lower.next_up(), third.upper );
+    assert!( third.contains(1.0 / 3.0) );
+    let tenth = &I::from(0.1) * &I::from(1.0);
+    assert_eq!( tenth, I::from(0.1) );
+    let sum   = &I::from(0.1) + &I::from(0.2);
+    assert_eq!( sum.lower.next_up(), sum.upper );
+    //
+    // comparisons
+    assert_eq!( x.num_gt(y), I::from(1.0) );
+    assert_eq!( x.num_le(y), I::from(0.0) );
+    assert_eq!( x.num_lt( I::new(2.0, 4.0) ), I::new(0.0, 1.0) );
+    assert_eq!( I::from(2.0).num_eq( I::from(2.0) ), I::from(1.0) );
+    //
+    // atan2, hypot, powf
+    for yi in sample(&y) {
+        for xi in sample(&x) {
+            assert!( y.atan2(x).contains( yi.atan2(xi) ) );
+            assert!( y.hypot(x).contains( yi.hypot(xi) ) );
+            assert!( x.powf(y).contains( xi.powf(yi) ) );
+        }
+    }
+    assert!( I::new(-1.0, 2.0).powf( I::from(0.5) ).is_nan() );
+}
+//
+// record_f
+// f(x) = [ x[0] * sin(x[1]) + if x[0] < 1 { exp(x[0]) } else { x[1]^2 } ]
+fn record_f(x : Vec<V>) -> ADfn<V> {
+    let (_, ax)   = start_recording(None, x);
+    let aone      = AD::from( V::from(1.0) );
+    let aexp      = (&ax[0]).exp();
+    let asq       = &ax[1] * &ax[1];
+    let acexp     = AD::select(&ax[0], &aone, CompareOp::Lt, &aexp, &asq);
+    let ay        = &( &ax[0] * &(&ax[1]).sin() ) + &acexp;
+    stop_recording( vec![ay] )
+}
+//
+// test_range
+// Check that range and derivative enclosures contain sampled values.
+fn test_range() {
+    let opt_vec : Vec<[&str; 2]> = Vec::new();
+    let f  = record_f( vec![ V::from(0.5), V::from(2.0) ] );
+    let g  = f.to_interval();
+    //
+    let x  = vec![ I::new(0.0, 2.0), I::new(-1.0, 3.0) ];
+    let (y, v) = g.forward_var_value(None, x.clone(), &opt_vec);
+    //
+    // dy = partial of f w.r.t. x[0]
+    let dx = vec![ I::from(1.0), I::from(0.0) ];
+    let dy = g.forward_der_value(None, &v, dx, &opt_vec);
+    //
+    // dx = gradient of f
+    let dx = g.reverse_der_value(None, &v, vec![ I::from(1.0) ], &opt_vec);
+    //
+    for x0 in sample(&x[0]) {
+        for x1 in sample(&x[1]) {
+            let xv       = vec![ V::from(x0), V::from(x1) ];
+            let (yv, vv) = f.forward_var_value(None, xv, &opt_vec);
+            assert!( y[0].contains( yv[0].0 ) );
+            //
+            let dxv      = vec![ V::from(1.0), V::from(0.0) ];
+            let dyv      = f.forward_der_value(None, &vv, dxv, &opt_vec);
+            assert!( dy[0].contains( dyv[0].0 ) );
+            //
+            let dyv      = vec![ V::from(1.0) ];
+            let dxv      = f.reverse_der_value(None, &vv, dyv, &opt_vec);
+            assert!( dx[0].contains( dxv[0].0 ) );
+            assert!( dx[1].contains( dxv[1].0 ) );
+        }
+    }
+    //
+    // a point box gives a tight enclosure
+    let x      = vec![ I::from(2.0), I::from(0.5) ];
+    let (y, _) = g.forward_var_value(None, x, &opt_vec);
+    let check  = 2.0 * (0.5f64).sin() + 0.25;
+    assert!( y[0].contains(check) );
+    assert!( y[0].upper - y[0].lower < 1e-14 );
+}
+//
+// test_to_text
+fn test_to_text() {
+    let f            = record_f( vec![ V::from(0.5), V::from(2.0) ] );
+    let g            = f.to_interval();
+    let text         = g.to_text();
+    assert!( text.contains("[ 1.0, 1.0 ]") );
+    let h : ADfn<I>  = ADfn::from_text(&text).unwrap();
+    assert_eq!( h.to_text(), text );
+    assert_eq!( h.to_bytes(), g.to_bytes() );
+}
+//
+#[test]
+fn interval() {
+    test_unary();
+    test_binary();
+    test_range();
+    test_to_text();
+}
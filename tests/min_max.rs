@@ -0,0 +1,232 @@
+// SPDX-License-Identifier: EPL-2.0 OR GPL-2.0-or-later
+// SPDX-FileCopyrightText: Bradley M. Bell <bradbell@seanet.com>
+// SPDX-FileContributor: 2026 Bradley M. Bell
+#![allow(clippy::useless_vec)]
+//
+use rustad::{
+    ADfn,
+    AzFloat,
+    NumVec,
+    FBinary,
+    start_recording,
+    stop_recording,
+};
+//
+// V
+type V = AzFloat<f64>;
+//
+// record_f
+// f(x) = [ min(x[0], x[1]), max(x[0], x[1]), max(x[1], x[0]) ]
+fn record_f(x : Vec<V>) -> ADfn<V> {
+    let (_, ax)   = start_recording(None, x);
+    let ay_0      = (&ax[0]).min(&ax[1]);
+    let ay_1      = (&ax[0]).max(&ax[1]);
+    let ay_2      = (&ax[1]).max(&ax[0]);
+    stop_recording( vec![ay_0, ay_1, ay_2] )
+}
+//
+// test_value
+fn test_value() {
+    let opt_vec : Vec<[&str; 2]> = Vec::new();
+    let f = record_f( vec![ V::from(1.0), V::from(2.0) ] );
+    //
+    for (x_0, x_1) in [ (1.0, 2.0), (3.0, 2.0) ] {
+        let x       = vec![ V::from(x_0), V::from(x_1) ];
+        let (y, _)  = f.forward_var_value(None, x.clone(), &opt_vec);
+        assert_eq!( y[0], V::from( f64::min(x_0, x_1) ) );
+        assert_eq!( y[1], V::from( f64::max(x_0, x_1) ) );
+        assert_eq!( y[2], V::from( f64::max(x_0, x_1) ) );
+    }
+    //
+    // if one operand is nan, the other operand is the result
+    let x       = vec![ V::from(f64::NAN), V::from(2.0) ];
+    let (y, _)  = f.forward_var_value(None, x.clone(), &opt_vec);
+    assert_eq!( y, vec![ V::from(2.0); 3 ] );
+}
+//
+// test_parameter
+// f(p, x) = [ min(p[0], x[0]), max(x[0], 2), max(p[0], p[1]) * x[0] ]
+fn test_parameter() {
+    let opt_vec : Vec<[&str; 2]> = Vec::new();
+    let p         = vec![ V::from(1.0), V::from(2.0) ];
+    let x         = vec![ V::from(3.0) ];
+    let (ap, ax)  = start_recording( Some(p), x );
+    let ay_0      = (&ap[0]).min(&ax[0]);
+    let ay_1      = (&ax[0]).max( &V::from(2.0) );
+    let ay_2      = &(&ap[0]).max(&ap[1]) * &ax[0];
+    let f         = stop_recording( vec![ay_0, ay_1, ay_2] );
+    let text      = f.to_text();
+    assert!( text.contains("min_pv") );
+    assert!( text.contains("max_vp") );
+    assert!( text.contains("max_pp") );
+    //
+    for (p_0, x_0) in [ (4.0, 5.0), (5.0, 1.0) ] {
+        let p        = vec![ V::from(p_0), V::from(3.0) ];
+        let x        = vec![ V::from(x_0) ];
+        let dyp_all  = f.forward_dyp_value(p.clone(), &opt_vec);
+        let (y, v)   = f.forward_var_value(Some(&dyp_all), x.clone(), &opt_vec);
+        assert_eq!( y[0], p[0].min(x[0]) );
+        assert_eq!( y[1], x[0].max( V::from(2.0) ) );
+        assert_eq!( y[2], p[0].max(p[1]) * x[0] );
+        //
+        // derivative
+        let dx    = vec![ V::from(1.0) ];
+        let dy    = f.forward_der_value(Some(&dyp_all), &v, dx, &opt_vec);
+        let one   = V::from(1.0);
+        let zero  = V::from(0.0);
+        let check = if x_0 < p_0 { one } else { zero };
+        assert_eq!( dy[0], check );
+        let check = if x_0 > 2.0 { one } else { zero };
+        assert_eq!( dy[1], check );
+        assert_eq!( dy[2], p[0].max(p[1]) );
+    }
+}
+//
+// test_derivative
+fn test_derivative() {
+    let opt_vec : Vec<[&str; 2]> = Vec::new();
+    let f = record_f( vec![ V::from(1.0), V::from(2.0) ] );
+    //
+    // the operands are equal at the last point (a tie)
+    for (x_0, x_1) in [ (1.0, 2.0), (3.0, 2.0), (2.0, 2.0) ] {
+        let x      = vec![ V::from(x_0), V::from(x_1) ];
+        let lt     = x_0 < x_1;
+        let gt     = x_1 < x_0;
+        //
+        // check
+        // At a tie, the derivative of the left operand is used.
+        let one    = V::from(1.0);
+        let zero   = V::from(0.0);
+        let unit   = |b : bool| if b { one } else { zero };
+        let check  = vec![
+            vec![ unit( ! gt ), unit( gt ) ],
+            vec![ unit( ! lt ), unit( lt ) ],
+            vec![ unit( gt ), unit( ! gt ) ],
+        ];
+        //
+        // forward_der_value
+        let (_, v)  = f.forward_var_value(None, x.clone(), &opt_vec);
+        for j in 0 .. 2 {
+            let mut dx = vec![ V::from(0.0); 2 ];
+            dx[j]      = V::from(1.0);
+            let dy     = f.forward_der_value(None, &v, dx, &opt_vec);
+            for i in 0 .. 3 {
+                assert_eq!( dy[i], check[i][j] );
+            }
+        }
+        //
+        // reverse_der_value
+        for i in 0 .. 3 {
+            let mut dy = vec![ V::from(0.0); 3 ];
+            dy[i]      = V::from(1.0);
+            let dx     = f.reverse_der_value(None, &v, dy, &opt_vec);
+            assert_eq!( dx, check[i] );
+        }
+        //
+        // forward_taylor_value
+        // x(t) = x + t * [1, 2]
+        let order        = 2;
+        let dom_taylor   = vec![
+            V::from(1.0), V::from(0.0), V::from(2.0), V::from(0.0)
+        ];
+        let (y_taylor, var_taylor) = f.forward_taylor_value(
+            None, &v, order, dom_taylor, &opt_vec
+        );
+        for i in 0 .. 3 {
+            let dy = check[i][0] + V::from(2.0) * check[i][1];
+            assert_eq!( y_taylor[i * order], dy );
+            assert_eq!( y_taylor[i * order + 1], V::from(0.0) );
+        }
+        //
+        // reverse_taylor_value
+        // partial of y[order] w.r.t. x[order] is the first derivative
+        for i in 0 .. 3 {
+            let mut rng_weight = vec![ V::from(0.0); 3 ];
+            rng_weight[i]      = V::from(1.0);
+            let dom_partial    = f.reverse_taylor_value(
+                None, &var_taylor, order, rng_weight, &opt_vec
+            );
+            for j in 0 .. 2 {
+                assert_eq!( dom_partial[j * (order + 1) + order], check[i][j] );
+            }
+        }
+    }
+}
+//
+// test_num_vec
+// The operators and their derivatives are element wise.
+fn test_num_vec() {
+    type S = AzFloat<f64>;
+    type W = NumVec<S>;
+    let opt_vec : Vec<[&str; 2]> = Vec::new();
+    let x0        = W::new( vec![ S::from(1.0), S::from(3.0), S::from(2.0) ] );
+    let x1        = W::new( vec![ S::from(2.0), S::from(1.0), S::from(2.0) ] );
+    let (_, ax)   = start_recording(None, vec![ x0.clone(), x1.clone() ] );
+    let ay        = (&ax[0]).max(&ax[1]);
+    let f         = stop_recording( vec![ay] );
+    //
+    let (y, v)    = f.forward_var_value(None, vec![x0, x1], &opt_vec);
+    assert_eq!( y[0], W::new( vec![ S::from(2.0), S::from(3.0), S::from(2.0) ] ) );
+    //
+    let dy        = vec![ W::from(1.0) ];
+    let dx        = f.reverse_der_value(None, &v, dy, &opt_vec);
+    assert_eq!( dx[0], W::new( vec![ S::from(0.0), S::from(1.0), S::from(1.0) ] ) );
+    assert_eq!( dx[1], W::new( vec![ S::from(1.0), S::from(0.0), S::from(0.0) ] ) );
+}
+//
+// test_sparsity
+fn test_sparsity() {
+    let opt_vec : Vec<[&str; 2]> = Vec::new();
+    let f = record_f( vec![ V::from(1.0), V::from(2.0) ] );
+    //
+    // for_sparsity
+    let mut pattern = f.for_sparsity(&opt_vec);
+    pattern.sort();
+    let check = vec![ [0, 0], [0, 1], [1, 0], [1, 1], [2, 0], [2, 1] ];
+    assert_eq!( pattern, check );
+    //
+    // hes_sparsity
+    // min and max are piecewise linear
+    let select_rng = vec![ true; 3 ];
+    let pattern    = f.hes_sparsity(&select_rng, &opt_vec);
+    assert!( pattern.is_empty() );
+}
+//
+// test_optimize
+fn test_optimize() {
+    let opt_vec : Vec<[&str; 2]> = Vec::new();
+    let x         = vec![ V::from(1.0), V::from(2.0) ];
+    let (_, ax)   = start_recording(None, x.clone());
+    let ay_0      = (&ax[0]).min(&ax[1]);
+    let ay_1      = (&ax[0]).min(&ax[1]);
+    let ay_2      = (&ax[0]).max(&ax[1]);
+    let mut f     = stop_recording( vec![ay_0, ay_1, ay_2] );
+    assert_eq!( f.var_dep_len(), 3 );
+    f.optimize(&opt_vec);
+    assert_eq!( f.var_dep_len(), 2 );
+    //
+    let (y, _)    = f.forward_var_value(None, x.clone(), &opt_vec);
+    assert_eq!( y, vec![ x[0], x[0], x[1] ] );
+}
+//
+// test_to_text
+fn test_to_text() {
+    let f = record_f( vec![ V::from(1.0), V::from(2.0) ] );
+    let text         = f.to_text();
+    assert!( text.contains("min_vv") );
+    assert!( text.contains("max_vv") );
+    let g : ADfn<V>  = ADfn::from_text(&text).unwrap();
+    assert_eq!( g.to_text(), text );
+    assert_eq!( g.to_bytes(), f.to_bytes() );
+}
+//
+#[test]
+fn min_max() {
+    test_value();
+    test_parameter();
+    test_derivative();
+    test_num_vec();
+    test_sparsity();
+    test_optimize();
+    test_to_text();
+}
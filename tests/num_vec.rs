@@ -0,0 +1,150 @@
+// SPDX-License-Identifier: EPL-2.0 OR GPL-2.0-or-later
+// SPDX-FileCopyrightText: Bradley M. Bell <bradbell@seanet.com>
+// SPDX-FileContributor: 2026 Bradley M. Bell
+#![allow(clippy::needless_range_loop)]
+//
+// Test the element-wise NumVec kernels.
+//
+use rustad::{
+    AzFloat,
+    NumVec,
+    FConst,
+    FUnary,
+    FBinary,
+    FValue,
+};
+use rustad::float::num_vec::LANES;
+//
+// LENGTHS
+// vector lengths that are less than, equal to, and not multiples of LANES
+const LENGTHS : [usize; 6] = [ 2, LANES - 1, LANES, LANES + 1, 3 * LANES, 41 ];
+//
+// test_binary
+// binary operators and FBinary functions agree with the element operations
+fn test_binary() {
+    type S = AzFloat<f64>;
+    for n in LENGTHS {
+        let j_vec : Vec<f64> = (0 .. n).map( |j| j as f64 ).collect();
+        let x_vec : Vec<S> = j_vec.iter().map( |j| S::from(j - 2.0) ).collect();
+        let y_vec : Vec<S> = j_vec.iter().map( |j| S::from(j + 0.5) ).collect();
+        let x     = NumVec::new( x_vec.clone() );
+        let y     = NumVec::new( y_vec.clone() );
+        let c     = NumVec::from( S::from(3.0) );
+        //
+        let sum   = &x + &y;
+        let diff  = &c - &y;
+        let prod  = &x * &c;
+        let quot  = &x / &y;
+        let min   = FBinary::min( &x, &y );
+        let lt    = FBinary::num_lt( &x, &c );
+        let powf  = FBinary::powf( &y, &x );
+        assert_eq!( sum.len(), n );
+        for j in 0 .. n {
+            let (xj, yj, cj) = ( x_vec[j], y_vec[j], S::from(3.0) );
+            assert_eq!( sum.get(j),  xj + yj );
+            assert_eq!( diff.get(j), cj - yj );
+            assert_eq!( prod.get(j), xj * cj );
+            assert_eq!( quot.get(j), xj / yj );
+            assert_eq!( min.get(j),  xj.min(yj) );
+            assert_eq!( lt.get(j),   xj.num_lt(cj) );
+            assert_eq!( powf.get(j), yj.powf(xj) );
+        }
+    }
+}
+//
+// test_unary
+// FUnary functions agree with the element functions
+fn test_unary() {
+    type S = AzFloat<f32>;
+    for n in LENGTHS {
+        let x_vec : Vec<S> = (0 .. n).map(
+            |j| S::from(j as f32 / 4.0)
+        ).collect();
+        let x     = NumVec::new( x_vec.clone() );
+        let sqrt  = (&x).sqrt();
+        let sin   = (&x).sin();
+        let powi  = (&x).powi(3);
+        assert_eq!( sqrt.len(), n );
+        for j in 0 .. n {
+            assert_eq!( sqrt.get(j), x_vec[j].sqrt() );
+            assert_eq!( sin.get(j),  x_vec[j].sin() );
+            assert_eq!( powi.get(j), x_vec[j].powi(3) );
+        }
+    }
+}
+//
+// test_compound
+// a scalar becomes a vector when the right operand is a vector
+fn test_compound() {
+    type S = AzFloat<f64>;
+    for n in LENGTHS {
+        let y_vec : Vec<S> = (0 .. n).map( |j| S::from(j as f64) ).collect();
+        let y     = NumVec::new( y_vec.clone() );
+        let mut z = NumVec::from( S::from(2.0) );
+        z        += &y;
+        z        *= &NumVec::from( S::from(4.0) );
+        z        -= &y;
+        assert_eq!( z.len(), n );
+        for j in 0 .. n {
+            let check = (S::from(2.0) + y_vec[j]) * S::from(4.0) - y_vec[j];
+            assert_eq!( z.get(j), check );
+        }
+    }
+}
+//
+// test_absolute_zero
+// zero times nan is zero for every element (including chunk remainders)
+fn test_absolute_zero() {
+    for n in LENGTHS {
+        // f32
+        type S32   = AzFloat<f32>;
+        let nan    = NumVec::new( vec![ S32::nan(); n ] );
+        let zero   = NumVec::from( S32::zero() );
+        assert!( (&nan * &zero).is_zero() );
+        assert!( (&zero * &nan).is_zero() );
+        let mut z  = nan.clone();
+        z         *= &zero;
+        assert!( z.is_zero() );
+        //
+        // f64
+        type S64   = AzFloat<f64>;
+        let x_vec : Vec<S64> = (0 .. n).map( |j|
+            if j % 2 == 0 { S64::nan() } else { S64::from(j as f64) }
+        ).collect();
+        let y_vec : Vec<S64> = (0 .. n).map( |j|
+            if j % 3 == 0 { S64::zero() } else { S64::nan() }
+        ).collect();
+        let prod   = &NumVec::new( x_vec ) * &NumVec::new( y_vec );
+        for j in 0 .. n {
+            if j % 3 == 0 {
+                assert_eq!( prod.get(j), S64::zero() );
+            } else {
+                assert!( prod.get(j).is_nan() );
+            }
+        }
+    }
+}
+//
+// test_eq
+// equality and debug output only use the elements of the vectors
+fn test_eq() {
+    type S     = AzFloat<f64>;
+    let n      = LANES + 3;
+    let x_vec : Vec<S> = (0 .. n).map( |j| S::from(j as f64) ).collect();
+    let x      = NumVec::new( x_vec.clone() );
+    let y      = &( &x + &NumVec::from( S::from(1.0) ) ) - &NumVec::one();
+    assert_eq!( x, y );
+    assert_eq!( format!("{x:?}"), format!("{y:?}") );
+    assert_eq!( format!("{x:?}").matches(',').count(), n - 1 );
+    let short  = NumVec::new( x_vec[0 .. n - 1].to_vec() );
+    assert_ne!( x, short );
+}
+//
+#[test]
+fn num_vec() {
+    test_binary();
+    test_unary();
+    test_compound();
+    test_absolute_zero();
+    test_eq();
+}
@@ -1,6 +1,12 @@
 // SPDX-License-Identifier: EPL-2.0 OR GPL-2.0-or-later
 // SPDX-FileCopyrightText: Bradley M. Bell <bradbell@seanet.com>
 // SPDX-FileContributor: 2025-26 Bradley M. Bell
+#![allow(
+    clippy::let_and_return,
+    clippy::needless_range_loop,
+    clippy::op_ref,
+    clippy::vec_init_then_push,
+)]
 // ---------------------------------------------------------------------------
 /*
 Test ADfn::optimize
@@ -482,7 +488,50 @@ fn find_first_equal_unary() {
     assert_eq!( f.var_dep_len(), 0 );
 }
 //
+// find_first_equal_log
+fn find_first_equal_log() {
+    //
+    // opt_vec
+    let opt_vec  = vec![ ["trace", "false"] ];
+    //
+    // x, ax
+    let x        = vec![ V::from(8.0) ];
+    let (_, ax)  = start_recording(None, x.clone());
+    //
+    // ay
+    // Optimizer should detect that the log2, exp2, and cbrt pairs are
+    // identical, but that log10 is different from log2.
+    let ay = vec![
+        FUnary::log2( &ax[0] ),
+        FUnary::log2( &ax[0] ),
+        FUnary::log10( &ax[0] ),
+        FUnary::exp2( &ax[0] ),
+        FUnary::exp2( &ax[0] ),
+        FUnary::cbrt( &ax[0] ),
+        FUnary::cbrt( &ax[0] ),
+    ];
+    //
+    // f
+    let mut f  = stop_recording(ay);
+    assert_eq!( f.var_dep_len(), 7 );
+    //
+    // f
+    f.optimize(&opt_vec);
+    assert_eq!( f.var_dep_len(), 4 );
+    //
+    // check f
+    let (y, _y) = f.forward_var_value(None, x.clone(), &opt_vec);
+    assert_eq!( y[0], V::from(3.0) );
+    assert_eq!( y[1], V::from(3.0) );
+    assert_eq!( y[2], FUnary::log10( &x[0] ) );
+    assert_eq!( y[3], V::from(256.0) );
+    assert_eq!( y[4], V::from(256.0) );
+    assert_eq!( y[5], V::from(2.0) );
+    assert_eq!( y[6], V::from(2.0) );
+}
+//
 // find_equal_num_cmp()
+#[allow(clippy::eq_op)]
 fn find_equal_num_cmp() {
     //
     // opt_vec
@@ -601,6 +650,7 @@ fn optimize() {
     find_first_equal_call();
     find_first_equal_binary();
     find_first_equal_unary();
+    find_first_equal_log();
     find_equal_num_cmp();
     an_atom_result_not_used();
 }
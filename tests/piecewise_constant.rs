@@ -0,0 +1,183 @@
+// SPDX-License-Identifier: EPL-2.0 OR GPL-2.0-or-later
+// SPDX-FileCopyrightText: Bradley M. Bell <bradbell@seanet.com>
+// SPDX-FileContributor: 2026 Bradley M. Bell
+#![allow(clippy::needless_range_loop, clippy::useless_vec)]
+//
+// Test the piecewise constant operators floor, ceil, round, and trunc.
+//
+use rustad::{
+    AD,
+    ADfn,
+    AzFloat,
+    NumVec,
+    FUnary,
+    start_recording,
+    stop_recording,
+    pop_this_thread_message,
+};
+//
+// V
+type V = AzFloat<f64>;
+//
+// record_f
+// f(x) = [ floor(x[0]) * x[1], ceil(x[0]), round(x[1]), trunc(x[1]) ]
+fn record_f(x : Vec<V>) -> ADfn<V> {
+    let (_, ax)   = start_recording(None, x);
+    let ay_0      = &(&ax[0]).floor() * &ax[1];
+    let ay_1      = (&ax[0]).ceil();
+    let ay_2      = (&ax[1]).round();
+    let ay_3      = (&ax[1]).trunc();
+    stop_recording( vec![ay_0, ay_1, ay_2, ay_3] )
+}
+//
+// test_value
+fn test_value() {
+    let opt_vec : Vec<[&str; 2]> = Vec::new();
+    let f = record_f( vec![ V::from(1.5), V::from(2.5) ] );
+    //
+    for (x_0, x_1) in [ (1.5, 2.5), (-1.5, -2.5), (3.0, -0.4) ] {
+        let x       = vec![ V::from(x_0), V::from(x_1) ];
+        let (y, _)  = f.forward_var_value(None, x.clone(), &opt_vec);
+        assert_eq!( y[0], V::from( f64::floor(x_0) * x_1 ) );
+        assert_eq!( y[1], V::from( f64::ceil(x_0) ) );
+        assert_eq!( y[2], V::from( f64::round(x_1) ) );
+        assert_eq!( y[3], V::from( f64::trunc(x_1) ) );
+    }
+}
+//
+// test_num_vec
+fn test_num_vec() {
+    type S = AzFloat<f64>;
+    type W = NumVec<S>;
+    let opt_vec : Vec<[&str; 2]> = Vec::new();
+    let x0        = W::new( vec![ S::from(1.5), S::from(-1.5) ] );
+    let (_, ax)   = start_recording(None, vec![ x0.clone() ] );
+    let ay        = vec![ (&ax[0]).floor(), (&ax[0]).round() ];
+    let f         = stop_recording(ay);
+    //
+    let (y, _)    = f.forward_var_value(None, vec![x0], &opt_vec);
+    assert_eq!( y[0], W::new( vec![ S::from(1.0), S::from(-2.0) ] ) );
+    assert_eq!( y[1], W::new( vec![ S::from(2.0), S::from(-2.0) ] ) );
+}
+//
+// test_derivative
+// The derivative of each piecewise constant operator is zero.
+fn test_derivative() {
+    let opt_vec : Vec<[&str; 2]> = Vec::new();
+    let f = record_f( vec![ V::from(1.5), V::from(2.5) ] );
+    //
+    // check
+    let x      = vec![ V::from(1.5), V::from(2.5) ];
+    let zero   = V::from(0.0);
+    let check  = vec![
+        vec![ zero, x[0].floor() ],
+        vec![ zero, zero ],
+        vec![ zero, zero ],
+        vec![ zero, zero ],
+    ];
+    //
+    // forward_der_value
+    let (_, v)  = f.forward_var_value(None, x.clone(), &opt_vec);
+    for j in 0 .. 2 {
+        let mut dx = vec![ V::from(0.0); 2 ];
+        dx[j]      = V::from(1.0);
+        let dy     = f.forward_der_value(None, &v, dx, &opt_vec);
+        for i in 0 .. 4 {
+            assert_eq!( dy[i], check[i][j] );
+        }
+    }
+    //
+    // reverse_der_value
+    for i in 0 .. 4 {
+        let mut dy = vec![ V::from(0.0); 4 ];
+        dy[i]      = V::from(1.0);
+        let dx     = f.reverse_der_value(None, &v, dy, &opt_vec);
+        assert_eq!( dx, check[i] );
+    }
+    //
+    // forward_taylor_value
+    // x(t) = x + t * [1, 1]
+    let order      = 2;
+    let dom_taylor = vec![
+        V::from(1.0), V::from(0.0), V::from(1.0), V::from(0.0)
+    ];
+    let (y_taylor, var_taylor) = f.forward_taylor_value(
+        None, &v, order, dom_taylor, &opt_vec
+    );
+    assert_eq!( y_taylor[0], x[0].floor() );
+    for k in 1 .. 4 * order {
+        assert_eq!( y_taylor[k], V::from(0.0) );
+    }
+    //
+    // reverse_taylor_value
+    let rng_weight  = vec![ V::from(1.0); 4 ];
+    let dom_partial = f.reverse_taylor_value(
+        None, &var_taylor, order, rng_weight, &opt_vec
+    );
+    for j in 0 .. 2 {
+        assert_eq!( dom_partial[j * (order + 1) + order], check[0][j] );
+    }
+}
+//
+// test_sparsity
+// The results depend on the arguments, but their derivatives are zero.
+fn test_sparsity() {
+    let opt_vec : Vec<[&str; 2]> = Vec::new();
+    let f = record_f( vec![ V::from(1.5), V::from(2.5) ] );
+    //
+    // for_sparsity
+    let mut pattern = f.for_sparsity(&opt_vec);
+    pattern.sort();
+    let check = vec![ [0, 0], [0, 1], [1, 0], [2, 1], [3, 1] ];
+    assert_eq!( pattern, check );
+    //
+    // sub_sparsity
+    let (_, mut pattern) = f.sub_sparsity(&opt_vec);
+    pattern.sort();
+    assert_eq!( pattern, check );
+    //
+    // hes_sparsity
+    let select_rng = vec![ false, true, true, true ];
+    let pattern    = f.hes_sparsity(&select_rng, &opt_vec);
+    assert!( pattern.is_empty() );
+}
+//
+// test_value_checked
+fn test_value_checked() {
+    let opt_forward : Vec<[&str; 2]> = Vec::new();
+    let opt_check   = vec![
+        [ "panic",   "false" ],
+        [ "message", "bucket changed" ],
+    ];
+    //
+    // f
+    // The recording depends on the value of floor( x[0] ).
+    let table     = [ V::from(10.0), V::from(20.0) ];
+    let x         = vec![ V::from(1.5) ];
+    let (_, ax)   = start_recording(None, x.clone());
+    let bucket    = (&ax[0]).floor().to_value_checked(&opt_check);
+    let index     = bucket.to_inner() as usize;
+    let ay        = &ax[0] * &AD::from( table[index] );
+    let f         = stop_recording( vec![ay] );
+    //
+    // same bucket
+    let x         = vec![ V::from(1.25) ];
+    let (y, _)    = f.forward_var_value(None, x.clone(), &opt_forward);
+    assert_eq!( y[0], x[0] * table[1] );
+    assert_eq!( pop_this_thread_message(), None );
+    //
+    // different bucket
+    let x         = vec![ V::from(0.5) ];
+    let _         = f.forward_var_value(None, x, &opt_forward);
+    let check     = "forward_var_value: is_one: bucket changed".to_string();
+    assert_eq!( pop_this_thread_message(), Some(check) );
+}
+//
+#[test]
+fn piecewise_constant() {
+    test_value();
+    test_num_vec();
+    test_derivative();
+    test_sparsity();
+    test_value_checked();
+}
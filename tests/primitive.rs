@@ -0,0 +1,290 @@
+// SPDX-License-Identifier: EPL-2.0 OR GPL-2.0-or-later
+// SPDX-FileCopyrightText: Bradley M. Bell <bradbell@seanet.com>
+// SPDX-FileContributor: 2026 Bradley M. Bell
+#![allow(clippy::useless_vec)]
+//
+// Test the f32 and f64 value types (no absolute zero).
+//
+use rustad::{
+    AD,
+    ADfn,
+    AzFloat,
+    CompareOp,
+    FConst,
+    FUnary,
+    FBinary,
+    VecAD,
+    start_recording,
+    stop_recording,
+    pop_this_thread_message,
+    get_lib,
+    RustSrcLink,
+    get_rust_src_fn,
+    create_src_dir,
+};
+//
+// V
+type V = f64;
+//
+// test_absolute_zero
+// Multiplication by the constant zero is recorded for f64 (not for AzFloat).
+fn test_absolute_zero() {
+    let opt_vec : Vec<[&str; 2]> = Vec::new();
+    //
+    // f64
+    let (_, ax)  = start_recording(None, vec![ 2f64 ] );
+    let ay       = vec![ &0f64 * &ax[0], &ax[0] * &0f64, &0f64 / &ax[0] ];
+    let f        = stop_recording(ay);
+    assert_eq!( f.var_dep_len(), 3 );
+    let (y, _)   = f.forward_var_value(None, vec![ f64::NAN ], &opt_vec);
+    assert!( y.iter().all( |yi| yi.is_nan() ) );
+    let (y, _)   = f.forward_var_value(None, vec![ 0f64 ], &opt_vec);
+    assert_eq!( y[0], 0f64 );
+    assert!( y[2].is_nan() );
+    //
+    // AzFloat
+    type A       = AzFloat<f64>;
+    let (_, ax)  = start_recording(None, vec![ A::from(2.0) ] );
+    let ay       = vec![ &A::zero() * &ax[0] ];
+    let f        = stop_recording(ay);
+    assert_eq!( f.var_dep_len(), 0 );
+    let (y, _)   = f.forward_var_value(None, vec![ A::nan() ], &opt_vec);
+    assert_eq!( y[0], A::zero() );
+}
+//
+// test_derivative
+// f(x) = [ x[0] * sin(x[1]) , erf(x[0]) ]
+fn test_derivative() {
+    let opt_vec : Vec<[&str; 2]> = Vec::new();
+    let x        = vec![ 2f64, 0.5f64 ];
+    let (_, ax)  = start_recording(None, x.clone() );
+    let ay_0     = &ax[0] * &(&ax[1]).sin();
+    let ay_1     = (&ax[0]).erf();
+    let f        = stop_recording( vec![ay_0, ay_1] );
+    //
+    let (y, v)   = f.forward_var_value(None, x.clone(), &opt_vec);
+    assert_eq!( y[0], x[0] * x[1].sin() );
+    //
+    let dx       = vec![ 0f64, 1f64 ];
+    let dy       = f.forward_der_value(None, &v, dx, &opt_vec);
+    assert_eq!( dy[0], x[0] * x[1].cos() );
+    assert_eq!( dy[1], 0f64 );
+    //
+    let dy       = vec![ 1f64, 0f64 ];
+    let dx       = f.reverse_der_value(None, &v, dy, &opt_vec);
+    assert_eq!( dx[0], x[1].sin() );
+    assert_eq!( dx[1], x[0] * x[1].cos() );
+    //
+    // f32
+    let (_, ax)  = start_recording(None, vec![ 3f32 ] );
+    let ay       = (&ax[0]).powi(2);
+    let f        = stop_recording( vec![ay] );
+    let (_, v)   = f.forward_var_value(None, vec![ 3f32 ], &opt_vec);
+    let dy       = f.forward_der_value(None, &v, vec![ 1f32 ], &opt_vec);
+    assert_eq!( dy[0], 6f32 );
+}
+//
+// record_load
+// f(x) = [ table[ x[0] ] ] where table = [ x[1], 2 * x[1] ]
+fn record_load(x : Vec<V>) -> ADfn<V> {
+    let (_, ax) = start_recording(None, x);
+    let atwo    = AD::from( 2f64 );
+    let avec    = VecAD::new( vec![ ax[1].clone(), &atwo * &ax[1] ] );
+    let ay      = avec.load( &ax[0] );
+    stop_recording( vec![ay] )
+}
+//
+// test_vec_ad
+fn test_vec_ad() {
+    let opt_vec : Vec<[&str; 2]> = Vec::new();
+    let f = record_load( vec![ 0f64, 3f64 ] );
+    //
+    let (y, _)  = f.forward_var_value(None, vec![ 1f64, 3f64 ], &opt_vec);
+    assert_eq!( y[0], 6f64 );
+    //
+    // an index that is not in the table results in nan
+    let (y, _)  = f.forward_var_value(None, vec![ 1.5f64, 3f64 ], &opt_vec);
+    assert!( y[0].is_nan() );
+}
+//
+// test_untaken
+// A nan or infinity that is not selected does not affect the result.
+// f(x) = [ if x[0] < 1 { x[1] * x[1] } else { inf } ,
+//          if x[0] < 1 { x[1] } else { log(-x[1]) } ,
+//          table[ x[0] ] ] where table = [ 2 * x[1], log(-x[1]) ]
+fn test_untaken() {
+    let opt_vec : Vec<[&str; 2]> = Vec::new();
+    let x        = vec![ 0f64, 2f64 ];
+    let (_, ax)  = start_recording(None, x.clone() );
+    let aone     = AD::from( 1f64 );
+    let ainf     = AD::from( f64::INFINITY );
+    let atwo     = AD::from( 2f64 );
+    let alog     = (&(&ax[1] * &(-1f64))).ln();
+    let asq      = &ax[1] * &ax[1];
+    let ay_0     = AD::select(&ax[0], &aone, CompareOp::Lt, &asq, &ainf);
+    let ay_1     = AD::select(&ax[0], &aone, CompareOp::Lt, &ax[1], &alog);
+    let avec     = VecAD::new( vec![ &atwo * &ax[1], alog ] );
+    let ay_2     = avec.load( &ax[0] );
+    let f        = stop_recording( vec![ay_0, ay_1, ay_2] );
+    //
+    // value
+    let (y, v)   = f.forward_var_value(None, x.clone(), &opt_vec);
+    assert_eq!( y, vec![ 4f64, 2f64, 4f64 ] );
+    let (y, _)   = f.forward_var_value(None, vec![ 1f64, 2f64 ], &opt_vec);
+    assert_eq!( y[0], f64::INFINITY );
+    assert!( y[1].is_nan() && y[2].is_nan() );
+    //
+    // forward_der
+    let dx       = vec![ 0f64, 1f64 ];
+    let dy       = f.forward_der_value(None, &v, dx, &opt_vec);
+    assert_eq!( dy, vec![ 4f64, 1f64, 2f64 ] );
+    //
+    // reverse_der
+    let dy       = vec![ 1f64, 1f64, 1f64 ];
+    let dx       = f.reverse_der_value(None, &v, dy, &opt_vec);
+    assert_eq!( dx, vec![ 0f64, 7f64 ] );
+    //
+    // forward_taylor_value
+    // x(t) = [ 0, 2 + t ]
+    let dom_tay  = vec![ 0f64, 0f64, 1f64, 0f64 ];
+    let (y_tay, _) = f.forward_taylor_value(None, &v, 2, dom_tay, &opt_vec);
+    assert_eq!( y_tay, vec![ 4f64, 1f64, 1f64, 0f64, 2f64, 0f64 ] );
+}
+//
+// test_max_untaken
+// The derivative of the operand that is not selected by max does not
+// affect the result. f(x) = max( x[0], sqrt(x[0] - 1) ) and at x[0] = 1
+// the derivative of sqrt(x[0] - 1) is infinite.
+fn test_max_untaken() {
+    let opt_vec : Vec<[&str; 2]> = Vec::new();
+    let x        = vec![ 1f64 ];
+    let (_, ax)  = start_recording(None, x.clone() );
+    let asqrt    = (&(&ax[0] - &1f64)).sqrt();
+    let ay       = (&ax[0]).max( &asqrt );
+    let f        = stop_recording( vec![ay] );
+    //
+    let (y, v)   = f.forward_var_value(None, x.clone(), &opt_vec);
+    assert_eq!( y, vec![ 1f64 ] );
+    //
+    // forward_der
+    let dy       = f.forward_der_value(None, &v, vec![ 1f64 ], &opt_vec);
+    assert_eq!( dy, vec![ 1f64 ] );
+    //
+    // forward_taylor_value
+    // x(t) = 1 + t
+    let (y_tay, _) = f.forward_taylor_value(None, &v, 1, vec![ 1f64 ], &opt_vec);
+    assert_eq!( y_tay, vec![ 1f64 ] );
+}
+//
+// test_optimize
+fn test_optimize() {
+    let opt_vec : Vec<[&str; 2]> = Vec::new();
+    let x        = vec![ 2f64 ];
+    let (_, ax)  = start_recording(None, x.clone() );
+    let _unused  = (&ax[0]).exp();
+    let ay       = vec![
+        &ax[0] * &0f64,
+        &ax[0] * &0f64,
+        &1f64 / &(&ax[0] * &(-0f64)),
+    ];
+    let mut f    = stop_recording(ay);
+    assert_eq!( f.var_dep_len(), 5 );
+    f.optimize(&opt_vec);
+    //
+    // The multiplications by zero are kept, the duplicate and exp are not.
+    assert_eq!( f.var_dep_len(), 3 );
+    //
+    // -0 and +0 are different constants
+    let (y, _)   = f.forward_var_value(None, vec![ 1f64 ], &opt_vec);
+    assert_eq!( y[0], 0f64 );
+    assert_eq!( y[2], f64::NEG_INFINITY );
+    let (y, _)   = f.forward_var_value(None, vec![ f64::NAN ], &opt_vec);
+    assert!( y.iter().all( |yi| yi.is_nan() ) );
+}
+//
+// test_zero_one
+// The product with zero is a variable, so its is_zero check is recorded.
+fn test_zero_one() {
+    let opt_forward : Vec<[&str; 2]> = Vec::new();
+    let opt_is_zero = vec![ ["panic", "false"], ["message", "nan times zero"] ];
+    let (_, ax)  = start_recording(None, vec![ 2f64 ] );
+    let aprod    = &ax[0] * &0f64;
+    assert!( aprod.is_zero(&opt_is_zero) );
+    let f        = stop_recording( vec![aprod] );
+    //
+    let _        = f.forward_var_value(None, vec![ 3f64 ], &opt_forward);
+    assert_eq!( pop_this_thread_message(), None );
+    let _        = f.forward_var_value(None, vec![ f64::NAN ], &opt_forward);
+    let message  = pop_this_thread_message().unwrap();
+    assert_eq!( message, "forward_var_value: is_zero: nan times zero" );
+}
+//
+// test_to_text
+fn test_to_text() {
+    let (_, ax)  = start_recording(None, vec![ 2f64 ] );
+    let ay       = &(&ax[0] * &(-0f64)) + &0.1f64;
+    let f        = stop_recording( vec![ay] );
+    //
+    let text         = f.to_text();
+    assert!( text.contains("-0.0") );
+    let g : ADfn<V>  = ADfn::from_text(&text).unwrap();
+    assert_eq!( g.to_text(), text );
+    //
+    let bytes        = f.to_bytes();
+    let g : ADfn<V>  = ADfn::from_bytes(&bytes).unwrap();
+    assert_eq!( g.to_bytes(), bytes );
+}
+//
+// test_rust_src
+fn test_rust_src() {
+    let (_, ax)  = start_recording(None, vec![ 0f64, 3f64 ] );
+    let atwo     = AD::from( 2f64 );
+    let avec     = VecAD::new( vec![ ax[1].clone(), &atwo * &ax[1] ] );
+    let aload    = avec.load( &ax[0] );
+    let alog     = (&(&ax[1] * &(-1f64))).ln();
+    let aone     = AD::from( 1f64 );
+    let avec     = VecAD::new( vec![ alog.clone(), ax[1].clone() ] );
+    let aload_2  = avec.load( &(&ax[0] - &aone) );
+    let acexp    = AD::select(&ax[0], &aone, CompareOp::Gt, &ax[1], &alog);
+    let ay       = vec![
+        aload, &ax[1] * &0f64, (&ax[1]).max(&f64::NAN), aload_2, acexp
+    ];
+    let f        = stop_recording(ay);
+    //
+    let fn_name  = "primitive";
+    let lib_src  = f.rust_src(fn_name);
+    let src_dir  = "tmp/test_primitive_rust_src";
+    create_src_dir(src_dir, &lib_src);
+    let lib_file = "tmp/test_primitive_rust_src.so";
+    let lib      = get_lib(src_dir, lib_file, true);
+    let f_fn : RustSrcLink<V> = get_rust_src_fn(&lib, fn_name);
+    //
+    let x        = vec![ 1f64, f64::NAN ];
+    let x_ref    = vec![ &x[0], &x[1] ];
+    let y        = f_fn(&vec![], &x_ref).unwrap();
+    assert!( y.iter().all( |yi| yi.is_nan() ) );
+    //
+    let x        = vec![ 2f64, 3f64 ];
+    let x_ref    = vec![ &x[0], &x[1] ];
+    let y        = f_fn(&vec![], &x_ref).unwrap();
+    assert!( y[0].is_nan() );
+    assert_eq!( y[1], 0f64 );
+    assert_eq!( y[2], 3f64 );
+    //
+    // log(-3) is nan, but it is not selected
+    assert_eq!( y[3], 3f64 );
+    assert_eq!( y[4], 3f64 );
+}
+//
+#[test]
+fn primitive() {
+    test_absolute_zero();
+    test_derivative();
+    test_vec_ad();
+    test_untaken();
+    test_max_untaken();
+    test_optimize();
+    test_zero_one();
+    test_to_text();
+    test_rust_src();
+}
@@ -0,0 +1,195 @@
+// SPDX-License-Identifier: EPL-2.0 OR GPL-2.0-or-later
+// SPDX-FileCopyrightText: Bradley M. Bell <bradbell@seanet.com>
+// SPDX-FileContributor: 2026 Bradley M. Bell
+//
+use std::rc::Rc;
+use std::cell::RefCell;
+use rustad::{
+    AD,
+    ADfn,
+    AzFloat,
+    NumVec,
+    start_recording,
+    stop_recording,
+    set_this_thread_print_sink,
+};
+//
+// V
+type V = AzFloat<f64>;
+//
+// Lines
+type Lines = Rc< RefCell< Vec<String> > >;
+//
+// capture_lines
+// Send the print operator output for this thread to the returned vector.
+fn capture_lines() -> Lines {
+    let lines : Lines = Rc::new( RefCell::new( Vec::new() ) );
+    let lines_clone   = lines.clone();
+    set_this_thread_print_sink( Some( Box::new(
+        move |line : &str| lines_clone.borrow_mut().push( line.to_string() )
+    ) ) );
+    lines
+}
+//
+// record_f
+// f(x) = [ x[0] * x[1] ] and print x[0] * x[1], and x[1] when x[0] > 0
+fn record_f(x : Vec<V>) -> ADfn<V> {
+    let (_, ax)   = start_recording(None, x);
+    let aprod     = &ax[0] * &ax[1];
+    aprod.print_on_forward("prod = ");
+    ax[1].print_on_forward_if("x[1] = ", &ax[0]);
+    stop_recording( vec![aprod] )
+}
+//
+// test_value
+fn test_value() {
+    let opt_vec : Vec<[&str; 2]> = Vec::new();
+    let lines   = capture_lines();
+    let f       = record_f( vec![ V::from(1.0), V::from(2.0) ] );
+    assert!( lines.borrow().is_empty() );
+    //
+    let x       = vec![ V::from(3.0), V::from(4.0) ];
+    let (y, _)  = f.forward_var_value(None, x, &opt_vec);
+    assert_eq!( y[0], V::from(12.0) );
+    assert_eq!( *lines.borrow(), vec![ "prod = 12", "x[1] = 4" ] );
+    //
+    lines.borrow_mut().clear();
+    let x       = vec![ V::from(-3.0), V::from(4.0) ];
+    f.forward_var_value(None, x, &opt_vec);
+    assert_eq!( *lines.borrow(), vec![ "prod = -12" ] );
+    //
+    // derivative calculations do not print
+    lines.borrow_mut().clear();
+    let x       = vec![ V::from(3.0), V::from(4.0) ];
+    let (_, v)  = f.forward_var_value(None, x, &opt_vec);
+    lines.borrow_mut().clear();
+    let dx      = vec![ V::from(1.0), V::from(0.0) ];
+    let dy      = f.forward_der_value(None, &v, dx, &opt_vec);
+    assert_eq!( dy[0], V::from(4.0) );
+    assert!( lines.borrow().is_empty() );
+    //
+    set_this_thread_print_sink(None);
+}
+//
+// test_constant
+// Nothing is recorded when the value does not depend on the domain.
+fn test_constant() {
+    let lines     = capture_lines();
+    let x         = vec![ V::from(1.0) ];
+    let (_, ax)   = start_recording(None, x);
+    let atwo      = AD::from( V::from(2.0) );
+    atwo.print_on_forward("two = ");
+    let f         = stop_recording( vec![ ax[0].clone() ] );
+    assert_eq!( f.var_dep_len(), 0 );
+    assert!( lines.borrow().is_empty() );
+    set_this_thread_print_sink(None);
+}
+//
+// test_dynamic
+// A print operator that only depends on dynamic parameters
+// prints during forward_dyp_value.
+fn test_dynamic() {
+    let opt_vec : Vec<[&str; 2]> = Vec::new();
+    let lines     = capture_lines();
+    let p         = vec![ V::from(1.0) ];
+    let x         = vec![ V::from(2.0) ];
+    let (ap, ax)  = start_recording( Some(p), x.clone() );
+    let asq       = &ap[0] * &ap[0];
+    asq.print_on_forward("p[0]^2 = ");
+    let ay        = &asq * &ax[0];
+    let f         = stop_recording( vec![ay] );
+    //
+    let p         = vec![ V::from(3.0) ];
+    let dyp_all   = f.forward_dyp_value(p, &opt_vec);
+    assert_eq!( *lines.borrow(), vec![ "p[0]^2 = 9" ] );
+    lines.borrow_mut().clear();
+    f.forward_var_value(Some(&dyp_all), x, &opt_vec);
+    assert!( lines.borrow().is_empty() );
+    set_this_thread_print_sink(None);
+}
+//
+// test_num_vec
+// The value is printed if any element of the condition is positive.
+fn test_num_vec() {
+    type S = AzFloat<f64>;
+    type W = NumVec<S>;
+    let opt_vec : Vec<[&str; 2]> = Vec::new();
+    let lines     = capture_lines();
+    let x0        = W::new( vec![ S::from(1.0), S::from(2.0) ] );
+    let (_, ax)   = start_recording(None, vec![ x0 ] );
+    ax[0].print_on_forward_if("x[0] = ", &ax[0]);
+    let f         = stop_recording( vec![ ax[0].clone() ] );
+    //
+    let x0        = W::new( vec![ S::from(-1.0), S::from(2.0) ] );
+    f.forward_var_value(None, vec![x0.clone()], &opt_vec);
+    assert_eq!( *lines.borrow(), vec![ format!("x[0] = {x0}") ] );
+    //
+    lines.borrow_mut().clear();
+    let x0        = W::new( vec![ S::from(-1.0), S::from(0.0) ] );
+    f.forward_var_value(None, vec![x0], &opt_vec);
+    assert!( lines.borrow().is_empty() );
+    set_this_thread_print_sink(None);
+}
+//
+// test_optimize
+// The print operators are not removed by optimize.
+fn test_optimize() {
+    let opt_vec : Vec<[&str; 2]> = Vec::new();
+    let lines     = capture_lines();
+    let mut f     = record_f( vec![ V::from(1.0), V::from(2.0) ] );
+    f.optimize(&opt_vec);
+    assert_eq!( f.var_dep_len(), 3 );
+    //
+    let x         = vec![ V::from(3.0), V::from(4.0) ];
+    f.forward_var_value(None, x, &opt_vec);
+    assert_eq!( *lines.borrow(), vec![ "prod = 12", "x[1] = 4" ] );
+    set_this_thread_print_sink(None);
+}
+//
+// test_reentrant
+// A sink that evaluates print operators; the lines it prints go to
+// standard output and it is the sink again when it returns.
+fn test_reentrant() {
+    let opt_vec : Vec<[&str; 2]> = Vec::new();
+    let x             = vec![ V::from(1.0), V::from(2.0) ];
+    let f             = Rc::new( record_f(x) );
+    let lines : Lines = Rc::new( RefCell::new( Vec::new() ) );
+    let f_clone       = f.clone();
+    let lines_clone   = lines.clone();
+    set_this_thread_print_sink( Some( Box::new( move |line : &str| {
+        lines_clone.borrow_mut().push( line.to_string() );
+        let opt_vec : Vec<[&str; 2]> = Vec::new();
+        let x = vec![ V::from(1.0), V::from(5.0) ];
+        f_clone.forward_var_value(None, x, &opt_vec);
+    } ) ) );
+    //
+    let x = vec![ V::from(3.0), V::from(4.0) ];
+    f.forward_var_value(None, x.clone(), &opt_vec);
+    assert_eq!( *lines.borrow(), vec![ "prod = 12", "x[1] = 4" ] );
+    //
+    lines.borrow_mut().clear();
+    f.forward_var_value(None, x, &opt_vec);
+    assert_eq!( *lines.borrow(), vec![ "prod = 12", "x[1] = 4" ] );
+    set_this_thread_print_sink(None);
+}
+//
+// test_to_text
+fn test_to_text() {
+    let f = record_f( vec![ V::from(1.0), V::from(2.0) ] );
+    let text         = f.to_text();
+    assert!( text.contains("str \"prod = \"") );
+    let g : ADfn<V>  = ADfn::from_text(&text).unwrap();
+    assert_eq!( g.to_text(), text );
+    assert_eq!( g.to_bytes(), f.to_bytes() );
+}
+//
+#[test]
+fn print() {
+    test_value();
+    test_constant();
+    test_dynamic();
+    test_num_vec();
+    test_optimize();
+    test_reentrant();
+    test_to_text();
+}
@@ -0,0 +1,343 @@
+// SPDX-License-Identifier: EPL-2.0 OR GPL-2.0-or-later
+// SPDX-FileCopyrightText: Bradley M. Bell <bradbell@seanet.com>
+// SPDX-FileContributor: 2026 Bradley M. Bell
+//
+// Test the reduction operators reduce_sum, reduce_max, reduce_len
+// and the functions reduce_mean, reduce_dot that are recorded using them.
+//
+use rustad::{
+    AD,
+    ADfn,
+    AzFloat,
+    NumVec,
+    FReduce,
+    FValue,
+    start_recording,
+    stop_recording,
+    get_lib,
+    RustSrcLink,
+    get_rust_src_fn,
+    create_src_dir,
+};
+//
+// S, V
+type S = AzFloat<f64>;
+type V = NumVec<S>;
+//
+// num_vec
+fn num_vec(x : &[f64]) -> V {
+    NumVec::new( x.iter().map( |xj| S::from(*xj) ).collect() )
+}
+//
+// scalar
+fn scalar(x : f64) -> V {
+    V::from( S::from(x) )
+}
+//
+// broadcast_eq
+// A vector of length one is equal to a vector with all its elements equal
+// to the same value.
+fn broadcast_eq(x : &V, y : &V) -> bool {
+    (x - y).is_zero()
+}
+//
+// record_fn
+// f(x) = [ sum(x[0]), max(x[0]), mean(x[1]), dot(x[0], x[1]), len(x[1]) ]
+fn record_fn(x : Vec<V>) -> ADfn<V> {
+    let (_, ax)  = start_recording(None, x);
+    let ay       = vec![
+        (&ax[0]).reduce_sum(),
+        (&ax[0]).reduce_max(),
+        (&ax[1]).reduce_mean(),
+        (&ax[0]).reduce_dot( &ax[1] ),
+        (&ax[1]).reduce_len(),
+    ];
+    stop_recording(ay)
+}
+//
+// test_forward_var
+fn test_forward_var() {
+    let opt_vec : Vec<[&str; 2]> = Vec::new();
+    let x0       = num_vec( &[ 1.0, 5.0, 2.0, 3.0 ] );
+    let x1       = num_vec( &[ 2.0, 4.0, 6.0, 8.0 ] );
+    let f        = record_fn( vec![ x0.clone(), x1.clone() ] );
+    //
+    // each result is a scalar; i.e., has length one
+    let x0       = num_vec( &[ 1.0, 2.0, 3.0, 4.0, 9.0, 6.0, 7.0, 8.0, 0.0 ] );
+    let x1       = num_vec( &[ 1.0; 9 ] );
+    let (y, _)   = f.forward_var_value(None, vec![ x0, x1 ], &opt_vec);
+    assert!( y.iter().all( |yi| yi.len() == 1 ) );
+    assert_eq!( y[0], scalar(40.0) );
+    assert_eq!( y[1], scalar(9.0) );
+    assert_eq!( y[2], scalar(1.0) );
+    assert_eq!( y[3], scalar(40.0) );
+    assert_eq!( y[4], scalar(9.0) );
+}
+//
+// test_derivative
+fn test_derivative() {
+    let opt_vec : Vec<[&str; 2]> = Vec::new();
+    let x0       = num_vec( &[ 1.0, 5.0, 2.0, 3.0 ] );
+    let x1       = num_vec( &[ 2.0, 4.0, 6.0, 8.0 ] );
+    let f        = record_fn( vec![ x0.clone(), x1.clone() ] );
+    let (_, v)   = f.forward_var_value(
+        None, vec![ x0.clone(), x1.clone() ], &opt_vec
+    );
+    //
+    // forward_der
+    let dx0      = num_vec( &[ 1.0, 2.0, 3.0, 4.0 ] );
+    let dx1      = num_vec( &[ 4.0, 0.0, 0.0, 0.0 ] );
+    let dy       = f.forward_der_value(
+        None, &v, vec![ dx0.clone(), dx1.clone() ], &opt_vec
+    );
+    assert_eq!( dy[0], scalar(10.0) );
+    assert_eq!( dy[1], scalar(2.0) );
+    assert_eq!( dy[2], scalar(1.0) );
+    assert_eq!( dy[3], scalar(2.0 + 8.0 + 18.0 + 32.0 + 4.0) );
+    assert_eq!( dy[4], scalar(0.0) );
+    //
+    // forward_der with scalar directions; i.e., the same for all elements
+    let dy       = f.forward_der_value(
+        None, &v, vec![ scalar(1.0), scalar(2.0) ], &opt_vec
+    );
+    assert_eq!( dy[0], scalar(4.0) );
+    assert_eq!( dy[1], scalar(1.0) );
+    assert_eq!( dy[2], scalar(2.0) );
+    assert_eq!( dy[3], scalar(20.0 + 22.0) );
+    //
+    // reverse_der
+    // The partials of sum and mean are the same for all the elements
+    // of their argument.
+    let n_rng    = 5;
+    let check_x0 = [
+        scalar(1.0),
+        num_vec( &[ 0.0, 1.0, 0.0, 0.0 ] ),
+        scalar(0.0),
+        x1.clone(),
+        scalar(0.0),
+    ];
+    let check_x1 = [
+        scalar(0.0),
+        scalar(0.0),
+        scalar(0.25),
+        x0.clone(),
+        scalar(0.0),
+    ];
+    for i in 0 .. n_rng {
+        let mut dy   = vec![ scalar(0.0); n_rng ];
+        dy[i]        = scalar(1.0);
+        let dx       = f.reverse_der_value(None, &v, dy, &opt_vec);
+        assert!( broadcast_eq( &dx[0], &check_x0[i] ) );
+        assert!( broadcast_eq( &dx[1], &check_x1[i] ) );
+    }
+}
+//
+// test_partial_len
+// The partials of sum and mean have the same length as their argument.
+fn test_partial_len() {
+    let opt_vec : Vec<[&str; 2]> = Vec::new();
+    let n        = 5;
+    let x        = num_vec( &[ 1.0, 2.0, f64::NAN, 4.0, 5.0 ] );
+    let (_, ax)  = start_recording(None, vec![ x.clone() ]);
+    let ay       = vec![ (&ax[0]).reduce_sum(), (&ax[0]).reduce_mean() ];
+    let f        = stop_recording(ay);
+    let (_, v)   = f.forward_var_value(None, vec![ x.clone() ], &opt_vec);
+    //
+    // reverse_der
+    let dy       = vec![ scalar(1.0), scalar(0.0) ];
+    let dx       = f.reverse_der_value(None, &v, dy, &opt_vec);
+    assert_eq!( dx[0].len(), n );
+    assert_eq!( dx[0], num_vec( &[ 1.0; 5 ] ) );
+    let dy       = vec![ scalar(0.0), scalar(1.0) ];
+    let dx       = f.reverse_der_value(None, &v, dy, &opt_vec);
+    assert_eq!( dx[0].len(), n );
+    assert_eq!( dx[0], num_vec( &[ 0.2; 5 ] ) );
+    //
+    // reverse_taylor
+    let order    = 1;
+    let dom_tay  = vec![ scalar(0.0) ];
+    let (_, var_tay) = f.forward_taylor_value(
+        None, &v, order, dom_tay, &opt_vec
+    );
+    let rng_weight = vec![ scalar(1.0), scalar(0.0) ];
+    let partial  = f.reverse_taylor_value(
+        None, &var_tay, order, rng_weight, &opt_vec
+    );
+    assert_eq!( partial.len(), order + 1 );
+    assert_eq!( partial[0].len(), n );
+    assert_eq!( partial[1].len(), n );
+    assert_eq!( partial[1], num_vec( &[ 1.0; 5 ] ) );
+}
+//
+// test_max_tie
+// The derivative of max, and its higher order Taylor coefficients, are
+// divided equally between the elements that are equal to the max.
+fn test_max_tie() {
+    let opt_vec : Vec<[&str; 2]> = Vec::new();
+    let x        = num_vec( &[ 1.0, 3.0, 3.0, 2.0 ] );
+    let (_, ax)  = start_recording(None, vec![ x.clone() ]);
+    let ay       = (&ax[0]).reduce_max();
+    let f        = stop_recording( vec![ ay ] );
+    let (y, v)   = f.forward_var_value(None, vec![ x.clone() ], &opt_vec);
+    assert_eq!( y[0], scalar(3.0) );
+    //
+    let dx       = num_vec( &[ 5.0, 1.0, 3.0, 7.0 ] );
+    let dy       = f.forward_der_value(None, &v, vec![ dx ], &opt_vec);
+    assert_eq!( dy[0], scalar(2.0) );
+    //
+    let dx       = f.reverse_der_value(None, &v, vec![ scalar(1.0) ], &opt_vec);
+    assert_eq!( dx[0], num_vec( &[ 0.0, 0.5, 0.5, 0.0 ] ) );
+    //
+    // Taylor coefficients are averaged over the elements equal to the max
+    // (they are not used to break the tie).
+    let dom_tay  = vec![
+        num_vec( &[ 5.0, 1.0, 3.0, 7.0 ] ), num_vec( &[ 0.0, 2.0, 4.0, 0.0 ] )
+    ];
+    let (rng_tay, _) = f.forward_taylor_value(None, &v, 2, dom_tay, &opt_vec);
+    assert_eq!( rng_tay, vec![ scalar(2.0), scalar(3.0) ] );
+    //
+    // nan elements are ignored
+    let x        = num_vec( &[ f64::NAN, 1.0, 2.0 ] );
+    let (y, _)   = f.forward_var_value(None, vec![ x ], &opt_vec);
+    assert_eq!( y[0], scalar(2.0) );
+}
+//
+// test_taylor
+// y(t) = sum( x(t) * x(t) ) where x(t) = x + t * dx
+//      = sum(x * x) + 2 * t * sum(x * dx) + t^2 * sum(dx * dx)
+fn test_taylor() {
+    let opt_vec : Vec<[&str; 2]> = Vec::new();
+    let x        = num_vec( &[ 1.0, 2.0, 3.0 ] );
+    let (_, ax)  = start_recording(None, vec![ x.clone() ]);
+    let ay       = (&ax[0]).reduce_dot( &ax[0] );
+    let f        = stop_recording( vec![ ay ] );
+    let (_, v)   = f.forward_var_value(None, vec![ x.clone() ], &opt_vec);
+    //
+    // forward_taylor
+    let order    = 2;
+    let dx       = num_vec( &[ 3.0, 2.0, 1.0 ] );
+    let dom_tay  = vec![ dx.clone(), scalar(0.0) ];
+    let (rng_tay, var_tay) = f.forward_taylor_value(
+        None, &v, order, dom_tay, &opt_vec
+    );
+    assert_eq!( rng_tay[0], scalar(2.0 * 10.0) );
+    assert_eq!( rng_tay[1], scalar(14.0) );
+    //
+    // reverse_taylor
+    // partial of the second order coefficient w.r.t. the first order
+    // domain coefficients is 2 * dx
+    let partial  = f.reverse_taylor_value(
+        None, &var_tay, order, vec![ scalar(1.0) ], &opt_vec
+    );
+    assert_eq!( partial.len(), order + 1 );
+    assert_eq!( partial[1], num_vec( &[ 6.0, 4.0, 2.0 ] ) );
+    assert_eq!( partial[2], num_vec( &[ 2.0, 4.0, 6.0 ] ) );
+}
+//
+// test_sparsity
+fn test_sparsity() {
+    let opt_vec : Vec<[&str; 2]> = Vec::new();
+    let x        = vec![ num_vec( &[ 1.0, 2.0 ] ); 3 ];
+    let (_, ax)  = start_recording(None, x);
+    let ay       = vec![
+        &(&ax[0]).reduce_sum() + &(&ax[1]).reduce_max(),
+        (&ax[1]).reduce_dot( &ax[2] ),
+    ];
+    let f        = stop_recording(ay);
+    //
+    // jacobian
+    let pattern  = f.for_sparsity(&opt_vec);
+    assert_eq!( pattern, vec![ [0, 0], [0, 1], [1, 1], [1, 2] ] );
+    //
+    // hessian: the reductions are linear
+    let pattern  = f.hes_sparsity(&[true, true], &opt_vec);
+    assert_eq!( pattern, vec![ [1, 2], [2, 1] ] );
+}
+//
+// test_optimize
+fn test_optimize() {
+    let opt_vec : Vec<[&str; 2]> = Vec::new();
+    let x        = num_vec( &[ 1.0, 2.0, 3.0 ] );
+    let (_, ax)  = start_recording(None, vec![ x.clone() ]);
+    let _unused  = (&ax[0]).reduce_max();
+    let ay       = vec![ (&ax[0]).reduce_sum(), (&ax[0]).reduce_sum() ];
+    let mut f    = stop_recording(ay);
+    assert_eq!( f.var_dep_len(), 3 );
+    f.optimize(&opt_vec);
+    assert_eq!( f.var_dep_len(), 1 );
+    let (y, _)   = f.forward_var_value(None, vec![ x ], &opt_vec);
+    assert_eq!( y, vec![ scalar(6.0), scalar(6.0) ] );
+}
+//
+// test_to_text
+fn test_to_text() {
+    let x        = vec![ num_vec( &[ 1.0, 2.0 ] ), num_vec( &[ 3.0, 4.0 ] ) ];
+    let f        = record_fn( x );
+    let text     = f.to_text();
+    assert!( text.contains("reduce_sum") );
+    assert!( text.contains("reduce_max") );
+    assert!( text.contains("reduce_len") );
+    let g : ADfn<V> = ADfn::from_text(&text).unwrap();
+    assert_eq!( g.to_text(), text );
+}
+//
+// test_rust_src
+fn test_rust_src() {
+    let opt_vec : Vec<[&str; 2]> = Vec::new();
+    let x        = vec![ num_vec( &[ 1.0, 2.0 ] ), num_vec( &[ 3.0, 4.0 ] ) ];
+    let f        = record_fn( x );
+    //
+    let fn_name  = "reduce";
+    let lib_src  = f.rust_src(fn_name);
+    let src_dir  = "tmp/test_reduce_rust_src";
+    create_src_dir(src_dir, &lib_src);
+    let lib_file = "tmp/test_reduce_rust_src.so";
+    let lib      = get_lib(src_dir, lib_file, true);
+    let f_fn : RustSrcLink<V> = get_rust_src_fn(&lib, fn_name);
+    //
+    let x        = vec![
+        num_vec( &[ 3.0, 1.0, 4.0, 1.0, 5.0 ] ),
+        num_vec( &[ 9.0, 2.0, 6.0, 5.0, 3.0 ] ),
+    ];
+    let x_ref    = vec![ &x[0], &x[1] ];
+    let y        = f_fn(&vec![], &x_ref).unwrap();
+    let (check, _) = f.forward_var_value(None, x.clone(), &opt_vec);
+    assert_eq!( y, check );
+}
+//
+// test_dynamic
+// reduction of a dynamic parameter
+fn test_dynamic() {
+    let opt_vec : Vec<[&str; 2]> = Vec::new();
+    let p        = num_vec( &[ 1.0, 2.0 ] );
+    let x        = scalar(3.0);
+    let (ap, ax) = start_recording( Some( vec![ p.clone() ] ), vec![ x ] );
+    let asum     = (&ap[0]).reduce_mean();
+    let ay       = &asum * &ax[0];
+    let f        = stop_recording( vec![ ay ] );
+    //
+    let p        = num_vec( &[ 1.0, 2.0, 3.0, 6.0 ] );
+    let dyp_all  = f.forward_dyp_value( vec![ p ], &opt_vec );
+    let (y, _)   = f.forward_var_value(
+        Some(&dyp_all), vec![ scalar(2.0) ], &opt_vec
+    );
+    assert_eq!( y[0], scalar(6.0) );
+    //
+    // AD<V> from a reduction of a constant is a constant
+    let ax       = AD::from( num_vec( &[ 1.0, 2.0 ] ) );
+    assert_eq!( (&ax).reduce_sum().to_value(), scalar(3.0) );
+}
+//
+#[test]
+fn reduce() {
+    test_forward_var();
+    test_derivative();
+    test_partial_len();
+    test_max_tie();
+    test_taylor();
+    test_sparsity();
+    test_optimize();
+    test_to_text();
+    test_rust_src();
+    test_dynamic();
+}
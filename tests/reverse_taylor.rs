@@ -0,0 +1,253 @@
+// SPDX-License-Identifier: EPL-2.0 OR GPL-2.0-or-later
+// SPDX-FileCopyrightText: Bradley M. Bell <bradbell@seanet.com>
+// SPDX-FileContributor: 2026 Bradley M. Bell
+#![allow(clippy::needless_range_loop)]
+//
+use rustad::{
+    AD,
+    ADfn,
+    AzFloat,
+    FConst,
+    FUnary,
+    FBinary,
+    start_recording,
+    stop_recording,
+    nearly_eq,
+};
+//
+// V
+type V = AzFloat<f64>;
+//
+// check_pairs
+// Each even range component, and the following odd range component,
+// are equal functions computed using different operators.
+// Check that the partials of their Taylor coefficients are nearly equal.
+// In addition, check that the partials of the highest order coefficient,
+// w.r.t. the highest order domain coefficients, are equal to the gradient.
+fn check_pairs(f : &ADfn<V>, p : Vec<V>, x : Vec<V>, order : usize) {
+    let opt_vec : Vec<[&str; 2]> = Vec::new();
+    //
+    // dyp_all
+    let dyp_all  = if p.is_empty() {
+        Vec::new()
+    } else {
+        f.forward_dyp_value(p, &opt_vec)
+    };
+    //
+    // x(t) = x + t + t^2 / 2 + t^3 / 3 + ...
+    let n_dom          = x.len();
+    let n_tay          = order + 1;
+    let (y, var_all)   = f.forward_var_value(Some(&dyp_all), x, &opt_vec);
+    let mut dom_taylor = Vec::new();
+    for _j in 0 .. n_dom {
+        for k in 1 ..= order {
+            dom_taylor.push( V::from(1.0) / V::from(k as f64) );
+        }
+    }
+    //
+    // var_taylor
+    let (_, var_taylor) = f.forward_taylor_value(
+        Some(&dyp_all), &var_all, order, dom_taylor, &opt_vec
+    );
+    //
+    // dom_partial
+    let n_rng = y.len();
+    let mut dom_partial : Vec< Vec<V> > = Vec::new();
+    for i in 0 .. n_rng {
+        let mut rng_weight = vec![ V::from(0.0); n_rng ];
+        rng_weight[i]      = V::from(1.0);
+        let partial        = f.reverse_taylor_value(
+            Some(&dyp_all), &var_taylor, order, rng_weight.clone(), &opt_vec
+        );
+        assert_eq!( partial.len(), n_dom * n_tay );
+        //
+        // check highest order partials using reverse_der_value
+        let gradient = f.reverse_der_value(
+            Some(&dyp_all), &var_all, rng_weight, &opt_vec
+        );
+        for j in 0 .. n_dom {
+            let lhs = &partial[j * n_tay + order];
+            assert!( nearly_eq::<V>(lhs, &gradient[j], &opt_vec) );
+        }
+        dom_partial.push( partial );
+    }
+    //
+    // check pairs
+    // Some of the partials are exactly zero for one function in a pair
+    // and only zero to within rounding for the other.
+    assert_eq!( n_rng % 2, 0 );
+    let eps99 = V::from( 99.0 * f64::EPSILON );
+    for i in 0 .. n_rng / 2 {
+        for jk in 0 .. n_dom * n_tay {
+            let lhs   = &dom_partial[2 * i][jk];
+            let rhs   = &dom_partial[2 * i + 1][jk];
+            let scale = V::from(1.0) + lhs.abs();
+            assert!( (lhs - rhs).abs() <= eps99 * scale );
+        }
+    }
+}
+//
+// test_hes_times_vec
+fn test_hes_times_vec() {
+    let opt_vec : Vec<[&str; 2]> = Vec::new();
+    //
+    // f(x) = sin( x[0] ) * x[1]
+    let x  : Vec<V>  = vec![ V::from(1.0), V::from(2.0) ];
+    let (_, ax)      = start_recording(None,  x.clone() );
+    let ay           = vec![ &FUnary::sin( &ax[0] ) * &ax[1] ];
+    let f            = stop_recording(ay);
+    //
+    // dom_partial
+    let order        = 1;
+    let u : Vec<V>   = vec![ V::from(3.0), V::from(4.0) ];
+    let (_, v)       = f.forward_var_value(None, x.clone(), &opt_vec);
+    let (_, v_tay)   = f.forward_taylor_value(
+        None, &v, order, u.clone(), &opt_vec
+    );
+    let w            = vec![ V::from(5.0) ];
+    let dom_partial  = f.reverse_taylor_value(
+        None, &v_tay, order, w, &opt_vec
+    );
+    //
+    // check
+    // f''(x) = [ - sin( x[0] ) * x[1] , cos( x[0] ) ]
+    //          [   cos( x[0] )        ,     0       ]
+    let (s, c)  = ( x[0].sin(), x[0].cos() );
+    let h_u_0   = V::from(5.0) * ( (s * x[1]).minus() * u[0] + c * u[1] );
+    let h_u_1   = V::from(5.0) * ( c * u[0] );
+    assert!( nearly_eq::<V>( &dom_partial[0], &h_u_0, &opt_vec ) );
+    assert!( nearly_eq::<V>( &dom_partial[2], &h_u_1, &opt_vec ) );
+}
+//
+// test_third_derivative
+fn test_third_derivative() {
+    let opt_vec : Vec<[&str; 2]> = Vec::new();
+    //
+    // f(x) = x[0]^4
+    let x  : Vec<V>  = vec![ V::from(2.0) ];
+    let (_, ax)      = start_recording(None,  x.clone() );
+    let ay           = vec![ FUnary::powi( &ax[0], 4 ) ];
+    let f            = stop_recording(ay);
+    //
+    // x(t) = x + t , y_2 = f''(x) / 2 , partial y_2 w.r.t x = f'''(x) / 2
+    let order        = 2;
+    let (_, v)       = f.forward_var_value(None, x.clone(), &opt_vec);
+    let dom_taylor   = vec![ V::from(1.0), V::from(0.0) ];
+    let (_, v_tay)   = f.forward_taylor_value(
+        None, &v, order, dom_taylor, &opt_vec
+    );
+    let dom_partial  = f.reverse_taylor_value(
+        None, &v_tay, order, vec![ V::from(1.0) ], &opt_vec
+    );
+    let check        = V::from(4.0 * 3.0 * 2.0 / 2.0) * x[0];
+    assert_eq!( dom_partial[0], check );
+}
+//
+// test_unary
+fn test_unary() {
+    let half         = V::from(0.5);
+    let one          = V::from(1.0);
+    let x  : Vec<V>  = vec![ V::from(0.7) ];
+    let (_, ax)      = start_recording(None,  x.clone() );
+    let ax           = &ax[0];
+    let aw           = &one + ax;
+    let ay : Vec< AD<V> > = vec![
+        // asin
+        ax.asin(), ( ax / &( &one - &ax.square() ).sqrt() ).atan(),
+        // acos
+        ax.acos(), &pi_2() - &ax.asin(),
+        // atan
+        ax.atan(), ( ax / &( &one + &ax.square() ).sqrt() ).asin(),
+        // asinh
+        ax.asinh(), ( ax + &( &ax.square() + &one ).sqrt() ).ln(),
+        // acosh
+        (&aw).acosh(), ( &aw + &( &(&aw).square() - &one ).sqrt() ).ln(),
+        // atanh
+        ax.atanh(), &half * &( &( &one + ax ) / &( &one - ax ) ).ln(),
+        // erf
+        ax.erf(), ax.minus().erf().minus(),
+        // erfc
+        ax.erfc(), &one - &ax.erf(),
+        // cbrt
+        ax.cbrt(), ( &ax.ln() / &V::from(3.0) ).exp(),
+        // exp2
+        ax.exp2(), ( ax * &V::from(2.0).ln() ).exp(),
+        // log2
+        ax.log2(), &ax.ln() / &V::from(2.0).ln(),
+        // log10
+        ax.log10(), &ax.ln() / &V::from(10.0).ln(),
+        // tan
+        ax.tan(), &ax.sin() / &ax.cos(),
+        // tanh
+        ax.tanh(), &ax.sinh() / &ax.cosh(),
+        // sinh
+        ax.sinh(), &( &ax.exp() - &ax.minus().exp() ) * &half,
+        // cosh
+        ax.cosh(), &( &ax.exp() + &ax.minus().exp() ) * &half,
+        // cos
+        ax.cos(), ( &pi_2() - ax ).sin(),
+        // exp_m1
+        ax.exp_m1(), &ax.exp() - &one,
+        // ln_1p
+        ax.ln_1p(), ( &one + ax ).ln(),
+        // ln
+        ax.ln(), (&one / ax).ln().minus(),
+        // sqrt
+        ax.sqrt(), ( &half * &ax.ln() ).exp(),
+        // square
+        ax.square(), ax * ax,
+        // abs
+        ax.abs(), ax.clone(),
+        // minus
+        ax.minus(), &V::zero() - ax,
+        // powi
+        ax.powi(3), &( ax * ax ) * ax,
+        ax.powi(-2), &one / &( ax * ax ),
+    ];
+    let f            = stop_recording(ay);
+    check_pairs(&f, Vec::new(), x, 3);
+}
+//
+// test_binary
+fn test_binary() {
+    let p  : Vec<V>  = vec![ V::from(1.5) ];
+    let x  : Vec<V>  = vec![ V::from(0.5), V::from(2.0) ];
+    let (ap, ax)     = start_recording( Some(p.clone()),  x.clone() );
+    let ap           = &ap[0];
+    let (ax, ay)     = (&ax[0], &ax[1]);
+    let az : Vec< AD<V> > = vec![
+        // add, sub
+        ax + ap, &( ax - ap ) + &( ap + ap ),
+        // mul, div
+        ap * ax, &ax.square() / &( ax / ap ),
+        ax * ay, &ax.square() / &( ax / ay ),
+        // powf
+        ax.powf(ay), ( ay * &ax.ln() ).exp(),
+        ax.powf(ap), ( ap * &ax.ln() ).exp(),
+        ap.powf(ax), ( ax * &ap.ln() ).exp(),
+        // min, max
+        &ax.min(ay) + ay, ax + ay,
+        &ax.max(ap) + ax, ap + ax,
+        ay.max(ax), ay.clone(),
+        // hypot
+        ax.hypot(ay), ( &( ax * ax ) + &( ay * ay ) ).sqrt(),
+        // atan2
+        ( &ax.sin() ).atan2( &ax.cos() ), ax.clone(),
+        ay.atan2(ax), &pi_2() - &ax.atan2(ay),
+    ];
+    let f            = stop_recording(az);
+    check_pairs(&f, p, x, 3);
+}
+//
+// pi_2
+fn pi_2() -> V {
+    V::pi() / V::from(2.0)
+}
+//
+#[test]
+fn reverse_taylor() {
+    test_hes_times_vec();
+    test_third_derivative();
+    test_unary();
+    test_binary();
+}
@@ -1,12 +1,16 @@
 // SPDX-License-Identifier: EPL-2.0 OR GPL-2.0-or-later
 // SPDX-FileCopyrightText: Bradley M. Bell <bradbell@seanet.com>
 // SPDX-FileContributor: 2025-26 Bradley M. Bell
+#![allow(clippy::useless_vec, clippy::vec_init_then_push)]
 //
 // Test generating rust source code.
 //
 use rustad::{
     AD,
     AzFloat,
+    Complex,
+    CompareOp,
+    VecAD,
     start_recording,
     stop_recording,
     get_lib,
@@ -35,6 +39,12 @@ fn test_sub () {
     // y[1] = x[0] - x[1];
     ay.push( &ax[0] - &ax[1] );
     //
+    // y[2] = min( p[0], p[1] )
+    ay.push( (&ap[0]).min(&ap[1]) );
+    //
+    // y[3] = max( x[0], p[1] )
+    ay.push( (&ax[0]).max(&ap[1]) );
+    //
     // f
     // f(x) = y
     let f  = stop_recording(ay);
@@ -53,7 +63,7 @@ fn test_sub () {
     let lib         = get_lib(src_dir, lib_file, replace_lib);
     //
     // test_sub_fn
-    let test_sub_fn : RustSrcLink<V> = get_rust_src_fn(&lib, &gn_name);
+    let test_sub_fn : RustSrcLink<V> = get_rust_src_fn(&lib, gn_name);
     //
     // p_ref, x_ref
     let p                   = vec! [ V::from(2.0), V::from(3.0) ];
@@ -74,6 +84,8 @@ fn test_sub () {
     // check
     assert_eq!( y[0], p[0] - p[1] );
     assert_eq!( y[1], x[0] - x[1] );
+    assert_eq!( y[2], p[0].min(p[1]) );
+    assert_eq!( y[3], x[0].max(p[1]) );
 }
 //
 fn test_unary () {
@@ -94,6 +106,30 @@ fn test_unary () {
     // y[1] = abs( x[0] );
     ay.push( (&ax[0]).abs() );
     //
+    // y[2] = atan( p[0] )
+    ay.push( (&ap[0]).atan() );
+    //
+    // y[3] = asinh( x[0] )
+    ay.push( (&ax[0]).asinh() );
+    //
+    // y[4] = erf( p[0] )
+    ay.push( (&ap[0]).erf() );
+    //
+    // y[5] = erfc( x[0] )
+    ay.push( (&ax[0]).erfc() );
+    //
+    // y[6] = log2( p[0] )
+    ay.push( (&ap[0]).log2() );
+    //
+    // y[7] = cbrt( x[0] )
+    ay.push( (&ax[0]).cbrt() );
+    //
+    // y[8] = floor( p[0] )
+    ay.push( (&ap[0]).floor() );
+    //
+    // y[9] = round( x[0] )
+    ay.push( (&ax[0]).round() );
+    //
     // f
     // f(x) = y
     let f  = stop_recording(ay);
@@ -112,7 +148,7 @@ fn test_unary () {
     let lib         = get_lib(src_dir, lib_file, replace_lib);
     //
     // test_unary_fn
-    let test_unary_fn : RustSrcLink<V> = get_rust_src_fn(&lib, &gn_name);
+    let test_unary_fn : RustSrcLink<V> = get_rust_src_fn(&lib, gn_name);
     //
     // p_ref, x_ref
     let p                   = vec! [ V::from(2.0) ];
@@ -133,6 +169,14 @@ fn test_unary () {
     // check
     assert_eq!( y[0], (&p[0]).sin() );
     assert_eq!( y[1], (&x[0]).abs() );
+    assert_eq!( y[2], (&p[0]).atan() );
+    assert_eq!( y[3], (&x[0]).asinh() );
+    assert_eq!( y[4], (&p[0]).erf() );
+    assert_eq!( y[5], (&x[0]).erfc() );
+    assert_eq!( y[6], (&p[0]).log2() );
+    assert_eq!( y[7], (&x[0]).cbrt() );
+    assert_eq!( y[8], (&p[0]).floor() );
+    assert_eq!( y[9], (&x[0]).round() );
 }
 //
 fn test_powi() {
@@ -172,7 +216,7 @@ fn test_powi() {
     let lib         = get_lib(src_dir, lib_file, replace_lib);
     //
     // test_powi_fn
-    let test_powi_fn : RustSrcLink<V> = get_rust_src_fn(&lib, &gn_name);
+    let test_powi_fn : RustSrcLink<V> = get_rust_src_fn(&lib, gn_name);
     //
     // p_ref, x_ref
     let p                   = vec! [ V::from(2.0) ];
@@ -231,7 +275,7 @@ fn test_cmp() {
     let lib         = get_lib(src_dir, lib_file, replace_lib);
     //
     // test_cmp_fn
-    let test_cmp_fn : RustSrcLink<V> = get_rust_src_fn(&lib, &gn_name);
+    let test_cmp_fn : RustSrcLink<V> = get_rust_src_fn(&lib, gn_name);
     //
     // p_ref, x_ref
     let p                   = vec! [ V::from(2.0), V::from(3.0) ];
@@ -254,10 +298,257 @@ fn test_cmp() {
     assert_eq!( y[1], V::from(0.0) );
 }
 //
+fn test_cexp() {
+    //
+    type V     = AzFloat<f32>;
+    //
+    // p, x, ap, ax
+    let p  = vec![ V::from(1.0), V::from(1.0) ];
+    let x  = vec![ V::from(1.0), V::from(1.0) ];
+    let (ap, ax)    = start_recording(Some(p), x.clone());
+    //
+    // ay
+    let mut ay : Vec< AD<V> > = Vec::new();
+    //
+    // y[0] = if p[0] < p[1] { p[0] } else { 2 }
+    let atwo = AD::from( V::from(2.0) );
+    ay.push( AD::select(&ap[0], &ap[1], CompareOp::Lt, &ap[0], &atwo) );
+    //
+    // y[1] = if x[0] >= p[0] { x[1] } else { x[0] }
+    ay.push( AD::select(&ax[0], &ap[0], CompareOp::Ge, &ax[1], &ax[0]) );
+    //
+    // f
+    // f(x) = y
+    let f  = stop_recording(ay);
+    //
+    // lib_src
+    let gn_name  = "test_cexp";
+    let lib_src  = f.rust_src(gn_name);
+    //
+    // src_dir
+    let src_dir = "tmp/test_cexp_rust_src";
+    create_src_dir(src_dir, &lib_src);
+    //
+    // lib
+    let lib_file    = "tmp/test_cexp_rust_src.so";
+    let replace_lib = true;
+    let lib         = get_lib(src_dir, lib_file, replace_lib);
+    //
+    // test_cexp_fn
+    let test_cexp_fn : RustSrcLink<V> = get_rust_src_fn(&lib, gn_name);
+    //
+    for (p_0, x_0) in [ (1.0, 4.0), (3.0, 2.0) ] {
+        //
+        // p_ref, x_ref
+        let p                   = vec! [ V::from(p_0), V::from(2.5) ];
+        let mut p_ref : Vec<&V> = Vec::new();
+        for p_j in p.iter() {
+            p_ref.push( p_j );
+        }
+        let x                   =  vec! [ V::from(x_0), V::from(5.0) ];
+        let mut x_ref : Vec<&V> = Vec::new();
+        for x_j in x.iter() {
+            x_ref.push( x_j )
+        }
+        //
+        // y
+        let result = test_cexp_fn(&p_ref, &x_ref);
+        let y      = result.unwrap();
+        //
+        // check
+        let y_0 = if p[0] < p[1] { p[0] } else { V::from(2.0) };
+        let y_1 = if x[0] >= p[0] { x[1] } else { x[0] };
+        assert_eq!( y[0], y_0 );
+        assert_eq!( y[1], y_1 );
+    }
+}
+//
+fn test_vec_ad() {
+    //
+    type V     = AzFloat<f32>;
+    //
+    // p, x, ap, ax
+    let p  = vec![ V::from(0.0) ];
+    let x  = vec![ V::from(0.0), V::from(1.0) ];
+    let (ap, ax)    = start_recording(Some(p), x.clone());
+    //
+    // avec = [ 4, 5, 6 ]
+    let table : Vec< AD<V> > = [ 4.0, 5.0, 6.0 ].iter().map(
+        |t| AD::from( V::from(*t) )
+    ).collect();
+    let mut avec = VecAD::new(table);
+    //
+    // ay
+    let mut ay : Vec< AD<V> > = Vec::new();
+    //
+    // y[0] = avec[ p[0] ]
+    ay.push( avec.load(&ap[0]) );
+    //
+    // avec[ x[0] ] = x[1]
+    avec.store( &ax[0], &ax[1] );
+    //
+    // y[1] = avec[ p[0] ]
+    ay.push( avec.load(&ap[0]) );
+    //
+    // f
+    // f(x) = y
+    let f  = stop_recording(ay);
+    //
+    // lib_src
+    let gn_name  = "test_vec_ad";
+    let lib_src  = f.rust_src(gn_name);
+    //
+    // src_dir
+    let src_dir = "tmp/test_vec_ad_rust_src";
+    create_src_dir(src_dir, &lib_src);
+    //
+    // lib
+    let lib_file    = "tmp/test_vec_ad_rust_src.so";
+    let replace_lib = true;
+    let lib         = get_lib(src_dir, lib_file, replace_lib);
+    //
+    // test_vec_ad_fn
+    let test_vec_ad_fn : RustSrcLink<V> = get_rust_src_fn(&lib, gn_name);
+    //
+    for (p_0, x_0) in [ (2.0, 2.0), (1.0, 2.0), (3.0, 0.0) ] {
+        //
+        // p_ref, x_ref
+        let p                   = vec! [ V::from(p_0) ];
+        let mut p_ref : Vec<&V> = Vec::new();
+        for p_j in p.iter() {
+            p_ref.push( p_j );
+        }
+        let x                   =  vec! [ V::from(x_0), V::from(7.0) ];
+        let mut x_ref : Vec<&V> = Vec::new();
+        for x_j in x.iter() {
+            x_ref.push( x_j )
+        }
+        //
+        // y
+        let result = test_vec_ad_fn(&p_ref, &x_ref);
+        let y      = result.unwrap();
+        //
+        // check
+        if p_0 == 3.0 {
+            assert!( y[0].to_inner().is_nan() );
+            assert!( y[1].to_inner().is_nan() );
+        } else {
+            let y_0 = V::from(4.0 + p_0);
+            let y_1 = if p_0 == x_0 { x[1] } else { y_0 };
+            assert_eq!( y[0], y_0 );
+            assert_eq!( y[1], y_1 );
+        }
+    }
+}
+//
+fn test_print() {
+    //
+    type V     = AzFloat<f32>;
+    //
+    // p, x, ap, ax
+    let p  = vec![ V::from(1.0) ];
+    let x  = vec![ V::from(1.0) ];
+    let (ap, ax)    = start_recording(Some(p), x.clone());
+    //
+    // print p[0] and print x[0] when p[0] is positive
+    ap[0].print_on_forward("p[0] = ");
+    ax[0].print_on_forward_if("x[0] = ", &ap[0]);
+    //
+    // ay
+    let ay = vec![ &ap[0] * &ax[0] ];
+    //
+    // f
+    // f(x) = y
+    let f  = stop_recording(ay);
+    //
+    // lib_src
+    let gn_name  = "test_print";
+    let lib_src  = f.rust_src(gn_name);
+    assert!( lib_src.contains("println!") );
+    //
+    // src_dir
+    let src_dir = "tmp/test_print_rust_src";
+    create_src_dir(src_dir, &lib_src);
+    //
+    // lib
+    let lib_file    = "tmp/test_print_rust_src.so";
+    let replace_lib = true;
+    let lib         = get_lib(src_dir, lib_file, replace_lib);
+    //
+    // test_print_fn
+    let test_print_fn : RustSrcLink<V> = get_rust_src_fn(&lib, gn_name);
+    //
+    // p_ref, x_ref
+    let p                   = vec! [ V::from(2.0) ];
+    let p_ref : Vec<&V>     = p.iter().collect();
+    let x                   = vec! [ V::from(3.0) ];
+    let x_ref : Vec<&V>     = x.iter().collect();
+    //
+    // y
+    let result = test_print_fn(&p_ref, &x_ref);
+    let y      = result.unwrap();
+    //
+    // check
+    assert_eq!( y[0], V::from(6.0) );
+}
+//
+// test_complex
+fn test_complex() {
+    //
+    type S     = AzFloat<f64>;
+    type V     = Complex<S>;
+    //
+    // x, ax
+    let x  = vec![ V::new( S::from(1.0), S::from(2.0) ) ];
+    let (_, ax)     = start_recording(None, x.clone());
+    //
+    // ay
+    // y[0] = i * x[0] * exp( x[0] )
+    let i      = V::new( S::from(0.0), S::from(1.0) );
+    let ay     = vec![ &( &ax[0] * &i ) * &(&ax[0]).exp() ];
+    //
+    // f
+    let f  = stop_recording(ay);
+    //
+    // lib_src
+    let gn_name  = "test_complex";
+    let lib_src  = f.rust_src(gn_name);
+    //
+    // src_dir
+    let src_dir = "tmp/test_complex_rust_src";
+    create_src_dir(src_dir, &lib_src);
+    //
+    // lib
+    let lib_file    = "tmp/test_complex_rust_src.so";
+    let replace_lib = true;
+    let lib         = get_lib(src_dir, lib_file, replace_lib);
+    //
+    // test_complex_fn
+    let test_complex_fn : RustSrcLink<V> = get_rust_src_fn(&lib, gn_name);
+    //
+    // x_ref
+    let p_ref : Vec<&V>     = Vec::new();
+    let x                   = vec! [ V::new( S::from(0.5), S::from(-1.0) ) ];
+    let x_ref : Vec<&V>     = x.iter().collect();
+    //
+    // y
+    let result = test_complex_fn(&p_ref, &x_ref);
+    let y      = result.unwrap();
+    //
+    // check
+    let opt_vec : Vec<[&str; 2]> = Vec::new();
+    let (check, _) = f.forward_var_value(None, x, &opt_vec);
+    assert_eq!( y[0], check[0] );
+}
+//
 #[test]
 fn rust_src() {
     test_sub();
     test_unary();
     test_powi();
     test_cmp();
+    test_cexp();
+    test_vec_ad();
+    test_print();
+    test_complex();
 }
@@ -0,0 +1,140 @@
+// SPDX-License-Identifier: EPL-2.0 OR GPL-2.0-or-later
+// SPDX-FileCopyrightText: Bradley M. Bell <bradbell@seanet.com>
+// SPDX-FileContributor: 2026 Bradley M. Bell
+//
+// Set up a value type that is defined outside of the rustad package.
+//
+use rustad::{
+    AD,
+    AzFloat,
+    FConst,
+    FUnary,
+    FBinary,
+    FValue,
+    start_recording,
+    stop_recording,
+};
+//
+// S
+type S = AzFloat<f64>;
+//
+// MyFloat
+/// A value type defined outside of rustad that wraps an `AzFloat<f64>`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct MyFloat(S);
+//
+// From
+impl From<f32> for MyFloat {
+    fn from(f : f32) -> Self { MyFloat( S::from(f) ) }
+}
+impl From<f64> for MyFloat {
+    fn from(f : f64) -> Self { MyFloat( S::from(f) ) }
+}
+//
+// Display
+impl std::fmt::Display for MyFloat {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "MyFloat({})", self.0)
+    }
+}
+//
+// Add, Sub, Mul, Div, AddAssign, SubAssign
+macro_rules! impl_binary_operator{ ($Name:ident, $name:ident) => {
+    impl std::ops::$Name<&MyFloat> for &MyFloat {
+        type Output = MyFloat;
+        fn $name(self, rhs : &MyFloat) -> MyFloat {
+            MyFloat( std::ops::$Name::$name(self.0, rhs.0) )
+        }
+    }
+} }
+impl_binary_operator!(Add, add);
+impl_binary_operator!(Sub, sub);
+impl_binary_operator!(Mul, mul);
+impl_binary_operator!(Div, div);
+impl std::ops::AddAssign<&MyFloat> for MyFloat {
+    fn add_assign(&mut self, rhs : &MyFloat) { self.0 += rhs.0; }
+}
+impl std::ops::SubAssign<&MyFloat> for MyFloat {
+    fn sub_assign(&mut self, rhs : &MyFloat) { self.0 -= rhs.0; }
+}
+//
+// FConst
+impl FConst for MyFloat {
+    fn pi()           -> Self { MyFloat( S::pi() ) }
+    fn nan()          -> Self { MyFloat( S::nan() ) }
+    fn one()          -> Self { MyFloat( S::one() ) }
+    fn zero()         -> Self { MyFloat( S::zero() ) }
+    fn epsilon()      -> Self { MyFloat( S::epsilon() ) }
+    fn min_positive() -> Self { MyFloat( S::min_positive() ) }
+}
+//
+// FValue
+impl FValue for MyFloat {
+    fn is_zero(&self) -> bool   { self.0.is_zero() }
+    fn is_one(&self)  -> bool   { self.0.is_one() }
+    fn is_nan(&self)  -> bool   { self.0.is_nan() }
+    fn to_src(&self)  -> String { "MyFloat(".to_string() + &self.0.to_src() + ")" }
+}
+//
+// FUnary
+macro_rules! unary_function{ ( $($name:ident),* ) => {
+    $( fn $name(self) -> MyFloat { MyFloat( self.0.$name() ) } )*
+} }
+impl FUnary for &MyFloat {
+    type Output = MyFloat;
+    unary_function!(
+        abs, acos, acosh, asin, asinh, atan, atanh, cbrt, ceil, cos, cosh,
+        erf, erfc, exp, exp2, exp_m1, floor, ln, ln_1p, log10, log2, minus,
+        round, signum, sin, sinh, sqrt, square, tan, tanh, trunc
+    );
+    fn powi(self, rhs : i32) -> MyFloat { MyFloat( self.0.powi(rhs) ) }
+}
+//
+// FBinary
+macro_rules! binary_function{ ( $($name:ident),* ) => {
+    $( fn $name(self, rhs : &MyFloat) -> MyFloat {
+        MyFloat( self.0.$name(rhs.0) )
+    } )*
+} }
+impl FBinary<&MyFloat> for &MyFloat {
+    type Output = MyFloat;
+    binary_function!(
+        num_lt, num_le, num_eq, num_ne, num_ge, num_gt,
+        atan2, hypot, powf, min, max
+    );
+}
+//
+// setup
+rustad::setup_value_type!( MyFloat );
+//
+// test_record
+// f(x) = [ x[0] * sin(x[1]) , 2 - x[0] ]
+fn test_record() {
+    let opt_vec : Vec<[&str; 2]> = Vec::new();
+    let x        = vec![ MyFloat::from(2.0), MyFloat::from(0.5) ];
+    let (_, ax)  = start_recording(None, x.clone());
+    let two      = MyFloat::from(2.0);
+    let ay_0     = &ax[0] * &(&ax[1]).sin();
+    let ay_1     = &two - &ax[0];
+    let f        = stop_recording( vec![ay_0, ay_1] );
+    //
+    // forward_var
+    let (y, v)   = f.forward_var_value(None, x.clone(), &opt_vec);
+    assert_eq!( y[0], MyFloat( x[0].0 * x[1].0.sin() ) );
+    assert_eq!( y[1], MyFloat::from(0.0) );
+    //
+    // reverse_der
+    let dy       = vec![ MyFloat::one(), MyFloat::zero() ];
+    let dx       = f.reverse_der_value(None, &v, dy, &opt_vec);
+    assert_eq!( dx[0], MyFloat( x[1].0.sin() ) );
+    assert_eq!( dx[1], MyFloat( x[0].0 * x[1].0.cos() ) );
+    //
+    // AD<MyFloat> from a MyFloat
+    let ax       = AD::from( MyFloat::from(3.0) );
+    assert_eq!( ax.to_value(), MyFloat::from(3.0) );
+}
+//
+#[test]
+fn setup_value_type() {
+    test_record();
+}
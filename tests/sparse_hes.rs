@@ -0,0 +1,176 @@
+// SPDX-License-Identifier: EPL-2.0 OR GPL-2.0-or-later
+// SPDX-FileCopyrightText: Bradley M. Bell <bradbell@seanet.com>
+// SPDX-FileContributor: 2026 Bradley M. Bell
+#![allow(clippy::needless_range_loop)]
+//
+use rustad::{
+    AD,
+    ADfn,
+    AzFloat,
+    FUnary,
+    SparsityPattern,
+    star_coloring,
+    start_recording,
+    stop_recording,
+    nearly_eq,
+};
+//
+// V
+type V = AzFloat<f64>;
+//
+// hessian
+// Use reverse_taylor to compute the dense Hessian of sum_i w[i] * f_i(x).
+fn hessian(f : &ADfn<V>, x : Vec<V>, w : &[V]) -> Vec< Vec<V> > {
+    let opt_vec : Vec<[&str; 2]> = Vec::new();
+    let n_dom        = x.len();
+    let (_, var_all) = f.forward_var_value(None, x, &opt_vec);
+    let mut result   = Vec::new();
+    for j in 0 .. n_dom {
+        let mut dom_taylor = vec![ V::from(0.0); n_dom ];
+        dom_taylor[j]      = V::from(1.0);
+        let (_, var_taylor) = f.forward_taylor_value(
+            None, &var_all, 1, dom_taylor, &opt_vec
+        );
+        let dom_partial = f.reverse_taylor_value(
+            None, &var_taylor, 1, w.to_vec(), &opt_vec
+        );
+        let mut column = Vec::new();
+        for i in 0 .. n_dom {
+            column.push( dom_partial[i * 2] );
+        }
+        result.push( column );
+    }
+    result
+}
+//
+// n_color
+fn n_color(n : usize, color_vec : &[usize]) -> usize {
+    color_vec.iter().filter( |&k| k < &n ).max().map_or(0, |k| k + 1)
+}
+//
+// check_sparse_hes
+// Check sparse_hes_value and sparse_hes_ad against the dense Hessian
+// and return the number of colors.
+fn check_sparse_hes(
+    f           : &ADfn<V>         ,
+    x           : Vec<V>           ,
+    w           : Vec<V>           ,
+    pattern     : &SparsityPattern ,
+    sub_pattern : &SparsityPattern ,
+) -> usize {
+    let opt_vec : Vec<[&str; 2]> = Vec::new();
+    let n         = x.len();
+    let color_vec = star_coloring(n, pattern, sub_pattern);
+    //
+    // distance one coloring
+    for [i, j] in pattern {
+        if i != j && color_vec[*i] < n && color_vec[*j] < n {
+            assert_ne!( color_vec[*i], color_vec[*j] );
+        }
+    }
+    //
+    // hes_value
+    let (_, var_all) = f.forward_var_value(None, x.clone(), &opt_vec);
+    let hes_value    = f.sparse_hes_value(
+        None, &var_all, w.clone(), pattern, sub_pattern, &color_vec, &opt_vec
+    );
+    //
+    // dense
+    let dense = hessian(f, x.clone(), &w);
+    assert_eq!( hes_value.len(), sub_pattern.len() );
+    for (k, [i, j]) in sub_pattern.iter().enumerate() {
+        assert!( nearly_eq::<V>( &hes_value[k], &dense[*j][*i], &opt_vec ) );
+    }
+    //
+    // g
+    // g(x) = the Hessian on the sub_pattern
+    let (_, ax)       = start_recording(None, x.clone());
+    let (_, avar_all) = f.forward_var_ad(None, ax, &opt_vec);
+    let aw : Vec< AD<V> > = w.iter().map( |w_i| AD::from(*w_i) ).collect();
+    let ahes          = f.sparse_hes_ad(
+        None, &avar_all, aw, pattern, sub_pattern, &color_vec, &opt_vec
+    );
+    let g             = stop_recording(ahes);
+    //
+    // hes_ad
+    let (hes_ad, _)   = g.forward_var_value(None, x, &opt_vec);
+    for k in 0 .. sub_pattern.len() {
+        assert!( nearly_eq::<V>( &hes_ad[k], &hes_value[k], &opt_vec ) );
+    }
+    n_color(n, &color_vec)
+}
+//
+// test_arrow
+// f(x) = x[0] * ( x[1] + ... + x[n-1] ) + sum_j sin( x[j] )
+fn test_arrow() {
+    let n            = 10;
+    let x : Vec<V>   = (0 .. n).map( |j| V::from(j as f64 + 0.5) ).collect();
+    let (_, ax)      = start_recording(None, x.clone());
+    let mut asum     = AD::from( V::from(0.0) );
+    for j in 1 .. n {
+        asum += &ax[j];
+    }
+    let mut ay_0     = &ax[0] * &asum;
+    for j in 0 .. n {
+        ay_0 += &(&ax[j]).sin();
+    }
+    let f            = stop_recording( vec![ ay_0 ] );
+    //
+    // pattern
+    let opt_vec : Vec<[&str; 2]> = Vec::new();
+    let pattern      = f.hes_sparsity(&[true], &opt_vec);
+    assert_eq!( pattern.len(), n + 2 * (n - 1) );
+    //
+    // full pattern
+    // A star coloring of an arrow needs two colors while a distance two
+    // coloring would need n colors.
+    let w            = vec![ V::from(2.0) ];
+    let count = check_sparse_hes(&f, x.clone(), w.clone(), &pattern, &pattern);
+    assert_eq!( count, 2 );
+    //
+    // diagonal sub_pattern
+    let diagonal : SparsityPattern = (0 .. n).map( |j| [j, j] ).collect();
+    check_sparse_hes(&f, x.clone(), w.clone(), &pattern, &diagonal);
+    //
+    // sub_pattern that does not include x[0]
+    let sub_pattern : SparsityPattern = vec![ [3, 3], [5, 5] ];
+    let count = check_sparse_hes(&f, x, w, &pattern, &sub_pattern);
+    assert_eq!( count, 1 );
+}
+//
+// test_band
+// f_0(x) = sum_j x[j] * x[j+1] , f_1(x) = sum_j exp( x[j] ) * x[j+2]
+fn test_band() {
+    let n            = 12;
+    let x : Vec<V>   = (0 .. n).map( |j| V::from(0.1 * j as f64) ).collect();
+    let (_, ax)      = start_recording(None, x.clone());
+    let mut ay_0     = AD::from( V::from(0.0) );
+    let mut ay_1     = AD::from( V::from(0.0) );
+    for j in 0 .. n - 1 {
+        ay_0 += &( &ax[j] * &ax[j+1] );
+    }
+    for j in 0 .. n - 2 {
+        ay_1 += &( &(&ax[j]).exp() * &ax[j+2] );
+    }
+    let f            = stop_recording( vec![ ay_0, ay_1 ] );
+    //
+    // pattern
+    let opt_vec : Vec<[&str; 2]> = Vec::new();
+    let pattern      = f.hes_sparsity(&[true, true], &opt_vec);
+    //
+    // full pattern
+    let w            = vec![ V::from(3.0), V::from(-2.0) ];
+    let count = check_sparse_hes(&f, x.clone(), w.clone(), &pattern, &pattern);
+    assert!( count < n );
+    //
+    // lower triangle
+    let lower : SparsityPattern =
+        pattern.iter().filter( |[i, j]| j <= i ).cloned().collect();
+    check_sparse_hes(&f, x, w, &pattern, &lower);
+}
+//
+#[test]
+fn sparse_hes() {
+    test_arrow();
+    test_band();
+}
@@ -0,0 +1,377 @@
+// SPDX-License-Identifier: EPL-2.0 OR GPL-2.0-or-later
+// SPDX-FileCopyrightText: Bradley M. Bell <bradbell@seanet.com>
+// SPDX-FileContributor: 2026 Bradley M. Bell
+#![allow(
+    clippy::let_and_return,
+    clippy::needless_range_loop,
+)]
+//
+use rustad::{
+    AD,
+    ADfn,
+    AzFloat,
+    NumVec,
+    FUnary,
+    FBinary,
+    call_atom,
+    start_recording,
+    stop_recording,
+};
+//
+mod atom_test;
+//
+// test_f64
+// f(p, x) = [ p[0] * sin( x[0] ) , cos( p[0] ) + 3 * x[1] , p[0] , 2 ]
+fn test_f64() {
+    type V = AzFloat<f64>;
+    let opt_vec : Vec<[&str; 2]> = Vec::new();
+    let p : Vec<V>   = vec![ V::from(2.0) ];
+    let x : Vec<V>   = vec![ V::from(0.5), V::from(1.5) ];
+    let (ap, ax)     = start_recording( Some(p.clone()), x.clone() );
+    let ay_0         = &ap[0] * &(&ax[0]).sin();
+    let ay_1         = &(&ap[0]).cos() + &( &AD::from( V::from(3.0) ) * &ax[1] );
+    let ay_2         = ap[0].clone();
+    let ay_3         = AD::from( V::from(2.0) );
+    let f            = stop_recording( vec![ay_0, ay_1, ay_2, ay_3] );
+    //
+    // g
+    let file_name    = "tmp/test_to_bytes.bin";
+    std::fs::create_dir_all("tmp").unwrap();
+    f.to_bytes_file(file_name).unwrap();
+    let g : ADfn<V>  = ADfn::from_bytes_file(file_name).unwrap();
+    assert_eq!( g.dyp_dom_len(), f.dyp_dom_len() );
+    assert_eq!( g.dyp_dep_len(), f.dyp_dep_len() );
+    assert_eq!( g.var_dom_len(), f.var_dom_len() );
+    assert_eq!( g.var_dep_len(), f.var_dep_len() );
+    assert_eq!( g.rng_len(),     f.rng_len() );
+    assert_eq!( g.cop_len(),     f.cop_len() );
+    //
+    // check
+    let p : Vec<V>   = vec![ V::from(-1.0) ];
+    let x : Vec<V>   = vec![ V::from(2.0), V::from(3.0) ];
+    let f_dyp        = f.forward_dyp_value(p.clone(), &opt_vec);
+    let g_dyp        = g.forward_dyp_value(p, &opt_vec);
+    let (f_y, _)     = f.forward_var_value(Some(&f_dyp), x.clone(), &opt_vec);
+    let (g_y, _)     = g.forward_var_value(Some(&g_dyp), x, &opt_vec);
+    assert_eq!( f_y, g_y );
+    //
+    // to_bytes is deterministic
+    assert_eq!( f.to_bytes(), g.to_bytes() );
+}
+//
+// test_f32
+// f(x) = [ x[0] * x[1] , exp( x[1] ) ]
+fn test_f32() {
+    type V = AzFloat<f32>;
+    let opt_vec : Vec<[&str; 2]> = Vec::new();
+    let x : Vec<V>   = vec![ V::from(0.5), V::from(1.5) ];
+    let (_, ax)      = start_recording(None, x.clone() );
+    let ay_0         = &ax[0] * &ax[1];
+    let ay_1         = (&ax[1]).exp();
+    let f            = stop_recording( vec![ay_0, ay_1] );
+    let g : ADfn<V>  = ADfn::from_bytes( &f.to_bytes() ).unwrap();
+    //
+    let (f_y, _)     = f.forward_var_value(None, x.clone(), &opt_vec);
+    let (g_y, _)     = g.forward_var_value(None, x, &opt_vec);
+    assert_eq!( f_y, g_y );
+    //
+    // empty function
+    let f : ADfn<V>  = ADfn::default();
+    let g : ADfn<V>  = ADfn::from_bytes( &f.to_bytes() ).unwrap();
+    assert_eq!( g.rng_len(), 0 );
+}
+//
+// test_num_vec
+// f(x) = [ x[0] * x[1] + c ]
+fn test_num_vec() {
+    type S = AzFloat<f64>;
+    type V = NumVec<S>;
+    let opt_vec : Vec<[&str; 2]> = Vec::new();
+    let c            = V::new( vec![ S::from(1.0), S::from(2.0), S::from(3.0) ] );
+    let x : Vec<V>   = vec![ V::from(2.0), V::from(3.0) ];
+    let (_, ax)      = start_recording(None, x.clone() );
+    let ay_0         = &( &ax[0] * &ax[1] ) + &AD::from(c);
+    let f            = stop_recording( vec![ay_0] );
+    let g : ADfn<V>  = ADfn::from_bytes( &f.to_bytes() ).unwrap();
+    //
+    let x : Vec<V>   = vec![
+        V::new( vec![ S::from(4.0), S::from(5.0), S::from(6.0) ] ),
+        V::from(2.0),
+    ];
+    let (f_y, _)     = f.forward_var_value(None, x.clone(), &opt_vec);
+    let (g_y, _)     = g.forward_var_value(None, x, &opt_vec);
+    assert_eq!( f_y, g_y );
+}
+//
+// test_call
+// f(p, x) = [ p[0] , x[0] , 5 ] computed using the identity atomic function.
+// The call in the dynamic parameter graph has a variable argument.
+fn test_call() {
+    type V = AzFloat<f64>;
+    let opt_vec : Vec<[&str; 2]> = Vec::new();
+    let eye_atom_id  = atom_test::register_eye::<V>();
+    let p : Vec<V>   = vec![ V::from(2.0) ];
+    let x : Vec<V>   = vec![ V::from(3.0) ];
+    let (ap, ax)     = start_recording( Some(p.clone()), x.clone() );
+    let az           = vec![
+        ap[0].clone(), ax[0].clone(), AD::from( V::from(5.0) )
+    ];
+    let ay           = call_atom(3, az, eye_atom_id, 0, false);
+    let f            = stop_recording(ay);
+    let g : ADfn<V>  = ADfn::from_bytes( &f.to_bytes() ).unwrap();
+    //
+    let g_dyp        = g.forward_dyp_value(p.clone(), &opt_vec);
+    let (g_y, _)     = g.forward_var_value(Some(&g_dyp), x.clone(), &opt_vec);
+    assert_eq!( g_y, vec![ p[0], x[0], V::from(5.0) ] );
+}
+//
+// test_error
+fn test_error() {
+    type V = AzFloat<f64>;
+    let x : Vec<V>   = vec![ V::from(0.5) ];
+    let (_, ax)      = start_recording(None, x);
+    let ay_0         = (&ax[0]).sin();
+    let f            = stop_recording( vec![ay_0] );
+    let bytes        = f.to_bytes();
+    //
+    // value type
+    let result = ADfn::< AzFloat<f32> >::from_bytes(&bytes);
+    assert!( result.is_err() );
+    //
+    // magic
+    let mut bad = bytes.clone();
+    bad[0]      = b'X';
+    assert!( ADfn::<V>::from_bytes(&bad).is_err() );
+    //
+    // truncated
+    for len in [ 0, 8, 12, bytes.len() / 2, bytes.len() - 1 ] {
+        assert!( ADfn::<V>::from_bytes(&bytes[0 .. len]).is_err() );
+    }
+    //
+    // extra bytes
+    let mut bad = bytes.clone();
+    bad.push(0);
+    assert!( ADfn::<V>::from_bytes(&bad).is_err() );
+    //
+    // file that does not exist
+    assert!( ADfn::<V>::from_bytes_file("tmp/does_not_exist.bin").is_err() );
+}
+//
+// test_bad_index
+// The bytes have the correct structure, but an index is out of range.
+fn test_bad_index() {
+    type V = AzFloat<f64>;
+    let x : Vec<V>   = vec![ V::from(0.5) ];
+    let (_, ax)      = start_recording(None, x);
+    let ay_0         = (&ax[0]).sin();
+    let f            = stop_recording( vec![ay_0] );
+    let bytes        = f.to_bytes();
+    assert!( ADfn::<V>::from_bytes(&bytes).is_ok() );
+    //
+    // rng_index
+    // The bytes end with rng_index (one u32), the number of constants (u64),
+    // and the constants (f64).
+    let start   = bytes.len() - 8 * f.cop_len() - 8 - 4;
+    let mut bad = bytes.clone();
+    bad[start .. start + 4].copy_from_slice( &100u32.to_le_bytes() );
+    assert!( ADfn::<V>::from_bytes(&bad).is_err() );
+    //
+    // arg_all
+    // The variable graph arg_all is [0] and arg_type_all is [Variable] .
+    let mut pattern : Vec<u8> = Vec::new();
+    pattern.extend_from_slice( &1u64.to_le_bytes() );
+    pattern.extend_from_slice( &0u32.to_le_bytes() );
+    pattern.extend_from_slice( &1u64.to_le_bytes() );
+    pattern.push( 2 );
+    let start   = bytes.windows( pattern.len() ).rposition(
+        |window| window == pattern.as_slice()
+    ).unwrap() + 8;
+    let mut bad = bytes.clone();
+    bad[start .. start + 4].copy_from_slice( &100u32.to_le_bytes() );
+    assert!( ADfn::<V>::from_bytes(&bad).is_err() );
+}
+//
+// Op
+// operator name, arguments as (type code, index) pairs, and booleans
+// where the type codes are 0, 1, 2, 3 for constant, dynamic, variable, empty.
+type Op = (&'static str, Vec<(u8, u32)>, Vec<bool>);
+//
+// graph_bytes
+// binary representation of a function with n_dom variable domain components,
+// no dynamic parameters and no constants. The variable graph operators are
+// op_vec, str_all is the strings they use, and rng is the range.
+fn graph_bytes(
+    n_dom   : usize      ,
+    op_vec  : &[Op]      ,
+    str_all : &str       ,
+    rng     : &[(u8, u32)] ,
+) -> Vec<u8> {
+    type V = AzFloat<f64>;
+    //
+    // bytes, name_vec
+    // copy the header and operator names from a valid representation
+    let x : Vec<V>   = vec![ V::from(0.5) ];
+    let (_, ax)      = start_recording(None, x);
+    let f            = stop_recording( vec![ (&ax[0]).sin() ] );
+    let valid        = f.to_bytes();
+    let get_u64      = |start : usize| u64::from_le_bytes(
+        valid[start .. start + 8].try_into().unwrap()
+    ) as usize;
+    let mut start    = 8 + 4;
+    start           += 8 + get_u64(start);
+    let n_op         = get_u64(start);
+    start           += 8;
+    let mut name_vec : Vec<String> = Vec::new();
+    for _i in 0 .. n_op {
+        let len = get_u64(start);
+        let name = &valid[start + 8 .. start + 8 + len];
+        name_vec.push( String::from_utf8( name.to_vec() ).unwrap() );
+        start  += 8 + len;
+    }
+    let mut bytes = valid[0 .. start].to_vec();
+    let put_u64   = |bytes : &mut Vec<u8>, value : usize|
+        bytes.extend_from_slice( &(value as u64).to_le_bytes() );
+    //
+    // dyp
+    for _i in 0 .. 7 {
+        put_u64(&mut bytes, 0);
+    }
+    put_u64(&mut bytes, 0);
+    //
+    // var
+    let mut id_all    : Vec<u8>   = Vec::new();
+    let mut arg_start : Vec<u32>  = Vec::new();
+    let mut arg_all   : Vec<u32>  = Vec::new();
+    let mut arg_type  : Vec<u8>   = Vec::new();
+    let mut bool_all  : Vec<bool> = Vec::new();
+    for (name, arg, flag) in op_vec {
+        let op_id = name_vec.iter().position( |n| n == name ).unwrap();
+        id_all.push( op_id as u8 );
+        arg_start.push( arg_all.len() as u32 );
+        for (ad_type, index) in arg {
+            arg_type.push( *ad_type );
+            arg_all.push( *index );
+        }
+        bool_all.extend_from_slice( flag );
+    }
+    arg_start.push( arg_all.len() as u32 );
+    put_u64(&mut bytes, n_dom);
+    put_u64(&mut bytes, op_vec.len());
+    put_u64(&mut bytes, id_all.len());
+    bytes.extend_from_slice( &id_all );
+    for vec in [ &arg_start, &arg_all ] {
+        put_u64(&mut bytes, vec.len());
+        for value in vec.iter() {
+            bytes.extend_from_slice( &value.to_le_bytes() );
+        }
+    }
+    put_u64(&mut bytes, arg_type.len());
+    bytes.extend_from_slice( &arg_type );
+    put_u64(&mut bytes, bool_all.len());
+    bytes.extend( bool_all.iter().map( |b| *b as u8 ) );
+    put_u64(&mut bytes, str_all.len());
+    bytes.extend_from_slice( str_all.as_bytes() );
+    //
+    // rng_ad_type, rng_index
+    put_u64(&mut bytes, rng.len());
+    bytes.extend( rng.iter().map( |(ad_type, _)| *ad_type ) );
+    put_u64(&mut bytes, rng.len());
+    for (_, index) in rng {
+        bytes.extend_from_slice( &index.to_le_bytes() );
+    }
+    //
+    // cop
+    put_u64(&mut bytes, 0);
+    bytes
+}
+//
+// test_bad_graph
+fn test_bad_graph() {
+    type V = AzFloat<f64>;
+    let (c, v, e) = (0u8, 2u8, 3u8);
+    let from_bytes = |n_dom, op_vec : &[Op], str_all, rng : &[(u8, u32)]|
+        ADfn::<V>::from_bytes( &graph_bytes(n_dom, op_vec, str_all, rng) );
+    let error = |n_dom, op_vec : &[Op], str_all, rng : &[(u8, u32)]|
+        from_bytes(n_dom, op_vec, str_all, rng).err().unwrap();
+    //
+    // valid
+    let op_vec : Vec<Op> = vec![ ("sin", vec![ (v, 0) ], vec![]) ];
+    let f = from_bytes(1, &op_vec, "", &[ (v, 1) ]).unwrap();
+    let opt_vec : Vec<[&str; 2]> = Vec::new();
+    let (y, _) = f.forward_var_value(None, vec![ V::from(0.5) ], &opt_vec);
+    assert_eq!( y[0], V::from( 0.5f64.sin() ) );
+    //
+    // valid comparison; its third argument is the value when recorded
+    let x : Vec<V> = vec![ V::from(0.5) ];
+    let (_, ax)    = start_recording(None, x);
+    let ay         = (&ax[0]).num_lt( &V::from(3.0) );
+    let f          = stop_recording( vec![ay] );
+    assert!( ADfn::<V>::from_bytes( &f.to_bytes() ).is_ok() );
+    //
+    // forward reference
+    let op_vec : Vec<Op> = vec![
+        ("sin", vec![ (v, 2) ], vec![]),
+        ("cos", vec![ (v, 0) ], vec![]),
+    ];
+    let message = error(1, &op_vec, "", &[ (v, 1) ]);
+    assert!( message.contains("index 2 is out of range"), "{message}" );
+    //
+    // number of arguments
+    let op_vec : Vec<Op> = vec![ ("mul_vv", vec![ (v, 0) ], vec![]) ];
+    let message = error(1, &op_vec, "", &[ (v, 1) ]);
+    assert!( message.contains("wrong number of arguments"), "{message}" );
+    //
+    // argument type
+    let op_vec : Vec<Op> = vec![ ("mul_vv", vec![ (v, 0), (c, 0) ], vec![]) ];
+    let message = error(1, &op_vec, "", &[ (v, 1) ]);
+    assert!( message.contains("wrong type"), "{message}" );
+    //
+    // cexp bool offset
+    let op_vec : Vec<Op> = vec![ ("cexp",
+        vec![ (e, 0), (e, 5), (v, 0), (v, 0), (v, 0), (v, 0) ],
+        vec![ true, true ]
+    ) ];
+    let message = error(1, &op_vec, "", &[ (v, 1) ]);
+    assert!( message.contains("booleans are out of range"), "{message}" );
+    //
+    // print str range
+    let op_vec : Vec<Op> = vec![ ("print",
+        vec![ (e, 0), (e, 10), (v, 0), (e, 0) ], vec![]
+    ) ];
+    let message = error(1, &op_vec, "x = ", &[ (v, 0) ]);
+    assert!( message.contains("string is out of range"), "{message}" );
+    //
+    // zero_one str range
+    let op_vec : Vec<Op> = vec![ ("zero_one",
+        vec![ (e, 0), (e, 2), (e, 1), (v, 0) ], vec![ true, true, true ]
+    ) ];
+    let message = error(1, &op_vec, "is_one", &[ (v, 1) ]);
+    assert!( message.contains("string is out of range"), "{message}" );
+    //
+    // call atom_id
+    let op_vec : Vec<Op> = vec![ ("call",
+        vec![ (e, 99), (e, 0), (e, 1), (e, 1), (e, 0), (v, 0) ],
+        vec![ false, true ]
+    ) ];
+    let message = error(1, &op_vec, "", &[ (v, 1) ]);
+    assert!( message.contains("atom_id 99"), "{message}" );
+    //
+    // call n_rng
+    let op_vec : Vec<Op> = vec![ ("call",
+        vec![ (e, 0), (e, 0), (e, 1), (e, 5), (e, 0), (v, 0) ],
+        vec![ false, true ]
+    ) ];
+    let message = error(1, &op_vec, "", &[ (v, 1) ]);
+    assert!( message.contains("booleans are out of range"), "{message}" );
+}
+//
+#[test]
+fn to_bytes() {
+    test_f64();
+    test_f32();
+    test_num_vec();
+    test_call();
+    test_error();
+    test_bad_index();
+    test_bad_graph();
+}
@@ -0,0 +1,94 @@
+// SPDX-License-Identifier: EPL-2.0 OR GPL-2.0-or-later
+// SPDX-FileCopyrightText: Bradley M. Bell <bradbell@seanet.com>
+// SPDX-FileContributor: 2026 Bradley M. Bell
+//
+use rustad::{
+    AD,
+    AzFloat,
+    Direction,
+    FUnary,
+    start_recording,
+    stop_recording,
+    register_checkpoint,
+    call_checkpoint,
+};
+//
+// V
+type V = AzFloat<f64>;
+//
+// test_call
+// g(x) = [ x[0] * x[1] , sin( x[1] ) ] is called as an atomic function.
+fn test_call() {
+    //
+    // checkpoint_id
+    let x : Vec<V>     = vec![ V::from(1.0), V::from(2.0) ];
+    let (_, ax)        = start_recording(None, x.clone());
+    let ay_0           = &ax[0] * &ax[1];
+    let ay_1           = (&ax[1]).sin();
+    let g              = stop_recording( vec![ay_0, ay_1] );
+    let directions  : Vec<Direction> = Vec::new();
+    let opt_vec        = vec![ ["name",  "test_to_dot"] ];
+    let checkpoint_id  = register_checkpoint(g, &directions, &opt_vec);
+    //
+    // f
+    // f(x) = [ g_0(x) , 3 * g_1(x) ]
+    let (_, ax)        = start_recording(None, x);
+    let ag             = call_checkpoint(ax, checkpoint_id, false);
+    let ay_1           = &AD::from( V::from(3.0) ) * &ag[1];
+    let f              = stop_recording( vec![ ag[0].clone(), ay_1 ] );
+    //
+    // dot
+    let opt_vec : Vec<[&str; 2]> = Vec::new();
+    let dot = f.to_dot(&opt_vec);
+    //
+    // call cluster
+    let begin = dot.find("subgraph cluster_var_call_0 {").unwrap();
+    let end   = begin + dot[begin ..].find("    }\n").unwrap();
+    let call  = &dot[begin .. end];
+    assert!( call.contains("style=dashed;") );
+    assert!( call.contains("v2 [label=\"v2\\ncall\"") );
+    assert!( call.contains("v3 [label=\"v3\\ncall_res\"") );
+    assert!( ! call.contains("v4") );
+    //
+    // edges
+    assert!( dot.contains("  v0 -> v2;\n") );
+    assert!( dot.contains("  v1 -> v2;\n") );
+    assert!( dot.contains("  v3 -> v4;\n") );
+    assert!( dot.contains("  v2 -> y0;\n") );
+    assert!( dot.contains("  v4 -> y1;\n") );
+    assert!( dot.ends_with("}\n") );
+}
+//
+// test_constant
+// f(p, x) = [ p[0] + 4 , 5 ]
+fn test_constant() {
+    let p : Vec<V>     = vec![ V::from(1.0) ];
+    let x : Vec<V>     = vec![ V::from(1.0) ];
+    let (ap, _)        = start_recording( Some(p), x);
+    let ay_0           = &ap[0] + &AD::from( V::from(4.0) );
+    let ay_1           = AD::from( V::from(5.0) );
+    let f              = stop_recording( vec![ay_0, ay_1] );
+    //
+    let opt_vec : Vec<[&str; 2]> = Vec::new();
+    let dot = f.to_dot(&opt_vec);
+    assert!( dot.contains("d1 [label=\"d1\\nadd_pp\"") );
+    assert!( dot.contains("  d0 -> d1;\n") );
+    assert!( dot.contains("c1 [label=\"c1\\n4\"") );
+    assert!( dot.contains("c2 [label=\"c2\\n5\"") );
+    assert!( dot.contains("  c2 -> y1;\n") );
+    //
+    // the nan in cop[0] is not used
+    assert!( ! dot.contains("c0") );
+    //
+    let opt_vec = vec![ ["constant", "false"] ];
+    let dot = f.to_dot(&opt_vec);
+    assert!( ! dot.contains("c1") );
+    assert!( dot.contains("  d1 -> y0;\n") );
+    assert!( ! dot.contains("y1") );
+}
+//
+#[test]
+fn to_dot() {
+    test_call();
+    test_constant();
+}
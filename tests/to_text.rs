@@ -0,0 +1,151 @@
+// SPDX-License-Identifier: EPL-2.0 OR GPL-2.0-or-later
+// SPDX-FileCopyrightText: Bradley M. Bell <bradbell@seanet.com>
+// SPDX-FileContributor: 2026 Bradley M. Bell
+//
+use rustad::{
+    AD,
+    ADfn,
+    AzFloat,
+    NumVec,
+    FUnary,
+    FBinary,
+    start_recording,
+    stop_recording,
+};
+//
+// test_round_trip
+// f(p, x) = [ p[0] * sin( x[0] ) + 0.1 , if x[1] < 3 { x[1] * x[1] } , 7 ]
+fn test_round_trip() {
+    type V = AzFloat<f64>;
+    let opt_vec : Vec<[&str; 2]> = Vec::new();
+    //
+    // opt_is_one
+    // a message with characters that must be escaped
+    let opt_is_one = vec![
+        [ "message", "x[1] \"changed\"\n\tsee test_round_trip" ],
+    ];
+    //
+    // f
+    let p : Vec<V>   = vec![ V::from(2.0) ];
+    let x : Vec<V>   = vec![ V::from(0.5), V::from(1.5) ];
+    let (ap, ax)     = start_recording( Some(p.clone()), x.clone() );
+    let aq           = (&ap[0]).cos();
+    let ay_0         = &( &aq * &(&ax[0]).sin() ) + &AD::from( V::from(0.1) );
+    let ax_lt_three  = (&ax[1]).num_lt( &V::from(3.0) );
+    assert!( ax_lt_three.is_one(&opt_is_one) );
+    let ay_1         = &ax[1] * &ax[1];
+    let ay_2         = AD::from( V::from(7.0) );
+    let f            = stop_recording( vec![ay_0, ay_1, ay_2] );
+    //
+    // text, g
+    let text         = f.to_text();
+    assert!( text.contains("zero_one") );
+    assert!( text.contains("1 cos [ d0 ]") );
+    let g : ADfn<V>  = ADfn::from_text(&text).unwrap();
+    assert_eq!( g.to_text(), text );
+    assert_eq!( g.to_bytes(), f.to_bytes() );
+    //
+    // check
+    let p : Vec<V>   = vec![ V::from(-1.0) ];
+    let x : Vec<V>   = vec![ V::from(2.0), V::from(1.0) ];
+    let f_dyp        = f.forward_dyp_value(p.clone(), &opt_vec);
+    let g_dyp        = g.forward_dyp_value(p, &opt_vec);
+    let (f_y, _)     = f.forward_var_value(Some(&f_dyp), x.clone(), &opt_vec);
+    let (g_y, _)     = g.forward_var_value(Some(&g_dyp), x, &opt_vec);
+    assert_eq!( f_y, g_y );
+}
+//
+// test_num_vec
+// f(x) = [ x[0] * c ]
+fn test_num_vec() {
+    type S = AzFloat<f32>;
+    type V = NumVec<S>;
+    let c            = V::new( vec![ S::from(0.1), S::from(-2.0), S::from(3.5) ] );
+    let x : Vec<V>   = vec![ V::from(2.0) ];
+    let (_, ax)      = start_recording(None, x.clone() );
+    let ay_0         = &ax[0] * &AD::from(c);
+    let f            = stop_recording( vec![ay_0] );
+    //
+    let text         = f.to_text();
+    assert!( text.ends_with("[ 0.1, -2.0, 3.5 ]\n") );
+    let g : ADfn<V>  = ADfn::from_text(&text).unwrap();
+    assert_eq!( g.to_text(), text );
+}
+//
+// test_error
+fn test_error() {
+    type V = AzFloat<f64>;
+    let text = "rustad ADfn text 1\n\
+                dyp n_dom 0 n_dep 0\n\
+                var n_dom 1 n_dep 1\n\
+                1 sin [ v0 ]\n\
+                rng [ v1 ]\n";
+    assert!( ADfn::<V>::from_text(text).is_ok() );
+    //
+    // header
+    let bad = text.replace("text 1", "text 2");
+    assert!( ADfn::<V>::from_text(&bad).is_err() );
+    //
+    // operator name
+    let bad = text.replace("sin", "not_an_op");
+    let message = ADfn::<V>::from_text(&bad).err().unwrap();
+    assert!( message.contains("line 4") );
+    assert!( message.contains("not_an_op") );
+    //
+    // argument
+    let bad = text.replace("[ v0 ]", "[ x0 ]");
+    assert!( ADfn::<V>::from_text(&bad).is_err() );
+    //
+    // result index
+    let bad = text.replace("1 sin", "2 sin");
+    assert!( ADfn::<V>::from_text(&bad).is_err() );
+    //
+    // booleans that do not belong to an operator
+    let bad = text.replace("[ v0 ]", "[ v0 ] bool [ true ]");
+    assert!( ADfn::<V>::from_text(&bad).is_err() );
+    //
+    // rng
+    let bad = text.replace("rng [ v1 ]", "");
+    assert!( ADfn::<V>::from_text(&bad).is_err() );
+    //
+    // cop
+    let bad = text.to_string() + "cop 0 one\n";
+    let message = ADfn::<V>::from_text(&bad).err().unwrap();
+    assert!( message.contains("line 6") );
+    //
+    // out of range operator argument
+    let bad = text.replace("[ v0 ]", "[ v7 ]");
+    let message = ADfn::<V>::from_text(&bad).err().unwrap();
+    assert!( message.contains("line 4") );
+    assert!( message.contains("out of range") );
+    //
+    // out of range rng index
+    let bad = text.replace("rng [ v1 ]", "rng [ v9 ]");
+    let message = ADfn::<V>::from_text(&bad).err().unwrap();
+    assert!( message.contains("line 5") );
+    assert!( message.contains("out of range") );
+    //
+    // wrong number of arguments
+    let bad = text.replace("sin [ v0 ]", "mul_vv [ v0 ]");
+    let message = ADfn::<V>::from_text(&bad).err().unwrap();
+    assert!( message.contains("line 4") );
+    assert!( message.contains("wrong number of arguments") );
+    //
+    // forward reference
+    let bad = "rustad ADfn text 1\n\
+               dyp n_dom 0 n_dep 0\n\
+               var n_dom 1 n_dep 2\n\
+               1 sin [ v2 ]\n\
+               2 cos [ v0 ]\n\
+               rng [ v1 ]\n";
+    let message = ADfn::<V>::from_text(bad).err().unwrap();
+    assert!( message.contains("line 4") );
+    assert!( message.contains("index 2 is out of range") );
+}
+//
+#[test]
+fn to_text() {
+    test_round_trip();
+    test_num_vec();
+    test_error();
+}
@@ -0,0 +1,228 @@
+// SPDX-License-Identifier: EPL-2.0 OR GPL-2.0-or-later
+// SPDX-FileCopyrightText: Bradley M. Bell <bradbell@seanet.com>
+// SPDX-FileContributor: 2026 Bradley M. Bell
+#![allow(clippy::needless_range_loop)]
+//
+use rustad::{
+    AD,
+    ADfn,
+    AzFloat,
+    NumVec,
+    VecAD,
+    start_recording,
+    stop_recording,
+};
+//
+// V
+type V = AzFloat<f64>;
+//
+// record_f
+// f(x) = [ table[ x[0] ] * x[1] ] where table = [ x[2], 2 * x[2], 5 ]
+fn record_f(x : Vec<V>) -> ADfn<V> {
+    let (_, ax)   = start_recording(None, x);
+    let atwo      = AD::from( V::from(2.0) );
+    let afive     = AD::from( V::from(5.0) );
+    let avec      = VecAD::new( vec![ ax[2].clone(), &atwo * &ax[2], afive ] );
+    let aload     = avec.load( &ax[0] );
+    let ay        = &aload * &ax[1];
+    stop_recording( vec![ay] )
+}
+//
+// test_value
+fn test_value() {
+    let opt_vec : Vec<[&str; 2]> = Vec::new();
+    let f = record_f( vec![ V::from(0.0), V::from(3.0), V::from(4.0) ] );
+    //
+    // the index changes with the domain point
+    let table = [ 4.0, 8.0, 5.0 ];
+    for j in 0 .. 3 {
+        let x       = vec![ V::from(j as f64), V::from(3.0), V::from(4.0) ];
+        let (y, _)  = f.forward_var_value(None, x, &opt_vec);
+        assert_eq!( y[0], V::from( 3.0 * table[j] ) );
+    }
+    //
+    // an index that is not in the table results in nan
+    let x       = vec![ V::from(1.5), V::from(3.0), V::from(4.0) ];
+    let (y, _)  = f.forward_var_value(None, x, &opt_vec);
+    assert!( y[0].to_inner().is_nan() );
+}
+//
+// test_store
+// A state machine where the state transitions are stored at run time.
+fn test_store() {
+    let opt_vec : Vec<[&str; 2]> = Vec::new();
+    //
+    // f
+    // avec = [ 0, 0, 0 ]; avec[ x[0] ] = x[1]; y = [ avec[0], avec[1], avec[2] ]
+    let x         = vec![ V::from(0.0), V::from(7.0) ];
+    let (_, ax)   = start_recording(None, x);
+    let azero     = AD::from( V::from(0.0) );
+    let mut avec  = VecAD::new( vec![ azero; 3 ] );
+    avec.store( &ax[0], &ax[1] );
+    let mut ay : Vec< AD<V> > = Vec::new();
+    for j in 0 .. 3 {
+        ay.push( avec.load( &AD::from( V::from(j as f64) ) ) );
+    }
+    let f         = stop_recording(ay);
+    //
+    for j in 0 .. 3 {
+        let x       = vec![ V::from(j as f64), V::from(9.0) ];
+        let (y, _)  = f.forward_var_value(None, x, &opt_vec);
+        for k in 0 .. 3 {
+            let check = if j == k { 9.0 } else { 0.0 };
+            assert_eq!( y[k], V::from(check) );
+        }
+    }
+    //
+    // store with a constant index does not record an operator
+    let x         = vec![ V::from(7.0) ];
+    let (_, ax)   = start_recording(None, x);
+    let azero     = AD::from( V::from(0.0) );
+    let mut avec  = VecAD::new( vec![ azero.clone(), azero ] );
+    avec.store( &AD::from( V::from(1.0) ), &ax[0] );
+    let ay        = avec.load( &AD::from( V::from(1.0) ) );
+    let f         = stop_recording( vec![ay] );
+    assert_eq!( f.var_dep_len(), 0 );
+}
+//
+// test_dynamic
+// f(p, x) = [ table[ p[0] ] * x[0] ] where table = [ 2, 3 ]
+fn test_dynamic() {
+    let opt_vec : Vec<[&str; 2]> = Vec::new();
+    let p         = vec![ V::from(0.0) ];
+    let x         = vec![ V::from(4.0) ];
+    let (ap, ax)  = start_recording( Some(p), x.clone() );
+    let table     = vec![ AD::from( V::from(2.0) ), AD::from( V::from(3.0) ) ];
+    let avec      = VecAD::new(table);
+    let aload     = avec.load( &ap[0] );
+    let ay        = &aload * &ax[0];
+    let f         = stop_recording( vec![ay] );
+    assert!( f.to_text().contains("load") );
+    //
+    for (p_0, check) in [ (0.0, 2.0), (1.0, 3.0) ] {
+        let p        = vec![ V::from(p_0) ];
+        let dyp_all  = f.forward_dyp_value(p, &opt_vec);
+        let (y, _)   = f.forward_var_value(Some(&dyp_all), x.clone(), &opt_vec);
+        assert_eq!( y[0], V::from(check) * x[0] );
+    }
+}
+//
+// test_num_vec
+// The indexing is element wise.
+fn test_num_vec() {
+    type S = AzFloat<f64>;
+    type W = NumVec<S>;
+    let opt_vec : Vec<[&str; 2]> = Vec::new();
+    let x0        = W::new( vec![ S::from(0.0), S::from(1.0) ] );
+    let (_, ax)   = start_recording(None, vec![ x0 ] );
+    let table     = vec![ AD::from( W::from(4.0) ), AD::from( W::from(5.0) ) ];
+    let avec      = VecAD::new(table);
+    let ay        = avec.load( &ax[0] );
+    let f         = stop_recording( vec![ay] );
+    //
+    let x0        = W::new( vec![ S::from(1.0), S::from(0.0) ] );
+    let (y, _)    = f.forward_var_value(None, vec![x0], &opt_vec);
+    assert_eq!( y[0], W::new( vec![ S::from(5.0), S::from(4.0) ] ) );
+}
+//
+// test_derivative
+fn test_derivative() {
+    let opt_vec : Vec<[&str; 2]> = Vec::new();
+    let f = record_f( vec![ V::from(0.0), V::from(3.0), V::from(4.0) ] );
+    //
+    for j in 0 .. 3 {
+        let x = vec![ V::from(j as f64), V::from(3.0), V::from(4.0) ];
+        //
+        // check
+        let check = match j {
+            0 => vec![ V::from(0.0), x[2], x[1] ],
+            1 => vec![ V::from(0.0), V::from(2.0) * x[2], V::from(2.0) * x[1] ],
+            _ => vec![ V::from(0.0), V::from(5.0), V::from(0.0) ],
+        };
+        //
+        // forward_der_value
+        let (_, v)  = f.forward_var_value(None, x.clone(), &opt_vec);
+        for k in 0 .. 3 {
+            let mut dx = vec![ V::from(0.0); 3 ];
+            dx[k]      = V::from(1.0);
+            let dy     = f.forward_der_value(None, &v, dx, &opt_vec);
+            assert_eq!( dy[0], check[k] );
+        }
+        //
+        // reverse_der_value
+        let dy = vec![ V::from(1.0) ];
+        let dx = f.reverse_der_value(None, &v, dy, &opt_vec);
+        assert_eq!( dx, check );
+        //
+        // forward_taylor_value, reverse_taylor_value
+        // partial of y[order] w.r.t. x[order] is the first derivative
+        let order       = 1;
+        let dom_taylor  = vec![ V::from(1.0); 3 ];
+        let (_, var_taylor) = f.forward_taylor_value(
+            None, &v, order, dom_taylor, &opt_vec
+        );
+        let rng_weight  = vec![ V::from(1.0) ];
+        let dom_partial = f.reverse_taylor_value(
+            None, &var_taylor, order, rng_weight, &opt_vec
+        );
+        for k in 0 .. 3 {
+            assert_eq!( dom_partial[k * (order + 1) + order], check[k] );
+        }
+    }
+}
+//
+// test_sparsity
+// The vector is treated as a single dependency set and
+// the index does not contribute to the Hessian.
+fn test_sparsity() {
+    let opt_vec : Vec<[&str; 2]> = Vec::new();
+    let f = record_f( vec![ V::from(0.0), V::from(3.0), V::from(4.0) ] );
+    //
+    // for_sparsity
+    let mut pattern = f.for_sparsity(&opt_vec);
+    pattern.sort();
+    assert_eq!( pattern, vec![ [0, 0], [0, 1], [0, 2] ] );
+    //
+    // sub_sparsity
+    let (_, mut pattern) = f.sub_sparsity(&opt_vec);
+    pattern.sort();
+    assert_eq!( pattern, vec![ [0, 0], [0, 1], [0, 2] ] );
+    //
+    // hes_sparsity
+    let select_rng  = vec![ true ];
+    let mut pattern = f.hes_sparsity(&select_rng, &opt_vec);
+    pattern.sort();
+    assert_eq!( pattern, vec![ [1, 2], [2, 1] ] );
+}
+//
+// test_optimize
+fn test_optimize() {
+    let opt_vec : Vec<[&str; 2]> = Vec::new();
+    let mut f = record_f( vec![ V::from(0.0), V::from(3.0), V::from(4.0) ] );
+    f.optimize(&opt_vec);
+    //
+    let x       = vec![ V::from(1.0), V::from(3.0), V::from(4.0) ];
+    let (y, _)  = f.forward_var_value(None, x, &opt_vec);
+    assert_eq!( y[0], V::from(24.0) );
+}
+//
+// test_to_text
+fn test_to_text() {
+    let f = record_f( vec![ V::from(0.0), V::from(3.0), V::from(4.0) ] );
+    let text         = f.to_text();
+    let g : ADfn<V>  = ADfn::from_text(&text).unwrap();
+    assert_eq!( g.to_text(), text );
+    assert_eq!( g.to_bytes(), f.to_bytes() );
+}
+//
+#[test]
+fn vec_ad() {
+    test_value();
+    test_store();
+    test_dynamic();
+    test_num_vec();
+    test_derivative();
+    test_sparsity();
+    test_optimize();
+    test_to_text();
+}
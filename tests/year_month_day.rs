@@ -2,8 +2,6 @@
 // SPDX-FileCopyrightText: Bradley M. Bell <bradbell@seanet.com>
 // SPDX-FileContributor: 2025-26 Bradley M. Bell
 
-use rustad;
-
 #[test]
 fn year_month_day() {
     let year_month_day = rustad::YEAR_MONTH_DAY;
@@ -1,6 +1,7 @@
 // SPDX-License-Identifier: EPL-2.0 OR GPL-2.0-or-later
 // SPDX-FileCopyrightText: Bradley M. Bell <bradbell@seanet.com>
 // SPDX-FileContributor: 2026 Bradley M. Bell
+#![allow(clippy::useless_vec)]
 //
 use rustad::{
     AD,
@@ -85,7 +86,7 @@ fn zero_one() {
     let lib         = get_lib(src_dir, lib_file, replace_lib);
     //
     // zero_one_fn
-    let zero_one_fn : RustSrcLink<V> = get_rust_src_fn(&lib, &gn_name);
+    let zero_one_fn : RustSrcLink<V> = get_rust_src_fn(&lib, gn_name);
     //
     // p_ref, x_ref
     let p               = vec![ V::from(-5.0) ];